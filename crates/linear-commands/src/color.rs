@@ -0,0 +1,58 @@
+//! Label color chips for terminal output.
+//!
+//! Colors come from `linear_core::color`; this module only picks the terminal's color depth once
+//! and converts to crossterm's color type.
+
+use std::env;
+use std::sync::OnceLock;
+
+use crossterm::style::{Color, Stylize};
+use linear_core::color::{self, ColorDepth, TermColor};
+use linear_core::graphql::IssueLabel;
+
+use crate::table;
+
+/// Color depth of the terminal, detected once from the environment.
+pub fn depth() -> ColorDepth {
+    static DEPTH: OnceLock<ColorDepth> = OnceLock::new();
+    *DEPTH.get_or_init(|| ColorDepth::from_env(|name| env::var(name).ok()))
+}
+
+/// Background and foreground for a chip of color `hex`.
+pub fn crossterm_chip(hex: &str) -> Option<(Color, Color)> {
+    let (background, foreground) = color::chip(hex, depth())?;
+    Some((to_crossterm(background), to_crossterm(foreground)))
+}
+
+/// Labels as ` name ` chips on their own colors, or `a, b` when stdout takes no color.
+pub fn label_chips(labels: &[IssueLabel]) -> String {
+    if !table::color_enabled() {
+        return labels
+            .iter()
+            .map(|label| label.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+    }
+    labels
+        .iter()
+        .map(|label| {
+            let text = format!(" {} ", label.name);
+            match label.color.as_deref().and_then(crossterm_chip) {
+                Some((background, foreground)) => text.with(foreground).on(background).to_string(),
+                None => text,
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn to_crossterm(color: TermColor) -> Color {
+    match color {
+        TermColor::Rgb(rgb) => Color::Rgb {
+            r: rgb.0,
+            g: rgb.1,
+            b: rgb.2,
+        },
+        TermColor::Indexed(index) => Color::AnsiValue(index),
+    }
+}
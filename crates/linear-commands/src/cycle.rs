@@ -0,0 +1,166 @@
+//! `linear cycle list|update`.
+
+use std::io::Write;
+
+use anyhow::{Context, Result};
+use clap::Args;
+use linear_core::config::Impact;
+use linear_core::graphql::{CycleSummary, CycleUpdateInput, LinearGraphqlClient};
+use linear_core::services::cycles::{CycleQueryOptions, CycleService};
+
+use crate::fields::write_json;
+use crate::table::Table;
+use crate::{sort, Mutation};
+
+#[derive(Args, Debug, Clone)]
+pub struct ListArgs {
+    /// Team id to filter
+    #[arg(long = "team-id")]
+    pub team_id: Option<String>,
+    /// Include only cycles in state
+    #[arg(long)]
+    pub state: Option<String>,
+    /// Sort field (start|end) with optional :asc/:desc
+    #[arg(long, default_value = "start:desc")]
+    pub sort: String,
+    /// Maximum cycles to return
+    #[arg(long, default_value_t = 20)]
+    pub limit: usize,
+    /// Cursor for pagination
+    #[arg(long)]
+    pub after: Option<String>,
+    /// Output raw JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct UpdateArgs {
+    /// Cycle id
+    #[arg(long)]
+    pub id: String,
+    /// New name
+    #[arg(long)]
+    pub name: Option<String>,
+    /// Start date (YYYY-MM-DD)
+    #[arg(long = "start-date")]
+    pub start_date: Option<String>,
+    /// End date (YYYY-MM-DD)
+    #[arg(long = "end-date")]
+    pub end_date: Option<String>,
+    /// Cycle state
+    #[arg(long)]
+    pub state: Option<String>,
+    /// Output raw JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+impl Mutation for UpdateArgs {
+    fn impact(&self) -> Impact {
+        Impact::Mutation
+    }
+
+    fn action(&self) -> String {
+        format!("update cycle {}", self.id)
+    }
+}
+
+/// List cycles, with a `--after` hint on stderr when more pages exist.
+pub async fn list(
+    client: &LinearGraphqlClient,
+    out: &mut impl Write,
+    args: ListArgs,
+) -> Result<()> {
+    let options = CycleQueryOptions {
+        limit: args.limit,
+        after: args.after,
+        team_id: args.team_id,
+        state: args.state,
+        sort: Some(sort::cycle(&args.sort)?),
+    };
+    let cycles = CycleService::new(client.clone())
+        .list(options)
+        .await
+        .context("GraphQL request failed")?;
+    if args.json {
+        return write_json(out, &cycles);
+    }
+    render_cycle_list(out, &cycles.nodes)?;
+    if cycles.has_next_page {
+        if let Some(cursor) = &cycles.end_cursor {
+            eprintln!(
+                "… more cycles available. Use --after \"{}\" to continue.",
+                cursor
+            );
+        } else {
+            eprintln!("… more cycles available.");
+        }
+    }
+    Ok(())
+}
+
+/// Rename or reschedule a cycle.
+pub async fn update(
+    client: &LinearGraphqlClient,
+    out: &mut impl Write,
+    args: UpdateArgs,
+) -> Result<()> {
+    let input = CycleUpdateInput {
+        name: args.name,
+        starts_at: args.start_date,
+        ends_at: args.end_date,
+        state: args.state,
+    };
+    let cycle = CycleService::new(client.clone())
+        .update(&args.id, input)
+        .await
+        .context("GraphQL request failed")?;
+    if args.json {
+        return write_json(out, &cycle);
+    }
+    writeln!(
+        out,
+        "Updated cycle {}",
+        cycle.name.as_deref().unwrap_or(&cycle.id)
+    )?;
+    writeln!(
+        out,
+        "Team: {}  Number: {}  Span: {} → {}",
+        cycle
+            .team
+            .as_ref()
+            .map(|t| t.key.clone())
+            .unwrap_or_else(|| "-".into()),
+        cycle.number,
+        cycle.starts_at.as_deref().unwrap_or("-"),
+        cycle.ends_at.as_deref().unwrap_or("-")
+    )?;
+    Ok(())
+}
+
+fn render_cycle_list(out: &mut impl Write, cycles: &[CycleSummary]) -> Result<()> {
+    let mut table = Table::new()
+        .column("NUMBER", 8)
+        .column("TEAM", 8)
+        .column("STATE", 8)
+        .column("START", 24)
+        .column("END", 24)
+        .flex_column("NAME", 8, 32);
+    for cycle in cycles {
+        table.row([
+            cycle.number.to_string(),
+            cycle
+                .team
+                .as_ref()
+                .map(|t| t.key.clone())
+                .unwrap_or_else(|| "-".into()),
+            cycle.state.clone().unwrap_or_else(|| "-".into()),
+            cycle.starts_at.clone().unwrap_or_else(|| "-".into()),
+            cycle.ends_at.clone().unwrap_or_else(|| "-".into()),
+            cycle.name.clone().unwrap_or_else(|| "-".into()),
+        ]);
+    }
+    table.write(out)?;
+    Ok(())
+}
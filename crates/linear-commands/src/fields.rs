@@ -0,0 +1,55 @@
+use std::io::{self, Write};
+use std::sync::OnceLock;
+
+use anyhow::Result;
+use linear_core::extract::{format_row, FieldSelection, Separator};
+use serde::Serialize;
+use serde_json::Value;
+
+static SELECTION: OnceLock<Option<(FieldSelection, Separator)>> = OnceLock::new();
+
+/// Fix the `--fields` selection for the process.
+pub fn init(fields: Option<FieldSelection>, separator: Separator) {
+    let _ = SELECTION.set(fields.map(|fields| (fields, separator)));
+}
+
+fn selection() -> Option<&'static (FieldSelection, Separator)> {
+    SELECTION.get_or_init(|| None).as_ref()
+}
+
+/// Print `value` as pretty JSON, or as the selected fields, one line per record.
+pub fn print_json<T: Serialize + ?Sized>(value: &T) -> Result<()> {
+    write_json(&mut io::stdout().lock(), value)
+}
+
+/// [`print_json`] to `out`.
+pub fn write_json<T: Serialize + ?Sized>(out: &mut impl Write, value: &T) -> Result<()> {
+    match selection() {
+        Some(_) => {
+            for line in field_lines(&serde_json::to_value(value)?) {
+                writeln!(out, "{}", line)?;
+            }
+        }
+        None => writeln!(out, "{}", serde_json::to_string_pretty(value)?)?,
+    }
+    Ok(())
+}
+
+/// One NDJSON line for `value`, or its selected fields.
+pub fn json_line<T: Serialize + ?Sized>(value: &T) -> serde_json::Result<String> {
+    match selection() {
+        Some(_) => Ok(field_lines(&serde_json::to_value(value)?).join("\n")),
+        None => serde_json::to_string(value),
+    }
+}
+
+fn field_lines(value: &Value) -> Vec<String> {
+    let Some((fields, separator)) = selection() else {
+        return Vec::new();
+    };
+    fields
+        .rows(value)
+        .iter()
+        .map(|row| format_row(row, *separator))
+        .collect()
+}
@@ -0,0 +1,65 @@
+use std::env;
+use std::io::{self, IsTerminal};
+use std::sync::OnceLock;
+
+static ENABLED: OnceLock<bool> = OnceLock::new();
+
+/// Fix hyperlink support for the process; `disabled` comes from `--no-hyperlinks`.
+pub fn init(disabled: bool) {
+    let _ = ENABLED.set(!disabled && detect());
+}
+
+/// Whether OSC 8 hyperlinks should be emitted on stdout.
+pub fn enabled() -> bool {
+    *ENABLED.get_or_init(detect)
+}
+
+/// Wrap `label` in an OSC 8 hyperlink pointing at `url` when hyperlinks are enabled.
+pub fn link(url: &str, label: &str) -> String {
+    if enabled() {
+        format!("{}{}{}", open(url), label, close())
+    } else {
+        label.to_owned()
+    }
+}
+
+pub fn open(url: &str) -> String {
+    format!("\x1b]8;;{}\x1b\\", url)
+}
+
+pub fn close() -> &'static str {
+    "\x1b]8;;\x1b\\"
+}
+
+/// Conservative capability check: `FORCE_HYPERLINK` wins, otherwise only terminals known to
+/// understand OSC 8 get links, since some others print the escape sequence verbatim.
+fn detect() -> bool {
+    if let Ok(force) = env::var("FORCE_HYPERLINK") {
+        return force != "0";
+    }
+    if !io::stdout().is_terminal() {
+        return false;
+    }
+    let var = |name: &str| env::var(name).unwrap_or_default();
+    let term = var("TERM");
+    if term == "dumb" {
+        return false;
+    }
+    let known_program = matches!(
+        var("TERM_PROGRAM").as_str(),
+        "iTerm.app" | "WezTerm" | "vscode" | "ghostty" | "Hyper" | "rio"
+    );
+    let known_term = ["kitty", "alacritty", "foot", "wezterm", "contour"]
+        .iter()
+        .any(|name| term.contains(name));
+    let vte = var("VTE_VERSION")
+        .parse::<u32>()
+        .is_ok_and(|version| version >= 5000);
+    known_program
+        || known_term
+        || vte
+        || env::var_os("WT_SESSION").is_some()
+        || env::var_os("KONSOLE_VERSION").is_some()
+        || env::var_os("DOMTERM").is_some()
+        || env::var_os("KITTY_WINDOW_ID").is_some()
+}
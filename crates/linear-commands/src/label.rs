@@ -0,0 +1,160 @@
+//! `linear label list|create|update`.
+
+use std::io::Write;
+
+use anyhow::{Context, Result};
+use clap::Args;
+use linear_core::config::Impact;
+use linear_core::graphql::{
+    IssueLabel, IssueLabelCreateInput, IssueLabelUpdateInput, LinearGraphqlClient,
+};
+use linear_core::services::labels::LabelService;
+
+use crate::fields::write_json;
+use crate::table::{Cell, Table};
+use crate::Mutation;
+
+#[derive(Args, Debug, Clone)]
+pub struct ListArgs {
+    /// Team id
+    #[arg(long = "team-id")]
+    pub team_id: String,
+    /// Output raw JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct CreateArgs {
+    /// Team id
+    #[arg(long = "team-id")]
+    pub team_id: String,
+    /// Label name
+    #[arg(long)]
+    pub name: String,
+    /// Description
+    #[arg(long)]
+    pub description: Option<String>,
+    /// Hex color (#RGB/ #RRGGBB)
+    #[arg(long)]
+    pub color: Option<String>,
+    /// Output raw JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+impl Mutation for CreateArgs {
+    fn impact(&self) -> Impact {
+        Impact::Mutation
+    }
+
+    fn action(&self) -> String {
+        format!("create label \"{}\"", self.name)
+    }
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct UpdateArgs {
+    /// Label id
+    #[arg(long)]
+    pub id: String,
+    /// New name
+    #[arg(long)]
+    pub name: Option<String>,
+    /// Description
+    #[arg(long)]
+    pub description: Option<String>,
+    /// Hex color
+    #[arg(long)]
+    pub color: Option<String>,
+    /// Output raw JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+impl Mutation for UpdateArgs {
+    fn impact(&self) -> Impact {
+        Impact::Mutation
+    }
+
+    fn action(&self) -> String {
+        format!("update label {}", self.id)
+    }
+}
+
+/// List a team's labels with their color chips.
+pub async fn list(
+    client: &LinearGraphqlClient,
+    out: &mut impl Write,
+    args: ListArgs,
+) -> Result<()> {
+    let labels = LabelService::new(client.clone())
+        .list(&args.team_id)
+        .await
+        .context("GraphQL request failed")?;
+    if args.json {
+        return write_json(out, &labels);
+    }
+    render_label_list(out, &labels)
+}
+
+/// Create a team label; a blank `--color` lets Linear pick one.
+pub async fn create(
+    client: &LinearGraphqlClient,
+    out: &mut impl Write,
+    args: CreateArgs,
+) -> Result<()> {
+    let input = IssueLabelCreateInput {
+        team_id: args.team_id,
+        name: args.name,
+        description: args.description,
+        color: args.color.filter(|color| !color.trim().is_empty()),
+    };
+    let label = LabelService::new(client.clone())
+        .create(input)
+        .await
+        .context("GraphQL request failed")?;
+    if args.json {
+        return write_json(out, &label);
+    }
+    writeln!(out, "Created label {} ({})", label.name, label.id)?;
+    Ok(())
+}
+
+/// Rename, describe, or recolor a label.
+pub async fn update(
+    client: &LinearGraphqlClient,
+    out: &mut impl Write,
+    args: UpdateArgs,
+) -> Result<()> {
+    let input = IssueLabelUpdateInput {
+        name: args.name,
+        description: args.description,
+        color: args.color,
+    };
+    let label = LabelService::new(client.clone())
+        .update(&args.id, input)
+        .await
+        .context("GraphQL request failed")?;
+    if args.json {
+        return write_json(out, &label);
+    }
+    writeln!(out, "Updated label {} ({})", label.name, label.id)?;
+    Ok(())
+}
+
+fn render_label_list(out: &mut impl Write, labels: &[IssueLabel]) -> Result<()> {
+    let mut table = Table::new()
+        .column("ID", 36)
+        .flex_column("NAME", 12, 32)
+        .column("COLOR", 12);
+    for label in labels {
+        let color = match label.color.as_deref() {
+            Some(hex) => Cell::chip(hex),
+            None => Cell::from("-"),
+        };
+        table.row([label.id.as_str().into(), label.name.as_str().into(), color]);
+    }
+    table.write(out)?;
+    Ok(())
+}
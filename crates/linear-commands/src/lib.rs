@@ -0,0 +1,29 @@
+//! Command implementations shared by the `linear` binary and anything else that wants the CLI's
+//! output: each command takes a GraphQL client, a writer for its output, and its clap arguments.
+//!
+//! Sessions, profiles, and confirmation prompts stay with the front-end; a command only sees the
+//! client it was handed. Mutating commands implement [`Mutation`] so the caller can ask first.
+
+pub mod color;
+pub mod cycle;
+pub mod fields;
+pub mod hyperlink;
+pub mod label;
+pub mod markdown;
+pub mod project;
+pub mod sort;
+pub mod state;
+pub mod table;
+pub mod team;
+pub mod user;
+pub mod workspaces;
+
+use linear_core::config::Impact;
+
+/// A command that changes the workspace, described for a confirmation prompt.
+pub trait Mutation {
+    /// How hard the change is to undo.
+    fn impact(&self) -> Impact;
+    /// What the command is about to do, e.g. `update label abc`.
+    fn action(&self) -> String;
+}
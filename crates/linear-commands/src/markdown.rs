@@ -0,0 +1,153 @@
+use std::io::{self, IsTerminal};
+use std::sync::OnceLock;
+
+use crossterm::terminal;
+use pulldown_cmark::{Event, Options, Parser as MarkdownParser, Tag, TagEnd};
+
+use crate::hyperlink;
+
+/// Wrap width when stdout is not a terminal and `--width` is not given.
+const PIPED_WIDTH: usize = 80;
+
+static WIDTH: OnceLock<usize> = OnceLock::new();
+
+/// Fix the wrap width for the process; `width` comes from `--width`.
+pub fn init_width(width: Option<u16>) {
+    let _ = WIDTH.set(width.map_or_else(detect_width, usize::from));
+}
+
+/// Columns that issue descriptions and comments wrap at.
+pub fn wrap_width() -> usize {
+    *WIDTH.get_or_init(detect_width)
+}
+
+fn detect_width() -> usize {
+    if !io::stdout().is_terminal() {
+        return PIPED_WIDTH;
+    }
+    terminal::size()
+        .map(|(cols, _)| usize::from(cols))
+        .unwrap_or(PIPED_WIDTH)
+}
+
+/// Plain-text rendering of a markdown body plus the images it embeds.
+pub struct Rendered {
+    pub text: String,
+    pub images: Vec<ImageRef>,
+}
+
+/// Image node found while rendering; shown inline as a labeled link.
+#[derive(Debug, Clone)]
+pub struct ImageRef {
+    pub alt: String,
+    pub url: String,
+}
+
+impl ImageRef {
+    fn label(&self) -> String {
+        if self.alt.trim().is_empty() {
+            "[image]".to_owned()
+        } else {
+            format!("[image: {}]", self.alt.trim())
+        }
+    }
+}
+
+pub fn markdown_to_text(input: &str) -> String {
+    render(input).text
+}
+
+pub fn render(input: &str) -> Rendered {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    let parser = MarkdownParser::new_ext(input, options);
+    let mut out = String::new();
+    let mut images = Vec::new();
+    let mut image: Option<ImageRef> = None;
+    let mut need_space = false;
+    for event in parser {
+        match event {
+            Event::Text(text) | Event::Code(text) if image.is_some() => {
+                if let Some(image) = image.as_mut() {
+                    image.alt.push_str(&text);
+                }
+            }
+            Event::Text(text) | Event::Code(text) => {
+                if need_space
+                    && !out.ends_with([' ', '\n'])
+                    && !text.starts_with(char::is_whitespace)
+                {
+                    out.push(' ');
+                }
+                out.push_str(&text);
+                need_space = true;
+            }
+            Event::Start(Tag::Image { dest_url, .. }) => {
+                image = Some(ImageRef {
+                    alt: String::new(),
+                    url: dest_url.into_string(),
+                });
+            }
+            Event::End(TagEnd::Image) => {
+                if let Some(image) = image.take() {
+                    if need_space && !out.ends_with([' ', '\n']) {
+                        out.push(' ');
+                    }
+                    out.push_str(&image_link(&image));
+                    need_space = true;
+                    images.push(image);
+                }
+            }
+            Event::SoftBreak => {
+                out.push(' ');
+                need_space = false;
+            }
+            Event::HardBreak => {
+                out.push('\n');
+                need_space = false;
+            }
+            Event::Start(Tag::Paragraph) => {
+                if !out.ends_with('\n') && !out.is_empty() {
+                    out.push('\n');
+                }
+                need_space = false;
+            }
+            Event::End(TagEnd::Paragraph) => {
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                need_space = false;
+            }
+            Event::Start(Tag::List(_)) if !out.ends_with('\n') && !out.is_empty() => {
+                out.push('\n');
+            }
+            Event::Start(Tag::Item) => {
+                if !out.ends_with('\n') && !out.is_empty() {
+                    out.push('\n');
+                }
+                out.push_str("- ");
+                need_space = false;
+            }
+            Event::End(TagEnd::Item) => {
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                need_space = false;
+            }
+            _ => {}
+        }
+    }
+    Rendered {
+        text: out.trim().to_string(),
+        images,
+    }
+}
+
+/// Clickable label where hyperlinks are supported, `label <url>` otherwise.
+fn image_link(image: &ImageRef) -> String {
+    if hyperlink::enabled() {
+        hyperlink::link(&image.url, &image.label())
+    } else {
+        format!("{} <{}>", image.label(), image.url)
+    }
+}
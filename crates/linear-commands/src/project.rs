@@ -0,0 +1,452 @@
+//! `linear project list|create|update|archive`.
+
+use std::future::Future;
+use std::io::Write;
+
+use anyhow::{Context, Result};
+use clap::Args;
+use linear_core::config::Impact;
+use linear_core::error::LinearError;
+use linear_core::graphql::{
+    LinearGraphqlClient, ProjectCreateInput, ProjectDetail, ProjectSummary, ProjectUpdateInput,
+};
+use linear_core::services::projects::{
+    MilestoneSpec, ProjectExtras, ProjectQueryOptions, ProjectService, ProjectSetup,
+};
+
+use crate::fields::write_json;
+use crate::hyperlink;
+use crate::table::{Cell, Table};
+use crate::workspaces::{fan_out, with_workspace};
+use crate::{sort, Mutation};
+
+#[derive(Args, Debug, Clone)]
+pub struct ListArgs {
+    /// Maximum projects to return
+    #[arg(long, default_value_t = 20)]
+    pub limit: usize,
+    /// Cursor for pagination
+    // `all_profiles` and `profiles` are the front-end's multi-profile flags.
+    #[arg(long, conflicts_with_all = ["all_profiles", "profiles"])]
+    pub after: Option<String>,
+    /// Filter by project state
+    #[arg(long)]
+    pub state: Option<String>,
+    /// Filter by project status
+    #[arg(long)]
+    pub status: Option<String>,
+    /// Filter by team id
+    #[arg(long = "team-id", conflicts_with_all = ["all_profiles", "profiles"])]
+    pub team_id: Option<String>,
+    /// Sort results (updated|created|target) with optional :asc/:desc
+    #[arg(long, default_value = "updated:desc")]
+    pub sort: String,
+    /// Output raw JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+impl ListArgs {
+    fn options(&self) -> Result<ProjectQueryOptions> {
+        Ok(ProjectQueryOptions {
+            limit: self.limit,
+            after: self.after.clone(),
+            state: self.state.clone(),
+            status: self.status.clone(),
+            team_id: self.team_id.clone(),
+            sort: Some(sort::project(&self.sort)?),
+        })
+    }
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct CreateArgs {
+    /// Project name
+    #[arg(long)]
+    pub name: String,
+    /// Project description
+    #[arg(long)]
+    pub description: Option<String>,
+    /// Project state
+    #[arg(long)]
+    pub state: Option<String>,
+    /// Start date (YYYY-MM-DD)
+    #[arg(long = "start-date")]
+    pub start_date: Option<String>,
+    /// Target date (YYYY-MM-DD)
+    #[arg(long = "target-date")]
+    pub target_date: Option<String>,
+    /// Lead id
+    #[arg(long = "lead-id")]
+    pub lead_id: Option<String>,
+    /// Associate teams (repeatable)
+    #[arg(long = "team-id")]
+    pub team_ids: Vec<String>,
+    /// Add a member by id, email, or name (repeatable)
+    #[arg(long = "member", value_name = "USER")]
+    pub members: Vec<String>,
+    /// Add a milestone as NAME or NAME:YYYY-MM-DD (repeatable)
+    #[arg(long = "milestone", value_name = "NAME[:DATE]")]
+    pub milestones: Vec<MilestoneSpec>,
+    /// Post a status update with this markdown body
+    #[arg(long = "status-update", value_name = "BODY")]
+    pub status_update: Option<String>,
+    /// Output raw JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+impl Mutation for CreateArgs {
+    fn impact(&self) -> Impact {
+        Impact::Mutation
+    }
+
+    fn action(&self) -> String {
+        format!("create project \"{}\"", self.name)
+    }
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct UpdateArgs {
+    /// Project id
+    #[arg(long)]
+    pub id: String,
+    /// New name
+    #[arg(long)]
+    pub name: Option<String>,
+    /// New description
+    #[arg(long)]
+    pub description: Option<String>,
+    /// State
+    #[arg(long)]
+    pub state: Option<String>,
+    /// Start date (YYYY-MM-DD)
+    #[arg(long = "start-date")]
+    pub start_date: Option<String>,
+    /// Target date (YYYY-MM-DD)
+    #[arg(long = "target-date")]
+    pub target_date: Option<String>,
+    /// Replace teams (repeatable)
+    #[arg(long = "team-id")]
+    pub team_ids: Vec<String>,
+    /// Lead id
+    #[arg(long = "lead-id")]
+    pub lead_id: Option<String>,
+    /// Add a member, keeping current ones, by id, email, or name (repeatable)
+    #[arg(long = "member", value_name = "USER")]
+    pub members: Vec<String>,
+    /// Add a milestone as NAME or NAME:YYYY-MM-DD (repeatable)
+    #[arg(long = "milestone", value_name = "NAME[:DATE]")]
+    pub milestones: Vec<MilestoneSpec>,
+    /// Post a status update with this markdown body
+    #[arg(long = "status-update", value_name = "BODY")]
+    pub status_update: Option<String>,
+    /// Output raw JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+impl Mutation for UpdateArgs {
+    fn impact(&self) -> Impact {
+        Impact::Mutation
+    }
+
+    fn action(&self) -> String {
+        format!("update project {}", self.id)
+    }
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct ArchiveArgs {
+    /// Project id
+    #[arg(long)]
+    pub id: String,
+    /// Restore instead of archiving
+    #[arg(long)]
+    pub restore: bool,
+    /// Output raw JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+impl Mutation for ArchiveArgs {
+    fn impact(&self) -> Impact {
+        if self.restore {
+            Impact::Mutation
+        } else {
+            Impact::Destructive
+        }
+    }
+
+    fn action(&self) -> String {
+        let verb = if self.restore { "restore" } else { "archive" };
+        format!("{verb} project {}", self.id)
+    }
+}
+
+/// List projects, with a `--after` hint on stderr when more pages exist.
+pub async fn list(
+    client: &LinearGraphqlClient,
+    out: &mut impl Write,
+    args: ListArgs,
+) -> Result<()> {
+    let projects = ProjectService::new(client.clone())
+        .list(args.options()?)
+        .await
+        .context("GraphQL request failed")?;
+
+    if args.json {
+        return write_json(out, &projects);
+    }
+    let mut table = project_columns(Table::new());
+    for project in &projects.nodes {
+        table.row(project_cells(project));
+    }
+    table.write(out)?;
+    if projects.has_next_page {
+        if let Some(cursor) = &projects.end_cursor {
+            eprintln!(
+                "… more projects available. Use --after \"{}\" to continue.",
+                cursor
+            );
+        } else {
+            eprintln!("… more projects available.");
+        }
+    }
+    Ok(())
+}
+
+/// [`list`] across several profiles, each connected by `connect`, with a WORKSPACE column.
+pub async fn list_workspaces<F, Fut>(
+    profiles: Vec<String>,
+    connect: F,
+    out: &mut impl Write,
+    args: ListArgs,
+) -> Result<()>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<LinearGraphqlClient>> + Send + 'static,
+{
+    let options = args.options()?;
+    let results = fan_out(profiles, |profile| {
+        let client = connect(profile);
+        let options = options.clone();
+        async move {
+            ProjectService::new(client.await?)
+                .list(options)
+                .await
+                .context("GraphQL request failed")
+        }
+    })
+    .await?;
+    if args.json {
+        let projects = results
+            .iter()
+            .flat_map(|(profile, page)| page.nodes.iter().map(move |project| (profile, project)))
+            .map(|(profile, project)| with_workspace(project, profile))
+            .collect::<Result<Vec<_>>>()?;
+        return write_json(out, &projects);
+    }
+    let mut table = project_columns(Table::new().column("WORKSPACE", 10));
+    for (profile, page) in &results {
+        for project in &page.nodes {
+            let mut cells = vec![Cell::from(profile.as_str())];
+            cells.extend(project_cells(project));
+            table.row(cells);
+        }
+        if page.has_next_page {
+            eprintln!("… more projects available in {}", profile);
+        }
+    }
+    table.write(out)?;
+    Ok(())
+}
+
+/// Create a project with its members, milestones, and first status update.
+pub async fn create(
+    client: &LinearGraphqlClient,
+    out: &mut impl Write,
+    args: CreateArgs,
+) -> Result<()> {
+    let service = ProjectService::new(client.clone());
+    let mut input = ProjectCreateInput::new(args.name);
+    input.member_ids = resolve_members(&service, &args.members).await?;
+    input.description = args.description;
+    input.state = args.state;
+    input.start_date = args.start_date;
+    input.target_date = args.target_date;
+    input.lead_id = args.lead_id;
+    if !args.team_ids.is_empty() {
+        input.team_ids = args.team_ids;
+    }
+    let extras = ProjectExtras {
+        milestones: args.milestones,
+        status_update: args.status_update,
+    };
+
+    let setup = service
+        .create_with(input, &extras)
+        .await
+        .context("GraphQL request failed")?;
+    if args.json {
+        return write_json(out, &setup);
+    }
+    writeln!(out, "Created project {}", setup.project.name)?;
+    writeln!(out)?;
+    render_project_setup(out, &setup)
+}
+
+/// Update a project, adding members, milestones, and a status update.
+pub async fn update(
+    client: &LinearGraphqlClient,
+    out: &mut impl Write,
+    args: UpdateArgs,
+) -> Result<()> {
+    let service = ProjectService::new(client.clone());
+    let input = ProjectUpdateInput {
+        name: args.name,
+        description: args.description,
+        state: args.state,
+        start_date: args.start_date,
+        target_date: args.target_date,
+        team_ids: args.team_ids,
+        lead_id: args.lead_id,
+        member_ids: resolve_members(&service, &args.members).await?,
+    };
+    let extras = ProjectExtras {
+        milestones: args.milestones,
+        status_update: args.status_update,
+    };
+
+    let setup = service
+        .update_with(&args.id, input, &extras)
+        .await
+        .context("GraphQL request failed")?;
+    if args.json {
+        return write_json(out, &setup);
+    }
+    writeln!(out, "Updated project {}", setup.project.name)?;
+    writeln!(out)?;
+    render_project_setup(out, &setup)
+}
+
+/// Archive a project, or restore it with `--restore`.
+pub async fn archive(
+    client: &LinearGraphqlClient,
+    out: &mut impl Write,
+    args: ArchiveArgs,
+) -> Result<()> {
+    let project = ProjectService::new(client.clone())
+        .archive(&args.id, !args.restore)
+        .await
+        .context("GraphQL request failed")?;
+    if args.json {
+        write_json(out, &project)?;
+    } else if args.restore {
+        writeln!(out, "Restored project {}", project.name)?;
+    } else {
+        writeln!(out, "Archived project {}", project.name)?;
+    }
+    Ok(())
+}
+
+async fn resolve_members(service: &ProjectService, users: &[String]) -> Result<Vec<String>> {
+    let mut ids = Vec::new();
+    for user in users {
+        let id = service
+            .resolve_user_id(user)
+            .await
+            .context("GraphQL request failed")?
+            .ok_or_else(|| {
+                LinearError::not_found(format!(
+                    "no single user matches '{}'; use their email or id",
+                    user
+                ))
+            })?;
+        ids.push(id);
+    }
+    Ok(ids)
+}
+
+fn project_columns(table: Table) -> Table {
+    table
+        .flex_column("NAME", 12, 40)
+        .flex_column("STATE", 6, 10)
+        .flex_column("STATUS", 6, 10)
+        .column("START", 20)
+        .column("TARGET", 20)
+}
+
+fn project_cells(project: &ProjectSummary) -> [Cell; 5] {
+    [
+        Cell::link(project.name.clone(), project.url.as_deref()),
+        project.state.as_deref().unwrap_or("-").into(),
+        project.status.as_deref().unwrap_or("-").into(),
+        project.start_date.as_deref().unwrap_or("-").into(),
+        project.target_date.as_deref().unwrap_or("-").into(),
+    ]
+}
+
+fn render_project_detail(out: &mut impl Write, project: &ProjectDetail) -> Result<()> {
+    writeln!(out, "ID        : {}", project.id)?;
+    match &project.url {
+        Some(url) => writeln!(out, "Name      : {}", hyperlink::link(url, &project.name))?,
+        None => writeln!(out, "Name      : {}", project.name)?,
+    }
+    if let Some(url) = &project.url {
+        writeln!(out, "URL       : {}", hyperlink::link(url, url))?;
+    }
+    if let Some(desc) = &project.description {
+        if !desc.trim().is_empty() {
+            writeln!(out, "Description:\n{}", desc.trim())?;
+        }
+    }
+    if let Some(state) = &project.state {
+        writeln!(out, "State     : {}", state)?;
+    }
+    if let Some(status) = &project.status {
+        writeln!(out, "Status    : {}", status)?;
+    }
+    if let Some(start) = &project.start_date {
+        writeln!(out, "Start     : {}", start)?;
+    }
+    if let Some(target) = &project.target_date {
+        writeln!(out, "Target    : {}", target)?;
+    }
+    if let Some(lead) = &project.lead {
+        let name = lead
+            .display_name
+            .as_ref()
+            .or(lead.name.as_ref())
+            .cloned()
+            .unwrap_or_else(|| "-".into());
+        writeln!(out, "Lead      : {} ({})", name, lead.id)?;
+    }
+    if !project.teams.is_empty() {
+        let teams = project
+            .teams
+            .iter()
+            .map(|t| format!("{} ({})", t.name, t.key))
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(out, "Teams     : {}", teams)?;
+    }
+    Ok(())
+}
+
+fn render_project_setup(out: &mut impl Write, setup: &ProjectSetup) -> Result<()> {
+    render_project_detail(out, &setup.project)?;
+    for milestone in &setup.milestones {
+        match milestone.target_date {
+            Some(date) => writeln!(out, "Milestone : {} ({})", milestone.name, date)?,
+            None => writeln!(out, "Milestone : {}", milestone.name)?,
+        }
+    }
+    if let Some(update) = &setup.status_update {
+        match &update.url {
+            Some(url) => writeln!(out, "Update    : {}", hyperlink::link(url, url))?,
+            None => writeln!(out, "Update    : posted")?,
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,101 @@
+//! `--sort FIELD[:asc|:desc]` parsing for the list commands.
+
+use anyhow::Result;
+use linear_core::error::LinearError;
+use linear_core::services::cycles::CycleSort;
+use linear_core::services::issues::IssueSort;
+use linear_core::services::projects::ProjectSort;
+
+/// `--sort` for projects: updated, created, or target.
+pub fn project(sort: &str) -> Result<ProjectSort> {
+    let (field, dir) = sort_pair(sort)?;
+    match field.as_str() {
+        "updated" => Ok(match dir {
+            Direction::Asc => ProjectSort::UpdatedAsc,
+            Direction::Desc => ProjectSort::UpdatedDesc,
+        }),
+        "created" => Ok(match dir {
+            Direction::Asc => ProjectSort::CreatedAsc,
+            Direction::Desc => ProjectSort::CreatedDesc,
+        }),
+        "target" => Ok(match dir {
+            Direction::Asc => ProjectSort::TargetAsc,
+            Direction::Desc => ProjectSort::TargetDesc,
+        }),
+        other => {
+            Err(LinearError::validation(format!("unknown project sort field '{}'", other)).into())
+        }
+    }
+}
+
+/// `--sort` for issues, also read by the TUI's `:sort`.
+pub fn issue(sort: &str) -> Result<IssueSort> {
+    let (field, dir) = sort_pair(sort)?;
+    match field.as_str() {
+        "updated" => Ok(match dir {
+            Direction::Asc => IssueSort::UpdatedAsc,
+            Direction::Desc => IssueSort::UpdatedDesc,
+        }),
+        "created" => Ok(match dir {
+            Direction::Asc => IssueSort::CreatedAsc,
+            Direction::Desc => IssueSort::CreatedDesc,
+        }),
+        "priority" => Ok(match dir {
+            Direction::Asc => IssueSort::PriorityAsc,
+            Direction::Desc => IssueSort::PriorityDesc,
+        }),
+        other => Err(LinearError::validation(format!(
+            "unknown issue sort field '{}'; expected updated, created, or priority",
+            other
+        ))
+        .into()),
+    }
+}
+
+/// `--sort` for cycles: start or end.
+pub fn cycle(sort: &str) -> Result<CycleSort> {
+    let (field, dir) = sort_pair(sort)?;
+    match field.as_str() {
+        "start" => Ok(match dir {
+            Direction::Asc => CycleSort::StartAsc,
+            Direction::Desc => CycleSort::StartDesc,
+        }),
+        "end" => Ok(match dir {
+            Direction::Asc => CycleSort::EndAsc,
+            Direction::Desc => CycleSort::EndDesc,
+        }),
+        other => {
+            Err(LinearError::validation(format!("unknown cycle sort field '{}'", other)).into())
+        }
+    }
+}
+
+fn sort_pair(input: &str) -> Result<(String, Direction)> {
+    let mut parts = input.split(':');
+    let field = parts
+        .next()
+        .map(|s| s.trim().to_ascii_lowercase())
+        .filter(|s| !s.is_empty())
+        .ok_or_else(|| LinearError::validation("sort option must include a field"))?;
+    let direction = parts
+        .next()
+        .map(|s| s.trim().to_ascii_lowercase())
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| "desc".into());
+    let direction = match direction.as_str() {
+        "asc" => Direction::Asc,
+        "desc" => Direction::Desc,
+        other => {
+            return Err(
+                LinearError::validation(format!("invalid sort direction '{}'", other)).into(),
+            )
+        }
+    };
+    Ok((field, direction))
+}
+
+#[derive(Clone, Copy)]
+enum Direction {
+    Asc,
+    Desc,
+}
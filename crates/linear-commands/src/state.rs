@@ -0,0 +1,63 @@
+//! `linear state list`.
+
+use std::io::Write;
+
+use anyhow::Result;
+use clap::Args;
+use linear_core::error::LinearError;
+use linear_core::graphql::{LinearGraphqlClient, WorkflowStateSummary};
+use linear_core::services::issues::IssueService;
+use serde_json::json;
+
+use crate::fields::write_json;
+use crate::table::Table;
+
+#[derive(Args, Debug, Clone)]
+pub struct ListArgs {
+    /// Team identifier (key, name, or id)
+    #[arg(long = "team")]
+    pub team: String,
+    /// Output raw JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// List a team's workflow states.
+pub async fn list(
+    client: &LinearGraphqlClient,
+    out: &mut impl Write,
+    args: ListArgs,
+) -> Result<()> {
+    let (team, states) = IssueService::new(client.clone())
+        .workflow_states_for_team(&args.team)
+        .await?
+        .ok_or_else(|| LinearError::not_found(format!("team '{}' not found", args.team)))?;
+
+    if args.json {
+        return write_json(
+            out,
+            &json!({
+                "team": team,
+                "states": states,
+            }),
+        );
+    }
+    writeln!(out, "Team: {} ({})", team.name, team.key)?;
+    render_state_list(out, &states)
+}
+
+fn render_state_list(out: &mut impl Write, states: &[WorkflowStateSummary]) -> Result<()> {
+    let mut table = Table::new()
+        .flex_column("NAME", 12, 25)
+        .column("TYPE", 15)
+        .column("ID", 36);
+    for state in states {
+        table.row([
+            state.name.as_str(),
+            state.type_name.as_deref().unwrap_or("-"),
+            state.id.as_str(),
+        ]);
+    }
+    table.write(out)?;
+    Ok(())
+}
@@ -0,0 +1,336 @@
+use std::env;
+use std::io::{self, IsTerminal, Write};
+use std::sync::OnceLock;
+
+use crossterm::style::{Color, Stylize};
+use crossterm::terminal;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::hyperlink;
+
+const ELLIPSIS: char = '…';
+const GAP: usize = 1;
+
+/// Width-aware plain-text table shared by the list renderers.
+///
+/// Columns size to their widest cell (capped at `max`). When stdout is a terminal narrower than
+/// the natural width, flexible columns give up space, down to their `min`, until the table fits.
+/// Widths are measured in terminal cells, so CJK text and emoji stay aligned.
+#[derive(Default)]
+pub struct Table {
+    columns: Vec<Column>,
+    rows: Vec<Vec<Cell>>,
+    /// Section headings and the row index they precede; they do not affect column widths.
+    sections: Vec<(usize, String)>,
+}
+
+/// Table cell text, optionally colored or rendered as a hyperlink to `url`.
+pub struct Cell {
+    text: String,
+    url: Option<String>,
+    color: Option<Color>,
+    background: Option<Color>,
+}
+
+impl Cell {
+    pub fn link(text: impl Into<String>, url: Option<&str>) -> Self {
+        Self {
+            url: url.map(str::to_owned),
+            ..Self::from(text.into())
+        }
+    }
+
+    pub fn colored(text: impl Into<String>, color: Color) -> Self {
+        Self {
+            color: Some(color),
+            ..Self::from(text.into())
+        }
+    }
+
+    /// `hex` shown on its own color with a readable foreground, e.g. a label color.
+    pub fn chip(hex: &str) -> Self {
+        match crate::color::crossterm_chip(hex) {
+            Some((background, foreground)) => Self {
+                color: Some(foreground),
+                background: Some(background),
+                ..Self::from(hex)
+            },
+            None => Self::from(hex),
+        }
+    }
+}
+
+impl From<String> for Cell {
+    /// Control characters (tabs, newlines) would break alignment, so they become spaces.
+    fn from(text: String) -> Self {
+        let text = if text.contains(char::is_control) {
+            text.replace(|c: char| c.is_control(), " ")
+        } else {
+            text
+        };
+        Self {
+            text,
+            url: None,
+            color: None,
+            background: None,
+        }
+    }
+}
+
+impl From<&str> for Cell {
+    fn from(text: &str) -> Self {
+        text.to_owned().into()
+    }
+}
+
+struct Column {
+    header: &'static str,
+    min: usize,
+    max: usize,
+    flex: bool,
+}
+
+impl Table {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fixed column, never narrower than its content up to `max` cells.
+    pub fn column(mut self, header: &'static str, max: usize) -> Self {
+        self.columns.push(Column {
+            header,
+            min: max,
+            max,
+            flex: false,
+        });
+        self
+    }
+
+    /// Column that shrinks (down to `min` cells) to fit the terminal.
+    pub fn flex_column(mut self, header: &'static str, min: usize, max: usize) -> Self {
+        self.columns.push(Column {
+            header,
+            min: min.min(max),
+            max,
+            flex: true,
+        });
+        self
+    }
+
+    pub fn row<I, S>(&mut self, cells: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Cell>,
+    {
+        self.rows.push(cells.into_iter().map(Into::into).collect());
+    }
+
+    /// Start a titled group; rows added afterwards belong to it.
+    pub fn section(&mut self, title: impl Into<String>) {
+        self.sections.push((self.rows.len(), title.into()));
+    }
+
+    pub fn print(&self) {
+        print!("{}", self.render(terminal_width()));
+    }
+
+    /// Write the table to `out`, sized to stdout like [`Table::print`].
+    pub fn write(&self, out: &mut impl Write) -> io::Result<()> {
+        out.write_all(self.render(terminal_width()).as_bytes())
+    }
+
+    fn render(&self, available: Option<usize>) -> String {
+        let widths = self.widths(available);
+        let total = widths.iter().sum::<usize>() + GAP * widths.len().saturating_sub(1);
+        let headers: Vec<Cell> = self
+            .columns
+            .iter()
+            .map(|column| Cell::from(column.header))
+            .collect();
+
+        let mut out = String::new();
+        push_line(&mut out, &headers, &widths);
+        out.push_str(&"-".repeat(total));
+        out.push('\n');
+        let mut sections = self.sections.iter().peekable();
+        for (index, row) in self.rows.iter().enumerate() {
+            while let Some((_, title)) = sections.next_if(|(at, _)| *at == index) {
+                if index > 0 {
+                    out.push('\n');
+                }
+                let title = truncate(title, total.max(1));
+                if color_enabled() {
+                    out.push_str(&title.bold().to_string());
+                } else {
+                    out.push_str(&title);
+                }
+                out.push('\n');
+            }
+            push_line(&mut out, row, &widths);
+        }
+        out
+    }
+
+    fn widths(&self, available: Option<usize>) -> Vec<usize> {
+        let mut widths: Vec<usize> = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(index, column)| {
+                let content = self
+                    .rows
+                    .iter()
+                    .filter_map(|row| row.get(index))
+                    .map(|cell| cell.text.width())
+                    .chain(std::iter::once(column.header.width()))
+                    .max()
+                    .unwrap_or(0);
+                content.min(column.max)
+            })
+            .collect();
+
+        let Some(available) = available else {
+            return widths;
+        };
+        let gaps = GAP * widths.len().saturating_sub(1);
+        let mut overflow = (widths.iter().sum::<usize>() + gaps).saturating_sub(available);
+        // Take space from the widest flexible column first so no single column collapses early.
+        while overflow > 0 {
+            let candidate = self
+                .columns
+                .iter()
+                .enumerate()
+                .filter(|(index, column)| column.flex && widths[*index] > column.min)
+                .max_by_key(|(index, _)| widths[*index])
+                .map(|(index, _)| index);
+            let Some(index) = candidate else {
+                break;
+            };
+            widths[index] -= 1;
+            overflow -= 1;
+        }
+        widths
+    }
+}
+
+fn push_line(out: &mut String, cells: &[Cell], widths: &[usize]) {
+    let last = widths.len().saturating_sub(1);
+    for (index, width) in widths.iter().enumerate() {
+        let (text, url, color, background) = match cells.get(index) {
+            Some(cell) => (
+                truncate(&cell.text, *width),
+                cell.url.as_deref(),
+                cell.color,
+                cell.background,
+            ),
+            None => (String::new(), None, None, None),
+        };
+        let fill = if index == last {
+            0
+        } else {
+            (*width + GAP).saturating_sub(text.width())
+        };
+        let styled = match (color, background) {
+            (Some(color), Some(background)) if color_enabled() => {
+                text.clone().with(color).on(background).to_string()
+            }
+            (Some(color), None) if color_enabled() => text.clone().with(color).to_string(),
+            _ => text.clone(),
+        };
+        match url {
+            Some(url) => out.push_str(&hyperlink::link(url, &styled)),
+            None => out.push_str(&styled),
+        }
+        out.push_str(&" ".repeat(fill));
+    }
+    out.push('\n');
+}
+
+/// Colors are only used on a terminal and honour `NO_COLOR`.
+pub fn color_enabled() -> bool {
+    static ENABLED: OnceLock<bool> = OnceLock::new();
+    *ENABLED.get_or_init(|| io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none())
+}
+
+/// Width of stdout in cells, or `None` when output is piped.
+fn terminal_width() -> Option<usize> {
+    if !io::stdout().is_terminal() {
+        return None;
+    }
+    terminal::size().ok().map(|(cols, _)| cols as usize)
+}
+
+/// Truncate `value` to at most `max_width` terminal cells, ending with `…` when shortened.
+pub fn truncate(value: &str, max_width: usize) -> String {
+    if value.width() <= max_width {
+        return value.to_owned();
+    }
+    let budget = max_width.saturating_sub(1);
+    let mut used = 0;
+    let mut collected = String::new();
+    for ch in value.chars() {
+        let width = ch.width().unwrap_or(0);
+        if used + width > budget {
+            break;
+        }
+        used += width;
+        collected.push(ch);
+    }
+    if max_width > 0 {
+        collected.push(ELLIPSIS);
+    }
+    collected
+}
+
+/// Left-align `value` in `width` terminal cells.
+pub fn pad(value: &str, width: usize) -> String {
+    let fill = width.saturating_sub(value.width());
+    format!("{}{}", value, " ".repeat(fill))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn truncates_by_terminal_cells() {
+        assert_eq!(truncate("plain", 5), "plain");
+        assert_eq!(truncate("plain text", 6), "plain…");
+        assert_eq!(truncate("日本語テキスト", 7), "日本語…");
+        // A wide character that would straddle the limit is dropped whole.
+        assert_eq!(truncate("日本語", 4), "日…");
+        assert_eq!(truncate("日本語", 4).width(), 3);
+        assert_eq!(truncate("anything", 1), "…");
+        assert_eq!(truncate("anything", 0), "");
+    }
+
+    #[test]
+    fn aligns_wide_characters() {
+        let mut table = Table::new().column("KEY", 8).column("TITLE", 20);
+        table.row(["日本-1", "first"]);
+        table.row(["ENG-22", "second"]);
+        let rendered = table.render(None);
+        let lines: Vec<&str> = rendered.lines().collect();
+        let offset = |line: &str, title: &str| line[..line.find(title).unwrap()].width();
+        assert_eq!(offset(lines[0], "TITLE"), 7);
+        assert_eq!(offset(lines[2], "first"), 7);
+        assert_eq!(offset(lines[3], "second"), 7);
+        assert_eq!(pad("日本", 6), "日本  ");
+    }
+
+    #[test]
+    fn flex_columns_shrink_widest_first_down_to_their_minimum() {
+        let mut table = Table::new()
+            .column("KEY", 6)
+            .flex_column("TITLE", 8, 40)
+            .flex_column("PROJECT", 4, 20);
+        table.row(["ENG-1", "a title that is thirty cells..", "Roadmap Q3"]);
+        assert_eq!(table.widths(None), [5, 30, 10]);
+        assert_eq!(table.widths(Some(40)), [5, 23, 10]);
+        assert_eq!(table.widths(Some(20)), [5, 8, 5]);
+        // Narrower than every minimum: columns stop at `min` and the line overflows.
+        assert_eq!(table.widths(Some(5)), [5, 8, 4]);
+        let rendered = table.render(Some(5));
+        assert_eq!(rendered.lines().last(), Some("ENG-1 a title… Roa…"));
+    }
+}
@@ -0,0 +1,96 @@
+//! `linear team list`.
+
+use std::future::Future;
+use std::io::Write;
+
+use anyhow::{Context, Result};
+use clap::Args;
+use linear_core::graphql::{LinearGraphqlClient, TeamSummary};
+use linear_core::services::issues::IssueService;
+
+use crate::fields::write_json;
+use crate::table::Table;
+use crate::workspaces::{fan_out, with_workspace};
+
+#[derive(Args, Debug, Clone)]
+pub struct ListArgs {
+    /// Output raw JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// List the teams the client can see.
+pub async fn list(
+    client: &LinearGraphqlClient,
+    out: &mut impl Write,
+    args: ListArgs,
+) -> Result<()> {
+    let teams = IssueService::new(client.clone())
+        .teams()
+        .await
+        .context("GraphQL request failed")?;
+    if args.json {
+        return write_json(out, &teams);
+    }
+    render_team_list(out, &teams)
+}
+
+/// [`list`] across several profiles, each connected by `connect`, with a WORKSPACE column.
+pub async fn list_workspaces<F, Fut>(
+    profiles: Vec<String>,
+    connect: F,
+    out: &mut impl Write,
+    args: ListArgs,
+) -> Result<()>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<LinearGraphqlClient>> + Send + 'static,
+{
+    let results = fan_out(profiles, |profile| {
+        let client = connect(profile);
+        async move {
+            IssueService::new(client.await?)
+                .teams()
+                .await
+                .context("GraphQL request failed")
+        }
+    })
+    .await?;
+    if args.json {
+        let teams = results
+            .iter()
+            .flat_map(|(profile, teams)| teams.iter().map(move |team| (profile, team)))
+            .map(|(profile, team)| with_workspace(team, profile))
+            .collect::<Result<Vec<_>>>()?;
+        return write_json(out, &teams);
+    }
+    let mut table = Table::new()
+        .column("WORKSPACE", 10)
+        .column("KEY", 8)
+        .flex_column("NAME", 12, 32)
+        .column("ID", 36);
+    for (profile, teams) in &results {
+        for team in teams {
+            table.row([
+                profile.as_str(),
+                team.key.as_str(),
+                team.name.as_str(),
+                team.id.as_str(),
+            ]);
+        }
+    }
+    table.write(out)?;
+    Ok(())
+}
+
+fn render_team_list(out: &mut impl Write, teams: &[TeamSummary]) -> Result<()> {
+    let mut table = Table::new()
+        .column("KEY", 8)
+        .flex_column("NAME", 12, 32)
+        .column("ID", 36);
+    for team in teams {
+        table.row([team.key.as_str(), team.name.as_str(), team.id.as_str()]);
+    }
+    table.write(out)?;
+    Ok(())
+}
@@ -0,0 +1,41 @@
+//! `linear user me`.
+
+use std::io::Write;
+
+use anyhow::{Context, Result};
+use clap::Args;
+use linear_core::graphql::{LinearGraphqlClient, Viewer};
+
+use crate::fields::write_json;
+
+#[derive(Args, Debug, Clone)]
+pub struct MeArgs {
+    /// Output raw JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// Show the user the client is authenticated as.
+pub async fn me(client: &LinearGraphqlClient, out: &mut impl Write, args: MeArgs) -> Result<()> {
+    let viewer = client.viewer().await.context("GraphQL request failed")?;
+    if args.json {
+        write_json(out, &viewer)
+    } else {
+        render_viewer(out, &viewer)
+    }
+}
+
+fn render_viewer(out: &mut impl Write, viewer: &Viewer) -> Result<()> {
+    writeln!(out, "Viewer ID: {}", viewer.id)?;
+    if let Some(name) = &viewer.name {
+        writeln!(out, "Name      : {}", name)?;
+    }
+    if let Some(display) = &viewer.display_name {
+        writeln!(out, "Display   : {}", display)?;
+    }
+    if let Some(email) = &viewer.email {
+        writeln!(out, "Email     : {}", email)?;
+    }
+    writeln!(out, "Created   : {}", viewer.created_at.to_rfc3339())?;
+    Ok(())
+}
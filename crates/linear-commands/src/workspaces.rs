@@ -0,0 +1,55 @@
+//! Running a read command against several profiles' workspaces at once.
+
+use std::future::Future;
+
+use anyhow::Result;
+use serde::Serialize;
+use serde_json::Value;
+use tokio::task::JoinSet;
+
+/// Run `op` once per profile, all concurrently, keeping results in profile order.
+///
+/// Profiles that fail are reported on stderr and left out; the command only fails (with the
+/// first failure) when every profile did.
+pub async fn fan_out<T, F, Fut>(profiles: Vec<String>, op: F) -> Result<Vec<(String, T)>>
+where
+    T: Send + 'static,
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<T>> + Send + 'static,
+{
+    let mut tasks = JoinSet::new();
+    for (index, profile) in profiles.iter().cloned().enumerate() {
+        let future = op(profile);
+        tasks.spawn(async move { (index, future.await) });
+    }
+    let mut outcomes: Vec<Option<Result<T>>> = profiles.iter().map(|_| None).collect();
+    while let Some(joined) = tasks.join_next().await {
+        let (index, outcome) = joined?;
+        outcomes[index] = Some(outcome);
+    }
+
+    let mut results = Vec::new();
+    let mut first_failure = None;
+    for (profile, outcome) in profiles.into_iter().zip(outcomes) {
+        match outcome.unwrap_or_else(|| Err(anyhow::anyhow!("task did not complete"))) {
+            Ok(value) => results.push((profile, value)),
+            Err(err) => {
+                eprintln!("warning: profile '{}' failed: {:#}", profile, err);
+                first_failure.get_or_insert(err);
+            }
+        }
+    }
+    match first_failure {
+        Some(err) if results.is_empty() => Err(err),
+        _ => Ok(results),
+    }
+}
+
+/// `value` as JSON with a `workspace` field naming the profile it came from.
+pub fn with_workspace(value: &impl Serialize, profile: &str) -> Result<Value> {
+    let mut value = serde_json::to_value(value)?;
+    if let Some(object) = value.as_object_mut() {
+        object.insert("workspace".into(), profile.into());
+    }
+    Ok(value)
+}
@@ -0,0 +1,155 @@
+//! Runs commands against a mock GraphQL server and checks what they write.
+
+use httpmock::prelude::*;
+use linear_commands::{label, project, state, team, Mutation};
+use linear_core::auth::AuthSession;
+use linear_core::config::Impact;
+use linear_core::graphql::LinearGraphqlClient;
+use serde_json::{json, Value};
+
+fn client(server: &MockServer) -> LinearGraphqlClient {
+    LinearGraphqlClient::with_endpoint(
+        &AuthSession::new_api_key("test-key".into()),
+        &server.url("/graphql"),
+    )
+    .unwrap()
+}
+
+fn teams(server: &MockServer) {
+    server.mock(|when, then| {
+        when.method(POST).body_contains("query TeamsQuery");
+        then.status(200)
+            .json_body_obj(&json!({ "data": { "teams": { "nodes": [
+            { "id": "team-1", "name": "Engineering", "key": "ENG" }
+        ] } } }));
+    });
+}
+
+#[tokio::test]
+async fn team_list_writes_a_table() {
+    let server = MockServer::start();
+    teams(&server);
+
+    let mut out = Vec::new();
+    team::list(&client(&server), &mut out, team::ListArgs { json: false })
+        .await
+        .unwrap();
+
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "KEY NAME        ID\n----------------------\nENG Engineering team-1\n"
+    );
+}
+
+#[tokio::test]
+async fn state_list_names_a_missing_team() {
+    let server = MockServer::start();
+    teams(&server);
+
+    let mut out = Vec::new();
+    let args = state::ListArgs {
+        team: "OPS".into(),
+        json: false,
+    };
+    let err = state::list(&client(&server), &mut out, args)
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.to_string(), "team 'OPS' not found");
+    assert!(out.is_empty());
+}
+
+#[tokio::test]
+async fn project_list_writes_json() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(POST)
+            .body_contains("query ListProjects")
+            .body_contains(r#""first":5"#);
+        then.status(200)
+            .json_body_obj(&json!({ "data": { "projects": {
+            "edges": [{ "cursor": "c1", "node": {
+                "id": "project-1",
+                "name": "Launch",
+                "state": "started",
+                "updatedAt": "2024-01-01T00:00:00Z",
+                "createdAt": "2024-01-01T00:00:00Z"
+            } }],
+            "pageInfo": { "hasNextPage": false, "endCursor": "c1" }
+        } } }));
+    });
+
+    let mut out = Vec::new();
+    let args = project::ListArgs {
+        limit: 5,
+        after: None,
+        state: None,
+        status: None,
+        team_id: None,
+        sort: "updated:desc".into(),
+        json: true,
+    };
+    project::list(&client(&server), &mut out, args)
+        .await
+        .unwrap();
+
+    let printed: Value = serde_json::from_slice(&out).unwrap();
+    assert_eq!(printed["nodes"][0]["name"], "Launch");
+    assert_eq!(printed["has_next_page"], false);
+}
+
+#[tokio::test]
+async fn label_create_drops_a_blank_color() {
+    let server = MockServer::start();
+    let create = server.mock(|when, then| {
+        when.method(POST)
+            .body_contains("mutation IssueLabelCreate")
+            .body_contains(r#""name":"Bug""#)
+            .matches(|request| {
+                !String::from_utf8_lossy(request.body.as_deref().unwrap_or_default())
+                    .contains(r#""color""#)
+            });
+        then.status(200)
+            .json_body_obj(&json!({ "data": { "issueLabelCreate": {
+            "success": true,
+            "issueLabel": { "id": "label-1", "name": "Bug", "color": "#ff0000" }
+        } } }));
+    });
+
+    let args = label::CreateArgs {
+        team_id: "team-1".into(),
+        name: "Bug".into(),
+        description: None,
+        color: Some("  ".into()),
+        json: false,
+    };
+    assert_eq!(args.impact(), Impact::Mutation);
+    assert_eq!(args.action(), "create label \"Bug\"");
+    let mut out = Vec::new();
+    label::create(&client(&server), &mut out, args)
+        .await
+        .unwrap();
+
+    create.assert();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "Created label Bug (label-1)\n"
+    );
+}
+
+#[test]
+fn archiving_is_destructive_and_restoring_is_not() {
+    let archive = project::ArchiveArgs {
+        id: "project-1".into(),
+        restore: false,
+        json: false,
+    };
+    assert_eq!(archive.impact(), Impact::Destructive);
+    assert_eq!(archive.action(), "archive project project-1");
+    let restore = project::ArchiveArgs {
+        restore: true,
+        ..archive
+    };
+    assert_eq!(restore.impact(), Impact::Mutation);
+    assert_eq!(restore.action(), "restore project project-1");
+}
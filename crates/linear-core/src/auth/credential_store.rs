@@ -1,18 +1,42 @@
+use std::collections::HashMap;
 use std::fs::{self, OpenOptions};
 use std::io::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::Mutex;
 
 use serde::{Deserialize, Serialize};
 
-use crate::config::ConfigLocator;
+use crate::config::{Config, ConfigLocator};
+use crate::shell::shell_command;
 
-use super::{AuthError, AuthSession};
+use super::{Actor, AuthError, AuthSession};
 
 /// Persistence abstraction for authentication credentials.
 pub trait CredentialStore {
     fn load(&self, profile: &str) -> Result<Option<AuthSession>, AuthError>;
     fn save(&self, profile: &str, session: &AuthSession) -> Result<(), AuthError>;
     fn delete(&self, profile: &str) -> Result<(), AuthError>;
+    /// Names of the profiles with stored user credentials, sorted.
+    fn profiles(&self) -> Result<Vec<String>, AuthError>;
+}
+
+impl<S: CredentialStore + ?Sized> CredentialStore for Box<S> {
+    fn load(&self, profile: &str) -> Result<Option<AuthSession>, AuthError> {
+        (**self).load(profile)
+    }
+
+    fn save(&self, profile: &str, session: &AuthSession) -> Result<(), AuthError> {
+        (**self).save(profile, session)
+    }
+
+    fn delete(&self, profile: &str) -> Result<(), AuthError> {
+        (**self).delete(profile)
+    }
+
+    fn profiles(&self) -> Result<Vec<String>, AuthError> {
+        (**self).profiles()
+    }
 }
 
 /// Factory trait allowing higher-level components to obtain a credential store.
@@ -20,20 +44,55 @@ pub trait CredentialStoreFactory: Send + Sync {
     fn open(&self) -> Result<Box<dyn CredentialStore + Send + Sync>, AuthError>;
 }
 
+/// The store `config.toml` selects for `actor`: its `credential_helper` when one is set,
+/// otherwise the credentials files in the config directory.
+pub fn configured_store(
+    locator: ConfigLocator,
+    actor: Actor,
+) -> Result<Box<dyn CredentialStore + Send + Sync>, AuthError> {
+    let config = Config::load(&locator)?;
+    Ok(match config.credential_helper {
+        Some(command) if !command.trim().is_empty() => {
+            Box::new(HelperCredentialStore::new(command).with_actor(actor))
+        }
+        _ => Box::new(FileCredentialStore::new(locator).with_actor(actor)),
+    })
+}
+
 /// Filesystem-backed credential storage located in the user configuration directory.
+///
+/// Each profile has one slot per [`Actor`]: the user's credentials and, separately, the
+/// application's, so automation can opt into app attribution per command.
 pub struct FileCredentialStore {
     locator: ConfigLocator,
+    actor: Actor,
 }
 
 impl FileCredentialStore {
     pub fn new(locator: ConfigLocator) -> Self {
-        Self { locator }
+        Self {
+            locator,
+            actor: Actor::User,
+        }
     }
 
     pub fn with_default_locator() -> Result<Self, AuthError> {
         Ok(Self::new(ConfigLocator::new()?))
     }
 
+    /// Use the credential slot of `actor` instead of the user's.
+    pub fn with_actor(mut self, actor: Actor) -> Self {
+        self.actor = actor;
+        self
+    }
+
+    fn path(&self, profile: &str) -> PathBuf {
+        match self.actor {
+            Actor::User => self.locator.credentials_file(profile),
+            Actor::Application => self.locator.app_credentials_file(profile),
+        }
+    }
+
     fn write_file(path: &Path, payload: &str) -> Result<(), AuthError> {
         if let Some(parent) = path.parent() {
             fs::create_dir_all(parent)?;
@@ -59,7 +118,7 @@ impl FileCredentialStore {
 
 impl CredentialStore for FileCredentialStore {
     fn load(&self, profile: &str) -> Result<Option<AuthSession>, AuthError> {
-        let path = self.locator.credentials_file(profile);
+        let path = self.path(profile);
         if !path.exists() {
             return Ok(None);
         }
@@ -69,7 +128,7 @@ impl CredentialStore for FileCredentialStore {
     }
 
     fn save(&self, profile: &str, session: &AuthSession) -> Result<(), AuthError> {
-        let path = self.locator.credentials_file(profile);
+        let path = self.path(profile);
         let envelope = SessionEnvelope {
             profile: profile.to_owned(),
             session: session.clone(),
@@ -80,13 +139,155 @@ impl CredentialStore for FileCredentialStore {
     }
 
     fn delete(&self, profile: &str) -> Result<(), AuthError> {
-        let path = self.locator.credentials_file(profile);
+        let path = self.path(profile);
         match fs::remove_file(path) {
             Ok(_) => Ok(()),
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
             Err(err) => Err(err.into()),
         }
     }
+
+    fn profiles(&self) -> Result<Vec<String>, AuthError> {
+        Ok(self.locator.profiles()?)
+    }
+}
+
+/// Credential storage delegated to an external program, in the style of git and docker
+/// credential helpers, so sessions can live in a keychain or secret manager.
+///
+/// The helper runs through the platform shell as `<command> get`, `<command> store`,
+/// `<command> erase`, or `<command> list`, with `LINEAR_CREDENTIAL_PROFILE` and
+/// `LINEAR_CREDENTIAL_ACTOR` (`user` or `application`) set. `store` receives the session as
+/// JSON on stdin; `get` prints it, or nothing when none is stored; `list` prints the names of
+/// the profiles holding user credentials, one per line. A non-zero exit is an error carrying the
+/// helper's stderr.
+pub struct HelperCredentialStore {
+    command: String,
+    actor: Actor,
+}
+
+impl HelperCredentialStore {
+    pub fn new(command: impl Into<String>) -> Self {
+        Self {
+            command: command.into(),
+            actor: Actor::User,
+        }
+    }
+
+    /// Ask the helper for the credential slot of `actor` instead of the user's.
+    pub fn with_actor(mut self, actor: Actor) -> Self {
+        self.actor = actor;
+        self
+    }
+
+    /// Run `operation`, feeding it `input`, and return its stdout.
+    fn run(&self, operation: &str, profile: &str, input: &str) -> Result<String, AuthError> {
+        let mut command = shell_command(&format!("{} {}", self.command, operation));
+        let actor = match self.actor {
+            Actor::User => "user",
+            Actor::Application => "application",
+        };
+        let mut child = command
+            .env("LINEAR_CREDENTIAL_PROFILE", profile)
+            .env("LINEAR_CREDENTIAL_ACTOR", actor)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|err| self.error(format!("could not start it: {err}")))?;
+        if let Some(mut stdin) = child.stdin.take() {
+            // A helper that exits without reading its input is judged by its exit status.
+            let _ = stdin.write_all(input.as_bytes());
+        }
+        let output = child.wait_with_output()?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let message = match stderr.trim() {
+                "" => format!("`{operation}` exited with {}", output.status),
+                stderr => format!("`{operation}` exited with {}: {stderr}", output.status),
+            };
+            return Err(self.error(message));
+        }
+        String::from_utf8(output.stdout)
+            .map_err(|_| self.error(format!("`{operation}` printed invalid UTF-8")))
+    }
+
+    fn error(&self, message: String) -> AuthError {
+        AuthError::Helper {
+            command: self.command.clone(),
+            message,
+        }
+    }
+}
+
+impl CredentialStore for HelperCredentialStore {
+    fn load(&self, profile: &str) -> Result<Option<AuthSession>, AuthError> {
+        let output = self.run("get", profile, "")?;
+        if output.trim().is_empty() {
+            return Ok(None);
+        }
+        let session = serde_json::from_str(&output)
+            .map_err(|err| self.error(format!("`get` printed an invalid session: {err}")))?;
+        Ok(Some(session))
+    }
+
+    fn save(&self, profile: &str, session: &AuthSession) -> Result<(), AuthError> {
+        self.run("store", profile, &serde_json::to_string(session)?)
+            .map(drop)
+    }
+
+    fn delete(&self, profile: &str) -> Result<(), AuthError> {
+        self.run("erase", profile, "").map(drop)
+    }
+
+    fn profiles(&self) -> Result<Vec<String>, AuthError> {
+        let output = Self::new(self.command.clone()).run("list", "", "")?;
+        let mut profiles: Vec<String> = output
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::to_owned)
+            .collect();
+        profiles.sort();
+        profiles.dedup();
+        Ok(profiles)
+    }
+}
+
+/// Credential storage that keeps sessions in memory, for logins that must leave nothing on disk.
+#[derive(Default)]
+pub struct MemoryCredentialStore {
+    sessions: Mutex<HashMap<String, AuthSession>>,
+}
+
+impl CredentialStore for MemoryCredentialStore {
+    fn load(&self, profile: &str) -> Result<Option<AuthSession>, AuthError> {
+        Ok(self.sessions().get(profile).cloned())
+    }
+
+    fn save(&self, profile: &str, session: &AuthSession) -> Result<(), AuthError> {
+        self.sessions().insert(profile.to_owned(), session.clone());
+        Ok(())
+    }
+
+    fn delete(&self, profile: &str) -> Result<(), AuthError> {
+        self.sessions().remove(profile);
+        Ok(())
+    }
+
+    fn profiles(&self) -> Result<Vec<String>, AuthError> {
+        let mut profiles: Vec<String> = self.sessions().keys().cloned().collect();
+        profiles.sort();
+        Ok(profiles)
+    }
+}
+
+impl MemoryCredentialStore {
+    fn sessions(&self) -> std::sync::MutexGuard<'_, HashMap<String, AuthSession>> {
+        self.sessions
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -123,6 +324,76 @@ mod tests {
         assert_eq!(loaded.refresh_token, session.refresh_token);
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn helper_stores_gets_and_erases_sessions() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path().display();
+        let script = temp_dir.path().join("helper.sh");
+        fs::write(
+            &script,
+            "slot=\"$STORE/$LINEAR_CREDENTIAL_PROFILE-$LINEAR_CREDENTIAL_ACTOR\"\n\
+             case \"$1\" in\n\
+               get) cat \"$slot\" 2>/dev/null || true ;;\n\
+               store) cat > \"$slot\" ;;\n\
+               erase) rm -f \"$slot\" ;;\n\
+               list) for f in \"$STORE\"/*-user; do [ -e \"$f\" ] && basename \"$f\" -user; done ;;\n\
+               *) echo \"unknown operation $1\" >&2; exit 1 ;;\n\
+             esac\n",
+        )
+        .unwrap();
+        let command = format!("STORE={dir} sh {}", script.display());
+        let user = HelperCredentialStore::new(command.clone());
+        let app = HelperCredentialStore::new(command.clone()).with_actor(Actor::Application);
+
+        assert!(user.load("default").unwrap().is_none());
+        user.save("default", &sample_session()).unwrap();
+        assert!(temp_dir.path().join("default-user").exists());
+        assert_eq!(user.load("default").unwrap().unwrap().access_token, "token");
+        assert!(app.load("default").unwrap().is_none());
+        user.save("work", &sample_session()).unwrap();
+        app.save("bots", &sample_session()).unwrap();
+        assert_eq!(user.profiles().unwrap(), vec!["default", "work"]);
+        assert_eq!(app.profiles().unwrap(), vec!["default", "work"]);
+        user.delete("default").unwrap();
+        assert!(user.load("default").unwrap().is_none());
+        assert_eq!(user.profiles().unwrap(), vec!["work"]);
+
+        let failing = HelperCredentialStore::new(format!("{command} bogus"));
+        let err = failing.load("default").unwrap_err().to_string();
+        assert!(err.contains("unknown operation"), "{err}");
+    }
+
+    #[test]
+    fn application_credentials_use_their_own_slot() {
+        let temp_dir = TempDir::new().unwrap();
+        let locator = ConfigLocator::from_root_for_tests(temp_dir.path().to_path_buf());
+        let user = FileCredentialStore::new(locator.clone());
+        let app = FileCredentialStore::new(locator.clone()).with_actor(Actor::Application);
+        let mut session = sample_session();
+        session.actor = Actor::Application;
+        app.save("default", &session).unwrap();
+
+        assert!(user.load("default").unwrap().is_none());
+        assert_eq!(
+            app.load("default").unwrap().unwrap().actor,
+            Actor::Application
+        );
+        // App credentials are not a profile of their own.
+        assert!(locator.profiles().unwrap().is_empty());
+    }
+
+    #[test]
+    fn memory_store_keeps_sessions_per_profile() {
+        let store: Box<dyn CredentialStore + Send + Sync> =
+            Box::new(MemoryCredentialStore::default());
+        store.save("ci", &sample_session()).unwrap();
+        assert_eq!(store.load("ci").unwrap().unwrap().access_token, "token");
+        assert!(store.load("default").unwrap().is_none());
+        store.delete("ci").unwrap();
+        assert!(store.load("ci").unwrap().is_none());
+    }
+
     #[test]
     fn delete_missing_is_ok() {
         let temp_dir = TempDir::new().unwrap();
@@ -40,4 +40,6 @@ pub enum AuthError {
     UnsupportedFlow(&'static str),
     #[error("no available loopback port in the 9000-9999 range")]
     NoAvailablePort,
+    #[error("credential helper `{command}` failed: {message}")]
+    Helper { command: String, message: String },
 }
@@ -10,7 +10,10 @@ mod session;
 mod utils;
 
 pub use browser::{run_loopback_flow, run_loopback_flow_auto_port};
-pub use credential_store::{CredentialStore, CredentialStoreFactory, FileCredentialStore};
+pub use credential_store::{
+    configured_store, CredentialStore, CredentialStoreFactory, FileCredentialStore,
+    HelperCredentialStore, MemoryCredentialStore,
+};
 pub use error::AuthError;
 pub use flow::{AuthFlow, FlowPreference};
 pub use manual::run_manual_flow;
@@ -21,4 +24,4 @@ pub use oauth::{
 };
 pub use orchestrator::AuthManager;
 pub use pkce::PkcePair;
-pub use session::{AuthSession, TokenType};
+pub use session::{Actor, AuthSession, TokenType, ACCESS_TOKEN_ENV, API_KEY_ENV};
@@ -6,7 +6,7 @@ use reqwest::Client;
 use serde::Deserialize;
 use url::Url;
 
-use super::{AuthError, AuthSession, PkcePair, TokenType};
+use super::{Actor, AuthError, AuthSession, PkcePair, TokenType};
 
 pub const DEFAULT_CLIENT_ID: &str = "linear-rs-public";
 pub const DEFAULT_REDIRECT_HOST: &str = "127.0.0.1";
@@ -25,8 +25,6 @@ pub fn default_redirect_uri(port: u16) -> Result<Url, url::ParseError> {
     ))
 }
 
-const DEFAULT_USER_AGENT: &str = "linear-rs/0.1.0";
-
 /// OAuth client configuration supplied by consumers.
 #[derive(Debug, Clone)]
 pub struct OAuthConfig {
@@ -34,6 +32,8 @@ pub struct OAuthConfig {
     pub client_secret: Option<String>,
     pub redirect_uri: Url,
     pub scopes: Vec<String>,
+    /// Who the authorized token acts as; `Application` requests `actor=application`.
+    pub actor: Actor,
 }
 
 impl OAuthConfig {
@@ -43,6 +43,7 @@ impl OAuthConfig {
             client_secret: None,
             redirect_uri,
             scopes: vec![],
+            actor: Actor::User,
         }
     }
 
@@ -70,6 +71,26 @@ impl OAuthConfig {
         self.scopes = scopes.into_iter().map(Into::into).collect();
         self
     }
+
+    /// Add `scopes` to those requested, keeping the existing order and dropping duplicates.
+    pub fn with_additional_scopes<I, S>(mut self, scopes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        for scope in scopes {
+            let scope = scope.into();
+            if !self.scopes.contains(&scope) {
+                self.scopes.push(scope);
+            }
+        }
+        self
+    }
+
+    pub fn with_actor(mut self, actor: Actor) -> Self {
+        self.actor = actor;
+        self
+    }
 }
 
 /// OAuth endpoints used for browser/manual flows.
@@ -112,9 +133,8 @@ impl OAuthClient {
         config: OAuthConfig,
         endpoints: OAuthEndpoints,
     ) -> Result<Self, AuthError> {
-        let http = Client::builder().user_agent(DEFAULT_USER_AGENT).build()?;
         Ok(Self {
-            http,
+            http: crate::http::shared()?,
             config,
             endpoints,
         })
@@ -152,6 +172,9 @@ impl OAuthClient {
             pairs.append_pair("code_challenge", pkce.challenge());
             pairs.append_pair("code_challenge_method", "S256");
             pairs.append_pair("state", state);
+            if self.config.actor == Actor::Application {
+                pairs.append_pair("actor", "application");
+            }
         }
         Ok(url)
     }
@@ -188,7 +211,8 @@ impl OAuthClient {
             .send()
             .await?;
 
-        self.handle_token_response(response).await
+        self.handle_token_response(response, self.config.actor)
+            .await
     }
 
     /// Refresh an existing session using its refresh token.
@@ -219,7 +243,7 @@ impl OAuthClient {
             .send()
             .await?;
 
-        let mut token_result = self.handle_token_response(response).await?;
+        let mut token_result = self.handle_token_response(response, existing.actor).await?;
 
         if token_result.session.refresh_token.is_none() {
             token_result.session.refresh_token = existing.refresh_token.clone();
@@ -254,12 +278,15 @@ impl OAuthClient {
             .send()
             .await?;
 
-        self.handle_token_response(response).await
+        // Client credentials tokens belong to the application, never a user.
+        self.handle_token_response(response, Actor::Application)
+            .await
     }
 
     async fn handle_token_response(
         &self,
         response: reqwest::Response,
+        actor: Actor,
     ) -> Result<TokenExchangeResult, AuthError> {
         let status = response.status();
         let received_at = Utc::now();
@@ -269,7 +296,8 @@ impl OAuthClient {
         }
 
         let payload: TokenResponse = response.json().await?;
-        let session = payload.into_session(received_at)?;
+        let mut session = payload.into_session(received_at)?;
+        session.actor = actor;
         Ok(TokenExchangeResult {
             session,
             received_at,
@@ -295,7 +323,7 @@ impl TokenResponse {
 
         let expires_at = self
             .expires_in
-            .map(|seconds| received_at + Duration::seconds(seconds.into()));
+            .map(|seconds| received_at + Duration::seconds(seconds));
 
         let scope = self
             .scope
@@ -312,6 +340,7 @@ impl TokenResponse {
             expires_at,
             scope,
             created_at: received_at,
+            actor: Actor::User,
         })
     }
 }
@@ -479,6 +508,33 @@ mod tests {
             mock.assert();
             assert_eq!(result.session.access_token, "machine-token");
             assert_eq!(result.session.scope, vec!["read", "write"]);
+            assert_eq!(result.session.actor, Actor::Application);
         });
     }
+
+    #[test]
+    fn additional_scopes_form_a_union() {
+        let config = OAuthConfig::with_defaults()
+            .with_additional_scopes(["write", "admin"])
+            .with_additional_scopes(vec!["read".to_string()]);
+        assert_eq!(config.scopes, vec!["read", "write", "admin"]);
+    }
+
+    #[test]
+    fn application_actor_is_requested_in_the_authorization_url() {
+        let config = OAuthConfig::new(
+            "client-id",
+            Url::parse("http://localhost/callback").unwrap(),
+        );
+        let client = OAuthClient::new(config.clone()).unwrap();
+        let pkce = PkcePair::generate();
+        let url = client.authorization_url(&pkce, "state").unwrap();
+        assert!(!url.query_pairs().any(|(key, _)| key == "actor"));
+
+        let client = OAuthClient::new(config.with_actor(Actor::Application)).unwrap();
+        let url = client.authorization_url(&pkce, "state").unwrap();
+        assert!(url
+            .query_pairs()
+            .any(|(key, value)| key == "actor" && value == "application"));
+    }
 }
@@ -158,6 +158,10 @@ mod tests {
             *self.inner.lock().unwrap() = None;
             Ok(())
         }
+
+        fn profiles(&self) -> Result<Vec<String>, AuthError> {
+            Ok(Vec::new())
+        }
     }
 
     fn oauth_client(token_url: Url) -> OAuthClient {
@@ -1,6 +1,13 @@
+use std::env;
+
 use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 
+/// Environment variable with a personal API key that replaces the stored credentials.
+pub const API_KEY_ENV: &str = "LINEAR_API_KEY";
+/// Environment variable with an OAuth access token that replaces the stored credentials.
+pub const ACCESS_TOKEN_ENV: &str = "LINEAR_ACCESS_TOKEN";
+
 /// Type of token returned by Linear authentication endpoints.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -9,6 +16,17 @@ pub enum TokenType {
     ApiKey,
 }
 
+/// Identity that mutations made with a token are attributed to.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Actor {
+    /// The user who authorized the token.
+    #[default]
+    User,
+    /// The OAuth application itself (`actor=application`), e.g. for automation.
+    Application,
+}
+
 /// Represents a persisted Linear authentication session.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuthSession {
@@ -19,6 +37,8 @@ pub struct AuthSession {
     pub scope: Vec<String>,
     #[serde(default = "default_created_at")]
     pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    pub actor: Actor,
 }
 
 fn default_created_at() -> DateTime<Utc> {
@@ -39,6 +59,7 @@ impl AuthSession {
             expires_at: Some(expires_at),
             scope,
             created_at: Utc::now(),
+            actor: Actor::User,
         }
     }
 
@@ -50,7 +71,36 @@ impl AuthSession {
             expires_at: None,
             scope: vec![],
             created_at: Utc::now(),
+            actor: Actor::User,
+        }
+    }
+
+    /// The session given by `LINEAR_API_KEY` or `LINEAR_ACCESS_TOKEN`, if either is set.
+    ///
+    /// Such a session lives only in the environment, e.g. a CI secret: it is never stored,
+    /// refreshed, or re-scoped. The API key wins when both are set.
+    pub fn from_env() -> Option<Self> {
+        Self::from_env_values(env::var(API_KEY_ENV).ok(), env::var(ACCESS_TOKEN_ENV).ok())
+    }
+
+    fn from_env_values(api_key: Option<String>, access_token: Option<String>) -> Option<Self> {
+        let present = |value: Option<String>| {
+            value
+                .map(|value| value.trim().to_owned())
+                .filter(|value| !value.is_empty())
+        };
+        if let Some(key) = present(api_key) {
+            return Some(Self::new_api_key(key));
         }
+        present(access_token).map(|token| Self {
+            access_token: token,
+            refresh_token: None,
+            token_type: TokenType::Bearer,
+            expires_at: None,
+            scope: vec![],
+            created_at: Utc::now(),
+            actor: Actor::User,
+        })
     }
 
     pub fn is_expired(&self) -> bool {
@@ -90,4 +140,20 @@ mod tests {
         assert!(!session.is_expired());
         assert!(!session.will_expire_within(Duration::hours(1)));
     }
+
+    #[test]
+    fn env_tokens_prefer_the_api_key() {
+        assert!(AuthSession::from_env_values(None, Some("  ".into())).is_none());
+
+        let token =
+            AuthSession::from_env_values(Some(String::new()), Some("token\n".into())).unwrap();
+        assert_eq!(token.token_type, TokenType::Bearer);
+        assert_eq!(token.access_token, "token");
+        assert!(token.refresh_token.is_none());
+        assert!(!token.will_expire_within(Duration::hours(1)));
+
+        let key = AuthSession::from_env_values(Some("key".into()), Some("token".into())).unwrap();
+        assert_eq!(key.token_type, TokenType::ApiKey);
+        assert_eq!(key.access_token, "key");
+    }
 }
@@ -0,0 +1,517 @@
+//! Workspace backups written by `linear backup`: one dated directory per run, holding a JSON
+//! array per entity and a manifest with the `updatedAt` watermark each entity reached, from
+//! which an incremental run continues. `linear restore` reads them back through [`chain`] and
+//! records what it re-created in a [`RestoreMap`].
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::graphql::Organization;
+
+pub const MANIFEST_FILE: &str = "manifest.json";
+/// Bumped when the layout of a backup directory changes incompatibly.
+pub const FORMAT_VERSION: u32 = 1;
+
+/// Kinds of records a backup can hold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BackupEntity {
+    Labels,
+    Projects,
+    Issues,
+    Comments,
+}
+
+impl BackupEntity {
+    /// Every entity, in the order a restore needs them.
+    pub const ALL: [BackupEntity; 4] = [
+        BackupEntity::Labels,
+        BackupEntity::Projects,
+        BackupEntity::Issues,
+        BackupEntity::Comments,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            BackupEntity::Labels => "labels",
+            BackupEntity::Projects => "projects",
+            BackupEntity::Issues => "issues",
+            BackupEntity::Comments => "comments",
+        }
+    }
+
+    /// File the entity's records are written to, e.g. `issues.json.gz`.
+    pub fn file_name(self, compressed: bool) -> String {
+        let suffix = if compressed { ".gz" } else { "" };
+        format!("{}.json{suffix}", self.as_str())
+    }
+}
+
+impl fmt::Display for BackupEntity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for BackupEntity {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.trim().to_ascii_lowercase().as_str() {
+            "issue" | "issues" => Ok(BackupEntity::Issues),
+            "project" | "projects" => Ok(BackupEntity::Projects),
+            "comment" | "comments" => Ok(BackupEntity::Comments),
+            "label" | "labels" => Ok(BackupEntity::Labels),
+            other => Err(format!(
+                "unknown entity '{}'; expected issues, projects, comments, or labels",
+                other
+            )),
+        }
+    }
+}
+
+/// What one entity's file in a backup holds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityRecord {
+    pub file: String,
+    pub count: usize,
+    /// Only records updated at or after this were fetched; `None` for a full backup.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub since: Option<DateTime<Utc>>,
+    /// Newest `updatedAt` covered by this backup and the ones it continues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub watermark: Option<DateTime<Utc>>,
+}
+
+/// `manifest.json` of a backup directory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Manifest {
+    pub version: u32,
+    pub created_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workspace: Option<Organization>,
+    /// Directory name of the backup an incremental run continued from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous: Option<String>,
+    #[serde(default)]
+    pub entities: BTreeMap<BackupEntity, EntityRecord>,
+}
+
+#[derive(Debug, Error)]
+pub enum BackupError {
+    #[error("failed to access the backup: {0}")]
+    Io(#[from] io::Error),
+    #[error("backup manifest is corrupt: {0}")]
+    Parse(#[from] serde_json::Error),
+    #[error("backup format version {0} is not supported (expected {FORMAT_VERSION})")]
+    Version(u32),
+    #[error("no complete backup found in {0}")]
+    Missing(PathBuf),
+    #[error("backup {0} continues from {1}, which is missing")]
+    Broken(PathBuf, String),
+}
+
+impl Manifest {
+    pub fn new(created_at: DateTime<Utc>, workspace: Option<Organization>) -> Self {
+        Self {
+            version: FORMAT_VERSION,
+            created_at,
+            workspace,
+            previous: None,
+            entities: BTreeMap::new(),
+        }
+    }
+
+    pub fn load(dir: &Path) -> Result<Self, BackupError> {
+        let manifest: Manifest = serde_json::from_slice(&fs::read(dir.join(MANIFEST_FILE))?)?;
+        if manifest.version != FORMAT_VERSION {
+            return Err(BackupError::Version(manifest.version));
+        }
+        Ok(manifest)
+    }
+
+    /// Written last, so a directory with a manifest holds a complete backup.
+    pub fn save(&self, dir: &Path) -> Result<(), BackupError> {
+        let temp = dir.join(format!("{MANIFEST_FILE}.tmp"));
+        fs::write(&temp, serde_json::to_vec_pretty(self)?)?;
+        fs::rename(temp, dir.join(MANIFEST_FILE))?;
+        Ok(())
+    }
+
+    /// Where an incremental backup of `entity` should start.
+    pub fn watermark(&self, entity: BackupEntity) -> Option<DateTime<Utc>> {
+        self.entities
+            .get(&entity)
+            .and_then(|record| record.watermark)
+    }
+}
+
+/// Directory name for a backup taken at `at`; names sort chronologically.
+pub fn dir_name(at: DateTime<Utc>) -> String {
+    at.format("%Y-%m-%dT%H%M%SZ").to_string()
+}
+
+/// The newest complete backup under `root`, if any. Directories without a readable manifest
+/// (e.g. an interrupted run) are skipped.
+pub fn latest(root: &Path) -> Result<Option<(PathBuf, Manifest)>, BackupError> {
+    let entries = match fs::read_dir(root) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+    let mut dirs = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        if path.join(MANIFEST_FILE).is_file() {
+            dirs.push(path);
+        }
+    }
+    dirs.sort();
+    Ok(dirs
+        .into_iter()
+        .rev()
+        .find_map(|dir| Manifest::load(&dir).ok().map(|manifest| (dir, manifest))))
+}
+
+/// The backups needed to see everything as of `path`, oldest first: `path` itself when it is a
+/// backup directory, otherwise the latest backup under it, preceded by the runs it continues.
+pub fn chain(path: &Path) -> Result<Vec<(PathBuf, Manifest)>, BackupError> {
+    let (mut dir, mut manifest) = if path.join(MANIFEST_FILE).is_file() {
+        (path.to_path_buf(), Manifest::load(path)?)
+    } else {
+        latest(path)?.ok_or_else(|| BackupError::Missing(path.to_path_buf()))?
+    };
+    let mut chain = Vec::new();
+    while let Some(previous) = manifest.previous.clone() {
+        let earlier = dir
+            .parent()
+            .map(|root| root.join(&previous))
+            .filter(|earlier| earlier.join(MANIFEST_FILE).is_file())
+            .ok_or_else(|| BackupError::Broken(dir.clone(), previous))?;
+        let loaded = Manifest::load(&earlier)?;
+        chain.push((dir, manifest));
+        (dir, manifest) = (earlier, loaded);
+    }
+    chain.push((dir, manifest));
+    chain.reverse();
+    Ok(chain)
+}
+
+/// Fold the records of successive backups, oldest first, into one list where a later copy of a
+/// record replaces the earlier one in place.
+pub fn merge(layers: impl IntoIterator<Item = Vec<Value>>) -> Vec<Value> {
+    let mut merged: Vec<Value> = Vec::new();
+    let mut positions: HashMap<String, usize> = HashMap::new();
+    for record in layers.into_iter().flatten() {
+        let Some(id) = record.get("id").and_then(Value::as_str).map(str::to_owned) else {
+            continue;
+        };
+        match positions.get(&id) {
+            Some(&index) => merged[index] = record,
+            None => {
+                positions.insert(id, merged.len());
+                merged.push(record);
+            }
+        }
+    }
+    merged
+}
+
+/// Replace issue keys such as `ENG-12` in `text` with the key the issue was restored as. Keys
+/// inside longer words or numbers are left alone, so `XENG-12` or `ENG-123` never match `ENG-12`.
+pub fn rewrite_keys(text: &str, keys: &HashMap<String, String>) -> String {
+    if keys.is_empty() {
+        return text.to_owned();
+    }
+    let prefixes: HashSet<&str> = keys
+        .keys()
+        .filter_map(|key| key.split_once('-').map(|(team, _)| team))
+        .collect();
+    let word = |byte: u8| byte.is_ascii_alphanumeric() || byte == b'_';
+    let bytes = text.as_bytes();
+    let mut out = String::with_capacity(text.len());
+    let mut copied = 0;
+    let mut start = 0;
+    while start < bytes.len() {
+        if !bytes[start].is_ascii_uppercase() || (start > 0 && word(bytes[start - 1])) {
+            start += 1;
+            continue;
+        }
+        let mut end = start;
+        while end < bytes.len() && (bytes[end].is_ascii_uppercase() || bytes[end].is_ascii_digit())
+        {
+            end += 1;
+        }
+        let team = &text[start..end];
+        if end < bytes.len() && bytes[end] == b'-' && prefixes.contains(team) {
+            let digits = end + 1;
+            let mut stop = digits;
+            while stop < bytes.len() && bytes[stop].is_ascii_digit() {
+                stop += 1;
+            }
+            if stop > digits && (stop == bytes.len() || !word(bytes[stop])) {
+                if let Some(new) = keys.get(&text[start..stop]) {
+                    out.push_str(&text[copied..start]);
+                    out.push_str(new);
+                    copied = stop;
+                }
+                start = stop;
+                continue;
+            }
+        }
+        start = end.max(start + 1);
+    }
+    out.push_str(&text[copied..]);
+    out
+}
+
+/// One record re-created by a restore.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Restored {
+    /// Id of the new record.
+    pub id: String,
+    /// Key or name the record had in the backup, e.g. `ENG-12`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    /// Key or name of the new record, e.g. `PLAT-40`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+    /// Whether an existing record was reused instead of creating one, e.g. a label of that name.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub reused: bool,
+}
+
+/// Old→new mapping written by `linear restore`, keyed by the id each record had in the backup.
+/// A re-run with the same map skips what is already in it, so an interrupted restore resumes.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreMap {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<Organization>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<Organization>,
+    #[serde(default)]
+    pub entities: BTreeMap<BackupEntity, BTreeMap<String, Restored>>,
+}
+
+impl RestoreMap {
+    /// Load the map at `path`, treating a missing file as empty.
+    pub fn load(path: &Path) -> Result<Self, BackupError> {
+        match fs::read(path) {
+            Ok(raw) => Ok(serde_json::from_slice(&raw)?),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Replace the map on disk atomically.
+    pub fn save(&self, path: &Path) -> Result<(), BackupError> {
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+        let temp = path.with_extension("json.tmp");
+        fs::write(&temp, serde_json::to_vec_pretty(self)?)?;
+        fs::rename(temp, path)?;
+        Ok(())
+    }
+
+    pub fn get(&self, entity: BackupEntity, old_id: &str) -> Option<&Restored> {
+        self.entities.get(&entity)?.get(old_id)
+    }
+
+    /// Id of the record `old_id` was restored as.
+    pub fn id(&self, entity: BackupEntity, old_id: &str) -> Option<&str> {
+        self.get(entity, old_id)
+            .map(|restored| restored.id.as_str())
+    }
+
+    pub fn insert(&mut self, entity: BackupEntity, old_id: impl Into<String>, restored: Restored) {
+        self.entities
+            .entry(entity)
+            .or_default()
+            .insert(old_id.into(), restored);
+    }
+
+    /// Old issue key → new issue key, for [`rewrite_keys`].
+    pub fn issue_keys(&self) -> HashMap<String, String> {
+        self.entities
+            .get(&BackupEntity::Issues)
+            .into_iter()
+            .flat_map(|issues| issues.values())
+            .filter_map(|restored| Some((restored.from.clone()?, restored.to.clone()?)))
+            .collect()
+    }
+}
+
+/// The `updatedAt` of a raw API record.
+pub fn updated_at(record: &Value) -> Option<DateTime<Utc>> {
+    record
+        .get("updatedAt")
+        .and_then(Value::as_str)
+        .and_then(|raw| raw.parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    fn at(raw: &str) -> DateTime<Utc> {
+        raw.parse().unwrap()
+    }
+
+    #[test]
+    fn entities_parse_singular_or_plural() {
+        assert_eq!("Issue".parse(), Ok(BackupEntity::Issues));
+        assert_eq!(" labels".parse(), Ok(BackupEntity::Labels));
+        assert!("cycles".parse::<BackupEntity>().is_err());
+        assert_eq!(BackupEntity::Comments.file_name(true), "comments.json.gz");
+        assert_eq!(
+            updated_at(&json!({ "updatedAt": "2024-05-01T09:00:00.000Z" })),
+            Some(at("2024-05-01T09:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn latest_skips_incomplete_backups() {
+        let root = TempDir::new().unwrap();
+        assert!(latest(&root.path().join("missing")).unwrap().is_none());
+
+        for (name, watermark) in [
+            ("2024-05-01T090000Z", "2024-05-01T08:00:00Z"),
+            ("2024-05-02T090000Z", "2024-05-02T08:00:00Z"),
+        ] {
+            let dir = root.path().join(name);
+            fs::create_dir(&dir).unwrap();
+            let mut manifest = Manifest::new(at("2024-05-01T09:00:00Z"), None);
+            manifest.entities.insert(
+                BackupEntity::Issues,
+                EntityRecord {
+                    file: BackupEntity::Issues.file_name(false),
+                    count: 1,
+                    since: None,
+                    watermark: Some(at(watermark)),
+                },
+            );
+            manifest.save(&dir).unwrap();
+        }
+        // Interrupted before its manifest was written.
+        fs::create_dir(root.path().join("2024-05-03T090000Z")).unwrap();
+
+        let (dir, manifest) = latest(root.path()).unwrap().unwrap();
+        assert!(dir.ends_with("2024-05-02T090000Z"));
+        assert_eq!(
+            manifest.watermark(BackupEntity::Issues),
+            Some(at("2024-05-02T08:00:00Z"))
+        );
+        assert_eq!(manifest.watermark(BackupEntity::Comments), None);
+        assert_eq!(dir_name(at("2024-05-02T09:00:00Z")), "2024-05-02T090000Z");
+    }
+
+    #[test]
+    fn chain_follows_incremental_runs_and_newer_records_win() {
+        let root = TempDir::new().unwrap();
+        let mut previous = None;
+        for name in ["2024-05-01T090000Z", "2024-05-02T090000Z"] {
+            let dir = root.path().join(name);
+            fs::create_dir(&dir).unwrap();
+            let mut manifest = Manifest::new(at("2024-05-01T09:00:00Z"), None);
+            manifest.previous = previous.replace(name.to_owned());
+            manifest.save(&dir).unwrap();
+        }
+
+        let chain = chain(root.path()).unwrap();
+        let names: Vec<_> = chain
+            .iter()
+            .map(|(dir, _)| dir.file_name().unwrap())
+            .collect();
+        assert_eq!(names, ["2024-05-01T090000Z", "2024-05-02T090000Z"]);
+        assert_eq!(
+            super::chain(&root.path().join("2024-05-01T090000Z"))
+                .unwrap()
+                .len(),
+            1
+        );
+
+        fs::remove_file(root.path().join("2024-05-01T090000Z").join(MANIFEST_FILE)).unwrap();
+        assert!(matches!(
+            super::chain(root.path()),
+            Err(BackupError::Broken(_, _))
+        ));
+        assert!(matches!(
+            super::chain(&root.path().join("missing")),
+            Err(BackupError::Missing(_))
+        ));
+
+        let merged = merge([
+            vec![json!({ "id": "a", "title": "old" }), json!({ "id": "b" })],
+            vec![json!({ "id": "a", "title": "new" }), json!({ "id": "c" })],
+        ]);
+        assert_eq!(
+            merged,
+            [
+                json!({ "id": "a", "title": "new" }),
+                json!({ "id": "b" }),
+                json!({ "id": "c" })
+            ]
+        );
+    }
+
+    #[test]
+    fn rewrites_only_whole_restored_keys() {
+        let keys = HashMap::from([
+            ("ENG-12".to_owned(), "PLAT-40".to_owned()),
+            ("ENG-1".to_owned(), "PLAT-2".to_owned()),
+        ]);
+        assert_eq!(
+            rewrite_keys(
+                "Blocked by ENG-12, see ENG-1/ENG-123 and XENG-12 or ENG-7.",
+                &keys
+            ),
+            "Blocked by PLAT-40, see PLAT-2/ENG-123 and XENG-12 or ENG-7."
+        );
+        assert_eq!(rewrite_keys("(ENG-12)", &keys), "(PLAT-40)");
+        assert_eq!(rewrite_keys("naïve ENG-1", &keys), "naïve PLAT-2");
+    }
+
+    #[test]
+    fn restore_map_round_trips_and_lists_issue_keys() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("restore.json");
+        assert!(RestoreMap::load(&path).unwrap().entities.is_empty());
+
+        let mut map = RestoreMap::default();
+        map.insert(
+            BackupEntity::Issues,
+            "old-1",
+            Restored {
+                id: "new-1".into(),
+                from: Some("ENG-12".into()),
+                to: Some("PLAT-40".into()),
+                reused: false,
+            },
+        );
+        map.save(&path).unwrap();
+
+        let loaded = RestoreMap::load(&path).unwrap();
+        assert_eq!(loaded.id(BackupEntity::Issues, "old-1"), Some("new-1"));
+        assert_eq!(loaded.id(BackupEntity::Labels, "old-1"), None);
+        assert_eq!(
+            loaded.issue_keys(),
+            HashMap::from([("ENG-12".to_owned(), "PLAT-40".to_owned())])
+        );
+    }
+}
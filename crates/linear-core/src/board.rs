@@ -0,0 +1,324 @@
+//! Board layout of an issue list: one column per workflow state, optionally split into
+//! swimlanes by label, checked against the `[board]` WIP limits and age thresholds.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
+use crate::config::BoardLimits;
+use crate::graphql::IssueSummary;
+use crate::group::{group_issues, GroupBy};
+
+/// Issues laid out as state columns, each lane holding one cell per column.
+#[derive(Debug, Clone)]
+pub struct Board<'a> {
+    /// State names in workflow order.
+    pub columns: Vec<String>,
+    /// Issues per column, counting an issue in several lanes once.
+    pub counts: Vec<usize>,
+    pub lanes: Vec<Lane<'a>>,
+}
+
+/// A horizontal band of the board.
+#[derive(Debug, Clone)]
+pub struct Lane<'a> {
+    /// The lane's label; `None` for the lane of issues carrying none of the lane labels, which
+    /// is the only lane when no labels are selected.
+    pub label: Option<String>,
+    /// Issues per column, in list order.
+    pub cells: Vec<Vec<&'a IssueSummary>>,
+}
+
+impl IssueSummary {
+    /// Whether the issue carries a label named `name`, ignoring case.
+    pub fn has_label(&self, name: &str) -> bool {
+        self.labels.as_ref().is_some_and(|labels| {
+            labels
+                .nodes
+                .iter()
+                .any(|label| label.name.eq_ignore_ascii_case(name))
+        })
+    }
+}
+
+impl<'a> Board<'a> {
+    /// Lay `issues` out by state, with one lane per entry of `lane_labels` in that order and a
+    /// final lane for the rest. An issue with several lane labels appears in each of their lanes.
+    pub fn build(issues: &'a [IssueSummary], lane_labels: &[String]) -> Self {
+        let groups = group_issues(issues, GroupBy::State);
+        let columns: Vec<String> = groups.iter().map(|group| group.label.clone()).collect();
+        let counts = groups.iter().map(|group| group.issues.len()).collect();
+        let lane = |label: Option<String>, keep: &dyn Fn(&IssueSummary) -> bool| Lane {
+            label,
+            cells: groups
+                .iter()
+                .map(|group| {
+                    group
+                        .issues
+                        .iter()
+                        .copied()
+                        .filter(|issue| keep(issue))
+                        .collect()
+                })
+                .collect(),
+        };
+
+        let mut lanes: Vec<Lane<'a>> = lane_labels
+            .iter()
+            .map(|name| lane(Some(name.clone()), &|issue| issue.has_label(name)))
+            .collect();
+        let rest = lane(None, &|issue| {
+            !lane_labels.iter().any(|name| issue.has_label(name))
+        });
+        if lane_labels.is_empty() || !rest.is_empty() {
+            lanes.push(rest);
+        }
+        Self {
+            columns,
+            counts,
+            lanes,
+        }
+    }
+
+    /// Cards of column `column`, lane after lane.
+    pub fn column_cards(&self, column: usize) -> Vec<&'a IssueSummary> {
+        self.lanes
+            .iter()
+            .filter_map(|lane| lane.cells.get(column))
+            .flat_map(|cell| cell.iter().copied())
+            .collect()
+    }
+}
+
+/// A state holding more issues than its WIP limit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WipViolation {
+    pub state: String,
+    pub count: usize,
+    pub limit: usize,
+}
+
+/// An issue that has sat unchanged in its state for longer than the state allows.
+#[derive(Debug, Clone)]
+pub struct AgeViolation<'a> {
+    pub issue: &'a IssueSummary,
+    pub state: String,
+    pub age: chrono::Duration,
+    pub max_age: Duration,
+}
+
+/// Everything in an issue list that breaks the configured limits.
+#[derive(Debug, Clone, Default)]
+pub struct Violations<'a> {
+    pub wip: Vec<WipViolation>,
+    pub age: Vec<AgeViolation<'a>>,
+}
+
+impl Violations<'_> {
+    pub fn len(&self) -> usize {
+        self.wip.len() + self.age.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.wip.is_empty() && self.age.is_empty()
+    }
+}
+
+impl BoardLimits {
+    /// The limit of `state` when `count` issues exceed it.
+    pub fn over_wip(&self, state: &str, count: usize) -> Option<usize> {
+        self.wip_limit(state).filter(|limit| count > *limit)
+    }
+
+    /// How long `issue` has gone unchanged, when that exceeds its state's threshold.
+    ///
+    /// Linear does not expose when an issue entered its state, so the last update stands in;
+    /// moving an issue between states resets it.
+    pub fn overdue(&self, issue: &IssueSummary, now: DateTime<Utc>) -> Option<chrono::Duration> {
+        let max_age = self.max_age(&issue.state.as_ref()?.name)?;
+        let age = now - issue.updated_at;
+        (age.to_std().is_ok_and(|age| age > max_age)).then_some(age)
+    }
+
+    /// WIP and age violations in `issues`, states in workflow order and oldest issues first.
+    pub fn violations<'a>(&self, issues: &'a [IssueSummary], now: DateTime<Utc>) -> Violations<'a> {
+        let mut violations = Violations::default();
+        for group in group_issues(issues, GroupBy::State) {
+            if let Some(limit) = self.over_wip(&group.label, group.issues.len()) {
+                violations.wip.push(WipViolation {
+                    state: group.label.clone(),
+                    count: group.issues.len(),
+                    limit,
+                });
+            }
+            let mut aged: Vec<AgeViolation<'a>> = group
+                .issues
+                .iter()
+                .filter_map(|issue| {
+                    let age = self.overdue(issue, now)?;
+                    Some(AgeViolation {
+                        issue,
+                        state: group.label.clone(),
+                        age,
+                        max_age: self.max_age(&group.label)?,
+                    })
+                })
+                .collect();
+            aged.sort_by_key(|violation| std::cmp::Reverse(violation.age));
+            violations.age.extend(aged);
+        }
+        violations
+    }
+}
+
+/// An age as whole days, or hours or minutes when shorter, e.g. `9d` or `5h`.
+pub fn short_age(age: chrono::Duration) -> String {
+    if age.num_days() > 0 {
+        format!("{}d", age.num_days())
+    } else if age.num_hours() > 0 {
+        format!("{}h", age.num_hours())
+    } else {
+        format!("{}m", age.num_minutes().max(0))
+    }
+}
+
+impl Lane<'_> {
+    pub fn len(&self) -> usize {
+        self.cells.iter().map(Vec::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.iter().all(Vec::is_empty)
+    }
+
+    /// Rows the lane needs so its cells line up across columns.
+    pub fn height(&self) -> usize {
+        self.cells.iter().map(Vec::len).max().unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(identifier: &str, state: (&str, &str), labels: &[&str]) -> IssueSummary {
+        updated(identifier, state, labels, "2024-01-01T00:00:00Z")
+    }
+
+    fn updated(
+        identifier: &str,
+        state: (&str, &str),
+        labels: &[&str],
+        updated_at: &str,
+    ) -> IssueSummary {
+        serde_json::from_value(serde_json::json!({
+            "id": identifier,
+            "identifier": identifier,
+            "title": identifier,
+            "url": null,
+            "priority": 0,
+            "createdAt": "2024-01-01T00:00:00Z",
+            "updatedAt": updated_at,
+            "state": { "id": state.0, "name": state.0, "type": state.1 },
+            "assignee": null,
+            "labels": {
+                "nodes": labels
+                    .iter()
+                    .map(|name| serde_json::json!({ "id": name, "name": name, "color": null }))
+                    .collect::<Vec<_>>()
+            }
+        }))
+        .unwrap()
+    }
+
+    fn keys(cell: &[&IssueSummary]) -> Vec<String> {
+        cell.iter().map(|issue| issue.identifier.clone()).collect()
+    }
+
+    #[test]
+    fn columns_follow_workflow_order() {
+        let issues = vec![
+            issue("ENG-1", ("Done", "completed"), &[]),
+            issue("ENG-2", ("Todo", "unstarted"), &[]),
+            issue("ENG-3", ("In Progress", "started"), &[]),
+            issue("ENG-4", ("Todo", "unstarted"), &[]),
+        ];
+        let board = Board::build(&issues, &[]);
+        assert_eq!(board.columns, vec!["Todo", "In Progress", "Done"]);
+        assert_eq!(board.lanes.len(), 1);
+        assert_eq!(board.lanes[0].label, None);
+        assert_eq!(keys(&board.lanes[0].cells[0]), vec!["ENG-2", "ENG-4"]);
+        assert_eq!(board.lanes[0].height(), 2);
+        assert_eq!(board.counts, vec![2, 1, 1]);
+    }
+
+    #[test]
+    fn splits_lanes_by_label() {
+        let issues = vec![
+            issue("ENG-1", ("Todo", "unstarted"), &["Bug"]),
+            issue("ENG-2", ("Todo", "unstarted"), &["Feature"]),
+            issue("ENG-3", ("Done", "completed"), &["bug", "Feature"]),
+            issue("ENG-4", ("Done", "completed"), &["Chore"]),
+        ];
+        let board = Board::build(&issues, &["bug".into(), "feature".into()]);
+        let labels: Vec<_> = board.lanes.iter().map(|lane| lane.label.clone()).collect();
+        assert_eq!(
+            labels,
+            vec![Some("bug".into()), Some("feature".into()), None]
+        );
+        assert_eq!(keys(&board.lanes[0].cells[0]), vec!["ENG-1"]);
+        assert_eq!(keys(&board.lanes[0].cells[1]), vec!["ENG-3"]);
+        assert_eq!(keys(&board.lanes[1].cells[1]), vec!["ENG-3"]);
+        assert_eq!(keys(&board.lanes[2].cells[1]), vec!["ENG-4"]);
+        assert_eq!(
+            keys(&board.column_cards(1)),
+            vec!["ENG-3", "ENG-3", "ENG-4"]
+        );
+
+        // The catch-all lane disappears once every issue has a lane.
+        let board = Board::build(&issues[..3], &["bug".into(), "feature".into()]);
+        assert_eq!(board.lanes.len(), 2);
+    }
+
+    #[test]
+    fn reports_wip_and_age_violations() {
+        let started = ("In Progress", "started");
+        let issues = vec![
+            updated("ENG-1", started, &[], "2024-01-01T00:00:00Z"),
+            updated("ENG-2", started, &[], "2024-01-09T00:00:00Z"),
+            updated("ENG-3", started, &[], "2023-12-20T00:00:00Z"),
+            updated("ENG-4", ("Todo", "unstarted"), &[], "2023-01-01T00:00:00Z"),
+        ];
+        let limits: BoardLimits = toml::from_str(
+            r#"
+            wip = { "in progress" = 2, "Todo" = 1 }
+            max_age = { "In Progress" = "7d" }
+            "#,
+        )
+        .unwrap();
+        let now: DateTime<Utc> = "2024-01-10T00:00:00Z".parse().unwrap();
+
+        let violations = limits.violations(&issues, now);
+        assert_eq!(
+            violations.wip,
+            vec![WipViolation {
+                state: "In Progress".into(),
+                count: 3,
+                limit: 2,
+            }]
+        );
+        let aged: Vec<_> = violations
+            .age
+            .iter()
+            .map(|violation| {
+                (
+                    violation.issue.identifier.as_str(),
+                    short_age(violation.age),
+                )
+            })
+            .collect();
+        assert_eq!(aged, vec![("ENG-3", "21d".into()), ("ENG-1", "9d".into())]);
+        assert_eq!(violations.len(), 3);
+        assert!(BoardLimits::default().violations(&issues, now).is_empty());
+    }
+}
@@ -0,0 +1,90 @@
+//! What a command that pages through a lot of data will cost against Linear's rate limit,
+//! estimated before it runs and checked against the `[budget]` in `config.toml`.
+
+use serde::Serialize;
+
+use crate::config::Budget;
+
+/// Requests and complexity points a command is expected to spend.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Estimate {
+    /// Requests it would send; `None` when it pages until the results run out.
+    pub requests: Option<usize>,
+    /// Complexity points of each request.
+    pub per_request: f64,
+}
+
+impl Estimate {
+    /// Fetching `items` results `page_size` at a time, or every result when `items` is `None`.
+    pub fn pages(items: Option<usize>, page_size: usize, per_request: f64) -> Self {
+        Self {
+            requests: items.map(|items| items.div_ceil(page_size.max(1)).max(1)),
+            per_request,
+        }
+    }
+
+    /// Complexity points of every request together, when the request count is known.
+    pub fn complexity(&self) -> Option<f64> {
+        self.requests
+            .map(|requests| (requests as f64 * self.per_request * 10.0).round() / 10.0)
+    }
+
+    /// Why this estimate is over `budget`, one reason per limit; empty when it is within both.
+    pub fn exceeds(&self, budget: &Budget) -> Vec<String> {
+        let mut reasons = Vec::new();
+        if let Some(requests) = self.requests {
+            if budget.requests > 0 && requests > budget.requests {
+                reasons.push(format!(
+                    "about {requests} requests, above the budget of {}",
+                    budget.requests
+                ));
+            }
+        }
+        if let Some(complexity) = self.complexity() {
+            if budget.complexity > 0.0 && complexity > budget.complexity {
+                reasons.push(format!(
+                    "about {complexity} complexity points, above the budget of {}",
+                    budget.complexity
+                ));
+            }
+        }
+        reasons
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    #[test]
+    fn pages_are_checked_against_the_budget() {
+        let config: Config =
+            toml::from_str("[budget]\nrequests = 10\ncomplexity = 5000\nenforce = true").unwrap();
+        assert!(config.budget.enforce);
+
+        let small = Estimate::pages(Some(250), 100, 400.0);
+        assert_eq!(small.requests, Some(3));
+        assert_eq!(small.complexity(), Some(1200.0));
+        assert!(small.exceeds(&config.budget).is_empty());
+
+        let large = Estimate::pages(Some(2_000), 100, 400.0);
+        let reasons = large.exceeds(&config.budget);
+        assert_eq!(reasons.len(), 2);
+        assert!(reasons[0].contains("20 requests"));
+        assert!(reasons[1].contains("8000 complexity points"));
+
+        // Paging to the end has no known total to check up front.
+        let unbounded = Estimate::pages(None, 100, 400.0);
+        assert_eq!(unbounded.complexity(), None);
+        assert!(unbounded.exceeds(&config.budget).is_empty());
+
+        let off = Budget {
+            requests: 0,
+            complexity: 0.0,
+            enforce: false,
+        };
+        assert!(large.exceeds(&off).is_empty());
+    }
+}
@@ -0,0 +1,260 @@
+//! iCalendar (RFC 5545) feeds of cycles, project target dates, and issue due dates, for
+//! `linear calendar export`.
+
+use std::fmt::Write as _;
+use std::str::FromStr;
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::graphql::{CycleSummary, ProjectSummary};
+use crate::query::IssueFields;
+
+/// Kinds of dates a feed can include.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    Cycles,
+    Projects,
+    Issues,
+}
+
+impl Source {
+    pub const ALL: [Source; 3] = [Source::Cycles, Source::Projects, Source::Issues];
+}
+
+impl FromStr for Source {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "cycles" | "cycle" => Ok(Source::Cycles),
+            "projects" | "project" => Ok(Source::Projects),
+            "issues" | "issue" | "due" => Ok(Source::Issues),
+            other => Err(format!(
+                "unknown calendar source '{other}'; expected cycles, projects, or issues"
+            )),
+        }
+    }
+}
+
+/// An issue with a due date, as selected by [`DueIssue::fields`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DueIssue {
+    pub id: String,
+    pub identifier: String,
+    pub title: String,
+    pub url: Option<String>,
+    pub due_date: Option<NaiveDate>,
+}
+
+impl DueIssue {
+    pub fn fields() -> IssueFields {
+        IssueFields::IDENTIFIER | IssueFields::TITLE | IssueFields::URL | IssueFields::DUE_DATE
+    }
+}
+
+/// An all-day event; `end` is exclusive, as in `DTEND;VALUE=DATE`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    pub uid: String,
+    pub summary: String,
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    pub url: Option<String>,
+    pub category: &'static str,
+}
+
+impl Event {
+    fn day(uid: String, summary: String, date: NaiveDate, category: &'static str) -> Self {
+        Self {
+            uid,
+            summary,
+            start: date,
+            end: date + Duration::days(1),
+            url: None,
+            category,
+        }
+    }
+}
+
+/// One event per cycle spanning its start and end dates. Cycles without dates are skipped.
+pub fn cycle_events(cycles: &[CycleSummary]) -> Vec<Event> {
+    cycles
+        .iter()
+        .filter_map(|cycle| {
+            let start = date_of(cycle.starts_at.as_deref()?)?;
+            let end = date_of(cycle.ends_at.as_deref()?)?.max(start + Duration::days(1));
+            let team = cycle
+                .team
+                .as_ref()
+                .map(|team| format!("{} ", team.key))
+                .unwrap_or_default();
+            let name = match cycle.name.as_deref().filter(|name| !name.is_empty()) {
+                Some(name) => format!("{team}Cycle {}: {name}", cycle.number),
+                None => format!("{team}Cycle {}", cycle.number),
+            };
+            Some(Event {
+                end,
+                ..Event::day(format!("cycle-{}", cycle.id), name, start, "Cycle")
+            })
+        })
+        .collect()
+}
+
+/// A target-date event per project that has one.
+pub fn project_events(projects: &[ProjectSummary]) -> Vec<Event> {
+    projects
+        .iter()
+        .filter_map(|project| {
+            let target = date_of(project.target_date.as_deref()?)?;
+            Some(Event {
+                url: project.url.clone(),
+                ..Event::day(
+                    format!("project-{}", project.id),
+                    format!("{} target", project.name),
+                    target,
+                    "Project",
+                )
+            })
+        })
+        .collect()
+}
+
+/// A due-date event per issue that has one.
+pub fn issue_events(issues: &[DueIssue]) -> Vec<Event> {
+    issues
+        .iter()
+        .filter_map(|issue| {
+            Some(Event {
+                url: issue.url.clone(),
+                ..Event::day(
+                    format!("issue-{}", issue.id),
+                    format!("{} {} (due)", issue.identifier, issue.title),
+                    issue.due_date?,
+                    "Issue",
+                )
+            })
+        })
+        .collect()
+}
+
+/// `VCALENDAR` text with one `VEVENT` per event, CRLF line endings, and long lines folded.
+pub fn render(name: &str, events: &[Event], now: DateTime<Utc>) -> String {
+    let stamp = now.format("%Y%m%dT%H%M%SZ").to_string();
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_owned(),
+        "VERSION:2.0".to_owned(),
+        "PRODID:-//linear-rs//calendar export//EN".to_owned(),
+        "CALSCALE:GREGORIAN".to_owned(),
+        format!("X-WR-CALNAME:{}", escape(name)),
+    ];
+    for event in events {
+        lines.push("BEGIN:VEVENT".into());
+        lines.push(format!("UID:{}@linear-rs", event.uid));
+        lines.push(format!("DTSTAMP:{stamp}"));
+        lines.push(format!(
+            "DTSTART;VALUE=DATE:{}",
+            event.start.format("%Y%m%d")
+        ));
+        lines.push(format!("DTEND;VALUE=DATE:{}", event.end.format("%Y%m%d")));
+        lines.push(format!("SUMMARY:{}", escape(&event.summary)));
+        lines.push(format!("CATEGORIES:{}", event.category));
+        if let Some(url) = &event.url {
+            lines.push(format!("URL:{url}"));
+        }
+        lines.push("TRANSP:TRANSPARENT".into());
+        lines.push("END:VEVENT".into());
+    }
+    lines.push("END:VCALENDAR".into());
+
+    let mut out = String::new();
+    for line in lines {
+        fold(&mut out, &line);
+    }
+    out
+}
+
+/// Date part of an RFC 3339 timestamp or a plain `YYYY-MM-DD` date.
+fn date_of(value: &str) -> Option<NaiveDate> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|at| at.with_timezone(&Utc).date_naive())
+        .ok()
+        .or_else(|| NaiveDate::parse_from_str(value, "%Y-%m-%d").ok())
+}
+
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | ';' | ',' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '\n' => out.push_str("\\n"),
+            '\r' => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Append `line`, split into 75-octet pieces continued by a leading space.
+fn fold(out: &mut String, line: &str) {
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += c.len_utf8();
+    }
+    let _ = write!(out, "\r\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn renders_events_as_ics() {
+        let cycles: Vec<CycleSummary> = serde_json::from_value(json!([
+            {"id": "c1", "name": null, "number": 12, "startsAt": "2024-05-06T07:00:00.000Z",
+             "endsAt": "2024-05-20T07:00:00.000Z", "state": null,
+             "team": {"id": "t", "key": "ENG", "name": "Engineering"}},
+            {"id": "c2", "name": null, "number": 13, "startsAt": null, "endsAt": null,
+             "state": null, "team": null}
+        ]))
+        .unwrap();
+        let issues = vec![DueIssue {
+            id: "i1".into(),
+            identifier: "ENG-7".into(),
+            title: "Ship it; finally, with a title long enough to need folding onto a second line"
+                .into(),
+            url: Some("https://linear.app/acme/issue/ENG-7".into()),
+            due_date: NaiveDate::from_ymd_opt(2024, 5, 9),
+        }];
+        let mut events = cycle_events(&cycles);
+        events.extend(issue_events(&issues));
+        assert_eq!(events.len(), 2);
+
+        let now = "2024-05-01T12:00:00Z".parse().unwrap();
+        let ics = render("Linear ENG", &events, now);
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(ics.contains(
+            "UID:cycle-c1@linear-rs\r\nDTSTAMP:20240501T120000Z\r\n\
+             DTSTART;VALUE=DATE:20240506\r\nDTEND;VALUE=DATE:20240520\r\n\
+             SUMMARY:ENG Cycle 12\r\n"
+        ));
+        assert!(ics.contains(
+            "DTSTART;VALUE=DATE:20240509\r\nDTEND;VALUE=DATE:20240510\r\n\
+             SUMMARY:ENG-7 Ship it\\; finally\\, with a title long enough to need \
+             folding \r\n onto a second line (due)\r\n"
+        ));
+        assert!(ics.ends_with("END:VEVENT\r\nEND:VCALENDAR\r\n"));
+        assert!(ics.lines().all(|line| line.len() <= 75));
+        assert_eq!("due".parse::<Source>(), Ok(Source::Issues));
+    }
+}
@@ -0,0 +1,265 @@
+//! Label colors for terminals: hex parsing, a readable foreground for each background, and
+//! approximations for terminals without 24-bit color.
+
+/// A 24-bit sRGB color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+/// How many colors the terminal can show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorDepth {
+    TrueColor,
+    Ansi256,
+    Ansi16,
+}
+
+/// A color the terminal can show: exact RGB, or an index into its 256- or 16-color palette.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TermColor {
+    Rgb(Rgb),
+    /// 0–15 are the basic colors, 16–231 the 6×6×6 cube, 232–255 the gray ramp.
+    Indexed(u8),
+}
+
+/// Channel values of the 256-color cube.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Terminal names whose terminfo entries advertise 24-bit color.
+const TRUECOLOR_TERMS: &[&str] = &[
+    "kitty",
+    "alacritty",
+    "foot",
+    "wezterm",
+    "ghostty",
+    "contour",
+];
+
+impl Rgb {
+    /// `#rrggbb` or `#rgb`, with or without the `#`.
+    pub fn parse_hex(value: &str) -> Option<Self> {
+        let hex = value.trim().trim_start_matches('#');
+        if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let channel = |digits: &str| u8::from_str_radix(digits, 16).ok();
+        match hex.len() {
+            6 => Some(Self(
+                channel(&hex[0..2])?,
+                channel(&hex[2..4])?,
+                channel(&hex[4..6])?,
+            )),
+            3 => {
+                let short = |index: usize| channel(&hex[index..index + 1]).map(|v| v * 17);
+                Some(Self(short(0)?, short(1)?, short(2)?))
+            }
+            _ => None,
+        }
+    }
+
+    /// WCAG relative luminance, 0 (black) to 1 (white).
+    pub fn luminance(self) -> f64 {
+        let linear = |channel: u8| {
+            let value = f64::from(channel) / 255.0;
+            if value <= 0.039_28 {
+                value / 12.92
+            } else {
+                ((value + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        0.2126 * linear(self.0) + 0.7152 * linear(self.1) + 0.0722 * linear(self.2)
+    }
+
+    /// WCAG contrast ratio between two colors, 1 to 21.
+    pub fn contrast(self, other: Self) -> f64 {
+        let (a, b) = (self.luminance(), other.luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// Black or white, whichever reads better on this background.
+    pub fn readable_foreground(self) -> Self {
+        let (black, white) = (Self(0, 0, 0), Self(255, 255, 255));
+        if self.contrast(black) >= self.contrast(white) {
+            black
+        } else {
+            white
+        }
+    }
+
+    /// The closest color `depth` can show.
+    pub fn approximate(self, depth: ColorDepth) -> TermColor {
+        match depth {
+            ColorDepth::TrueColor => TermColor::Rgb(self),
+            ColorDepth::Ansi256 => TermColor::Indexed(self.to_ansi256()),
+            ColorDepth::Ansi16 => TermColor::Indexed(self.to_ansi16()),
+        }
+    }
+
+    /// Nearest entry of the 256-color cube or gray ramp.
+    fn to_ansi256(self) -> u8 {
+        let level = |channel: u8| {
+            (0..CUBE_LEVELS.len())
+                .min_by_key(|index| CUBE_LEVELS[*index].abs_diff(channel))
+                .unwrap_or_default()
+        };
+        let (r, g, b) = (level(self.0), level(self.1), level(self.2));
+        let cube = Self(CUBE_LEVELS[r], CUBE_LEVELS[g], CUBE_LEVELS[b]);
+        let average = (u16::from(self.0) + u16::from(self.1) + u16::from(self.2)) / 3;
+        let step = (average.saturating_sub(8) / 10).min(23) as u8;
+        let gray_value = 8 + step * 10;
+        let gray = Self(gray_value, gray_value, gray_value);
+        if self.distance(gray) < self.distance(cube) {
+            232 + step
+        } else {
+            16 + 36 * r as u8 + 6 * g as u8 + b as u8
+        }
+    }
+
+    /// Closest of the 16 basic colors by hue, so muted label colors do not all turn gray.
+    fn to_ansi16(self) -> u8 {
+        let channels = [self.0, self.1, self.2];
+        let max = channels.iter().copied().max().unwrap_or_default();
+        let min = channels.iter().copied().min().unwrap_or_default();
+        if max - min < 48 {
+            // Grays: black, bright black, white, bright white.
+            return match (u16::from(self.0) + u16::from(self.1) + u16::from(self.2)) / 3 {
+                0..=63 => 0,
+                64..=159 => 8,
+                160..=223 => 7,
+                _ => 15,
+            };
+        }
+        // Each channel well above the darkest one counts as lit; bits follow ANSI's BGR order.
+        let middle = min + (max - min) / 2;
+        let index = channels
+            .iter()
+            .enumerate()
+            .filter(|(_, channel)| **channel > middle)
+            .fold(0, |index, (bit, _)| index | 1 << bit);
+        if max >= 200 {
+            index + 8
+        } else {
+            index
+        }
+    }
+
+    fn distance(self, other: Self) -> u32 {
+        let d = |a: u8, b: u8| u32::from(a.abs_diff(b)).pow(2);
+        d(self.0, other.0) + d(self.1, other.1) + d(self.2, other.2)
+    }
+}
+
+impl ColorDepth {
+    /// Depth advertised by `COLORTERM`, `TERM`, and a few terminal-specific variables.
+    ///
+    /// `COLORTERM=truecolor` (or `24bit`), a `*-direct` or known 24-bit `TERM`, Windows Terminal,
+    /// and iTerm2 get true color; a `*-256color` `TERM` gets 256 colors; anything else 16.
+    pub fn from_env(var: impl Fn(&str) -> Option<String>) -> Self {
+        if matches!(var("COLORTERM").as_deref(), Some("truecolor" | "24bit"))
+            || var("WT_SESSION").is_some()
+            || var("TERM_PROGRAM").as_deref() == Some("iTerm.app")
+        {
+            return Self::TrueColor;
+        }
+        let term = var("TERM").unwrap_or_default().to_ascii_lowercase();
+        if term.ends_with("-direct") || TRUECOLOR_TERMS.iter().any(|name| term.contains(name)) {
+            Self::TrueColor
+        } else if term.contains("256color") {
+            Self::Ansi256
+        } else {
+            Self::Ansi16
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::TrueColor => "truecolor",
+            Self::Ansi256 => "256 colors",
+            Self::Ansi16 => "basic colors",
+        }
+    }
+}
+
+/// Background and foreground for a label chip of color `hex`, or `None` if `hex` is not a color.
+pub fn chip(hex: &str, depth: ColorDepth) -> Option<(TermColor, TermColor)> {
+    let background = Rgb::parse_hex(hex)?;
+    let foreground = background.readable_foreground();
+    Some((background.approximate(depth), foreground.approximate(depth)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_long_and_short_hex() {
+        assert_eq!(Rgb::parse_hex("#5e6ad2"), Some(Rgb(0x5e, 0x6a, 0xd2)));
+        assert_eq!(Rgb::parse_hex("F0a"), Some(Rgb(0xff, 0x00, 0xaa)));
+        assert_eq!(Rgb::parse_hex("#12345"), None);
+        assert_eq!(Rgb::parse_hex("#gg0000"), None);
+        assert_eq!(Rgb::parse_hex("#ÿÿÿ"), None);
+    }
+
+    #[test]
+    fn picks_the_more_readable_foreground() {
+        assert_eq!(Rgb(255, 255, 0).readable_foreground(), Rgb(0, 0, 0));
+        assert_eq!(Rgb(0, 0, 128).readable_foreground(), Rgb(255, 255, 255));
+        assert_eq!(Rgb(235, 87, 87).readable_foreground(), Rgb(0, 0, 0));
+        assert!((Rgb(0, 0, 0).contrast(Rgb(255, 255, 255)) - 21.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn approximates_for_smaller_palettes() {
+        let red = Rgb(255, 0, 0);
+        assert_eq!(red.approximate(ColorDepth::TrueColor), TermColor::Rgb(red));
+        assert_eq!(
+            red.approximate(ColorDepth::Ansi256),
+            TermColor::Indexed(196)
+        );
+        assert_eq!(red.approximate(ColorDepth::Ansi16), TermColor::Indexed(9));
+        assert_eq!(
+            Rgb(0x0f, 0x1e, 0x5c).approximate(ColorDepth::Ansi16),
+            TermColor::Indexed(4)
+        );
+        assert_eq!(
+            Rgb(0xeb, 0x57, 0x57).approximate(ColorDepth::Ansi16),
+            TermColor::Indexed(9)
+        );
+        // Grays land on the ramp rather than the cube.
+        assert_eq!(
+            Rgb(128, 128, 128).approximate(ColorDepth::Ansi256),
+            TermColor::Indexed(244)
+        );
+        assert_eq!(
+            Rgb(0x5e, 0x6a, 0xd2).approximate(ColorDepth::Ansi16),
+            TermColor::Indexed(12)
+        );
+    }
+
+    #[test]
+    fn detects_depth_from_the_environment() {
+        let env = |pairs: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                pairs
+                    .iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+        assert_eq!(
+            ColorDepth::from_env(env(&[("COLORTERM", "truecolor"), ("TERM", "xterm")])),
+            ColorDepth::TrueColor
+        );
+        assert_eq!(
+            ColorDepth::from_env(env(&[("TERM", "xterm-kitty")])),
+            ColorDepth::TrueColor
+        );
+        assert_eq!(
+            ColorDepth::from_env(env(&[("TERM", "screen-256color")])),
+            ColorDepth::Ansi256
+        );
+        assert_eq!(
+            ColorDepth::from_env(env(&[("TERM", "linux")])),
+            ColorDepth::Ansi16
+        );
+    }
+}
@@ -1,9 +1,14 @@
+use std::collections::BTreeMap;
+use std::fmt;
 use std::fs;
 #[cfg(unix)]
 use std::os::unix::fs::PermissionsExt;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
 
 use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 /// Application-specific configuration helpers.
@@ -30,12 +35,525 @@ impl ConfigLocator {
         self.root.join(format!("credentials-{profile}.json"))
     }
 
+    /// Path to the application-actor credentials (`auth login --as-app`) for the given profile,
+    /// kept beside the user's so both can be used.
+    pub fn app_credentials_file(&self, profile: &str) -> PathBuf {
+        self.root.join(format!("app-credentials-{profile}.json"))
+    }
+
+    /// Path to the user-editable `config.toml`.
+    pub fn config_file(&self) -> PathBuf {
+        self.root.join("config.toml")
+    }
+
+    /// Path to the unix socket `linear daemon` listens on for the given profile.
+    pub fn daemon_socket(&self, profile: &str) -> PathBuf {
+        self.root.join(format!("daemon-{profile}.sock"))
+    }
+
+    /// Path to the log the TUI writes when it panics.
+    pub fn tui_crash_log(&self) -> PathBuf {
+        self.root.join("tui-crash.log")
+    }
+
+    /// Path to the automation rules read by `linear automate run`.
+    pub fn rules_file(&self) -> PathBuf {
+        self.root.join("rules.toml")
+    }
+
+    /// Path to the notification rules run by `linear notify watch`.
+    pub fn notify_rules_file(&self) -> PathBuf {
+        self.root.join("notify-rules.toml")
+    }
+
+    /// Names of all profiles with a credentials file on disk, sorted alphabetically.
+    pub fn profiles(&self) -> Result<Vec<String>, ConfigError> {
+        let mut profiles = Vec::new();
+        for entry in fs::read_dir(&self.root)? {
+            let name = entry?.file_name();
+            let Some(name) = name.to_str() else {
+                continue;
+            };
+            if let Some(profile) = name
+                .strip_prefix("credentials-")
+                .and_then(|rest| rest.strip_suffix(".json"))
+            {
+                profiles.push(profile.to_owned());
+            }
+        }
+        profiles.sort();
+        Ok(profiles)
+    }
+
     #[cfg(test)]
     pub(crate) fn from_root_for_tests(root: PathBuf) -> Self {
         Self { root }
     }
 }
 
+/// Settings read from `config.toml`; every field is optional so an absent file is valid.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Per-request timeout such as `"30s"`; `"0"` disables it. Overridden by `--timeout`.
+    #[serde(with = "duration_option", skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<Duration>,
+    /// Open issues idle this long are marked stale in the TUI, e.g. `"14d"`; `"0"` disables it.
+    #[serde(with = "duration_option", skip_serializing_if = "Option::is_none")]
+    pub stale_after: Option<Duration>,
+    /// How long team, state, member, label, and template lookups are served from the response
+    /// cache, e.g. `"10m"`; `"0"` disables the cache.
+    #[serde(with = "duration_option", skip_serializing_if = "Option::is_none")]
+    pub cache_ttl: Option<Duration>,
+    /// Pane layout of the TUI issue view; the TUI writes it back when it changes.
+    pub tui: TuiLayout,
+    /// Per-state WIP limits and age thresholds for the board and `report wip`.
+    #[serde(skip_serializing_if = "BoardLimits::is_empty")]
+    pub board: BoardLimits,
+    /// When mutating commands ask before applying their change.
+    pub confirm: ConfirmPolicy,
+    /// How much one command may spend against the rate limit before it warns or refuses.
+    #[serde(skip_serializing_if = "Budget::is_default")]
+    pub budget: Budget,
+    /// Shell command `issue view --summary` pipes the issue into, e.g. an LLM CLI; it reads
+    /// Markdown on stdin and prints the summary.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summarize_command: Option<String>,
+    /// Program that keeps credentials instead of the files in the config directory, run as
+    /// `<helper> get|store|erase` (see [`HelperCredentialStore`](crate::auth::HelperCredentialStore)).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub credential_helper: Option<String>,
+    /// Name of the team label `issue create --from-junit` puts on the issues it files.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub junit_label: Option<String>,
+    /// Shell command run for each due `linear remind` reminder, e.g. `notify-send`; without it
+    /// reminders are printed to stdout.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reminder_command: Option<String>,
+    /// Team key, name, or id `issue list` and `issue create` use without `--team`; a
+    /// repository's [`LocalConfig`] overrides it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_team: Option<String>,
+}
+
+/// Name of the per-repository settings file, kept at the top of a git checkout.
+pub const LOCAL_CONFIG_FILE: &str = ".linear.toml";
+
+/// Settings from a repository's `.linear.toml`, which win over `config.toml` inside that
+/// checkout.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LocalConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_team: Option<String>,
+}
+
+impl LocalConfig {
+    /// Path of the settings file for the checkout at `repo_root`.
+    pub fn file(repo_root: &Path) -> PathBuf {
+        repo_root.join(LOCAL_CONFIG_FILE)
+    }
+
+    /// Load the checkout's settings, falling back to defaults when it has none.
+    pub fn load(repo_root: &Path) -> Result<Self, ConfigError> {
+        match fs::read_to_string(Self::file(repo_root)) {
+            Ok(raw) => toml::from_str(&raw).map_err(ConfigError::Parse),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(ConfigError::Io(err)),
+        }
+    }
+}
+
+/// Set the top-level string `key` in the TOML file at `path`, keeping its other settings and
+/// creating the file if needed.
+pub fn store_setting(path: &Path, key: &str, value: &str) -> Result<(), ConfigError> {
+    update_file(path, |table| {
+        table.insert(key.into(), toml::Value::String(value.into()));
+        Ok(())
+    })
+}
+
+fn update_file(
+    path: &Path,
+    update: impl FnOnce(&mut toml::Table) -> Result<(), ConfigError>,
+) -> Result<(), ConfigError> {
+    let mut table = match fs::read_to_string(path) {
+        Ok(raw) => raw.parse::<toml::Table>().map_err(ConfigError::Parse)?,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => toml::Table::new(),
+        Err(err) => return Err(ConfigError::Io(err)),
+    };
+    update(&mut table)?;
+    let raw = toml::to_string(&table).map_err(ConfigError::Serialize)?;
+    fs::write(path, raw)?;
+    Ok(())
+}
+
+/// How much a command's change would cost to undo, which decides whether it asks first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Impact {
+    /// Creates or edits something that can be edited back.
+    Mutation,
+    /// Deletes, archives, merges, or changes many things at once.
+    Destructive,
+}
+
+/// The `confirm` setting: which mutating commands prompt `[y/N]` before applying.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ConfirmPolicy {
+    /// Ask only before destructive changes.
+    #[default]
+    DestructiveOnly,
+    /// Never ask.
+    Never,
+    /// Ask before every change.
+    Always,
+}
+
+impl ConfirmPolicy {
+    /// Whether a change of `impact` should be confirmed under this policy.
+    pub fn asks(self, impact: Impact) -> bool {
+        match self {
+            ConfirmPolicy::Never => false,
+            ConfirmPolicy::DestructiveOnly => impact == Impact::Destructive,
+            ConfirmPolicy::Always => true,
+        }
+    }
+}
+
+/// The `[budget]` table: what one command may cost before it warns, or, with `enforce`,
+/// refuses to run without `--force`. A limit of `0` turns that check off.
+///
+/// ```toml
+/// [budget]
+/// requests = 200
+/// complexity = 100000
+/// enforce = true
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Budget {
+    /// Most requests a command should send.
+    pub requests: usize,
+    /// Most complexity points its requests should add up to.
+    pub complexity: f64,
+    /// Refuse commands over budget unless given `--force`, instead of only warning.
+    pub enforce: bool,
+}
+
+impl Default for Budget {
+    fn default() -> Self {
+        Self {
+            requests: 500,
+            complexity: 250_000.0,
+            enforce: false,
+        }
+    }
+}
+
+impl Budget {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// The `[board]` table, keyed by workflow state name (matched ignoring case):
+///
+/// ```toml
+/// [board.wip]
+/// "In Progress" = 5
+///
+/// [board.max_age]
+/// "In Progress" = "7d"
+/// "In Review" = "3d"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BoardLimits {
+    /// Most issues a state should hold at once.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub wip: BTreeMap<String, usize>,
+    /// Longest an issue should sit in a state without changing.
+    #[serde(with = "duration_map", skip_serializing_if = "BTreeMap::is_empty")]
+    pub max_age: BTreeMap<String, Duration>,
+}
+
+impl BoardLimits {
+    pub fn is_empty(&self) -> bool {
+        self.wip.is_empty() && self.max_age.is_empty()
+    }
+
+    /// WIP limit of the state named `state`.
+    pub fn wip_limit(&self, state: &str) -> Option<usize> {
+        lookup(&self.wip, state).copied()
+    }
+
+    /// Age threshold of the state named `state`; `"0"` disables it.
+    pub fn max_age(&self, state: &str) -> Option<Duration> {
+        lookup(&self.max_age, state)
+            .copied()
+            .filter(|age| !age.is_zero())
+    }
+}
+
+fn lookup<'a, T>(map: &'a BTreeMap<String, T>, state: &str) -> Option<&'a T> {
+    map.iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(state))
+        .map(|(_, value)| value)
+}
+
+/// Arrangement of the TUI issue list and detail pane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LayoutPreset {
+    /// The issue list alone.
+    List,
+    /// List above detail.
+    #[default]
+    Stacked,
+    /// List left of detail.
+    SideBySide,
+    /// The detail pane alone, without the sidebar.
+    Zen,
+}
+
+impl LayoutPreset {
+    pub const ALL: [LayoutPreset; 4] = [
+        LayoutPreset::List,
+        LayoutPreset::SideBySide,
+        LayoutPreset::Stacked,
+        LayoutPreset::Zen,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LayoutPreset::List => "list",
+            LayoutPreset::Stacked => "stacked",
+            LayoutPreset::SideBySide => "side-by-side",
+            LayoutPreset::Zen => "zen",
+        }
+    }
+
+    /// The preset after this one in [`LayoutPreset::ALL`], wrapping around.
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|p| *p == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+impl fmt::Display for LayoutPreset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for LayoutPreset {
+    type Err = String;
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "list" | "list-only" => Ok(LayoutPreset::List),
+            "stacked" | "horizontal" => Ok(LayoutPreset::Stacked),
+            "side-by-side" | "side" | "vertical" => Ok(LayoutPreset::SideBySide),
+            "zen" | "detail" => Ok(LayoutPreset::Zen),
+            other => Err(format!(
+                "unknown layout '{other}' (expected list, stacked, side-by-side, or zen)"
+            )),
+        }
+    }
+}
+
+/// The `[tui]` table: layout preset and the issue list's share of each split, in percent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TuiLayout {
+    pub layout: LayoutPreset,
+    /// Share of the height the list takes in the stacked layout.
+    pub stacked_split: u16,
+    /// Share of the width the list takes side by side.
+    pub side_split: u16,
+}
+
+impl TuiLayout {
+    pub const MIN_SPLIT: u16 = 20;
+    pub const MAX_SPLIT: u16 = 80;
+
+    /// The list's share for the current preset, if it has a split.
+    pub fn split(&self) -> Option<u16> {
+        match self.layout {
+            LayoutPreset::Stacked => Some(self.stacked_split),
+            LayoutPreset::SideBySide => Some(self.side_split),
+            LayoutPreset::List | LayoutPreset::Zen => None,
+        }
+    }
+
+    /// Grow (or shrink, for negative `delta`) the list's share of the current split, within
+    /// [`Self::MIN_SPLIT`]..=[`Self::MAX_SPLIT`]. Returns the new share.
+    pub fn resize(&mut self, delta: i16) -> Option<u16> {
+        let split = match self.layout {
+            LayoutPreset::Stacked => &mut self.stacked_split,
+            LayoutPreset::SideBySide => &mut self.side_split,
+            LayoutPreset::List | LayoutPreset::Zen => return None,
+        };
+        *split = split
+            .saturating_add_signed(delta)
+            .clamp(Self::MIN_SPLIT, Self::MAX_SPLIT);
+        Some(*split)
+    }
+
+    /// Restore the default share of the current split.
+    pub fn reset_split(&mut self) {
+        let defaults = Self::default();
+        match self.layout {
+            LayoutPreset::Stacked => self.stacked_split = defaults.stacked_split,
+            LayoutPreset::SideBySide => self.side_split = defaults.side_split,
+            LayoutPreset::List | LayoutPreset::Zen => {}
+        }
+    }
+
+    /// Write this layout as the `[tui]` table of the config file, keeping its other settings.
+    pub fn store(&self, locator: &ConfigLocator) -> Result<(), ConfigError> {
+        update_file(&locator.config_file(), |table| {
+            let value = toml::Value::try_from(self).map_err(ConfigError::Serialize)?;
+            table.insert("tui".into(), value);
+            Ok(())
+        })
+    }
+}
+
+impl Default for TuiLayout {
+    fn default() -> Self {
+        Self {
+            layout: LayoutPreset::default(),
+            stacked_split: 60,
+            side_split: 50,
+        }
+    }
+}
+
+/// Idle time after which the TUI marks an open issue stale unless `stale_after` is set.
+pub const DEFAULT_STALE_AFTER: Duration = Duration::from_secs(30 * 86_400);
+
+/// How long cached lookups are served unless `cache_ttl` is set.
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(5 * 60);
+
+impl Config {
+    /// Stale threshold for the TUI, `None` when disabled.
+    pub fn stale_threshold(&self) -> Option<Duration> {
+        let threshold = self.stale_after.unwrap_or(DEFAULT_STALE_AFTER);
+        (!threshold.is_zero()).then_some(threshold)
+    }
+
+    /// Response cache lifetime, `None` when disabled.
+    pub fn cache_ttl(&self) -> Option<Duration> {
+        let ttl = self.cache_ttl.unwrap_or(DEFAULT_CACHE_TTL);
+        (!ttl.is_zero()).then_some(ttl)
+    }
+
+    /// Load the config file, falling back to defaults when it does not exist.
+    pub fn load(locator: &ConfigLocator) -> Result<Self, ConfigError> {
+        Self::load_from(&locator.config_file())
+    }
+
+    /// Load settings from an explicit path, falling back to defaults when it does not exist.
+    pub fn load_from(path: &Path) -> Result<Self, ConfigError> {
+        match fs::read_to_string(path) {
+            Ok(raw) => toml::from_str(&raw).map_err(ConfigError::Parse),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(ConfigError::Io(err)),
+        }
+    }
+}
+
+/// Parse a duration like `500ms`, `10s`, `2m`, `1h`, or `7d`; a bare number is seconds.
+pub fn parse_duration(raw: &str) -> Result<Duration, String> {
+    let raw = raw.trim();
+    let split = raw
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(raw.len());
+    let (number, unit) = raw.split_at(split);
+    let value: f64 = number
+        .parse()
+        .map_err(|_| format!("invalid duration '{raw}' (expected e.g. 10s, 500ms, 2m)"))?;
+    let seconds = match unit.trim() {
+        "ms" => value / 1000.0,
+        "" | "s" => value,
+        "m" => value * 60.0,
+        "h" => value * 3600.0,
+        "d" => value * 86_400.0,
+        "w" => value * 604_800.0,
+        other => return Err(format!("unknown duration unit '{other}' in '{raw}'")),
+    };
+    Duration::try_from_secs_f64(seconds).map_err(|_| format!("duration '{raw}' is too long"))
+}
+
+/// Render a duration in the shortest unit [`parse_duration`] reads back.
+pub fn format_duration(duration: Duration) -> String {
+    let millis = duration.as_millis();
+    if millis % 1000 != 0 {
+        format!("{millis}ms")
+    } else if millis % 60_000 != 0 || millis == 0 {
+        format!("{}s", millis / 1000)
+    } else {
+        format!("{}m", millis / 60_000)
+    }
+}
+
+mod duration_option {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<Duration>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(duration) => serializer.serialize_str(&super::format_duration(*duration)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Duration>, D::Error> {
+        let raw = Option::<String>::deserialize(deserializer)?;
+        raw.map(|raw| super::parse_duration(&raw).map_err(serde::de::Error::custom))
+            .transpose()
+    }
+}
+
+mod duration_map {
+    use std::collections::BTreeMap;
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        value: &BTreeMap<String, Duration>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(
+            value
+                .iter()
+                .map(|(name, duration)| (name, super::format_duration(*duration))),
+        )
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<BTreeMap<String, Duration>, D::Error> {
+        BTreeMap::<String, String>::deserialize(deserializer)?
+            .into_iter()
+            .map(|(name, raw)| {
+                super::parse_duration(&raw)
+                    .map(|duration| (name, duration))
+                    .map_err(serde::de::Error::custom)
+            })
+            .collect()
+    }
+}
+
 fn set_user_only_permissions(path: &Path) -> Result<(), ConfigError> {
     #[cfg(unix)]
     {
@@ -61,6 +579,10 @@ pub enum ConfigError {
     CreateDir(#[source] std::io::Error),
     #[error("filesystem error: {0}")]
     Io(#[source] std::io::Error),
+    #[error("invalid config file: {0}")]
+    Parse(#[source] toml::de::Error),
+    #[error("failed to write config file: {0}")]
+    Serialize(#[source] toml::ser::Error),
 }
 
 impl From<std::io::Error> for ConfigError {
@@ -83,4 +605,141 @@ mod tests {
         let path = locator.credentials_file("default");
         assert!(path.ends_with("credentials-default.json"));
     }
+
+    #[test]
+    fn profiles_lists_credential_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let locator = ConfigLocator::from_root_for_tests(temp_dir.path().to_path_buf());
+        fs::write(locator.credentials_file("work"), "{}").unwrap();
+        fs::write(locator.credentials_file("default"), "{}").unwrap();
+        fs::write(locator.config_file(), "").unwrap();
+        assert_eq!(locator.profiles().unwrap(), vec!["default", "work"]);
+    }
+
+    #[test]
+    fn missing_config_file_uses_defaults() {
+        let temp_dir = TempDir::new().unwrap();
+        let locator = ConfigLocator::from_root_for_tests(temp_dir.path().to_path_buf());
+        assert!(Config::load(&locator).is_ok());
+        fs::write(locator.config_file(), "not = [valid").unwrap();
+        assert!(matches!(Config::load(&locator), Err(ConfigError::Parse(_))));
+    }
+
+    #[test]
+    fn stores_settings_beside_existing_ones() {
+        let temp_dir = TempDir::new().unwrap();
+        let locator = ConfigLocator::from_root_for_tests(temp_dir.path().to_path_buf());
+        fs::write(locator.config_file(), "junit_label = \"ci\"\n").unwrap();
+        store_setting(&locator.config_file(), "default_team", "ENG").unwrap();
+        let config = Config::load(&locator).unwrap();
+        assert_eq!(config.default_team.as_deref(), Some("ENG"));
+        assert_eq!(config.junit_label.as_deref(), Some("ci"));
+
+        assert!(LocalConfig::load(temp_dir.path())
+            .unwrap()
+            .default_team
+            .is_none());
+        store_setting(&LocalConfig::file(temp_dir.path()), "default_team", "OPS").unwrap();
+        let local = LocalConfig::load(temp_dir.path()).unwrap();
+        assert_eq!(local.default_team.as_deref(), Some("OPS"));
+    }
+
+    #[test]
+    fn reads_timeout_durations() {
+        assert_eq!(parse_duration("10s").unwrap(), Duration::from_secs(10));
+        assert_eq!(parse_duration("250ms").unwrap(), Duration::from_millis(250));
+        assert_eq!(parse_duration("2m").unwrap(), Duration::from_secs(120));
+        assert_eq!(parse_duration("45").unwrap(), Duration::from_secs(45));
+        assert!(parse_duration("10 parsecs").is_err());
+        assert!(parse_duration(".").is_err());
+        assert!(parse_duration("99999999999999999999999").is_err());
+        assert!(parse_duration("99999999999999999w").is_err());
+        assert_eq!(format_duration(Duration::from_secs(90)), "90s");
+        assert_eq!(format_duration(Duration::from_secs(120)), "2m");
+
+        let config: Config = toml::from_str(r#"timeout = "1.5s""#).unwrap();
+        assert_eq!(config.timeout, Some(Duration::from_millis(1500)));
+        assert!(toml::from_str::<Config>(r#"timeout = "soon""#).is_err());
+
+        assert_eq!(
+            Config::default().stale_threshold(),
+            Some(DEFAULT_STALE_AFTER)
+        );
+        let config: Config = toml::from_str(r#"stale_after = "2w""#).unwrap();
+        assert_eq!(
+            config.stale_threshold(),
+            Some(Duration::from_secs(14 * 86_400))
+        );
+        let config: Config = toml::from_str(r#"stale_after = "0""#).unwrap();
+        assert_eq!(config.stale_threshold(), None);
+
+        assert_eq!(Config::default().cache_ttl(), Some(DEFAULT_CACHE_TTL));
+        let config: Config = toml::from_str(r#"cache_ttl = "0""#).unwrap();
+        assert_eq!(config.cache_ttl(), None);
+    }
+
+    #[test]
+    fn reads_board_limits_by_state_name() {
+        let config: Config = toml::from_str(
+            r#"
+            [board.wip]
+            "In Progress" = 3
+
+            [board.max_age]
+            "In Progress" = "7d"
+            "Todo" = "0"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.board.wip_limit("in progress"), Some(3));
+        assert_eq!(config.board.wip_limit("Todo"), None);
+        assert_eq!(
+            config.board.max_age("IN PROGRESS"),
+            Some(Duration::from_secs(7 * 86_400))
+        );
+        assert_eq!(config.board.max_age("Todo"), None);
+        assert!(Config::default().board.is_empty());
+        assert!(toml::from_str::<Config>("[board.max_age]\nTodo = \"later\"").is_err());
+    }
+
+    #[test]
+    fn confirm_policy_decides_which_changes_ask() {
+        let config: Config = toml::from_str("confirm = \"always\"").unwrap();
+        assert_eq!(config.confirm, ConfirmPolicy::Always);
+        assert!(config.confirm.asks(Impact::Mutation));
+
+        let default = Config::default().confirm;
+        assert_eq!(default, ConfirmPolicy::DestructiveOnly);
+        assert!(default.asks(Impact::Destructive));
+        assert!(!default.asks(Impact::Mutation));
+
+        let never: Config = toml::from_str("confirm = \"never\"").unwrap();
+        assert!(!never.confirm.asks(Impact::Destructive));
+        assert!(toml::from_str::<Config>("confirm = \"sometimes\"").is_err());
+    }
+
+    #[test]
+    fn stores_tui_layout_alongside_other_settings() {
+        let temp_dir = TempDir::new().unwrap();
+        let locator = ConfigLocator::from_root_for_tests(temp_dir.path().to_path_buf());
+        assert_eq!(Config::load(&locator).unwrap().tui, TuiLayout::default());
+        fs::write(locator.config_file(), "timeout = \"5s\"\nextra = 1\n").unwrap();
+
+        let mut layout = TuiLayout {
+            layout: "side-by-side".parse().unwrap(),
+            ..TuiLayout::default()
+        };
+        assert_eq!(layout.resize(-45), Some(TuiLayout::MIN_SPLIT));
+        layout.store(&locator).unwrap();
+
+        let raw = fs::read_to_string(locator.config_file()).unwrap();
+        assert!(raw.contains("extra = 1"), "{raw}");
+        let config = Config::load(&locator).unwrap();
+        assert_eq!(config.timeout, Some(Duration::from_secs(5)));
+        assert_eq!(config.tui.layout, LayoutPreset::SideBySide);
+        assert_eq!(config.tui.split(), Some(TuiLayout::MIN_SPLIT));
+        assert_eq!(config.tui.stacked_split, 60);
+        assert_eq!(LayoutPreset::Zen.next(), LayoutPreset::List);
+        assert!("grid".parse::<LayoutPreset>().is_err());
+    }
 }
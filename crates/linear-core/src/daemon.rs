@@ -0,0 +1,325 @@
+//! Wire protocol of `linear daemon`: JSON-RPC 2.0 requests and responses, one JSON object per
+//! line, over a unix socket in the config directory. The daemon keeps an authenticated client
+//! and a short-lived response cache; [`DaemonClient`] is how the CLI reaches it, and editor
+//! integrations can speak the same protocol directly.
+//!
+//! A connection that calls `subscribe` is also sent [`ISSUES_CHANGED`] notifications (requests
+//! without an `id`) whenever the daemon sees issues change, which is how the TUI refreshes
+//! without polling.
+
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use thiserror::Error;
+
+use crate::error::ErrorKind;
+
+/// The `jsonrpc` member of every request and response.
+pub const JSONRPC_VERSION: &str = "2.0";
+
+/// Notification sent to subscribed connections; its params are an [`IssuesChanged`].
+pub const ISSUES_CHANGED: &str = "issuesChanged";
+
+/// The line was not valid JSON.
+pub const PARSE_ERROR: i64 = -32700;
+/// The JSON was not a request object.
+pub const INVALID_REQUEST: i64 = -32600;
+pub const METHOD_NOT_FOUND: i64 = -32601;
+pub const INVALID_PARAMS: i64 = -32602;
+/// The method ran and failed; `data.kind` says how, as in the CLI's JSON errors.
+pub const REQUEST_FAILED: i64 = -32000;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RpcRequest {
+    pub jsonrpc: String,
+    /// Echoed in the response; a request without one is a notification and gets none.
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    pub id: Value,
+    pub method: String,
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    pub params: Value,
+}
+
+impl RpcRequest {
+    pub fn new(id: u64, method: &str, params: Value) -> Self {
+        Self {
+            jsonrpc: JSONRPC_VERSION.into(),
+            id: json!(id),
+            method: method.to_owned(),
+            params,
+        }
+    }
+
+    /// A request without an `id`, which is never answered.
+    pub fn notification(method: &str, params: Value) -> Self {
+        Self {
+            id: Value::Null,
+            ..Self::new(0, method, params)
+        }
+    }
+}
+
+/// Params of [`ISSUES_CHANGED`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IssuesChanged {
+    pub issues: Vec<ChangedIssue>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangedIssue {
+    pub id: String,
+    pub identifier: String,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RpcResponse {
+    pub jsonrpc: String,
+    pub id: Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<RpcError>,
+}
+
+impl RpcResponse {
+    pub fn success(id: Value, result: Value) -> Self {
+        Self {
+            jsonrpc: JSONRPC_VERSION.into(),
+            id,
+            result: Some(result),
+            error: None,
+        }
+    }
+
+    pub fn failure(id: Value, error: RpcError) -> Self {
+        Self {
+            jsonrpc: JSONRPC_VERSION.into(),
+            id,
+            result: None,
+            error: Some(error),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Error, Serialize, Deserialize)]
+#[error("{message}")]
+pub struct RpcError {
+    pub code: i64,
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+}
+
+impl RpcError {
+    pub fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            data: None,
+        }
+    }
+
+    /// A method that failed the way `kind` describes.
+    pub fn failed(kind: ErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            data: Some(json!({ "kind": kind.as_str() })),
+            ..Self::new(REQUEST_FAILED, message)
+        }
+    }
+
+    /// The failure's kind, for the exit code; protocol errors count as validation errors.
+    pub fn kind(&self) -> ErrorKind {
+        self.data
+            .as_ref()
+            .and_then(|data| data.get("kind"))
+            .and_then(Value::as_str)
+            .and_then(ErrorKind::from_name)
+            .unwrap_or(if self.code == REQUEST_FAILED {
+                ErrorKind::Internal
+            } else {
+                ErrorKind::Validation
+            })
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum DaemonError {
+    #[error("daemon connection failed: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("unexpected daemon response: {0}")]
+    Protocol(String),
+    #[error(transparent)]
+    Rpc(#[from] RpcError),
+}
+
+#[cfg(unix)]
+pub use client::DaemonClient;
+
+#[cfg(unix)]
+mod client {
+    use std::path::Path;
+
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::unix::{OwnedReadHalf, OwnedWriteHalf};
+    use tokio::net::UnixStream;
+
+    use super::*;
+
+    /// One connection to a running daemon; calls on it are answered in order.
+    pub struct DaemonClient {
+        reader: BufReader<OwnedReadHalf>,
+        writer: OwnedWriteHalf,
+        next_id: u64,
+    }
+
+    impl DaemonClient {
+        /// Connect to the daemon listening on `socket`; fails at once when none is.
+        pub async fn connect(socket: &Path) -> Result<Self, DaemonError> {
+            let (reader, writer) = UnixStream::connect(socket).await?.into_split();
+            Ok(Self {
+                reader: BufReader::new(reader),
+                writer,
+                next_id: 1,
+            })
+        }
+
+        /// Call `method` and decode its result.
+        pub async fn call<T: DeserializeOwned>(
+            &mut self,
+            method: &str,
+            params: Value,
+        ) -> Result<T, DaemonError> {
+            let id = self.next_id;
+            self.next_id += 1;
+            let mut line = serde_json::to_string(&RpcRequest::new(id, method, params))
+                .map_err(|err| DaemonError::Protocol(err.to_string()))?;
+            line.push('\n');
+            self.writer.write_all(line.as_bytes()).await?;
+
+            let mut reply = String::new();
+            if self.reader.read_line(&mut reply).await? == 0 {
+                return Err(DaemonError::Protocol("connection closed".into()));
+            }
+            let response: RpcResponse = serde_json::from_str(&reply)
+                .map_err(|err| DaemonError::Protocol(err.to_string()))?;
+            if response.id != json!(id) {
+                return Err(DaemonError::Protocol(format!(
+                    "response to request {} instead of {id}",
+                    response.id
+                )));
+            }
+            if let Some(error) = response.error {
+                return Err(error.into());
+            }
+            serde_json::from_value(response.result.unwrap_or(Value::Null))
+                .map_err(|err| DaemonError::Protocol(err.to_string()))
+        }
+
+        /// Wait for the next notification on a connection that called `subscribe`; `None`
+        /// once the daemon hangs up. Make no further calls on it: their responses would be
+        /// read here.
+        pub async fn notification(&mut self) -> Result<Option<RpcRequest>, DaemonError> {
+            let mut line = String::new();
+            if self.reader.read_line(&mut line).await? == 0 {
+                return Ok(None);
+            }
+            serde_json::from_str(&line)
+                .map(Some)
+                .map_err(|err| DaemonError::Protocol(err.to_string()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn responses_carry_a_result_or_an_error() {
+        let ok =
+            serde_json::to_value(RpcResponse::success(json!(1), json!({"pong": true}))).unwrap();
+        assert_eq!(
+            ok,
+            json!({"jsonrpc": "2.0", "id": 1, "result": {"pong": true}})
+        );
+
+        let failed = RpcResponse::failure(
+            json!(2),
+            RpcError::failed(ErrorKind::NotFound, "issue ENG-9 not found"),
+        );
+        let line = serde_json::to_string(&failed).unwrap();
+        let parsed: RpcResponse = serde_json::from_str(&line).unwrap();
+        assert_eq!(parsed.error.as_ref().unwrap().kind(), ErrorKind::NotFound);
+        assert_eq!(
+            RpcError::new(METHOD_NOT_FOUND, "no method 'frob'").kind(),
+            ErrorKind::Validation
+        );
+
+        let request: RpcRequest =
+            serde_json::from_str(r#"{"jsonrpc":"2.0","method":"ping"}"#).unwrap();
+        assert_eq!(request.id, Value::Null);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn client_calls_over_a_socket() {
+        use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+        use tokio::net::UnixListener;
+
+        let dir = tempfile::tempdir().unwrap();
+        let socket = dir.path().join("daemon.sock");
+        let listener = UnixListener::bind(&socket).unwrap();
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let (reader, mut writer) = stream.into_split();
+            let mut lines = BufReader::new(reader).lines();
+            while let Some(line) = lines.next_line().await.unwrap() {
+                let request: RpcRequest = serde_json::from_str(&line).unwrap();
+                let subscribed = request.method == "subscribe";
+                let response = match request.method.as_str() {
+                    "echo" => RpcResponse::success(request.id, request.params),
+                    "subscribe" => RpcResponse::success(request.id, json!(true)),
+                    _ => RpcResponse::failure(
+                        request.id,
+                        RpcError::new(METHOD_NOT_FOUND, "no such method"),
+                    ),
+                };
+                let mut out = serde_json::to_string(&response).unwrap();
+                out.push('\n');
+                if subscribed {
+                    let changed = RpcRequest::notification(
+                        ISSUES_CHANGED,
+                        json!({ "issues": [{
+                            "id": "issue-1",
+                            "identifier": "ENG-1",
+                            "updatedAt": "2026-10-15T09:00:00Z"
+                        }] }),
+                    );
+                    out.push_str(&serde_json::to_string(&changed).unwrap());
+                    out.push('\n');
+                }
+                writer.write_all(out.as_bytes()).await.unwrap();
+            }
+        });
+
+        let mut client = DaemonClient::connect(&socket).await.unwrap();
+        let echoed: Vec<String> = client.call("echo", json!(["ENG-1"])).await.unwrap();
+        assert_eq!(echoed, ["ENG-1"]);
+        let missing = client.call::<Value>("frob", Value::Null).await.unwrap_err();
+        assert!(matches!(missing, DaemonError::Rpc(error) if error.code == METHOD_NOT_FOUND));
+
+        assert!(client.call::<bool>("subscribe", Value::Null).await.unwrap());
+        let notification = client.notification().await.unwrap().unwrap();
+        assert_eq!(notification.method, ISSUES_CHANGED);
+        assert_eq!(notification.id, Value::Null);
+        let changed: IssuesChanged = serde_json::from_value(notification.params).unwrap();
+        assert_eq!(changed.issues[0].identifier, "ENG-1");
+
+        assert!(DaemonClient::connect(&dir.path().join("absent.sock"))
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,139 @@
+//! Line-based unified diffs for showing what an edit changes in a text field.
+
+/// Lines of unchanged text kept around each change.
+pub const DEFAULT_CONTEXT: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// Unified diff of `old` against `new` under `--- label`/`+++ label` headers, or `None`
+/// when the texts have the same lines.
+pub fn unified(label: &str, old: &str, new: &str, context: usize) -> Option<String> {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    let ops = edit_script(&old_lines, &new_lines);
+    if ops.iter().all(|op| *op == Op::Equal) {
+        return None;
+    }
+
+    // Position of each op in the old and new texts.
+    let mut positions = Vec::with_capacity(ops.len());
+    let (mut at_old, mut at_new) = (0, 0);
+    for op in &ops {
+        positions.push((at_old, at_new));
+        match op {
+            Op::Equal => {
+                at_old += 1;
+                at_new += 1;
+            }
+            Op::Delete => at_old += 1,
+            Op::Insert => at_new += 1,
+        }
+    }
+
+    let mut out = format!("--- {label}\n+++ {label}\n");
+    let changes: Vec<usize> = (0..ops.len()).filter(|&i| ops[i] != Op::Equal).collect();
+    let mut index = 0;
+    while index < changes.len() {
+        let start = changes[index].saturating_sub(context);
+        let mut end = changes[index];
+        while index + 1 < changes.len() && changes[index + 1] <= end + 2 * context + 1 {
+            index += 1;
+            end = changes[index];
+        }
+        let end = (end + context + 1).min(ops.len());
+        index += 1;
+
+        let hunk = start..end;
+        let old_count = hunk.clone().filter(|&i| ops[i] != Op::Insert).count();
+        let new_count = hunk.clone().filter(|&i| ops[i] != Op::Delete).count();
+        let (old_start, new_start) = positions[start];
+        out.push_str(&format!(
+            "@@ -{} +{} @@\n",
+            range(old_start, old_count),
+            range(new_start, new_count)
+        ));
+        for i in hunk {
+            let (at_old, at_new) = positions[i];
+            match ops[i] {
+                Op::Equal => out.push_str(&format!(" {}\n", old_lines[at_old])),
+                Op::Delete => out.push_str(&format!("-{}\n", old_lines[at_old])),
+                Op::Insert => out.push_str(&format!("+{}\n", new_lines[at_new])),
+            }
+        }
+    }
+    Some(out)
+}
+
+/// `start,count` in unified-diff notation, 1-based; empty ranges name the line before.
+fn range(start: usize, count: usize) -> String {
+    match count {
+        0 => format!("{start},0"),
+        1 => format!("{}", start + 1),
+        _ => format!("{},{}", start + 1, count),
+    }
+}
+
+/// Shortest edit script via a longest-common-subsequence table, deletions before insertions.
+fn edit_script(old: &[&str], new: &[&str]) -> Vec<Op> {
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+    let mut ops = Vec::with_capacity(old.len() + new.len());
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            ops.push(Op::Equal);
+            i += 1;
+            j += 1;
+        } else if j == new.len() || (i < old.len() && lcs[i + 1][j] >= lcs[i][j + 1]) {
+            ops.push(Op::Delete);
+            i += 1;
+        } else {
+            ops.push(Op::Insert);
+            j += 1;
+        }
+    }
+    ops
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_hunks_with_context() {
+        let old = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\n";
+        let new = "a\nB\nc\nd\ne\nf\ng\nh\ni\nj\nk\n";
+        assert_eq!(
+            unified("description", old, new, 1).unwrap(),
+            "--- description\n+++ description\n\
+             @@ -1,3 +1,3 @@\n a\n-b\n+B\n c\n\
+             @@ -10 +10,2 @@\n j\n+k\n"
+        );
+        assert_eq!(
+            unified("title", "Fix login", "Fix login flow", DEFAULT_CONTEXT).unwrap(),
+            "--- title\n+++ title\n@@ -1 +1 @@\n-Fix login\n+Fix login flow\n"
+        );
+        assert_eq!(
+            unified("description", "", "New text", DEFAULT_CONTEXT).unwrap(),
+            "--- description\n+++ description\n@@ -0,0 +1 @@\n+New text\n"
+        );
+    }
+
+    #[test]
+    fn unchanged_text_has_no_diff() {
+        assert_eq!(unified("title", "same\n", "same", DEFAULT_CONTEXT), None);
+    }
+}
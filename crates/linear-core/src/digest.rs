@@ -0,0 +1,215 @@
+//! `linear report digest`: an activity feed regrouped per issue into an email-style report,
+//! with comments cut to snippets, for cron jobs that pipe it into mail.
+
+use std::fmt::{Display, Write};
+
+use chrono::{DateTime, TimeZone, Utc};
+use serde::Serialize;
+
+use crate::services::activity::{ActivityEvent, ActivityFeed, ActivityKind};
+
+/// Comments longer than this many characters are cut.
+const SNIPPET_CHARS: usize = 160;
+
+/// What changed on one issue during the window.
+#[derive(Debug, Clone, Serialize)]
+pub struct IssueDigest {
+    pub issue: String,
+    pub title: String,
+    pub url: Option<String>,
+    /// Oldest first; comment bodies are already [`snippet`]s.
+    pub changes: Vec<ActivityEvent>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Digest {
+    pub since: DateTime<Utc>,
+    /// Most recently changed first.
+    pub issues: Vec<IssueDigest>,
+    /// More updated issues matched than were scanned.
+    pub truncated: bool,
+}
+
+impl Digest {
+    pub fn from_feed(feed: ActivityFeed) -> Self {
+        let mut issues: Vec<IssueDigest> = Vec::new();
+        for mut event in feed.events {
+            if let ActivityKind::Commented { body } = &mut event.kind {
+                *body = snippet(body);
+            }
+            match issues.iter_mut().find(|digest| digest.issue == event.issue) {
+                Some(digest) => digest.changes.push(event),
+                None => issues.push(IssueDigest {
+                    issue: event.issue.clone(),
+                    title: event.title.clone(),
+                    url: event.url.clone(),
+                    changes: vec![event],
+                }),
+            }
+        }
+        issues.sort_by_key(|digest| std::cmp::Reverse(digest.changes.last().map(|e| e.at)));
+        Self {
+            since: feed.since,
+            issues,
+            truncated: feed.truncated,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Plain text for a mail body, times shown in `tz`.
+    pub fn text<Tz: TimeZone>(&self, tz: &Tz) -> String
+    where
+        Tz::Offset: Display,
+    {
+        let mut out = self.heading(tz);
+        for digest in &self.issues {
+            let _ = write!(out, "\n{}  {}\n", digest.issue, digest.title);
+            if let Some(url) = &digest.url {
+                let _ = writeln!(out, "{url}");
+            }
+            for event in &digest.changes {
+                let at = event.at.with_timezone(tz).format("%m-%d %H:%M");
+                let _ = writeln!(out, "  {at}  {}", describe(event, "\"", "\""));
+            }
+        }
+        out
+    }
+
+    /// Markdown with a section per issue, times shown in `tz`.
+    pub fn markdown<Tz: TimeZone>(&self, tz: &Tz) -> String
+    where
+        Tz::Offset: Display,
+    {
+        let mut out = format!("# {}", self.heading(tz));
+        for digest in &self.issues {
+            let title = digest.title.replace(['[', ']'], "");
+            match &digest.url {
+                Some(url) => {
+                    let _ = write!(out, "\n## [{} {}]({url})\n\n", digest.issue, title);
+                }
+                None => {
+                    let _ = write!(out, "\n## {} {}\n\n", digest.issue, title);
+                }
+            }
+            for event in &digest.changes {
+                let at = event.at.with_timezone(tz).format("%m-%d %H:%M");
+                let _ = writeln!(out, "- {at} {}", describe(event, "“", "”"));
+            }
+        }
+        out
+    }
+
+    fn heading<Tz: TimeZone>(&self, tz: &Tz) -> String
+    where
+        Tz::Offset: Display,
+    {
+        let since = self.since.with_timezone(tz).format("%Y-%m-%d %H:%M");
+        match self.issues.len() {
+            1 => format!("1 issue changed since {since}\n"),
+            count => format!("{count} issues changed since {since}\n"),
+        }
+    }
+}
+
+fn describe(event: &ActivityEvent, open_quote: &str, close_quote: &str) -> String {
+    let actor = event.actor.as_deref().unwrap_or("someone");
+    match &event.kind {
+        ActivityKind::Created => format!("created by {actor}"),
+        ActivityKind::StateChanged { from, to } => format!("{from} → {to} ({actor})"),
+        ActivityKind::Commented { body } => {
+            format!("{actor} commented: {open_quote}{body}{close_quote}")
+        }
+        ActivityKind::LabelAdded { label } => format!("{actor} added label {label}"),
+        ActivityKind::Stale { days } => format!("not updated for {days} days"),
+    }
+}
+
+/// A comment on one line: whitespace runs collapsed and cut after [`SNIPPET_CHARS`].
+pub fn snippet(body: &str) -> String {
+    let line = body.split_whitespace().collect::<Vec<_>>().join(" ");
+    if line.chars().count() <= SNIPPET_CHARS {
+        return line;
+    }
+    let cut: String = line.chars().take(SNIPPET_CHARS - 1).collect();
+    format!("{}…", cut.trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(at: &str, issue: &str, actor: &str, kind: ActivityKind) -> ActivityEvent {
+        ActivityEvent {
+            at: DateTime::parse_from_rfc3339(at)
+                .unwrap()
+                .with_timezone(&Utc),
+            issue: issue.into(),
+            title: format!("Title of {issue}"),
+            url: Some(format!("https://linear.app/acme/issue/{issue}")),
+            team: Some("ENG".into()),
+            priority: None,
+            actor: Some(actor.into()),
+            kind,
+        }
+    }
+
+    #[test]
+    fn groups_changes_per_issue_newest_issue_first() {
+        let feed = ActivityFeed {
+            since: DateTime::parse_from_rfc3339("2024-05-01T00:00:00Z")
+                .unwrap()
+                .with_timezone(&Utc),
+            events: vec![
+                event(
+                    "2024-05-01T09:00:00Z",
+                    "ENG-1",
+                    "ada",
+                    ActivityKind::StateChanged {
+                        from: "Todo".into(),
+                        to: "In Progress".into(),
+                    },
+                ),
+                event(
+                    "2024-05-01T10:00:00Z",
+                    "ENG-2",
+                    "grace",
+                    ActivityKind::Commented {
+                        body: "Seen again\n\n  on   staging".into(),
+                    },
+                ),
+                event(
+                    "2024-05-01T11:00:00Z",
+                    "ENG-1",
+                    "ada",
+                    ActivityKind::Commented {
+                        body: "x".repeat(200),
+                    },
+                ),
+            ],
+            truncated: false,
+        };
+        let digest = Digest::from_feed(feed);
+        assert_eq!(digest.issues.len(), 2);
+        assert_eq!(digest.issues[0].issue, "ENG-1");
+        assert_eq!(digest.issues[0].changes.len(), 2);
+
+        let text = digest.text(&Utc);
+        assert!(text.starts_with("2 issues changed since 2024-05-01 00:00\n"));
+        assert!(text.contains(
+            "\nENG-1  Title of ENG-1\nhttps://linear.app/acme/issue/ENG-1\n\
+             \x20 05-01 09:00  Todo → In Progress (ada)\n"
+        ));
+        assert!(text.contains("  05-01 10:00  grace commented: \"Seen again on staging\"\n"));
+        assert!(text.contains(&format!("\"{}…\"", "x".repeat(SNIPPET_CHARS - 1))));
+
+        let markdown = digest.markdown(&Utc);
+        assert!(markdown.starts_with("# 2 issues changed since"));
+        assert!(markdown.contains(
+            "\n## [ENG-2 Title of ENG-2](https://linear.app/acme/issue/ENG-2)\n\n\
+             - 05-01 10:00 grace commented: “Seen again on staging”\n"
+        ));
+    }
+}
@@ -0,0 +1,247 @@
+//! Error taxonomy shared by front-ends to pick process exit codes and machine-readable output.
+
+use std::fmt;
+
+use reqwest::StatusCode;
+use thiserror::Error;
+
+use crate::auth::AuthError;
+use crate::config::ConfigError;
+use crate::graphql::{GraphqlError, GraphqlResponseError};
+use crate::notify::NotifyError;
+use crate::services::issues::StateMatchError;
+
+/// Broad failure categories that scripts can branch on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// Anything not covered by a more specific category.
+    Internal,
+    /// Invalid arguments, input rejected by the API, or malformed configuration.
+    Validation,
+    /// Missing, expired, or rejected credentials.
+    Auth,
+    /// The requested entity does not exist or is not visible.
+    NotFound,
+    /// Linear rejected the request because of rate limiting.
+    RateLimited,
+    /// Transport failures, timeouts, and server-side errors.
+    Network,
+    /// The user cancelled the command (Ctrl-C).
+    Interrupted,
+}
+
+impl ErrorKind {
+    /// Documented process exit code for this category.
+    pub fn exit_code(self) -> i32 {
+        match self {
+            ErrorKind::Internal => 1,
+            ErrorKind::Validation => 2,
+            ErrorKind::Auth => 3,
+            ErrorKind::NotFound => 4,
+            ErrorKind::RateLimited => 5,
+            ErrorKind::Network => 6,
+            ErrorKind::Interrupted => 130,
+        }
+    }
+
+    /// Stable identifier used in JSON error envelopes.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ErrorKind::Internal => "internal",
+            ErrorKind::Validation => "validation",
+            ErrorKind::Auth => "auth",
+            ErrorKind::NotFound => "not_found",
+            ErrorKind::RateLimited => "rate_limited",
+            ErrorKind::Network => "network",
+            ErrorKind::Interrupted => "interrupted",
+        }
+    }
+
+    /// The kind [`ErrorKind::as_str`] names, e.g. when read back from a JSON error envelope.
+    pub fn from_name(name: &str) -> Option<Self> {
+        [
+            ErrorKind::Internal,
+            ErrorKind::Validation,
+            ErrorKind::Auth,
+            ErrorKind::NotFound,
+            ErrorKind::RateLimited,
+            ErrorKind::Network,
+            ErrorKind::Interrupted,
+        ]
+        .into_iter()
+        .find(|kind| kind.as_str() == name)
+    }
+
+    /// Classify an error by walking its source chain for the first recognised cause.
+    pub fn classify(err: &anyhow::Error) -> Self {
+        err.chain()
+            .find_map(Self::classify_single)
+            .unwrap_or(ErrorKind::Internal)
+    }
+
+    fn classify_single(err: &(dyn std::error::Error + 'static)) -> Option<Self> {
+        if let Some(err) = err.downcast_ref::<LinearError>() {
+            return Some(err.kind);
+        }
+        if let Some(err) = err.downcast_ref::<GraphqlError>() {
+            return Some(Self::from_graphql(err));
+        }
+        if let Some(err) = err.downcast_ref::<AuthError>() {
+            return Some(Self::from_auth(err));
+        }
+        if let Some(err) = err.downcast_ref::<ConfigError>() {
+            return Some(match err {
+                ConfigError::Parse(_) => ErrorKind::Validation,
+                _ => ErrorKind::Internal,
+            });
+        }
+        if let Some(err) = err.downcast_ref::<NotifyError>() {
+            return Some(match err {
+                NotifyError::Http(_) => ErrorKind::Network,
+                NotifyError::Status { status, .. } => Self::from_status(*status),
+            });
+        }
+        if let Some(err) = err.downcast_ref::<StateMatchError>() {
+            return Some(match err {
+                StateMatchError::Ambiguous { .. } => ErrorKind::Validation,
+                StateMatchError::Unknown { .. } => ErrorKind::NotFound,
+            });
+        }
+        if err.downcast_ref::<reqwest::Error>().is_some() {
+            return Some(ErrorKind::Network);
+        }
+        None
+    }
+
+    fn from_graphql(err: &GraphqlError) -> Self {
+        match err {
+            GraphqlError::Http(_) | GraphqlError::Timeout(_) => ErrorKind::Network,
+            GraphqlError::HttpStatus { status, .. } => Self::from_status(*status),
+            GraphqlError::InvalidEndpoint(_) => ErrorKind::Validation,
+            GraphqlError::ResponseErrors { errors, .. } => Self::from_response_errors(errors),
+            GraphqlError::OperationFailed(_) => ErrorKind::Validation,
+            GraphqlError::Deserialize(_) => ErrorKind::Internal,
+            GraphqlError::MissingViewer => ErrorKind::Auth,
+            GraphqlError::NotFound => ErrorKind::NotFound,
+            GraphqlError::DryRun(_) | GraphqlError::Tape(_) => ErrorKind::Internal,
+            GraphqlError::Incomplete { source, .. } => Self::from_graphql(source),
+        }
+    }
+
+    fn from_auth(err: &AuthError) -> Self {
+        match err {
+            AuthError::Http(_) => ErrorKind::Network,
+            AuthError::Config(_) | AuthError::Io(_) | AuthError::Serde(_) => ErrorKind::Internal,
+            AuthError::TokenEndpoint { status, .. } if *status == StatusCode::TOO_MANY_REQUESTS => {
+                ErrorKind::RateLimited
+            }
+            _ => ErrorKind::Auth,
+        }
+    }
+
+    fn from_status(status: StatusCode) -> Self {
+        match status {
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => ErrorKind::Auth,
+            StatusCode::NOT_FOUND => ErrorKind::NotFound,
+            StatusCode::TOO_MANY_REQUESTS => ErrorKind::RateLimited,
+            StatusCode::BAD_REQUEST | StatusCode::UNPROCESSABLE_ENTITY => ErrorKind::Validation,
+            status if status.is_server_error() => ErrorKind::Network,
+            _ => ErrorKind::Internal,
+        }
+    }
+
+    fn from_response_errors(errors: &[GraphqlResponseError]) -> Self {
+        for error in errors {
+            match error.code() {
+                Some("RATELIMITED") => return ErrorKind::RateLimited,
+                Some("AUTHENTICATION_ERROR") | Some("FORBIDDEN") => return ErrorKind::Auth,
+                Some("ENTITY_NOT_FOUND") => return ErrorKind::NotFound,
+                Some("INVALID_INPUT")
+                | Some("BAD_USER_INPUT")
+                | Some("GRAPHQL_VALIDATION_FAILED") => return ErrorKind::Validation,
+                _ => {}
+            }
+        }
+        ErrorKind::Internal
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Error raised by front-ends when they detect a categorised failure themselves.
+#[derive(Debug, Error)]
+#[error("{message}")]
+pub struct LinearError {
+    pub kind: ErrorKind,
+    pub message: String,
+}
+
+impl LinearError {
+    pub fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+        }
+    }
+
+    pub fn auth(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Auth, message)
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::NotFound, message)
+    }
+
+    pub fn validation(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Validation, message)
+    }
+
+    pub fn interrupted() -> Self {
+        Self::new(ErrorKind::Interrupted, "interrupted")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    #[test]
+    fn classifies_through_context() {
+        let err = Err::<(), _>(GraphqlError::HttpStatus {
+            status: StatusCode::TOO_MANY_REQUESTS,
+            body: String::new(),
+        })
+        .context("GraphQL request failed")
+        .unwrap_err();
+        assert_eq!(ErrorKind::classify(&err), ErrorKind::RateLimited);
+        assert_eq!(ErrorKind::classify(&err).exit_code(), 5);
+    }
+
+    #[test]
+    fn classifies_response_error_codes() {
+        let errors: Vec<GraphqlResponseError> = serde_json::from_value(serde_json::json!([
+            { "message": "Entity not found", "extensions": { "code": "ENTITY_NOT_FOUND" } }
+        ]))
+        .unwrap();
+        let err = anyhow::Error::new(GraphqlError::response_errors(
+            "query Issue { issue }",
+            errors,
+        ));
+        assert_eq!(ErrorKind::classify(&err), ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn front_end_errors_keep_their_kind() {
+        let err = anyhow::Error::new(LinearError::validation("bad priority"));
+        assert_eq!(ErrorKind::classify(&err), ErrorKind::Validation);
+        assert_eq!(
+            ErrorKind::classify(&anyhow::anyhow!("boom")),
+            ErrorKind::Internal
+        );
+    }
+}
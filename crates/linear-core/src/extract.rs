@@ -0,0 +1,188 @@
+//! Pull dotted field paths out of JSON output, for `--fields` in the CLI.
+
+use std::str::FromStr;
+
+use serde_json::Value;
+
+/// How extracted values are joined on a line.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Separator {
+    #[default]
+    Tab,
+    Csv,
+}
+
+impl FromStr for Separator {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "tab" | "tsv" => Ok(Separator::Tab),
+            "csv" | "comma" => Ok(Separator::Csv),
+            other => Err(format!("unknown separator '{other}'; expected tab or csv")),
+        }
+    }
+}
+
+/// Ordered list of field paths such as `state.name` or `labels.0.name`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldSelection {
+    paths: Vec<Vec<String>>,
+}
+
+impl FieldSelection {
+    /// Parse a comma-separated list of dotted paths.
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let mut paths = Vec::new();
+        for path in spec
+            .split(',')
+            .map(str::trim)
+            .filter(|path| !path.is_empty())
+        {
+            let segments: Vec<String> = path.split('.').map(str::to_owned).collect();
+            if segments.iter().any(|segment| segment.is_empty()) {
+                return Err(format!("invalid field path '{path}'"));
+            }
+            paths.push(segments);
+        }
+        if paths.is_empty() {
+            return Err("no fields given".into());
+        }
+        Ok(Self { paths })
+    }
+
+    /// One row of values per record in `value`.
+    ///
+    /// Arrays yield a row per element. An object wrapping a single array of objects (such as
+    /// `{"issues": [...], "hasNextPage": true}`) yields a row per element of that array unless
+    /// a path names one of its own fields; any other value is a single record.
+    pub fn rows(&self, value: &Value) -> Vec<Vec<String>> {
+        self.records(value)
+            .into_iter()
+            .map(|record| self.paths.iter().map(|path| lookup(record, path)).collect())
+            .collect()
+    }
+
+    fn records<'a>(&self, value: &'a Value) -> Vec<&'a Value> {
+        match value {
+            Value::Array(items) => items.iter().collect(),
+            Value::Object(map) => {
+                let names_own_field = self.paths.iter().any(|path| map.contains_key(&path[0]));
+                let mut lists = map.values().filter_map(|field| {
+                    field
+                        .as_array()
+                        .filter(|items| items.iter().all(Value::is_object))
+                });
+                match (lists.next(), lists.next()) {
+                    (Some(items), None) if !names_own_field => items.iter().collect(),
+                    _ => vec![value],
+                }
+            }
+            _ => vec![value],
+        }
+    }
+}
+
+/// Join `cells` into one output line.
+pub fn format_row(cells: &[String], separator: Separator) -> String {
+    match separator {
+        Separator::Tab => cells
+            .iter()
+            .map(|cell| cell.replace(['\t', '\n', '\r'], " "))
+            .collect::<Vec<_>>()
+            .join("\t"),
+        Separator::Csv => cells
+            .iter()
+            .map(|cell| {
+                if cell.contains([',', '"', '\n', '\r']) {
+                    format!("\"{}\"", cell.replace('"', "\"\""))
+                } else {
+                    cell.clone()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(","),
+    }
+}
+
+/// Resolve `path` in `value`; a non-numeric segment applied to an array maps over its elements
+/// and joins the results with commas, so `labels.name` lists every label.
+fn lookup(value: &Value, path: &[String]) -> String {
+    let Some((segment, rest)) = path.split_first() else {
+        return scalar(value);
+    };
+    match value {
+        Value::Object(map) => map
+            .get(segment)
+            .map(|field| lookup(field, rest))
+            .unwrap_or_default(),
+        Value::Array(items) => match segment.parse::<usize>() {
+            Ok(index) => items
+                .get(index)
+                .map(|item| lookup(item, rest))
+                .unwrap_or_default(),
+            Err(_) => items
+                .iter()
+                .map(|item| lookup(item, path))
+                .filter(|cell| !cell.is_empty())
+                .collect::<Vec<_>>()
+                .join(","),
+        },
+        _ => String::new(),
+    }
+}
+
+fn scalar(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn extracts_rows_from_wrapped_lists() {
+        let output = json!({
+            "issues": [
+                {"identifier": "ENG-1", "state": {"name": "Todo"}, "assignee": null,
+                 "labels": [{"name": "bug"}, {"name": "ui"}]},
+                {"identifier": "ENG-2", "state": {"name": "Done, really"}, "priority": 2},
+            ],
+            "hasNextPage": false,
+        });
+        let fields =
+            FieldSelection::parse("identifier, state.name,assignee.displayName,labels.name")
+                .unwrap();
+        let rows = fields.rows(&output);
+        assert_eq!(rows[0], ["ENG-1", "Todo", "", "bug,ui"]);
+        assert_eq!(
+            format_row(&rows[1], Separator::Csv),
+            "ENG-2,\"Done, really\",,"
+        );
+        assert_eq!(
+            format_row(&rows[0], Separator::Tab),
+            "ENG-1\tTodo\t\tbug,ui"
+        );
+
+        let own = FieldSelection::parse("hasNextPage,issues.0.identifier").unwrap();
+        assert_eq!(own.rows(&output), vec![vec!["false", "ENG-1"]]);
+        assert_eq!(
+            FieldSelection::parse("priority")
+                .unwrap()
+                .rows(&json!([{"priority": 3}])),
+            vec![vec!["3"]]
+        );
+    }
+
+    #[test]
+    fn rejects_empty_paths() {
+        assert!(FieldSelection::parse(" , ").is_err());
+        assert!(FieldSelection::parse("state..name").is_err());
+        assert_eq!("CSV".parse::<Separator>(), Ok(Separator::Csv));
+    }
+}
@@ -0,0 +1,262 @@
+//! Small skim/fzf-style fuzzy matcher shared by interactive pickers and name resolution.
+//!
+//! [`resolve`] picks the team, state, or label a typed name means: exact and normalized names
+//! first, then prefixes, word prefixes, subsequences, and names within a few typos.
+
+const MATCH: i64 = 16;
+const CONSECUTIVE: i64 = 12;
+const BOUNDARY: i64 = 10;
+const GAP: i64 = 1;
+const MAX_GAP_PENALTY: i64 = 8;
+
+/// Score `candidate` against a whitespace-separated `pattern`.
+///
+/// Every term must appear as a case-insensitive subsequence; returns `None` otherwise. Higher
+/// scores favour consecutive runs and matches at word boundaries. An empty pattern matches
+/// everything with a score of zero.
+pub fn score(pattern: &str, candidate: &str) -> Option<i64> {
+    let candidate: Vec<char> = candidate.chars().collect();
+    let lowered: Vec<char> = candidate
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect();
+    pattern
+        .split_whitespace()
+        .map(|term| score_term(term, &candidate, &lowered))
+        .sum()
+}
+
+fn score_term(term: &str, original: &[char], lowered: &[char]) -> Option<i64> {
+    let needle: Vec<char> = term.chars().flat_map(char::to_lowercase).collect();
+    // Try each occurrence of the first character as a starting point and keep the best run.
+    (0..lowered.len())
+        .filter(|&start| Some(&lowered[start]) == needle.first())
+        .filter_map(|start| score_from(&needle, original, lowered, start))
+        .max()
+}
+
+fn score_from(needle: &[char], original: &[char], lowered: &[char], start: usize) -> Option<i64> {
+    let mut total = 0;
+    let mut position = start;
+    let mut previous: Option<usize> = None;
+    for expected in needle {
+        let found = (position..lowered.len()).find(|&idx| lowered[idx] == *expected)?;
+        total += MATCH;
+        match previous {
+            Some(prev) if found == prev + 1 => total += CONSECUTIVE,
+            Some(prev) => total -= ((found - prev - 1) as i64 * GAP).min(MAX_GAP_PENALTY),
+            None => total -= (found as i64 * GAP).min(MAX_GAP_PENALTY),
+        }
+        if is_boundary(original, found) {
+            total += BOUNDARY;
+        }
+        previous = Some(found);
+        position = found + 1;
+    }
+    Some(total)
+}
+
+fn is_boundary(chars: &[char], idx: usize) -> bool {
+    if idx == 0 {
+        return true;
+    }
+    let prev = chars[idx - 1];
+    let current = chars[idx];
+    !prev.is_alphanumeric() || (prev.is_lowercase() && current.is_uppercase())
+}
+
+/// Rank `items` by fuzzy score (best first, ties keep input order), dropping non-matches.
+pub fn rank<'a, T, F>(pattern: &str, items: &'a [T], key: F) -> Vec<(i64, &'a T)>
+where
+    F: Fn(&T) -> String,
+{
+    let mut ranked: Vec<(i64, &T)> = items
+        .iter()
+        .filter_map(|item| score(pattern, &key(item)).map(|score| (score, item)))
+        .collect();
+    ranked.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+    ranked
+}
+
+/// Candidates scoring within this much of the best are too close to call.
+const AMBIGUITY_MARGIN: f64 = 0.1;
+
+/// What a typed name resolved to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Resolution<T> {
+    Unique(T),
+    /// Several candidates matched about equally well, best first.
+    Ambiguous(Vec<T>),
+    Missing,
+}
+
+impl<T> Resolution<T> {
+    pub fn map<U>(self, f: impl Fn(T) -> U) -> Resolution<U> {
+        match self {
+            Resolution::Unique(item) => Resolution::Unique(f(item)),
+            Resolution::Ambiguous(items) => {
+                Resolution::Ambiguous(items.into_iter().map(f).collect())
+            }
+            Resolution::Missing => Resolution::Missing,
+        }
+    }
+
+    /// The match when it is unique.
+    pub fn unique(self) -> Option<T> {
+        match self {
+            Resolution::Unique(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+
+/// Lowercase words separated by single spaces, so `in-progress` and `In  Progress` compare equal.
+pub fn normalize(text: &str) -> String {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Edits (insertions, deletions, substitutions, and swaps of adjacent characters) that turn
+/// `a` into `b`.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![(0..=b.len()).collect::<Vec<_>>()];
+    for i in 1..=a.len() {
+        let mut row = vec![i; b.len() + 1];
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            row[j] = (rows[i - 1][j] + 1)
+                .min(row[j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                row[j] = row[j].min(rows[i - 2][j - 2] + 1);
+            }
+        }
+        rows.push(row);
+    }
+    rows[a.len()][b.len()]
+}
+
+/// How well `input` names `candidate`, from 1.0 (same normalized name) down; `None` when it
+/// does not match at all.
+pub fn similarity(input: &str, candidate: &str) -> Option<f64> {
+    let (input, candidate) = (normalize(input), normalize(candidate));
+    if input.is_empty() {
+        return None;
+    }
+    if input == candidate {
+        return Some(1.0);
+    }
+    if candidate.starts_with(&input) {
+        return Some(0.9);
+    }
+    if candidate.split(' ').any(|word| word.starts_with(&input)) {
+        return Some(0.8);
+    }
+    let length = input.chars().count();
+    let allowed = match length {
+        0..=4 => 1,
+        5..=8 => 2,
+        _ => 3,
+    };
+    let distance = edit_distance(&input, &candidate);
+    if distance <= allowed {
+        return Some(0.75 - 0.05 * distance as f64);
+    }
+    score(&input, &candidate).map(|_| 0.5)
+}
+
+/// The item `input` names: one whose key equals it (ids, keys, and names compared
+/// case-insensitively), else the clear best by [`similarity`]. Candidates within
+/// [`AMBIGUITY_MARGIN`] of the best are [`Resolution::Ambiguous`].
+pub fn resolve<'a, T, F>(input: &str, items: &'a [T], keys: F) -> Resolution<&'a T>
+where
+    F: Fn(&T) -> Vec<&str>,
+{
+    let input = input.trim();
+    if let Some(item) = items
+        .iter()
+        .find(|item| keys(item).iter().any(|key| key.eq_ignore_ascii_case(input)))
+    {
+        return Resolution::Unique(item);
+    }
+    let mut scored: Vec<(f64, &T)> = items
+        .iter()
+        .filter_map(|item| {
+            keys(item)
+                .iter()
+                .filter_map(|key| similarity(input, key))
+                .reduce(f64::max)
+                .map(|score| (score, item))
+        })
+        .collect();
+    scored.sort_by(|a, b| b.0.total_cmp(&a.0));
+    let Some(&(best, first)) = scored.first() else {
+        return Resolution::Missing;
+    };
+    let close: Vec<&T> = scored
+        .iter()
+        .take_while(|(score, _)| best - score < AMBIGUITY_MARGIN)
+        .map(|(_, item)| *item)
+        .collect();
+    if close.len() == 1 {
+        Resolution::Unique(first)
+    } else {
+        Resolution::Ambiguous(close)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn requires_every_term_as_subsequence() {
+        assert!(score("eng 12", "ENG-123 Fix login").is_some());
+        assert!(score("lgn", "ENG-123 Fix login").is_some());
+        assert!(score("xyz", "ENG-123 Fix login").is_none());
+        assert_eq!(score("", "anything"), Some(0));
+    }
+
+    #[test]
+    fn prefers_consecutive_and_boundary_matches() {
+        let tight = score("login", "Fix login flow").unwrap();
+        let loose = score("login", "Lots of going in").unwrap();
+        assert!(tight > loose);
+
+        let items = ["Catalog cleanup", "Add login page", "Release notes"];
+        let ranked = rank("log", &items, |item| item.to_string());
+        assert_eq!(ranked.len(), 2);
+        assert_eq!(*ranked[0].1, "Add login page");
+    }
+
+    #[test]
+    fn resolves_names_loosely_but_refuses_close_calls() {
+        let states = ["Todo", "In Progress", "In Review", "Done", "Canceled"];
+        let state = |input| resolve(input, &states, |name| vec![*name]);
+        assert_eq!(state("done"), Resolution::Unique(&"Done"));
+        assert_eq!(state("in-progress"), Resolution::Unique(&"In Progress"));
+        assert_eq!(state("prog"), Resolution::Unique(&"In Progress"));
+        assert_eq!(state("Dnoe"), Resolution::Unique(&"Done"));
+        assert_eq!(state("cancelled"), Resolution::Unique(&"Canceled"));
+        assert_eq!(
+            state("in"),
+            Resolution::Ambiguous(vec![&"In Progress", &"In Review"])
+        );
+        assert_eq!(state("blocked"), Resolution::Missing);
+
+        let teams = [("ENG", "Engineering"), ("ENT", "Enterprise")];
+        let team = |input| resolve(input, &teams, |team| vec![team.0, team.1]);
+        assert_eq!(team("eng").unique(), Some(&teams[0]));
+        assert_eq!(team("enterprize").unique(), Some(&teams[1]));
+        assert!(matches!(team("en"), Resolution::Ambiguous(_)));
+
+        assert_eq!(normalize("  In--Progress "), "in progress");
+        assert_eq!(edit_distance("done", "dnoe"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+}
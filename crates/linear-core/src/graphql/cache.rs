@@ -0,0 +1,238 @@
+//! On-disk cache of query responses, so the team, state, and label lookups most commands start
+//! with are answered without a round trip.
+//!
+//! Entries live under `<dir>/<credentials>/<query>-<variables>.json`, hashes of the endpoint and
+//! credentials, the query text, and its variables, so profiles never read each other's entries.
+//! An entry is served as is until the TTL it was stored with runs out; after that, one that
+//! kept an `ETag` or `Last-Modified` header is revalidated with `If-None-Match`/
+//! `If-Modified-Since`. Any mutation drops every entry for its credentials.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+const CACHE_VERSION: u32 = 1;
+
+/// Where one request's response is kept.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct CacheKey {
+    partition: String,
+    request: String,
+}
+
+impl CacheKey {
+    /// Key for a request body (`query` plus `variables`) sent to `endpoint` with `credentials`.
+    pub(crate) fn new(endpoint: &str, credentials: &str, body: &Value) -> Self {
+        let query = body
+            .get("query")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        let variables = body.get("variables").cloned().unwrap_or(Value::Null);
+        Self {
+            partition: hash(&format!("{endpoint}\n{credentials}")),
+            request: format!("{}-{}", hash(query), hash(&variables.to_string())),
+        }
+    }
+}
+
+/// A stored response with the validators the server sent with it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CachedResponse {
+    version: u32,
+    pub(crate) expires_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) etag: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) last_modified: Option<String>,
+    pub(crate) body: String,
+}
+
+impl CachedResponse {
+    /// Whether the server gave a way to check the entry without sending it again.
+    pub(crate) fn revalidatable(&self) -> bool {
+        self.etag.is_some() || self.last_modified.is_some()
+    }
+}
+
+/// What a lookup found.
+#[derive(Debug)]
+pub(crate) enum Lookup {
+    /// Within its TTL; serve it.
+    Fresh(String),
+    /// Past its TTL; serve it only if the server confirms it is unchanged.
+    Stale(CachedResponse),
+    Miss,
+}
+
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+    dir: PathBuf,
+    ttl: Duration,
+    refresh: bool,
+}
+
+impl ResponseCache {
+    pub fn new(dir: impl Into<PathBuf>, ttl: Duration) -> Self {
+        Self {
+            dir: dir.into(),
+            ttl,
+            refresh: false,
+        }
+    }
+
+    /// Fetch every lookup again and store the result, ignoring entries already cached, as
+    /// `linear cache warm` does.
+    pub fn refreshing(mut self) -> Self {
+        self.refresh = true;
+        self
+    }
+
+    /// Cache under the platform cache directory.
+    pub fn in_cache_dir(ttl: Duration) -> Option<Self> {
+        let dirs = ProjectDirs::from("app", "linear", "linear-rs")?;
+        Some(Self::new(dirs.cache_dir().join("responses"), ttl))
+    }
+
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub(crate) fn lookup(&self, key: &CacheKey) -> Lookup {
+        if self.refresh {
+            return Lookup::Miss;
+        }
+        let Some(entry) = fs::read(self.path(key))
+            .ok()
+            .and_then(|raw| serde_json::from_slice::<CachedResponse>(&raw).ok())
+            .filter(|entry| entry.version == CACHE_VERSION)
+        else {
+            return Lookup::Miss;
+        };
+        if Utc::now() < entry.expires_at {
+            Lookup::Fresh(entry.body)
+        } else {
+            Lookup::Stale(entry)
+        }
+    }
+
+    /// Keep `body` under `key`, replacing any earlier entry atomically. Failures are ignored:
+    /// the cache only saves round trips.
+    pub(crate) fn store(
+        &self,
+        key: &CacheKey,
+        etag: Option<String>,
+        last_modified: Option<String>,
+        body: String,
+    ) {
+        let entry = CachedResponse {
+            version: CACHE_VERSION,
+            expires_at: self.expiry(),
+            etag,
+            last_modified,
+            body,
+        };
+        let _ = self.write(key, &entry);
+    }
+
+    /// Restart the TTL of an entry the server confirmed is unchanged.
+    pub(crate) fn refresh(&self, key: &CacheKey, mut entry: CachedResponse) {
+        entry.expires_at = self.expiry();
+        let _ = self.write(key, &entry);
+    }
+
+    fn expiry(&self) -> DateTime<Utc> {
+        Utc::now() + chrono::Duration::from_std(self.ttl).unwrap_or(chrono::Duration::MAX)
+    }
+
+    /// Drop every entry stored for `key`'s endpoint and credentials.
+    pub(crate) fn bust(&self, key: &CacheKey) {
+        let _ = fs::remove_dir_all(self.dir.join(&key.partition));
+    }
+
+    /// Drop every entry for every profile.
+    pub fn clear(&self) -> io::Result<()> {
+        match fs::remove_dir_all(&self.dir) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+            _ => Ok(()),
+        }
+    }
+
+    fn write(&self, key: &CacheKey, entry: &CachedResponse) -> io::Result<()> {
+        let dir = self.dir.join(&key.partition);
+        fs::create_dir_all(&dir)?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&dir, fs::Permissions::from_mode(0o700))?;
+        }
+        let path = self.path(key);
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, serde_json::to_vec(entry)?)?;
+        fs::rename(tmp, path)
+    }
+
+    fn path(&self, key: &CacheKey) -> PathBuf {
+        self.dir
+            .join(&key.partition)
+            .join(format!("{}.json", key.request))
+    }
+}
+
+fn hash(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .take(12)
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn serves_fresh_entries_and_busts_per_credentials() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cache = ResponseCache::new(temp_dir.path(), Duration::from_secs(60));
+        let body = json!({ "query": "query Teams { teams { nodes { id } } }", "variables": {} });
+        let ada = CacheKey::new("https://api.linear.app/graphql", "key-ada", &body);
+        let grace = CacheKey::new("https://api.linear.app/graphql", "key-grace", &body);
+        assert_ne!(ada, grace);
+        assert!(matches!(cache.lookup(&ada), Lookup::Miss));
+
+        cache.store(&ada, Some("\"v1\"".into()), None, "{\"data\":{}}".into());
+        cache.store(&grace, None, None, "{\"data\":{}}".into());
+        let expired = ResponseCache::new(temp_dir.path(), Duration::ZERO);
+        let stale = CacheKey::new("https://api.linear.app/graphql", "key-stale", &body);
+        expired.store(&stale, None, None, "{}".into());
+        assert!(matches!(cache.lookup(&ada), Lookup::Fresh(body) if body == "{\"data\":{}}"));
+
+        cache.bust(&ada);
+        assert!(matches!(cache.lookup(&ada), Lookup::Miss));
+        assert!(matches!(cache.lookup(&grace), Lookup::Fresh(_)));
+
+        match cache.lookup(&stale) {
+            Lookup::Stale(entry) => assert!(!entry.revalidatable()),
+            other => panic!("expected a stale entry, got {other:?}"),
+        }
+        let other_variables = CacheKey::new(
+            "https://api.linear.app/graphql",
+            "key-grace",
+            &json!({ "query": body["query"], "variables": { "id": "x" } }),
+        );
+        assert!(matches!(cache.lookup(&other_variables), Lookup::Miss));
+        assert!(matches!(cache.refreshing().lookup(&grace), Lookup::Miss));
+    }
+}
@@ -1,15 +1,34 @@
-use chrono::{DateTime, Utc};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use reqwest::header::{HeaderMap, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED, LOCATION};
 use reqwest::{Client, StatusCode};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{json, Value};
 use thiserror::Error;
 use url::Url;
 
+use super::cache::{CacheKey, Lookup, ResponseCache};
+use super::document::{self, Operation, OperationKind};
+use super::scheduler::{QueueDepth, RequestPriority, Scheduler};
+use super::schema::{Schema, INTROSPECTION_QUERY};
+use super::stream;
+use super::tape::{SessionTape, Tape};
 use crate::auth::{AuthSession, TokenType};
+use crate::backup::BackupEntity;
+use crate::config::format_duration;
+use crate::http;
+use crate::query::{IssuePage, IssueQuery};
 
 const DEFAULT_ENDPOINT: &str = "https://api.linear.app/graphql";
-const USER_AGENT: &str = "linear-rs/0.1.0";
+const UPLOAD_HOST: &str = "uploads.linear.app";
+/// Largest file [`LinearGraphqlClient::fetch_upload`] reads into memory.
+const MAX_UPLOAD_BYTES: usize = 50 * 1024 * 1024;
+/// Redirects [`LinearGraphqlClient::fetch_upload`] follows before giving up.
+const MAX_UPLOAD_REDIRECTS: usize = 5;
 
 /// Errors returned by the GraphQL client.
 #[derive(Debug, Error)]
@@ -20,8 +39,11 @@ pub enum GraphqlError {
     HttpStatus { status: StatusCode, body: String },
     #[error("invalid GraphQL endpoint: {0}")]
     InvalidEndpoint(#[from] url::ParseError),
-    #[error("GraphQL returned errors: {0:?}")]
-    ResponseErrors(Vec<GraphqlResponseError>),
+    #[error("{operation} failed: {}", describe_errors(.errors))]
+    ResponseErrors {
+        operation: String,
+        errors: Vec<GraphqlResponseError>,
+    },
     #[error("GraphQL operation failed: {0}")]
     OperationFailed(String),
     #[error("failed to deserialize response: {0}")]
@@ -30,16 +52,214 @@ pub enum GraphqlError {
     MissingViewer,
     #[error("requested resource not found")]
     NotFound,
+    /// Returned instead of sending a mutation when the client is in dry-run mode.
+    #[error("dry run: {} was not sent", .0.operation)]
+    DryRun(Box<DryRunRequest>),
+    #[error("session tape error: {0}")]
+    Tape(String),
+    #[error("request timed out after {}", format_duration(*.0))]
+    Timeout(Duration),
+    /// A step of a multi-step operation failed after the earlier steps were applied.
+    #[error("{source}; already done: {}", .completed.join(", "))]
+    Incomplete {
+        completed: Vec<String>,
+        source: Box<GraphqlError>,
+    },
+}
+
+/// Mutation captured by a dry-run client, with credentials redacted.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DryRunRequest {
+    pub endpoint: String,
+    pub authorization: String,
+    pub operation: String,
+    pub query: String,
+    pub variables: Value,
+}
+
+impl GraphqlError {
+    /// Errors Linear reported for the operation defined in `query`.
+    pub fn response_errors(query: &str, errors: Vec<GraphqlResponseError>) -> Self {
+        GraphqlError::ResponseErrors {
+            operation: operation_name(query).to_owned(),
+            errors,
+        }
+    }
+
+    /// Suggested fix for the first reported error that has one.
+    pub fn hint(&self) -> Option<String> {
+        match self {
+            GraphqlError::ResponseErrors { errors, .. } => {
+                errors.iter().find_map(GraphqlResponseError::hint)
+            }
+            GraphqlError::Incomplete { source, .. } => source.hint(),
+            _ => None,
+        }
+    }
+
+    /// OAuth scope the token lacks, when Linear refused the operation for that reason.
+    pub fn missing_scope(&self) -> Option<String> {
+        match self {
+            GraphqlError::ResponseErrors { errors, .. } => {
+                errors.iter().find_map(GraphqlResponseError::missing_scope)
+            }
+            GraphqlError::Incomplete { source, .. } => source.missing_scope(),
+            _ => None,
+        }
+    }
+}
+
+fn describe_errors(errors: &[GraphqlResponseError]) -> String {
+    if errors.is_empty() {
+        return "no error details returned".into();
+    }
+    errors
+        .iter()
+        .map(|error| match error.code() {
+            Some(code) => format!("{} ({})", error.user_message(), code),
+            None => error.user_message().to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Name of the operation defined in a GraphQL document, e.g. `IssueUpdate`.
+fn operation_name(query: &str) -> &str {
+    let query = query.trim_start();
+    let rest = ["query", "mutation", "subscription"]
+        .iter()
+        .find_map(|keyword| query.strip_prefix(keyword))
+        .unwrap_or(query);
+    rest.split(|c: char| c == '(' || c == '{' || c.is_whitespace())
+        .find(|part| !part.is_empty())
+        .unwrap_or("GraphQL request")
 }
 
 pub type GraphqlResult<T> = Result<T, GraphqlError>;
 
 /// Minimal GraphQL client for interacting with Linear.
+///
+/// Clones are cheap and share one [`Connection`], so services built from clones of a client
+/// reuse its pooled connections, metrics, and request queue.
 #[derive(Debug, Clone)]
 pub struct LinearGraphqlClient {
+    connection: Arc<Connection>,
+    priority: RequestPriority,
+    dry_run: bool,
+    tape: Option<Arc<Tape>>,
+    timeout: Option<Duration>,
+    cache: Option<Arc<ResponseCache>>,
+}
+
+/// What every clone of a client shares.
+#[derive(Debug)]
+struct Connection {
     http: Client,
     endpoint: Url,
     auth_header: String,
+    metrics: MetricsHandle,
+    scheduler: Scheduler,
+}
+
+/// Rate-limit headroom reported by Linear on the most recent response.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RateLimitStatus {
+    pub requests_limit: Option<u64>,
+    pub requests_remaining: Option<u64>,
+    pub requests_reset: Option<DateTime<Utc>>,
+    pub complexity_limit: Option<u64>,
+    pub complexity_remaining: Option<u64>,
+    pub complexity_reset: Option<DateTime<Utc>>,
+}
+
+/// Whether the API answered the most recent request.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Connectivity {
+    /// No request has finished yet.
+    #[default]
+    Unknown,
+    Online,
+    /// The last request never reached the API (connection failure or timeout).
+    Offline,
+}
+
+/// What the HTTP layer has observed so far; see [`MetricsHandle`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientMetrics {
+    /// Headroom from the last response that carried rate-limit headers.
+    pub rate_limit: Option<RateLimitStatus>,
+    /// When a request last completed with a success status.
+    pub last_success: Option<DateTime<Utc>>,
+    pub connectivity: Connectivity,
+    /// Requests the scheduler is holding back or running.
+    pub queue: QueueDepth,
+}
+
+/// Metrics shared by a client and all of its clones, updated on every request.
+#[derive(Debug, Clone, Default)]
+pub struct MetricsHandle(Arc<Mutex<ClientMetrics>>);
+
+impl MetricsHandle {
+    /// A copy of the current metrics.
+    pub fn snapshot(&self) -> ClientMetrics {
+        self.0
+            .lock()
+            .map(|metrics| metrics.clone())
+            .unwrap_or_default()
+    }
+
+    /// Change the metrics under the lock, e.g. to move a request through the queue.
+    pub(crate) fn update<T>(&self, change: impl FnOnce(&mut ClientMetrics) -> T) -> T {
+        let mut metrics = self
+            .0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        change(&mut metrics)
+    }
+
+    fn record_response(&self, status: StatusCode, limits: Option<RateLimitStatus>) {
+        if let Ok(mut metrics) = self.0.lock() {
+            metrics.connectivity = Connectivity::Online;
+            if status.is_success() {
+                metrics.last_success = Some(Utc::now());
+            }
+            if limits.is_some() {
+                metrics.rate_limit = limits;
+            }
+        }
+    }
+
+    fn record_unreachable(&self) {
+        if let Ok(mut metrics) = self.0.lock() {
+            metrics.connectivity = Connectivity::Offline;
+        }
+    }
+}
+
+impl RateLimitStatus {
+    fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let number = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse::<u64>().ok())
+        };
+        let status = Self {
+            requests_limit: number("x-ratelimit-requests-limit"),
+            requests_remaining: number("x-ratelimit-requests-remaining"),
+            requests_reset: number("x-ratelimit-requests-reset")
+                .and_then(|millis| DateTime::from_timestamp_millis(millis as i64)),
+            complexity_limit: number("x-ratelimit-complexity-limit"),
+            complexity_remaining: number("x-ratelimit-complexity-remaining"),
+            complexity_reset: number("x-ratelimit-complexity-reset")
+                .and_then(|millis| DateTime::from_timestamp_millis(millis as i64)),
+        };
+        (status != Self::default()).then_some(status)
+    }
 }
 
 impl LinearGraphqlClient {
@@ -55,14 +275,168 @@ impl LinearGraphqlClient {
             TokenType::Bearer => format!("Bearer {}", session.access_token),
             TokenType::ApiKey => session.access_token.clone(),
         };
-        let http = Client::builder().user_agent(USER_AGENT).build()?;
+        let metrics = MetricsHandle::default();
         Ok(Self {
-            http,
-            endpoint,
-            auth_header,
+            connection: Arc::new(Connection {
+                http: http::shared()?,
+                endpoint,
+                auth_header,
+                scheduler: Scheduler::new(metrics.clone()),
+                metrics,
+            }),
+            priority: RequestPriority::Interactive,
+            dry_run: false,
+            tape: None,
+            timeout: None,
+            cache: None,
         })
     }
 
+    /// Capture every request/response pair to `path` (credentials are not written).
+    pub fn with_recording(self, path: &Path) -> GraphqlResult<Self> {
+        Ok(self.with_tape(SessionTape::record(path)?))
+    }
+
+    /// Serve responses from a tape written by [`with_recording`](Self::with_recording)
+    /// instead of contacting the API.
+    pub fn with_replay(self, path: &Path) -> GraphqlResult<Self> {
+        Ok(self.with_tape(SessionTape::replay(path)?))
+    }
+
+    /// Record to, or replay from, a tape other clients may share.
+    pub fn with_tape(mut self, tape: SessionTape) -> Self {
+        self.tape = Some(tape.0);
+        self
+    }
+
+    /// Toggle dry-run mode: queries still execute, but mutations are captured and
+    /// returned as [`GraphqlError::DryRun`] without being sent.
+    pub fn with_dry_run(mut self, enabled: bool) -> Self {
+        self.dry_run = enabled;
+        self
+    }
+
+    /// Fail any request (including reading its body) that takes longer than `timeout`.
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Answer the lookups that rarely change (teams, states, members, labels, templates) from
+    /// `cache` while their entries are fresh. Mutations sent through this client clear the
+    /// entries for its credentials; recording and replay bypass the cache.
+    pub fn with_cache(mut self, cache: Option<ResponseCache>) -> Self {
+        self.cache = cache.map(Arc::new);
+        self
+    }
+
+    /// Schedule this client's requests as `priority`. Clones keep sharing one queue and budget,
+    /// so e.g. a background clone for polling yields to the interactive original.
+    pub fn with_priority(mut self, priority: RequestPriority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Whether mutations are currently suppressed.
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// Rate-limit headers captured from the most recent response, if Linear sent any.
+    pub fn rate_limit(&self) -> Option<RateLimitStatus> {
+        self.connection.metrics.snapshot().rate_limit
+    }
+
+    /// Handle to the metrics this client (and every clone of it) records.
+    pub fn metrics(&self) -> MetricsHandle {
+        self.connection.metrics.clone()
+    }
+
+    /// Download an uploaded file (e.g. an image embedded in a description).
+    ///
+    /// Credentials are only attached over HTTPS to Linear's upload host or the API host itself,
+    /// never to third-party URLs, and a credentialed download fails rather than follow a
+    /// redirect anywhere else. Files over 50 MiB are refused. Replayed sessions have no recorded
+    /// downloads and fail with a tape error.
+    pub async fn fetch_upload(&self, url: &str) -> GraphqlResult<Vec<u8>> {
+        self.download(url, MAX_UPLOAD_BYTES).await
+    }
+
+    async fn download(&self, url: &str, limit: usize) -> GraphqlResult<Vec<u8>> {
+        if self.tape.as_ref().is_some_and(|tape| tape.is_replay()) {
+            return Err(GraphqlError::Tape(
+                "file downloads are not available during replay".into(),
+            ));
+        }
+        let http = http::without_redirects()?;
+        let mut url = Url::parse(url)?;
+        let credentials = self.sends_credentials(&url);
+        for _ in 0..=MAX_UPLOAD_REDIRECTS {
+            let mut request = http.get(url.clone());
+            if credentials {
+                request = request.header("Authorization", &self.connection.auth_header);
+            }
+            let mut response = request.send().await?;
+            let status = response.status();
+            if status.is_redirection() {
+                let location = response
+                    .headers()
+                    .get(LOCATION)
+                    .and_then(|value| value.to_str().ok())
+                    .unwrap_or_default();
+                url = self.redirect_target(&url, location, credentials)?;
+                continue;
+            }
+            if !status.is_success() {
+                let body = response.text().await.unwrap_or_default();
+                return Err(GraphqlError::HttpStatus { status, body });
+            }
+            let too_large = || {
+                GraphqlError::OperationFailed(format!(
+                    "{url} is larger than {} MiB",
+                    limit / (1024 * 1024)
+                ))
+            };
+            if response
+                .content_length()
+                .is_some_and(|length| length > limit as u64)
+            {
+                return Err(too_large());
+            }
+            let mut body = Vec::new();
+            while let Some(chunk) = response.chunk().await? {
+                if body.len() + chunk.len() > limit {
+                    return Err(too_large());
+                }
+                body.extend_from_slice(&chunk);
+            }
+            return Ok(body);
+        }
+        Err(GraphqlError::OperationFailed(format!(
+            "{url} redirected more than {MAX_UPLOAD_REDIRECTS} times"
+        )))
+    }
+
+    /// Whether a download from `url` may carry this client's credentials.
+    fn sends_credentials(&self, url: &Url) -> bool {
+        url.scheme() == "https"
+            && url.host_str().is_some_and(|host| {
+                host == UPLOAD_HOST || Some(host) == self.connection.endpoint.host_str()
+            })
+    }
+
+    /// Where a redirect from `from` to `location` leads, refusing to take credentials off the
+    /// hosts [`sends_credentials`](Self::sends_credentials) trusts.
+    fn redirect_target(&self, from: &Url, location: &str, credentials: bool) -> GraphqlResult<Url> {
+        let target = from.join(location)?;
+        if credentials && !self.sends_credentials(&target) {
+            return Err(GraphqlError::OperationFailed(format!(
+                "refusing to follow the redirect from {from} to {target}"
+            )));
+        }
+        Ok(target)
+    }
+
     /// Fetch the current user (`viewer`) object.
     pub async fn viewer(&self) -> GraphqlResult<Viewer> {
         #[derive(Serialize)]
@@ -91,13 +465,112 @@ impl LinearGraphqlClient {
         let response: GraphqlEnvelope<ViewerEnvelope> = self.post(request).await?;
 
         if let Some(errors) = response.errors {
-            return Err(GraphqlError::ResponseErrors(errors));
+            return Err(GraphqlError::response_errors(QUERY, errors));
         }
 
         let data = response.data.ok_or(GraphqlError::MissingViewer)?;
         Ok(data.viewer)
     }
 
+    /// Fetch the workspace the session belongs to.
+    pub async fn organization(&self) -> GraphqlResult<Organization> {
+        #[derive(Serialize)]
+        struct Request<'a> {
+            query: &'a str,
+        }
+
+        #[derive(Deserialize)]
+        struct OrganizationEnvelope {
+            organization: Organization,
+        }
+
+        const QUERY: &str = r#"
+            query Organization {
+                organization {
+                    id
+                    name
+                    urlKey
+                }
+            }
+        "#;
+
+        let response: GraphqlEnvelope<OrganizationEnvelope> =
+            self.post(Request { query: QUERY }).await?;
+
+        if let Some(errors) = response.errors {
+            return Err(GraphqlError::response_errors(QUERY, errors));
+        }
+
+        Ok(response.data.ok_or(GraphqlError::NotFound)?.organization)
+    }
+
+    /// Fetch the viewer's workspace role and team memberships.
+    pub async fn viewer_permissions(&self) -> GraphqlResult<ViewerPermissions> {
+        #[derive(Serialize)]
+        struct Request<'a> {
+            query: &'a str,
+        }
+
+        #[derive(Deserialize)]
+        struct MembershipConnection {
+            nodes: Vec<TeamMembership>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct ViewerRole {
+            admin: bool,
+            guest: bool,
+            team_memberships: MembershipConnection,
+        }
+
+        #[derive(Deserialize)]
+        struct PermissionsEnvelope {
+            viewer: Option<ViewerRole>,
+            organization: Organization,
+        }
+
+        const QUERY: &str = r#"
+            query ViewerPermissions {
+                viewer {
+                    admin
+                    guest
+                    teamMemberships(first: 250) {
+                        nodes {
+                            owner
+                            team {
+                                id
+                                name
+                                key
+                            }
+                        }
+                    }
+                }
+                organization {
+                    id
+                    name
+                    urlKey
+                }
+            }
+        "#;
+
+        let response: GraphqlEnvelope<PermissionsEnvelope> =
+            self.post(Request { query: QUERY }).await?;
+
+        if let Some(errors) = response.errors {
+            return Err(GraphqlError::response_errors(QUERY, errors));
+        }
+
+        let data = response.data.ok_or(GraphqlError::NotFound)?;
+        let viewer = data.viewer.ok_or(GraphqlError::MissingViewer)?;
+        Ok(ViewerPermissions {
+            organization: data.organization,
+            admin: viewer.admin,
+            guest: viewer.guest,
+            team_memberships: viewer.team_memberships.nodes,
+        })
+    }
+
     /// Fetch all teams accessible to the session.
     pub async fn teams(&self) -> GraphqlResult<Vec<TeamSummary>> {
         #[derive(Serialize)]
@@ -127,10 +600,11 @@ impl LinearGraphqlClient {
             }
         "#;
 
-        let response: GraphqlEnvelope<TeamsEnvelope> = self.post(Request { query: QUERY }).await?;
+        let response: GraphqlEnvelope<TeamsEnvelope> =
+            self.post_cached(Request { query: QUERY }).await?;
 
         if let Some(errors) = response.errors {
-            return Err(GraphqlError::ResponseErrors(errors));
+            return Err(GraphqlError::response_errors(QUERY, errors));
         }
 
         let data = response.data.ok_or(GraphqlError::NotFound)?;
@@ -173,6 +647,8 @@ impl LinearGraphqlClient {
                             id
                             name
                             type
+                            color
+                            description
                         }
                     }
                 }
@@ -180,14 +656,14 @@ impl LinearGraphqlClient {
         "#;
 
         let response: GraphqlEnvelope<WorkflowEnvelope> = self
-            .post(Request {
+            .post_cached(Request {
                 query: QUERY,
                 variables: Variables { team_id },
             })
             .await?;
 
         if let Some(errors) = response.errors {
-            return Err(GraphqlError::ResponseErrors(errors));
+            return Err(GraphqlError::response_errors(QUERY, errors));
         }
 
         let team = response
@@ -198,82 +674,75 @@ impl LinearGraphqlClient {
         Ok(team.states.nodes)
     }
 
-    /// Fetch a list of recent issues.
-    pub async fn list_issues(&self, params: IssueListParams) -> GraphqlResult<IssueListResponse> {
+    /// Members of a team, including deactivated users so old mentions still resolve.
+    pub async fn team_members(&self, team_id: &str) -> GraphqlResult<Vec<TeamMember>> {
         #[derive(Serialize)]
-        struct Variables {
-            first: i64,
-            #[serde(skip_serializing_if = "Option::is_none")]
-            filter: Option<Value>,
-            #[serde(skip_serializing_if = "Option::is_none")]
-            after: Option<String>,
+        struct Variables<'a> {
+            team_id: &'a str,
         }
 
         #[derive(Serialize)]
         struct Request<'a> {
             query: &'a str,
-            variables: Variables,
+            variables: Variables<'a>,
         }
 
         #[derive(Deserialize)]
-        struct IssuesEnvelope {
-            issues: IssueEdgeConnection<IssueSummary>,
+        struct MembersEnvelope {
+            team: Option<TeamMembers>,
+        }
+
+        #[derive(Deserialize)]
+        struct TeamMembers {
+            members: TeamMemberConnection,
+        }
+
+        #[derive(Deserialize)]
+        struct TeamMemberConnection {
+            nodes: Vec<TeamMember>,
         }
 
         const QUERY: &str = r#"
-            query ListIssues($first: Int!, $filter: IssueFilter, $after: String) {
-                issues(first: $first, filter: $filter, orderBy: updatedAt, after: $after) {
-                    edges {
-                        cursor
-                        node {
-                        id
-                        identifier
-                        title
-                        url
-                        priority
-                        createdAt
-                        updatedAt
-                        state { id name type }
-                        assignee { id name displayName }
-                    }
-                    }
-                    pageInfo {
-                        hasNextPage
-                        endCursor
+            query TeamMembers($team_id: String!) {
+                team(id: $team_id) {
+                    members(first: 250) {
+                        nodes {
+                            id
+                            name
+                            displayName
+                            email
+                            url
+                            active
+                        }
                     }
                 }
             }
         "#;
 
-        let response: GraphqlEnvelope<IssuesEnvelope> = self
-            .post(Request {
+        let response: GraphqlEnvelope<MembersEnvelope> = self
+            .post_cached(Request {
                 query: QUERY,
-                variables: Variables {
-                    first: params.first as i64,
-                    filter: params.filter,
-                    after: params.after,
-                },
+                variables: Variables { team_id },
             })
             .await?;
 
         if let Some(errors) = response.errors {
-            return Err(GraphqlError::ResponseErrors(errors));
+            return Err(GraphqlError::response_errors(QUERY, errors));
         }
 
-        let data = response.data.ok_or(GraphqlError::NotFound)?.issues;
-        let nodes = data.edges.into_iter().map(|edge| edge.node).collect();
-        Ok(IssueListResponse {
-            nodes,
-            end_cursor: data.page_info.end_cursor,
-            has_next_page: data.page_info.has_next_page,
-        })
+        let team = response
+            .data
+            .and_then(|payload| payload.team)
+            .ok_or(GraphqlError::NotFound)?;
+
+        Ok(team.members.nodes)
     }
 
-    /// Fetch a single issue by its identifier (e.g. "ENG-123").
-    pub async fn issue_by_key(&self, key: &str) -> GraphqlResult<IssueDetail> {
+    /// A team's cycle, estimation, triage, and default template settings.
+    pub async fn team_settings(&self, team_id: &str) -> GraphqlResult<TeamSettings> {
         #[derive(Serialize)]
         struct Variables<'a> {
-            id: &'a str,
+            team_id: &'a str,
         }
 
         #[derive(Serialize)]
@@ -282,45 +751,534 @@ impl LinearGraphqlClient {
             variables: Variables<'a>,
         }
 
+        #[derive(Deserialize)]
+        struct TeamEnvelope {
+            team: Option<TeamSettings>,
+        }
+
         const QUERY: &str = r#"
-            query IssueByKey($id: String!) {
-                issue(id: $id) {
+            query TeamSettings($team_id: String!) {
+                team(id: $team_id) {
                     id
-                    identifier
-                    title
-                    description
-                    url
-                    priority
-                    createdAt
-                    updatedAt
-                    state { id name type }
-                    assignee { id name displayName }
-                    labels(first: 20) {
-                        nodes { id name color }
-                    }
-                    team { id name key }
-                    comments(last: 20) {
-                        nodes {
-                            id
-                            body
-                            createdAt
-                            updatedAt
-                            user { id name displayName }
-                        }
-                    }
-                    history(last: 50) {
-                        nodes {
-                            __typename
-                            id
-                            createdAt
-                            updatedAt
-                            fromState { id name type }
-                            toState { id name type }
-                            fromAssignee { id name displayName }
-                            toAssignee { id name displayName }
-                            fromPriority
-                            toPriority
-                            fromDueDate
+                    key
+                    name
+                    cyclesEnabled
+                    cycleDuration
+                    cycleCooldownTime
+                    cycleStartDay
+                    upcomingCycleCount
+                    cycleIssueAutoAssignStarted
+                    cycleIssueAutoAssignCompleted
+                    issueEstimationType
+                    issueEstimationAllowZero
+                    issueEstimationExtended
+                    defaultIssueEstimate
+                    triageEnabled
+                    defaultTemplateForMembers { id name }
+                    defaultTemplateForNonMembers { id name }
+                    defaultProjectTemplate { id name }
+                }
+            }
+        "#;
+
+        let response: GraphqlEnvelope<TeamEnvelope> = self
+            .post(Request {
+                query: QUERY,
+                variables: Variables { team_id },
+            })
+            .await?;
+
+        if let Some(errors) = response.errors {
+            return Err(GraphqlError::response_errors(QUERY, errors));
+        }
+
+        response
+            .data
+            .and_then(|payload| payload.team)
+            .ok_or(GraphqlError::NotFound)
+    }
+
+    /// Update a team's settings, returning them as they are afterwards.
+    pub async fn update_team(
+        &self,
+        id: &str,
+        input: TeamUpdateInput,
+    ) -> GraphqlResult<TeamSettings> {
+        #[derive(Serialize)]
+        struct Variables {
+            id: String,
+            input: TeamUpdateInput,
+        }
+
+        #[derive(Serialize)]
+        struct Request<'a> {
+            query: &'a str,
+            variables: Variables,
+        }
+
+        #[derive(Deserialize)]
+        struct TeamUpdateEnvelope {
+            #[serde(rename = "teamUpdate")]
+            team_update: TeamPayload,
+        }
+
+        #[derive(Deserialize)]
+        struct TeamPayload {
+            success: bool,
+            team: Option<TeamSettings>,
+        }
+
+        const MUTATION: &str = r#"
+            mutation TeamUpdate($id: String!, $input: TeamUpdateInput!) {
+                teamUpdate(id: $id, input: $input) {
+                    success
+                    team {
+                        id
+                        key
+                        name
+                        cyclesEnabled
+                        cycleDuration
+                        cycleCooldownTime
+                        cycleStartDay
+                        upcomingCycleCount
+                        cycleIssueAutoAssignStarted
+                        cycleIssueAutoAssignCompleted
+                        issueEstimationType
+                        issueEstimationAllowZero
+                        issueEstimationExtended
+                        defaultIssueEstimate
+                        triageEnabled
+                        defaultTemplateForMembers { id name }
+                        defaultTemplateForNonMembers { id name }
+                        defaultProjectTemplate { id name }
+                    }
+                }
+            }
+        "#;
+
+        let response: GraphqlEnvelope<TeamUpdateEnvelope> = self
+            .post(Request {
+                query: MUTATION,
+                variables: Variables {
+                    id: id.to_owned(),
+                    input,
+                },
+            })
+            .await?;
+
+        if let Some(errors) = response.errors {
+            return Err(GraphqlError::response_errors(MUTATION, errors));
+        }
+
+        let payload = response.data.ok_or(GraphqlError::NotFound)?.team_update;
+        if !payload.success {
+            return Err(GraphqlError::OperationFailed("team update failed".into()));
+        }
+
+        payload.team.ok_or(GraphqlError::NotFound)
+    }
+
+    /// Fetch a list of recent issues.
+    pub async fn list_issues(&self, params: IssueListParams) -> GraphqlResult<IssueListResponse> {
+        #[derive(Serialize)]
+        struct Variables {
+            first: i64,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            filter: Option<Value>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            after: Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            sort: Option<Value>,
+        }
+
+        #[derive(Serialize)]
+        struct Request<'a> {
+            query: &'a str,
+            variables: Variables,
+        }
+
+        #[derive(Deserialize)]
+        struct IssuesEnvelope {
+            issues: IssueEdgeConnection<IssueSummary>,
+        }
+
+        const QUERY: &str = r#"
+            query ListIssues(
+                $first: Int!
+                $filter: IssueFilter
+                $after: String
+                $sort: [IssueSortInput!]
+            ) {
+                issues(
+                    first: $first
+                    filter: $filter
+                    orderBy: updatedAt
+                    sort: $sort
+                    after: $after
+                ) {
+                    edges {
+                        cursor
+                        node {
+                        id
+                        identifier
+                        title
+                        url
+                        branchName
+                        priority
+                        createdAt
+                        updatedAt
+                        state { id name type }
+                        assignee { id name displayName }
+                        project { id name }
+                        labels(first: 20) { nodes { id name color } }
+                    }
+                    }
+                    pageInfo {
+                        hasNextPage
+                        endCursor
+                    }
+                }
+            }
+        "#;
+
+        let response: GraphqlEnvelope<IssuesEnvelope> = self
+            .post(Request {
+                query: QUERY,
+                variables: Variables {
+                    first: params.first as i64,
+                    filter: params.filter,
+                    after: params.after,
+                    sort: params.sort,
+                },
+            })
+            .await?;
+
+        if let Some(errors) = response.errors {
+            return Err(GraphqlError::response_errors(QUERY, errors));
+        }
+
+        let data = response.data.ok_or(GraphqlError::NotFound)?.issues;
+        let nodes = data.edges.into_iter().map(|edge| edge.node).collect();
+        Ok(IssueListResponse {
+            nodes,
+            end_cursor: data.page_info.end_cursor,
+            has_next_page: data.page_info.has_next_page,
+        })
+    }
+
+    /// Run a builder-composed issue query, deserializing each node as `T`.
+    ///
+    /// `T` must match the query's selection; [`IssueFields::SUMMARY`](crate::query::IssueFields)
+    /// pairs with [`IssueSummary`], and `serde_json::Value` accepts any selection.
+    pub async fn query_issues<T: DeserializeOwned + Send + 'static>(
+        &self,
+        query: &IssueQuery,
+    ) -> GraphqlResult<IssuePage<T>> {
+        #[derive(Serialize)]
+        struct Request {
+            query: String,
+            variables: Value,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(bound = "T: DeserializeOwned")]
+        struct IssuesEnvelope<T> {
+            issues: IssueNodeConnection<T>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(bound = "T: DeserializeOwned")]
+        struct IssueNodeConnection<T> {
+            nodes: Vec<T>,
+            #[serde(rename = "pageInfo")]
+            page_info: PageInfo,
+        }
+
+        let document = query.document();
+        let response: GraphqlEnvelope<IssuesEnvelope<T>> = self
+            .post(Request {
+                query: document.clone(),
+                variables: query.variables(),
+            })
+            .await?;
+
+        if let Some(errors) = response.errors {
+            return Err(GraphqlError::response_errors(&document, errors));
+        }
+
+        let data = response.data.ok_or(GraphqlError::NotFound)?.issues;
+        Ok(IssuePage {
+            nodes: data.nodes,
+            end_cursor: data.page_info.end_cursor,
+            has_next_page: data.page_info.has_next_page,
+        })
+    }
+
+    /// One page of raw records for a backup, archived ones included. `updated_since` limits it
+    /// to records updated at or after then.
+    pub async fn backup_page(
+        &self,
+        entity: BackupEntity,
+        first: usize,
+        after: Option<String>,
+        updated_since: Option<DateTime<Utc>>,
+    ) -> GraphqlResult<BackupPage> {
+        #[derive(Serialize)]
+        struct Variables {
+            first: i64,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            after: Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            filter: Option<Value>,
+        }
+
+        #[derive(Serialize)]
+        struct Request<'a> {
+            query: &'a str,
+            variables: Variables,
+        }
+
+        #[derive(Deserialize)]
+        struct Connection {
+            nodes: Vec<Value>,
+            #[serde(rename = "pageInfo")]
+            page_info: PageInfo,
+        }
+
+        const ISSUES: &str = r#"
+            query BackupIssues($first: Int!, $after: String, $filter: IssueFilter) {
+                issues(first: $first, after: $after, filter: $filter, includeArchived: true, orderBy: updatedAt) {
+                    nodes {
+                        id
+                        identifier
+                        number
+                        title
+                        description
+                        priority
+                        estimate
+                        dueDate
+                        url
+                        branchName
+                        createdAt
+                        updatedAt
+                        archivedAt
+                        startedAt
+                        completedAt
+                        canceledAt
+                        team { id key name }
+                        state { id name type }
+                        assignee { id name displayName email }
+                        creator { id name displayName email }
+                        project { id name }
+                        cycle { id number name }
+                        parent { id identifier }
+                        labels { nodes { id name color } }
+                    }
+                    pageInfo { hasNextPage endCursor }
+                }
+            }
+        "#;
+        const PROJECTS: &str = r#"
+            query BackupProjects($first: Int!, $after: String, $filter: ProjectFilter) {
+                projects(first: $first, after: $after, filter: $filter, includeArchived: true, orderBy: { field: updatedAt, direction: DESC }) {
+                    nodes {
+                        id
+                        name
+                        description
+                        content
+                        icon
+                        color
+                        state
+                        startDate
+                        targetDate
+                        url
+                        createdAt
+                        updatedAt
+                        archivedAt
+                        completedAt
+                        canceledAt
+                        lead { id name displayName email }
+                        members { nodes { id name displayName email } }
+                        teams { id key name }
+                        projectMilestones { nodes { id name description targetDate } }
+                    }
+                    pageInfo { hasNextPage endCursor }
+                }
+            }
+        "#;
+        const COMMENTS: &str = r#"
+            query BackupComments($first: Int!, $after: String, $filter: CommentFilter) {
+                comments(first: $first, after: $after, filter: $filter, includeArchived: true, orderBy: updatedAt) {
+                    nodes {
+                        id
+                        body
+                        createdAt
+                        updatedAt
+                        editedAt
+                        archivedAt
+                        user { id name displayName email }
+                        issue { id identifier }
+                        parent { id }
+                    }
+                    pageInfo { hasNextPage endCursor }
+                }
+            }
+        "#;
+        const LABELS: &str = r#"
+            query BackupLabels($first: Int!, $after: String, $filter: IssueLabelFilter) {
+                issueLabels(first: $first, after: $after, filter: $filter, includeArchived: true, orderBy: updatedAt) {
+                    nodes {
+                        id
+                        name
+                        color
+                        description
+                        isGroup
+                        createdAt
+                        updatedAt
+                        archivedAt
+                        team { id key name }
+                        parent { id name }
+                    }
+                    pageInfo { hasNextPage endCursor }
+                }
+            }
+        "#;
+
+        let (query, field) = match entity {
+            BackupEntity::Issues => (ISSUES, "issues"),
+            BackupEntity::Projects => (PROJECTS, "projects"),
+            BackupEntity::Comments => (COMMENTS, "comments"),
+            BackupEntity::Labels => (LABELS, "issueLabels"),
+        };
+        let response: GraphqlEnvelope<Value> = self
+            .post(Request {
+                query,
+                variables: Variables {
+                    first: first as i64,
+                    after,
+                    filter: updated_since
+                        .map(|since| json!({ "updatedAt": { "gte": since.to_rfc3339() } })),
+                },
+            })
+            .await?;
+
+        if let Some(errors) = response.errors {
+            return Err(GraphqlError::response_errors(query, errors));
+        }
+
+        let connection = response
+            .data
+            .and_then(|mut data| data.get_mut(field).map(Value::take))
+            .ok_or(GraphqlError::NotFound)?;
+        let connection: Connection = serde_json::from_value(connection)?;
+        Ok(BackupPage {
+            nodes: connection.nodes,
+            end_cursor: connection.page_info.end_cursor,
+            has_next_page: connection.page_info.has_next_page,
+        })
+    }
+
+    /// Run a hand-written document and return its `data` as untyped JSON.
+    ///
+    /// `operation` picks one operation out of a document that defines several. Under dry-run a
+    /// mutation is returned as [`GraphqlError::DryRun`] like any other.
+    pub async fn execute(
+        &self,
+        document: &str,
+        variables: Value,
+        operation: Option<&str>,
+    ) -> GraphqlResult<Value> {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Request<'a> {
+            query: &'a str,
+            variables: Value,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            operation_name: Option<&'a str>,
+        }
+
+        let response: GraphqlEnvelope<Value> = self
+            .post(Request {
+                query: document,
+                variables,
+                operation_name: operation,
+            })
+            .await?;
+
+        if let Some(errors) = response.errors {
+            let operation = operation
+                .map(str::to_owned)
+                .or_else(|| document::operations(document).into_iter().next()?.name)
+                .unwrap_or_else(|| "GraphQL request".into());
+            return Err(GraphqlError::ResponseErrors { operation, errors });
+        }
+        Ok(response.data.unwrap_or(Value::Null))
+    }
+
+    /// Introspect the schema the endpoint serves.
+    pub async fn schema(&self) -> GraphqlResult<Schema> {
+        let data = self.execute(INTROSPECTION_QUERY, json!({}), None).await?;
+        Ok(Schema::from_introspection(data)?)
+    }
+
+    /// Fetch a single issue by its identifier (e.g. "ENG-123").
+    pub async fn issue_by_key(&self, key: &str) -> GraphqlResult<IssueDetail> {
+        #[derive(Serialize)]
+        struct Variables<'a> {
+            id: &'a str,
+        }
+
+        #[derive(Serialize)]
+        struct Request<'a> {
+            query: &'a str,
+            variables: Variables<'a>,
+        }
+
+        const QUERY: &str = r#"
+            query IssueByKey($id: String!) {
+                issue(id: $id) {
+                    id
+                    identifier
+                    title
+                    description
+                    url
+                    branchName
+                    priority
+                    estimate
+                    dueDate
+                    createdAt
+                    updatedAt
+                    state { id name type }
+                    assignee { id name displayName }
+                    labels(first: 20) {
+                        nodes { id name color }
+                    }
+                    team { id name key }
+                    subscribers(first: 50) {
+                        nodes { id name displayName }
+                    }
+                    comments(last: 20) {
+                        nodes {
+                            id
+                            body
+                            createdAt
+                            updatedAt
+                            user { id name displayName }
+                        }
+                    }
+                    history(last: 50) {
+                        nodes {
+                            __typename
+                            id
+                            createdAt
+                            updatedAt
+                            fromState { id name type }
+                            toState { id name type }
+                            fromAssignee { id name displayName }
+                            toAssignee { id name displayName }
+                            fromPriority
+                            toPriority
+                            fromDueDate
                             toDueDate
                             fromTitle
                             toTitle
@@ -328,83 +1286,1042 @@ impl LinearGraphqlClient {
                             actors { id name displayName }
                         }
                     }
-                    children(first: 50) {
-                        nodes {
+                    children(first: 50) {
+                        nodes {
+                            id
+                            identifier
+                            title
+                            url
+                            priority
+                            createdAt
+                            updatedAt
+                            state { id name type }
+                            assignee { id name displayName }
+                            team { id name key }
+                            children(first: 25) {
+                                nodes {
+                                    id
+                                    identifier
+                                    title
+                                    url
+                                    priority
+                                    createdAt
+                                    updatedAt
+                                    state { id name type }
+                                    assignee { id name displayName }
+                                    team { id name key }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct IssueEnvelope {
+            issue: Option<IssueDetail>,
+        }
+
+        let response: GraphqlEnvelope<IssueEnvelope> = self
+            .post(Request {
+                query: QUERY,
+                variables: Variables { id: key },
+            })
+            .await?;
+
+        if let Some(errors) = response.errors {
+            return Err(GraphqlError::response_errors(QUERY, errors));
+        }
+
+        response
+            .data
+            .and_then(|payload| payload.issue)
+            .ok_or(GraphqlError::NotFound)
+    }
+
+    /// Create a new issue using the Linear GraphQL API.
+    pub async fn create_issue(&self, input: IssueCreateInput) -> GraphqlResult<IssueDetail> {
+        #[derive(Serialize)]
+        struct Variables {
+            input: IssueCreateInput,
+        }
+
+        #[derive(Serialize)]
+        struct Request<'a> {
+            query: &'a str,
+            variables: Variables,
+        }
+
+        #[derive(Deserialize)]
+        struct IssueCreateEnvelope {
+            #[serde(rename = "issueCreate")]
+            issue_create: IssueCreatePayload,
+        }
+
+        #[derive(Deserialize)]
+        struct IssueCreatePayload {
+            success: bool,
+            issue: Option<IssueDetail>,
+            #[serde(rename = "userErrors", default)]
+            user_errors: Vec<ApiUserError>,
+        }
+
+        #[derive(Deserialize)]
+        struct ApiUserError {
+            message: Option<String>,
+        }
+
+        const MUTATION: &str = r#"
+            mutation CreateIssue($input: IssueCreateInput!) {
+                issueCreate(input: $input) {
+                    success
+                    userErrors {
+                        message
+                    }
+                    issue {
+                        id
+                        identifier
+                        title
+                        description
+                        url
+                        priority
+                        estimate
+                        dueDate
+                        createdAt
+                        updatedAt
+                        state { id name type }
+                        assignee { id name displayName }
+                        labels(first: 20) {
+                            nodes { id name color }
+                        }
+                        team { id name key }
+                    }
+                }
+            }
+        "#;
+
+        let response: GraphqlEnvelope<IssueCreateEnvelope> = self
+            .post(Request {
+                query: MUTATION,
+                variables: Variables { input },
+            })
+            .await?;
+
+        if let Some(errors) = response.errors {
+            return Err(GraphqlError::response_errors(MUTATION, errors));
+        }
+
+        let payload = response.data.ok_or(GraphqlError::NotFound)?.issue_create;
+
+        if !payload.success {
+            let message = payload
+                .user_errors
+                .into_iter()
+                .filter_map(|err| err.message)
+                .collect::<Vec<_>>()
+                .join("; ");
+            let message = if message.is_empty() {
+                "unknown error".to_string()
+            } else {
+                message
+            };
+            return Err(GraphqlError::OperationFailed(message));
+        }
+
+        let issue = payload.issue.ok_or(GraphqlError::NotFound)?;
+        Ok(issue)
+    }
+
+    /// Update an issue by id.
+    pub async fn update_issue(
+        &self,
+        id: &str,
+        input: IssueUpdateInput,
+    ) -> GraphqlResult<IssueDetail> {
+        #[derive(Serialize)]
+        struct Variables {
+            id: String,
+            input: IssueUpdateInput,
+        }
+
+        #[derive(Serialize)]
+        struct Request<'a> {
+            query: &'a str,
+            variables: Variables,
+        }
+
+        #[derive(Deserialize)]
+        struct IssueUpdateEnvelope {
+            #[serde(rename = "issueUpdate")]
+            issue_update: IssueUpdatePayload,
+        }
+
+        #[derive(Deserialize)]
+        struct IssueUpdatePayload {
+            success: bool,
+            issue: Option<IssueDetail>,
+            #[serde(rename = "userErrors", default)]
+            user_errors: Vec<ApiUserError>,
+        }
+
+        #[derive(Deserialize)]
+        struct ApiUserError {
+            message: Option<String>,
+        }
+
+        const MUTATION: &str = r#"
+            mutation IssueUpdate($id: String!, $input: IssueUpdateInput!) {
+                issueUpdate(id: $id, input: $input) {
+                    success
+                    userErrors { message }
+                    issue {
+                        id
+                        identifier
+                        title
+                        description
+                        url
+                        priority
+                        estimate
+                        dueDate
+                        createdAt
+                        updatedAt
+                        state { id name type }
+                        assignee { id name displayName }
+                        labels(first: 20) { nodes { id name color } }
+                        team { id name key }
+                    }
+                }
+            }
+        "#;
+
+        let response: GraphqlEnvelope<IssueUpdateEnvelope> = self
+            .post(Request {
+                query: MUTATION,
+                variables: Variables {
+                    id: id.to_owned(),
+                    input,
+                },
+            })
+            .await?;
+
+        if let Some(errors) = response.errors {
+            return Err(GraphqlError::response_errors(MUTATION, errors));
+        }
+
+        let payload = response.data.ok_or(GraphqlError::NotFound)?.issue_update;
+        if !payload.success {
+            let message = payload
+                .user_errors
+                .into_iter()
+                .filter_map(|err| err.message)
+                .collect::<Vec<_>>()
+                .join("; ");
+            return Err(GraphqlError::OperationFailed(if message.is_empty() {
+                "issue update failed".into()
+            } else {
+                message
+            }));
+        }
+
+        payload.issue.ok_or(GraphqlError::NotFound)
+    }
+
+    /// Archive or restore an issue.
+    pub async fn archive_issue(&self, id: &str, archive: bool) -> GraphqlResult<IssueDetail> {
+        #[derive(Serialize)]
+        struct Variables<'a> {
+            id: &'a str,
+            archive: bool,
+        }
+
+        #[derive(Serialize)]
+        struct Request<'a> {
+            query: &'a str,
+            variables: Variables<'a>,
+        }
+
+        #[derive(Deserialize)]
+        struct IssueArchiveEnvelope {
+            #[serde(rename = "issueArchive")]
+            issue_archive: IssueUpdatePayload,
+        }
+
+        #[derive(Deserialize)]
+        struct IssueUpdatePayload {
+            success: bool,
+            issue: Option<IssueDetail>,
+            #[serde(rename = "userErrors", default)]
+            user_errors: Vec<ApiUserError>,
+        }
+
+        #[derive(Deserialize)]
+        struct ApiUserError {
+            message: Option<String>,
+        }
+
+        const MUTATION: &str = r#"
+            mutation IssueArchive($id: String!, $archive: Boolean!) {
+                issueArchive(id: $id, archive: $archive) {
+                    success
+                    userErrors { message }
+                    issue {
+                        id
+                        identifier
+                        title
+                        description
+                        url
+                        priority
+                        estimate
+                        dueDate
+                        createdAt
+                        updatedAt
+                        state { id name type }
+                        assignee { id name displayName }
+                        labels(first: 20) { nodes { id name color } }
+                        team { id name key }
+                    }
+                }
+            }
+        "#;
+
+        let response: GraphqlEnvelope<IssueArchiveEnvelope> = self
+            .post(Request {
+                query: MUTATION,
+                variables: Variables { id, archive },
+            })
+            .await?;
+
+        if let Some(errors) = response.errors {
+            return Err(GraphqlError::response_errors(MUTATION, errors));
+        }
+
+        let payload = response.data.ok_or(GraphqlError::NotFound)?.issue_archive;
+        if !payload.success {
+            let message = payload
+                .user_errors
+                .into_iter()
+                .filter_map(|err| err.message)
+                .collect::<Vec<_>>()
+                .join("; ");
+            return Err(GraphqlError::OperationFailed(if message.is_empty() {
+                "issue archive failed".into()
+            } else {
+                message
+            }));
+        }
+
+        payload.issue.ok_or(GraphqlError::NotFound)
+    }
+
+    /// Delete an issue by id.
+    pub async fn delete_issue(&self, id: &str) -> GraphqlResult<bool> {
+        #[derive(Serialize)]
+        struct Variables<'a> {
+            id: &'a str,
+        }
+
+        #[derive(Serialize)]
+        struct Request<'a> {
+            query: &'a str,
+            variables: Variables<'a>,
+        }
+
+        #[derive(Deserialize)]
+        struct IssueDeleteEnvelope {
+            #[serde(rename = "issueDelete")]
+            issue_delete: IssueDeletePayload,
+        }
+
+        #[derive(Deserialize)]
+        struct IssueDeletePayload {
+            success: bool,
+            #[serde(rename = "userErrors", default)]
+            user_errors: Vec<ApiUserError>,
+        }
+
+        #[derive(Deserialize)]
+        struct ApiUserError {
+            message: Option<String>,
+        }
+
+        const MUTATION: &str = r#"
+            mutation IssueDelete($id: String!) {
+                issueDelete(id: $id) {
+                    success
+                    userErrors { message }
+                }
+            }
+        "#;
+
+        let response: GraphqlEnvelope<IssueDeleteEnvelope> = self
+            .post(Request {
+                query: MUTATION,
+                variables: Variables { id },
+            })
+            .await?;
+
+        if let Some(errors) = response.errors {
+            return Err(GraphqlError::response_errors(MUTATION, errors));
+        }
+
+        let payload = response.data.ok_or(GraphqlError::NotFound)?.issue_delete;
+        if !payload.success {
+            let message = payload
+                .user_errors
+                .into_iter()
+                .filter_map(|err| err.message)
+                .collect::<Vec<_>>()
+                .join("; ");
+            return Err(GraphqlError::OperationFailed(if message.is_empty() {
+                "issue delete failed".into()
+            } else {
+                message
+            }));
+        }
+
+        Ok(true)
+    }
+
+    /// Create a new comment on an issue.
+    pub async fn create_comment(&self, input: CommentCreateInput) -> GraphqlResult<Comment> {
+        #[derive(Serialize)]
+        struct Variables {
+            input: CommentCreateInput,
+        }
+
+        #[derive(Serialize)]
+        struct Request<'a> {
+            query: &'a str,
+            variables: Variables,
+        }
+
+        #[derive(Deserialize)]
+        struct CommentCreateEnvelope {
+            #[serde(rename = "commentCreate")]
+            comment_create: CommentCreatePayload,
+        }
+
+        #[derive(Deserialize)]
+        struct CommentCreatePayload {
+            success: bool,
+            comment: Option<Comment>,
+            #[serde(rename = "userErrors", default)]
+            user_errors: Vec<ApiUserError>,
+        }
+
+        #[derive(Deserialize)]
+        struct ApiUserError {
+            message: Option<String>,
+        }
+
+        const MUTATION: &str = r#"
+            mutation CommentCreate($input: CommentCreateInput!) {
+                commentCreate(input: $input) {
+                    success
+                    userErrors { message }
+                    comment {
+                        id
+                        body
+                        createdAt
+                        updatedAt
+                        user { id name displayName }
+                    }
+                }
+            }
+        "#;
+
+        let response: GraphqlEnvelope<CommentCreateEnvelope> = self
+            .post(Request {
+                query: MUTATION,
+                variables: Variables { input },
+            })
+            .await?;
+
+        if let Some(errors) = response.errors {
+            return Err(GraphqlError::response_errors(MUTATION, errors));
+        }
+
+        let payload = response.data.ok_or(GraphqlError::NotFound)?.comment_create;
+        if !payload.success {
+            let message = payload
+                .user_errors
+                .into_iter()
+                .filter_map(|err| err.message)
+                .collect::<Vec<_>>()
+                .join("; ");
+            return Err(GraphqlError::OperationFailed(if message.is_empty() {
+                "comment create failed".into()
+            } else {
+                message
+            }));
+        }
+
+        payload.comment.ok_or(GraphqlError::NotFound)
+    }
+
+    /// Full-text issue search (`searchIssues`).
+    pub async fn search_issues(
+        &self,
+        term: &str,
+        first: usize,
+    ) -> GraphqlResult<Vec<IssueSummary>> {
+        const QUERY: &str = r#"
+            query SearchIssues($term: String!, $first: Int!) {
+                searchIssues(term: $term, first: $first) {
+                    nodes {
+                        id
+                        identifier
+                        title
+                        url
+                        branchName
+                        priority
+                        createdAt
+                        updatedAt
+                        state { id name type }
+                        assignee { id name displayName }
+                    }
+                }
+            }
+        "#;
+        self.search_nodes(QUERY, "searchIssues", term, first).await
+    }
+
+    /// Full-text project search (`searchProjects`).
+    pub async fn search_projects(
+        &self,
+        term: &str,
+        first: usize,
+    ) -> GraphqlResult<Vec<ProjectSummary>> {
+        const QUERY: &str = r#"
+            query SearchProjects($term: String!, $first: Int!) {
+                searchProjects(term: $term, first: $first) {
+                    nodes {
+                        id
+                        name
+                        state
+                        description
+                        startDate
+                        targetDate
+                        url
+                        status
+                        updatedAt
+                        createdAt
+                        lead { id name displayName }
+                    }
+                }
+            }
+        "#;
+        self.search_nodes(QUERY, "searchProjects", term, first)
+            .await
+    }
+
+    /// Full-text document search (`searchDocuments`).
+    pub async fn search_documents(
+        &self,
+        term: &str,
+        first: usize,
+    ) -> GraphqlResult<Vec<DocumentSummary>> {
+        const QUERY: &str = r#"
+            query SearchDocuments($term: String!, $first: Int!) {
+                searchDocuments(term: $term, first: $first) {
+                    nodes {
+                        id
+                        title
+                        slugId
+                        url
+                        updatedAt
+                        project { id name }
+                    }
+                }
+            }
+        "#;
+        self.search_nodes(QUERY, "searchDocuments", term, first)
+            .await
+    }
+
+    /// Users whose name, display name, or email contains `term`.
+    pub async fn search_users(&self, term: &str, first: usize) -> GraphqlResult<Vec<UserProfile>> {
+        const QUERY: &str = r#"
+            query SearchUsers($term: String!, $first: Int!) {
+                users(
+                    first: $first
+                    filter: {
+                        or: [
+                            { name: { containsIgnoreCase: $term } }
+                            { displayName: { containsIgnoreCase: $term } }
+                            { email: { containsIgnoreCase: $term } }
+                        ]
+                    }
+                ) {
+                    nodes {
+                        id
+                        name
+                        displayName
+                        email
+                        active
+                    }
+                }
+            }
+        "#;
+        self.search_nodes(QUERY, "users", term, first).await
+    }
+
+    /// The viewer with their custom status.
+    pub async fn viewer_status(&self) -> GraphqlResult<UserStatus> {
+        #[derive(Serialize)]
+        struct Request<'a> {
+            query: &'a str,
+        }
+
+        #[derive(Deserialize)]
+        struct ViewerStatusEnvelope {
+            viewer: UserStatus,
+        }
+
+        const QUERY: &str = r#"
+            query ViewerStatus {
+                viewer {
+                    id
+                    name
+                    displayName
+                    email
+                    active
+                    statusEmoji
+                    statusLabel
+                    statusUntilAt
+                }
+            }
+        "#;
+
+        let response: GraphqlEnvelope<ViewerStatusEnvelope> =
+            self.post(Request { query: QUERY }).await?;
+
+        if let Some(errors) = response.errors {
+            return Err(GraphqlError::response_errors(QUERY, errors));
+        }
+
+        Ok(response.data.ok_or(GraphqlError::MissingViewer)?.viewer)
+    }
+
+    /// Users whose name, display name, or email contains `term`, with their custom status.
+    pub async fn user_statuses(&self, term: &str, first: usize) -> GraphqlResult<Vec<UserStatus>> {
+        const QUERY: &str = r#"
+            query UserStatuses($term: String!, $first: Int!) {
+                users(
+                    first: $first
+                    filter: {
+                        or: [
+                            { name: { containsIgnoreCase: $term } }
+                            { displayName: { containsIgnoreCase: $term } }
+                            { email: { containsIgnoreCase: $term } }
+                        ]
+                    }
+                ) {
+                    nodes {
+                        id
+                        name
+                        displayName
+                        email
+                        active
+                        statusEmoji
+                        statusLabel
+                        statusUntilAt
+                    }
+                }
+            }
+        "#;
+        self.search_nodes(QUERY, "users", term, first).await
+    }
+
+    /// Replace a user's custom status; every field of `input` is sent, so `None` clears it.
+    pub async fn update_user_status(
+        &self,
+        user_id: &str,
+        input: UserStatusInput,
+    ) -> GraphqlResult<UserStatus> {
+        #[derive(Serialize)]
+        struct Variables<'a> {
+            id: &'a str,
+            input: UserStatusInput,
+        }
+
+        #[derive(Serialize)]
+        struct Request<'a> {
+            query: &'a str,
+            variables: Variables<'a>,
+        }
+
+        #[derive(Deserialize)]
+        struct UserUpdateEnvelope {
+            #[serde(rename = "userUpdate")]
+            user_update: UserUpdatePayload,
+        }
+
+        #[derive(Deserialize)]
+        struct UserUpdatePayload {
+            success: bool,
+            user: Option<UserStatus>,
+        }
+
+        const MUTATION: &str = r#"
+            mutation UserStatusUpdate($id: String!, $input: UserUpdateInput!) {
+                userUpdate(id: $id, input: $input) {
+                    success
+                    user {
+                        id
+                        name
+                        displayName
+                        email
+                        active
+                        statusEmoji
+                        statusLabel
+                        statusUntilAt
+                    }
+                }
+            }
+        "#;
+
+        let response: GraphqlEnvelope<UserUpdateEnvelope> = self
+            .post(Request {
+                query: MUTATION,
+                variables: Variables { id: user_id, input },
+            })
+            .await?;
+
+        if let Some(errors) = response.errors {
+            return Err(GraphqlError::response_errors(MUTATION, errors));
+        }
+
+        let payload = response.data.ok_or(GraphqlError::NotFound)?.user_update;
+        if !payload.success {
+            return Err(GraphqlError::OperationFailed(
+                "user status update failed".into(),
+            ));
+        }
+        payload.user.ok_or(GraphqlError::NotFound)
+    }
+
+    /// Run a `($term, $first)` query and return `data.<field>.nodes`.
+    async fn search_nodes<T: DeserializeOwned>(
+        &self,
+        query: &str,
+        field: &str,
+        term: &str,
+        first: usize,
+    ) -> GraphqlResult<Vec<T>> {
+        #[derive(Serialize)]
+        struct Variables<'a> {
+            term: &'a str,
+            first: i64,
+        }
+
+        #[derive(Serialize)]
+        struct Request<'a> {
+            query: &'a str,
+            variables: Variables<'a>,
+        }
+
+        let response: GraphqlEnvelope<Value> = self
+            .post(Request {
+                query,
+                variables: Variables {
+                    term,
+                    first: first as i64,
+                },
+            })
+            .await?;
+
+        if let Some(errors) = response.errors {
+            return Err(GraphqlError::response_errors(query, errors));
+        }
+
+        let mut data = response.data.ok_or(GraphqlError::NotFound)?;
+        let nodes = data
+            .get_mut(field)
+            .and_then(|connection| connection.get_mut("nodes"))
+            .map(Value::take)
+            .ok_or(GraphqlError::NotFound)?;
+        Ok(serde_json::from_value(nodes)?)
+    }
+
+    /// List projects with optional filters.
+    pub async fn projects(&self, params: ProjectListParams) -> GraphqlResult<ProjectListResponse> {
+        #[derive(Serialize)]
+        struct Variables {
+            first: i64,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            filter: Option<Value>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            order_by: Option<Value>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            after: Option<String>,
+        }
+
+        #[derive(Serialize)]
+        struct Request<'a> {
+            query: &'a str,
+            variables: Variables,
+        }
+
+        #[derive(Deserialize)]
+        struct ProjectsEnvelope {
+            projects: IssueEdgeConnection<ProjectSummary>,
+        }
+
+        const QUERY: &str = r#"
+            query ListProjects($first: Int!, $filter: ProjectFilter, $orderBy: ProjectOrderByInput, $after: String) {
+                projects(first: $first, filter: $filter, orderBy: $orderBy, after: $after) {
+                    edges {
+                        cursor
+                        node {
                             id
-                            identifier
-                            title
+                            name
+                            state
+                            description
+                            startDate
+                            targetDate
                             url
-                            priority
-                            createdAt
+                            status
+                            progress
                             updatedAt
-                            state { id name type }
-                            assignee { id name displayName }
-                            team { id name key }
-                            children(first: 25) {
-                                nodes {
-                                    id
-                                    identifier
-                                    title
-                                    url
-                                    priority
-                                    createdAt
-                                    updatedAt
-                                    state { id name type }
-                                    assignee { id name displayName }
-                                    team { id name key }
-                                }
-                            }
+                            createdAt
+                            lead { id name displayName }
                         }
                     }
+                    pageInfo {
+                        hasNextPage
+                        endCursor
+                    }
+                }
+            }
+        "#;
+
+        let response: GraphqlEnvelope<ProjectsEnvelope> = self
+            .post(Request {
+                query: QUERY,
+                variables: Variables {
+                    first: params.first as i64,
+                    filter: params.filter,
+                    order_by: params.order_by,
+                    after: params.after,
+                },
+            })
+            .await?;
+
+        if let Some(errors) = response.errors {
+            return Err(GraphqlError::response_errors(QUERY, errors));
+        }
+
+        let connection = response.data.ok_or(GraphqlError::NotFound)?.projects;
+        let nodes = connection.edges.into_iter().map(|edge| edge.node).collect();
+        Ok(ProjectListResponse {
+            nodes,
+            end_cursor: connection.page_info.end_cursor,
+            has_next_page: connection.page_info.has_next_page,
+        })
+    }
+
+    /// Create a project.
+    pub async fn project_create(&self, input: ProjectCreateInput) -> GraphqlResult<ProjectDetail> {
+        #[derive(Serialize)]
+        struct Variables {
+            input: ProjectCreateInput,
+        }
+
+        #[derive(Serialize)]
+        struct Request<'a> {
+            query: &'a str,
+            variables: Variables,
+        }
+
+        #[derive(Deserialize)]
+        struct ProjectCreateEnvelope {
+            #[serde(rename = "projectCreate")]
+            project_create: ProjectPayload,
+        }
+
+        #[derive(Deserialize)]
+        struct ProjectPayload {
+            success: bool,
+            project: Option<ProjectDetail>,
+            #[serde(rename = "userErrors", default)]
+            user_errors: Vec<ApiUserError>,
+        }
+
+        #[derive(Deserialize)]
+        struct ApiUserError {
+            message: Option<String>,
+        }
+
+        const MUTATION: &str = r#"
+            mutation ProjectCreate($input: ProjectCreateInput!) {
+                projectCreate(input: $input) {
+                    success
+                    userErrors { message }
+                    project {
+                        id
+                        name
+                        description
+                        state
+                        startDate
+                        targetDate
+                        url
+                        status
+                        updatedAt
+                        createdAt
+                        lead { id name displayName }
+                        teams { id name key }
+                    }
+                }
+            }
+        "#;
+
+        let response: GraphqlEnvelope<ProjectCreateEnvelope> = self
+            .post(Request {
+                query: MUTATION,
+                variables: Variables { input },
+            })
+            .await?;
+
+        if let Some(errors) = response.errors {
+            return Err(GraphqlError::response_errors(MUTATION, errors));
+        }
+
+        let payload = response.data.ok_or(GraphqlError::NotFound)?.project_create;
+        if !payload.success {
+            let message = payload
+                .user_errors
+                .into_iter()
+                .filter_map(|err| err.message)
+                .collect::<Vec<_>>()
+                .join("; ");
+            return Err(GraphqlError::OperationFailed(if message.is_empty() {
+                "project create failed".into()
+            } else {
+                message
+            }));
+        }
+
+        payload.project.ok_or(GraphqlError::NotFound)
+    }
+
+    /// Update a project by id.
+    pub async fn project_update(
+        &self,
+        id: &str,
+        input: ProjectUpdateInput,
+    ) -> GraphqlResult<ProjectDetail> {
+        #[derive(Serialize)]
+        struct Variables {
+            id: String,
+            input: ProjectUpdateInput,
+        }
+
+        #[derive(Serialize)]
+        struct Request<'a> {
+            query: &'a str,
+            variables: Variables,
+        }
+
+        #[derive(Deserialize)]
+        struct ProjectUpdateEnvelope {
+            #[serde(rename = "projectUpdate")]
+            project_update: ProjectPayload,
+        }
+
+        #[derive(Deserialize)]
+        struct ProjectPayload {
+            success: bool,
+            project: Option<ProjectDetail>,
+            #[serde(rename = "userErrors", default)]
+            user_errors: Vec<ApiUserError>,
+        }
+
+        #[derive(Deserialize)]
+        struct ApiUserError {
+            message: Option<String>,
+        }
+
+        const MUTATION: &str = r#"
+            mutation ProjectUpdate($id: String!, $input: ProjectUpdateInput!) {
+                projectUpdate(id: $id, input: $input) {
+                    success
+                    userErrors { message }
+                    project {
+                        id
+                        name
+                        description
+                        state
+                        startDate
+                        targetDate
+                        url
+                        status
+                        updatedAt
+                        createdAt
+                        lead { id name displayName }
+                        teams { id name key }
+                    }
                 }
             }
         "#;
 
-        #[derive(Deserialize)]
-        struct IssueEnvelope {
-            issue: Option<IssueDetail>,
-        }
-
-        let response: GraphqlEnvelope<IssueEnvelope> = self
+        let response: GraphqlEnvelope<ProjectUpdateEnvelope> = self
             .post(Request {
-                query: QUERY,
-                variables: Variables { id: key },
+                query: MUTATION,
+                variables: Variables {
+                    id: id.to_owned(),
+                    input,
+                },
             })
             .await?;
 
         if let Some(errors) = response.errors {
-            return Err(GraphqlError::ResponseErrors(errors));
+            return Err(GraphqlError::response_errors(MUTATION, errors));
         }
 
-        response
-            .data
-            .and_then(|payload| payload.issue)
-            .ok_or(GraphqlError::NotFound)
+        let payload = response.data.ok_or(GraphqlError::NotFound)?.project_update;
+        if !payload.success {
+            let message = payload
+                .user_errors
+                .into_iter()
+                .filter_map(|err| err.message)
+                .collect::<Vec<_>>()
+                .join("; ");
+            return Err(GraphqlError::OperationFailed(if message.is_empty() {
+                "project update failed".into()
+            } else {
+                message
+            }));
+        }
+
+        payload.project.ok_or(GraphqlError::NotFound)
     }
 
-    /// Create a new issue using the Linear GraphQL API.
-    pub async fn create_issue(&self, input: IssueCreateInput) -> GraphqlResult<IssueDetail> {
+    /// Archive or restore a project.
+    pub async fn project_archive(&self, id: &str, archive: bool) -> GraphqlResult<ProjectDetail> {
         #[derive(Serialize)]
-        struct Variables {
-            input: IssueCreateInput,
+        struct Variables<'a> {
+            id: &'a str,
+            archive: bool,
         }
 
         #[derive(Serialize)]
         struct Request<'a> {
             query: &'a str,
-            variables: Variables,
+            variables: Variables<'a>,
         }
 
         #[derive(Deserialize)]
-        struct IssueCreateEnvelope {
-            #[serde(rename = "issueCreate")]
-            issue_create: IssueCreatePayload,
+        struct ProjectArchiveEnvelope {
+            #[serde(rename = "projectArchive")]
+            project_archive: ProjectPayload,
         }
 
         #[derive(Deserialize)]
-        struct IssueCreatePayload {
+        struct ProjectPayload {
             success: bool,
-            issue: Option<IssueDetail>,
+            project: Option<ProjectDetail>,
             #[serde(rename = "userErrors", default)]
             user_errors: Vec<ApiUserError>,
         }
@@ -415,45 +2332,40 @@ impl LinearGraphqlClient {
         }
 
         const MUTATION: &str = r#"
-            mutation CreateIssue($input: IssueCreateInput!) {
-                issueCreate(input: $input) {
+            mutation ProjectArchive($id: String!, $archive: Boolean!) {
+                projectArchive(id: $id, archive: $archive) {
                     success
-                    userErrors {
-                        message
-                    }
-                    issue {
+                    userErrors { message }
+                    project {
                         id
-                        identifier
-                        title
+                        name
                         description
+                        state
+                        startDate
+                        targetDate
                         url
-                        priority
-                        createdAt
+                        status
                         updatedAt
-                        state { id name type }
-                        assignee { id name displayName }
-                        labels(first: 20) {
-                            nodes { id name color }
-                        }
-                        team { id name key }
+                        createdAt
+                        lead { id name displayName }
+                        teams { id name key }
                     }
                 }
             }
         "#;
 
-        let response: GraphqlEnvelope<IssueCreateEnvelope> = self
+        let response: GraphqlEnvelope<ProjectArchiveEnvelope> = self
             .post(Request {
                 query: MUTATION,
-                variables: Variables { input },
+                variables: Variables { id, archive },
             })
             .await?;
 
         if let Some(errors) = response.errors {
-            return Err(GraphqlError::ResponseErrors(errors));
+            return Err(GraphqlError::response_errors(MUTATION, errors));
         }
 
-        let payload = response.data.ok_or(GraphqlError::NotFound)?.issue_create;
-
+        let payload = response.data.ok_or(GraphqlError::NotFound)?.project_archive;
         if !payload.success {
             let message = payload
                 .user_errors
@@ -461,28 +2373,157 @@ impl LinearGraphqlClient {
                 .filter_map(|err| err.message)
                 .collect::<Vec<_>>()
                 .join("; ");
-            let message = if message.is_empty() {
-                "unknown error".to_string()
+            return Err(GraphqlError::OperationFailed(if message.is_empty() {
+                "project archive failed".into()
             } else {
                 message
-            };
-            return Err(GraphqlError::OperationFailed(message));
+            }));
         }
 
-        let issue = payload.issue.ok_or(GraphqlError::NotFound)?;
-        Ok(issue)
+        payload.project.ok_or(GraphqlError::NotFound)
+    }
+
+    /// Fetch a single project by id.
+    pub async fn project(&self, id: &str) -> GraphqlResult<ProjectDetail> {
+        #[derive(Serialize)]
+        struct Variables<'a> {
+            id: &'a str,
+        }
+
+        #[derive(Serialize)]
+        struct Request<'a> {
+            query: &'a str,
+            variables: Variables<'a>,
+        }
+
+        #[derive(Deserialize)]
+        struct ProjectEnvelope {
+            project: Option<ProjectDetail>,
+        }
+
+        const QUERY: &str = r#"
+            query ProjectById($id: String!) {
+                project(id: $id) {
+                    id
+                    name
+                    description
+                    state
+                    startDate
+                    targetDate
+                    url
+                    status
+                    progress
+                    updatedAt
+                    createdAt
+                    lead { id name displayName }
+                    teams { id name key }
+                }
+            }
+        "#;
+
+        let response: GraphqlEnvelope<ProjectEnvelope> = self
+            .post(Request {
+                query: QUERY,
+                variables: Variables { id },
+            })
+            .await?;
+
+        if let Some(errors) = response.errors {
+            return Err(GraphqlError::response_errors(QUERY, errors));
+        }
+
+        response
+            .data
+            .and_then(|payload| payload.project)
+            .ok_or(GraphqlError::NotFound)
+    }
+
+    /// Ids of a project's members.
+    pub async fn project_member_ids(&self, id: &str) -> GraphqlResult<Vec<String>> {
+        #[derive(Serialize)]
+        struct Variables<'a> {
+            id: &'a str,
+        }
+
+        #[derive(Serialize)]
+        struct Request<'a> {
+            query: &'a str,
+            variables: Variables<'a>,
+        }
+
+        #[derive(Deserialize)]
+        struct ProjectEnvelope {
+            project: Option<ProjectMembers>,
+        }
+
+        #[derive(Deserialize)]
+        struct ProjectMembers {
+            members: MemberConnection,
+        }
+
+        #[derive(Deserialize)]
+        struct MemberConnection {
+            nodes: Vec<MemberNode>,
+        }
+
+        #[derive(Deserialize)]
+        struct MemberNode {
+            id: String,
+        }
+
+        const QUERY: &str = r#"
+            query ProjectMembers($id: String!) {
+                project(id: $id) {
+                    members(first: 250) {
+                        nodes { id }
+                    }
+                }
+            }
+        "#;
+
+        let response: GraphqlEnvelope<ProjectEnvelope> = self
+            .post(Request {
+                query: QUERY,
+                variables: Variables { id },
+            })
+            .await?;
+
+        if let Some(errors) = response.errors {
+            return Err(GraphqlError::response_errors(QUERY, errors));
+        }
+
+        let project = response
+            .data
+            .and_then(|payload| payload.project)
+            .ok_or(GraphqlError::NotFound)?;
+        Ok(project
+            .members
+            .nodes
+            .into_iter()
+            .map(|member| member.id)
+            .collect())
+    }
+
+    /// Delete a project by id; Linear keeps it in the trash for a while.
+    pub async fn project_delete(&self, id: &str) -> GraphqlResult<bool> {
+        const MUTATION: &str = r#"
+            mutation ProjectDelete($id: String!) {
+                projectDelete(id: $id) {
+                    success
+                }
+            }
+        "#;
+        self.delete_by_id(MUTATION, "projectDelete", id).await
     }
 
-    /// Update an issue by id.
-    pub async fn update_issue(
+    /// Add a milestone to a project.
+    pub async fn project_milestone_create(
         &self,
-        id: &str,
-        input: IssueUpdateInput,
-    ) -> GraphqlResult<IssueDetail> {
+        input: ProjectMilestoneCreateInput,
+    ) -> GraphqlResult<ProjectMilestone> {
         #[derive(Serialize)]
         struct Variables {
-            id: String,
-            input: IssueUpdateInput,
+            input: ProjectMilestoneCreateInput,
         }
 
         #[derive(Serialize)]
@@ -492,166 +2533,148 @@ impl LinearGraphqlClient {
         }
 
         #[derive(Deserialize)]
-        struct IssueUpdateEnvelope {
-            #[serde(rename = "issueUpdate")]
-            issue_update: IssueUpdatePayload,
+        struct MilestoneCreateEnvelope {
+            #[serde(rename = "projectMilestoneCreate")]
+            project_milestone_create: MilestonePayload,
         }
 
         #[derive(Deserialize)]
-        struct IssueUpdatePayload {
+        struct MilestonePayload {
             success: bool,
-            issue: Option<IssueDetail>,
-            #[serde(rename = "userErrors", default)]
-            user_errors: Vec<ApiUserError>,
-        }
-
-        #[derive(Deserialize)]
-        struct ApiUserError {
-            message: Option<String>,
+            #[serde(rename = "projectMilestone")]
+            project_milestone: Option<ProjectMilestone>,
         }
 
         const MUTATION: &str = r#"
-            mutation IssueUpdate($id: String!, $input: IssueUpdateInput!) {
-                issueUpdate(id: $id, input: $input) {
+            mutation ProjectMilestoneCreate($input: ProjectMilestoneCreateInput!) {
+                projectMilestoneCreate(input: $input) {
                     success
-                    userErrors { message }
-                    issue {
+                    projectMilestone {
                         id
-                        identifier
-                        title
-                        description
-                        url
-                        priority
-                        createdAt
-                        updatedAt
-                        state { id name type }
-                        assignee { id name displayName }
-                        labels(first: 20) { nodes { id name color } }
-                        team { id name key }
+                        name
+                        targetDate
                     }
                 }
             }
         "#;
 
-        let response: GraphqlEnvelope<IssueUpdateEnvelope> = self
+        let response: GraphqlEnvelope<MilestoneCreateEnvelope> = self
             .post(Request {
                 query: MUTATION,
-                variables: Variables {
-                    id: id.to_owned(),
-                    input,
-                },
+                variables: Variables { input },
             })
             .await?;
 
         if let Some(errors) = response.errors {
-            return Err(GraphqlError::ResponseErrors(errors));
+            return Err(GraphqlError::response_errors(MUTATION, errors));
         }
 
-        let payload = response.data.ok_or(GraphqlError::NotFound)?.issue_update;
+        let payload = response
+            .data
+            .ok_or(GraphqlError::NotFound)?
+            .project_milestone_create;
         if !payload.success {
-            let message = payload
-                .user_errors
-                .into_iter()
-                .filter_map(|err| err.message)
-                .collect::<Vec<_>>()
-                .join("; ");
-            return Err(GraphqlError::OperationFailed(if message.is_empty() {
-                "issue update failed".into()
-            } else {
-                message
-            }));
+            return Err(GraphqlError::OperationFailed(
+                "project milestone create failed".into(),
+            ));
         }
 
-        payload.issue.ok_or(GraphqlError::NotFound)
+        payload.project_milestone.ok_or(GraphqlError::NotFound)
     }
 
-    /// Archive or restore an issue.
-    pub async fn archive_issue(&self, id: &str, archive: bool) -> GraphqlResult<IssueDetail> {
+    /// Delete a project milestone by id.
+    pub async fn project_milestone_delete(&self, id: &str) -> GraphqlResult<bool> {
+        const MUTATION: &str = r#"
+            mutation ProjectMilestoneDelete($id: String!) {
+                projectMilestoneDelete(id: $id) {
+                    success
+                }
+            }
+        "#;
+        self.delete_by_id(MUTATION, "projectMilestoneDelete", id)
+            .await
+    }
+
+    /// Post a status update on a project.
+    pub async fn project_status_update_create(
+        &self,
+        input: ProjectStatusUpdateCreateInput,
+    ) -> GraphqlResult<ProjectStatusUpdate> {
         #[derive(Serialize)]
-        struct Variables<'a> {
-            id: &'a str,
-            archive: bool,
+        struct Variables {
+            input: ProjectStatusUpdateCreateInput,
         }
 
         #[derive(Serialize)]
         struct Request<'a> {
             query: &'a str,
-            variables: Variables<'a>,
+            variables: Variables,
         }
 
         #[derive(Deserialize)]
-        struct IssueArchiveEnvelope {
-            #[serde(rename = "issueArchive")]
-            issue_archive: IssueUpdatePayload,
+        struct StatusUpdateCreateEnvelope {
+            #[serde(rename = "projectUpdateCreate")]
+            project_update_create: StatusUpdatePayload,
         }
 
         #[derive(Deserialize)]
-        struct IssueUpdatePayload {
+        struct StatusUpdatePayload {
             success: bool,
-            issue: Option<IssueDetail>,
-            #[serde(rename = "userErrors", default)]
-            user_errors: Vec<ApiUserError>,
-        }
-
-        #[derive(Deserialize)]
-        struct ApiUserError {
-            message: Option<String>,
+            #[serde(rename = "projectUpdate")]
+            project_update: Option<ProjectStatusUpdate>,
         }
 
         const MUTATION: &str = r#"
-            mutation IssueArchive($id: String!, $archive: Boolean!) {
-                issueArchive(id: $id, archive: $archive) {
+            mutation ProjectUpdateCreate($input: ProjectUpdateCreateInput!) {
+                projectUpdateCreate(input: $input) {
                     success
-                    userErrors { message }
-                    issue {
+                    projectUpdate {
                         id
-                        identifier
-                        title
-                        description
+                        body
                         url
-                        priority
-                        createdAt
-                        updatedAt
-                        state { id name type }
-                        assignee { id name displayName }
-                        labels(first: 20) { nodes { id name color } }
-                        team { id name key }
                     }
                 }
             }
         "#;
 
-        let response: GraphqlEnvelope<IssueArchiveEnvelope> = self
+        let response: GraphqlEnvelope<StatusUpdateCreateEnvelope> = self
             .post(Request {
                 query: MUTATION,
-                variables: Variables { id, archive },
+                variables: Variables { input },
             })
             .await?;
 
         if let Some(errors) = response.errors {
-            return Err(GraphqlError::ResponseErrors(errors));
+            return Err(GraphqlError::response_errors(MUTATION, errors));
         }
 
-        let payload = response.data.ok_or(GraphqlError::NotFound)?.issue_archive;
+        let payload = response
+            .data
+            .ok_or(GraphqlError::NotFound)?
+            .project_update_create;
         if !payload.success {
-            let message = payload
-                .user_errors
-                .into_iter()
-                .filter_map(|err| err.message)
-                .collect::<Vec<_>>()
-                .join("; ");
-            return Err(GraphqlError::OperationFailed(if message.is_empty() {
-                "issue archive failed".into()
-            } else {
-                message
-            }));
+            return Err(GraphqlError::OperationFailed(
+                "project status update create failed".into(),
+            ));
         }
 
-        payload.issue.ok_or(GraphqlError::NotFound)
+        payload.project_update.ok_or(GraphqlError::NotFound)
     }
 
-    /// Delete an issue by id.
-    pub async fn delete_issue(&self, id: &str) -> GraphqlResult<bool> {
+    /// Delete a project status update by id.
+    pub async fn project_status_update_delete(&self, id: &str) -> GraphqlResult<bool> {
+        const MUTATION: &str = r#"
+            mutation ProjectUpdateDelete($id: String!) {
+                projectUpdateDelete(id: $id) {
+                    success
+                }
+            }
+        "#;
+        self.delete_by_id(MUTATION, "projectUpdateDelete", id).await
+    }
+
+    /// Run a `($id)` mutation whose payload is `data.<field>.success`.
+    async fn delete_by_id(&self, mutation: &str, field: &str, id: &str) -> GraphqlResult<bool> {
         #[derive(Serialize)]
         struct Variables<'a> {
             id: &'a str,
@@ -663,67 +2686,113 @@ impl LinearGraphqlClient {
             variables: Variables<'a>,
         }
 
-        #[derive(Deserialize)]
-        struct IssueDeleteEnvelope {
-            #[serde(rename = "issueDelete")]
-            issue_delete: IssueDeletePayload,
+        let response: GraphqlEnvelope<Value> = self
+            .post(Request {
+                query: mutation,
+                variables: Variables { id },
+            })
+            .await?;
+
+        if let Some(errors) = response.errors {
+            return Err(GraphqlError::response_errors(mutation, errors));
         }
 
-        #[derive(Deserialize)]
-        struct IssueDeletePayload {
-            success: bool,
-            #[serde(rename = "userErrors", default)]
-            user_errors: Vec<ApiUserError>,
+        let success = response
+            .data
+            .as_ref()
+            .and_then(|data| data[field]["success"].as_bool())
+            .ok_or(GraphqlError::NotFound)?;
+        if !success {
+            return Err(GraphqlError::OperationFailed(format!("{field} failed")));
+        }
+        Ok(true)
+    }
+
+    /// List cycles for teams or organization.
+    pub async fn cycles(&self, params: CycleListParams) -> GraphqlResult<CycleListResponse> {
+        #[derive(Serialize)]
+        struct Variables {
+            first: i64,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            filter: Option<Value>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            order_by: Option<Value>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            after: Option<String>,
+        }
+
+        #[derive(Serialize)]
+        struct Request<'a> {
+            query: &'a str,
+            variables: Variables,
         }
 
         #[derive(Deserialize)]
-        struct ApiUserError {
-            message: Option<String>,
+        struct CyclesEnvelope {
+            cycles: IssueEdgeConnection<CycleSummary>,
         }
 
-        const MUTATION: &str = r#"
-            mutation IssueDelete($id: String!) {
-                issueDelete(id: $id) {
-                    success
-                    userErrors { message }
+        const QUERY: &str = r#"
+            query ListCycles($first: Int!, $filter: CycleFilter, $orderBy: CycleOrderByInput, $after: String) {
+                cycles(first: $first, filter: $filter, orderBy: $orderBy, after: $after) {
+                    edges {
+                        cursor
+                        node {
+                            id
+                            name
+                            number
+                            startsAt
+                            endsAt
+                            state
+                            isActive
+                            issueCountHistory
+                            completedIssueCountHistory
+                            team { id name key }
+                        }
+                    }
+                    pageInfo {
+                        hasNextPage
+                        endCursor
+                    }
                 }
             }
         "#;
 
-        let response: GraphqlEnvelope<IssueDeleteEnvelope> = self
+        let response: GraphqlEnvelope<CyclesEnvelope> = self
             .post(Request {
-                query: MUTATION,
-                variables: Variables { id },
+                query: QUERY,
+                variables: Variables {
+                    first: params.first as i64,
+                    filter: params.filter,
+                    order_by: params.order_by,
+                    after: params.after,
+                },
             })
             .await?;
 
         if let Some(errors) = response.errors {
-            return Err(GraphqlError::ResponseErrors(errors));
+            return Err(GraphqlError::response_errors(QUERY, errors));
         }
 
-        let payload = response.data.ok_or(GraphqlError::NotFound)?.issue_delete;
-        if !payload.success {
-            let message = payload
-                .user_errors
-                .into_iter()
-                .filter_map(|err| err.message)
-                .collect::<Vec<_>>()
-                .join("; ");
-            return Err(GraphqlError::OperationFailed(if message.is_empty() {
-                "issue delete failed".into()
-            } else {
-                message
-            }));
-        }
-
-        Ok(true)
+        let connection = response.data.ok_or(GraphqlError::NotFound)?.cycles;
+        let nodes = connection.edges.into_iter().map(|edge| edge.node).collect();
+        Ok(CycleListResponse {
+            nodes,
+            end_cursor: connection.page_info.end_cursor,
+            has_next_page: connection.page_info.has_next_page,
+        })
     }
 
-    /// Create a new comment on an issue.
-    pub async fn create_comment(&self, input: CommentCreateInput) -> GraphqlResult<Comment> {
+    /// Update a cycle.
+    pub async fn cycle_update(
+        &self,
+        id: &str,
+        input: CycleUpdateInput,
+    ) -> GraphqlResult<CycleSummary> {
         #[derive(Serialize)]
         struct Variables {
-            input: CommentCreateInput,
+            id: String,
+            input: CycleUpdateInput,
         }
 
         #[derive(Serialize)]
@@ -733,15 +2802,15 @@ impl LinearGraphqlClient {
         }
 
         #[derive(Deserialize)]
-        struct CommentCreateEnvelope {
-            #[serde(rename = "commentCreate")]
-            comment_create: CommentCreatePayload,
+        struct CycleUpdateEnvelope {
+            #[serde(rename = "cycleUpdate")]
+            cycle_update: CycleUpdatePayload,
         }
 
         #[derive(Deserialize)]
-        struct CommentCreatePayload {
+        struct CycleUpdatePayload {
             success: bool,
-            comment: Option<Comment>,
+            cycle: Option<CycleSummary>,
             #[serde(rename = "userErrors", default)]
             user_errors: Vec<ApiUserError>,
         }
@@ -752,33 +2821,38 @@ impl LinearGraphqlClient {
         }
 
         const MUTATION: &str = r#"
-            mutation CommentCreate($input: CommentCreateInput!) {
-                commentCreate(input: $input) {
+            mutation CycleUpdate($id: String!, $input: CycleUpdateInput!) {
+                cycleUpdate(id: $id, input: $input) {
                     success
                     userErrors { message }
-                    comment {
+                    cycle {
                         id
-                        body
-                        createdAt
-                        updatedAt
-                        user { id name displayName }
+                        name
+                        number
+                        startsAt
+                        endsAt
+                        state
+                        team { id name key }
                     }
                 }
             }
         "#;
 
-        let response: GraphqlEnvelope<CommentCreateEnvelope> = self
+        let response: GraphqlEnvelope<CycleUpdateEnvelope> = self
             .post(Request {
                 query: MUTATION,
-                variables: Variables { input },
+                variables: Variables {
+                    id: id.to_owned(),
+                    input,
+                },
             })
             .await?;
 
         if let Some(errors) = response.errors {
-            return Err(GraphqlError::ResponseErrors(errors));
+            return Err(GraphqlError::response_errors(MUTATION, errors));
         }
 
-        let payload = response.data.ok_or(GraphqlError::NotFound)?.comment_create;
+        let payload = response.data.ok_or(GraphqlError::NotFound)?.cycle_update;
         if !payload.success {
             let message = payload
                 .user_errors
@@ -787,95 +2861,108 @@ impl LinearGraphqlClient {
                 .collect::<Vec<_>>()
                 .join("; ");
             return Err(GraphqlError::OperationFailed(if message.is_empty() {
-                "comment create failed".into()
+                "cycle update failed".into()
             } else {
                 message
             }));
         }
 
-        payload.comment.ok_or(GraphqlError::NotFound)
+        payload.cycle.ok_or(GraphqlError::NotFound)
     }
 
-    /// List projects with optional filters.
-    pub async fn projects(&self, params: ProjectListParams) -> GraphqlResult<ProjectListResponse> {
+    /// Labels of every team plus workspace-wide ones, up to 250.
+    pub async fn workspace_labels(&self) -> GraphqlResult<Vec<IssueLabel>> {
         #[derive(Serialize)]
-        struct Variables {
-            first: i64,
-            #[serde(skip_serializing_if = "Option::is_none")]
-            filter: Option<Value>,
-            #[serde(skip_serializing_if = "Option::is_none")]
-            order_by: Option<Value>,
-            #[serde(skip_serializing_if = "Option::is_none")]
-            after: Option<String>,
+        struct Request<'a> {
+            query: &'a str,
+        }
+
+        #[derive(Deserialize)]
+        struct LabelsEnvelope {
+            #[serde(rename = "issueLabels")]
+            issue_labels: IssueLabelConnection,
+        }
+
+        const QUERY: &str = r#"
+            query WorkspaceLabels {
+                issueLabels(first: 250) {
+                    nodes {
+                        id
+                        name
+                        color
+                        description
+                    }
+                }
+            }
+        "#;
+
+        let response: GraphqlEnvelope<LabelsEnvelope> =
+            self.post_cached(Request { query: QUERY }).await?;
+
+        if let Some(errors) = response.errors {
+            return Err(GraphqlError::response_errors(QUERY, errors));
+        }
+
+        let connection = response.data.ok_or(GraphqlError::NotFound)?.issue_labels;
+        Ok(connection.nodes)
+    }
+
+    /// List issue labels for a team.
+    pub async fn issue_labels(&self, team_id: &str) -> GraphqlResult<Vec<IssueLabel>> {
+        #[derive(Serialize)]
+        struct Variables<'a> {
+            #[serde(rename = "teamId")]
+            team_id: &'a str,
         }
 
         #[derive(Serialize)]
         struct Request<'a> {
             query: &'a str,
-            variables: Variables,
+            variables: Variables<'a>,
         }
 
         #[derive(Deserialize)]
-        struct ProjectsEnvelope {
-            projects: IssueEdgeConnection<ProjectSummary>,
+        struct LabelsEnvelope {
+            #[serde(rename = "issueLabels")]
+            issue_labels: IssueLabelConnection,
         }
 
         const QUERY: &str = r#"
-            query ListProjects($first: Int!, $filter: ProjectFilter, $orderBy: ProjectOrderByInput, $after: String) {
-                projects(first: $first, filter: $filter, orderBy: $orderBy, after: $after) {
-                    edges {
-                        cursor
-                        node {
-                            id
-                            name
-                            state
-                            description
-                            startDate
-                            targetDate
-                            status
-                            updatedAt
-                            createdAt
-                            lead { id name displayName }
-                        }
-                    }
-                    pageInfo {
-                        hasNextPage
-                        endCursor
+            query IssueLabels($teamId: String!) {
+                issueLabels(filter: { team: { id: { eq: $teamId } } }) {
+                    nodes {
+                        id
+                        name
+                        color
+                        description
                     }
                 }
             }
         "#;
 
-        let response: GraphqlEnvelope<ProjectsEnvelope> = self
-            .post(Request {
+        let response: GraphqlEnvelope<LabelsEnvelope> = self
+            .post_cached(Request {
                 query: QUERY,
-                variables: Variables {
-                    first: params.first as i64,
-                    filter: params.filter,
-                    order_by: params.order_by,
-                    after: params.after,
-                },
+                variables: Variables { team_id },
             })
             .await?;
 
         if let Some(errors) = response.errors {
-            return Err(GraphqlError::ResponseErrors(errors));
+            return Err(GraphqlError::response_errors(QUERY, errors));
         }
 
-        let connection = response.data.ok_or(GraphqlError::NotFound)?.projects;
-        let nodes = connection.edges.into_iter().map(|edge| edge.node).collect();
-        Ok(ProjectListResponse {
-            nodes,
-            end_cursor: connection.page_info.end_cursor,
-            has_next_page: connection.page_info.has_next_page,
-        })
+        let connection = response.data.ok_or(GraphqlError::NotFound)?.issue_labels;
+        Ok(connection.nodes)
     }
 
-    /// Create a project.
-    pub async fn project_create(&self, input: ProjectCreateInput) -> GraphqlResult<ProjectDetail> {
+    /// Create a new issue label.
+    pub async fn create_issue_label(
+        &self,
+        input: IssueLabelCreateInput,
+    ) -> GraphqlResult<IssueLabel> {
         #[derive(Serialize)]
         struct Variables {
-            input: ProjectCreateInput,
+            input: IssueLabelCreateInput,
         }
 
         #[derive(Serialize)]
@@ -885,15 +2972,16 @@ impl LinearGraphqlClient {
         }
 
         #[derive(Deserialize)]
-        struct ProjectCreateEnvelope {
-            #[serde(rename = "projectCreate")]
-            project_create: ProjectPayload,
+        struct LabelCreateEnvelope {
+            #[serde(rename = "issueLabelCreate")]
+            label_create: LabelPayload,
         }
 
         #[derive(Deserialize)]
-        struct ProjectPayload {
+        struct LabelPayload {
             success: bool,
-            project: Option<ProjectDetail>,
+            #[serde(rename = "issueLabel")]
+            issue_label: Option<IssueLabel>,
             #[serde(rename = "userErrors", default)]
             user_errors: Vec<ApiUserError>,
         }
@@ -904,28 +2992,21 @@ impl LinearGraphqlClient {
         }
 
         const MUTATION: &str = r#"
-            mutation ProjectCreate($input: ProjectCreateInput!) {
-                projectCreate(input: $input) {
+            mutation IssueLabelCreate($input: IssueLabelCreateInput!) {
+                issueLabelCreate(input: $input) {
                     success
                     userErrors { message }
-                    project {
+                    issueLabel {
                         id
                         name
+                        color
                         description
-                        state
-                        startDate
-                        targetDate
-                        status
-                        updatedAt
-                        createdAt
-                        lead { id name displayName }
-                        teams { id name key }
                     }
                 }
             }
         "#;
 
-        let response: GraphqlEnvelope<ProjectCreateEnvelope> = self
+        let response: GraphqlEnvelope<LabelCreateEnvelope> = self
             .post(Request {
                 query: MUTATION,
                 variables: Variables { input },
@@ -933,10 +3014,10 @@ impl LinearGraphqlClient {
             .await?;
 
         if let Some(errors) = response.errors {
-            return Err(GraphqlError::ResponseErrors(errors));
+            return Err(GraphqlError::response_errors(MUTATION, errors));
         }
 
-        let payload = response.data.ok_or(GraphqlError::NotFound)?.project_create;
+        let payload = response.data.ok_or(GraphqlError::NotFound)?.label_create;
         if !payload.success {
             let message = payload
                 .user_errors
@@ -945,25 +3026,25 @@ impl LinearGraphqlClient {
                 .collect::<Vec<_>>()
                 .join("; ");
             return Err(GraphqlError::OperationFailed(if message.is_empty() {
-                "project create failed".into()
+                "issue label create failed".into()
             } else {
                 message
             }));
         }
 
-        payload.project.ok_or(GraphqlError::NotFound)
+        payload.issue_label.ok_or(GraphqlError::NotFound)
     }
 
-    /// Update a project by id.
-    pub async fn project_update(
+    /// Update an existing issue label.
+    pub async fn update_issue_label(
         &self,
         id: &str,
-        input: ProjectUpdateInput,
-    ) -> GraphqlResult<ProjectDetail> {
+        input: IssueLabelUpdateInput,
+    ) -> GraphqlResult<IssueLabel> {
         #[derive(Serialize)]
         struct Variables {
             id: String,
-            input: ProjectUpdateInput,
+            input: IssueLabelUpdateInput,
         }
 
         #[derive(Serialize)]
@@ -973,15 +3054,16 @@ impl LinearGraphqlClient {
         }
 
         #[derive(Deserialize)]
-        struct ProjectUpdateEnvelope {
-            #[serde(rename = "projectUpdate")]
-            project_update: ProjectPayload,
+        struct LabelUpdateEnvelope {
+            #[serde(rename = "issueLabelUpdate")]
+            label_update: LabelPayload,
         }
 
         #[derive(Deserialize)]
-        struct ProjectPayload {
+        struct LabelPayload {
             success: bool,
-            project: Option<ProjectDetail>,
+            #[serde(rename = "issueLabel")]
+            issue_label: Option<IssueLabel>,
             #[serde(rename = "userErrors", default)]
             user_errors: Vec<ApiUserError>,
         }
@@ -992,28 +3074,21 @@ impl LinearGraphqlClient {
         }
 
         const MUTATION: &str = r#"
-            mutation ProjectUpdate($id: String!, $input: ProjectUpdateInput!) {
-                projectUpdate(id: $id, input: $input) {
+            mutation IssueLabelUpdate($id: String!, $input: IssueLabelUpdateInput!) {
+                issueLabelUpdate(id: $id, input: $input) {
                     success
                     userErrors { message }
-                    project {
+                    issueLabel {
                         id
                         name
+                        color
                         description
-                        state
-                        startDate
-                        targetDate
-                        status
-                        updatedAt
-                        createdAt
-                        lead { id name displayName }
-                        teams { id name key }
                     }
                 }
             }
         "#;
 
-        let response: GraphqlEnvelope<ProjectUpdateEnvelope> = self
+        let response: GraphqlEnvelope<LabelUpdateEnvelope> = self
             .post(Request {
                 query: MUTATION,
                 variables: Variables {
@@ -1024,10 +3099,10 @@ impl LinearGraphqlClient {
             .await?;
 
         if let Some(errors) = response.errors {
-            return Err(GraphqlError::ResponseErrors(errors));
+            return Err(GraphqlError::response_errors(MUTATION, errors));
         }
 
-        let payload = response.data.ok_or(GraphqlError::NotFound)?.project_update;
+        let payload = response.data.ok_or(GraphqlError::NotFound)?.label_update;
         if !payload.success {
             let message = payload
                 .user_errors
@@ -1036,110 +3111,90 @@ impl LinearGraphqlClient {
                 .collect::<Vec<_>>()
                 .join("; ");
             return Err(GraphqlError::OperationFailed(if message.is_empty() {
-                "project update failed".into()
+                "issue label update failed".into()
             } else {
                 message
             }));
         }
 
-        payload.project.ok_or(GraphqlError::NotFound)
+        payload.issue_label.ok_or(GraphqlError::NotFound)
     }
 
-    /// Archive or restore a project.
-    pub async fn project_archive(&self, id: &str, archive: bool) -> GraphqlResult<ProjectDetail> {
+    /// Relate two issues (e.g. mark `issue_id` as a duplicate of `related_issue_id`).
+    pub async fn create_issue_relation(
+        &self,
+        input: IssueRelationCreateInput,
+    ) -> GraphqlResult<String> {
         #[derive(Serialize)]
-        struct Variables<'a> {
-            id: &'a str,
-            archive: bool,
+        struct Variables {
+            input: IssueRelationCreateInput,
         }
 
         #[derive(Serialize)]
         struct Request<'a> {
             query: &'a str,
-            variables: Variables<'a>,
+            variables: Variables,
         }
 
         #[derive(Deserialize)]
-        struct ProjectArchiveEnvelope {
-            #[serde(rename = "projectArchive")]
-            project_archive: ProjectPayload,
+        struct RelationCreateEnvelope {
+            #[serde(rename = "issueRelationCreate")]
+            relation_create: RelationPayload,
         }
 
         #[derive(Deserialize)]
-        struct ProjectPayload {
+        struct RelationPayload {
             success: bool,
-            project: Option<ProjectDetail>,
-            #[serde(rename = "userErrors", default)]
-            user_errors: Vec<ApiUserError>,
+            #[serde(rename = "issueRelation")]
+            issue_relation: Option<RelationNode>,
         }
 
         #[derive(Deserialize)]
-        struct ApiUserError {
-            message: Option<String>,
+        struct RelationNode {
+            id: String,
         }
 
         const MUTATION: &str = r#"
-            mutation ProjectArchive($id: String!, $archive: Boolean!) {
-                projectArchive(id: $id, archive: $archive) {
+            mutation IssueRelationCreate($input: IssueRelationCreateInput!) {
+                issueRelationCreate(input: $input) {
                     success
-                    userErrors { message }
-                    project {
-                        id
-                        name
-                        description
-                        state
-                        startDate
-                        targetDate
-                        status
-                        updatedAt
-                        createdAt
-                        lead { id name displayName }
-                        teams { id name key }
-                    }
+                    issueRelation { id }
                 }
             }
         "#;
 
-        let response: GraphqlEnvelope<ProjectArchiveEnvelope> = self
+        let response: GraphqlEnvelope<RelationCreateEnvelope> = self
             .post(Request {
                 query: MUTATION,
-                variables: Variables { id, archive },
+                variables: Variables { input },
             })
             .await?;
 
         if let Some(errors) = response.errors {
-            return Err(GraphqlError::ResponseErrors(errors));
+            return Err(GraphqlError::response_errors(MUTATION, errors));
         }
 
-        let payload = response.data.ok_or(GraphqlError::NotFound)?.project_archive;
+        let payload = response.data.ok_or(GraphqlError::NotFound)?.relation_create;
         if !payload.success {
-            let message = payload
-                .user_errors
-                .into_iter()
-                .filter_map(|err| err.message)
-                .collect::<Vec<_>>()
-                .join("; ");
-            return Err(GraphqlError::OperationFailed(if message.is_empty() {
-                "project archive failed".into()
-            } else {
-                message
-            }));
+            return Err(GraphqlError::OperationFailed(
+                "issue relation create failed".into(),
+            ));
         }
 
-        payload.project.ok_or(GraphqlError::NotFound)
+        payload
+            .issue_relation
+            .map(|relation| relation.id)
+            .ok_or(GraphqlError::NotFound)
     }
 
-    /// List cycles for teams or organization.
-    pub async fn cycles(&self, params: CycleListParams) -> GraphqlResult<CycleListResponse> {
+    /// Create a workflow state on a team.
+    pub async fn create_workflow_state(
+        &self,
+        input: WorkflowStateCreateInput,
+    ) -> GraphqlResult<WorkflowStateSummary> {
         #[derive(Serialize)]
         struct Variables {
-            first: i64,
-            #[serde(skip_serializing_if = "Option::is_none")]
-            filter: Option<Value>,
-            #[serde(skip_serializing_if = "Option::is_none")]
-            order_by: Option<Value>,
-            #[serde(skip_serializing_if = "Option::is_none")]
-            after: Option<String>,
+            input: WorkflowStateCreateInput,
         }
 
         #[derive(Serialize)]
@@ -1149,68 +3204,80 @@ impl LinearGraphqlClient {
         }
 
         #[derive(Deserialize)]
-        struct CyclesEnvelope {
-            cycles: IssueEdgeConnection<CycleSummary>,
+        struct StateCreateEnvelope {
+            #[serde(rename = "workflowStateCreate")]
+            state_create: StatePayload,
         }
 
-        const QUERY: &str = r#"
-            query ListCycles($first: Int!, $filter: CycleFilter, $orderBy: CycleOrderByInput, $after: String) {
-                cycles(first: $first, filter: $filter, orderBy: $orderBy, after: $after) {
-                    edges {
-                        cursor
-                        node {
-                            id
-                            name
-                            number
-                            startsAt
-                            endsAt
-                            state
-                            team { id name key }
-                        }
-                    }
-                    pageInfo {
-                        hasNextPage
-                        endCursor
+        #[derive(Deserialize)]
+        struct StatePayload {
+            success: bool,
+            #[serde(rename = "workflowState")]
+            workflow_state: Option<WorkflowStateSummary>,
+            #[serde(rename = "userErrors", default)]
+            user_errors: Vec<ApiUserError>,
+        }
+
+        #[derive(Deserialize)]
+        struct ApiUserError {
+            message: Option<String>,
+        }
+
+        const MUTATION: &str = r#"
+            mutation WorkflowStateCreate($input: WorkflowStateCreateInput!) {
+                workflowStateCreate(input: $input) {
+                    success
+                    userErrors { message }
+                    workflowState {
+                        id
+                        name
+                        type
+                        color
+                        description
                     }
                 }
             }
         "#;
 
-        let response: GraphqlEnvelope<CyclesEnvelope> = self
+        let response: GraphqlEnvelope<StateCreateEnvelope> = self
             .post(Request {
-                query: QUERY,
-                variables: Variables {
-                    first: params.first as i64,
-                    filter: params.filter,
-                    order_by: params.order_by,
-                    after: params.after,
-                },
+                query: MUTATION,
+                variables: Variables { input },
             })
             .await?;
 
         if let Some(errors) = response.errors {
-            return Err(GraphqlError::ResponseErrors(errors));
+            return Err(GraphqlError::response_errors(MUTATION, errors));
         }
 
-        let connection = response.data.ok_or(GraphqlError::NotFound)?.cycles;
-        let nodes = connection.edges.into_iter().map(|edge| edge.node).collect();
-        Ok(CycleListResponse {
-            nodes,
-            end_cursor: connection.page_info.end_cursor,
-            has_next_page: connection.page_info.has_next_page,
-        })
+        let payload = response.data.ok_or(GraphqlError::NotFound)?.state_create;
+        if !payload.success {
+            let message = payload
+                .user_errors
+                .into_iter()
+                .filter_map(|err| err.message)
+                .collect::<Vec<_>>()
+                .join("; ");
+            return Err(GraphqlError::OperationFailed(if message.is_empty() {
+                "workflow state create failed".into()
+            } else {
+                message
+            }));
+        }
+
+        payload.workflow_state.ok_or(GraphqlError::NotFound)
     }
 
-    /// Update a cycle.
-    pub async fn cycle_update(
+    /// Update an existing workflow state.
+    pub async fn update_workflow_state(
         &self,
         id: &str,
-        input: CycleUpdateInput,
-    ) -> GraphqlResult<CycleSummary> {
+        input: WorkflowStateUpdateInput,
+    ) -> GraphqlResult<WorkflowStateSummary> {
         #[derive(Serialize)]
         struct Variables {
             id: String,
-            input: CycleUpdateInput,
+            input: WorkflowStateUpdateInput,
         }
 
         #[derive(Serialize)]
@@ -1220,15 +3287,16 @@ impl LinearGraphqlClient {
         }
 
         #[derive(Deserialize)]
-        struct CycleUpdateEnvelope {
-            #[serde(rename = "cycleUpdate")]
-            cycle_update: CycleUpdatePayload,
+        struct StateUpdateEnvelope {
+            #[serde(rename = "workflowStateUpdate")]
+            state_update: StatePayload,
         }
 
         #[derive(Deserialize)]
-        struct CycleUpdatePayload {
+        struct StatePayload {
             success: bool,
-            cycle: Option<CycleSummary>,
+            #[serde(rename = "workflowState")]
+            workflow_state: Option<WorkflowStateSummary>,
             #[serde(rename = "userErrors", default)]
             user_errors: Vec<ApiUserError>,
         }
@@ -1239,24 +3307,22 @@ impl LinearGraphqlClient {
         }
 
         const MUTATION: &str = r#"
-            mutation CycleUpdate($id: String!, $input: CycleUpdateInput!) {
-                cycleUpdate(id: $id, input: $input) {
+            mutation WorkflowStateUpdate($id: String!, $input: WorkflowStateUpdateInput!) {
+                workflowStateUpdate(id: $id, input: $input) {
                     success
                     userErrors { message }
-                    cycle {
+                    workflowState {
                         id
                         name
-                        number
-                        startsAt
-                        endsAt
-                        state
-                        team { id name key }
+                        type
+                        color
+                        description
                     }
                 }
             }
         "#;
 
-        let response: GraphqlEnvelope<CycleUpdateEnvelope> = self
+        let response: GraphqlEnvelope<StateUpdateEnvelope> = self
             .post(Request {
                 query: MUTATION,
                 variables: Variables {
@@ -1267,10 +3333,10 @@ impl LinearGraphqlClient {
             .await?;
 
         if let Some(errors) = response.errors {
-            return Err(GraphqlError::ResponseErrors(errors));
+            return Err(GraphqlError::response_errors(MUTATION, errors));
         }
 
-        let payload = response.data.ok_or(GraphqlError::NotFound)?.cycle_update;
+        let payload = response.data.ok_or(GraphqlError::NotFound)?.state_update;
         if !payload.success {
             let message = payload
                 .user_errors
@@ -1279,68 +3345,58 @@ impl LinearGraphqlClient {
                 .collect::<Vec<_>>()
                 .join("; ");
             return Err(GraphqlError::OperationFailed(if message.is_empty() {
-                "cycle update failed".into()
+                "workflow state update failed".into()
             } else {
                 message
             }));
         }
 
-        payload.cycle.ok_or(GraphqlError::NotFound)
+        payload.workflow_state.ok_or(GraphqlError::NotFound)
     }
 
-    /// List issue labels for a team.
-    pub async fn issue_labels(&self, team_id: &str) -> GraphqlResult<Vec<IssueLabel>> {
-        #[derive(Serialize)]
-        struct Variables<'a> {
-            team_id: &'a str,
-        }
-
+    /// List all templates visible to the session.
+    pub async fn templates(&self) -> GraphqlResult<Vec<TemplateSummary>> {
         #[derive(Serialize)]
         struct Request<'a> {
             query: &'a str,
-            variables: Variables<'a>,
         }
 
         #[derive(Deserialize)]
-        struct LabelsEnvelope {
-            issue_labels: IssueLabelConnection,
+        struct TemplatesEnvelope {
+            templates: Vec<TemplateSummary>,
         }
 
         const QUERY: &str = r#"
-            query IssueLabels($teamId: String!) {
-                issueLabels(filter: { team: { id: { eq: $teamId } } }) {
-                    nodes {
-                        id
-                        name
-                        color
-                    }
+            query Templates {
+                templates {
+                    id
+                    name
+                    type
+                    description
+                    templateData
+                    team { id name key }
                 }
             }
         "#;
 
-        let response: GraphqlEnvelope<LabelsEnvelope> = self
-            .post(Request {
-                query: QUERY,
-                variables: Variables { team_id },
-            })
-            .await?;
+        let response: GraphqlEnvelope<TemplatesEnvelope> =
+            self.post_cached(Request { query: QUERY }).await?;
 
         if let Some(errors) = response.errors {
-            return Err(GraphqlError::ResponseErrors(errors));
+            return Err(GraphqlError::response_errors(QUERY, errors));
         }
 
-        let connection = response.data.ok_or(GraphqlError::NotFound)?.issue_labels;
-        Ok(connection.nodes)
+        Ok(response.data.ok_or(GraphqlError::NotFound)?.templates)
     }
 
-    /// Create a new issue label.
-    pub async fn create_issue_label(
+    /// Create a template.
+    pub async fn create_template(
         &self,
-        input: IssueLabelCreateInput,
-    ) -> GraphqlResult<IssueLabel> {
+        input: TemplateCreateInput,
+    ) -> GraphqlResult<TemplateSummary> {
         #[derive(Serialize)]
         struct Variables {
-            input: IssueLabelCreateInput,
+            input: TemplateCreateInput,
         }
 
         #[derive(Serialize)]
@@ -1350,39 +3406,34 @@ impl LinearGraphqlClient {
         }
 
         #[derive(Deserialize)]
-        struct LabelCreateEnvelope {
-            #[serde(rename = "issueLabelCreate")]
-            label_create: LabelPayload,
+        struct TemplateCreateEnvelope {
+            #[serde(rename = "templateCreate")]
+            template_create: TemplatePayload,
         }
 
         #[derive(Deserialize)]
-        struct LabelPayload {
+        struct TemplatePayload {
             success: bool,
-            issue_label: Option<IssueLabel>,
-            #[serde(rename = "userErrors", default)]
-            user_errors: Vec<ApiUserError>,
-        }
-
-        #[derive(Deserialize)]
-        struct ApiUserError {
-            message: Option<String>,
+            template: Option<TemplateSummary>,
         }
 
         const MUTATION: &str = r#"
-            mutation IssueLabelCreate($input: IssueLabelCreateInput!) {
-                issueLabelCreate(input: $input) {
+            mutation TemplateCreate($input: TemplateCreateInput!) {
+                templateCreate(input: $input) {
                     success
-                    userErrors { message }
-                    issueLabel {
+                    template {
                         id
                         name
-                        color
+                        type
+                        description
+                        templateData
+                        team { id name key }
                     }
                 }
             }
         "#;
 
-        let response: GraphqlEnvelope<LabelCreateEnvelope> = self
+        let response: GraphqlEnvelope<TemplateCreateEnvelope> = self
             .post(Request {
                 query: MUTATION,
                 variables: Variables { input },
@@ -1390,37 +3441,29 @@ impl LinearGraphqlClient {
             .await?;
 
         if let Some(errors) = response.errors {
-            return Err(GraphqlError::ResponseErrors(errors));
+            return Err(GraphqlError::response_errors(MUTATION, errors));
         }
 
-        let payload = response.data.ok_or(GraphqlError::NotFound)?.label_create;
+        let payload = response.data.ok_or(GraphqlError::NotFound)?.template_create;
         if !payload.success {
-            let message = payload
-                .user_errors
-                .into_iter()
-                .filter_map(|err| err.message)
-                .collect::<Vec<_>>()
-                .join("; ");
-            return Err(GraphqlError::OperationFailed(if message.is_empty() {
-                "issue label create failed".into()
-            } else {
-                message
-            }));
+            return Err(GraphqlError::OperationFailed(
+                "template create failed".into(),
+            ));
         }
 
-        payload.issue_label.ok_or(GraphqlError::NotFound)
+        payload.template.ok_or(GraphqlError::NotFound)
     }
 
-    /// Update an existing issue label.
-    pub async fn update_issue_label(
+    /// Update an existing template.
+    pub async fn update_template(
         &self,
         id: &str,
-        input: IssueLabelUpdateInput,
-    ) -> GraphqlResult<IssueLabel> {
+        input: TemplateUpdateInput,
+    ) -> GraphqlResult<TemplateSummary> {
         #[derive(Serialize)]
         struct Variables {
             id: String,
-            input: IssueLabelUpdateInput,
+            input: TemplateUpdateInput,
         }
 
         #[derive(Serialize)]
@@ -1430,39 +3473,34 @@ impl LinearGraphqlClient {
         }
 
         #[derive(Deserialize)]
-        struct LabelUpdateEnvelope {
-            #[serde(rename = "issueLabelUpdate")]
-            label_update: LabelPayload,
+        struct TemplateUpdateEnvelope {
+            #[serde(rename = "templateUpdate")]
+            template_update: TemplatePayload,
         }
 
         #[derive(Deserialize)]
-        struct LabelPayload {
+        struct TemplatePayload {
             success: bool,
-            issue_label: Option<IssueLabel>,
-            #[serde(rename = "userErrors", default)]
-            user_errors: Vec<ApiUserError>,
-        }
-
-        #[derive(Deserialize)]
-        struct ApiUserError {
-            message: Option<String>,
+            template: Option<TemplateSummary>,
         }
 
         const MUTATION: &str = r#"
-            mutation IssueLabelUpdate($id: String!, $input: IssueLabelUpdateInput!) {
-                issueLabelUpdate(id: $id, input: $input) {
+            mutation TemplateUpdate($id: String!, $input: TemplateUpdateInput!) {
+                templateUpdate(id: $id, input: $input) {
                     success
-                    userErrors { message }
-                    issueLabel {
+                    template {
                         id
                         name
-                        color
+                        type
+                        description
+                        templateData
+                        team { id name key }
                     }
                 }
             }
         "#;
 
-        let response: GraphqlEnvelope<LabelUpdateEnvelope> = self
+        let response: GraphqlEnvelope<TemplateUpdateEnvelope> = self
             .post(Request {
                 query: MUTATION,
                 variables: Variables {
@@ -1473,49 +3511,219 @@ impl LinearGraphqlClient {
             .await?;
 
         if let Some(errors) = response.errors {
-            return Err(GraphqlError::ResponseErrors(errors));
+            return Err(GraphqlError::response_errors(MUTATION, errors));
         }
 
-        let payload = response.data.ok_or(GraphqlError::NotFound)?.label_update;
+        let payload = response.data.ok_or(GraphqlError::NotFound)?.template_update;
         if !payload.success {
-            let message = payload
-                .user_errors
-                .into_iter()
-                .filter_map(|err| err.message)
-                .collect::<Vec<_>>()
-                .join("; ");
-            return Err(GraphqlError::OperationFailed(if message.is_empty() {
-                "issue label update failed".into()
-            } else {
-                message
-            }));
+            return Err(GraphqlError::OperationFailed(
+                "template update failed".into(),
+            ));
         }
 
-        payload.issue_label.ok_or(GraphqlError::NotFound)
+        payload.template.ok_or(GraphqlError::NotFound)
     }
 
     async fn post<T, R>(&self, body: T) -> GraphqlResult<R>
     where
         T: Serialize,
-        R: DeserializeOwned,
+        R: DeserializeOwned + Send + 'static,
     {
-        let response = self
+        self.send(body, false).await
+    }
+
+    /// [`post`](Self::post) for a read whose response may come from the [`ResponseCache`].
+    async fn post_cached<T, R>(&self, body: T) -> GraphqlResult<R>
+    where
+        T: Serialize,
+        R: DeserializeOwned + Send + 'static,
+    {
+        self.send(body, true).await
+    }
+
+    async fn send<T, R>(&self, body: T, cacheable: bool) -> GraphqlResult<R>
+    where
+        T: Serialize,
+        R: DeserializeOwned + Send + 'static,
+    {
+        if self.dry_run {
+            if let Some(request) = self.capture_mutation(&body)? {
+                return Err(GraphqlError::DryRun(Box::new(request)));
+            }
+        }
+
+        let recorded_request = match &self.tape {
+            Some(_) => Some(serde_json::to_value(&body)?),
+            None => None,
+        };
+        if let (Some(tape), Some(request)) = (&self.tape, &recorded_request) {
+            if tape.is_replay() {
+                let (status, text) = tape.next_response(request)?;
+                let status = StatusCode::from_u16(status).unwrap_or(StatusCode::OK);
+                self.connection.metrics.record_response(status, None);
+                return Self::decode(status, text);
+            }
+        }
+
+        let cache = match (&self.cache, &self.tape) {
+            (Some(cache), None) => {
+                let payload = serde_json::to_value(&body)?;
+                let key = CacheKey::new(
+                    self.connection.endpoint.as_str(),
+                    &self.connection.auth_header,
+                    &payload,
+                );
+                Some((cache, key, selected_mutation(&payload).is_some()))
+            }
+            _ => None,
+        };
+        let mut stale = None;
+        if let (true, Some((cache, key, _))) = (cacheable, &cache) {
+            match cache.lookup(key) {
+                Lookup::Fresh(text) => return Self::decode(StatusCode::OK, text),
+                Lookup::Stale(entry) if entry.revalidatable() => stale = Some(entry),
+                _ => {}
+            }
+        }
+
+        let _permit = self.connection.scheduler.acquire(self.priority).await;
+        let mut request = self
+            .connection
             .http
-            .post(self.endpoint.clone())
-            .header("Authorization", &self.auth_header)
-            .json(&body)
-            .send()
-            .await?;
+            .post(self.connection.endpoint.clone())
+            .header("Authorization", &self.connection.auth_header)
+            .json(&body);
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+        if let Some(entry) = &stale {
+            if let Some(etag) = &entry.etag {
+                request = request.header(IF_NONE_MATCH, etag);
+            }
+            if let Some(modified) = &entry.last_modified {
+                request = request.header(IF_MODIFIED_SINCE, modified);
+            }
+        }
+        let response = request.send().await.map_err(|err| {
+            self.connection.metrics.record_unreachable();
+            self.http_error(err)
+        })?;
 
         let status = response.status();
+        self.connection
+            .metrics
+            .record_response(status, RateLimitStatus::from_headers(response.headers()));
+        match (&cache, stale) {
+            (Some((cache, key, true)), _) => cache.bust(key),
+            (Some((cache, key, false)), Some(entry)) if status == StatusCode::NOT_MODIFIED => {
+                let text = entry.body.clone();
+                cache.refresh(key, entry);
+                return Self::decode(StatusCode::OK, text);
+            }
+            _ => {}
+        }
+        let store = match &cache {
+            Some((cache, key, false)) if cacheable && status.is_success() => {
+                let header = |name| {
+                    response
+                        .headers()
+                        .get(name)
+                        .and_then(|value: &reqwest::header::HeaderValue| value.to_str().ok())
+                        .map(str::to_owned)
+                };
+                Some((cache, key, header(ETAG), header(LAST_MODIFIED)))
+            }
+            _ => None,
+        };
+        // Recording and caching need the body as text, and error bodies are reported whole.
+        if status.is_success()
+            && self.tape.is_none()
+            && store.is_none()
+            && stream::worth_streaming(&response)
+        {
+            return stream::decode(response, |err| self.http_error(err)).await;
+        }
+        let text = response.text().await.map_err(|err| self.http_error(err))?;
+        if let (Some(tape), Some(request)) = (&self.tape, recorded_request) {
+            tape.capture(request, status.as_u16(), &text)?;
+        }
+        if let Some((cache, key, etag, last_modified)) = store {
+            let failed = serde_json::from_str::<Value>(&text)
+                .map_or(true, |payload| payload.get("errors").is_some());
+            if !failed {
+                cache.store(key, etag, last_modified, text.clone());
+            }
+        }
+        Self::decode(status, text)
+    }
+
+    fn http_error(&self, err: reqwest::Error) -> GraphqlError {
+        match self.timeout {
+            Some(timeout) if err.is_timeout() => GraphqlError::Timeout(timeout),
+            _ => GraphqlError::Http(err),
+        }
+    }
+
+    fn decode<R: DeserializeOwned>(status: StatusCode, text: String) -> GraphqlResult<R> {
         if !status.is_success() {
-            let text = response.text().await.unwrap_or_default();
             return Err(GraphqlError::HttpStatus { status, body: text });
         }
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    fn capture_mutation<T: Serialize>(&self, body: &T) -> GraphqlResult<Option<DryRunRequest>> {
+        let mut payload = serde_json::to_value(body)?;
+        let Some((query, mutation)) = selected_mutation(&payload) else {
+            return Ok(None);
+        };
+        let operation = mutation
+            .name
+            .unwrap_or_else(|| operation_name(&query).to_string());
+        let authorization = match self.connection.auth_header.split_once(' ') {
+            Some((scheme, _)) => format!("{scheme} [redacted]"),
+            None => "[redacted]".to_string(),
+        };
+        Ok(Some(DryRunRequest {
+            endpoint: self.connection.endpoint.to_string(),
+            authorization,
+            operation,
+            query,
+            variables: payload
+                .get_mut("variables")
+                .map(Value::take)
+                .unwrap_or(Value::Null),
+        }))
+    }
+}
+
+/// The mutation a request body runs, with its dedented document, or `None` for a query.
+fn selected_mutation(payload: &Value) -> Option<(String, Operation)> {
+    let query = payload
+        .get("query")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    let query = dedent(query);
+    let selected = payload.get("operationName").and_then(Value::as_str);
+    let operation = document::operations(&query)
+        .into_iter()
+        .find(|operation| selected.map_or(true, |name| operation.name.as_deref() == Some(name)))?;
+    (operation.kind == OperationKind::Mutation).then_some((query, operation))
+}
 
-        let payload = response.json::<R>().await?;
-        Ok(payload)
-    }
+/// Strip the shared leading indentation from an embedded GraphQL document.
+fn dedent(source: &str) -> String {
+    let indent = source
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    source
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.get(indent..).unwrap_or(line.trim_start()))
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
 #[derive(Debug, Deserialize)]
@@ -1529,6 +3737,32 @@ struct ViewerEnvelope {
     viewer: Viewer,
 }
 
+/// Workspace metadata; `url_key` is the slug in `https://linear.app/<url_key>/...`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Organization {
+    pub id: String,
+    pub name: String,
+    pub url_key: String,
+}
+
+/// The viewer's role in the workspace and in each team they belong to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ViewerPermissions {
+    pub organization: Organization,
+    pub admin: bool,
+    pub guest: bool,
+    pub team_memberships: Vec<TeamMembership>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamMembership {
+    #[serde(default)]
+    pub owner: bool,
+    pub team: TeamSummary,
+}
+
 /// Subset of viewer fields useful for identity-aware commands.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -1547,11 +3781,17 @@ pub struct IssueSummary {
     pub identifier: String,
     pub title: String,
     pub url: Option<String>,
+    #[serde(default)]
+    pub branch_name: Option<String>,
     pub state: Option<IssueState>,
     pub assignee: Option<IssueAssignee>,
     pub priority: Option<i32>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    #[serde(default)]
+    pub project: Option<ProjectRef>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<IssueLabelConnection>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1562,9 +3802,15 @@ pub struct IssueDetail {
     pub title: String,
     pub description: Option<String>,
     pub url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch_name: Option<String>,
     pub state: Option<IssueState>,
     pub assignee: Option<IssueAssignee>,
     pub priority: Option<i32>,
+    #[serde(default)]
+    pub estimate: Option<f64>,
+    #[serde(default)]
+    pub due_date: Option<NaiveDate>,
     pub labels: Option<IssueLabelConnection>,
     pub team: Option<TeamSummary>,
     pub created_at: DateTime<Utc>,
@@ -1573,6 +3819,14 @@ pub struct IssueDetail {
     pub history: Option<IssueHistoryConnection>,
     #[serde(rename = "children")]
     pub sub_issues: Option<IssueSubIssueConnection>,
+    #[serde(default)]
+    pub subscribers: Option<UserConnection>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserConnection {
+    pub nodes: Vec<UserSummary>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1602,6 +3856,8 @@ pub struct IssueLabel {
     pub id: String,
     pub name: String,
     pub color: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1637,6 +3893,8 @@ pub struct IssueHistory {
     pub updated_description: Option<String>,
     #[serde(default)]
     pub actors: Vec<UserSummary>,
+    #[serde(default)]
+    pub added_labels: Option<Vec<IssueLabel>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1669,12 +3927,84 @@ pub struct TeamSummary {
     pub key: String,
 }
 
+/// Team settings shown by `linear team settings`, with cycle lengths in weeks and the cycle
+/// start day as 0 (Sunday) to 6.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamSettings {
+    pub id: String,
+    pub key: String,
+    pub name: String,
+    pub cycles_enabled: bool,
+    pub cycle_duration: f64,
+    pub cycle_cooldown_time: f64,
+    pub cycle_start_day: f64,
+    pub upcoming_cycle_count: f64,
+    pub cycle_issue_auto_assign_started: bool,
+    pub cycle_issue_auto_assign_completed: bool,
+    /// `notUsed`, `exponential`, `fibonacci`, `linear`, or `tShirt`.
+    pub issue_estimation_type: String,
+    pub issue_estimation_allow_zero: bool,
+    pub issue_estimation_extended: bool,
+    pub default_issue_estimate: f64,
+    pub triage_enabled: bool,
+    pub default_template_for_members: Option<TemplateRef>,
+    pub default_template_for_non_members: Option<TemplateRef>,
+    pub default_project_template: Option<TemplateRef>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemplateRef {
+    pub id: String,
+    pub name: String,
+}
+
+/// Changes to a team's settings; template ids are `Some(None)` to clear a default.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamUpdateInput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cycles_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cycle_duration: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cycle_cooldown_time: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cycle_start_day: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upcoming_cycle_count: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cycle_issue_auto_assign_started: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cycle_issue_auto_assign_completed: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issue_estimation_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issue_estimation_allow_zero: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issue_estimation_extended: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_issue_estimate: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub triage_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_template_for_members_id: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_template_for_non_members_id: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_project_template_id: Option<Option<String>>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorkflowStateSummary {
     pub id: String,
     pub name: String,
     #[serde(rename = "type")]
     pub type_name: Option<String>,
+    #[serde(default)]
+    pub color: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -1682,6 +4012,8 @@ pub struct IssueListParams {
     pub first: usize,
     pub filter: Option<Value>,
     pub after: Option<String>,
+    /// `[IssueSortInput!]`; `None` keeps the newest update first.
+    pub sort: Option<Value>,
 }
 
 /// Input used when creating a new issue.
@@ -1700,6 +4032,14 @@ pub struct IssueCreateInput {
     pub label_ids: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub priority: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimate: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<NaiveDate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
 }
 
 impl IssueCreateInput {
@@ -1712,6 +4052,10 @@ impl IssueCreateInput {
             state_id: None,
             label_ids: Vec::new(),
             priority: None,
+            estimate: None,
+            due_date: None,
+            project_id: None,
+            parent_id: None,
         }
     }
 }
@@ -1734,6 +4078,18 @@ pub struct IssueUpdateInput {
     pub priority: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub project_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cycle_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscriber_ids: Option<Vec<String>>,
+    /// `Some(None)` clears the estimate.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimate: Option<Option<i32>>,
+    /// `Some(None)` clears the due date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<Option<NaiveDate>>,
 }
 
 /// Input used when creating a new comment.
@@ -1751,6 +4107,14 @@ pub struct IssueListResponse {
     pub has_next_page: bool,
 }
 
+/// Raw records of one [`BackupEntity`], as returned by [`LinearGraphqlClient::backup_page`].
+#[derive(Debug, Clone)]
+pub struct BackupPage {
+    pub nodes: Vec<Value>,
+    pub end_cursor: Option<String>,
+    pub has_next_page: bool,
+}
+
 #[derive(Debug, Clone)]
 pub struct ProjectListParams {
     pub first: usize,
@@ -1782,6 +4146,8 @@ pub struct ProjectCreateInput {
     pub team_ids: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub lead_id: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub member_ids: Vec<String>,
 }
 
 impl ProjectCreateInput {
@@ -1794,10 +4160,29 @@ impl ProjectCreateInput {
             target_date: None,
             team_ids: Vec::new(),
             lead_id: None,
+            member_ids: Vec::new(),
         }
     }
 }
 
+/// Input used when adding a milestone to a project.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectMilestoneCreateInput {
+    pub project_id: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_date: Option<NaiveDate>,
+}
+
+/// Input used when posting a project status update.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectStatusUpdateCreateInput {
+    pub project_id: String,
+    pub body: String,
+}
+
 #[derive(Debug, Clone, Default, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectUpdateInput {
@@ -1815,6 +4200,9 @@ pub struct ProjectUpdateInput {
     pub team_ids: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub lead_id: Option<String>,
+    /// Replaces the project's members.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub member_ids: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -1867,6 +4255,76 @@ pub struct IssueLabelUpdateInput {
     pub color: Option<String>,
 }
 
+/// Input used when relating two issues; `type_name` is `duplicate`, `blocks`, or `related`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueRelationCreateInput {
+    pub issue_id: String,
+    pub related_issue_id: String,
+    #[serde(rename = "type")]
+    pub type_name: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkflowStateCreateInput {
+    pub team_id: String,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub color: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkflowStateUpdateInput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// Issue/project template; `template_data` holds the prefilled fields as raw JSON.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateSummary {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub template_data: Value,
+    pub team: Option<TeamSummary>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateCreateInput {
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub team_id: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub template_data: Value,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateUpdateInput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_data: Option<Value>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Comment {
@@ -1877,6 +4335,86 @@ pub struct Comment {
     pub user: Option<UserSummary>,
 }
 
+/// Document returned by search.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentSummary {
+    pub id: String,
+    pub title: String,
+    pub slug_id: Option<String>,
+    pub url: Option<String>,
+    pub updated_at: DateTime<Utc>,
+    pub project: Option<ProjectRef>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectRef {
+    pub id: String,
+    pub name: String,
+}
+
+/// User with contact details, as returned by user search.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserProfile {
+    pub id: String,
+    pub name: Option<String>,
+    pub display_name: Option<String>,
+    pub email: Option<String>,
+    #[serde(default)]
+    pub active: bool,
+}
+
+/// A user with the custom status shown next to their name in the app.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserStatus {
+    pub id: String,
+    pub name: Option<String>,
+    pub display_name: Option<String>,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub active: bool,
+    /// Emoji shortcode such as `:palm_tree:`, or the emoji itself.
+    #[serde(default)]
+    pub status_emoji: Option<String>,
+    #[serde(default)]
+    pub status_label: Option<String>,
+    /// When the status clears itself.
+    #[serde(default)]
+    pub status_until_at: Option<DateTime<Utc>>,
+}
+
+impl UserStatus {
+    pub fn has_status(&self) -> bool {
+        self.status_emoji.is_some() || self.status_label.is_some()
+    }
+}
+
+/// Custom status to set on a user. Fields left `None` are sent as `null`, clearing them.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserStatusInput {
+    pub status_emoji: Option<String>,
+    pub status_label: Option<String>,
+    pub status_until_at: Option<DateTime<Utc>>,
+}
+
+/// Team member as listed for mention pickers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamMember {
+    pub id: String,
+    pub name: String,
+    pub display_name: String,
+    pub email: Option<String>,
+    /// Profile URL; Linear renders it as an @mention and notifies the user.
+    pub url: String,
+    #[serde(default)]
+    pub active: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UserSummary {
@@ -1895,6 +4433,11 @@ pub struct ProjectSummary {
     pub start_date: Option<String>,
     pub target_date: Option<String>,
     pub status: Option<String>,
+    #[serde(default)]
+    pub url: Option<String>,
+    /// Share of the project's scope completed, from 0 to 1.
+    #[serde(default)]
+    pub progress: Option<f64>,
     pub updated_at: DateTime<Utc>,
     pub created_at: DateTime<Utc>,
     pub lead: Option<UserSummary>,
@@ -1910,12 +4453,36 @@ pub struct ProjectDetail {
     pub start_date: Option<String>,
     pub target_date: Option<String>,
     pub status: Option<String>,
+    #[serde(default)]
+    pub url: Option<String>,
+    /// Share of the project's scope completed, from 0 to 1; only [`LinearGraphqlClient::project`]
+    /// selects it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub progress: Option<f64>,
     pub updated_at: DateTime<Utc>,
     pub created_at: DateTime<Utc>,
     pub lead: Option<UserSummary>,
     pub teams: Vec<TeamSummary>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectMilestone {
+    pub id: String,
+    pub name: String,
+    pub target_date: Option<NaiveDate>,
+}
+
+/// A status update posted on a project (`ProjectUpdate` in Linear's schema).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectStatusUpdate {
+    pub id: String,
+    pub body: String,
+    #[serde(default)]
+    pub url: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CycleSummary {
@@ -1925,14 +4492,141 @@ pub struct CycleSummary {
     pub starts_at: Option<String>,
     pub ends_at: Option<String>,
     pub state: Option<String>,
+    #[serde(default)]
+    pub is_active: bool,
+    /// Issues in scope per day of the cycle; only [`LinearGraphqlClient::cycles`] selects it.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub issue_count_history: Vec<f64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub completed_issue_count_history: Vec<f64>,
     pub team: Option<TeamSummary>,
 }
 
+impl CycleSummary {
+    /// Completed issues and issues in scope as of the latest day recorded.
+    pub fn issue_counts(&self) -> Option<(u32, u32)> {
+        let scope = *self.issue_count_history.last()?;
+        let completed = self
+            .completed_issue_count_history
+            .last()
+            .copied()
+            .unwrap_or_default();
+        Some((completed as u32, scope as u32))
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct GraphqlResponseError {
     pub message: String,
+    /// Response path of the failing field; entries are field names or list indices.
     #[serde(default)]
-    pub path: Option<Vec<String>>,
+    pub path: Option<Vec<Value>>,
+    #[serde(default)]
+    pub extensions: Option<Value>,
+}
+
+impl GraphqlResponseError {
+    /// Machine-readable error code reported under `extensions.code`, when present.
+    pub fn code(&self) -> Option<&str> {
+        self.extensions
+            .as_ref()
+            .and_then(|ext| ext.get("code"))
+            .and_then(Value::as_str)
+    }
+
+    /// OAuth scope named by a missing-scope error, e.g. `write` for a read-only token.
+    pub fn missing_scope(&self) -> Option<String> {
+        [self.message.as_str(), self.user_message()]
+            .into_iter()
+            .filter(|message| {
+                let message = message.to_ascii_lowercase();
+                message.contains("scope")
+                    && (message.contains("invalid")
+                        || message.contains("missing")
+                        || message.contains("required")
+                        || message.contains("insufficient"))
+            })
+            .find_map(required_scope)
+    }
+
+    /// Linear's user-facing wording (`extensions.userPresentableMessage`), else `message`.
+    pub fn user_message(&self) -> &str {
+        self.extensions
+            .as_ref()
+            .and_then(|ext| ext.get("userPresentableMessage"))
+            .and_then(Value::as_str)
+            .filter(|message| !message.trim().is_empty())
+            .unwrap_or(&self.message)
+    }
+
+    /// Suggested fix for common failures: unknown filter fields, missing permissions, missing
+    /// entities, rejected credentials, and rate limits.
+    pub fn hint(&self) -> Option<String> {
+        let message = self.message.to_ascii_lowercase();
+        if let Some((field, type_name)) = undefined_field(&self.message) {
+            return Some(if type_name.ends_with("Filter") {
+                format!(
+                    "`{field}` is not a field of {type_name}; check the filter against Linear's API schema"
+                )
+            } else {
+                format!("`{field}` is not a field of {type_name}; this linear build may be older than the API")
+            });
+        }
+        if let Some(scope) = self.missing_scope() {
+            return Some(format!(
+                "the token lacks the `{scope}` scope; run `linear auth login --scope {scope}` to add it"
+            ));
+        }
+        match self.code() {
+            Some("AUTHENTICATION_ERROR") => {
+                Some("credentials were rejected; run `linear auth login` again".into())
+            }
+            Some("FORBIDDEN") => Some(
+                "this account lacks permission for the operation; ask a workspace admin, or log in with an API key or OAuth scopes that allow it".into(),
+            ),
+            Some("ENTITY_NOT_FOUND") => Some(
+                "check the key or id, and that it belongs to the workspace of the selected --profile".into(),
+            ),
+            Some("RATELIMITED") => {
+                Some("Linear's rate limit was hit; wait a minute and retry".into())
+            }
+            _ if message.contains("permission") || message.contains("not authorized") => Some(
+                "this account lacks permission for the operation; ask a workspace admin, or log in with an API key or OAuth scopes that allow it".into(),
+            ),
+            _ if message.contains("not found") => Some(
+                "check the key or id, and that it belongs to the workspace of the selected --profile".into(),
+            ),
+            _ => None,
+        }
+    }
+}
+
+/// `write` from scope errors such as ``Invalid scope: `write` required`` or
+/// `Missing required scope: admin`.
+fn required_scope(message: &str) -> Option<String> {
+    let lower = message.to_ascii_lowercase();
+    let rest = &message[lower.find("scope")? + "scope".len()..];
+    let quoted = rest
+        .split(['`', '\'', '"'])
+        .nth(1)
+        .filter(|scope| !scope.trim().is_empty());
+    let scope = match quoted {
+        Some(scope) => scope,
+        None => rest
+            .split(|c: char| c.is_whitespace() || c == ':' || c == ',')
+            .find(|word| !word.is_empty() && !word.eq_ignore_ascii_case("required"))?,
+    };
+    let scope = scope.trim_matches(|c: char| !c.is_ascii_alphanumeric() && c != ':' && c != '_');
+    (!scope.is_empty()).then(|| scope.to_owned())
+}
+
+/// `("foo", "IssueFilter")` from GraphQL's `Field "foo" is not defined by type "IssueFilter".`
+fn undefined_field(message: &str) -> Option<(&str, &str)> {
+    let rest = message.split_once("Field \"")?.1;
+    let (field, rest) = rest.split_once('"')?;
+    let rest = rest.split_once("is not defined by type \"")?.1;
+    let (type_name, _) = rest.split_once('"')?;
+    Some((field, type_name))
 }
 
 #[derive(Debug, Deserialize)]
@@ -1967,6 +4661,75 @@ mod tests {
         AuthSession::new_api_key("test-key".into())
     }
 
+    #[tokio::test]
+    async fn response_errors_name_operation_and_hint() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(POST).path("/graphql");
+            then.status(200).json_body_obj(&serde_json::json!({
+                "data": null,
+                "errors": [{
+                    "message": "Forbidden",
+                    "path": ["teams", 0],
+                    "extensions": {
+                        "code": "FORBIDDEN",
+                        "userPresentableMessage": "You don't have access to this team."
+                    }
+                }]
+            }));
+        });
+
+        let client = LinearGraphqlClient::with_endpoint(
+            &sample_session(),
+            &format!("{}{}", server.base_url(), "/graphql"),
+        )
+        .unwrap();
+        let err = client.teams().await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "TeamsQuery failed: You don't have access to this team. (FORBIDDEN)"
+        );
+        assert!(err.hint().unwrap().contains("permission"));
+
+        let filter: GraphqlResponseError = serde_json::from_value(serde_json::json!({
+            "message": "Field \"titel\" is not defined by type \"IssueFilter\".",
+            "extensions": { "code": "GRAPHQL_VALIDATION_FAILED" }
+        }))
+        .unwrap();
+        assert_eq!(
+            filter.hint().unwrap(),
+            "`titel` is not a field of IssueFilter; check the filter against Linear's API schema"
+        );
+    }
+
+    #[test]
+    fn detects_missing_scopes() {
+        let error = |message: &str| -> GraphqlResponseError {
+            serde_json::from_value(serde_json::json!({
+                "message": message,
+                "extensions": { "code": "FORBIDDEN" }
+            }))
+            .unwrap()
+        };
+        assert_eq!(
+            error("Invalid scope: `write` required").missing_scope(),
+            Some("write".into())
+        );
+        assert_eq!(
+            error("Missing required scope: admin").missing_scope(),
+            Some("admin".into())
+        );
+        assert_eq!(
+            error("Insufficient scope 'issues:create'").missing_scope(),
+            Some("issues:create".into())
+        );
+        assert_eq!(error("Forbidden").missing_scope(), None);
+        assert!(error("Invalid scope: `write` required")
+            .hint()
+            .unwrap()
+            .contains("linear auth login --scope write"));
+    }
+
     #[tokio::test]
     async fn viewer_success() {
         let server = MockServer::start();
@@ -1994,6 +4757,155 @@ mod tests {
         let viewer = client.viewer().await.unwrap();
         mock.assert();
         assert_eq!(viewer.id, "user-1");
+        assert!(client.rate_limit().is_none());
+    }
+
+    #[test]
+    fn fetch_upload_sends_credentials_over_https_to_linear_hosts_only() {
+        let client = LinearGraphqlClient::from_session(&sample_session()).unwrap();
+        let sends = |url: &str| client.sends_credentials(&Url::parse(url).unwrap());
+        assert!(sends("https://uploads.linear.app/a/b.png"));
+        assert!(sends("https://api.linear.app/files/b.png"));
+        assert!(!sends("http://uploads.linear.app/a/b.png"));
+        assert!(!sends("https://uploads.linear.app.evil.test/b.png"));
+        assert!(!sends("https://example.com/b.png"));
+
+        let from = Url::parse("https://uploads.linear.app/a/b.png").unwrap();
+        let follow = |location: &str, credentials: bool| {
+            client
+                .redirect_target(&from, location, credentials)
+                .map(String::from)
+        };
+        assert_eq!(
+            follow("/a/c.png", true).unwrap(),
+            "https://uploads.linear.app/a/c.png"
+        );
+        assert!(matches!(
+            follow("https://example.com/c.png", true),
+            Err(GraphqlError::OperationFailed(_))
+        ));
+        assert!(follow("http://uploads.linear.app/a/c.png", true).is_err());
+        assert!(follow("https://example.com/c.png", false).is_ok());
+    }
+
+    #[tokio::test]
+    async fn fetch_upload_follows_redirects_and_caps_the_body() {
+        let server = MockServer::start();
+        let image = server.mock(|when, then| {
+            when.method(GET)
+                .path("/uploads/image.png")
+                .matches(|request| {
+                    !request
+                        .headers
+                        .iter()
+                        .flatten()
+                        .any(|(name, _)| name.eq_ignore_ascii_case("authorization"))
+                });
+            then.status(200).body("png-bytes");
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/uploads/moved.png");
+            then.status(302).header("Location", "/uploads/image.png");
+        });
+        let client =
+            LinearGraphqlClient::with_endpoint(&sample_session(), &server.url("/graphql")).unwrap();
+
+        let bytes = client
+            .fetch_upload(&server.url("/uploads/moved.png"))
+            .await
+            .unwrap();
+        image.assert();
+        assert_eq!(bytes, b"png-bytes");
+
+        let capped = client.download(&server.url("/uploads/image.png"), 4).await;
+        assert!(matches!(capped, Err(GraphqlError::OperationFailed(_))));
+
+        let missing = client
+            .fetch_upload(&server.url("/uploads/missing.png"))
+            .await;
+        assert!(matches!(missing, Err(GraphqlError::HttpStatus { .. })));
+    }
+
+    #[tokio::test]
+    async fn captures_rate_limit_headers() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(POST).path("/graphql");
+            then.status(200)
+                .header("X-RateLimit-Requests-Limit", "1500")
+                .header("X-RateLimit-Requests-Remaining", "1420")
+                .header("X-RateLimit-Complexity-Remaining", "240000")
+                .json_body_obj(&serde_json::json!({
+                    "data": {
+                        "viewer": {
+                            "id": "user-1",
+                            "name": null,
+                            "displayName": null,
+                            "email": null,
+                            "createdAt": "2024-01-01T00:00:00.000Z"
+                        }
+                    }
+                }));
+        });
+
+        let client = LinearGraphqlClient::with_endpoint(
+            &sample_session(),
+            &format!("{}{}", server.base_url(), "/graphql"),
+        )
+        .unwrap();
+
+        client.viewer().await.unwrap();
+        let limits = client.rate_limit().expect("rate limit captured");
+        assert_eq!(limits.requests_limit, Some(1500));
+        assert_eq!(limits.requests_remaining, Some(1420));
+        assert_eq!(limits.complexity_remaining, Some(240000));
+        assert_eq!(limits.complexity_limit, None);
+    }
+
+    #[tokio::test]
+    async fn metrics_track_sync_and_connectivity() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(POST).path("/graphql");
+            then.status(200)
+                .header("X-RateLimit-Requests-Remaining", "99")
+                .json_body_obj(&serde_json::json!({
+                    "data": { "organization": { "id": "org-1", "name": "Acme", "urlKey": "acme" } }
+                }));
+        });
+        let client = LinearGraphqlClient::with_endpoint(
+            &sample_session(),
+            &format!("{}{}", server.base_url(), "/graphql"),
+        )
+        .unwrap();
+        let metrics = client.metrics();
+        assert_eq!(metrics.snapshot(), ClientMetrics::default());
+
+        client.organization().await.unwrap();
+        let seen = metrics.snapshot();
+        assert_eq!(seen.connectivity, Connectivity::Online);
+        assert!(seen.last_success.is_some());
+        assert_eq!(
+            seen.rate_limit.and_then(|limits| limits.requests_remaining),
+            Some(99)
+        );
+
+        // Clients sharing the handle see each other's failures.
+        let unreachable = LinearGraphqlClient {
+            connection: Arc::new(Connection {
+                http: client.connection.http.clone(),
+                endpoint: Url::parse("http://127.0.0.1:9/graphql").unwrap(),
+                auth_header: client.connection.auth_header.clone(),
+                metrics: metrics.clone(),
+                scheduler: Scheduler::new(metrics.clone()),
+            }),
+            ..client.clone()
+        };
+        assert!(unreachable.organization().await.is_err());
+        let offline = metrics.snapshot();
+        assert_eq!(offline.connectivity, Connectivity::Offline);
+        assert_eq!(offline.last_success, seen.last_success);
+        assert!(offline.rate_limit.is_some());
     }
 
     #[tokio::test]
@@ -2040,6 +4952,7 @@ mod tests {
                 first: 5,
                 filter: None,
                 after: None,
+                sort: None,
             })
             .await
             .unwrap();
@@ -2050,6 +4963,208 @@ mod tests {
         assert!(!issues.has_next_page);
     }
 
+    #[tokio::test]
+    async fn large_pages_are_decoded_as_they_stream() {
+        #[derive(Deserialize)]
+        struct Node {
+            identifier: String,
+            description: String,
+        }
+
+        let description = "lorem ipsum ".repeat(1_000);
+        let nodes: Vec<Value> = (1..=250)
+            .map(|n| serde_json::json!({ "identifier": format!("ENG-{n}"), "description": description }))
+            .collect();
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(POST).path("/graphql");
+            then.status(200).json_body_obj(&serde_json::json!({
+                "data": { "issues": {
+                    "nodes": nodes,
+                    "pageInfo": { "hasNextPage": false, "endCursor": null }
+                } }
+            }));
+        });
+
+        let client = LinearGraphqlClient::with_endpoint(
+            &sample_session(),
+            &format!("{}{}", server.base_url(), "/graphql"),
+        )
+        .unwrap();
+        let page = client
+            .query_issues::<Node>(&IssueQuery::new().first(250))
+            .await
+            .unwrap();
+        assert_eq!(page.nodes.len(), 250);
+        assert_eq!(page.nodes[249].identifier, "ENG-250");
+        assert_eq!(page.nodes[0].description, description);
+    }
+
+    #[tokio::test]
+    async fn backup_page_filters_by_watermark() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/graphql")
+                .body_contains("query BackupComments")
+                .body_contains(r#""updatedAt":{"gte":"2024-05-01T09:00:00+00:00"}"#);
+            then.status(200).json_body_obj(&serde_json::json!({
+                "data": { "comments": {
+                    "nodes": [{ "id": "comment-1", "body": "Done", "updatedAt": "2024-05-02T10:00:00.000Z" }],
+                    "pageInfo": { "hasNextPage": true, "endCursor": "c1" }
+                } }
+            }));
+        });
+
+        let client = LinearGraphqlClient::with_endpoint(
+            &sample_session(),
+            &format!("{}{}", server.base_url(), "/graphql"),
+        )
+        .unwrap();
+        let since = "2024-05-01T09:00:00Z".parse().unwrap();
+        let page = client
+            .backup_page(BackupEntity::Comments, 50, None, Some(since))
+            .await
+            .unwrap();
+        mock.assert();
+        assert_eq!(page.nodes[0]["id"], "comment-1");
+        assert_eq!(page.end_cursor.as_deref(), Some("c1"));
+        assert!(page.has_next_page);
+    }
+
+    #[tokio::test]
+    async fn update_user_status_sends_every_field() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/graphql")
+                .body_contains("mutation UserStatusUpdate")
+                .body_contains(r#""id":"user-1""#)
+                .body_contains(
+                    r#""input":{"statusEmoji":":palm_tree:","statusLabel":null,"statusUntilAt":null}"#,
+                );
+            then.status(200).json_body_obj(&serde_json::json!({
+                "data": { "userUpdate": {
+                    "success": true,
+                    "user": {
+                        "id": "user-1",
+                        "name": "Ada Lovelace",
+                        "displayName": "ada",
+                        "statusEmoji": ":palm_tree:",
+                        "statusLabel": null,
+                        "statusUntilAt": null
+                    }
+                } }
+            }));
+        });
+
+        let client = LinearGraphqlClient::with_endpoint(
+            &sample_session(),
+            &format!("{}{}", server.base_url(), "/graphql"),
+        )
+        .unwrap();
+        let user = client
+            .update_user_status(
+                "user-1",
+                UserStatusInput {
+                    status_emoji: Some(":palm_tree:".into()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        mock.assert();
+        assert!(user.has_status());
+        assert_eq!(user.status_label, None);
+    }
+
+    #[tokio::test]
+    async fn query_issues_sends_builder_document() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/graphql")
+                .body_contains("query IssueQuery")
+                .body_contains(r#""isMe":{"eq":true}"#);
+            then.status(200).json_body_obj(&serde_json::json!({
+                "data": { "issues": {
+                    "nodes": [{ "id": "issue-1", "identifier": "ENG-1" }],
+                    "pageInfo": { "hasNextPage": true, "endCursor": "c1" }
+                } }
+            }));
+        });
+
+        let client = LinearGraphqlClient::with_endpoint(
+            &sample_session(),
+            &format!("{}{}", server.base_url(), "/graphql"),
+        )
+        .unwrap();
+
+        let query = IssueQuery::new()
+            .assignee_me()
+            .select(crate::query::IssueFields::IDENTIFIER);
+        let page: IssuePage<Value> = client.query_issues(&query).await.unwrap();
+        mock.assert();
+        assert_eq!(page.nodes[0]["identifier"], "ENG-1");
+        assert_eq!(page.end_cursor.as_deref(), Some("c1"));
+        assert!(page.has_next_page);
+    }
+
+    #[tokio::test]
+    async fn slow_responses_time_out() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(POST).path("/graphql");
+            then.status(200)
+                .delay(Duration::from_millis(500))
+                .json_body_obj(&serde_json::json!({ "data": { "teams": { "nodes": [] } } }));
+        });
+
+        let client = LinearGraphqlClient::with_endpoint(
+            &sample_session(),
+            &format!("{}{}", server.base_url(), "/graphql"),
+        )
+        .unwrap()
+        .with_timeout(Some(Duration::from_millis(50)));
+
+        let err = client.teams().await.unwrap_err();
+        assert!(matches!(err, GraphqlError::Timeout(_)), "{err:?}");
+        assert_eq!(err.to_string(), "request timed out after 50ms");
+    }
+
+    #[tokio::test]
+    async fn team_members_returns_nodes() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/graphql")
+                .body_contains("query TeamMembers")
+                .body_contains(r#""team_id":"team-1""#);
+            then.status(200).json_body_obj(&serde_json::json!({
+                "data": { "team": { "members": { "nodes": [{
+                    "id": "user-1",
+                    "name": "Ada Lovelace",
+                    "displayName": "ada",
+                    "email": "ada@example.com",
+                    "url": "https://linear.app/acme/profiles/ada",
+                    "active": true
+                }] } } }
+            }));
+        });
+
+        let client = LinearGraphqlClient::with_endpoint(
+            &sample_session(),
+            &format!("{}{}", server.base_url(), "/graphql"),
+        )
+        .unwrap();
+
+        let members = client.team_members("team-1").await.unwrap();
+        mock.assert();
+        assert_eq!(members.len(), 1);
+        assert_eq!(members[0].display_name, "ada");
+        assert_eq!(members[0].url, "https://linear.app/acme/profiles/ada");
+    }
+
     #[tokio::test]
     async fn issue_by_key_not_found() {
         let server = MockServer::start();
@@ -2118,6 +5233,292 @@ mod tests {
         assert_eq!(issue.description.as_deref(), Some("Body"));
     }
 
+    #[tokio::test]
+    async fn recorded_session_replays_offline() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/graphql");
+            then.status(200).json_body_obj(&serde_json::json!({
+                "data": {
+                    "viewer": {
+                        "id": "user-1",
+                        "name": "Ada Lovelace",
+                        "displayName": "Ada",
+                        "email": "ada@example.com",
+                        "createdAt": "2024-01-01T00:00:00.000Z"
+                    }
+                }
+            }));
+        });
+        let dir = tempfile::TempDir::new().unwrap();
+        let tape = dir.path().join("session.json");
+
+        let recorder = LinearGraphqlClient::with_endpoint(
+            &sample_session(),
+            &format!("{}{}", server.base_url(), "/graphql"),
+        )
+        .unwrap()
+        .with_recording(&tape)
+        .unwrap();
+        recorder.viewer().await.unwrap();
+        mock.assert_hits(1);
+        let raw = std::fs::read_to_string(&tape).unwrap();
+        assert!(!raw.contains("test-key"));
+
+        let replayer = LinearGraphqlClient::with_endpoint(
+            &sample_session(),
+            &format!("{}{}", server.base_url(), "/graphql"),
+        )
+        .unwrap()
+        .with_replay(&tape)
+        .unwrap();
+        let viewer = replayer.viewer().await.unwrap();
+        mock.assert_hits(1);
+        assert_eq!(viewer.id, "user-1");
+        assert!(matches!(
+            replayer.viewer().await,
+            Err(GraphqlError::Tape(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn clients_sharing_a_session_tape_record_one_session() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/graphql");
+            then.status(200).json_body_obj(&serde_json::json!({
+                "data": {
+                    "viewer": {
+                        "id": "user-1",
+                        "name": "Ada Lovelace",
+                        "displayName": "Ada",
+                        "email": "ada@example.com",
+                        "createdAt": "2024-01-01T00:00:00.000Z"
+                    }
+                }
+            }));
+        });
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("session.json");
+        let client = || {
+            LinearGraphqlClient::with_endpoint(&sample_session(), &server.url("/graphql")).unwrap()
+        };
+
+        let tape = SessionTape::record(&path).unwrap();
+        client().with_tape(tape.clone()).viewer().await.unwrap();
+        client().with_tape(tape).viewer().await.unwrap();
+        mock.assert_hits(2);
+
+        let replay = SessionTape::replay(&path).unwrap();
+        client().with_tape(replay.clone()).viewer().await.unwrap();
+        client().with_tape(replay.clone()).viewer().await.unwrap();
+        mock.assert_hits(2);
+        assert!(matches!(
+            client().with_tape(replay).viewer().await,
+            Err(GraphqlError::Tape(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn replay_answers_by_query_and_variables_in_any_order() {
+        let server = MockServer::start();
+        for key in ["ENG-1", "ENG-2"] {
+            server.mock(|when, then| {
+                when.method(POST)
+                    .path("/graphql")
+                    .body_contains(format!("\"key\":\"{key}\""));
+                then.status(200)
+                    .json_body_obj(&serde_json::json!({ "data": { "issue": key } }));
+            });
+        }
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("session.json");
+        let client = || {
+            LinearGraphqlClient::with_endpoint(&sample_session(), &server.url("/graphql")).unwrap()
+        };
+        const QUERY: &str = "query Issue($key: String!, $full: Boolean) { issue(id: $key) }";
+        let issue = |client: LinearGraphqlClient, variables: Value| async move {
+            client.execute(QUERY, variables, None).await
+        };
+
+        let recorder = client().with_tape(SessionTape::record(&path).unwrap());
+        for key in ["ENG-1", "ENG-2"] {
+            let variables = serde_json::json!({ "key": key, "full": true });
+            issue(recorder.clone(), variables).await.unwrap();
+        }
+        let raw = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(raw.lines().count(), 3, "a header and one line per exchange");
+
+        let replayer = client().with_tape(SessionTape::replay(&path).unwrap());
+        // Reversed, and with the variables built in another order.
+        let second = issue(
+            replayer.clone(),
+            serde_json::json!({ "full": true, "key": "ENG-2" }),
+        );
+        assert_eq!(second.await.unwrap()["issue"], "ENG-2");
+        let first = issue(
+            replayer.clone(),
+            serde_json::json!({ "full": true, "key": "ENG-1" }),
+        );
+        assert_eq!(first.await.unwrap()["issue"], "ENG-1");
+        let other = issue(
+            replayer.clone(),
+            serde_json::json!({ "full": true, "key": "ENG-3" }),
+        );
+        match other.await {
+            Err(GraphqlError::Tape(message)) => {
+                assert!(
+                    message.contains(r#"{"full":true,"key":"ENG-3"}"#),
+                    "{message}"
+                )
+            }
+            other => panic!("expected a tape miss, got {other:?}"),
+        }
+        let again = issue(
+            replayer,
+            serde_json::json!({ "full": true, "key": "ENG-1" }),
+        );
+        assert!(
+            matches!(again.await, Err(GraphqlError::Tape(message)) if message.contains("left"))
+        );
+    }
+
+    #[tokio::test]
+    async fn caches_lookups_until_a_mutation() {
+        let server = MockServer::start();
+        let teams = server.mock(|when, then| {
+            when.method(POST)
+                .path("/graphql")
+                .body_contains("TeamsQuery");
+            then.status(200).json_body_obj(&serde_json::json!({
+                "data": { "teams": { "nodes": [{ "id": "team-1", "name": "Eng", "key": "ENG" }] } }
+            }));
+        });
+        let mutation = server.mock(|when, then| {
+            when.method(POST).path("/graphql").body_contains("mutation");
+            then.status(200)
+                .json_body_obj(&serde_json::json!({ "data": { "ok": true } }));
+        });
+        let temp_dir = tempfile::tempdir().unwrap();
+        let client = LinearGraphqlClient::with_endpoint(&sample_session(), &server.url("/graphql"))
+            .unwrap()
+            .with_cache(Some(ResponseCache::new(
+                temp_dir.path(),
+                Duration::from_secs(60),
+            )));
+
+        assert_eq!(client.teams().await.unwrap()[0].key, "ENG");
+        assert_eq!(client.teams().await.unwrap()[0].key, "ENG");
+        teams.assert_hits(1);
+
+        client
+            .execute("mutation Touch { ok }", serde_json::json!({}), None)
+            .await
+            .unwrap();
+        client.teams().await.unwrap();
+        mutation.assert_hits(1);
+        teams.assert_hits(2);
+    }
+
+    #[tokio::test]
+    async fn revalidates_expired_lookups_with_their_etag() {
+        let server = MockServer::start();
+        let first = server.mock(|when, then| {
+            when.method(POST)
+                .path("/graphql")
+                .matches(|request| {
+                    !request.headers.iter().flatten().any(|(name, _)| {
+                        name.eq_ignore_ascii_case("if-none-match")
+                    })
+                });
+            then.status(200)
+                .header("etag", "\"teams-1\"")
+                .json_body_obj(&serde_json::json!({
+                    "data": { "teams": { "nodes": [{ "id": "team-1", "name": "Eng", "key": "ENG" }] } }
+                }));
+        });
+        let unchanged = server.mock(|when, then| {
+            when.method(POST)
+                .path("/graphql")
+                .header("if-none-match", "\"teams-1\"");
+            then.status(304);
+        });
+        let temp_dir = tempfile::tempdir().unwrap();
+        let client = LinearGraphqlClient::with_endpoint(&sample_session(), &server.url("/graphql"))
+            .unwrap()
+            .with_cache(Some(ResponseCache::new(temp_dir.path(), Duration::ZERO)));
+
+        client.teams().await.unwrap();
+        assert_eq!(client.teams().await.unwrap()[0].key, "ENG");
+        first.assert_hits(1);
+        unchanged.assert_hits(1);
+    }
+
+    #[tokio::test]
+    async fn dry_run_skips_mutations() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/graphql");
+            then.status(200);
+        });
+
+        let client = LinearGraphqlClient::with_endpoint(
+            &sample_session(),
+            &format!("{}{}", server.base_url(), "/graphql"),
+        )
+        .unwrap()
+        .with_dry_run(true);
+
+        let err = client
+            .create_issue(IssueCreateInput::new("team-1", "Dry run"))
+            .await
+            .unwrap_err();
+        mock.assert_hits(0);
+        let GraphqlError::DryRun(request) = err else {
+            panic!("expected dry run, got {err:?}");
+        };
+        assert_eq!(request.operation, "CreateIssue");
+        assert_eq!(request.authorization, "[redacted]");
+        assert!(request.query.starts_with("mutation CreateIssue"));
+        assert_eq!(request.variables["input"]["title"], "Dry run");
+    }
+
+    #[tokio::test]
+    async fn execute_selects_operation_and_catches_mutations_under_dry_run() {
+        let server = MockServer::start();
+        let document =
+            "# explore\nmutation Archive($id: String!) { issueArchive(id: $id) { success } }\n\
+                        query Me { viewer { id } }";
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/graphql")
+                .json_body_partial(r#"{"operationName": "Me", "variables": {"id": "x"}}"#);
+            then.status(200)
+                .json_body_obj(&serde_json::json!({ "data": { "viewer": { "id": "user-1" } } }));
+        });
+        let endpoint = format!("{}{}", server.base_url(), "/graphql");
+
+        let client = LinearGraphqlClient::with_endpoint(&sample_session(), &endpoint)
+            .unwrap()
+            .with_dry_run(true);
+        let data = client
+            .execute(document, serde_json::json!({ "id": "x" }), Some("Me"))
+            .await
+            .unwrap();
+        mock.assert();
+        assert_eq!(data["viewer"]["id"], "user-1");
+
+        let err = client
+            .execute(document, serde_json::json!({ "id": "x" }), Some("Archive"))
+            .await
+            .unwrap_err();
+        mock.assert_hits(1);
+        let GraphqlError::DryRun(request) = err else {
+            panic!("expected dry run, got {err:?}");
+        };
+        assert_eq!(request.operation, "Archive");
+    }
+
     #[tokio::test]
     async fn create_issue_failure_returns_operation_failed() {
         let server = MockServer::start();
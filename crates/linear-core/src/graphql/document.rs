@@ -0,0 +1,608 @@
+//! Just enough GraphQL document parsing to run hand-written operations: which operations a
+//! document defines, and the variables each declares, so `--var key=value` strings can be sent
+//! as the JSON types the server expects. [`complexity`] estimates what an operation costs
+//! against the rate limit before it is sent.
+
+use std::collections::HashMap;
+
+use serde_json::{json, Value};
+
+/// Page size Linear assumes for a connection queried without `first` or `last`.
+const DEFAULT_PAGE_SIZE: f64 = 50.0;
+/// Fragment spreads nested deeper than this are not followed, so a cycle cannot recurse forever.
+const MAX_FRAGMENT_DEPTH: usize = 16;
+
+/// `query`, `mutation`, or `subscription`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperationKind {
+    Query,
+    Mutation,
+    Subscription,
+}
+
+/// An operation defined in a document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Operation {
+    pub kind: OperationKind,
+    /// `None` for anonymous operations, including the `{ viewer { id } }` shorthand.
+    pub name: Option<String>,
+    pub variables: Vec<VariableDefinition>,
+}
+
+/// A `$name: Type` declaration, e.g. `$first: Int = 50`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VariableDefinition {
+    pub name: String,
+    /// The type as written, without whitespace, e.g. `[String!]!`.
+    pub type_name: String,
+    pub has_default: bool,
+}
+
+impl VariableDefinition {
+    /// Non-null without a default, so the request fails unless a value is given.
+    pub fn is_required(&self) -> bool {
+        self.type_name.ends_with('!') && !self.has_default
+    }
+
+    /// Convert a command-line string to this variable's type.
+    pub fn coerce(&self, raw: &str) -> Result<Value, String> {
+        coerce(&self.type_name, raw).map_err(|err| format!("${}: {}", self.name, err))
+    }
+}
+
+/// Operations in `document`, in order. Fragments are skipped.
+pub fn operations(document: &str) -> Vec<Operation> {
+    let source: Vec<char> = strip_comments(document).chars().collect();
+    let mut operations = Vec::new();
+    let mut pos = 0;
+    while pos < source.len() {
+        let c = source[pos];
+        if c == '{' {
+            operations.push(Operation {
+                kind: OperationKind::Query,
+                name: None,
+                variables: Vec::new(),
+            });
+            pos = skip_group(&source, pos);
+        } else if is_name_char(c) {
+            let word = read_name(&source, &mut pos);
+            let kind = match word.as_str() {
+                "query" => Some(OperationKind::Query),
+                "mutation" => Some(OperationKind::Mutation),
+                "subscription" => Some(OperationKind::Subscription),
+                _ => None,
+            };
+            let header_end = find_top_level(&source, pos, '{');
+            if let Some(kind) = kind {
+                operations.push(parse_header(kind, &source[pos..header_end]));
+            }
+            pos = skip_group(&source, header_end);
+        } else {
+            pos += 1;
+        }
+    }
+    operations
+}
+
+/// Estimated complexity of the operation named `operation` (the first one when `None`), by the
+/// rules Linear documents for its rate limit: 0.1 point per scalar field and 1 per object, with
+/// everything inside a connection multiplied by its `first`/`last` argument (50 when absent).
+/// Arguments given as variables are read from `variables`. `None` when there is no such
+/// operation.
+pub fn complexity(document: &str, operation: Option<&str>, variables: &Value) -> Option<f64> {
+    let source: Vec<char> = strip_comments(document).chars().collect();
+    let mut operations: Vec<(Option<String>, usize)> = Vec::new();
+    let mut fragments = HashMap::new();
+    let mut pos = 0;
+    while pos < source.len() {
+        let c = source[pos];
+        if c == '{' {
+            operations.push((None, pos));
+            pos = skip_group(&source, pos);
+        } else if is_name_char(c) {
+            let word = read_name(&source, &mut pos);
+            let body = find_top_level(&source, pos, '{');
+            let mut name_pos = pos;
+            skip_whitespace(&source, &mut name_pos);
+            let name = (name_pos < body && is_name_char(source[name_pos]))
+                .then(|| read_name(&source, &mut name_pos));
+            match word.as_str() {
+                "query" | "mutation" | "subscription" => operations.push((name, body)),
+                "fragment" => {
+                    if let Some(name) = name {
+                        fragments.insert(name, body);
+                    }
+                }
+                _ => {}
+            }
+            pos = skip_group(&source, body);
+        } else {
+            pos += 1;
+        }
+    }
+
+    let body = match operation {
+        Some(wanted) => {
+            operations
+                .iter()
+                .find(|(name, _)| name.as_deref() == Some(wanted))?
+                .1
+        }
+        None => operations.first()?.1,
+    };
+    let estimator = Estimator {
+        source: &source,
+        fragments: &fragments,
+        variables,
+    };
+    let points = estimator.selection(body, 1.0, 0);
+    // Sums of tenths drift; report them as Linear does, to one decimal place.
+    Some((points * 10.0).round() / 10.0)
+}
+
+struct Estimator<'a> {
+    source: &'a [char],
+    /// Where each fragment's selection set opens.
+    fragments: &'a HashMap<String, usize>,
+    variables: &'a Value,
+}
+
+impl Estimator<'_> {
+    /// Cost of the selection set opening at `start`, each field counted `multiplier` times.
+    fn selection(&self, start: usize, multiplier: f64, depth: usize) -> f64 {
+        let source = self.source;
+        let end = skip_group(source, start).saturating_sub(1);
+        let mut cost = 0.0;
+        let mut pos = start + 1;
+        while pos < end {
+            let c = source[pos];
+            if source[pos..].starts_with(&['.', '.', '.']) {
+                pos += 3;
+                skip_whitespace(source, &mut pos);
+                let spread = (pos < end && is_name_char(source[pos])).then(|| {
+                    let mut after = pos;
+                    (read_name(source, &mut after), after)
+                });
+                match spread {
+                    // A named spread; `... on Type { }` is an inline fragment.
+                    Some((name, after)) if name != "on" => {
+                        if let Some(&body) = self.fragments.get(&name) {
+                            if depth < MAX_FRAGMENT_DEPTH {
+                                cost += self.selection(body, multiplier, depth + 1);
+                            }
+                        }
+                        pos = after;
+                    }
+                    _ => {
+                        let body = find_top_level(source, pos, '{');
+                        if body < end {
+                            cost += self.selection(body, multiplier, depth);
+                        }
+                        pos = skip_group(source, body);
+                    }
+                }
+            } else if c == '@' {
+                skip_directives(source, &mut pos);
+            } else if is_name_char(c) {
+                read_name(source, &mut pos);
+                skip_whitespace(source, &mut pos);
+                if source.get(pos) == Some(&':') {
+                    // `alias: field`; only the field's shape matters.
+                    pos += 1;
+                    skip_whitespace(source, &mut pos);
+                    read_name(source, &mut pos);
+                    skip_whitespace(source, &mut pos);
+                }
+                let mut page = None;
+                if source.get(pos) == Some(&'(') {
+                    let close = skip_group(source, pos);
+                    page = self.page_size(&source[pos + 1..close.saturating_sub(1)]);
+                    pos = close;
+                }
+                skip_directives(source, &mut pos);
+                if source.get(pos) == Some(&'{') && pos < end {
+                    let page = page.unwrap_or_else(|| {
+                        if self.has_child(pos, &["nodes", "edges"]) {
+                            DEFAULT_PAGE_SIZE
+                        } else {
+                            1.0
+                        }
+                    });
+                    cost += multiplier + self.selection(pos, multiplier * page, depth);
+                    pos = skip_group(source, pos);
+                } else {
+                    cost += 0.1 * multiplier;
+                }
+            } else {
+                pos += 1;
+            }
+        }
+        cost
+    }
+
+    /// The `first` or `last` argument among a field's arguments, when given.
+    fn page_size(&self, arguments: &[char]) -> Option<f64> {
+        let mut pos = 0;
+        while pos < arguments.len() {
+            skip_whitespace(arguments, &mut pos);
+            let name = read_name(arguments, &mut pos);
+            skip_whitespace(arguments, &mut pos);
+            if name.is_empty() || arguments.get(pos) != Some(&':') {
+                return None;
+            }
+            pos += 1;
+            skip_whitespace(arguments, &mut pos);
+            let value_start = pos;
+            match arguments.get(pos) {
+                Some('"') => pos = skip_string(arguments, pos) + 1,
+                Some('{' | '[') => pos = skip_group(arguments, pos),
+                Some('$') => {
+                    pos += 1;
+                    read_name(arguments, &mut pos);
+                }
+                _ => {
+                    while pos < arguments.len()
+                        && (is_name_char(arguments[pos]) || matches!(arguments[pos], '-' | '.'))
+                    {
+                        pos += 1;
+                    }
+                }
+            }
+            if name == "first" || name == "last" {
+                let value: String = arguments[value_start..pos].iter().collect();
+                return Some(
+                    match value.strip_prefix('$') {
+                        Some(variable) => self.variables.get(variable).and_then(Value::as_f64),
+                        None => value.parse().ok(),
+                    }
+                    // An unset variable falls back to the server's default page.
+                    .unwrap_or(DEFAULT_PAGE_SIZE),
+                );
+            }
+        }
+        None
+    }
+
+    /// Whether the selection set opening at `start` selects one of `names` directly, which is
+    /// how a connection queried without arguments is told apart from a plain object.
+    fn has_child(&self, start: usize, names: &[&str]) -> bool {
+        let source = self.source;
+        let end = skip_group(source, start).saturating_sub(1);
+        let mut pos = start + 1;
+        while pos < end {
+            match source[pos] {
+                '{' | '(' | '[' => pos = skip_group(source, pos),
+                '"' => pos = skip_string(source, pos) + 1,
+                c if is_name_char(c) => {
+                    if names.contains(&read_name(source, &mut pos).as_str()) {
+                        return true;
+                    }
+                }
+                _ => pos += 1,
+            }
+        }
+        false
+    }
+}
+
+/// Step past directives such as `@include(if: $full)` and the whitespace around them.
+fn skip_directives(source: &[char], pos: &mut usize) {
+    skip_whitespace(source, pos);
+    while source.get(*pos) == Some(&'@') {
+        *pos += 1;
+        read_name(source, pos);
+        skip_whitespace(source, pos);
+        if source.get(*pos) == Some(&'(') {
+            *pos = skip_group(source, *pos);
+        }
+        skip_whitespace(source, pos);
+    }
+}
+fn parse_header(kind: OperationKind, header: &[char]) -> Operation {
+    let mut pos = 0;
+    skip_whitespace(header, &mut pos);
+    let name =
+        (pos < header.len() && is_name_char(header[pos])).then(|| read_name(header, &mut pos));
+    skip_whitespace(header, &mut pos);
+    let mut variables = Vec::new();
+    if header.get(pos) == Some(&'(') {
+        let end = skip_group(header, pos);
+        let list = &header[pos + 1..end.saturating_sub(1).max(pos + 1)];
+        variables = parse_variables(list);
+    }
+    Operation {
+        kind,
+        name,
+        variables,
+    }
+}
+
+fn parse_variables(list: &[char]) -> Vec<VariableDefinition> {
+    let mut variables = Vec::new();
+    let mut pos = 0;
+    while pos < list.len() {
+        if list[pos] != '$' {
+            pos += 1;
+            continue;
+        }
+        pos += 1;
+        let name = read_name(list, &mut pos);
+        skip_whitespace(list, &mut pos);
+        if list.get(pos) != Some(&':') {
+            continue;
+        }
+        pos += 1;
+        let mut type_name = String::new();
+        while let Some(&c) = list.get(pos) {
+            if is_name_char(c) || matches!(c, '[' | ']' | '!') {
+                type_name.push(c);
+            } else if !c.is_whitespace() {
+                break;
+            }
+            pos += 1;
+        }
+        // Whatever follows up to the next `$` is a default value and/or directives.
+        let next = find_top_level(list, pos, '$');
+        let has_default = list[pos..next].iter().find(|c| !c.is_whitespace()) == Some(&'=');
+        variables.push(VariableDefinition {
+            name,
+            type_name,
+            has_default,
+        });
+        pos = next;
+    }
+    variables
+}
+
+fn coerce(type_name: &str, raw: &str) -> Result<Value, String> {
+    let nullable = !type_name.ends_with('!');
+    let base = type_name.strip_suffix('!').unwrap_or(type_name);
+    if nullable && raw == "null" {
+        return Ok(Value::Null);
+    }
+    if let Some(inner) = base.strip_prefix('[').and_then(|t| t.strip_suffix(']')) {
+        if raw.trim_start().starts_with('[') {
+            return serde_json::from_str(raw).map_err(|err| format!("invalid JSON list: {err}"));
+        }
+        if raw.trim().is_empty() {
+            return Ok(json!([]));
+        }
+        return raw
+            .split(',')
+            .map(|item| coerce(inner, item.trim()))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Value::Array);
+    }
+    match base {
+        "Int" => raw
+            .parse::<i64>()
+            .map(Value::from)
+            .map_err(|_| format!("expected an Int, got '{raw}'")),
+        "Float" => raw
+            .parse::<f64>()
+            .ok()
+            .and_then(|value| serde_json::Number::from_f64(value).map(Value::Number))
+            .ok_or_else(|| format!("expected a Float, got '{raw}'")),
+        "Boolean" => match raw.to_ascii_lowercase().as_str() {
+            "true" | "yes" | "1" => Ok(Value::Bool(true)),
+            "false" | "no" | "0" => Ok(Value::Bool(false)),
+            _ => Err(format!("expected a Boolean, got '{raw}'")),
+        },
+        "JSON" | "JSONObject" => {
+            serde_json::from_str(raw).map_err(|err| format!("invalid JSON: {err}"))
+        }
+        // Input objects are written as JSON; enums and custom scalars are sent as strings.
+        _ if raw.trim_start().starts_with('{') => {
+            serde_json::from_str(raw).map_err(|err| format!("invalid JSON object: {err}"))
+        }
+        _ => Ok(Value::String(raw.to_owned())),
+    }
+}
+
+/// Drop `#` comments, leaving string contents alone.
+fn strip_comments(document: &str) -> String {
+    let mut out = String::with_capacity(document.len());
+    let mut in_string = false;
+    let mut in_comment = false;
+    let mut escaped = false;
+    for c in document.chars() {
+        if in_comment {
+            if c == '\n' {
+                in_comment = false;
+                out.push(c);
+            }
+            continue;
+        }
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+        } else if c == '"' {
+            in_string = true;
+        } else if c == '#' {
+            in_comment = true;
+            continue;
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Index just past the group opened at `start` (`{`, `(`, or `[`), or the end of input.
+fn skip_group(source: &[char], start: usize) -> usize {
+    let mut depth = 0usize;
+    let mut pos = start;
+    while pos < source.len() {
+        match source[pos] {
+            '"' => pos = skip_string(source, pos),
+            '{' | '(' | '[' => depth += 1,
+            '}' | ')' | ']' => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    return pos + 1;
+                }
+            }
+            _ => {}
+        }
+        pos += 1;
+    }
+    source.len()
+}
+
+/// Index of the closing quote of the string starting at `start`.
+fn skip_string(source: &[char], start: usize) -> usize {
+    let mut pos = start + 1;
+    while pos < source.len() {
+        match source[pos] {
+            '\\' => pos += 1,
+            '"' => return pos,
+            _ => {}
+        }
+        pos += 1;
+    }
+    source.len()
+}
+
+/// First `target` at nesting depth zero from `start`, or the end of input.
+fn find_top_level(source: &[char], start: usize, target: char) -> usize {
+    let mut pos = start;
+    while pos < source.len() {
+        match source[pos] {
+            c if c == target => return pos,
+            '"' => pos = skip_string(source, pos),
+            '(' | '[' => {
+                pos = skip_group(source, pos);
+                continue;
+            }
+            '{' => {
+                pos = skip_group(source, pos);
+                continue;
+            }
+            _ => {}
+        }
+        pos += 1;
+    }
+    source.len()
+}
+
+fn read_name(source: &[char], pos: &mut usize) -> String {
+    let start = *pos;
+    while *pos < source.len() && is_name_char(source[*pos]) {
+        *pos += 1;
+    }
+    source[start..*pos].iter().collect()
+}
+
+fn skip_whitespace(source: &[char], pos: &mut usize) {
+    while *pos < source.len() && (source[*pos].is_whitespace() || source[*pos] == ',') {
+        *pos += 1;
+    }
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_operations_and_variables() {
+        let document = r#"
+            # Issues for a team
+            query TeamIssues($team: String!, $first: Int = 50, $labels: [ID!]) {
+                issues(first: $first, filter: { team: { key: { eq: $team } }, title: { contains: "a { b" } }) {
+                    nodes { id }
+                }
+            }
+            fragment Fields on Issue { id }
+            mutation Archive($id: String!) @live { issueArchive(id: $id) { success } }
+            { viewer { id } }
+        "#;
+        let ops = operations(document);
+        assert_eq!(ops.len(), 3);
+        assert_eq!(ops[0].kind, OperationKind::Query);
+        assert_eq!(ops[0].name.as_deref(), Some("TeamIssues"));
+        let vars: Vec<(&str, &str, bool)> = ops[0]
+            .variables
+            .iter()
+            .map(|v| (v.name.as_str(), v.type_name.as_str(), v.is_required()))
+            .collect();
+        assert_eq!(
+            vars,
+            [
+                ("team", "String!", true),
+                ("first", "Int", false),
+                ("labels", "[ID!]", false)
+            ]
+        );
+        assert_eq!(ops[1].kind, OperationKind::Mutation);
+        assert_eq!(ops[1].name.as_deref(), Some("Archive"));
+        assert_eq!(ops[2].name, None);
+    }
+
+    #[test]
+    fn coerces_values_by_declared_type() {
+        let var = |type_name: &str| VariableDefinition {
+            name: "v".into(),
+            type_name: type_name.into(),
+            has_default: false,
+        };
+        assert_eq!(var("Int!").coerce("50"), Ok(json!(50)));
+        assert_eq!(var("Float").coerce("1.5"), Ok(json!(1.5)));
+        assert_eq!(var("Boolean").coerce("yes"), Ok(json!(true)));
+        assert_eq!(var("String!").coerce("42"), Ok(json!("42")));
+        assert_eq!(var("String").coerce("null"), Ok(Value::Null));
+        assert_eq!(var("[Int!]").coerce("1, 2"), Ok(json!([1, 2])));
+        assert_eq!(var("[String!]").coerce(r#"["a,b"]"#), Ok(json!(["a,b"])));
+        assert_eq!(
+            var("IssueFilter").coerce(r#"{"team": {"key": {"eq": "ENG"}}}"#),
+            Ok(json!({"team": {"key": {"eq": "ENG"}}}))
+        );
+        assert_eq!(
+            var("PaginationOrderBy").coerce("updatedAt"),
+            Ok(json!("updatedAt"))
+        );
+        assert_eq!(
+            var("Int").coerce("ten"),
+            Err("$v: expected an Int, got 'ten'".into())
+        );
+    }
+
+    #[test]
+    fn estimates_complexity_like_linear() {
+        let document = r#"
+            query Issues($first: Int) {
+                issues(first: $first, filter: { title: { contains: "{" } }) {
+                    nodes {
+                        id
+                        title
+                        ...Labels
+                        assignee @include(if: true) { name }
+                    }
+                    pageInfo { hasNextPage }
+                }
+            }
+            fragment Labels on Issue { labels { nodes { name } } }
+            query Viewer { me: viewer { id name } }
+        "#;
+        // issues 1, nodes 100, id/title 2 × 10, labels 100, its nodes 5000 and names 500,
+        // assignee 100 and its name 10, pageInfo 100 and hasNextPage 10.
+        assert_eq!(
+            complexity(document, Some("Issues"), &json!({ "first": 100 })),
+            Some(5941.0)
+        );
+        // An unset page size is the server's default of 50.
+        assert_eq!(
+            complexity(document, None, &json!({})),
+            Some(1.0 + 50.0 + 10.0 + 50.0 + 2500.0 + 250.0 + 50.0 + 5.0 + 50.0 + 5.0)
+        );
+        assert_eq!(complexity(document, Some("Viewer"), &json!({})), Some(1.2));
+        assert_eq!(complexity(document, Some("Missing"), &json!({})), None);
+    }
+}
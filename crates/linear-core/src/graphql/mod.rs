@@ -1,11 +1,28 @@
+mod cache;
 mod client;
+mod document;
+mod scheduler;
+pub mod schema;
+mod stream;
+mod tape;
 
+pub use cache::ResponseCache;
 pub use client::{
-    Comment, CommentConnection, CommentCreateInput, CycleListParams, CycleListResponse,
-    CycleSummary, CycleUpdateInput, GraphqlError, GraphqlResult, IssueAssignee, IssueCreateInput,
-    IssueDetail, IssueHistory, IssueHistoryConnection, IssueLabel, IssueLabelCreateInput,
-    IssueLabelUpdateInput, IssueListParams, IssueListResponse, IssueSubIssue,
-    IssueSubIssueConnection, IssueSummary, IssueUpdateInput, LinearGraphqlClient,
-    ProjectCreateInput, ProjectDetail, ProjectListParams, ProjectListResponse, ProjectSummary,
-    ProjectUpdateInput, TeamSummary, UserSummary, Viewer, WorkflowStateSummary,
+    BackupPage, ClientMetrics, Comment, CommentConnection, CommentCreateInput, Connectivity,
+    CycleListParams, CycleListResponse, CycleSummary, CycleUpdateInput, DocumentSummary,
+    DryRunRequest, GraphqlError, GraphqlResponseError, GraphqlResult, IssueAssignee,
+    IssueCreateInput, IssueDetail, IssueHistory, IssueHistoryConnection, IssueLabel,
+    IssueLabelConnection, IssueLabelCreateInput, IssueLabelUpdateInput, IssueListParams,
+    IssueListResponse, IssueRelationCreateInput, IssueState, IssueSubIssue,
+    IssueSubIssueConnection, IssueSummary, IssueUpdateInput, LinearGraphqlClient, MetricsHandle,
+    Organization, ProjectCreateInput, ProjectDetail, ProjectListParams, ProjectListResponse,
+    ProjectMilestone, ProjectMilestoneCreateInput, ProjectRef, ProjectStatusUpdate,
+    ProjectStatusUpdateCreateInput, ProjectSummary, ProjectUpdateInput, RateLimitStatus,
+    TeamMember, TeamMembership, TeamSettings, TeamSummary, TeamUpdateInput, TemplateCreateInput,
+    TemplateRef, TemplateSummary, TemplateUpdateInput, UserConnection, UserProfile, UserStatus,
+    UserStatusInput, UserSummary, Viewer, ViewerPermissions, WorkflowStateCreateInput,
+    WorkflowStateSummary, WorkflowStateUpdateInput,
 };
+pub use document::{complexity, operations, Operation, OperationKind, VariableDefinition};
+pub use scheduler::{QueueDepth, RequestPriority};
+pub use tape::SessionTape;
@@ -0,0 +1,317 @@
+//! Client-side scheduling of API requests by priority class, within Linear's rate-limit budget.
+//!
+//! Every clone of a client shares one scheduler. Interactive requests run as soon as a slot is
+//! free; background requests use at most [`BACKGROUND_IN_FLIGHT`] slots, yield to queued
+//! interactive requests, and wait for the budget to reset once less than a tenth of it is left,
+//! so a long export or polling loop cannot starve the requests someone is waiting on.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use tokio::sync::Notify;
+
+use super::client::{ClientMetrics, MetricsHandle, RateLimitStatus};
+
+/// Requests in flight at once across all clones of a client.
+const MAX_IN_FLIGHT: usize = 16;
+/// Of those, how many may be background requests.
+const BACKGROUND_IN_FLIGHT: usize = 2;
+
+/// How urgently a request's result is needed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RequestPriority {
+    /// Someone is waiting on the result, e.g. the TUI's detail pane or a one-off command.
+    #[default]
+    Interactive,
+    /// Work nobody is watching: prefetches, polling loops, exports.
+    Background,
+}
+
+/// Requests the scheduler is holding back or running, for status displays.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueueDepth {
+    /// Interactive requests waiting for a slot.
+    pub interactive: usize,
+    /// Background requests waiting for a slot or for the budget.
+    pub background: usize,
+    pub in_flight: usize,
+    /// When background requests held for a low budget may run again.
+    pub held_until: Option<DateTime<Utc>>,
+}
+
+impl QueueDepth {
+    /// Requests waiting to be sent.
+    pub fn waiting(&self) -> usize {
+        self.interactive + self.background
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Scheduler {
+    metrics: MetricsHandle,
+    released: Arc<Notify>,
+}
+
+/// A slot for one request; dropping it lets the next queued request run.
+pub(crate) struct Permit {
+    scheduler: Scheduler,
+}
+
+/// Counts a request as queued until it is admitted or abandoned (e.g. its task was aborted).
+struct Waiting<'a> {
+    scheduler: &'a Scheduler,
+    priority: RequestPriority,
+    admitted: bool,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Admission {
+    Now,
+    AfterRelease,
+    At(DateTime<Utc>),
+}
+
+impl Scheduler {
+    pub(crate) fn new(metrics: MetricsHandle) -> Self {
+        Self {
+            metrics,
+            released: Arc::new(Notify::new()),
+        }
+    }
+
+    /// Wait until a request of `priority` may be sent.
+    pub(crate) async fn acquire(&self, priority: RequestPriority) -> Permit {
+        let mut waiting = Waiting::enter(self, priority);
+        loop {
+            let released = self.released.notified();
+            tokio::pin!(released);
+            // Register before checking, so a release in between is not missed.
+            released.as_mut().enable();
+            match self
+                .metrics
+                .update(|metrics| admit(metrics, priority, Utc::now()))
+            {
+                Admission::Now => {
+                    waiting.admitted = true;
+                    return Permit {
+                        scheduler: self.clone(),
+                    };
+                }
+                Admission::AfterRelease => released.await,
+                Admission::At(until) => {
+                    let delay = (until - Utc::now()).to_std().unwrap_or_default();
+                    tokio::select! {
+                        _ = released => {}
+                        _ = tokio::time::sleep(delay) => {}
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        self.scheduler.metrics.update(|metrics| {
+            metrics.queue.in_flight = metrics.queue.in_flight.saturating_sub(1);
+        });
+        self.scheduler.released.notify_waiters();
+    }
+}
+
+impl<'a> Waiting<'a> {
+    fn enter(scheduler: &'a Scheduler, priority: RequestPriority) -> Self {
+        scheduler.metrics.update(|metrics| match priority {
+            RequestPriority::Interactive => metrics.queue.interactive += 1,
+            RequestPriority::Background => metrics.queue.background += 1,
+        });
+        Self {
+            scheduler,
+            priority,
+            admitted: false,
+        }
+    }
+}
+
+impl Drop for Waiting<'_> {
+    fn drop(&mut self) {
+        if self.admitted {
+            return;
+        }
+        self.scheduler.metrics.update(|metrics| {
+            let queue = &mut metrics.queue;
+            match self.priority {
+                RequestPriority::Interactive => {
+                    queue.interactive = queue.interactive.saturating_sub(1)
+                }
+                RequestPriority::Background => {
+                    queue.background = queue.background.saturating_sub(1)
+                }
+            }
+            if queue.background == 0 {
+                queue.held_until = None;
+            }
+        });
+        // A background request held back by this one may now run.
+        self.scheduler.released.notify_waiters();
+    }
+}
+
+/// Decide whether a queued request of `priority` runs now, moving it to in-flight if so.
+fn admit(metrics: &mut ClientMetrics, priority: RequestPriority, now: DateTime<Utc>) -> Admission {
+    let held_until = metrics
+        .rate_limit
+        .as_ref()
+        .and_then(|limits| held_until(limits, now));
+    let queue = &mut metrics.queue;
+    match priority {
+        RequestPriority::Interactive if queue.in_flight < MAX_IN_FLIGHT => {
+            queue.interactive -= 1;
+            queue.in_flight += 1;
+            Admission::Now
+        }
+        RequestPriority::Interactive => Admission::AfterRelease,
+        RequestPriority::Background => {
+            queue.held_until = held_until;
+            if let Some(until) = held_until {
+                return Admission::At(until);
+            }
+            if queue.interactive > 0 || queue.in_flight >= BACKGROUND_IN_FLIGHT {
+                return Admission::AfterRelease;
+            }
+            queue.background -= 1;
+            queue.in_flight += 1;
+            Admission::Now
+        }
+    }
+}
+
+/// When the budget resets, if less than a tenth of it is left and the reset is still ahead.
+fn held_until(limits: &RateLimitStatus, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let low = |remaining: Option<u64>, limit: Option<u64>| match (remaining, limit) {
+        (Some(remaining), Some(limit)) => remaining * 10 < limit,
+        (Some(remaining), None) => remaining == 0,
+        _ => false,
+    };
+    let requests = low(limits.requests_remaining, limits.requests_limit)
+        .then_some(limits.requests_reset)
+        .flatten();
+    let complexity = low(limits.complexity_remaining, limits.complexity_limit)
+        .then_some(limits.complexity_reset)
+        .flatten();
+    requests.max(complexity).filter(|reset| *reset > now)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn metrics(queue: QueueDepth, limits: Option<RateLimitStatus>) -> ClientMetrics {
+        ClientMetrics {
+            rate_limit: limits,
+            queue,
+            ..ClientMetrics::default()
+        }
+    }
+
+    #[test]
+    fn background_requests_yield_to_interactive_ones() {
+        let now = Utc::now();
+        let queued = QueueDepth {
+            interactive: 1,
+            background: 1,
+            ..QueueDepth::default()
+        };
+        let mut state = metrics(queued, None);
+        assert_eq!(
+            admit(&mut state, RequestPriority::Background, now),
+            Admission::AfterRelease
+        );
+        assert_eq!(
+            admit(&mut state, RequestPriority::Interactive, now),
+            Admission::Now
+        );
+        assert_eq!(
+            admit(&mut state, RequestPriority::Background, now),
+            Admission::Now
+        );
+        assert_eq!(state.queue.in_flight, 2);
+        assert_eq!(state.queue.waiting(), 0);
+
+        let mut busy = metrics(
+            QueueDepth {
+                background: 1,
+                in_flight: BACKGROUND_IN_FLIGHT,
+                ..QueueDepth::default()
+            },
+            None,
+        );
+        assert_eq!(
+            admit(&mut busy, RequestPriority::Background, now),
+            Admission::AfterRelease
+        );
+    }
+
+    #[test]
+    fn background_requests_wait_for_a_low_budget_to_reset() {
+        let now = Utc::now();
+        let reset = now + Duration::minutes(5);
+        let low = RateLimitStatus {
+            complexity_limit: Some(200_000),
+            complexity_remaining: Some(1_000),
+            complexity_reset: Some(reset),
+            ..RateLimitStatus::default()
+        };
+        let queued = QueueDepth {
+            interactive: 1,
+            background: 1,
+            ..QueueDepth::default()
+        };
+        let mut state = metrics(queued, Some(low.clone()));
+        assert_eq!(
+            admit(&mut state, RequestPriority::Background, now),
+            Admission::At(reset)
+        );
+        assert_eq!(state.queue.held_until, Some(reset));
+        // Interactive requests spend what is left.
+        assert_eq!(
+            admit(&mut state, RequestPriority::Interactive, now),
+            Admission::Now
+        );
+
+        // Past the reset the stale headers no longer hold anything back.
+        let later = reset + Duration::seconds(1);
+        assert_eq!(
+            admit(&mut state, RequestPriority::Background, later),
+            Admission::Now
+        );
+        assert_eq!(state.queue.held_until, None);
+    }
+
+    #[tokio::test]
+    async fn permits_track_queue_depth() {
+        let metrics = MetricsHandle::default();
+        let scheduler = Scheduler::new(metrics.clone());
+        let first = scheduler.acquire(RequestPriority::Background).await;
+        let second = scheduler.acquire(RequestPriority::Background).await;
+        assert_eq!(metrics.snapshot().queue.in_flight, 2);
+
+        let queued = {
+            let scheduler = scheduler.clone();
+            tokio::spawn(async move {
+                let _permit = scheduler.acquire(RequestPriority::Background).await;
+            })
+        };
+        while metrics.snapshot().queue.background == 0 {
+            tokio::task::yield_now().await;
+        }
+        drop(first);
+        queued.await.unwrap();
+        drop(second);
+        assert_eq!(metrics.snapshot().queue, QueueDepth::default());
+    }
+}
@@ -0,0 +1,571 @@
+//! The Linear schema as reported by introspection, and a field selection over it that renders
+//! to a query document. Backs the TUI's `:api` explorer; `cargo xtask verify-schema` runs the
+//! same introspection query.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Introspection query for the types, fields, arguments, input fields, and enum values.
+pub const INTROSPECTION_QUERY: &str = r#"
+    query IntrospectionQuery {
+        __schema {
+            queryType { name }
+            mutationType { name }
+            types {
+                kind
+                name
+                description
+                fields(includeDeprecated: true) {
+                    name
+                    description
+                    args { name description defaultValue type { ...TypeRef } }
+                    type { ...TypeRef }
+                }
+                inputFields { name description defaultValue type { ...TypeRef } }
+                enumValues(includeDeprecated: true) { name }
+            }
+        }
+    }
+
+    fragment TypeRef on __Type {
+        kind name
+        ofType { kind name ofType { kind name ofType { kind name ofType { kind name
+            ofType { kind name ofType { kind name ofType { kind name } } } } } } }
+    }
+"#;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TypeKind {
+    Scalar,
+    Object,
+    Interface,
+    Union,
+    Enum,
+    InputObject,
+    List,
+    NonNull,
+}
+
+/// A field or argument type such as `[IssueLabel!]!`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TypeRef {
+    Named(String),
+    List(Box<TypeRef>),
+    NonNull(Box<TypeRef>),
+}
+
+impl TypeRef {
+    /// The named type under any list and non-null wrappers.
+    pub fn named(&self) -> &str {
+        match self {
+            TypeRef::Named(name) => name,
+            TypeRef::List(inner) | TypeRef::NonNull(inner) => inner.named(),
+        }
+    }
+}
+
+impl fmt::Display for TypeRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypeRef::Named(name) => f.write_str(name),
+            TypeRef::List(inner) => write!(f, "[{inner}]"),
+            TypeRef::NonNull(inner) => write!(f, "{inner}!"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SchemaType {
+    pub kind: TypeKind,
+    pub name: String,
+    pub description: Option<String>,
+    pub fields: Vec<SchemaField>,
+    pub input_fields: Vec<InputValue>,
+    pub enum_values: Vec<String>,
+}
+
+impl SchemaType {
+    /// Objects, interfaces, and unions, which need a selection set.
+    pub fn is_composite(&self) -> bool {
+        matches!(
+            self.kind,
+            TypeKind::Object | TypeKind::Interface | TypeKind::Union
+        )
+    }
+
+    pub fn field(&self, name: &str) -> Option<&SchemaField> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SchemaField {
+    pub name: String,
+    pub description: Option<String>,
+    pub args: Vec<InputValue>,
+    pub ty: TypeRef,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InputValue {
+    pub name: String,
+    pub description: Option<String>,
+    pub ty: TypeRef,
+    pub default_value: Option<String>,
+}
+
+/// Every named type in the schema, keyed by name.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Schema {
+    pub query_type: String,
+    pub mutation_type: Option<String>,
+    pub types: BTreeMap<String, SchemaType>,
+}
+
+impl Schema {
+    /// Parse an introspection result, either the full response or its `data` payload.
+    pub fn from_introspection(mut value: Value) -> serde_json::Result<Self> {
+        if let Some(data) = value.get_mut("data") {
+            value = data.take();
+        }
+        if let Some(schema) = value.get_mut("__schema") {
+            value = schema.take();
+        }
+        let raw: raw::Schema = serde_json::from_value(value)?;
+        Ok(Self {
+            query_type: raw.query_type.name,
+            mutation_type: raw.mutation_type.map(|named| named.name),
+            types: raw
+                .types
+                .into_iter()
+                .map(|ty| (ty.name.clone(), ty.into()))
+                .collect(),
+        })
+    }
+
+    pub fn get(&self, name: &str) -> Option<&SchemaType> {
+        self.types.get(name)
+    }
+
+    /// The field at `path` below the type `root`, following each field's type.
+    pub fn field_at(&self, root: &str, path: &[String]) -> Option<&SchemaField> {
+        let (last, parents) = path.split_last()?;
+        let mut owner = self.get(root)?;
+        for name in parents {
+            owner = self.get(owner.field(name)?.ty.named())?;
+        }
+        owner.field(last)
+    }
+
+    /// The type whose fields are listed at `path` below `root`.
+    pub fn type_at(&self, root: &str, path: &[String]) -> Option<&SchemaType> {
+        match path {
+            [] => self.get(root),
+            _ => self.get(self.field_at(root, path)?.ty.named()),
+        }
+    }
+
+    /// What this schema declares that `other` lacks or gives a different named type, one entry
+    /// per type, field, argument, input field, or enum value, e.g. `Issue.title` or
+    /// `Query.issues(first)`. Nullability is not compared. Empty when `other` serves everything
+    /// this schema expects.
+    pub fn missing_from(&self, other: &Schema) -> Vec<String> {
+        let mut missing = Vec::new();
+        for (name, ty) in &self.types {
+            let Some(theirs) = other.get(name) else {
+                missing.push(name.clone());
+                continue;
+            };
+            for field in &ty.fields {
+                let Some(their_field) = theirs.field(&field.name) else {
+                    missing.push(format!("{name}.{}", field.name));
+                    continue;
+                };
+                if field.ty.named() != their_field.ty.named() {
+                    missing.push(format!("{name}.{}: {}", field.name, field.ty));
+                }
+                for arg in &field.args {
+                    match their_field.args.iter().find(|their| their.name == arg.name) {
+                        Some(their) if their.ty.named() == arg.ty.named() => {}
+                        _ => missing.push(format!("{name}.{}({})", field.name, arg.name)),
+                    }
+                }
+            }
+            for input in &ty.input_fields {
+                match theirs
+                    .input_fields
+                    .iter()
+                    .find(|their| their.name == input.name)
+                {
+                    Some(their) if their.ty.named() == input.ty.named() => {}
+                    _ => missing.push(format!("{name}.{}", input.name)),
+                }
+            }
+            for value in &ty.enum_values {
+                if !theirs.enum_values.contains(value) {
+                    missing.push(format!("{name}.{value}"));
+                }
+            }
+        }
+        missing
+    }
+}
+
+/// Fields picked out of a type, with their arguments and nested selections.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Selection {
+    pub fields: Vec<SelectedField>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SelectedField {
+    pub name: String,
+    /// Argument list written as GraphQL, e.g. `first: 10, filter: { ... }`; empty for none.
+    pub arguments: String,
+    pub selection: Selection,
+}
+
+impl Selection {
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    pub fn get(&self, path: &[String]) -> Option<&SelectedField> {
+        let (first, rest) = path.split_first()?;
+        let field = self.fields.iter().find(|field| &field.name == first)?;
+        match rest {
+            [] => Some(field),
+            _ => field.selection.get(rest),
+        }
+    }
+
+    pub fn contains(&self, path: &[String]) -> bool {
+        self.get(path).is_some()
+    }
+
+    /// The field at `path`, adding it and any missing parents.
+    pub fn ensure(&mut self, path: &[String]) -> Option<&mut SelectedField> {
+        let (first, rest) = path.split_first()?;
+        let index = match self.fields.iter().position(|field| &field.name == first) {
+            Some(index) => index,
+            None => {
+                self.fields.push(SelectedField {
+                    name: first.clone(),
+                    ..Default::default()
+                });
+                self.fields.len() - 1
+            }
+        };
+        let field = &mut self.fields[index];
+        match rest {
+            [] => Some(field),
+            _ => field.selection.ensure(rest),
+        }
+    }
+
+    /// Add the field at `path`, or remove it with everything below; returns whether it is
+    /// now selected.
+    pub fn toggle(&mut self, path: &[String]) -> bool {
+        if self.remove(path) {
+            return false;
+        }
+        self.ensure(path);
+        true
+    }
+
+    fn remove(&mut self, path: &[String]) -> bool {
+        let Some((first, rest)) = path.split_first() else {
+            return false;
+        };
+        let Some(index) = self.fields.iter().position(|field| &field.name == first) else {
+            return false;
+        };
+        if rest.is_empty() {
+            self.fields.remove(index);
+            true
+        } else {
+            self.fields[index].selection.remove(rest)
+        }
+    }
+
+    /// A `kind Name { ... }` document for this selection on the type `root`. Composite fields
+    /// with nothing picked below them select `__typename` so the document stays valid.
+    pub fn document(&self, schema: &Schema, kind: &str, name: &str, root: &str) -> String {
+        let mut out = format!("{kind} {name} {{\n");
+        self.write(&mut out, schema, Some(root), 1);
+        out.push_str("}\n");
+        out
+    }
+
+    fn write(&self, out: &mut String, schema: &Schema, owner: Option<&str>, depth: usize) {
+        let indent = "  ".repeat(depth);
+        let owner = owner.and_then(|name| schema.get(name));
+        for field in &self.fields {
+            out.push_str(&indent);
+            out.push_str(&field.name);
+            let arguments = field.arguments.trim();
+            if !arguments.is_empty() {
+                out.push_str(&format!("({arguments})"));
+            }
+            let ty = owner
+                .and_then(|owner| owner.field(&field.name))
+                .map(|definition| definition.ty.named());
+            let composite = ty
+                .and_then(|ty| schema.get(ty))
+                .is_some_and(SchemaType::is_composite);
+            if composite || !field.selection.is_empty() {
+                out.push_str(" {\n");
+                if field.selection.is_empty() {
+                    out.push_str(&format!("{indent}  __typename\n"));
+                } else {
+                    field.selection.write(out, schema, ty, depth + 1);
+                }
+                out.push_str(&indent);
+                out.push('}');
+            }
+            out.push('\n');
+        }
+    }
+}
+
+/// Shapes of the introspection response.
+mod raw {
+    use serde::Deserialize;
+
+    use super::{InputValue as SchemaInput, SchemaField, SchemaType, TypeKind, TypeRef};
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Schema {
+        pub query_type: Named,
+        pub mutation_type: Option<Named>,
+        pub types: Vec<FullType>,
+    }
+
+    #[derive(Deserialize)]
+    pub struct Named {
+        pub name: String,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct FullType {
+        kind: TypeKind,
+        pub name: String,
+        #[serde(default)]
+        description: Option<String>,
+        #[serde(default)]
+        fields: Option<Vec<Field>>,
+        #[serde(default)]
+        input_fields: Option<Vec<InputValue>>,
+        #[serde(default)]
+        enum_values: Option<Vec<Named>>,
+    }
+
+    #[derive(Deserialize)]
+    struct Field {
+        name: String,
+        #[serde(default)]
+        description: Option<String>,
+        #[serde(default)]
+        args: Vec<InputValue>,
+        #[serde(rename = "type")]
+        ty: Type,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct InputValue {
+        name: String,
+        #[serde(default)]
+        description: Option<String>,
+        #[serde(rename = "type")]
+        ty: Type,
+        #[serde(default)]
+        default_value: Option<String>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Type {
+        kind: TypeKind,
+        name: Option<String>,
+        of_type: Option<Box<Type>>,
+    }
+
+    impl From<Type> for TypeRef {
+        fn from(ty: Type) -> Self {
+            let inner = || {
+                ty.of_type
+                    .map_or(TypeRef::Named(String::new()), |inner| (*inner).into())
+            };
+            match ty.kind {
+                TypeKind::NonNull => TypeRef::NonNull(Box::new(inner())),
+                TypeKind::List => TypeRef::List(Box::new(inner())),
+                _ => TypeRef::Named(ty.name.unwrap_or_default()),
+            }
+        }
+    }
+
+    impl From<InputValue> for SchemaInput {
+        fn from(value: InputValue) -> Self {
+            Self {
+                name: value.name,
+                description: value.description,
+                ty: value.ty.into(),
+                default_value: value.default_value,
+            }
+        }
+    }
+
+    impl From<FullType> for SchemaType {
+        fn from(ty: FullType) -> Self {
+            Self {
+                kind: ty.kind,
+                name: ty.name,
+                description: ty.description,
+                fields: ty
+                    .fields
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|field| SchemaField {
+                        name: field.name,
+                        description: field.description,
+                        args: field.args.into_iter().map(Into::into).collect(),
+                        ty: field.ty.into(),
+                    })
+                    .collect(),
+                input_fields: ty
+                    .input_fields
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(Into::into)
+                    .collect(),
+                enum_values: ty
+                    .enum_values
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|value| value.name)
+                    .collect(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn schema() -> Schema {
+        let named = |kind: &str, name: &str| json!({"kind": kind, "name": name, "ofType": null});
+        let non_null = |inner: Value| json!({"kind": "NON_NULL", "name": null, "ofType": inner});
+        let field = |name: &str, ty: Value| json!({"name": name, "args": [], "type": ty});
+        Schema::from_introspection(json!({"data": {"__schema": {
+            "queryType": {"name": "Query"},
+            "mutationType": null,
+            "types": [
+                {"kind": "OBJECT", "name": "Query", "fields": [
+                    {"name": "issues", "type": non_null(named("OBJECT", "IssueConnection")),
+                     "args": [{"name": "first", "type": named("SCALAR", "Int"), "defaultValue": "50"}]},
+                    field("viewer", non_null(named("OBJECT", "User")))
+                ]},
+                {"kind": "OBJECT", "name": "IssueConnection", "fields": [
+                    field("nodes", non_null(json!({"kind": "LIST", "name": null,
+                        "ofType": non_null(named("OBJECT", "Issue"))})))
+                ]},
+                {"kind": "OBJECT", "name": "Issue", "fields": [
+                    field("id", non_null(named("SCALAR", "ID"))),
+                    field("title", non_null(named("SCALAR", "String")))
+                ]},
+                {"kind": "OBJECT", "name": "User", "fields": [
+                    field("id", non_null(named("SCALAR", "ID")))
+                ]},
+                {"kind": "SCALAR", "name": "ID"}
+            ]
+        }}}))
+        .unwrap()
+    }
+
+    fn path(parts: &[&str]) -> Vec<String> {
+        parts.iter().map(|part| part.to_string()).collect()
+    }
+
+    #[test]
+    fn resolves_types_along_a_path() {
+        let schema = schema();
+        let nodes = schema
+            .field_at("Query", &path(&["issues", "nodes"]))
+            .unwrap();
+        assert_eq!(nodes.ty.to_string(), "[Issue!]!");
+        assert_eq!(nodes.ty.named(), "Issue");
+        assert_eq!(
+            schema
+                .type_at("Query", &path(&["issues", "nodes"]))
+                .unwrap()
+                .name,
+            "Issue"
+        );
+        let issues = schema.field_at("Query", &path(&["issues"])).unwrap();
+        assert_eq!(issues.args[0].default_value.as_deref(), Some("50"));
+        assert!(schema
+            .field_at("Query", &path(&["issues", "nope"]))
+            .is_none());
+    }
+
+    #[test]
+    fn renders_selected_fields_as_a_document() {
+        let schema = schema();
+        let mut selection = Selection::default();
+        assert!(selection.toggle(&path(&["issues", "nodes", "title"])));
+        selection.toggle(&path(&["issues", "nodes", "id"]));
+        selection.ensure(&path(&["issues"])).unwrap().arguments = "first: 5".into();
+        selection.toggle(&path(&["viewer"]));
+        assert_eq!(
+            selection.document(&schema, "query", "Explorer", "Query"),
+            "query Explorer {\n  issues(first: 5) {\n    nodes {\n      title\n      id\n    }\n  }\n  \
+             viewer {\n    __typename\n  }\n}\n"
+        );
+
+        assert!(!selection.toggle(&path(&["issues", "nodes"])));
+        assert!(selection.contains(&path(&["issues"])));
+        assert!(!selection.contains(&path(&["issues", "nodes", "id"])));
+    }
+
+    #[test]
+    fn reports_what_another_schema_lacks() {
+        let expected = schema();
+        assert!(expected.missing_from(&expected).is_empty());
+
+        let mut live = schema();
+        live.types.remove("User");
+        let issue = live.types.get_mut("Issue").unwrap();
+        issue.fields.retain(|field| field.name != "title");
+        issue.fields[0].ty = TypeRef::Named("String".into());
+        let query = live.types.get_mut("Query").unwrap();
+        query.fields[0].args.clear();
+        query.fields[1].ty = TypeRef::Named("User".into());
+        query.fields.push(SchemaField {
+            name: "teams".into(),
+            description: None,
+            args: Vec::new(),
+            ty: TypeRef::Named("ID".into()),
+        });
+        assert_eq!(
+            expected.missing_from(&live),
+            [
+                "Issue.id: ID!",
+                "Issue.title",
+                "Query.issues(first)",
+                "User"
+            ]
+        );
+        assert!(live
+            .missing_from(&expected)
+            .contains(&"Query.teams".to_owned()));
+    }
+}
@@ -0,0 +1,124 @@
+//! Incremental decoding of large GraphQL responses.
+//!
+//! A full issue page with descriptions runs to megabytes. Instead of buffering the body into a
+//! string and parsing it afterwards, [`decode`] hands chunks to `serde_json` on a blocking
+//! thread as they arrive, so parsing overlaps the transfer and the raw body is never held in
+//! full next to the structs built from it.
+
+use std::io::{self, Read};
+
+use bytes::{Buf, Bytes};
+use reqwest::Response;
+use serde::de::DeserializeOwned;
+use tokio::sync::mpsc;
+
+use super::client::{GraphqlError, GraphqlResult};
+
+/// Bodies at least this large, or of unknown length, are decoded as they stream in.
+pub(crate) const STREAM_THRESHOLD: u64 = 64 * 1024;
+/// Chunks buffered between the connection and the parser.
+const CHUNKS_IN_FLIGHT: usize = 16;
+
+/// Whether `response` is worth decoding incrementally rather than buffering.
+pub(crate) fn worth_streaming(response: &Response) -> bool {
+    response
+        .content_length()
+        .map_or(true, |length| length >= STREAM_THRESHOLD)
+}
+
+/// Deserialize the body of a successful `response` while it downloads.
+///
+/// `http_error` maps a transfer failure; it takes precedence over the parse error that the
+/// truncated body causes.
+pub(crate) async fn decode<R>(
+    mut response: Response,
+    http_error: impl FnOnce(reqwest::Error) -> GraphqlError,
+) -> GraphqlResult<R>
+where
+    R: DeserializeOwned + Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel(CHUNKS_IN_FLIGHT);
+    let parser =
+        tokio::task::spawn_blocking(move || serde_json::from_reader(ChunkReader::new(receiver)));
+
+    let mut failure = None;
+    loop {
+        match response.chunk().await {
+            Ok(Some(chunk)) => {
+                // The parser stopped early, e.g. on malformed JSON; its error says why.
+                if sender.send(chunk).await.is_err() {
+                    break;
+                }
+            }
+            Ok(None) => break,
+            Err(err) => {
+                failure = Some(err);
+                break;
+            }
+        }
+    }
+    drop(sender);
+
+    let parsed = match parser.await {
+        Ok(parsed) => parsed,
+        Err(err) => std::panic::resume_unwind(err.into_panic()),
+    };
+    if let Some(err) = failure {
+        return Err(http_error(err));
+    }
+    Ok(parsed?)
+}
+
+/// Blocking reader over chunks sent from the async side; ends when the sender is dropped.
+struct ChunkReader {
+    chunks: mpsc::Receiver<Bytes>,
+    current: Bytes,
+}
+
+impl ChunkReader {
+    fn new(chunks: mpsc::Receiver<Bytes>) -> Self {
+        Self {
+            chunks,
+            current: Bytes::new(),
+        }
+    }
+}
+
+impl Read for ChunkReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while !self.current.has_remaining() {
+            match self.chunks.blocking_recv() {
+                Some(chunk) => self.current = chunk,
+                None => return Ok(0),
+            }
+        }
+        let len = buf.len().min(self.current.remaining());
+        self.current.copy_to_slice(&mut buf[..len]);
+        Ok(len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reader_joins_chunks_split_anywhere() {
+        let (sender, receiver) = mpsc::channel(4);
+        for part in [
+            r#"{"nodes":[{"ti"#,
+            r#"tle":"a"},"#,
+            "",
+            r#"{"title":"b"}]}"#,
+        ] {
+            sender.try_send(Bytes::from(part)).unwrap();
+        }
+        drop(sender);
+
+        let value: serde_json::Value = serde_json::from_reader(ChunkReader::new(receiver)).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({ "nodes": [{ "title": "a" }, { "title": "b" }] })
+        );
+    }
+}
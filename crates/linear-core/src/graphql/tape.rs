@@ -0,0 +1,254 @@
+use std::collections::{HashMap, VecDeque};
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::client::{GraphqlError, GraphqlResult};
+
+/// Tapes are JSON lines: a `{"version": 2}` header, then one [`Interaction`] per line.
+const TAPE_VERSION: u32 = 2;
+/// Version 1 tapes were one JSON document holding every interaction; they still replay.
+const LEGACY_TAPE_VERSION: u32 = 1;
+
+/// One captured GraphQL exchange. Credentials are never written: only the request
+/// body (query + variables) and the raw response are kept.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: Value,
+    pub status: u16,
+    pub response: Value,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Header {
+    version: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct LegacyTapeFile {
+    version: u32,
+    interactions: Vec<Interaction>,
+}
+
+/// A `--record` or `--replay` session that any number of clients share, so a command that
+/// builds several (one per profile, or a TUI profile switch) writes or replays one tape.
+#[derive(Debug, Clone)]
+pub struct SessionTape(pub(crate) Arc<Tape>);
+
+impl SessionTape {
+    /// Start recording to `path`, replacing what it held.
+    pub fn record(path: &Path) -> GraphqlResult<Self> {
+        Ok(Self(Arc::new(Tape::record(path)?)))
+    }
+
+    pub fn replay(path: &Path) -> GraphqlResult<Self> {
+        Ok(Self(Arc::new(Tape::replay(path)?)))
+    }
+}
+
+/// Query text and canonical variables; replay answers a request with the responses recorded
+/// for the same key, in recorded order, so concurrent requests may arrive in any order.
+type RequestKey = (String, String);
+
+/// Record/replay middleware attached to a [`LinearGraphqlClient`](super::LinearGraphqlClient).
+#[derive(Debug)]
+pub(crate) enum Tape {
+    Record {
+        path: PathBuf,
+        file: Mutex<File>,
+    },
+    Replay {
+        interactions: Mutex<HashMap<RequestKey, VecDeque<Interaction>>>,
+    },
+}
+
+impl Tape {
+    pub(crate) fn record(path: &Path) -> GraphqlResult<Self> {
+        let file = File::create(path).map_err(|err| write_error(path, err))?;
+        let tape = Tape::Record {
+            path: path.to_path_buf(),
+            file: Mutex::new(file),
+        };
+        tape.append(&Header {
+            version: TAPE_VERSION,
+        })?;
+        Ok(tape)
+    }
+
+    pub(crate) fn replay(path: &Path) -> GraphqlResult<Self> {
+        let raw = fs::read_to_string(path).map_err(|err| {
+            GraphqlError::Tape(format!("failed to read {}: {}", path.display(), err))
+        })?;
+        let mut interactions: HashMap<RequestKey, VecDeque<Interaction>> = HashMap::new();
+        for interaction in parse(&raw)? {
+            interactions
+                .entry(request_key(&interaction.request))
+                .or_default()
+                .push_back(interaction);
+        }
+        Ok(Tape::Replay {
+            interactions: Mutex::new(interactions),
+        })
+    }
+
+    /// Return the next response recorded for this query and these variables; a request the
+    /// tape has no (more) responses for is an error.
+    pub(crate) fn next_response(&self, request: &Value) -> GraphqlResult<(u16, String)> {
+        let Tape::Replay { interactions } = self else {
+            return Err(GraphqlError::Tape("tape is not in replay mode".into()));
+        };
+        let mut interactions = interactions
+            .lock()
+            .map_err(|_| GraphqlError::Tape("tape lock poisoned".into()))?;
+        let key = request_key(request);
+        let interaction = interactions
+            .get_mut(&key)
+            .and_then(VecDeque::pop_front)
+            .ok_or_else(|| {
+                let left = if interactions.contains_key(&key) {
+                    "no recorded response left"
+                } else {
+                    "no recorded response"
+                };
+                GraphqlError::Tape(format!(
+                    "{left} for {} with variables {}",
+                    operation_name(request),
+                    key.1
+                ))
+            })?;
+        let body = match interaction.response {
+            Value::String(text) => text,
+            other => other.to_string(),
+        };
+        Ok((interaction.status, body))
+    }
+
+    pub(crate) fn capture(&self, request: Value, status: u16, body: &str) -> GraphqlResult<()> {
+        if !matches!(self, Tape::Record { .. }) {
+            return Ok(());
+        }
+        let response =
+            serde_json::from_str(body).unwrap_or_else(|_| Value::String(body.to_string()));
+        self.append(&Interaction {
+            request,
+            status,
+            response,
+        })
+    }
+
+    pub(crate) fn is_replay(&self) -> bool {
+        matches!(self, Tape::Replay { .. })
+    }
+
+    /// Write one line to the end of the tape, so each exchange costs only its own size and a
+    /// session cut short keeps everything recorded before it.
+    fn append<T: Serialize>(&self, entry: &T) -> GraphqlResult<()> {
+        let Tape::Record { path, file } = self else {
+            return Ok(());
+        };
+        let mut line = serde_json::to_string(entry)?;
+        line.push('\n');
+        let mut file = file
+            .lock()
+            .map_err(|_| GraphqlError::Tape("tape lock poisoned".into()))?;
+        file.write_all(line.as_bytes())
+            .map_err(|err| write_error(path, err))
+    }
+}
+
+/// The interactions of a JSON lines tape, or of a version 1 tape.
+fn parse(raw: &str) -> GraphqlResult<Vec<Interaction>> {
+    let mut lines = raw.lines().filter(|line| !line.trim().is_empty());
+    let header = lines.next().map(serde_json::from_str::<Header>);
+    match header {
+        Some(Ok(Header {
+            version: TAPE_VERSION,
+        })) => lines
+            .map(|line| serde_json::from_str(line).map_err(Into::into))
+            .collect(),
+        Some(Ok(header)) => Err(unsupported(header.version)),
+        // A version 1 tape is pretty-printed, so its first line is not a document of its own.
+        _ => {
+            let file: LegacyTapeFile = serde_json::from_str(raw)?;
+            if file.version != LEGACY_TAPE_VERSION {
+                return Err(unsupported(file.version));
+            }
+            Ok(file.interactions)
+        }
+    }
+}
+
+fn unsupported(version: u32) -> GraphqlError {
+    GraphqlError::Tape(format!("unsupported tape version {version}"))
+}
+
+fn write_error(path: &Path, err: std::io::Error) -> GraphqlError {
+    GraphqlError::Tape(format!("failed to write {}: {}", path.display(), err))
+}
+
+fn request_key(request: &Value) -> RequestKey {
+    let query = request
+        .get("query")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string();
+    let variables = canonical(request.get("variables").unwrap_or(&Value::Null));
+    (query, variables.to_string())
+}
+
+/// `value` with object keys sorted, so equal variables compare equal however they were built.
+fn canonical(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(b.0));
+            Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key.clone(), canonical(value)))
+                    .collect(),
+            )
+        }
+        Value::Array(items) => Value::Array(items.iter().map(canonical).collect()),
+        other => other.clone(),
+    }
+}
+
+fn operation_name(request: &Value) -> String {
+    request
+        .get("query")
+        .and_then(Value::as_str)
+        .and_then(|query| {
+            query
+                .split(|c: char| c == '(' || c == '{' || c.is_whitespace())
+                .filter(|part| !part.is_empty())
+                .nth(1)
+        })
+        .unwrap_or("anonymous operation")
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_json_lines_and_version_1_tapes() {
+        let interaction = r#"{"request":{"query":"query Viewer { viewer { id } }"},"status":200,"response":{"data":{}}}"#;
+        let lines = format!("{{\"version\":2}}\n{interaction}\n{interaction}\n");
+        assert_eq!(parse(&lines).unwrap().len(), 2);
+
+        let legacy =
+            format!("{{\n  \"version\": 1,\n  \"interactions\": [\n    {interaction}\n  ]\n}}");
+        assert_eq!(parse(&legacy).unwrap().len(), 1);
+
+        assert!(matches!(
+            parse("{\"version\":3}\n"),
+            Err(GraphqlError::Tape(_))
+        ));
+    }
+}
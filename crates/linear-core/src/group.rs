@@ -0,0 +1,202 @@
+//! Partition issue lists into labelled sections, shared by `issue list --group-by` and the TUI.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::graphql::IssueSummary;
+use crate::priority::Priority;
+
+/// Field issues are grouped by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupBy {
+    State,
+    Assignee,
+    Project,
+    Priority,
+}
+
+impl GroupBy {
+    pub const ALL: [GroupBy; 4] = [
+        GroupBy::State,
+        GroupBy::Assignee,
+        GroupBy::Project,
+        GroupBy::Priority,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            GroupBy::State => "state",
+            GroupBy::Assignee => "assignee",
+            GroupBy::Project => "project",
+            GroupBy::Priority => "priority",
+        }
+    }
+}
+
+impl fmt::Display for GroupBy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for GroupBy {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let normalized = input.trim().to_ascii_lowercase();
+        GroupBy::ALL
+            .into_iter()
+            .find(|by| by.as_str() == normalized)
+            .ok_or_else(|| {
+                format!(
+                    "unknown grouping '{}'; expected state, assignee, project, or priority",
+                    input
+                )
+            })
+    }
+}
+
+/// Issues sharing one group label, in their original order.
+#[derive(Debug, Clone)]
+pub struct IssueGroup<'a> {
+    pub label: String,
+    pub issues: Vec<&'a IssueSummary>,
+}
+
+impl IssueSummary {
+    /// Section header this issue falls under when grouped `by`.
+    pub fn group_label(&self, by: GroupBy) -> String {
+        match by {
+            GroupBy::State => self
+                .state
+                .as_ref()
+                .map(|state| state.name.clone())
+                .unwrap_or_else(|| "No state".into()),
+            GroupBy::Assignee => self
+                .assignee
+                .as_ref()
+                .and_then(|a| a.display_name.clone().or_else(|| a.name.clone()))
+                .unwrap_or_else(|| "Unassigned".into()),
+            GroupBy::Project => self
+                .project
+                .as_ref()
+                .map(|project| project.name.clone())
+                .unwrap_or_else(|| "No project".into()),
+            GroupBy::Priority => match self.priority.and_then(Priority::from_value) {
+                Some(priority) => priority.label().to_owned(),
+                None => "No priority".into(),
+            },
+        }
+    }
+
+    /// Where this issue's group sorts: workflow order for states, importance for priorities,
+    /// and "nobody"/"none" groups last otherwise. Ties fall back to the label.
+    fn group_rank(&self, by: GroupBy) -> u8 {
+        match by {
+            GroupBy::State => match self.state.as_ref().and_then(|s| s.kind.as_deref()) {
+                Some("triage") => 0,
+                Some("backlog") => 1,
+                Some("unstarted") => 2,
+                Some("started") => 3,
+                Some("completed") => 4,
+                Some("canceled") => 5,
+                _ => 6,
+            },
+            GroupBy::Assignee => u8::from(self.assignee.is_none()),
+            GroupBy::Project => u8::from(self.project.is_none()),
+            GroupBy::Priority => match self.priority.and_then(Priority::from_value) {
+                Some(Priority::None) | None => 4,
+                Some(priority) => priority.value() as u8 - 1,
+            },
+        }
+    }
+}
+
+/// Split `issues` into groups sorted by [`GroupBy`] order, keeping issue order within a group.
+pub fn group_issues(issues: &[IssueSummary], by: GroupBy) -> Vec<IssueGroup<'_>> {
+    let mut groups: Vec<(u8, IssueGroup<'_>)> = Vec::new();
+    for issue in issues {
+        let label = issue.group_label(by);
+        match groups.iter_mut().find(|(_, group)| group.label == label) {
+            Some((_, group)) => group.issues.push(issue),
+            None => groups.push((
+                issue.group_rank(by),
+                IssueGroup {
+                    label,
+                    issues: vec![issue],
+                },
+            )),
+        }
+    }
+    groups.sort_by_cached_key(|(rank, group)| (*rank, group.label.to_lowercase()));
+    groups.into_iter().map(|(_, group)| group).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(
+        identifier: &str,
+        state: (&str, &str),
+        assignee: Option<&str>,
+        priority: i32,
+    ) -> IssueSummary {
+        serde_json::from_value(serde_json::json!({
+            "id": identifier,
+            "identifier": identifier,
+            "title": identifier,
+            "url": null,
+            "priority": priority,
+            "createdAt": "2024-01-01T00:00:00Z",
+            "updatedAt": "2024-01-01T00:00:00Z",
+            "state": { "id": state.0, "name": state.0, "type": state.1 },
+            "assignee": assignee.map(|name| serde_json::json!({ "id": name, "name": name })),
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn orders_states_by_workflow_and_keeps_issue_order() {
+        let issues = vec![
+            issue("ENG-1", ("Done", "completed"), None, 0),
+            issue("ENG-2", ("In Progress", "started"), Some("ada"), 1),
+            issue("ENG-3", ("Todo", "unstarted"), Some("ada"), 3),
+            issue("ENG-4", ("In Progress", "started"), None, 2),
+        ];
+        let groups = group_issues(&issues, GroupBy::State);
+        let labels: Vec<_> = groups.iter().map(|g| g.label.as_str()).collect();
+        assert_eq!(labels, ["Todo", "In Progress", "Done"]);
+        let keys: Vec<_> = groups[1]
+            .issues
+            .iter()
+            .map(|i| i.identifier.as_str())
+            .collect();
+        assert_eq!(keys, ["ENG-2", "ENG-4"]);
+    }
+
+    #[test]
+    fn puts_missing_values_last() {
+        let issues = vec![
+            issue("ENG-1", ("Todo", "unstarted"), None, 0),
+            issue("ENG-2", ("Todo", "unstarted"), Some("ada"), 4),
+            issue("ENG-3", ("Todo", "unstarted"), None, 1),
+        ];
+        let by_assignee: Vec<_> = group_issues(&issues, GroupBy::Assignee)
+            .into_iter()
+            .map(|g| (g.label, g.issues.len()))
+            .collect();
+        assert_eq!(by_assignee, [("ada".into(), 1), ("Unassigned".into(), 2)]);
+
+        let by_priority: Vec<_> = group_issues(&issues, GroupBy::Priority)
+            .into_iter()
+            .map(|g| g.label)
+            .collect();
+        assert_eq!(by_priority, ["Urgent", "Low", "No priority"]);
+        assert_eq!(
+            group_issues(&issues, GroupBy::Project)[0].label,
+            "No project"
+        );
+        assert_eq!("Priority".parse::<GroupBy>(), Ok(GroupBy::Priority));
+    }
+}
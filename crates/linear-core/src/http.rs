@@ -0,0 +1,145 @@
+//! The HTTP client shared by everything in the process that talks to Linear or a webhook.
+//!
+//! `reqwest::Client` keeps its connection pool behind an `Arc`, so handing out clones of one
+//! client lets the GraphQL client, OAuth exchanges, notifiers, and every service built on them
+//! reuse warm TLS connections instead of dialing afresh. Idle connections are kept long enough
+//! to survive a TUI session's pauses, TCP keepalive stops middleboxes from dropping them, and
+//! resolved addresses are cached so reconnecting does not wait on DNS.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use reqwest::redirect::Policy;
+use reqwest::{Client, ClientBuilder};
+
+pub const USER_AGENT: &str = "linear-rs/0.1.0";
+
+/// How long an idle pooled connection is kept before it is closed.
+const POOL_IDLE_TIMEOUT: Duration = Duration::from_secs(300);
+/// Idle connections kept per host; the TUI runs a handful of requests at once.
+const POOL_MAX_IDLE_PER_HOST: usize = 8;
+const TCP_KEEPALIVE: Duration = Duration::from_secs(30);
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+/// How long resolved addresses are reused.
+const DNS_TTL: Duration = Duration::from_secs(300);
+
+/// The process-wide pooled client, built on first use.
+pub fn shared() -> reqwest::Result<Client> {
+    static SHARED: OnceLock<Client> = OnceLock::new();
+    if let Some(client) = SHARED.get() {
+        return Ok(client.clone());
+    }
+    let client = builder().build()?;
+    Ok(SHARED.get_or_init(|| client).clone())
+}
+
+/// Like [`shared`], but redirects are returned instead of followed, so a caller attaching
+/// credentials can decide where they may go.
+pub fn without_redirects() -> reqwest::Result<Client> {
+    static CLIENT: OnceLock<Client> = OnceLock::new();
+    if let Some(client) = CLIENT.get() {
+        return Ok(client.clone());
+    }
+    let client = builder().redirect(Policy::none()).build()?;
+    Ok(CLIENT.get_or_init(|| client).clone())
+}
+
+fn builder() -> ClientBuilder {
+    Client::builder()
+        .user_agent(USER_AGENT)
+        .pool_idle_timeout(POOL_IDLE_TIMEOUT)
+        .pool_max_idle_per_host(POOL_MAX_IDLE_PER_HOST)
+        .tcp_keepalive(TCP_KEEPALIVE)
+        .tcp_nodelay(true)
+        .connect_timeout(CONNECT_TIMEOUT)
+        .dns_resolver(Arc::new(CachingResolver::new(DNS_TTL)))
+}
+
+/// Resolves through the system resolver and remembers successful answers for `ttl`.
+#[derive(Debug)]
+struct CachingResolver {
+    ttl: Duration,
+    entries: Arc<Mutex<HashMap<String, Resolved>>>,
+}
+
+#[derive(Debug, Clone)]
+struct Resolved {
+    addrs: Vec<SocketAddr>,
+    at: Instant,
+}
+
+impl CachingResolver {
+    fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Arc::default(),
+        }
+    }
+
+    fn cached(&self, host: &str, now: Instant) -> Option<Vec<SocketAddr>> {
+        let entries = self.entries.lock().ok()?;
+        entries
+            .get(host)
+            .filter(|resolved| now.duration_since(resolved.at) < self.ttl)
+            .map(|resolved| resolved.addrs.clone())
+    }
+
+    fn store(
+        entries: &Mutex<HashMap<String, Resolved>>,
+        host: String,
+        addrs: Vec<SocketAddr>,
+        at: Instant,
+    ) {
+        if let Ok(mut entries) = entries.lock() {
+            entries.insert(host, Resolved { addrs, at });
+        }
+    }
+}
+
+impl Resolve for CachingResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let host = name.as_str().to_owned();
+        if let Some(addrs) = self.cached(&host, Instant::now()) {
+            return Box::pin(async move { Ok(Box::new(addrs.into_iter()) as Addrs) });
+        }
+        let entries = self.entries.clone();
+        Box::pin(async move {
+            // Port 0 is replaced by the scheme's port when connecting.
+            let addrs: Vec<SocketAddr> =
+                tokio::net::lookup_host((host.as_str(), 0)).await?.collect();
+            // Failures are not cached, so the next request asks again.
+            if !addrs.is_empty() {
+                Self::store(&entries, host, addrs.clone(), Instant::now());
+            }
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cached_addresses_expire_after_the_ttl() {
+        let resolver = CachingResolver::new(Duration::from_secs(60));
+        let at = Instant::now();
+        let addrs = vec!["10.0.0.1:0".parse().unwrap()];
+        CachingResolver::store(
+            &resolver.entries,
+            "api.linear.app".into(),
+            addrs.clone(),
+            at,
+        );
+
+        assert_eq!(resolver.cached("api.linear.app", at), Some(addrs));
+        assert_eq!(resolver.cached("uploads.linear.app", at), None);
+        assert_eq!(
+            resolver.cached("api.linear.app", at + Duration::from_secs(60)),
+            None
+        );
+    }
+}
@@ -0,0 +1,262 @@
+//! Message catalogs for user-facing strings.
+//!
+//! Catalogs use a small subset of [Fluent](https://projectfluent.org) syntax: `# comments`,
+//! `message-id = text`, indented continuation lines, and `{ $name }` placeables. Anything a
+//! locale leaves out falls back to the English catalog, then to the message id itself.
+
+use std::collections::HashMap;
+
+use thiserror::Error;
+
+/// Locale every other catalog falls back to.
+pub const FALLBACK_LOCALE: &str = "en";
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum CatalogError {
+    #[error("line {line}: expected `message-id = text`")]
+    Syntax { line: usize },
+    #[error("line {line}: `{id}` is defined twice")]
+    Duplicate { line: usize, id: String },
+}
+
+/// Messages of one locale, keyed by id.
+#[derive(Debug, Clone, Default)]
+pub struct Catalog {
+    messages: HashMap<String, String>,
+}
+
+impl Catalog {
+    pub fn parse(source: &str) -> Result<Self, CatalogError> {
+        let mut messages = HashMap::new();
+        let mut current: Option<(String, String)> = None;
+        for (index, raw) in source.lines().enumerate() {
+            let line = index + 1;
+            if raw.trim().is_empty() || raw.trim_start().starts_with('#') {
+                continue;
+            }
+            if raw.starts_with(char::is_whitespace) {
+                // Continuation of the previous message.
+                let Some((_, value)) = current.as_mut() else {
+                    return Err(CatalogError::Syntax { line });
+                };
+                if !value.is_empty() {
+                    value.push('\n');
+                }
+                value.push_str(raw.trim());
+                continue;
+            }
+            let (id, value) = raw.split_once('=').ok_or(CatalogError::Syntax { line })?;
+            let id = id.trim();
+            if id.is_empty() || !id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+                return Err(CatalogError::Syntax { line });
+            }
+            if let Some((id, value)) = current.take() {
+                messages.insert(id, value);
+            }
+            if messages.contains_key(id) {
+                return Err(CatalogError::Duplicate {
+                    line,
+                    id: id.to_owned(),
+                });
+            }
+            current = Some((id.to_owned(), value.trim().to_owned()));
+        }
+        if let Some((id, value)) = current {
+            messages.insert(id, value);
+        }
+        Ok(Self { messages })
+    }
+
+    pub fn get(&self, id: &str) -> Option<&str> {
+        self.messages.get(id).map(String::as_str)
+    }
+
+    /// Ids defined in this catalog, sorted.
+    pub fn ids(&self) -> Vec<&str> {
+        let mut ids: Vec<&str> = self.messages.keys().map(String::as_str).collect();
+        ids.sort_unstable();
+        ids
+    }
+}
+
+/// Looks messages up in a locale's catalog, falling back to English.
+#[derive(Debug, Clone)]
+pub struct Localizer {
+    locale: String,
+    primary: Catalog,
+    fallback: Catalog,
+}
+
+impl Localizer {
+    pub fn new(locale: impl Into<String>, primary: Catalog, fallback: Catalog) -> Self {
+        Self {
+            locale: locale.into(),
+            primary,
+            fallback,
+        }
+    }
+
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    /// The message `id` with its `{ $name }` placeables filled from `args`.
+    pub fn message(&self, id: &str, args: &[(&str, &str)]) -> String {
+        let pattern = self
+            .primary
+            .get(id)
+            .or_else(|| self.fallback.get(id))
+            .unwrap_or(id);
+        format(pattern, args)
+    }
+}
+
+/// Replace each `{ $name }` in `pattern` with its value from `args`; unknown names stay as-is.
+pub fn format(pattern: &str, args: &[(&str, &str)]) -> String {
+    let mut out = String::with_capacity(pattern.len());
+    let mut rest = pattern;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let Some(end) = rest[start..].find('}') else {
+            out.push_str(&rest[start..]);
+            return out;
+        };
+        let placeable = &rest[start..start + end + 1];
+        let name = placeable[1..placeable.len() - 1].trim();
+        match name
+            .strip_prefix('$')
+            .and_then(|name| args.iter().find(|(key, _)| *key == name))
+        {
+            Some((_, value)) => out.push_str(value),
+            None => out.push_str(placeable),
+        }
+        rest = &rest[start + end + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Locale requested by the environment: `LC_ALL`, then `LC_MESSAGES`, then `LANG`.
+///
+/// `de_DE.UTF-8` becomes `de-DE`; `C` and `POSIX` mean no preference.
+pub fn locale_from_env(var: impl Fn(&str) -> Option<String>) -> Option<String> {
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .iter()
+        .filter_map(|name| var(name))
+        .find(|value| !value.trim().is_empty())
+        .and_then(|value| normalize_locale(&value))
+}
+
+/// `de_DE.UTF-8@euro` → `de-DE`; `None` for the C locale.
+pub fn normalize_locale(value: &str) -> Option<String> {
+    let tag = value
+        .split(['.', '@'])
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .replace('_', "-");
+    if tag.is_empty() || tag.eq_ignore_ascii_case("C") || tag.eq_ignore_ascii_case("POSIX") {
+        return None;
+    }
+    Some(tag)
+}
+
+/// The entry of `available` that best serves `requested`: an exact match, else the same language.
+pub fn negotiate<'a>(requested: &str, available: &[&'a str]) -> Option<&'a str> {
+    let language = |tag: &str| {
+        tag.split('-')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase()
+    };
+    available
+        .iter()
+        .find(|tag| tag.eq_ignore_ascii_case(requested))
+        .or_else(|| {
+            available
+                .iter()
+                .find(|tag| language(tag) == language(requested))
+        })
+        .copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_messages_comments_and_continuations() {
+        let catalog = Catalog::parse(
+            "# Status line\nback = Back to issues\n\nhelp = First line\n    second line\n",
+        )
+        .unwrap();
+        assert_eq!(catalog.get("back"), Some("Back to issues"));
+        assert_eq!(catalog.get("help"), Some("First line\nsecond line"));
+        assert_eq!(catalog.ids(), vec!["back", "help"]);
+    }
+
+    #[test]
+    fn rejects_malformed_and_duplicate_messages() {
+        assert_eq!(
+            Catalog::parse("no equals sign").unwrap_err(),
+            CatalogError::Syntax { line: 1 }
+        );
+        assert_eq!(
+            Catalog::parse("  orphan continuation").unwrap_err(),
+            CatalogError::Syntax { line: 1 }
+        );
+        assert_eq!(
+            Catalog::parse("a = one\na = two").unwrap_err(),
+            CatalogError::Duplicate {
+                line: 2,
+                id: "a".into()
+            }
+        );
+    }
+
+    #[test]
+    fn formats_placeables() {
+        assert_eq!(
+            format(
+                "Copied { $what } ({$how})",
+                &[("what", "URL"), ("how", "pbcopy")]
+            ),
+            "Copied URL (pbcopy)"
+        );
+        assert_eq!(format("Keep { $missing } {", &[]), "Keep { $missing } {");
+    }
+
+    #[test]
+    fn falls_back_to_english_then_the_id() {
+        let en = Catalog::parse("greet = Hello { $name }\nbye = Bye").unwrap();
+        let de = Catalog::parse("greet = Hallo { $name }").unwrap();
+        let localizer = Localizer::new("de", de, en);
+        assert_eq!(localizer.message("greet", &[("name", "Ada")]), "Hallo Ada");
+        assert_eq!(localizer.message("bye", &[]), "Bye");
+        assert_eq!(localizer.message("missing-id", &[]), "missing-id");
+    }
+
+    #[test]
+    fn detects_and_negotiates_locales() {
+        let env = |pairs: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                pairs
+                    .iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+        assert_eq!(
+            locale_from_env(env(&[("LANG", "de_DE.UTF-8")])),
+            Some("de-DE".into())
+        );
+        assert_eq!(
+            locale_from_env(env(&[("LC_ALL", "fr_FR"), ("LANG", "de_DE")])),
+            Some("fr-FR".into())
+        );
+        assert_eq!(locale_from_env(env(&[("LANG", "C.UTF-8")])), None);
+        assert_eq!(negotiate("de-AT", &["en", "de"]), Some("de"));
+        assert_eq!(negotiate("EN", &["en", "de"]), Some("en"));
+        assert_eq!(negotiate("ja", &["en", "de"]), None);
+    }
+}
@@ -0,0 +1,147 @@
+//! Ledger of issues brought in from other trackers, stored per profile, so `linear import`
+//! re-runs update the Linear issue created the first time instead of creating another.
+
+pub mod csv;
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use chrono::{DateTime, Utc};
+use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// The Linear issue created for one source issue.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerEntry {
+    pub issue_id: String,
+    pub identifier: String,
+    /// The source's last-modified stamp as of the last import, to skip unchanged issues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_updated_at: Option<String>,
+    pub imported_at: DateTime<Utc>,
+    /// Source comment ids already copied as Linear comments.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub comments: Vec<String>,
+}
+
+impl LedgerEntry {
+    pub fn new(issue_id: impl Into<String>, identifier: impl Into<String>) -> Self {
+        Self {
+            issue_id: issue_id.into(),
+            identifier: identifier.into(),
+            source_updated_at: None,
+            imported_at: Utc::now(),
+            comments: Vec::new(),
+        }
+    }
+
+    pub fn has_comment(&self, id: &str) -> bool {
+        self.comments.iter().any(|seen| seen == id)
+    }
+}
+
+/// Imported issues keyed by source, e.g. `github:acme/web#42`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ImportLedger {
+    #[serde(default)]
+    pub entries: BTreeMap<String, LedgerEntry>,
+}
+
+impl ImportLedger {
+    pub fn get(&self, source: &str) -> Option<&LedgerEntry> {
+        self.entries.get(source)
+    }
+
+    pub fn get_mut(&mut self, source: &str) -> Option<&mut LedgerEntry> {
+        self.entries.get_mut(source)
+    }
+
+    pub fn insert(&mut self, source: impl Into<String>, entry: LedgerEntry) {
+        self.entries.insert(source.into(), entry);
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ImportLedgerError {
+    #[error("failed to access import ledger: {0}")]
+    Io(#[from] io::Error),
+    #[error("import ledger is corrupt: {0}")]
+    Parse(#[from] serde_json::Error),
+}
+
+/// JSON file holding one profile's [`ImportLedger`].
+#[derive(Debug, Clone)]
+pub struct ImportLedgerStore {
+    path: PathBuf,
+}
+
+impl ImportLedgerStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Store for `profile` under the platform data directory.
+    pub fn for_profile(profile: &str) -> Option<Self> {
+        let dirs = ProjectDirs::from("app", "linear", "linear-rs")?;
+        Some(Self::new(
+            dirs.data_dir()
+                .join("imports")
+                .join(format!("{profile}.json")),
+        ))
+    }
+
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    /// Load the ledger, treating a missing file as empty.
+    pub fn load(&self) -> Result<ImportLedger, ImportLedgerError> {
+        match fs::read(&self.path) {
+            Ok(raw) => Ok(serde_json::from_slice(&raw)?),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(ImportLedger::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Replace the ledger on disk atomically.
+    pub fn save(&self, ledger: &ImportLedger) -> Result<(), ImportLedgerError> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let temp = self.path.with_extension("json.tmp");
+        fs::write(&temp, serde_json::to_vec_pretty(ledger)?)?;
+        fs::rename(temp, &self.path)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn round_trips_entries_through_store() {
+        let temp = TempDir::new().unwrap();
+        let store = ImportLedgerStore::new(temp.path().join("imports").join("default.json"));
+        assert!(store.load().unwrap().entries.is_empty());
+
+        let mut ledger = ImportLedger::default();
+        let mut entry = LedgerEntry::new("issue-1", "ENG-1");
+        entry.source_updated_at = Some("2024-05-01T09:00:00Z".into());
+        entry.comments.push("101".into());
+        ledger.insert("github:acme/web#42", entry);
+        store.save(&ledger).unwrap();
+
+        let loaded = store.load().unwrap();
+        let entry = loaded.get("github:acme/web#42").unwrap();
+        assert_eq!(entry.identifier, "ENG-1");
+        assert!(entry.has_comment("101"));
+        assert!(!entry.has_comment("102"));
+        assert!(loaded.get("github:acme/web#43").is_none());
+    }
+}
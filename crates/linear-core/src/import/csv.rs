@@ -0,0 +1,472 @@
+//! CSV rows mapped onto issue fields for `linear import csv`.
+//!
+//! The first row names the columns; a [`ColumnMap`] says which column feeds which
+//! [`IssueField`], and [`ColumnMap::read`] turns each remaining row into a typed [`CsvIssue`].
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::NaiveDate;
+use serde::Serialize;
+
+use crate::priority::Priority;
+
+/// A parsed CSV document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvTable {
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+/// Parse RFC 4180 CSV: quoted fields may hold commas, newlines, and `""` escapes. A leading
+/// byte-order mark, CRLF line endings, and blank lines are tolerated.
+pub fn parse(text: &str) -> Result<CsvTable, String> {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let mut records: Vec<Vec<String>> = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut chars = text.chars().peekable();
+    let mut line = 1;
+    let mut quoted_from = None;
+    let mut started = false;
+
+    while let Some(c) = chars.next() {
+        if let Some(start) = quoted_from {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => {
+                    quoted_from = None;
+                    match chars.peek() {
+                        None | Some(',' | '\n' | '\r') => {}
+                        Some(_) => {
+                            return Err(format!(
+                                "line {line}: unexpected text after the quoted field started on line {start}"
+                            ))
+                        }
+                    }
+                }
+                '\n' => {
+                    line += 1;
+                    field.push(c);
+                }
+                _ => field.push(c),
+            }
+            continue;
+        }
+        match c {
+            '"' if field.is_empty() => {
+                quoted_from = Some(line);
+                started = true;
+            }
+            ',' => {
+                record.push(std::mem::take(&mut field));
+                started = true;
+            }
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' | '\r' => {
+                if started || !field.is_empty() {
+                    record.push(std::mem::take(&mut field));
+                    records.push(std::mem::take(&mut record));
+                }
+                started = false;
+                line += 1;
+            }
+            _ => {
+                field.push(c);
+                started = true;
+            }
+        }
+    }
+    if let Some(start) = quoted_from {
+        return Err(format!("line {start}: unterminated quoted field"));
+    }
+    if started || !field.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+
+    let mut records = records.into_iter();
+    let headers: Vec<String> = records
+        .next()
+        .ok_or_else(|| "the file is empty".to_owned())?
+        .into_iter()
+        .map(|header| header.trim().to_owned())
+        .collect();
+    Ok(CsvTable {
+        headers,
+        rows: records.collect(),
+    })
+}
+
+/// An issue field a column can feed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IssueField {
+    Title,
+    Description,
+    Priority,
+    Estimate,
+    DueDate,
+    State,
+    Labels,
+    Assignee,
+}
+
+impl IssueField {
+    pub const ALL: [IssueField; 8] = [
+        IssueField::Title,
+        IssueField::Description,
+        IssueField::Priority,
+        IssueField::Estimate,
+        IssueField::DueDate,
+        IssueField::State,
+        IssueField::Labels,
+        IssueField::Assignee,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            IssueField::Title => "title",
+            IssueField::Description => "description",
+            IssueField::Priority => "priority",
+            IssueField::Estimate => "estimate",
+            IssueField::DueDate => "due",
+            IssueField::State => "state",
+            IssueField::Labels => "labels",
+            IssueField::Assignee => "assignee",
+        }
+    }
+
+    /// What the column must hold, for prompts and errors.
+    pub fn expects(self) -> &'static str {
+        match self {
+            IssueField::Title => "text (required)",
+            IssueField::Description => "markdown",
+            IssueField::Priority => "urgent, high, medium, low, none, or 0-4",
+            IssueField::Estimate => "whole number of points",
+            IssueField::DueDate => "date as YYYY-MM-DD",
+            IssueField::State => "workflow state name",
+            IssueField::Labels => "label names separated by , or ;",
+            IssueField::Assignee => "name, display name, or email",
+        }
+    }
+
+    /// Header names, lowercase, that [`ColumnMap::suggest`] maps to this field.
+    fn aliases(self) -> &'static [&'static str] {
+        match self {
+            IssueField::Title => &["title", "summary", "name", "subject"],
+            IssueField::Description => &["description", "details", "body", "notes"],
+            IssueField::Priority => &["priority"],
+            IssueField::Estimate => &["estimate", "points", "story points"],
+            IssueField::DueDate => &["due", "due date", "duedate", "due_date", "deadline"],
+            IssueField::State => &["state", "status"],
+            IssueField::Labels => &["labels", "label", "tags"],
+            IssueField::Assignee => &["assignee", "owner", "assigned to"],
+        }
+    }
+}
+
+impl fmt::Display for IssueField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for IssueField {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let normalized = input.trim().to_ascii_lowercase();
+        match normalized.as_str() {
+            "due-date" | "due_date" | "duedate" => return Ok(IssueField::DueDate),
+            "label" => return Ok(IssueField::Labels),
+            _ => {}
+        }
+        IssueField::ALL
+            .into_iter()
+            .find(|field| field.as_str() == normalized)
+            .ok_or_else(|| {
+                let names: Vec<&str> = IssueField::ALL.iter().map(|field| field.as_str()).collect();
+                format!(
+                    "unknown field '{}'; expected one of: {}",
+                    input,
+                    names.join(", ")
+                )
+            })
+    }
+}
+
+/// Which column, by index, feeds each mapped field.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ColumnMap {
+    columns: Vec<(IssueField, usize)>,
+}
+
+impl ColumnMap {
+    /// Map from `field=column` pairs; columns are matched against `headers` case-insensitively.
+    pub fn from_pairs(pairs: &[(IssueField, String)], headers: &[String]) -> Result<Self, String> {
+        let mut map = ColumnMap::default();
+        for (field, column) in pairs {
+            let index = headers
+                .iter()
+                .position(|header| header.eq_ignore_ascii_case(column.trim()))
+                .ok_or_else(|| {
+                    format!(
+                        "no column named '{}' for {}; the columns are: {}",
+                        column,
+                        field,
+                        headers.join(", ")
+                    )
+                })?;
+            map.set(*field, Some(index));
+        }
+        if map.column(IssueField::Title).is_none() {
+            return Err("map a column to title, e.g. --map title=Summary".into());
+        }
+        Ok(map)
+    }
+
+    /// Map each field to the first column whose header is one of its usual names.
+    pub fn suggest(headers: &[String]) -> Self {
+        let mut map = ColumnMap::default();
+        for field in IssueField::ALL {
+            let index = headers.iter().position(|header| {
+                field
+                    .aliases()
+                    .contains(&header.trim().to_ascii_lowercase().as_str())
+            });
+            map.set(field, index);
+        }
+        map
+    }
+
+    pub fn column(&self, field: IssueField) -> Option<usize> {
+        self.columns
+            .iter()
+            .find(|(mapped, _)| *mapped == field)
+            .map(|(_, index)| *index)
+    }
+
+    pub fn set(&mut self, field: IssueField, column: Option<usize>) {
+        self.columns.retain(|(mapped, _)| *mapped != field);
+        if let Some(index) = column {
+            self.columns.push((field, index));
+        }
+    }
+
+    /// Mapped fields in [`IssueField::ALL`] order.
+    pub fn fields(&self) -> Vec<IssueField> {
+        IssueField::ALL
+            .into_iter()
+            .filter(|field| self.column(*field).is_some())
+            .collect()
+    }
+
+    /// Typed values of one row, or a message per cell that does not fit its field.
+    pub fn read(&self, row: &[String]) -> Result<CsvIssue, Vec<String>> {
+        let cell = |field| {
+            self.column(field)
+                .and_then(|index| row.get(index))
+                .map(|value| value.trim())
+                .filter(|value| !value.is_empty())
+        };
+        let mut errors = Vec::new();
+
+        let title = cell(IssueField::Title).map(str::to_owned);
+        if title.is_none() {
+            errors.push(format!("{}: is empty", IssueField::Title));
+        }
+        let priority = checked(
+            &mut errors,
+            IssueField::Priority,
+            cell(IssueField::Priority)
+                .map(Priority::from_str)
+                .transpose(),
+        );
+        let estimate = checked(
+            &mut errors,
+            IssueField::Estimate,
+            cell(IssueField::Estimate)
+                .map(|raw| {
+                    raw.parse::<i32>()
+                        .ok()
+                        .filter(|points| *points >= 0)
+                        .ok_or_else(|| format!("'{raw}' is not a whole number"))
+                })
+                .transpose(),
+        );
+        let due_date = checked(
+            &mut errors,
+            IssueField::DueDate,
+            cell(IssueField::DueDate).map(parse_date).transpose(),
+        );
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        Ok(CsvIssue {
+            title: title.unwrap_or_default(),
+            description: cell(IssueField::Description).map(str::to_owned),
+            priority,
+            estimate,
+            due_date,
+            state: cell(IssueField::State).map(str::to_owned),
+            labels: cell(IssueField::Labels)
+                .map(|raw| {
+                    raw.split([',', ';'])
+                        .map(str::trim)
+                        .filter(|label| !label.is_empty())
+                        .map(str::to_owned)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            assignee: cell(IssueField::Assignee).map(str::to_owned),
+        })
+    }
+}
+
+/// The parsed value, or `None` after recording why the cell did not parse.
+fn checked<T>(
+    errors: &mut Vec<String>,
+    field: IssueField,
+    result: Result<Option<T>, String>,
+) -> Option<T> {
+    result.unwrap_or_else(|err| {
+        errors.push(format!("{field}: {err}"));
+        None
+    })
+}
+
+/// `YYYY-MM-DD`, or the date part of an RFC 3339 timestamp.
+fn parse_date(raw: &str) -> Result<NaiveDate, String> {
+    let date = raw.split_once('T').map_or(raw, |(date, _)| date);
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| format!("'{raw}' is not YYYY-MM-DD"))
+}
+
+/// One row's values, checked against their fields' types. Names (state, labels, assignee)
+/// are resolved against the team by the caller.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CsvIssue {
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<Priority>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimate: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<NaiveDate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| (*value).to_owned()).collect()
+    }
+
+    #[test]
+    fn parses_quoted_fields_and_line_endings() {
+        let table = parse(
+            "\u{feff}Summary,Details,Tags\r\n\"Fix, login\",\"Line one\nsaid \"\"hi\"\"\",bug\r\n\r\nPlain,,\n",
+        )
+        .unwrap();
+        assert_eq!(table.headers, strings(&["Summary", "Details", "Tags"]));
+        assert_eq!(
+            table.rows,
+            vec![
+                strings(&["Fix, login", "Line one\nsaid \"hi\"", "bug"]),
+                strings(&["Plain", "", ""]),
+            ]
+        );
+
+        let err = parse("Title\n\"open\nstill open").unwrap_err();
+        assert_eq!(err, "line 2: unterminated quoted field");
+        assert!(parse("Title\n\"a\"b\n").unwrap_err().starts_with("line 2:"));
+        assert!(parse("").is_err());
+    }
+
+    #[test]
+    fn maps_columns_by_flag_or_header_name() {
+        let headers = strings(&["Summary", "Details", "Points", "Due Date", "Status"]);
+        let suggested = ColumnMap::suggest(&headers);
+        assert_eq!(suggested.column(IssueField::Title), Some(0));
+        assert_eq!(suggested.column(IssueField::Estimate), Some(2));
+        assert_eq!(suggested.column(IssueField::DueDate), Some(3));
+        assert_eq!(suggested.column(IssueField::Priority), None);
+        assert_eq!(
+            suggested.fields(),
+            vec![
+                IssueField::Title,
+                IssueField::Description,
+                IssueField::Estimate,
+                IssueField::DueDate,
+                IssueField::State
+            ]
+        );
+
+        let map = ColumnMap::from_pairs(
+            &[
+                (IssueField::Title, "details".into()),
+                ("due-date".parse().unwrap(), "Due Date".into()),
+            ],
+            &headers,
+        )
+        .unwrap();
+        assert_eq!(map.column(IssueField::Title), Some(1));
+        assert_eq!(map.column(IssueField::DueDate), Some(3));
+
+        let err = ColumnMap::from_pairs(&[(IssueField::Title, "Name".into())], &headers);
+        assert!(err.unwrap_err().contains("no column named 'Name'"));
+        let err = ColumnMap::from_pairs(&[(IssueField::State, "Status".into())], &headers);
+        assert!(err.unwrap_err().contains("title"));
+        assert!("colour".parse::<IssueField>().is_err());
+    }
+
+    #[test]
+    fn validates_cells_by_field_type() {
+        let headers = strings(&["Title", "Priority", "Estimate", "Due", "Labels"]);
+        let map = ColumnMap::suggest(&headers);
+
+        let issue = map
+            .read(&strings(&[
+                " Fix login ",
+                "High",
+                "3",
+                "2024-06-01",
+                "bug; ui,",
+            ]))
+            .unwrap();
+        assert_eq!(issue.title, "Fix login");
+        assert_eq!(issue.priority, Some(Priority::High));
+        assert_eq!(issue.estimate, Some(3));
+        assert_eq!(issue.due_date, NaiveDate::from_ymd_opt(2024, 6, 1));
+        assert_eq!(issue.labels, strings(&["bug", "ui"]));
+
+        let issue = map
+            .read(&strings(&["Short row", "", "", "2024-06-01T09:00:00Z"]))
+            .unwrap();
+        assert_eq!(issue.priority, None);
+        assert_eq!(issue.due_date, NaiveDate::from_ymd_opt(2024, 6, 1));
+        assert!(issue.labels.is_empty());
+
+        let errors = map
+            .read(&strings(&["", "asap", "2.5", "06/01/2024", ""]))
+            .unwrap_err();
+        assert_eq!(errors.len(), 4);
+        assert_eq!(errors[0], "title: is empty");
+        assert!(errors[1].starts_with("priority: unknown priority 'asap'"));
+        assert_eq!(errors[2], "estimate: '2.5' is not a whole number");
+        assert_eq!(errors[3], "due: '06/01/2024' is not YYYY-MM-DD");
+    }
+}
@@ -0,0 +1,477 @@
+//! Failing test suites read from a JUnit XML report for `linear issue create --from-junit`.
+//!
+//! Each failing suite becomes one issue. The description ends with the suite's
+//! [`fingerprint`](TestSuite::fingerprint), which later runs search for to update that issue
+//! instead of filing another.
+
+use std::fmt::Write;
+
+use sha2::{Digest, Sha256};
+
+/// Failure output longer than this many characters is cut, keeping the start.
+const MAX_OUTPUT: usize = 4000;
+
+/// A `<testsuite>` with at least one failing `<testcase>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestSuite {
+    pub name: String,
+    /// Test cases in the suite, passing ones included.
+    pub tests: usize,
+    pub failures: Vec<TestFailure>,
+}
+
+/// A `<failure>` or `<error>` in a test case.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestFailure {
+    pub name: String,
+    pub classname: Option<String>,
+    /// `true` for `<error>` (the test could not run) rather than `<failure>`.
+    pub error: bool,
+    pub message: Option<String>,
+    /// The element's text, usually the assertion and stack trace.
+    pub output: String,
+}
+
+impl TestSuite {
+    /// Stable marker for the suite, searched for in issue descriptions, e.g. `junit:3f2a…`.
+    pub fn fingerprint(&self) -> String {
+        let digest = Sha256::digest(self.name.as_bytes());
+        let hex: String = digest[..8].iter().map(|b| format!("{b:02x}")).collect();
+        format!("junit:{hex}")
+    }
+
+    pub fn title(&self) -> String {
+        match self.failures.len() {
+            1 => format!("{}: 1 failing test", self.name),
+            count => format!("{}: {count} failing tests", self.name),
+        }
+    }
+
+    /// Markdown listing each failure with its output, ending with the fingerprint.
+    pub fn description(&self) -> String {
+        let mut text = format!(
+            "{} of {} tests in `{}` failed.\n",
+            self.failures.len(),
+            self.tests,
+            self.name
+        );
+        for failure in &self.failures {
+            let name = match &failure.classname {
+                Some(class) if class != &self.name => format!("{class}.{}", failure.name),
+                _ => failure.name.clone(),
+            };
+            let _ = write!(text, "\n### {name}\n");
+            if failure.error {
+                text.push_str("\nErrored before finishing.\n");
+            }
+            if let Some(message) = failure.message.as_deref().map(str::trim) {
+                if !message.is_empty() {
+                    let _ = write!(text, "\n> {}\n", message.replace('\n', "\n> "));
+                }
+            }
+            let output = failure.output.trim();
+            if !output.is_empty() {
+                let _ = write!(text, "\n{}\n", code_block(output));
+            }
+        }
+        let _ = write!(text, "\n---\nFingerprint: `{}`\n", self.fingerprint());
+        text
+    }
+}
+
+/// `output` in a fence longer than any backtick run inside it, cut to [`MAX_OUTPUT`].
+fn code_block(output: &str) -> String {
+    let output = match output.char_indices().nth(MAX_OUTPUT) {
+        Some((end, _)) => format!("{}\n… (truncated)", &output[..end]),
+        None => output.to_owned(),
+    };
+    let longest = output
+        .split(|c| c != '`')
+        .map(str::len)
+        .max()
+        .unwrap_or_default();
+    let fence = "`".repeat((longest + 1).max(3));
+    format!("{fence}text\n{output}\n{fence}")
+}
+
+/// The failing suites in a JUnit report, in document order. Suites may be nested in
+/// `<testsuites>` or each other; test cases count toward their nearest suite.
+pub fn parse(text: &str) -> Result<Vec<TestSuite>, String> {
+    let root = xml::parse(text)?;
+    if root.name != "testsuites" && root.name != "testsuite" {
+        return Err(format!(
+            "expected <testsuites> or <testsuite>, found <{}>",
+            root.name
+        ));
+    }
+    let mut suites = Vec::new();
+    collect(&root, &mut suites);
+    Ok(suites
+        .into_iter()
+        .filter(|suite| !suite.failures.is_empty())
+        .collect())
+}
+
+fn collect(element: &xml::Element, suites: &mut Vec<TestSuite>) {
+    if element.name != "testsuite" {
+        for child in element.elements() {
+            collect(child, suites);
+        }
+        return;
+    }
+    let mut suite = TestSuite {
+        name: element.attr("name").unwrap_or("unnamed suite").to_owned(),
+        tests: 0,
+        failures: Vec::new(),
+    };
+    // Hold the suite's place so it comes before the suites nested in it.
+    let index = suites.len();
+    suites.push(suite.clone());
+    for child in element.elements() {
+        match child.name.as_str() {
+            "testsuite" => collect(child, suites),
+            "testcase" => {
+                suite.tests += 1;
+                suite.failures.extend(failures(child));
+            }
+            _ => {}
+        }
+    }
+    suites[index] = suite;
+}
+
+fn failures(case: &xml::Element) -> impl Iterator<Item = TestFailure> + '_ {
+    case.elements()
+        .filter(|child| child.name == "failure" || child.name == "error")
+        .map(|child| TestFailure {
+            name: case.attr("name").unwrap_or("unnamed test").to_owned(),
+            classname: case.attr("classname").map(str::to_owned),
+            error: child.name == "error",
+            message: child.attr("message").map(str::to_owned),
+            output: child.text(),
+        })
+}
+
+/// Just enough XML for test reports: elements, attributes, text, CDATA, and the predefined
+/// and numeric entities. Declarations, comments, and doctypes are skipped.
+mod xml {
+    #[derive(Debug)]
+    pub(super) enum Node {
+        Element(Element),
+        Text(String),
+    }
+
+    #[derive(Debug)]
+    pub(super) struct Element {
+        pub name: String,
+        attrs: Vec<(String, String)>,
+        children: Vec<Node>,
+    }
+
+    impl Element {
+        fn new(name: String, attrs: Vec<(String, String)>) -> Self {
+            Self {
+                name,
+                attrs,
+                children: Vec::new(),
+            }
+        }
+
+        pub fn attr(&self, name: &str) -> Option<&str> {
+            self.attrs
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.as_str())
+        }
+
+        pub fn elements(&self) -> impl Iterator<Item = &Element> {
+            self.children.iter().filter_map(|node| match node {
+                Node::Element(element) => Some(element),
+                Node::Text(_) => None,
+            })
+        }
+
+        pub fn text(&self) -> String {
+            self.children
+                .iter()
+                .filter_map(|node| match node {
+                    Node::Text(text) => Some(text.as_str()),
+                    Node::Element(_) => None,
+                })
+                .collect()
+        }
+    }
+
+    /// The document's root element.
+    pub(super) fn parse(text: &str) -> Result<Element, String> {
+        let mut parser = Parser {
+            text: text.strip_prefix('\u{feff}').unwrap_or(text),
+            pos: 0,
+        };
+        let mut stack: Vec<Element> = Vec::new();
+        let mut root = None;
+        loop {
+            let rest = parser.rest();
+            if rest.is_empty() {
+                break;
+            }
+            if rest.starts_with("<?") {
+                parser.skip_past("?>")?;
+            } else if rest.starts_with("<!--") {
+                parser.skip_past("-->")?;
+            } else if rest.starts_with("<![CDATA[") {
+                parser.pos += "<![CDATA[".len();
+                let start = parser.pos;
+                parser.skip_past("]]>")?;
+                let data = parser.text[start..parser.pos - "]]>".len()].to_owned();
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push(Node::Text(data)),
+                    None => return Err(parser.error("text outside the root element")),
+                }
+            } else if rest.starts_with("<!") {
+                parser.skip_past(">")?;
+            } else if rest.starts_with("</") {
+                parser.pos += 2;
+                let name = parser.name()?;
+                parser.skip_whitespace();
+                parser.expect(">")?;
+                let element = match stack.pop() {
+                    Some(element) if element.name == name => element,
+                    Some(element) => {
+                        return Err(parser.error(&format!("</{name}> closes <{}>", element.name)))
+                    }
+                    None => return Err(parser.error(&format!("unexpected </{name}>"))),
+                };
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push(Node::Element(element)),
+                    None => root = Some(element),
+                }
+            } else if rest.starts_with('<') {
+                parser.pos += 1;
+                let (element, closed) = parser.start_tag()?;
+                if root.is_some() && stack.is_empty() {
+                    return Err(parser.error("more than one root element"));
+                }
+                match (closed, stack.last_mut()) {
+                    (false, _) => stack.push(element),
+                    (true, Some(parent)) => parent.children.push(Node::Element(element)),
+                    (true, None) => root = Some(element),
+                }
+            } else {
+                let end = rest.find('<').unwrap_or(rest.len());
+                let raw = &rest[..end];
+                match stack.last_mut() {
+                    Some(parent) => {
+                        let text = parser.unescape(raw)?;
+                        parent.children.push(Node::Text(text));
+                    }
+                    None if raw.trim().is_empty() => {}
+                    None => return Err(parser.error("text outside the root element")),
+                }
+                parser.pos += end;
+            }
+        }
+        if let Some(open) = stack.last() {
+            return Err(format!("<{}> is never closed", open.name));
+        }
+        root.ok_or_else(|| "no root element".to_owned())
+    }
+
+    struct Parser<'a> {
+        text: &'a str,
+        pos: usize,
+    }
+
+    impl<'a> Parser<'a> {
+        fn rest(&self) -> &'a str {
+            &self.text[self.pos..]
+        }
+
+        fn error(&self, message: &str) -> String {
+            let line = self.text[..self.pos].matches('\n').count() + 1;
+            format!("line {line}: {message}")
+        }
+
+        fn skip_past(&mut self, end: &str) -> Result<(), String> {
+            match self.rest().find(end) {
+                Some(at) => {
+                    self.pos += at + end.len();
+                    Ok(())
+                }
+                None => Err(self.error(&format!("missing '{end}'"))),
+            }
+        }
+
+        fn skip_whitespace(&mut self) {
+            let rest = self.rest();
+            self.pos += rest.len() - rest.trim_start().len();
+        }
+
+        fn expect(&mut self, token: &str) -> Result<(), String> {
+            if !self.rest().starts_with(token) {
+                return Err(self.error(&format!("expected '{token}'")));
+            }
+            self.pos += token.len();
+            Ok(())
+        }
+
+        fn name(&mut self) -> Result<String, String> {
+            let rest = self.rest();
+            let end = rest
+                .find(|c: char| c.is_whitespace() || matches!(c, '>' | '/' | '='))
+                .unwrap_or(rest.len());
+            if end == 0 {
+                return Err(self.error("expected a name"));
+            }
+            self.pos += end;
+            Ok(rest[..end].to_owned())
+        }
+
+        /// Everything after `<` up to and including `>`; `true` when it ended with `/>`.
+        fn start_tag(&mut self) -> Result<(Element, bool), String> {
+            let name = self.name()?;
+            let mut attrs = Vec::new();
+            loop {
+                self.skip_whitespace();
+                if self.rest().starts_with("/>") {
+                    self.pos += 2;
+                    return Ok((Element::new(name, attrs), true));
+                }
+                if self.rest().starts_with('>') {
+                    self.pos += 1;
+                    return Ok((Element::new(name, attrs), false));
+                }
+                let key = self.name()?;
+                self.skip_whitespace();
+                self.expect("=")?;
+                self.skip_whitespace();
+                let quote = match self.rest().chars().next() {
+                    Some(quote @ ('"' | '\'')) => quote,
+                    _ => return Err(self.error(&format!("unquoted value for '{key}'"))),
+                };
+                self.pos += 1;
+                let Some(end) = self.rest().find(quote) else {
+                    return Err(self.error(&format!("unterminated value for '{key}'")));
+                };
+                let value = self.unescape(&self.rest()[..end])?;
+                self.pos += end + 1;
+                attrs.push((key, value));
+            }
+        }
+
+        fn unescape(&self, raw: &str) -> Result<String, String> {
+            let mut out = String::with_capacity(raw.len());
+            let mut rest = raw;
+            while let Some(amp) = rest.find('&') {
+                out.push_str(&rest[..amp]);
+                let Some(semi) = rest[amp..].find(';') else {
+                    return Err(self.error("'&' without a closing ';'"));
+                };
+                let entity = &rest[amp + 1..amp + semi];
+                let decoded = match entity {
+                    "lt" => Some('<'),
+                    "gt" => Some('>'),
+                    "amp" => Some('&'),
+                    "quot" => Some('"'),
+                    "apos" => Some('\''),
+                    _ => entity
+                        .strip_prefix("#x")
+                        .map(|hex| u32::from_str_radix(hex, 16))
+                        .or_else(|| entity.strip_prefix('#').map(str::parse))
+                        .and_then(Result::ok)
+                        .and_then(char::from_u32),
+                };
+                match decoded {
+                    Some(c) => out.push(c),
+                    None => return Err(self.error(&format!("unknown entity '&{entity};'"))),
+                }
+                rest = &rest[amp + semi + 1..];
+            }
+            out.push_str(rest);
+            Ok(out)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REPORT: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!-- generated by the test runner -->
+<testsuites>
+  <testsuite name="auth" tests="3">
+    <testcase classname="auth" name="logs_in"/>
+    <testcase classname="auth.session" name="refreshes">
+      <failure message="expected 200, got 401" type="AssertionError"><![CDATA[at session.rs:42
+  left: 401 & "body"]]></failure>
+    </testcase>
+    <testcase classname="auth" name="logs_out">
+      <error message="panicked">thread &apos;main&apos; panicked &#x2014; see log</error>
+    </testcase>
+  </testsuite>
+  <testsuite name="billing">
+    <testcase name="charges"/>
+  </testsuite>
+</testsuites>
+"#;
+
+    #[test]
+    fn parses_failing_suites_only() {
+        let suites = parse(REPORT).unwrap();
+        assert_eq!(suites.len(), 1);
+        let suite = &suites[0];
+        assert_eq!(suite.name, "auth");
+        assert_eq!(suite.tests, 3);
+        assert_eq!(
+            suite.failures,
+            [
+                TestFailure {
+                    name: "refreshes".into(),
+                    classname: Some("auth.session".into()),
+                    error: false,
+                    message: Some("expected 200, got 401".into()),
+                    output: "at session.rs:42\n  left: 401 & \"body\"".into(),
+                },
+                TestFailure {
+                    name: "logs_out".into(),
+                    classname: Some("auth".into()),
+                    error: true,
+                    message: Some("panicked".into()),
+                    output: "thread 'main' panicked \u{2014} see log".into(),
+                },
+            ]
+        );
+        assert_eq!(suite.title(), "auth: 2 failing tests");
+        assert!(suite.fingerprint().starts_with("junit:"));
+        assert_eq!(suite.fingerprint().len(), "junit:".len() + 16);
+    }
+
+    #[test]
+    fn description_ends_with_the_fingerprint() {
+        let suite = &parse(REPORT).unwrap()[0];
+        let description = suite.description();
+        assert!(description.starts_with("2 of 3 tests in `auth` failed.\n"));
+        assert!(description.contains(
+            "\n### auth.session.refreshes\n\n> expected 200, got 401\n\n```text\nat session.rs:42"
+        ));
+        assert!(description.contains("\n### logs_out\n\nErrored before finishing.\n"));
+        assert!(description.ends_with(&format!("Fingerprint: `{}`\n", suite.fingerprint())));
+        assert_eq!(code_block("a ``` b"), "````text\na ``` b\n````");
+    }
+
+    #[test]
+    fn rejects_malformed_reports() {
+        assert!(parse("<testsuite><testcase></testsuite>")
+            .unwrap_err()
+            .contains("</testsuite> closes <testcase>"));
+        assert!(parse("<testsuite>").unwrap_err().contains("never closed"));
+        assert!(parse("<coverage/>")
+            .unwrap_err()
+            .contains("expected <testsuites>"));
+        let single =
+            parse(r#"<testsuite name="s"><testcase name="t"><failure/></testcase></testsuite>"#)
+                .unwrap();
+        assert_eq!(single[0].failures[0].output, "");
+    }
+}
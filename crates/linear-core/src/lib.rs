@@ -1,9 +1,43 @@
 //! Core library for shared Linear integrations used by both CLI and TUI front-ends.
 
 pub mod auth;
+pub mod backup;
+pub mod board;
+pub mod budget;
+pub mod calendar;
+pub mod color;
 pub mod config;
+pub mod daemon;
+pub mod diff;
+pub mod digest;
+pub mod error;
+pub mod extract;
+pub mod fuzzy;
 pub mod graphql;
+pub mod group;
+pub mod http;
+pub mod i18n;
+pub mod import;
+pub mod junit;
+pub mod mention;
+pub mod notify;
+pub mod permissions;
+pub mod phrase;
+pub mod priority;
+pub mod query;
+pub mod remind;
 pub mod services;
+pub mod share;
+pub mod shell;
+pub mod snapshot;
+pub mod status;
+pub mod summary;
+pub mod tasks;
+pub mod team_settings;
+pub mod template;
+pub mod timelog;
+pub mod unfurl;
+pub mod web;
 
 /// Entry point used by early scaffolding binaries until real initialization exists.
 pub fn init() -> anyhow::Result<()> {
@@ -0,0 +1,138 @@
+//! `@handle` mentions in comment and description bodies.
+//!
+//! Editors let users type `@display-name`; before sending, [`expand`] swaps each known handle for
+//! the member's profile URL, which Linear renders as a mention and uses to notify the user.
+
+use crate::fuzzy;
+use crate::graphql::TeamMember;
+
+/// The `@partial` handle being typed at the end of a buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MentionQuery<'a> {
+    /// Byte offset of the `@`.
+    pub start: usize,
+    /// Text typed after the `@`, possibly empty.
+    pub partial: &'a str,
+}
+
+/// Mention being typed at the end of `text`, if the cursor is inside one.
+pub fn active_query(text: &str) -> Option<MentionQuery<'_>> {
+    let start = text.rfind('@')?;
+    let partial = &text[start + 1..];
+    if !partial.chars().all(is_handle_char) || !starts_mention(text, start) {
+        return None;
+    }
+    Some(MentionQuery { start, partial })
+}
+
+/// Best matches for `partial` among `members`, active members first.
+pub fn suggest<'a>(partial: &str, members: &'a [TeamMember], limit: usize) -> Vec<&'a TeamMember> {
+    let mut ranked = fuzzy::rank(partial, members, |member| {
+        format!("{} {}", member.display_name, member.name)
+    });
+    ranked.sort_by_key(|(score, member)| (!member.active, std::cmp::Reverse(*score)));
+    ranked
+        .into_iter()
+        .take(limit)
+        .map(|(_, member)| member)
+        .collect()
+}
+
+/// Replace `@handle` for every member whose display name matches with their profile URL.
+///
+/// Unknown handles and `@` inside words (such as email addresses) are left untouched.
+pub fn expand(text: &str, members: &[TeamMember]) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = 0;
+    let mut search = 0;
+    while let Some(offset) = text[search..].find('@') {
+        let at = search + offset;
+        search = at + 1;
+        if !starts_mention(text, at) {
+            continue;
+        }
+        let handle_len = text[at + 1..]
+            .find(|c: char| !is_handle_char(c))
+            .unwrap_or(text.len() - at - 1);
+        // Sentence punctuation directly after a handle is not part of it.
+        let handle = text[at + 1..at + 1 + handle_len].trim_end_matches(['.', '-']);
+        if handle.is_empty() {
+            continue;
+        }
+        if let Some(member) = members
+            .iter()
+            .find(|member| member.display_name.eq_ignore_ascii_case(handle))
+        {
+            out.push_str(&text[rest..at]);
+            out.push_str(&member.url);
+            rest = at + 1 + handle.len();
+            search = rest;
+        }
+    }
+    out.push_str(&text[rest..]);
+    out
+}
+
+fn is_handle_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '.' | '_' | '-')
+}
+
+fn starts_mention(text: &str, at: usize) -> bool {
+    text[..at]
+        .chars()
+        .next_back()
+        .map_or(true, |prev| !is_handle_char(prev))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(display_name: &str, name: &str, active: bool) -> TeamMember {
+        TeamMember {
+            id: format!("user-{display_name}"),
+            name: name.into(),
+            display_name: display_name.into(),
+            email: None,
+            url: format!("https://linear.app/acme/profiles/{display_name}"),
+            active,
+        }
+    }
+
+    #[test]
+    fn finds_mention_under_cursor() {
+        assert_eq!(
+            active_query("ping @ad"),
+            Some(MentionQuery {
+                start: 5,
+                partial: "ad"
+            })
+        );
+        assert_eq!(active_query("@").map(|q| q.partial), Some(""));
+        assert_eq!(active_query("mail ada@example"), None);
+        assert_eq!(active_query("@ada done"), None);
+    }
+
+    #[test]
+    fn suggests_active_members_first() {
+        let members = vec![
+            member("adam", "Adam Old", false),
+            member("ada", "Ada Lovelace", true),
+            member("grace", "Grace Hopper", true),
+        ];
+        let names: Vec<_> = suggest("ad", &members, 5)
+            .into_iter()
+            .map(|m| m.display_name.as_str())
+            .collect();
+        assert_eq!(names, ["ada", "adam"]);
+    }
+
+    #[test]
+    fn expands_known_handles_only() {
+        let members = vec![member("ada", "Ada Lovelace", true)];
+        assert_eq!(
+            expand("Thanks @Ada. cc @nobody, ada@example.com", &members),
+            "Thanks https://linear.app/acme/profiles/ada. cc @nobody, ada@example.com"
+        );
+    }
+}
@@ -0,0 +1,498 @@
+//! Forward activity events to chat and webhook endpoints.
+//!
+//! `linear notify forward` polls [`ActivityService`](crate::services::activity::ActivityService),
+//! keeps events matching an [`EventFilter`], renders them with a message template, and posts
+//! them to each [`Destination`] through a [`Notifier`]. [`rules`] holds the local rules behind
+//! `linear notify watch`.
+
+pub mod rules;
+
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+use thiserror::Error;
+use url::Url;
+
+use crate::priority::{self, Priority};
+use crate::services::activity::{ActivityEvent, ActivityKind};
+
+/// Message used when no `--template` is given.
+pub const DEFAULT_TEMPLATE: &str = "{issue} {summary}: {title} {url}";
+
+/// Errors raised while delivering a notification.
+#[derive(Debug, Error)]
+pub enum NotifyError {
+    #[error("webhook request failed: {0}")]
+    Http(#[from] reqwest::Error),
+    #[error("webhook returned {status}: {body}")]
+    Status { status: StatusCode, body: String },
+}
+
+/// Where notifications are posted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Destination {
+    /// Slack incoming webhook, written `slack://hooks.slack.com/services/…`.
+    Slack(Url),
+    /// Discord webhook, written `discord://discord.com/api/webhooks/…`.
+    Discord(Url),
+    /// Any `http(s)://` endpoint; receives the event JSON plus the rendered `text`.
+    Webhook(Url),
+}
+
+impl Destination {
+    pub fn url(&self) -> &Url {
+        match self {
+            Destination::Slack(url) | Destination::Discord(url) | Destination::Webhook(url) => url,
+        }
+    }
+
+    /// Request body for `text` describing `event`, in the shape the endpoint expects.
+    pub fn payload(&self, text: &str, event: &ActivityEvent) -> Value {
+        match self {
+            Destination::Slack(_) => json!({ "text": text }),
+            Destination::Discord(_) => json!({ "content": text }),
+            Destination::Webhook(_) => {
+                let mut body = serde_json::to_value(event).unwrap_or_else(|_| json!({}));
+                body["text"] = json!(text);
+                body
+            }
+        }
+    }
+}
+
+impl FromStr for Destination {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let input = input.trim();
+        let (scheme, rest) = input.split_once("://").ok_or_else(|| {
+            format!("invalid destination '{input}' (expected slack://, discord://, or https://)")
+        })?;
+        let https = |rest: &str| {
+            Url::parse(&format!("https://{rest}"))
+                .map_err(|err| format!("invalid destination '{input}': {err}"))
+        };
+        match scheme.to_ascii_lowercase().as_str() {
+            "slack" => https(rest).map(Destination::Slack),
+            "discord" => https(rest).map(Destination::Discord),
+            "http" | "https" => Url::parse(input)
+                .map(Destination::Webhook)
+                .map_err(|err| format!("invalid destination '{input}': {err}")),
+            other => Err(format!(
+                "unknown destination scheme '{other}'; expected slack, discord, http, or https"
+            )),
+        }
+    }
+}
+
+/// Shows the scheme and host only; webhook paths carry secrets.
+impl fmt::Display for Destination {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let scheme = match self {
+            Destination::Slack(_) => "slack",
+            Destination::Discord(_) => "discord",
+            Destination::Webhook(url) => url.scheme(),
+        };
+        write!(f, "{}://{}", scheme, self.url().host_str().unwrap_or("?"))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Team,
+    Priority,
+    Kind,
+    Actor,
+    State,
+    Label,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Eq,
+    Ne,
+    Ge,
+    Gt,
+    Le,
+    Lt,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Condition {
+    field: Field,
+    op: Op,
+    /// Alternatives from a comma-separated value; any may match.
+    values: Vec<String>,
+}
+
+/// Space-separated `field<op>value` conditions, all of which must hold.
+///
+/// Fields are `team`, `priority`, `kind` (see [`ActivityKind::NAMES`]), `actor`, `state` (the
+/// target state of a change), and `label` (an added label). `=` and `!=` work everywhere and accept comma-separated
+/// alternatives; `priority` also supports `>=`, `>`, `<=`, and `<` by importance, so
+/// `priority>=high` matches high and urgent.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EventFilter {
+    conditions: Vec<Condition>,
+}
+
+impl EventFilter {
+    pub fn matches(&self, event: &ActivityEvent) -> bool {
+        self.conditions
+            .iter()
+            .all(|condition| condition.matches(event))
+    }
+
+    /// The team key when the filter pins exactly one, so callers can narrow the query.
+    pub fn team(&self) -> Option<&str> {
+        let mut teams = self
+            .conditions
+            .iter()
+            .filter(|condition| condition.field == Field::Team && condition.op == Op::Eq);
+        match (teams.next(), teams.next()) {
+            (Some(condition), None) if condition.values.len() == 1 => {
+                Some(condition.values[0].as_str())
+            }
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for EventFilter {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let conditions = input
+            .split_whitespace()
+            .map(Condition::parse)
+            .collect::<Result<_, _>>()?;
+        Ok(Self { conditions })
+    }
+}
+
+impl Condition {
+    fn parse(term: &str) -> Result<Self, String> {
+        const OPS: [(&str, Op); 6] = [
+            (">=", Op::Ge),
+            ("<=", Op::Le),
+            ("!=", Op::Ne),
+            (">", Op::Gt),
+            ("<", Op::Lt),
+            ("=", Op::Eq),
+        ];
+        let (at, symbol, op) = OPS
+            .iter()
+            .filter_map(|(symbol, op)| term.find(symbol).map(|at| (at, *symbol, *op)))
+            .min_by_key(|(at, symbol, _)| (*at, std::cmp::Reverse(symbol.len())))
+            .ok_or_else(|| format!("invalid filter '{term}' (expected field=value)"))?;
+        let name = &term[..at];
+        let value = &term[at + symbol.len()..];
+        let field = match name.to_ascii_lowercase().as_str() {
+            "team" => Field::Team,
+            "priority" => Field::Priority,
+            "kind" => Field::Kind,
+            "actor" => Field::Actor,
+            "state" => Field::State,
+            "label" => Field::Label,
+            other => {
+                return Err(format!(
+                    "unknown filter field '{other}'; expected team, priority, kind, actor, state, \
+                     or label"
+                ))
+            }
+        };
+        if field != Field::Priority && !matches!(op, Op::Eq | Op::Ne) {
+            return Err(format!("'{symbol}' only applies to priority (in '{term}')"));
+        }
+        let values: Vec<String> = value
+            .split(',')
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_owned)
+            .collect();
+        if values.is_empty() {
+            return Err(format!("filter '{term}' has no value"));
+        }
+        if field == Field::Priority {
+            for value in &values {
+                value.parse::<Priority>()?;
+            }
+        }
+        if field == Field::Kind {
+            for value in &values {
+                if !ActivityKind::NAMES.contains(&value.as_str()) {
+                    return Err(format!(
+                        "unknown event kind '{value}'; expected {}",
+                        ActivityKind::NAMES.join(", ")
+                    ));
+                }
+            }
+        }
+        Ok(Self { field, op, values })
+    }
+
+    fn matches(&self, event: &ActivityEvent) -> bool {
+        if self.field == Field::Priority && !matches!(self.op, Op::Eq | Op::Ne) {
+            let Some(actual) = event.priority.and_then(Priority::from_value) else {
+                return false;
+            };
+            return self.values.iter().any(|value| {
+                let bound: Priority = value.parse().unwrap_or(Priority::None);
+                match self.op {
+                    Op::Ge => actual >= bound,
+                    Op::Gt => actual > bound,
+                    Op::Le => actual <= bound,
+                    _ => actual < bound,
+                }
+            });
+        }
+        let actual = match self.field {
+            Field::Team => event.team.clone(),
+            Field::Priority => event
+                .priority
+                .and_then(Priority::from_value)
+                .map(|priority| priority.value().to_string()),
+            Field::Kind => Some(event.kind.name().to_owned()),
+            Field::Actor => event.actor.clone(),
+            Field::State => match &event.kind {
+                ActivityKind::StateChanged { to, .. } => Some(to.clone()),
+                _ => None,
+            },
+            Field::Label => match &event.kind {
+                ActivityKind::LabelAdded { label } => Some(label.clone()),
+                _ => None,
+            },
+        };
+        let hit = actual.is_some_and(|actual| {
+            self.values.iter().any(|value| match self.field {
+                Field::Priority => value
+                    .parse::<Priority>()
+                    .is_ok_and(|priority| priority.value().to_string() == actual),
+                _ => value.eq_ignore_ascii_case(&actual),
+            })
+        });
+        hit == (self.op == Op::Eq)
+    }
+}
+
+/// Short description such as `moved Todo → Done by ada`.
+pub fn summary(event: &ActivityEvent) -> String {
+    let actor = event.actor.as_deref().unwrap_or("someone");
+    match &event.kind {
+        ActivityKind::Created => format!("created by {actor}"),
+        ActivityKind::StateChanged { from, to } => format!("moved {from} → {to} by {actor}"),
+        ActivityKind::Commented { .. } => format!("commented by {actor}"),
+        ActivityKind::LabelAdded { label } => format!("labelled {label} by {actor}"),
+        ActivityKind::Stale { days } => format!("has not been updated for {days} days"),
+    }
+}
+
+/// Fill `{issue}`, `{title}`, `{url}`, `{team}`, `{priority}`, `{actor}`, `{kind}`, `{summary}`,
+/// `{from}`, `{to}`, `{body}`, `{label}`, and `{days}` in `template`; placeholders without a value
+/// become empty.
+pub fn render(template: &str, event: &ActivityEvent) -> String {
+    let (mut from, mut to, mut body, mut label, mut days) = ("", "", "", "", String::new());
+    match &event.kind {
+        ActivityKind::StateChanged { from: old, to: new } => (from, to) = (old, new),
+        ActivityKind::Commented { body: text } => body = text,
+        ActivityKind::LabelAdded { label: name } => label = name,
+        ActivityKind::Stale { days: count } => days = count.to_string(),
+        ActivityKind::Created => {}
+    }
+    let priority = event
+        .priority
+        .map(|value| priority::label_for(Some(value)))
+        .unwrap_or_default();
+    let values = [
+        ("issue", event.issue.as_str()),
+        ("title", event.title.as_str()),
+        ("url", event.url.as_deref().unwrap_or_default()),
+        ("team", event.team.as_deref().unwrap_or_default()),
+        ("priority", priority.as_str()),
+        ("actor", event.actor.as_deref().unwrap_or_default()),
+        ("kind", event.kind.name()),
+        ("summary", &summary(event)),
+        ("from", from),
+        ("to", to),
+        ("body", body),
+        ("label", label),
+        ("days", &days),
+    ];
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        out.push_str(&rest[..open]);
+        let after = &rest[open + 1..];
+        match after.find('}').and_then(|close| {
+            values
+                .iter()
+                .find(|(name, _)| *name == &after[..close])
+                .map(|(_, value)| (close, *value))
+        }) {
+            Some((close, value)) => {
+                out.push_str(value);
+                rest = &after[close + 1..];
+            }
+            None => {
+                out.push('{');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out.trim_end().to_owned()
+}
+
+/// Posts notification payloads.
+#[derive(Clone)]
+pub struct Notifier {
+    http: reqwest::Client,
+    timeout: Option<Duration>,
+}
+
+impl Notifier {
+    pub fn new(timeout: Option<Duration>) -> Self {
+        Self {
+            http: crate::http::shared().unwrap_or_default(),
+            timeout,
+        }
+    }
+
+    pub async fn send(
+        &self,
+        destination: &Destination,
+        payload: &Value,
+    ) -> Result<(), NotifyError> {
+        let mut request = self.http.post(destination.url().clone()).json(payload);
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+        let response = request.send().await?;
+        let status = response.status();
+        if status.is_success() {
+            Ok(())
+        } else {
+            let body = response.text().await.unwrap_or_default();
+            Err(NotifyError::Status { status, body })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use httpmock::prelude::*;
+
+    fn event(kind: ActivityKind, priority: i32) -> ActivityEvent {
+        ActivityEvent {
+            at: Utc::now(),
+            issue: "ENG-1".into(),
+            title: "Login fails".into(),
+            url: Some("https://linear.app/acme/issue/ENG-1".into()),
+            team: Some("ENG".into()),
+            priority: Some(priority),
+            actor: Some("ada".into()),
+            kind,
+        }
+    }
+
+    #[test]
+    fn filters_by_team_priority_and_kind() {
+        let filter: EventFilter = "team=ENG priority>=high".parse().unwrap();
+        assert_eq!(filter.team(), Some("ENG"));
+        assert!(filter.matches(&event(ActivityKind::Created, 1)));
+        assert!(filter.matches(&event(ActivityKind::Created, 2)));
+        assert!(!filter.matches(&event(ActivityKind::Created, 3)));
+        assert!(!filter.matches(&event(ActivityKind::Created, 0)));
+
+        let filter: EventFilter = "kind!=commented state=Done,Canceled".parse().unwrap();
+        let moved = |to: &str| {
+            event(
+                ActivityKind::StateChanged {
+                    from: "Todo".into(),
+                    to: to.into(),
+                },
+                3,
+            )
+        };
+        assert!(filter.matches(&moved("done")));
+        assert!(!filter.matches(&moved("In Progress")));
+        assert!(filter.team().is_none());
+
+        assert!(EventFilter::from_str("").unwrap().matches(&moved("x")));
+        assert!("team>=ENG".parse::<EventFilter>().is_err());
+        assert!("priority>=critical".parse::<EventFilter>().is_err());
+        assert!("colour=red".parse::<EventFilter>().is_err());
+    }
+
+    #[test]
+    fn renders_templates_and_payloads() {
+        let moved = event(
+            ActivityKind::StateChanged {
+                from: "Todo".into(),
+                to: "Done".into(),
+            },
+            2,
+        );
+        assert_eq!(
+            render(DEFAULT_TEMPLATE, &moved),
+            "ENG-1 moved Todo → Done by ada: Login fails https://linear.app/acme/issue/ENG-1"
+        );
+        assert_eq!(
+            render("[{priority}] {issue} {unknown} {body}", &moved),
+            "[High] ENG-1 {unknown}"
+        );
+
+        let slack: Destination = "slack://hooks.slack.com/services/T/B/secret"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            slack.url().as_str(),
+            "https://hooks.slack.com/services/T/B/secret"
+        );
+        assert_eq!(slack.to_string(), "slack://hooks.slack.com");
+        assert_eq!(slack.payload("hi", &moved), json!({ "text": "hi" }));
+        let hook: Destination = "https://example.com/hook".parse().unwrap();
+        let body = hook.payload("hi", &moved);
+        assert_eq!(body["kind"], "state_changed");
+        assert_eq!(body["text"], "hi");
+        assert!("ftp://example.com".parse::<Destination>().is_err());
+    }
+
+    #[tokio::test]
+    async fn reports_failed_deliveries() {
+        let server = MockServer::start();
+        let ok = server.mock(|when, then| {
+            when.method(POST)
+                .path("/ok")
+                .json_body(json!({ "content": "hi" }));
+            then.status(204);
+        });
+        server.mock(|when, then| {
+            when.method(POST).path("/gone");
+            then.status(404).body("unknown webhook");
+        });
+
+        let notifier = Notifier::new(None);
+        let created = event(ActivityKind::Created, 0);
+        let discord = Destination::Discord(Url::parse(&server.url("/ok")).unwrap());
+        notifier
+            .send(&discord, &discord.payload("hi", &created))
+            .await
+            .unwrap();
+        ok.assert();
+
+        let gone = Destination::Webhook(Url::parse(&server.url("/gone")).unwrap());
+        let err = notifier
+            .send(&gone, &gone.payload("hi", &created))
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, NotifyError::Status { status, .. } if status == StatusCode::NOT_FOUND)
+        );
+    }
+}
@@ -0,0 +1,378 @@
+//! Notification rules for `linear notify watch`: run a local command whenever an issue newly
+//! matches a rule's [`IssueFilter`].
+//!
+//! Rules are kept in `notify-rules.toml` in the config directory
+//! ([`ConfigLocator::notify_rules_file`](crate::config::ConfigLocator::notify_rules_file)):
+//!
+//! ```toml
+//! [[rule]]
+//! name = "urgent ENG"
+//! filter = "team=ENG priority=urgent"
+//! command = "notify-send \"$LINEAR_ISSUE\" \"$LINEAR_TITLE\""
+//! ```
+
+use std::collections::HashSet;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::graphql::{IssueState, TeamSummary};
+use crate::priority::{self, Priority};
+use crate::query::{IssueFields, IssueQuery};
+
+/// Every rule in `notify-rules.toml`, in file order.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NotifyRules {
+    #[serde(default, rename = "rule")]
+    pub rules: Vec<NotifyRule>,
+}
+
+/// `when an issue starts matching <filter>, run <command>`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NotifyRule {
+    pub name: String,
+    pub filter: IssueFilter,
+    /// Run by the platform shell with `LINEAR_*` variables describing the issue.
+    pub command: String,
+}
+
+impl NotifyRules {
+    /// Read rules from `path`; a missing file has no rules.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let raw = match fs::read_to_string(path) {
+            Ok(raw) => raw,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(format!("failed to read {}: {}", path.display(), err)),
+        };
+        toml::from_str(&raw).map_err(|err| format!("invalid {}: {}", path.display(), err))
+    }
+
+    /// Write rules to `path`, replacing the previous file atomically.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let raw = toml::to_string(self).map_err(|err| io::Error::other(err.to_string()))?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let temp = path.with_extension("toml.tmp");
+        fs::write(&temp, raw)?;
+        fs::rename(temp, path)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&NotifyRule> {
+        self.rules
+            .iter()
+            .find(|rule| rule.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Append `rule`; names are unique, ignoring case.
+    pub fn add(&mut self, rule: NotifyRule) -> Result<(), String> {
+        if rule.name.trim().is_empty() {
+            return Err("rule name must not be empty".into());
+        }
+        if self.get(&rule.name).is_some() {
+            return Err(format!("a rule named '{}' already exists", rule.name));
+        }
+        self.rules.push(rule);
+        Ok(())
+    }
+
+    /// Drop the rule called `name`, returning it if there was one.
+    pub fn remove(&mut self, name: &str) -> Option<NotifyRule> {
+        let index = self
+            .rules
+            .iter()
+            .position(|rule| rule.name.eq_ignore_ascii_case(name))?;
+        Some(self.rules.remove(index))
+    }
+}
+
+/// Space-separated `field=value` conditions on issues, all of which must hold.
+///
+/// Fields are `team` (key), `state` (name), `label` (name), `assignee` (`me`, `none`, or a
+/// display name), and `priority`. Values may list comma-separated alternatives except for
+/// `label`, which can be repeated instead; `priority` also supports `>=`, `>`, `<=`, and `<` by
+/// importance, as in `notify forward --filter`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IssueFilter {
+    raw: String,
+    conditions: Vec<Condition>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Condition {
+    Team(Vec<String>),
+    State(Vec<String>),
+    Label(String),
+    Assignee(Vec<String>),
+    Priority(Vec<Priority>),
+}
+
+impl IssueFilter {
+    /// Query for the issues this filter matches, selecting the fields of [`WatchedIssue`].
+    pub fn query(&self) -> IssueQuery {
+        let any = |field: &str, values: &[String]| match values {
+            [value] => serde_json::json!({ field: { "eqIgnoreCase": value } }),
+            _ => serde_json::json!({ "or": values
+                .iter()
+                .map(|value| serde_json::json!({ field: { "eqIgnoreCase": value } }))
+                .collect::<Vec<_>>() }),
+        };
+        self.conditions.iter().fold(
+            IssueQuery::new().select(
+                IssueFields::IDENTIFIER
+                    | IssueFields::TITLE
+                    | IssueFields::URL
+                    | IssueFields::PRIORITY
+                    | IssueFields::STATE
+                    | IssueFields::TEAM,
+            ),
+            |query, condition| match condition {
+                Condition::Team(keys) => query.raw("team", any("key", keys)),
+                Condition::State(names) => query.raw("state", any("name", names)),
+                Condition::Label(name) => query.label(name.clone()),
+                Condition::Assignee(names) => match names.as_slice() {
+                    [name] if name.eq_ignore_ascii_case("me") => query.assignee_me(),
+                    [name] if name.eq_ignore_ascii_case("none") => query.unassigned(),
+                    _ => query.raw("assignee", any("displayName", names)),
+                },
+                Condition::Priority(allowed) => query.raw(
+                    "priority",
+                    serde_json::json!({
+                        "in": allowed.iter().map(|priority| priority.value()).collect::<Vec<_>>()
+                    }),
+                ),
+            },
+        )
+    }
+}
+
+impl FromStr for IssueFilter {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let conditions = input
+            .split_whitespace()
+            .map(Condition::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+        if conditions.is_empty() {
+            return Err("filter has no conditions".into());
+        }
+        Ok(Self {
+            raw: input.split_whitespace().collect::<Vec<_>>().join(" "),
+            conditions,
+        })
+    }
+}
+
+impl fmt::Display for IssueFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.raw)
+    }
+}
+
+impl Serialize for IssueFilter {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.raw)
+    }
+}
+
+impl<'de> Deserialize<'de> for IssueFilter {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+        raw.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl Condition {
+    fn parse(term: &str) -> Result<Self, String> {
+        let (at, symbol) = [">=", "<=", ">", "<", "="]
+            .iter()
+            .filter_map(|symbol| term.find(symbol).map(|at| (at, *symbol)))
+            .min_by_key(|(at, symbol)| (*at, std::cmp::Reverse(symbol.len())))
+            .ok_or_else(|| format!("invalid filter '{term}' (expected field=value)"))?;
+        let name = term[..at].to_ascii_lowercase();
+        let values: Vec<String> = term[at + symbol.len()..]
+            .split(',')
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_owned)
+            .collect();
+        if values.is_empty() {
+            return Err(format!("filter '{term}' has no value"));
+        }
+        if name != "priority" && symbol != "=" {
+            return Err(format!("'{symbol}' only applies to priority (in '{term}')"));
+        }
+        match name.as_str() {
+            "team" => Ok(Condition::Team(values)),
+            "state" => Ok(Condition::State(values)),
+            "assignee" => Ok(Condition::Assignee(values)),
+            "label" if values.len() == 1 => Ok(Condition::Label(values[0].clone())),
+            "label" => Err(format!(
+                "'{term}' lists several labels; repeat label= to require each"
+            )),
+            "priority" => {
+                let bounds = values
+                    .iter()
+                    .map(|value| value.parse::<Priority>())
+                    .collect::<Result<Vec<_>, _>>()?;
+                let allowed: Vec<Priority> = Priority::ALL
+                    .into_iter()
+                    .filter(|candidate| {
+                        bounds.iter().any(|bound| match symbol {
+                            ">=" => *candidate >= *bound,
+                            ">" => *candidate > *bound,
+                            "<=" => *candidate <= *bound,
+                            "<" => *candidate < *bound,
+                            _ => candidate == bound,
+                        })
+                    })
+                    .collect();
+                if allowed.is_empty() {
+                    return Err(format!("filter '{term}' matches no priority"));
+                }
+                Ok(Condition::Priority(allowed))
+            }
+            other => Err(format!(
+                "unknown filter field '{other}'; expected team, state, label, assignee, or priority"
+            )),
+        }
+    }
+}
+
+/// An issue matched by a rule, as handed to its command.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchedIssue {
+    pub id: String,
+    pub identifier: String,
+    pub title: String,
+    pub url: Option<String>,
+    pub priority: Option<i32>,
+    pub state: Option<IssueState>,
+    pub team: Option<TeamSummary>,
+}
+
+impl WatchedIssue {
+    /// Environment for the command of `rule`: `LINEAR_RULE`, `LINEAR_ISSUE`, `LINEAR_TITLE`,
+    /// `LINEAR_URL`, `LINEAR_TEAM`, `LINEAR_STATE`, and `LINEAR_PRIORITY`.
+    pub fn env(&self, rule: &str) -> Vec<(&'static str, String)> {
+        vec![
+            ("LINEAR_RULE", rule.to_owned()),
+            ("LINEAR_ISSUE", self.identifier.clone()),
+            ("LINEAR_TITLE", self.title.clone()),
+            ("LINEAR_URL", self.url.clone().unwrap_or_default()),
+            (
+                "LINEAR_TEAM",
+                self.team
+                    .as_ref()
+                    .map(|team| team.key.clone())
+                    .unwrap_or_default(),
+            ),
+            (
+                "LINEAR_STATE",
+                self.state
+                    .as_ref()
+                    .map(|state| state.name.clone())
+                    .unwrap_or_default(),
+            ),
+            ("LINEAR_PRIORITY", priority::label_for(self.priority)),
+        ]
+    }
+}
+
+/// Issues in `current` whose ids were not in `previous`, in `current` order.
+pub fn newly_matching<'a>(
+    previous: &HashSet<String>,
+    current: &'a [WatchedIssue],
+) -> Vec<&'a WatchedIssue> {
+    current
+        .iter()
+        .filter(|issue| !previous.contains(&issue.id))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn parses_filters_into_queries() {
+        let filter: IssueFilter = "team=ENG  priority>=high label=bug assignee=me"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            filter.to_string(),
+            "team=ENG priority>=high label=bug assignee=me"
+        );
+        let query = filter.query().filter().unwrap();
+        assert_eq!(query["team"]["key"]["eqIgnoreCase"], "ENG");
+        assert_eq!(query["priority"]["in"], serde_json::json!([1, 2]));
+        assert_eq!(query["labels"]["some"]["name"]["eqIgnoreCase"], "bug");
+        assert_eq!(query["assignee"]["isMe"]["eq"], true);
+
+        let states: IssueFilter = "state=Todo,Backlog priority=urgent".parse().unwrap();
+        let query = states.query().filter().unwrap();
+        assert_eq!(query["state"]["or"][1]["name"]["eqIgnoreCase"], "Backlog");
+        assert_eq!(query["priority"]["in"], serde_json::json!([1]));
+
+        assert!("".parse::<IssueFilter>().is_err());
+        assert!("team>ENG".parse::<IssueFilter>().is_err());
+        assert!("label=a,b".parse::<IssueFilter>().is_err());
+        assert!("owner=me".parse::<IssueFilter>().is_err());
+    }
+
+    #[test]
+    fn round_trips_rules_through_toml() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("notify-rules.toml");
+        let mut rules = NotifyRules::load(&path).unwrap();
+        assert!(rules.rules.is_empty());
+        let rule = NotifyRule {
+            name: "urgent ENG".into(),
+            filter: "team=ENG priority=urgent".parse().unwrap(),
+            command: "notify-send \"$LINEAR_ISSUE\"".into(),
+        };
+        rules.add(rule.clone()).unwrap();
+        assert!(rules
+            .add(NotifyRule {
+                name: "Urgent eng".into(),
+                ..rule.clone()
+            })
+            .is_err());
+        rules.save(&path).unwrap();
+
+        let mut loaded = NotifyRules::load(&path).unwrap();
+        assert_eq!(loaded.rules, [rule]);
+        assert!(loaded.remove("URGENT ENG").is_some());
+        assert!(loaded.remove("urgent ENG").is_none());
+    }
+
+    #[test]
+    fn reports_only_new_matches() {
+        let issue = |id: &str| WatchedIssue {
+            id: id.into(),
+            identifier: format!("ENG-{id}"),
+            title: "Outage".into(),
+            url: None,
+            priority: Some(1),
+            state: None,
+            team: None,
+        };
+        let current = [issue("1"), issue("2")];
+        let previous: HashSet<String> = ["1".to_string()].into();
+        let fresh = newly_matching(&previous, &current);
+        assert_eq!(fresh.len(), 1);
+        assert_eq!(fresh[0].identifier, "ENG-2");
+        assert!(fresh[0]
+            .env("urgent")
+            .contains(&("LINEAR_PRIORITY", "Urgent".into())));
+    }
+}
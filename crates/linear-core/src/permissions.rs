@@ -0,0 +1,99 @@
+//! Checking the viewer's role before admin-only operations, so they fail with the missing role
+//! named instead of an opaque `FORBIDDEN` from the API halfway through.
+
+use crate::error::LinearError;
+use crate::graphql::ViewerPermissions;
+
+/// Role an operation needs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Requirement<'a> {
+    /// A workspace admin.
+    WorkspaceAdmin,
+    /// A workspace admin or an owner of the team with this key; team settings such as workflow
+    /// states, labels, and templates need it.
+    TeamAdmin(&'a str),
+}
+
+impl ViewerPermissions {
+    /// Whether the viewer owns the team with key `key` (ignoring case).
+    pub fn owns_team(&self, key: &str) -> bool {
+        self.team_memberships
+            .iter()
+            .any(|membership| membership.owner && membership.team.key.eq_ignore_ascii_case(key))
+    }
+
+    pub fn is_member_of(&self, key: &str) -> bool {
+        self.team_memberships
+            .iter()
+            .any(|membership| membership.team.key.eq_ignore_ascii_case(key))
+    }
+
+    /// `Ok` when the viewer meets `requirement`, else an `auth` error naming the role and
+    /// workspace, e.g. `requires admin in workspace Acme`.
+    pub fn require(&self, requirement: Requirement<'_>) -> Result<(), LinearError> {
+        let workspace = &self.organization.name;
+        if self.admin {
+            return Ok(());
+        }
+        match requirement {
+            Requirement::WorkspaceAdmin => Err(LinearError::auth(format!(
+                "requires admin in workspace {workspace}"
+            ))),
+            Requirement::TeamAdmin(key) if self.owns_team(key) => Ok(()),
+            Requirement::TeamAdmin(key) if self.is_member_of(key) => Err(LinearError::auth(
+                format!("requires owner of team {key} or admin in workspace {workspace}"),
+            )),
+            Requirement::TeamAdmin(key) => Err(LinearError::auth(format!(
+                "requires owner of team {key} or admin in workspace {workspace}; you are not a member of {key}"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorKind;
+
+    fn permissions(admin: bool, teams: &[(&str, bool)]) -> ViewerPermissions {
+        serde_json::from_value(serde_json::json!({
+            "organization": { "id": "o", "name": "Acme", "urlKey": "acme" },
+            "admin": admin,
+            "guest": false,
+            "teamMemberships": teams
+                .iter()
+                .map(|(key, owner)| serde_json::json!({
+                    "owner": owner,
+                    "team": { "id": key, "name": key, "key": key }
+                }))
+                .collect::<Vec<_>>()
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn names_the_missing_role() {
+        let member = permissions(false, &[("ENG", true), ("OPS", false)]);
+        assert!(member.require(Requirement::TeamAdmin("eng")).is_ok());
+
+        let err = member.require(Requirement::WorkspaceAdmin).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::Auth);
+        assert_eq!(err.message, "requires admin in workspace Acme");
+        assert_eq!(
+            member
+                .require(Requirement::TeamAdmin("OPS"))
+                .unwrap_err()
+                .message,
+            "requires owner of team OPS or admin in workspace Acme"
+        );
+        assert!(member
+            .require(Requirement::TeamAdmin("WEB"))
+            .unwrap_err()
+            .message
+            .ends_with("you are not a member of WEB"));
+
+        let admin = permissions(true, &[]);
+        assert!(admin.require(Requirement::WorkspaceAdmin).is_ok());
+        assert!(admin.require(Requirement::TeamAdmin("WEB")).is_ok());
+    }
+}
@@ -0,0 +1,404 @@
+//! Plain-English issue filters for `linear issue list --query`, such as
+//! `urgent bugs assigned to me updated this week`.
+//!
+//! Recognised phrases are priorities, `assigned to me`/`my`/`unassigned`, state categories
+//! (`open`, `in progress`, `done`, ...), label names (plurals too), and relative dates after
+//! `updated` or `created` (`today`, `this week`, `last month`, `in the last 3 days`,
+//! `since 2024-05-01`). Each becomes an [`IssueQuery`] condition: several priorities match any
+//! of them, while several labels must all be present. Words that match nothing make the whole
+//! query an error instead of being dropped, so a typo never widens the results.
+
+use chrono::{DateTime, Datelike, Days, Duration, Months, NaiveDate, TimeZone, Utc};
+use serde::Serialize;
+use serde_json::json;
+
+use crate::priority::Priority;
+use crate::query::{IssueQuery, StateType};
+
+/// Words that carry no filter, like `show me all the issues with`.
+const FILLER: &[&str] = &[
+    "a", "all", "an", "and", "are", "any", "by", "find", "for", "from", "in", "is", "issue",
+    "issues", "label", "labeled", "labelled", "list", "me", "of", "or", "priority", "show", "that",
+    "the", "ticket", "tickets", "to", "tagged", "was", "were", "which", "with",
+];
+
+/// One recognised phrase and the condition it became.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Phrase {
+    pub text: String,
+    pub meaning: String,
+}
+
+/// A parsed query: its conditions and what each phrase was read as.
+#[derive(Debug, Clone)]
+pub struct Interpretation {
+    pub query: IssueQuery,
+    pub phrases: Vec<Phrase>,
+}
+
+/// Parse `input`, matching label names from `labels` and dates relative to `now` in its time
+/// zone. Fails with the words it did not understand.
+pub fn interpret<Tz: TimeZone>(
+    input: &str,
+    labels: &[String],
+    now: DateTime<Tz>,
+) -> Result<Interpretation, String> {
+    let words: Vec<String> = input
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .map(|word| {
+            word.trim_matches(|c: char| !c.is_alphanumeric() && c != '-')
+                .to_lowercase()
+        })
+        .filter(|word| !word.is_empty())
+        .collect();
+    if words.is_empty() {
+        return Err("the query is empty".into());
+    }
+    let labels: Vec<(Vec<String>, &String)> = labels
+        .iter()
+        .map(|label| {
+            let words = label.split_whitespace().map(str::to_lowercase).collect();
+            (words, label)
+        })
+        .collect();
+
+    let mut parser = Parser {
+        query: IssueQuery::new(),
+        phrases: Vec::new(),
+        priorities: Vec::new(),
+    };
+    let mut unknown = Vec::new();
+    let mut at = 0;
+    while at < words.len() {
+        let rest = &words[at..];
+        let matched = parser
+            .assignee(rest)
+            .or_else(|| parser.priority(rest))
+            .or_else(|| parser.state(rest))
+            .map(Ok)
+            .or_else(|| parser.date(rest, &now).transpose())
+            .or_else(|| parser.label(rest, &labels).map(Ok))
+            .transpose()?;
+        match matched {
+            Some(consumed) => at += consumed,
+            None => {
+                if !FILLER.contains(&rest[0].as_str()) {
+                    unknown.push(rest[0].clone());
+                }
+                at += 1;
+            }
+        }
+    }
+    if !unknown.is_empty() {
+        return Err(format!(
+            "did not understand {}; the query knows priorities, 'assigned to me', states, \
+             label names, and dates like 'updated this week'",
+            unknown
+                .iter()
+                .map(|word| format!("'{word}'"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+    Ok(parser.finish())
+}
+
+struct Parser {
+    query: IssueQuery,
+    phrases: Vec<Phrase>,
+    /// Several priorities mean any of them, so they become one condition at the end.
+    priorities: Vec<Priority>,
+}
+
+/// Whether `words` starts with `phrase`.
+fn starts(words: &[String], phrase: &[&str]) -> bool {
+    words.len() >= phrase.len() && words.iter().zip(phrase).all(|(word, part)| word == part)
+}
+
+impl Parser {
+    fn add(&mut self, words: &[String], meaning: impl Into<String>) -> usize {
+        self.phrases.push(Phrase {
+            text: words.join(" "),
+            meaning: meaning.into(),
+        });
+        words.len()
+    }
+
+    fn take(&mut self, query: impl FnOnce(IssueQuery) -> IssueQuery) {
+        self.query = query(std::mem::take(&mut self.query));
+    }
+
+    fn assignee(&mut self, words: &[String]) -> Option<usize> {
+        for phrase in [
+            &["assigned", "to", "me"][..],
+            &["assigned", "to", "myself"],
+            &["my"],
+            &["mine"],
+        ] {
+            if starts(words, phrase) {
+                self.take(IssueQuery::assignee_me);
+                return Some(self.add(&words[..phrase.len()], "assigned to you"));
+            }
+        }
+        if starts(words, &["unassigned"]) || starts(words, &["not", "assigned"]) {
+            let len = if words[0] == "not" { 2 } else { 1 };
+            self.take(IssueQuery::unassigned);
+            return Some(self.add(&words[..len], "no assignee"));
+        }
+        None
+    }
+
+    fn priority(&mut self, words: &[String]) -> Option<usize> {
+        let (priority, mut len) = match words[0].as_str() {
+            "urgent" => (Priority::Urgent, 1),
+            "high" | "high-priority" => (Priority::High, 1),
+            "medium" | "medium-priority" => (Priority::Medium, 1),
+            "low" | "low-priority" => (Priority::Low, 1),
+            "no" if starts(words, &["no", "priority"]) => (Priority::None, 2),
+            "important" => {
+                self.priorities.extend([Priority::Urgent, Priority::High]);
+                return Some(self.add(&words[..1], "priority urgent or high"));
+            }
+            _ => return None,
+        };
+        if priority != Priority::None && words.get(1).map(String::as_str) == Some("priority") {
+            len += 1;
+        }
+        self.priorities.push(priority);
+        Some(self.add(
+            &words[..len],
+            format!("priority {}", priority.label().to_lowercase()),
+        ))
+    }
+
+    fn state(&mut self, words: &[String]) -> Option<usize> {
+        if words[0] == "open" {
+            self.take(IssueQuery::open);
+            return Some(self.add(&words[..1], "not completed or canceled"));
+        }
+        let (state_type, len) = match words[0].as_str() {
+            "in" if starts(words, &["in", "progress"]) => (StateType::Started, 2),
+            "in-progress" | "started" => (StateType::Started, 1),
+            "todo" | "unstarted" => (StateType::Unstarted, 1),
+            "backlog" => (StateType::Backlog, 1),
+            "triage" => (StateType::Triage, 1),
+            "done" | "completed" | "closed" | "finished" => (StateType::Completed, 1),
+            "canceled" | "cancelled" => (StateType::Canceled, 1),
+            _ => return None,
+        };
+        self.take(|query| query.state_type(state_type));
+        Some(self.add(&words[..len], format!("state type {state_type}")))
+    }
+
+    /// `updated`/`created` and a period, or a bare period, which means updated.
+    fn date<Tz: TimeZone>(
+        &mut self,
+        words: &[String],
+        now: &DateTime<Tz>,
+    ) -> Result<Option<usize>, String> {
+        let (field, verb) = match words[0].as_str() {
+            "updated" | "changed" | "modified" | "touched" => ("updatedAt", 1),
+            "created" | "opened" | "filed" | "reported" => ("createdAt", 1),
+            _ => ("updatedAt", 0),
+        };
+        let Some((range, len)) = period(&words[verb..], now) else {
+            if verb == 0 {
+                return Ok(None);
+            }
+            return Err(format!(
+                "expected a time after '{}', such as 'this week' or 'in the last 3 days'",
+                words[0]
+            ));
+        };
+        let (since, until) = range;
+        let condition = match until {
+            Some(until) => json!({ "gte": since.to_rfc3339(), "lt": until.to_rfc3339() }),
+            None => json!({ "gte": since.to_rfc3339() }),
+        };
+        self.take(|query| query.raw(field, condition));
+        let what = if field == "createdAt" {
+            "created"
+        } else {
+            "updated"
+        };
+        let meaning = match until {
+            Some(until) => format!(
+                "{what} from {} until {}",
+                since.format("%Y-%m-%d %H:%M UTC"),
+                until.format("%Y-%m-%d %H:%M UTC")
+            ),
+            None => format!("{what} since {}", since.format("%Y-%m-%d %H:%M UTC")),
+        };
+        Ok(Some(self.add(&words[..verb + len], meaning)))
+    }
+
+    /// The longest label name at the start of `words`, also matching a plural last word.
+    fn label(&mut self, words: &[String], labels: &[(Vec<String>, &String)]) -> Option<usize> {
+        let (len, name) = labels
+            .iter()
+            .filter(|(parts, _)| !parts.is_empty() && parts.len() <= words.len())
+            .filter(|(parts, _)| {
+                let last = parts.len() - 1;
+                parts[..last] == words[..last] && {
+                    let (word, part) = (&words[last], &parts[last]);
+                    word == part
+                        || word.strip_suffix('s') == Some(part.as_str())
+                        || word.strip_suffix("es") == Some(part.as_str())
+                }
+            })
+            .map(|(parts, name)| (parts.len(), *name))
+            .max_by_key(|(len, _)| *len)?;
+        self.take(|query| query.label(name.clone()));
+        Some(self.add(&words[..len], format!("label {name}")))
+    }
+
+    fn finish(mut self) -> Interpretation {
+        self.priorities.dedup();
+        match self.priorities.as_slice() {
+            [] => {}
+            [priority] => {
+                let priority = *priority;
+                self.take(|query| query.priority(priority));
+            }
+            several => {
+                let values: Vec<i32> = several.iter().map(|priority| priority.value()).collect();
+                self.take(|query| query.raw("priority", json!({ "in": values })));
+            }
+        }
+        Interpretation {
+            query: self.query,
+            phrases: self.phrases,
+        }
+    }
+}
+
+/// Start of a period and, when it has ended, its end.
+type Range = (DateTime<Utc>, Option<DateTime<Utc>>);
+
+/// The range a relative time at the start of `words` covers, with how many words it took.
+fn period<Tz: TimeZone>(words: &[String], now: &DateTime<Tz>) -> Option<(Range, usize)> {
+    let zone = now.timezone();
+    let today = now.date_naive();
+    let midnight = |date: NaiveDate| {
+        zone.from_local_datetime(&date.and_hms_opt(0, 0, 0)?)
+            .earliest()
+            .map(|start| start.with_timezone(&Utc))
+    };
+    let week_start = today - Days::new(today.weekday().num_days_from_monday().into());
+    let month_start = today.with_day(1)?;
+    let word = |index: usize| words.get(index).map(String::as_str);
+
+    match (word(0)?, word(1)) {
+        ("today", _) => Some(((midnight(today)?, None), 1)),
+        ("yesterday", _) => {
+            let since = midnight(today - Days::new(1))?;
+            Some(((since, Some(midnight(today)?)), 1))
+        }
+        ("this", Some("week")) => Some(((midnight(week_start)?, None), 2)),
+        ("this", Some("month")) => Some(((midnight(month_start)?, None), 2)),
+        ("last", Some("week")) => {
+            let since = midnight(week_start - Days::new(7))?;
+            Some(((since, Some(midnight(week_start)?)), 2))
+        }
+        ("last", Some("month")) => {
+            let since = midnight(month_start - Months::new(1))?;
+            Some(((since, Some(midnight(month_start)?)), 2))
+        }
+        ("since", Some(date)) => {
+            let date = match date {
+                "today" => today,
+                "yesterday" => today - Days::new(1),
+                date => NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?,
+            };
+            Some(((midnight(date)?, None), 2))
+        }
+        ("in", Some("the")) | ("within", Some("the")) => {
+            let (range, len) = period(&words[2..], now)?;
+            Some((range, len + 2))
+        }
+        ("last" | "past", Some(count)) => {
+            let count: u32 = count.parse().ok()?;
+            let now = now.with_timezone(&Utc);
+            let since = match word(2)?.trim_end_matches('s') {
+                "hour" => now - Duration::hours(count.into()),
+                "day" => now - Duration::days(count.into()),
+                "week" => now - Duration::weeks(count.into()),
+                "month" => now.checked_sub_months(Months::new(count))?,
+                _ => return None,
+            };
+            Some(((since, None), 3))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::FixedOffset;
+
+    use super::*;
+
+    fn now() -> DateTime<FixedOffset> {
+        // A Thursday, two hours ahead of UTC.
+        DateTime::parse_from_rfc3339("2024-05-09T15:30:00+02:00").unwrap()
+    }
+
+    fn labels() -> Vec<String> {
+        ["Bug", "Feature request", "Frontend"]
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    fn reads_priorities_assignee_labels_and_dates() {
+        let parsed = interpret(
+            "urgent bugs assigned to me updated this week",
+            &labels(),
+            now(),
+        )
+        .unwrap();
+        assert_eq!(
+            parsed.query.filter().unwrap(),
+            json!({
+                "assignee": { "isMe": { "eq": true } },
+                "labels": { "some": { "name": { "eqIgnoreCase": "Bug" } } },
+                "updatedAt": { "gte": "2024-05-05T22:00:00+00:00" },
+                "priority": { "eq": 1 },
+            })
+        );
+        let texts: Vec<_> = parsed.phrases.iter().map(|p| p.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            ["urgent", "bugs", "assigned to me", "updated this week"]
+        );
+
+        let parsed = interpret(
+            "open high or urgent feature requests created in the last 3 days",
+            &labels(),
+            now(),
+        )
+        .unwrap();
+        let filter = parsed.query.filter().unwrap();
+        assert_eq!(filter["priority"], json!({ "in": [2, 1] }));
+        assert_eq!(
+            filter["labels"]["some"]["name"]["eqIgnoreCase"],
+            "Feature request"
+        );
+        assert_eq!(filter["createdAt"]["gte"], "2024-05-06T13:30:00+00:00");
+
+        let parsed = interpret("my frontend issues yesterday", &labels(), now()).unwrap();
+        assert_eq!(
+            parsed.query.filter().unwrap()["updatedAt"],
+            json!({ "gte": "2024-05-07T22:00:00+00:00", "lt": "2024-05-08T22:00:00+00:00" })
+        );
+    }
+
+    #[test]
+    fn rejects_words_it_does_not_know() {
+        let err = interpret("urgent bugz for alice", &labels(), now()).unwrap_err();
+        assert!(err.contains("'bugz', 'alice'"), "{err}");
+        let err = interpret("updated recently", &labels(), now()).unwrap_err();
+        assert!(err.contains("after 'updated'"), "{err}");
+    }
+}
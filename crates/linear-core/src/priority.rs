@@ -0,0 +1,158 @@
+//! Human-readable issue priorities.
+//!
+//! Linear stores priority as a number where `0` means "no priority" and `1`–`4` run from urgent
+//! to low, so numeric order is not importance order. [`Priority`]'s `Ord` follows importance:
+//! `None < Low < Medium < High < Urgent`.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Priority {
+    None,
+    Urgent,
+    High,
+    Medium,
+    Low,
+}
+
+impl Priority {
+    pub const ALL: [Priority; 5] = [
+        Priority::Urgent,
+        Priority::High,
+        Priority::Medium,
+        Priority::Low,
+        Priority::None,
+    ];
+
+    /// Map Linear's numeric priority; unknown values return `None`.
+    pub fn from_value(value: i32) -> Option<Self> {
+        match value {
+            0 => Some(Priority::None),
+            1 => Some(Priority::Urgent),
+            2 => Some(Priority::High),
+            3 => Some(Priority::Medium),
+            4 => Some(Priority::Low),
+            _ => None,
+        }
+    }
+
+    /// Numeric value sent to the API.
+    pub fn value(self) -> i32 {
+        match self {
+            Priority::None => 0,
+            Priority::Urgent => 1,
+            Priority::High => 2,
+            Priority::Medium => 3,
+            Priority::Low => 4,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Priority::None => "No priority",
+            Priority::Urgent => "Urgent",
+            Priority::High => "High",
+            Priority::Medium => "Medium",
+            Priority::Low => "Low",
+        }
+    }
+
+    fn importance(self) -> u8 {
+        match self {
+            Priority::None => 0,
+            Priority::Low => 1,
+            Priority::Medium => 2,
+            Priority::High => 3,
+            Priority::Urgent => 4,
+        }
+    }
+}
+
+/// Label for an optional raw priority, falling back to the number for unknown values.
+pub fn label_for(value: Option<i32>) -> String {
+    match value {
+        Some(value) => Priority::from_value(value)
+            .map(|priority| priority.label().to_owned())
+            .unwrap_or_else(|| value.to_string()),
+        None => "-".to_owned(),
+    }
+}
+
+impl Ord for Priority {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.importance().cmp(&other.importance())
+    }
+}
+
+impl PartialOrd for Priority {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Priority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+impl FromStr for Priority {
+    type Err = String;
+
+    /// Accepts `urgent|high|medium|low|none` (case-insensitive, `med` and `no-priority` too)
+    /// or the numeric form `0`-`4`.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let normalized = input.trim().to_ascii_lowercase();
+        if let Ok(value) = normalized.parse::<i32>() {
+            return Priority::from_value(value)
+                .ok_or_else(|| format!("priority must be 0-4, got {}", value));
+        }
+        match normalized.as_str() {
+            "urgent" => Ok(Priority::Urgent),
+            "high" => Ok(Priority::High),
+            "medium" | "med" => Ok(Priority::Medium),
+            "low" => Ok(Priority::Low),
+            "none" | "no" | "no-priority" | "no priority" => Ok(Priority::None),
+            _ => Err(format!(
+                "unknown priority '{}'; expected urgent, high, medium, low, none, or 0-4",
+                input
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_names_and_numbers() {
+        assert_eq!("High".parse::<Priority>(), Ok(Priority::High));
+        assert_eq!("med".parse::<Priority>(), Ok(Priority::Medium));
+        assert_eq!("1".parse::<Priority>(), Ok(Priority::Urgent));
+        assert_eq!("0".parse::<Priority>(), Ok(Priority::None));
+        assert!("5".parse::<Priority>().is_err());
+        assert!("critical".parse::<Priority>().is_err());
+        for priority in Priority::ALL {
+            assert_eq!(Priority::from_value(priority.value()), Some(priority));
+        }
+    }
+
+    #[test]
+    fn orders_by_importance_not_value() {
+        let mut values: Vec<Priority> = [0, 4, 1, 3, 2]
+            .into_iter()
+            .filter_map(Priority::from_value)
+            .collect();
+        values.sort_by(|a, b| b.cmp(a));
+        assert_eq!(values, Priority::ALL);
+        assert_eq!(label_for(Some(2)), "High");
+        assert_eq!(label_for(Some(9)), "9");
+        assert_eq!(label_for(None), "-");
+    }
+}
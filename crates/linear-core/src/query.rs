@@ -0,0 +1,495 @@
+//! Typed issue query builder for library consumers.
+//!
+//! [`IssueQuery`] compiles to Linear's `IssueFilter` JSON plus a selection set, so callers can
+//! filter beyond what [`IssueQueryOptions`](crate::services::issues::IssueQueryOptions) exposes
+//! without hand-writing GraphQL:
+//!
+//! ```
+//! use linear_core::query::{IssueFields, IssueQuery, StateType};
+//!
+//! let query = IssueQuery::new()
+//!     .team("ENG")
+//!     .state_type(StateType::Started)
+//!     .assignee_me()
+//!     .select(IssueFields::SUMMARY | IssueFields::LABELS);
+//! assert_eq!(query.filter().unwrap()["team"]["key"]["eq"], "ENG");
+//! ```
+
+use std::fmt;
+use std::ops::{BitOr, BitOrAssign};
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+
+use crate::priority::Priority;
+
+/// Workflow state categories shared by every team.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StateType {
+    Triage,
+    Backlog,
+    Unstarted,
+    Started,
+    Completed,
+    Canceled,
+}
+
+impl StateType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            StateType::Triage => "triage",
+            StateType::Backlog => "backlog",
+            StateType::Unstarted => "unstarted",
+            StateType::Started => "started",
+            StateType::Completed => "completed",
+            StateType::Canceled => "canceled",
+        }
+    }
+}
+
+impl fmt::Display for StateType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for StateType {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.trim().to_ascii_lowercase().as_str() {
+            "triage" => Ok(StateType::Triage),
+            "backlog" => Ok(StateType::Backlog),
+            "unstarted" | "todo" => Ok(StateType::Unstarted),
+            "started" | "in-progress" => Ok(StateType::Started),
+            "completed" | "done" => Ok(StateType::Completed),
+            "canceled" | "cancelled" => Ok(StateType::Canceled),
+            other => Err(format!(
+                "unknown state type '{}'; expected triage, backlog, unstarted, started, \
+                 completed, or canceled",
+                other
+            )),
+        }
+    }
+}
+
+/// Issue fields to select; combine with `|`. The id is always selected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IssueFields(u32);
+
+impl IssueFields {
+    pub const ID: Self = Self(1 << 0);
+    pub const IDENTIFIER: Self = Self(1 << 1);
+    pub const TITLE: Self = Self(1 << 2);
+    pub const DESCRIPTION: Self = Self(1 << 3);
+    pub const URL: Self = Self(1 << 4);
+    pub const BRANCH_NAME: Self = Self(1 << 5);
+    pub const PRIORITY: Self = Self(1 << 6);
+    pub const ESTIMATE: Self = Self(1 << 7);
+    pub const DUE_DATE: Self = Self(1 << 8);
+    pub const CREATED_AT: Self = Self(1 << 9);
+    pub const UPDATED_AT: Self = Self(1 << 10);
+    pub const STATE: Self = Self(1 << 11);
+    pub const ASSIGNEE: Self = Self(1 << 12);
+    pub const TEAM: Self = Self(1 << 13);
+    pub const PROJECT: Self = Self(1 << 14);
+    pub const CYCLE: Self = Self(1 << 15);
+    pub const LABELS: Self = Self(1 << 16);
+    pub const CREATOR: Self = Self(1 << 17);
+    /// The latest 50 comments.
+    pub const COMMENTS: Self = Self(1 << 18);
+    /// The latest 50 history entries, shaped like [`IssueHistory`](crate::graphql::IssueHistory).
+    pub const HISTORY: Self = Self(1 << 19);
+
+    /// Everything [`IssueSummary`](crate::graphql::IssueSummary) deserializes.
+    pub const SUMMARY: Self = Self(
+        Self::ID.0
+            | Self::IDENTIFIER.0
+            | Self::TITLE.0
+            | Self::URL.0
+            | Self::BRANCH_NAME.0
+            | Self::PRIORITY.0
+            | Self::CREATED_AT.0
+            | Self::UPDATED_AT.0
+            | Self::STATE.0
+            | Self::ASSIGNEE.0
+            | Self::PROJECT.0
+            | Self::LABELS.0,
+    );
+
+    const SELECTIONS: [(IssueFields, &'static str); 20] = [
+        (Self::ID, "id"),
+        (Self::IDENTIFIER, "identifier"),
+        (Self::TITLE, "title"),
+        (Self::DESCRIPTION, "description"),
+        (Self::URL, "url"),
+        (Self::BRANCH_NAME, "branchName"),
+        (Self::PRIORITY, "priority"),
+        (Self::ESTIMATE, "estimate"),
+        (Self::DUE_DATE, "dueDate"),
+        (Self::CREATED_AT, "createdAt"),
+        (Self::UPDATED_AT, "updatedAt"),
+        (Self::STATE, "state { id name type }"),
+        (Self::ASSIGNEE, "assignee { id name displayName }"),
+        (Self::TEAM, "team { id key name }"),
+        (Self::PROJECT, "project { id name }"),
+        (Self::CYCLE, "cycle { id number name }"),
+        (Self::LABELS, "labels { nodes { id name color } }"),
+        (Self::CREATOR, "creator { id name displayName }"),
+        (
+            Self::COMMENTS,
+            "comments(last: 50) { nodes { id body createdAt updatedAt \
+             user { id name displayName } } }",
+        ),
+        (
+            Self::HISTORY,
+            "history(last: 50) { nodes { __typename id createdAt updatedAt \
+             fromState { id name type } toState { id name type } \
+             fromAssignee { id name displayName } toAssignee { id name displayName } \
+             fromPriority toPriority fromDueDate toDueDate fromTitle toTitle \
+             updatedDescription actors { id name displayName } addedLabels { id name } } }",
+        ),
+    ];
+
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// GraphQL selection set body for these fields.
+    pub fn selection(self) -> String {
+        let fields = self | Self::ID;
+        Self::SELECTIONS
+            .iter()
+            .filter(|(flag, _)| fields.contains(*flag))
+            .map(|(_, selection)| *selection)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl Default for IssueFields {
+    fn default() -> Self {
+        Self::SUMMARY
+    }
+}
+
+impl BitOr for IssueFields {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for IssueFields {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// Server-side ordering supported by the `issues` connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IssueOrder {
+    CreatedAt,
+    #[default]
+    UpdatedAt,
+}
+
+impl IssueOrder {
+    fn as_graphql(self) -> &'static str {
+        match self {
+            IssueOrder::CreatedAt => "createdAt",
+            IssueOrder::UpdatedAt => "updatedAt",
+        }
+    }
+}
+
+/// One page of issues returned by [`LinearGraphqlClient::query_issues`](crate::graphql::LinearGraphqlClient::query_issues).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssuePage<T> {
+    pub nodes: Vec<T>,
+    pub end_cursor: Option<String>,
+    pub has_next_page: bool,
+}
+
+/// Builder for an `issues` query. Conditions are AND-ed together.
+#[derive(Debug, Clone)]
+pub struct IssueQuery {
+    conditions: Vec<(String, Value)>,
+    fields: IssueFields,
+    first: usize,
+    after: Option<String>,
+    order: IssueOrder,
+    include_archived: bool,
+}
+
+impl Default for IssueQuery {
+    fn default() -> Self {
+        Self {
+            conditions: Vec::new(),
+            fields: IssueFields::SUMMARY,
+            first: 50,
+            after: None,
+            order: IssueOrder::UpdatedAt,
+            include_archived: false,
+        }
+    }
+}
+
+impl IssueQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn with(mut self, field: &str, condition: Value) -> Self {
+        self.conditions.push((field.to_owned(), condition));
+        self
+    }
+
+    /// Team by key (e.g. `ENG`).
+    pub fn team(self, key: impl Into<String>) -> Self {
+        self.with("team", json!({ "key": { "eq": key.into() } }))
+    }
+
+    pub fn team_id(self, id: impl Into<String>) -> Self {
+        self.with("team", json!({ "id": { "eq": id.into() } }))
+    }
+
+    pub fn state_type(self, state_type: StateType) -> Self {
+        self.with("state", json!({ "type": { "eq": state_type.as_str() } }))
+    }
+
+    /// Workflow state by name, case-insensitively.
+    pub fn state_name(self, name: impl Into<String>) -> Self {
+        self.with("state", json!({ "name": { "eqIgnoreCase": name.into() } }))
+    }
+
+    pub fn state_id(self, id: impl Into<String>) -> Self {
+        self.with("state", json!({ "id": { "eq": id.into() } }))
+    }
+
+    /// Issues not yet completed or canceled.
+    pub fn open(self) -> Self {
+        self.with(
+            "state",
+            json!({ "type": { "nin": [StateType::Completed.as_str(), StateType::Canceled.as_str()] } }),
+        )
+    }
+
+    /// Issues assigned to the authenticated user.
+    pub fn assignee_me(self) -> Self {
+        self.with("assignee", json!({ "isMe": { "eq": true } }))
+    }
+
+    /// Issues the authenticated user subscribes to.
+    pub fn subscribed_me(self) -> Self {
+        self.with("subscribers", json!({ "some": { "isMe": { "eq": true } } }))
+    }
+
+    pub fn assignee(self, id: impl Into<String>) -> Self {
+        self.with("assignee", json!({ "id": { "eq": id.into() } }))
+    }
+
+    pub fn unassigned(self) -> Self {
+        self.with("assignee", json!({ "null": true }))
+    }
+
+    /// Has a label with this name; repeat to require several labels.
+    pub fn label(self, name: impl Into<String>) -> Self {
+        self.with(
+            "labels",
+            json!({ "some": { "name": { "eqIgnoreCase": name.into() } } }),
+        )
+    }
+
+    /// Has any of these label ids.
+    pub fn label_ids<I, S>(self, ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let ids: Vec<String> = ids.into_iter().map(Into::into).collect();
+        self.with("labels", json!({ "id": { "in": ids } }))
+    }
+
+    pub fn project(self, id: impl Into<String>) -> Self {
+        self.with("project", json!({ "id": { "eq": id.into() } }))
+    }
+
+    pub fn cycle(self, id: impl Into<String>) -> Self {
+        self.with("cycle", json!({ "id": { "eq": id.into() } }))
+    }
+
+    pub fn priority(self, priority: Priority) -> Self {
+        self.with("priority", json!({ "eq": priority.value() }))
+    }
+
+    /// Priority at or above `priority` (e.g. `High` matches urgent and high).
+    pub fn priority_at_least(self, priority: Priority) -> Self {
+        let values: Vec<i32> = Priority::ALL
+            .into_iter()
+            .filter(|candidate| *candidate >= priority)
+            .map(Priority::value)
+            .collect();
+        self.with("priority", json!({ "in": values }))
+    }
+
+    pub fn title_contains(self, text: impl Into<String>) -> Self {
+        self.with("title", json!({ "contains": text.into() }))
+    }
+
+    pub fn created_since(self, since: DateTime<Utc>) -> Self {
+        self.with("createdAt", json!({ "gte": since.to_rfc3339() }))
+    }
+
+    pub fn updated_since(self, since: DateTime<Utc>) -> Self {
+        self.with("updatedAt", json!({ "gte": since.to_rfc3339() }))
+    }
+
+    /// Add a raw `IssueFilter` condition for filters the builder does not cover.
+    pub fn raw(self, field: &str, condition: Value) -> Self {
+        self.with(field, condition)
+    }
+
+    /// Add the conditions of `other`; its selection, paging, and order are ignored.
+    pub fn and(mut self, other: IssueQuery) -> Self {
+        self.conditions.extend(other.conditions);
+        self
+    }
+
+    pub fn select(mut self, fields: IssueFields) -> Self {
+        self.fields = fields;
+        self
+    }
+
+    /// Page size; Linear caps this at 250.
+    pub fn first(mut self, first: usize) -> Self {
+        self.first = first.max(1);
+        self
+    }
+
+    pub fn after(mut self, cursor: impl Into<String>) -> Self {
+        self.after = Some(cursor.into());
+        self
+    }
+
+    pub fn order_by(mut self, order: IssueOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    pub fn include_archived(mut self, include: bool) -> Self {
+        self.include_archived = include;
+        self
+    }
+
+    /// The compiled `IssueFilter`, or `None` without conditions.
+    ///
+    /// Conditions on distinct fields share one object; repeated fields are combined with `and`.
+    pub fn filter(&self) -> Option<Value> {
+        if self.conditions.is_empty() {
+            return None;
+        }
+        let mut merged = Map::new();
+        let mut repeated = false;
+        for (field, condition) in &self.conditions {
+            repeated |= merged.insert(field.clone(), condition.clone()).is_some();
+        }
+        if !repeated {
+            return Some(Value::Object(merged));
+        }
+        let all = self
+            .conditions
+            .iter()
+            .map(|(field, condition)| json!({ field.as_str(): condition }))
+            .collect::<Vec<_>>();
+        Some(json!({ "and": all }))
+    }
+
+    /// GraphQL document for this query.
+    pub fn document(&self) -> String {
+        format!(
+            "query IssueQuery($first: Int!, $filter: IssueFilter, $after: String, \
+             $orderBy: PaginationOrderBy, $includeArchived: Boolean) {{ \
+             issues(first: $first, filter: $filter, after: $after, orderBy: $orderBy, \
+             includeArchived: $includeArchived) {{ nodes {{ {} }} \
+             pageInfo {{ hasNextPage endCursor }} }} }}",
+            self.fields.selection()
+        )
+    }
+
+    /// Complexity points one page of this query costs, as [`complexity`](crate::graphql::complexity)
+    /// estimates them.
+    pub fn complexity(&self) -> f64 {
+        crate::graphql::complexity(&self.document(), None, &self.variables()).unwrap_or_default()
+    }
+
+    /// Variables matching [`IssueQuery::document`].
+    pub fn variables(&self) -> Value {
+        let mut variables = json!({
+            "first": self.first,
+            "orderBy": self.order.as_graphql(),
+            "includeArchived": self.include_archived,
+        });
+        if let Some(filter) = self.filter() {
+            variables["filter"] = filter;
+        }
+        if let Some(after) = &self.after {
+            variables["after"] = json!(after);
+        }
+        variables
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compiles_conditions_to_issue_filter() {
+        let query = IssueQuery::new()
+            .team("ENG")
+            .state_type(StateType::Started)
+            .assignee_me()
+            .priority_at_least(Priority::High);
+        let filter = query.filter().unwrap();
+        assert_eq!(filter["team"]["key"]["eq"], "ENG");
+        assert_eq!(filter["state"]["type"]["eq"], "started");
+        assert_eq!(filter["assignee"]["isMe"]["eq"], true);
+        assert_eq!(filter["priority"]["in"], json!([1, 2]));
+
+        let filter = IssueQuery::new()
+            .label("bug")
+            .label("regression")
+            .open()
+            .filter()
+            .unwrap();
+        let all = filter["and"].as_array().unwrap();
+        assert_eq!(all.len(), 3);
+        assert_eq!(
+            all[1]["labels"]["some"]["name"]["eqIgnoreCase"],
+            "regression"
+        );
+        assert!(IssueQuery::new().filter().is_none());
+    }
+
+    #[test]
+    fn selects_requested_fields() {
+        let query = IssueQuery::new()
+            .select(IssueFields::IDENTIFIER | IssueFields::LABELS)
+            .first(5)
+            .after("cursor");
+        let document = query.document();
+        assert!(document.contains("nodes { id identifier labels { nodes { id name color } } }"));
+        assert!(!document.contains("title"));
+        let variables = query.variables();
+        assert_eq!(variables["first"], 5);
+        assert_eq!(variables["after"], "cursor");
+        assert_eq!(variables["orderBy"], "updatedAt");
+        assert!(variables.get("filter").is_none());
+        assert!(IssueFields::SUMMARY.contains(IssueFields::STATE));
+    }
+}
@@ -0,0 +1,188 @@
+//! Local reminders behind `linear remind`, stored per profile and surfaced by
+//! `notify watch` and `daemon run` once due.
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use chrono::{DateTime, Utc};
+use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// A note to come back to an issue at `due_at`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Reminder {
+    pub id: u64,
+    pub issue: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub due_at: DateTime<Utc>,
+}
+
+impl Reminder {
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        self.due_at <= now
+    }
+}
+
+/// Reminders that have not been surfaced yet, soonest first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Reminders {
+    /// Ids are never reused, so `remind cancel` cannot hit a newer reminder by mistake.
+    #[serde(default)]
+    pub next_id: u64,
+    #[serde(default)]
+    pub pending: Vec<Reminder>,
+}
+
+impl Reminders {
+    /// Add a reminder and return it with its id.
+    pub fn add(
+        &mut self,
+        issue: impl Into<String>,
+        title: Option<String>,
+        url: Option<String>,
+        note: Option<String>,
+        now: DateTime<Utc>,
+        due_at: DateTime<Utc>,
+    ) -> Reminder {
+        self.next_id += 1;
+        let reminder = Reminder {
+            id: self.next_id,
+            issue: issue.into(),
+            title,
+            url,
+            note,
+            created_at: now,
+            due_at,
+        };
+        let index = self.pending.partition_point(|other| other.due_at <= due_at);
+        self.pending.insert(index, reminder.clone());
+        reminder
+    }
+
+    /// Remove the reminder with `id`, returning it.
+    pub fn cancel(&mut self, id: u64) -> Option<Reminder> {
+        let index = self.pending.iter().position(|reminder| reminder.id == id)?;
+        Some(self.pending.remove(index))
+    }
+
+    /// Remove and return the reminders due at `now`, oldest due first.
+    pub fn take_due(&mut self, now: DateTime<Utc>) -> Vec<Reminder> {
+        let split = self
+            .pending
+            .partition_point(|reminder| reminder.is_due(now));
+        self.pending.drain(..split).collect()
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum RemindError {
+    #[error("failed to access reminders: {0}")]
+    Io(#[from] io::Error),
+    #[error("reminders file is corrupt: {0}")]
+    Parse(#[from] serde_json::Error),
+}
+
+/// JSON file holding one profile's [`Reminders`].
+#[derive(Debug, Clone)]
+pub struct ReminderStore {
+    path: PathBuf,
+}
+
+impl ReminderStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Store for `profile` under the platform data directory.
+    pub fn for_profile(profile: &str) -> Option<Self> {
+        let dirs = ProjectDirs::from("app", "linear", "linear-rs")?;
+        Some(Self::new(
+            dirs.data_dir()
+                .join("reminders")
+                .join(format!("{profile}.json")),
+        ))
+    }
+
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    /// Load the reminders, treating a missing file as none.
+    pub fn load(&self) -> Result<Reminders, RemindError> {
+        match fs::read(&self.path) {
+            Ok(raw) => Ok(serde_json::from_slice(&raw)?),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Reminders::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Replace the reminders on disk atomically.
+    pub fn save(&self, reminders: &Reminders) -> Result<(), RemindError> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let temp = self.path.with_extension("json.tmp");
+        fs::write(&temp, serde_json::to_vec_pretty(reminders)?)?;
+        fs::rename(temp, &self.path)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn day(day: u32) -> DateTime<Utc> {
+        format!("2024-05-{day:02}T09:00:00Z").parse().unwrap()
+    }
+
+    #[test]
+    fn hands_out_due_reminders_once() {
+        let mut reminders = Reminders::default();
+        reminders.add("ENG-2", None, None, None, day(1), day(5));
+        let first = reminders.add("ENG-1", None, None, Some("ping QA".into()), day(1), day(3));
+        reminders.add("ENG-3", None, None, None, day(1), day(9));
+        assert_eq!(first.id, 2);
+        assert_eq!(reminders.pending[0].issue, "ENG-1");
+
+        assert!(reminders.take_due(day(2)).is_empty());
+        let due: Vec<_> = reminders
+            .take_due(day(5))
+            .into_iter()
+            .map(|reminder| reminder.issue)
+            .collect();
+        assert_eq!(due, ["ENG-1", "ENG-2"]);
+        assert!(reminders.take_due(day(5)).is_empty());
+
+        assert_eq!(reminders.cancel(3).unwrap().issue, "ENG-3");
+        assert!(reminders.cancel(3).is_none());
+        assert_eq!(
+            reminders.add("ENG-4", None, None, None, day(6), day(7)).id,
+            4
+        );
+    }
+
+    #[test]
+    fn round_trips_through_store() {
+        let temp = TempDir::new().unwrap();
+        let store = ReminderStore::new(temp.path().join("reminders").join("default.json"));
+        assert!(store.load().unwrap().pending.is_empty());
+        let mut reminders = Reminders::default();
+        reminders.add("ENG-1", None, None, None, day(1), day(2));
+        store.save(&reminders).unwrap();
+        let loaded = store.load().unwrap();
+        assert_eq!(loaded.pending[0].issue, "ENG-1");
+        assert_eq!(loaded.next_id, 1);
+    }
+}
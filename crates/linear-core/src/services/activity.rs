@@ -0,0 +1,474 @@
+use chrono::{DateTime, Local, NaiveDate, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::budget::Estimate;
+use crate::config::parse_duration;
+use crate::graphql::{
+    CommentConnection, GraphqlResult, IssueHistoryConnection, LinearGraphqlClient, TeamSummary,
+    UserSummary,
+};
+use crate::query::{IssueFields, IssueQuery};
+
+const PAGE_SIZE: usize = 50;
+
+/// What happened in an [`ActivityEvent`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ActivityKind {
+    Created,
+    StateChanged {
+        from: String,
+        to: String,
+    },
+    Commented {
+        body: String,
+    },
+    LabelAdded {
+        label: String,
+    },
+    /// Not updated for `days`; reported by [`ActivityService::stale`], never by the feed.
+    Stale {
+        days: i64,
+    },
+}
+
+impl ActivityKind {
+    pub const NAMES: [&'static str; 5] = [
+        "created",
+        "state_changed",
+        "commented",
+        "label_added",
+        "stale",
+    ];
+
+    /// The serialized `kind` tag.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ActivityKind::Created => "created",
+            ActivityKind::StateChanged { .. } => "state_changed",
+            ActivityKind::Commented { .. } => "commented",
+            ActivityKind::LabelAdded { .. } => "label_added",
+            ActivityKind::Stale { .. } => "stale",
+        }
+    }
+}
+
+/// One entry of a team activity feed.
+#[derive(Debug, Clone, Serialize)]
+pub struct ActivityEvent {
+    pub at: DateTime<Utc>,
+    pub issue: String,
+    pub title: String,
+    pub url: Option<String>,
+    /// Team key.
+    pub team: Option<String>,
+    pub priority: Option<i32>,
+    pub actor: Option<String>,
+    #[serde(flatten)]
+    pub kind: ActivityKind,
+}
+
+/// Chronological events since `since`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ActivityFeed {
+    pub since: DateTime<Utc>,
+    pub events: Vec<ActivityEvent>,
+    /// More updated issues matched than were scanned.
+    pub truncated: bool,
+}
+
+/// Issue with the timelines an activity feed is built from.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueActivity {
+    pub id: String,
+    pub identifier: String,
+    pub title: String,
+    pub url: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    #[serde(default)]
+    pub priority: Option<i32>,
+    #[serde(default)]
+    pub team: Option<TeamSummary>,
+    #[serde(default)]
+    pub creator: Option<UserSummary>,
+    #[serde(default)]
+    pub comments: Option<CommentConnection>,
+    #[serde(default)]
+    pub history: Option<IssueHistoryConnection>,
+}
+
+impl IssueActivity {
+    /// Selection matching this struct, for [`IssueQuery::select`].
+    pub fn fields() -> IssueFields {
+        IssueFields::IDENTIFIER
+            | IssueFields::TITLE
+            | IssueFields::URL
+            | IssueFields::CREATED_AT
+            | IssueFields::UPDATED_AT
+            | IssueFields::PRIORITY
+            | IssueFields::TEAM
+            | IssueFields::CREATOR
+            | IssueFields::COMMENTS
+            | IssueFields::HISTORY
+    }
+
+    /// Creation, state changes, label additions, and comments at or after `since`.
+    pub fn events(&self, since: DateTime<Utc>) -> Vec<ActivityEvent> {
+        let event = |at, actor: Option<&UserSummary>, kind| ActivityEvent {
+            at,
+            issue: self.identifier.clone(),
+            title: self.title.clone(),
+            url: self.url.clone(),
+            team: self.team.as_ref().map(|team| team.key.clone()),
+            priority: self.priority,
+            actor: actor.and_then(user_label),
+            kind,
+        };
+        let mut events = Vec::new();
+        if self.created_at >= since {
+            events.push(event(
+                self.created_at,
+                self.creator.as_ref(),
+                ActivityKind::Created,
+            ));
+        }
+        for entry in self.history.iter().flat_map(|history| &history.nodes) {
+            if entry.created_at >= since {
+                for label in entry.added_labels.iter().flatten() {
+                    events.push(event(
+                        entry.created_at,
+                        entry.actors.first(),
+                        ActivityKind::LabelAdded {
+                            label: label.name.clone(),
+                        },
+                    ));
+                }
+            }
+            // Entries without a previous state record the initial state, covered by `Created`.
+            let (Some(from), Some(to)) = (&entry.from_state, &entry.to_state) else {
+                continue;
+            };
+            if entry.created_at >= since && from.id != to.id {
+                events.push(event(
+                    entry.created_at,
+                    entry.actors.first(),
+                    ActivityKind::StateChanged {
+                        from: from.name.clone(),
+                        to: to.name.clone(),
+                    },
+                ));
+            }
+        }
+        for comment in self.comments.iter().flat_map(|comments| &comments.nodes) {
+            if comment.created_at >= since {
+                events.push(event(
+                    comment.created_at,
+                    comment.user.as_ref(),
+                    ActivityKind::Commented {
+                        body: comment.body.clone(),
+                    },
+                ));
+            }
+        }
+        events
+    }
+}
+
+fn user_label(user: &UserSummary) -> Option<String> {
+    user.display_name.clone().or_else(|| user.name.clone())
+}
+
+/// Start of a feed window: a duration back from `now` (`24h`, `7d`), a date (local midnight),
+/// or an RFC 3339 timestamp.
+pub fn parse_since(raw: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>, String> {
+    let raw = raw.trim();
+    if let Ok(at) = DateTime::parse_from_rfc3339(raw) {
+        return Ok(at.with_timezone(&Utc));
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(raw, "%Y-%m-%d") {
+        return Local
+            .from_local_datetime(&date.and_time(Default::default()))
+            .earliest()
+            .map(|at| at.with_timezone(&Utc))
+            .ok_or_else(|| format!("'{raw}' has no local midnight"));
+    }
+    let window = parse_duration(raw).map_err(|_| {
+        format!("invalid --since '{raw}' (expected e.g. 24h, 7d, 2024-05-01, or an RFC 3339 time)")
+    })?;
+    chrono::Duration::from_std(window)
+        .ok()
+        .and_then(|window| now.checked_sub_signed(window))
+        .ok_or_else(|| format!("--since '{raw}' is too far back"))
+}
+
+#[derive(Clone)]
+pub struct ActivityService {
+    client: LinearGraphqlClient,
+}
+
+impl ActivityService {
+    pub fn new(client: LinearGraphqlClient) -> Self {
+        Self { client }
+    }
+
+    /// What [`ActivityService::feed`] costs for up to `max_issues` updated issues.
+    pub fn estimate(max_issues: usize) -> Estimate {
+        let page = IssueQuery::new()
+            .select(IssueActivity::fields())
+            .first(PAGE_SIZE.min(max_issues));
+        Estimate::pages(Some(max_issues), PAGE_SIZE, page.complexity())
+    }
+
+    /// Events on issues updated since `since`, oldest first, scanning at most `max_issues`.
+    ///
+    /// Each issue contributes its latest 50 comments and history entries, which covers any
+    /// realistic standup window.
+    pub async fn feed(
+        &self,
+        team_id: Option<&str>,
+        since: DateTime<Utc>,
+        max_issues: usize,
+    ) -> GraphqlResult<ActivityFeed> {
+        let mut filter = IssueQuery::new();
+        if let Some(team_id) = team_id {
+            filter = filter.team_id(team_id);
+        }
+        self.feed_matching(filter, since, max_issues).await
+    }
+
+    /// Like [`ActivityService::feed`], for the issues matching the conditions of `filter`.
+    pub async fn feed_matching(
+        &self,
+        filter: IssueQuery,
+        since: DateTime<Utc>,
+        max_issues: usize,
+    ) -> GraphqlResult<ActivityFeed> {
+        let base = IssueQuery::new()
+            .updated_since(since)
+            .and(filter)
+            .select(IssueActivity::fields());
+
+        let mut events = Vec::new();
+        let mut scanned = 0;
+        let mut cursor: Option<String> = None;
+        let truncated = loop {
+            let mut query = base
+                .clone()
+                .first(PAGE_SIZE.min(max_issues.saturating_sub(scanned)));
+            if let Some(after) = cursor.take() {
+                query = query.after(after);
+            }
+            let page = self.client.query_issues::<IssueActivity>(&query).await?;
+            scanned += page.nodes.len();
+            events.extend(page.nodes.iter().flat_map(|issue| issue.events(since)));
+            match page.end_cursor {
+                Some(after) if page.has_next_page => {
+                    if scanned >= max_issues {
+                        break true;
+                    }
+                    cursor = Some(after);
+                }
+                _ => break false,
+            }
+        };
+
+        events.sort_by(|a, b| a.at.cmp(&b.at).then_with(|| a.issue.cmp(&b.issue)));
+        Ok(ActivityFeed {
+            since,
+            events,
+            truncated,
+        })
+    }
+
+    /// Open issues not updated since `before`, as [`ActivityKind::Stale`] events stamped with
+    /// their last update, up to `limit` issues.
+    pub async fn stale(
+        &self,
+        team_key: Option<&str>,
+        before: DateTime<Utc>,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> GraphqlResult<Vec<ActivityEvent>> {
+        let mut query = IssueQuery::new()
+            .open()
+            .raw("updatedAt", json!({ "lt": before.to_rfc3339() }))
+            .select(
+                IssueFields::IDENTIFIER
+                    | IssueFields::TITLE
+                    | IssueFields::URL
+                    | IssueFields::CREATED_AT
+                    | IssueFields::UPDATED_AT
+                    | IssueFields::PRIORITY
+                    | IssueFields::TEAM,
+            )
+            .first(limit);
+        if let Some(team_key) = team_key {
+            query = query.team(team_key);
+        }
+        let page = self.client.query_issues::<IssueActivity>(&query).await?;
+        Ok(page
+            .nodes
+            .into_iter()
+            .map(|issue| ActivityEvent {
+                at: issue.updated_at,
+                kind: ActivityKind::Stale {
+                    days: (now - issue.updated_at).num_days(),
+                },
+                issue: issue.identifier,
+                title: issue.title,
+                url: issue.url,
+                team: issue.team.map(|team| team.key),
+                priority: issue.priority,
+                actor: None,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::AuthSession;
+    use httpmock::prelude::*;
+
+    #[test]
+    fn parses_since_windows() {
+        let now = DateTime::parse_from_rfc3339("2024-05-02T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(
+            parse_since("24h", now).unwrap().to_rfc3339(),
+            "2024-05-01T12:00:00+00:00"
+        );
+        assert_eq!(
+            parse_since("2d", now).unwrap().to_rfc3339(),
+            "2024-04-30T12:00:00+00:00"
+        );
+        assert_eq!(
+            parse_since("2024-05-01T08:30:00+02:00", now)
+                .unwrap()
+                .to_rfc3339(),
+            "2024-05-01T06:30:00+00:00"
+        );
+        assert!(parse_since("yesterday", now).is_err());
+    }
+
+    #[tokio::test]
+    async fn builds_chronological_feed_from_updated_issues() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/graphql")
+                .body_contains("history(last: 50)")
+                .body_contains("updatedAt");
+            then.status(200).json_body_obj(&serde_json::json!({
+                "data": { "issues": {
+                    "nodes": [{
+                        "id": "issue-1",
+                        "identifier": "ENG-1",
+                        "title": "Login fails",
+                        "url": "https://linear.app/acme/issue/ENG-1",
+                        "createdAt": "2024-05-02T09:00:00Z",
+                        "updatedAt": "2024-05-02T11:00:00Z",
+                        "creator": { "id": "u1", "name": "Ada Lovelace", "displayName": "ada" },
+                        "comments": { "nodes": [{
+                            "id": "c1",
+                            "body": "Repro attached",
+                            "createdAt": "2024-05-02T10:00:00Z",
+                            "updatedAt": "2024-05-02T10:00:00Z",
+                            "user": { "id": "u2", "name": "Grace Hopper", "displayName": null }
+                        }] },
+                        "history": { "nodes": [{
+                            "__typename": "IssueHistory",
+                            "id": "h1",
+                            "createdAt": "2024-05-02T09:00:00Z",
+                            "updatedAt": "2024-05-02T09:00:00Z",
+                            "toState": { "id": "s1", "name": "Todo", "type": "unstarted" }
+                        }, {
+                            "__typename": "IssueHistory",
+                            "id": "h2",
+                            "createdAt": "2024-05-02T11:00:00Z",
+                            "updatedAt": "2024-05-02T11:00:00Z",
+                            "fromState": { "id": "s1", "name": "Todo", "type": "unstarted" },
+                            "toState": { "id": "s2", "name": "In Progress", "type": "started" },
+                            "actors": [{ "id": "u1", "name": "Ada Lovelace", "displayName": "ada" }]
+                        }] }
+                    }, {
+                        "id": "issue-2",
+                        "identifier": "ENG-2",
+                        "title": "Old issue, new comment",
+                        "createdAt": "2024-04-01T09:00:00Z",
+                        "updatedAt": "2024-05-02T09:30:00Z",
+                        "comments": { "nodes": [{
+                            "id": "c2",
+                            "body": "Too old",
+                            "createdAt": "2024-04-02T09:00:00Z",
+                            "updatedAt": "2024-04-02T09:00:00Z"
+                        }, {
+                            "id": "c3",
+                            "body": "Still relevant",
+                            "createdAt": "2024-05-02T09:30:00Z",
+                            "updatedAt": "2024-05-02T09:30:00Z"
+                        }] }
+                    }],
+                    "pageInfo": { "hasNextPage": true, "endCursor": "cursor-1" }
+                } }
+            }));
+        });
+
+        let client = LinearGraphqlClient::with_endpoint(
+            &AuthSession::new_api_key("test-key".into()),
+            &server.url("/graphql"),
+        )
+        .unwrap();
+        let since = DateTime::parse_from_rfc3339("2024-05-01T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let feed = ActivityService::new(client)
+            .feed(Some("team-1"), since, 2)
+            .await
+            .unwrap();
+
+        mock.assert();
+        assert!(feed.truncated);
+        let summary: Vec<_> = feed
+            .events
+            .iter()
+            .map(|event| (event.issue.as_str(), event.actor.as_deref(), &event.kind))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("ENG-1", Some("ada"), &ActivityKind::Created),
+                (
+                    "ENG-2",
+                    None,
+                    &ActivityKind::Commented {
+                        body: "Still relevant".into()
+                    }
+                ),
+                (
+                    "ENG-1",
+                    Some("Grace Hopper"),
+                    &ActivityKind::Commented {
+                        body: "Repro attached".into()
+                    }
+                ),
+                (
+                    "ENG-1",
+                    Some("ada"),
+                    &ActivityKind::StateChanged {
+                        from: "Todo".into(),
+                        to: "In Progress".into()
+                    }
+                ),
+            ]
+        );
+        let json = serde_json::to_value(&feed.events[3]).unwrap();
+        assert_eq!(json["kind"], "state_changed");
+        assert_eq!(json["to"], "In Progress");
+    }
+}
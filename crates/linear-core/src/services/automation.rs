@@ -0,0 +1,478 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::graphql::{GraphqlError, GraphqlResult, IssueUpdateInput, LinearGraphqlClient};
+use crate::mention;
+use crate::notify::{self, EventFilter};
+use crate::priority::Priority;
+use crate::services::activity::{ActivityEvent, ActivityKind, ActivityService};
+use crate::services::issues::IssueService;
+
+/// Local triage rules (`rules.toml`).
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RuleSet {
+    #[serde(default, rename = "rule")]
+    pub rules: Vec<Rule>,
+}
+
+impl RuleSet {
+    pub fn from_toml(raw: &str) -> Result<Self, String> {
+        let rules: Self = toml::from_str(raw).map_err(|err| err.to_string())?;
+        for rule in &rules.rules {
+            rule.validate()
+                .map_err(|err| format!("rule '{}': {}", rule.name, err))?;
+        }
+        Ok(rules)
+    }
+}
+
+/// Issue event a rule reacts to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Trigger {
+    Created,
+    StateChanged,
+    Commented,
+    LabelAdded,
+    /// Open issues without updates for `stale_days`.
+    Stale,
+}
+
+/// `when <trigger> [matching conditions] then <actions>`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Rule {
+    pub name: String,
+    pub on: Trigger,
+    /// Team key the rule is limited to.
+    pub team: Option<String>,
+    /// `label_added`: only this label.
+    pub label: Option<String>,
+    /// `state_changed`: only moves into this state.
+    pub to_state: Option<String>,
+    /// `stale`: days without updates.
+    pub stale_days: Option<u32>,
+    /// Extra conditions in `notify forward --filter` syntax, e.g. `priority>=high`.
+    #[serde(default, deserialize_with = "filter_expr")]
+    pub filter: EventFilter,
+    pub actions: Actions,
+}
+
+/// What a matching rule does to the issue.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Actions {
+    /// `me`, or a team member's display name, name, or email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assign: Option<String>,
+    /// Comment template with the `notify` placeholders (`{issue}`, `{actor}`, `{days}`, ...);
+    /// `@handles` of team members become mentions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<String>,
+    /// Workflow state name in the issue's team.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+}
+
+impl Actions {
+    fn is_empty(&self) -> bool {
+        self.assign.is_none()
+            && self.comment.is_none()
+            && self.priority.is_none()
+            && self.state.is_none()
+    }
+}
+
+fn filter_expr<'de, D: Deserializer<'de>>(deserializer: D) -> Result<EventFilter, D::Error> {
+    let raw = String::deserialize(deserializer)?;
+    raw.parse().map_err(serde::de::Error::custom)
+}
+
+impl Rule {
+    fn validate(&self) -> Result<(), String> {
+        if self.actions.is_empty() {
+            return Err("no actions".into());
+        }
+        if let Some(priority) = &self.actions.priority {
+            priority.parse::<Priority>()?;
+        }
+        let misplaced =
+            |field: &str, on: &str| Err(format!("`{field}` only applies to on = \"{on}\""));
+        if self.label.is_some() && self.on != Trigger::LabelAdded {
+            return misplaced("label", "label_added");
+        }
+        if self.to_state.is_some() && self.on != Trigger::StateChanged {
+            return misplaced("to_state", "state_changed");
+        }
+        match (self.on, self.stale_days) {
+            (Trigger::Stale, None) | (Trigger::Stale, Some(0)) => {
+                Err("on = \"stale\" needs stale_days of at least 1".into())
+            }
+            (Trigger::Stale, Some(_)) => Ok(()),
+            (_, Some(_)) => misplaced("stale_days", "stale"),
+            _ => Ok(()),
+        }
+    }
+
+    /// Whether `event` triggers this rule.
+    pub fn matches(&self, event: &ActivityEvent) -> bool {
+        let eq = |expected: &Option<String>, actual: &str| {
+            expected
+                .as_deref()
+                .map_or(true, |expected| expected.eq_ignore_ascii_case(actual))
+        };
+        let triggered = match (&event.kind, self.on) {
+            (ActivityKind::Created, Trigger::Created)
+            | (ActivityKind::Commented { .. }, Trigger::Commented)
+            | (ActivityKind::Stale { .. }, Trigger::Stale) => true,
+            (ActivityKind::StateChanged { to, .. }, Trigger::StateChanged) => {
+                eq(&self.to_state, to)
+            }
+            (ActivityKind::LabelAdded { label }, Trigger::LabelAdded) => eq(&self.label, label),
+            _ => false,
+        };
+        triggered
+            && self.team.as_deref().map_or(true, |team| {
+                event
+                    .team
+                    .as_deref()
+                    .is_some_and(|key| key.eq_ignore_ascii_case(team))
+            })
+            && self.filter.matches(event)
+    }
+}
+
+/// A rule matched an event and will run its actions on the issue.
+#[derive(Debug, Clone, Serialize)]
+pub struct PlannedRun {
+    pub rule: String,
+    pub event: ActivityEvent,
+    pub actions: Actions,
+}
+
+/// What applying a [`PlannedRun`] changed.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RunOutcome {
+    /// Human-readable changes, e.g. `state → Done`.
+    pub changes: Vec<String>,
+    /// Actions that were already satisfied.
+    pub skipped: Vec<String>,
+}
+
+/// Issues scanned per poll or stale query.
+const SCAN_LIMIT: usize = 250;
+
+#[derive(Clone)]
+pub struct AutomationService {
+    client: LinearGraphqlClient,
+    issues: IssueService,
+    activity: ActivityService,
+}
+
+impl AutomationService {
+    pub fn new(client: LinearGraphqlClient) -> Self {
+        Self {
+            issues: IssueService::new(client.clone()),
+            activity: ActivityService::new(client.clone()),
+            client,
+        }
+    }
+
+    /// Match `rules` against events since `since` and currently stale issues.
+    ///
+    /// Each issue is planned at most once per rule, so a burst of events does not comment
+    /// twice. Returns the plan and the feed events scanned, for advancing a watermark.
+    pub async fn plan(
+        &self,
+        rules: &RuleSet,
+        since: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> GraphqlResult<(Vec<PlannedRun>, Vec<ActivityEvent>)> {
+        let mut candidates = Vec::new();
+        if rules.rules.iter().any(|rule| rule.on != Trigger::Stale) {
+            let feed = self.activity.feed(None, since, SCAN_LIMIT).await?;
+            candidates.extend(feed.events);
+        }
+        let events = candidates.clone();
+        for rule in rules.rules.iter().filter(|rule| rule.on == Trigger::Stale) {
+            let days = i64::from(rule.stale_days.unwrap_or(1));
+            candidates.extend(
+                self.activity
+                    .stale(
+                        rule.team.as_deref(),
+                        now - Duration::days(days),
+                        now,
+                        SCAN_LIMIT,
+                    )
+                    .await?,
+            );
+        }
+
+        let mut plan: Vec<PlannedRun> = Vec::new();
+        for rule in &rules.rules {
+            for event in candidates.iter().filter(|event| rule.matches(event)) {
+                if plan
+                    .iter()
+                    .any(|run| run.rule == rule.name && run.event.issue == event.issue)
+                {
+                    continue;
+                }
+                plan.push(PlannedRun {
+                    rule: rule.name.clone(),
+                    event: event.clone(),
+                    actions: rule.actions.clone(),
+                });
+            }
+        }
+        Ok((plan, events))
+    }
+
+    /// Run one planned rule against the current issue, skipping actions already satisfied.
+    pub async fn apply(&self, run: &PlannedRun) -> GraphqlResult<RunOutcome> {
+        let issue = self.issues.get_by_key(&run.event.issue).await?;
+        let team_id = issue.team.as_ref().map(|team| team.id.clone());
+        let mut outcome = RunOutcome::default();
+        let mut input = IssueUpdateInput::default();
+        let mut update = false;
+
+        if let Some(name) = &run.actions.state {
+            let team_id = team_id.as_deref().ok_or(GraphqlError::NotFound)?;
+            let state_id = self
+                .issues
+                .resolve_state_id(team_id, name)
+                .await?
+                .ok_or_else(|| {
+                    GraphqlError::OperationFailed(format!("state '{name}' not found"))
+                })?;
+            if issue
+                .state
+                .as_ref()
+                .is_some_and(|state| state.id == state_id)
+            {
+                outcome.skipped.push(format!("state already {name}"));
+            } else {
+                input.state_id = Some(state_id);
+                outcome.changes.push(format!("state → {name}"));
+                update = true;
+            }
+        }
+        if let Some(priority) = &run.actions.priority {
+            let priority: Priority = priority.parse().map_err(GraphqlError::OperationFailed)?;
+            if issue.priority == Some(priority.value()) {
+                outcome.skipped.push(format!("priority already {priority}"));
+            } else {
+                input.priority = Some(priority.value());
+                outcome.changes.push(format!("priority → {priority}"));
+                update = true;
+            }
+        }
+        if let Some(who) = &run.actions.assign {
+            let assignee_id = self.resolve_user(team_id.as_deref(), who).await?;
+            if issue
+                .assignee
+                .as_ref()
+                .is_some_and(|assignee| assignee.id == assignee_id)
+            {
+                outcome.skipped.push(format!("already assigned to {who}"));
+            } else {
+                input.assignee_id = Some(assignee_id);
+                outcome.changes.push(format!("assign → {who}"));
+                update = true;
+            }
+        }
+        if update {
+            self.issues.update(&issue.id, input).await?;
+        }
+
+        if let Some(template) = &run.actions.comment {
+            let mut body = notify::render(template, &run.event);
+            if let Some(team_id) = team_id.as_deref() {
+                let members = self.issues.team_members(team_id).await?;
+                body = mention::expand(&body, &members);
+            }
+            self.issues.comment(&issue.id, &body).await?;
+            outcome.changes.push("comment".into());
+        }
+        Ok(outcome)
+    }
+
+    async fn resolve_user(&self, team_id: Option<&str>, who: &str) -> GraphqlResult<String> {
+        if who.eq_ignore_ascii_case("me") {
+            return Ok(self.client.viewer().await?.id);
+        }
+        let team_id = team_id.ok_or(GraphqlError::NotFound)?;
+        let handle = who.trim_start_matches('@');
+        self.issues
+            .team_members(team_id)
+            .await?
+            .into_iter()
+            .find(|member| {
+                member.display_name.eq_ignore_ascii_case(handle)
+                    || member.name.eq_ignore_ascii_case(handle)
+                    || member
+                        .email
+                        .as_deref()
+                        .is_some_and(|email| email.eq_ignore_ascii_case(handle))
+            })
+            .map(|member| member.id)
+            .ok_or_else(|| GraphqlError::OperationFailed(format!("no team member matches '{who}'")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::AuthSession;
+    use httpmock::prelude::*;
+
+    const RULES: &str = r#"
+        [[rule]]
+        name = "escalate bugs"
+        on = "label_added"
+        label = "bug"
+        team = "ENG"
+        filter = "priority>=high"
+        actions = { priority = "urgent", state = "Todo", comment = "Escalated by rule after {actor} added the label" }
+
+        [[rule]]
+        name = "nudge stale"
+        on = "stale"
+        stale_days = 14
+        actions = { comment = "No updates for {days} days" }
+    "#;
+
+    fn label_event(label: &str, priority: i32) -> ActivityEvent {
+        ActivityEvent {
+            at: Utc::now(),
+            issue: "ENG-1".into(),
+            title: "Login fails".into(),
+            url: None,
+            team: Some("ENG".into()),
+            priority: Some(priority),
+            actor: Some("ada".into()),
+            kind: ActivityKind::LabelAdded {
+                label: label.into(),
+            },
+        }
+    }
+
+    #[test]
+    fn parses_and_matches_rules() {
+        let rules = RuleSet::from_toml(RULES).unwrap();
+        let escalate = &rules.rules[0];
+        assert!(escalate.matches(&label_event("Bug", 2)));
+        assert!(!escalate.matches(&label_event("bug", 3)));
+        assert!(!escalate.matches(&label_event("docs", 1)));
+        assert!(!rules.rules[1].matches(&label_event("bug", 1)));
+
+        let err = RuleSet::from_toml(
+            r#"
+            [[rule]]
+            name = "broken"
+            on = "stale"
+            actions = { assign = "me" }
+            "#,
+        )
+        .unwrap_err();
+        assert!(err.contains("stale_days"), "{err}");
+        assert!(
+            RuleSet::from_toml("[[rule]]\nname = \"x\"\non = \"created\"\nactions = {}\n")
+                .unwrap_err()
+                .contains("no actions")
+        );
+        assert!(RuleSet::from_toml(
+            "[[rule]]\nname = \"x\"\non = \"created\"\nfilter = \"colour=red\"\nactions = { assign = \"me\" }\n"
+        )
+        .is_err());
+    }
+
+    #[tokio::test]
+    async fn applies_only_unsatisfied_actions() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(POST)
+                .path("/graphql")
+                .body_contains("IssueByKey");
+            then.status(200).json_body_obj(&serde_json::json!({
+                "data": { "issue": {
+                    "id": "issue-1",
+                    "identifier": "ENG-1",
+                    "title": "Login fails",
+                    "priority": 1,
+                    "createdAt": "2024-01-01T00:00:00Z",
+                    "updatedAt": "2024-01-01T00:00:00Z",
+                    "state": { "id": "state-triage", "name": "Triage", "type": "triage" },
+                    "team": { "id": "team-1", "name": "Engineering", "key": "ENG" }
+                } }
+            }));
+        });
+        server.mock(|when, then| {
+            when.method(POST)
+                .path("/graphql")
+                .body_contains("WorkflowStates");
+            then.status(200).json_body_obj(&serde_json::json!({
+                "data": { "team": { "states": { "nodes": [
+                    { "id": "state-todo", "name": "Todo", "type": "unstarted", "position": 1.0 }
+                ] } } }
+            }));
+        });
+        let update = server.mock(|when, then| {
+            when.method(POST)
+                .path("/graphql")
+                .body_contains("issueUpdate")
+                .body_contains("state-todo");
+            then.status(200).json_body_obj(&serde_json::json!({
+                "data": { "issueUpdate": { "success": true, "issue": {
+                    "id": "issue-1",
+                    "identifier": "ENG-1",
+                    "title": "Login fails",
+                    "createdAt": "2024-01-01T00:00:00Z",
+                    "updatedAt": "2024-01-02T00:00:00Z"
+                } } }
+            }));
+        });
+        server.mock(|when, then| {
+            when.method(POST)
+                .path("/graphql")
+                .body_contains("TeamMembers");
+            then.status(200).json_body_obj(&serde_json::json!({
+                "data": { "team": { "members": { "nodes": [] } } }
+            }));
+        });
+        let comment = server.mock(|when, then| {
+            when.method(POST)
+                .path("/graphql")
+                .body_contains("commentCreate")
+                .body_contains("after ada added the label");
+            then.status(200).json_body_obj(&serde_json::json!({
+                "data": { "commentCreate": { "success": true, "comment": {
+                    "id": "comment-1",
+                    "body": "Escalated",
+                    "createdAt": "2024-01-02T00:00:00Z",
+                    "updatedAt": "2024-01-02T00:00:00Z"
+                } } }
+            }));
+        });
+
+        let client = LinearGraphqlClient::with_endpoint(
+            &AuthSession::new_api_key("test-key".into()),
+            &server.url("/graphql"),
+        )
+        .unwrap();
+        let rules = RuleSet::from_toml(RULES).unwrap();
+        let run = PlannedRun {
+            rule: rules.rules[0].name.clone(),
+            event: label_event("bug", 1),
+            actions: rules.rules[0].actions.clone(),
+        };
+        let outcome = AutomationService::new(client).apply(&run).await.unwrap();
+
+        update.assert();
+        comment.assert();
+        assert_eq!(outcome.changes, ["state → Todo", "comment"]);
+        assert_eq!(outcome.skipped, ["priority already Urgent"]);
+    }
+}
@@ -0,0 +1,38 @@
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+use crate::backup::BackupEntity;
+use crate::graphql::LinearGraphqlClient;
+use crate::services::pagination::{paginate, Page, PageStream};
+
+/// Records per request; issue pages carry labels and descriptions, so this stays modest.
+const PAGE_SIZE: usize = 50;
+
+/// Walks every page of the entities `linear backup` saves.
+#[derive(Clone)]
+pub struct BackupService {
+    client: LinearGraphqlClient,
+}
+
+impl BackupService {
+    pub fn new(client: LinearGraphqlClient) -> Self {
+        Self { client }
+    }
+
+    /// Raw records of `entity`, archived ones included, limited to those updated at or after
+    /// `since` when given.
+    pub fn records(&self, entity: BackupEntity, since: Option<DateTime<Utc>>) -> PageStream<Value> {
+        let client = self.client.clone();
+        paginate(None, move |after| {
+            let client = client.clone();
+            async move {
+                let page = client.backup_page(entity, PAGE_SIZE, after, since).await?;
+                Ok(Page {
+                    nodes: page.nodes,
+                    end_cursor: page.end_cursor,
+                    has_next_page: page.has_next_page,
+                })
+            }
+        })
+    }
+}
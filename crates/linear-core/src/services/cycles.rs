@@ -4,6 +4,7 @@ use crate::graphql::{
     CycleListParams, CycleListResponse, CycleSummary, CycleUpdateInput, GraphqlResult,
     LinearGraphqlClient,
 };
+use crate::services::pagination::{page_size, paginate, Page, PageStream};
 
 #[derive(Clone)]
 pub struct CycleService {
@@ -20,6 +21,28 @@ impl CycleService {
         self.client.cycles(params).await
     }
 
+    /// Every cycle matching `options`, following cursors as the stream is consumed;
+    /// `options.limit` is the page size.
+    pub fn list_iter(&self, mut options: CycleQueryOptions) -> PageStream<CycleSummary> {
+        let service = self.clone();
+        options.limit = page_size(options.limit);
+        paginate(options.after.take(), move |after| {
+            let service = service.clone();
+            let options = CycleQueryOptions {
+                after,
+                ..options.clone()
+            };
+            async move {
+                let page = service.list(options).await?;
+                Ok(Page {
+                    nodes: page.nodes,
+                    end_cursor: page.end_cursor,
+                    has_next_page: page.has_next_page,
+                })
+            }
+        })
+    }
+
     pub async fn update(
         &self,
         cycle_id: &str,
@@ -0,0 +1,278 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde_json::{json, Map, Value};
+
+use crate::graphql::{GraphqlError, GraphqlResult, LinearGraphqlClient};
+
+/// Entity types `linear get` can fetch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EntityKind {
+    Issue,
+    Project,
+    Team,
+    User,
+    Document,
+}
+
+impl EntityKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            EntityKind::Issue => "issue",
+            EntityKind::Project => "project",
+            EntityKind::Team => "team",
+            EntityKind::User => "user",
+            EntityKind::Document => "document",
+        }
+    }
+
+    fn selection(self) -> &'static str {
+        match self {
+            EntityKind::Issue => {
+                "id identifier title description url branchName priority estimate dueDate \
+                 createdAt updatedAt state { id name type } assignee { id name displayName } \
+                 labels(first: 20) { nodes { id name color } } team { id name key } \
+                 project { id name }"
+            }
+            EntityKind::Project => {
+                "id name description state startDate targetDate url status progress createdAt \
+                 updatedAt lead { id name displayName } teams { nodes { id name key } }"
+            }
+            EntityKind::Team => "id name key description private timezone",
+            EntityKind::User => "id name displayName email active admin url",
+            EntityKind::Document => {
+                "id title content url createdAt updatedAt creator { id name displayName } \
+                 project { id name }"
+            }
+        }
+    }
+}
+
+impl FromStr for EntityKind {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.trim().to_ascii_lowercase().as_str() {
+            "issue" | "issues" => Ok(EntityKind::Issue),
+            "project" | "projects" => Ok(EntityKind::Project),
+            "team" | "teams" => Ok(EntityKind::Team),
+            "user" | "users" => Ok(EntityKind::User),
+            "document" | "documents" | "doc" | "docs" => Ok(EntityKind::Document),
+            other => Err(format!(
+                "unknown entity type '{other}'; expected issue, project, team, user, or document"
+            )),
+        }
+    }
+}
+
+/// One entity named as `kind/id`, e.g. `issue/ENG-123`, `team/ENG`, or `user/me`. Teams may be
+/// named by id, key, or name, and users by id, email, display name, or `me`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EntityRef {
+    pub kind: EntityKind,
+    pub id: String,
+}
+
+impl FromStr for EntityRef {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (kind, id) = input
+            .split_once('/')
+            .ok_or_else(|| format!("'{input}' is not kind/id, e.g. issue/ENG-123 or team/ENG"))?;
+        let id = id.trim();
+        if id.is_empty() {
+            return Err(format!("'{input}' names no {kind}"));
+        }
+        Ok(EntityRef {
+            kind: kind.parse()?,
+            id: id.to_owned(),
+        })
+    }
+}
+
+impl fmt::Display for EntityRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.kind.as_str(), self.id)
+    }
+}
+
+/// One aliased field per entity (`e0`, `e1`, …), each with its own variable, so a single
+/// request fetches them all.
+pub fn document(entities: &[EntityRef]) -> (String, Value) {
+    let mut declarations = Vec::new();
+    let mut fields = Vec::new();
+    let mut variables = Map::new();
+    for (index, entity) in entities.iter().enumerate() {
+        let alias = format!("e{index}");
+        let var = format!("$v{index}");
+        let selection = entity.kind.selection();
+        let by_id = is_uuid(&entity.id);
+        let field = match entity.kind {
+            EntityKind::User if entity.id.eq_ignore_ascii_case("me") => {
+                fields.push(format!("{alias}: viewer {{ {selection} }}"));
+                continue;
+            }
+            EntityKind::Team if !by_id => format!(
+                "{alias}: teams(first: 1, filter: {{ or: [{{ key: {{ eqIgnoreCase: {var} }} }}, \
+                 {{ name: {{ eqIgnoreCase: {var} }} }}] }}) {{ nodes {{ {selection} }} }}"
+            ),
+            EntityKind::User if !by_id => format!(
+                "{alias}: users(first: 1, filter: {{ or: [{{ email: {{ eqIgnoreCase: {var} }} }}, \
+                 {{ displayName: {{ eqIgnoreCase: {var} }} }}] }}) {{ nodes {{ {selection} }} }}"
+            ),
+            kind => format!("{alias}: {}(id: {var}) {{ {selection} }}", kind.as_str()),
+        };
+        declarations.push(format!("{var}: String!"));
+        fields.push(field);
+        variables.insert(format!("v{index}"), json!(entity.id));
+    }
+    let declarations = if declarations.is_empty() {
+        String::new()
+    } else {
+        format!("({})", declarations.join(", "))
+    };
+    let query = format!(
+        "query GetEntities{declarations} {{\n  {}\n}}",
+        fields.join("\n  ")
+    );
+    (query, Value::Object(variables))
+}
+
+/// The entity under alias `e{index}` of a [`document`] response; filtered lookups yield their
+/// first node.
+fn extract(data: &Value, index: usize) -> Option<Value> {
+    let value = data.get(format!("e{index}"))?;
+    let value = match value.get("nodes") {
+        Some(nodes) => nodes.get(0)?,
+        None => value,
+    };
+    (!value.is_null()).then(|| value.clone())
+}
+
+fn is_uuid(input: &str) -> bool {
+    input.len() == 36
+        && input.char_indices().all(|(index, c)| match index {
+            8 | 13 | 18 | 23 => c == '-',
+            _ => c.is_ascii_hexdigit(),
+        })
+}
+
+#[derive(Clone)]
+pub struct EntityService {
+    client: LinearGraphqlClient,
+}
+
+impl EntityService {
+    pub fn new(client: LinearGraphqlClient) -> Self {
+        Self { client }
+    }
+
+    /// Fetch every entity in one request, in the order given. Linear fails the whole request
+    /// when any one entity is missing, so then each is fetched on its own to tell which; a
+    /// missing entity is [`GraphqlError::NotFound`] or the error Linear reported for it.
+    pub async fn fetch(&self, entities: &[EntityRef]) -> GraphqlResult<Vec<GraphqlResult<Value>>> {
+        if entities.is_empty() {
+            return Ok(Vec::new());
+        }
+        let (query, variables) = document(entities);
+        match self.client.execute(&query, variables, None).await {
+            Ok(data) => Ok((0..entities.len())
+                .map(|index| extract(&data, index).ok_or(GraphqlError::NotFound))
+                .collect()),
+            Err(GraphqlError::ResponseErrors { .. }) if entities.len() > 1 => {
+                let mut results = Vec::new();
+                for entity in entities {
+                    let (query, variables) = document(std::slice::from_ref(entity));
+                    results.push(match self.client.execute(&query, variables, None).await {
+                        Ok(data) => extract(&data, 0).ok_or(GraphqlError::NotFound),
+                        Err(err @ GraphqlError::ResponseErrors { .. }) => Err(err),
+                        Err(err) => return Err(err),
+                    });
+                }
+                Ok(results)
+            }
+            Err(err @ GraphqlError::ResponseErrors { .. }) => Ok(vec![Err(err)]),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::AuthSession;
+    use httpmock::prelude::*;
+
+    #[test]
+    fn builds_one_aliased_document() {
+        let entities: Vec<EntityRef> = ["issue/ENG-1", "team/ENG", "user/me", "docs/abc"]
+            .iter()
+            .map(|input| input.parse().unwrap())
+            .collect();
+        let (query, variables) = document(&entities);
+        assert!(query.starts_with(
+            "query GetEntities($v0: String!, $v1: String!, $v3: String!) {\n  e0: issue(id: $v0) {"
+        ));
+        assert!(query.contains("e1: teams(first: 1, filter: { or: [{ key: { eqIgnoreCase: $v1 } }"));
+        assert!(query.contains("e2: viewer {"));
+        assert!(query.contains("e3: document(id: $v3) {"));
+        assert_eq!(
+            variables,
+            json!({ "v0": "ENG-1", "v1": "ENG", "v3": "abc" })
+        );
+        assert_eq!(entities[3].to_string(), "document/abc");
+        assert!("cycle/1".parse::<EntityRef>().is_err());
+        assert!("issue/".parse::<EntityRef>().is_err());
+        assert!("ENG-1".parse::<EntityRef>().is_err());
+    }
+
+    #[tokio::test]
+    async fn falls_back_to_one_request_per_entity_when_one_is_missing() {
+        let server = MockServer::start();
+        let batch = server.mock(|when, then| {
+            when.method(POST).path("/graphql").body_contains("e1: team");
+            then.status(200).json_body_obj(&json!({
+                "data": null,
+                "errors": [{ "message": "Entity not found", "path": ["e0"] }]
+            }));
+        });
+        let issue = server.mock(|when, then| {
+            when.method(POST)
+                .path("/graphql")
+                .body_contains("($v0: String!) {\\n  e0: issue");
+            then.status(200).json_body_obj(&json!({
+                "data": null,
+                "errors": [{ "message": "Entity not found", "path": ["e0"] }]
+            }));
+        });
+        let team = server.mock(|when, then| {
+            when.method(POST)
+                .path("/graphql")
+                .body_contains("e0: teams");
+            then.status(200).json_body_obj(&json!({
+                "data": { "e0": { "nodes": [{ "id": "team-1", "key": "ENG", "name": "Eng" }] } }
+            }));
+        });
+
+        let client = LinearGraphqlClient::with_endpoint(
+            &AuthSession::new_api_key("test-key".into()),
+            &server.url("/graphql"),
+        )
+        .unwrap();
+        let entities = [
+            "issue/ENG-404".parse().unwrap(),
+            "team/eng".parse().unwrap(),
+        ];
+        let results = EntityService::new(client).fetch(&entities).await.unwrap();
+
+        batch.assert();
+        issue.assert();
+        team.assert();
+        assert!(matches!(
+            results[0],
+            Err(GraphqlError::ResponseErrors { .. })
+        ));
+        assert_eq!(results[1].as_ref().unwrap()["key"], "ENG");
+    }
+}
@@ -1,15 +1,25 @@
-use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
 use std::sync::Arc;
 
+use chrono::{DateTime, NaiveDate, Utc};
+use futures_util::TryStreamExt;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use serde_json::{json, Map, Value};
+use thiserror::Error;
 use tokio::sync::RwLock;
 
+use crate::fuzzy::{self, Resolution};
 use crate::graphql::{
-    Comment, CommentCreateInput, GraphqlResult, IssueCreateInput, IssueDetail, IssueListParams,
-    IssueListResponse, IssueSummary, IssueUpdateInput, LinearGraphqlClient, TeamSummary,
-    WorkflowStateSummary,
+    Comment, CommentCreateInput, GraphqlError, GraphqlResult, IssueCreateInput, IssueDetail,
+    IssueLabel, IssueListParams, IssueListResponse, IssueRelationCreateInput, IssueSummary,
+    IssueUpdateInput, LinearGraphqlClient, TeamMember, TeamSummary, WorkflowStateSummary,
 };
+use crate::priority::Priority;
+use crate::query::{IssuePage, IssueQuery, StateType};
+use crate::services::pagination::{page_size, paginate, Page, PageStream};
+use crate::services::sync::{IssueChanges, IssueSet};
 
 /// Provides higher-level helpers around Linear issues.
 #[derive(Clone)]
@@ -26,6 +36,11 @@ impl IssueService {
         }
     }
 
+    /// The underlying client, for requests this service does not wrap.
+    pub fn client(&self) -> &LinearGraphqlClient {
+        &self.client
+    }
+
     pub async fn list(&self, options: IssueQueryOptions) -> GraphqlResult<IssueListResult> {
         let params = options.into_params();
         let response: IssueListResponse = self.client.list_issues(params).await?;
@@ -36,6 +51,112 @@ impl IssueService {
         })
     }
 
+    /// Every issue matching `options`, following cursors from `options.after` as the stream is
+    /// consumed; `options.limit` is the page size.
+    pub fn list_iter(&self, mut options: IssueQueryOptions) -> PageStream<IssueSummary> {
+        let service = self.clone();
+        options.limit = page_size(options.limit);
+        paginate(options.after.take(), move |after| {
+            let service = service.clone();
+            let options = IssueQueryOptions {
+                after,
+                ..options.clone()
+            };
+            async move {
+                let page = service.list(options).await?;
+                Ok(Page {
+                    nodes: page.issues,
+                    end_cursor: page.end_cursor,
+                    has_next_page: page.has_next_page,
+                })
+            }
+        })
+    }
+
+    /// Changes to the issues matching `options` since `set`'s watermark: issues updated at or
+    /// after it that still match, and cached issues updated since that no longer do. Without a
+    /// watermark every matching issue counts as updated.
+    ///
+    /// Archived or deleted issues drop out of both queries, so they stay in the set until it is
+    /// rebuilt from a full listing.
+    pub async fn changes_since(
+        &self,
+        options: IssueQueryOptions,
+        set: &IssueSet,
+    ) -> GraphqlResult<IssueChanges> {
+        let matching: Vec<IssueSummary> = self
+            .list_iter(IssueQueryOptions {
+                updated_since: set.watermark,
+                after: None,
+                ..options
+            })
+            .try_collect()
+            .await?;
+        let still_matching: HashSet<String> =
+            matching.iter().map(|issue| issue.id.clone()).collect();
+        let updated = matching
+            .into_iter()
+            .filter(|issue| !set.holds(issue))
+            .collect();
+
+        let mut departed = Vec::new();
+        if let Some(since) = set.watermark {
+            let ids: Vec<String> = set.issues.iter().map(|issue| issue.id.clone()).collect();
+            for chunk in ids.chunks(200) {
+                let params = IssueListParams {
+                    first: chunk.len(),
+                    filter: IssueQuery::new()
+                        .raw("id", serde_json::json!({ "in": chunk }))
+                        .updated_since(since)
+                        .filter(),
+                    after: None,
+                    sort: None,
+                };
+                let response = self.client.list_issues(params).await?;
+                departed.extend(
+                    response
+                        .nodes
+                        .into_iter()
+                        .filter(|issue| !still_matching.contains(&issue.id) && !set.holds(issue))
+                        .map(|issue| issue.id),
+                );
+            }
+        }
+        Ok(IssueChanges { updated, departed })
+    }
+
+    /// Every issue matching `query`, following cursors as the stream is consumed; the query's
+    /// `first` is the page size.
+    pub fn query_iter<T>(&self, query: IssueQuery) -> PageStream<T>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        let service = self.clone();
+        paginate(None, move |after| {
+            let service = service.clone();
+            let query = match after {
+                Some(cursor) => query.clone().after(cursor),
+                None => query.clone(),
+            };
+            async move {
+                let page = service.query::<T>(&query).await?;
+                Ok(Page {
+                    nodes: page.nodes,
+                    end_cursor: page.end_cursor,
+                    has_next_page: page.has_next_page,
+                })
+            }
+        })
+    }
+
+    /// Run a builder-composed query; see [`IssueQuery`].
+    pub async fn query<T: DeserializeOwned + Send + 'static>(
+        &self,
+        query: &IssueQuery,
+    ) -> GraphqlResult<IssuePage<T>> {
+        self.client.query_issues(query).await
+    }
+
     pub async fn get_by_key(&self, key: &str) -> GraphqlResult<IssueDetail> {
         self.client.issue_by_key(key).await
     }
@@ -60,27 +181,58 @@ impl IssueService {
         }
     }
 
-    pub async fn resolve_team_id(&self, identifier: &str) -> GraphqlResult<Option<String>> {
+    /// Team members, cached for the lifetime of the service like teams and states.
+    pub async fn team_members(&self, team_id: &str) -> GraphqlResult<Vec<TeamMember>> {
+        if let Some(members) = self.cache.read_members(team_id).await {
+            Ok(members)
+        } else {
+            let members = self.client.team_members(team_id).await?;
+            self.cache.write_members(team_id, members.clone()).await;
+            Ok(members)
+        }
+    }
+
+    /// The team `identifier` names: its id, key, or name, or a close [`fuzzy::resolve`] match.
+    pub async fn find_team(&self, identifier: &str) -> GraphqlResult<Resolution<TeamSummary>> {
         let teams = self.teams().await?;
-        Ok(teams
-            .into_iter()
-            .find(|team| {
-                team.id == identifier
-                    || team.key.eq_ignore_ascii_case(identifier)
-                    || team.name.eq_ignore_ascii_case(identifier)
-            })
+        Ok(fuzzy::resolve(identifier, &teams, |team| {
+            vec![team.id.as_str(), team.key.as_str(), team.name.as_str()]
+        })
+        .map(Clone::clone))
+    }
+
+    /// The state of the team `identifier` names: its id or name, or a close fuzzy match.
+    pub async fn find_state(
+        &self,
+        team_id: &str,
+        identifier: &str,
+    ) -> GraphqlResult<Resolution<WorkflowStateSummary>> {
+        let states = self.workflow_states(team_id).await?;
+        Ok(fuzzy::resolve(identifier, &states, |state| {
+            vec![state.id.as_str(), state.name.as_str()]
+        })
+        .map(Clone::clone))
+    }
+
+    /// The id of the team [`IssueService::find_team`] resolves unambiguously.
+    pub async fn resolve_team_id(&self, identifier: &str) -> GraphqlResult<Option<String>> {
+        Ok(self
+            .find_team(identifier)
+            .await?
+            .unique()
             .map(|team| team.id))
     }
 
+    /// The id of the state [`IssueService::find_state`] resolves unambiguously.
     pub async fn resolve_state_id(
         &self,
         team_id: &str,
         identifier: &str,
     ) -> GraphqlResult<Option<String>> {
-        let states = self.workflow_states(team_id).await?;
-        Ok(states
-            .into_iter()
-            .find(|state| state.id == identifier || state.name.eq_ignore_ascii_case(identifier))
+        Ok(self
+            .find_state(team_id, identifier)
+            .await?
+            .unique()
             .map(|state| state.id))
     }
 
@@ -88,12 +240,7 @@ impl IssueService {
         &self,
         team_identifier: &str,
     ) -> GraphqlResult<Option<(TeamSummary, Vec<WorkflowStateSummary>)>> {
-        let teams = self.teams().await?;
-        if let Some(team) = teams.into_iter().find(|team| {
-            team.id == team_identifier
-                || team.key.eq_ignore_ascii_case(team_identifier)
-                || team.name.eq_ignore_ascii_case(team_identifier)
-        }) {
+        if let Some(team) = self.find_team(team_identifier).await?.unique() {
             let states = self.workflow_states(&team.id).await?;
             Ok(Some((team, states)))
         } else {
@@ -101,6 +248,48 @@ impl IssueService {
         }
     }
 
+    /// The states `issue` can move to: its team's workflow in type order (triage, backlog,
+    /// unstarted, started, completed, canceled), without the state it is in.
+    pub async fn valid_transitions(
+        &self,
+        issue: &IssueDetail,
+    ) -> GraphqlResult<Vec<WorkflowStateSummary>> {
+        let Some(team) = &issue.team else {
+            return Ok(Vec::new());
+        };
+        let current = issue.state.as_ref().map(|state| state.id.as_str());
+        let mut states: Vec<_> = self
+            .workflow_states(&team.id)
+            .await?
+            .into_iter()
+            .filter(|state| Some(state.id.as_str()) != current)
+            .collect();
+        states.sort_by_key(|state| workflow_rank(state.type_name.as_deref()));
+        Ok(states)
+    }
+
+    /// The state named by `input` (an id, a name, or a fuzzy pattern such as `prog`) that
+    /// `issue` can move to. Naming the current state is allowed and changes nothing.
+    pub async fn resolve_transition(
+        &self,
+        issue: &IssueDetail,
+        input: &str,
+    ) -> GraphqlResult<Result<WorkflowStateSummary, StateMatchError>> {
+        if let Some(current) = &issue.state {
+            if current.id == input || current.name.eq_ignore_ascii_case(input.trim()) {
+                return Ok(Ok(WorkflowStateSummary {
+                    id: current.id.clone(),
+                    name: current.name.clone(),
+                    type_name: current.kind.clone(),
+                    color: None,
+                    description: None,
+                }));
+            }
+        }
+        let states = self.valid_transitions(issue).await?;
+        Ok(match_state(&states, input).cloned())
+    }
+
     pub async fn create(&self, options: IssueCreateOptions) -> GraphqlResult<IssueDetail> {
         let IssueCreateOptions {
             team_id,
@@ -110,6 +299,8 @@ impl IssueService {
             state_id,
             label_ids,
             priority,
+            estimate,
+            due_date,
         } = options;
 
         let mut input = IssueCreateInput::new(team_id, title);
@@ -120,6 +311,8 @@ impl IssueService {
             input.label_ids = label_ids;
         }
         input.priority = priority;
+        input.estimate = estimate;
+        input.due_date = due_date;
 
         self.client.create_issue(input).await
     }
@@ -148,6 +341,292 @@ impl IssueService {
             })
             .await
     }
+
+    /// Add `user_id` to the issue's subscribers so Linear notifies them of its changes.
+    /// Returns `false` when they were subscribed already.
+    pub async fn subscribe(&self, issue: &IssueDetail, user_id: &str) -> GraphqlResult<bool> {
+        let mut subscriber_ids: Vec<String> = issue
+            .subscribers
+            .as_ref()
+            .map(|users| users.nodes.iter().map(|user| user.id.clone()).collect())
+            .unwrap_or_default();
+        if subscriber_ids.iter().any(|id| id == user_id) {
+            return Ok(false);
+        }
+        subscriber_ids.push(user_id.to_owned());
+        let input = IssueUpdateInput {
+            subscriber_ids: Some(subscriber_ids),
+            ..Default::default()
+        };
+        self.client.update_issue(&issue.id, input).await?;
+        Ok(true)
+    }
+
+    /// Fold `duplicate_key` into `target_key`: copy labels and subscribers onto the target,
+    /// optionally re-parent the duplicate's sub-issues, relate it as a duplicate, cancel it, and
+    /// cross-link both with comments.
+    ///
+    /// Labels scoped to the duplicate's team are not copied onto a target in another team. The
+    /// target is updated before the duplicate is touched; if a later step fails the error is
+    /// [`GraphqlError::Incomplete`] and lists the steps that were already applied.
+    pub async fn merge(
+        &self,
+        duplicate_key: &str,
+        target_key: &str,
+        move_sub_issues: bool,
+    ) -> GraphqlResult<MergeOutcome> {
+        let duplicate = self.client.issue_by_key(duplicate_key).await?;
+        let target = self.client.issue_by_key(target_key).await?;
+        if duplicate.id == target.id {
+            return Err(GraphqlError::OperationFailed(
+                "cannot merge an issue into itself".into(),
+            ));
+        }
+
+        let team_id = |issue: &IssueDetail| issue.team.as_ref().map(|team| team.id.clone());
+        let foreign_labels = match team_id(&duplicate) {
+            Some(team) if team_id(&target).as_ref() != Some(&team) => {
+                self.client.issue_labels(&team).await?
+            }
+            _ => Vec::new(),
+        };
+        let (copied_labels, skipped_labels): (Vec<IssueLabel>, Vec<IssueLabel>) = duplicate
+            .labels
+            .as_ref()
+            .map(|labels| labels.nodes.clone())
+            .unwrap_or_default()
+            .into_iter()
+            .partition(|label| !foreign_labels.iter().any(|foreign| foreign.id == label.id));
+        let canceled_state = match duplicate.team.as_ref() {
+            Some(team) => {
+                let states = self.workflow_states(&team.id).await?;
+                pick_duplicate_state(&states).cloned()
+            }
+            None => None,
+        };
+
+        let label_ids = |labels: &[IssueLabel]| -> Vec<String> {
+            labels.iter().map(|label| label.id.clone()).collect()
+        };
+        let target_labels = target
+            .labels
+            .as_ref()
+            .map(|labels| label_ids(&labels.nodes))
+            .unwrap_or_default();
+        let subscriber_ids = |issue: &IssueDetail| -> Vec<String> {
+            issue
+                .subscribers
+                .as_ref()
+                .map(|users| users.nodes.iter().map(|user| user.id.clone()).collect())
+                .unwrap_or_default()
+        };
+        let merged_labels = merged_ids(&target_labels, &label_ids(&copied_labels));
+        let merged_subscribers = merged_ids(&subscriber_ids(&target), &subscriber_ids(&duplicate));
+        let labels_added = merged_labels
+            .as_ref()
+            .map_or(0, |ids| ids.len() - target_labels.len());
+        let subscribers_added = merged_subscribers
+            .as_ref()
+            .map_or(0, |ids| ids.len() - subscriber_ids(&target).len());
+
+        let mut completed = Vec::new();
+        if merged_labels.is_some() || merged_subscribers.is_some() {
+            let input = IssueUpdateInput {
+                label_ids: merged_labels,
+                subscriber_ids: merged_subscribers,
+                ..Default::default()
+            };
+            self.client.update_issue(&target.id, input).await?;
+            completed.push(format!(
+                "copied labels and subscribers to {}",
+                target.identifier
+            ));
+        }
+
+        let mut moved_sub_issues = Vec::new();
+        if move_sub_issues {
+            let children = duplicate
+                .sub_issues
+                .as_ref()
+                .map(|children| children.nodes.clone())
+                .unwrap_or_default();
+            for child in children {
+                let input = IssueUpdateInput {
+                    parent_id: Some(target.id.clone()),
+                    ..Default::default()
+                };
+                self.client
+                    .update_issue(&child.id, input)
+                    .await
+                    .map_err(|err| incomplete(&completed, err))?;
+                completed.push(format!(
+                    "moved {} under {}",
+                    child.identifier, target.identifier
+                ));
+                moved_sub_issues.push(child.identifier);
+            }
+        }
+
+        self.client
+            .create_issue_relation(IssueRelationCreateInput {
+                issue_id: duplicate.id.clone(),
+                related_issue_id: target.id.clone(),
+                type_name: "duplicate".into(),
+            })
+            .await
+            .map_err(|err| incomplete(&completed, err))?;
+        completed.push(format!(
+            "marked {} as a duplicate of {}",
+            duplicate.identifier, target.identifier
+        ));
+
+        let duplicate = match &canceled_state {
+            Some(state) => {
+                let input = IssueUpdateInput {
+                    state_id: Some(state.id.clone()),
+                    ..Default::default()
+                };
+                let updated = self
+                    .client
+                    .update_issue(&duplicate.id, input)
+                    .await
+                    .map_err(|err| incomplete(&completed, err))?;
+                completed.push(format!("moved {} to {}", updated.identifier, state.name));
+                updated
+            }
+            None => duplicate,
+        };
+
+        let link = |issue: &IssueDetail| match &issue.url {
+            Some(url) => format!("[{}]({})", issue.identifier, url),
+            None => issue.identifier.clone(),
+        };
+        self.comment(
+            &duplicate.id,
+            &format!("Marked as a duplicate of {}.", link(&target)),
+        )
+        .await
+        .map_err(|err| incomplete(&completed, err))?;
+        completed.push(format!("commented on {}", duplicate.identifier));
+        self.comment(
+            &target.id,
+            &format!("Merged duplicate {} into this issue.", link(&duplicate)),
+        )
+        .await
+        .map_err(|err| incomplete(&completed, err))?;
+
+        Ok(MergeOutcome {
+            duplicate,
+            target,
+            canceled_state: canceled_state.map(|state| state.name),
+            labels_added,
+            labels_skipped: skipped_labels.into_iter().map(|label| label.name).collect(),
+            subscribers_added,
+            moved_sub_issues,
+        })
+    }
+}
+
+/// Attach the already-applied steps to `err`, if there are any.
+fn incomplete(completed: &[String], err: GraphqlError) -> GraphqlError {
+    if completed.is_empty() {
+        return err;
+    }
+    GraphqlError::Incomplete {
+        completed: completed.to_vec(),
+        source: Box::new(err),
+    }
+}
+
+/// Result of [`IssueService::merge`].
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeOutcome {
+    pub duplicate: IssueDetail,
+    pub target: IssueDetail,
+    pub canceled_state: Option<String>,
+    pub labels_added: usize,
+    /// Names of the duplicate's team labels that the target's team cannot use.
+    pub labels_skipped: Vec<String>,
+    pub subscribers_added: usize,
+    pub moved_sub_issues: Vec<String>,
+}
+
+/// Why a state name did not pick exactly one workflow state; both list the closest states.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum StateMatchError {
+    #[error("state '{input}' matches several states: {}", .candidates.join(", "))]
+    Ambiguous {
+        input: String,
+        candidates: Vec<String>,
+    },
+    #[error("state '{input}' is not a state the issue can move to; valid states: {}", .candidates.join(", "))]
+    Unknown {
+        input: String,
+        candidates: Vec<String>,
+    },
+}
+
+/// Suggestions listed by [`StateMatchError::Ambiguous`].
+const MAX_SUGGESTIONS: usize = 5;
+
+/// Pick the state `input` names in `states` with [`fuzzy::resolve`]. Close calls are
+/// ambiguous and listed best first; with no match, every state in `states` is suggested.
+pub fn match_state<'a>(
+    states: &'a [WorkflowStateSummary],
+    input: &str,
+) -> Result<&'a WorkflowStateSummary, StateMatchError> {
+    match fuzzy::resolve(input, states, |state| {
+        vec![state.id.as_str(), state.name.as_str()]
+    }) {
+        Resolution::Unique(state) => Ok(state),
+        Resolution::Ambiguous(close) => Err(StateMatchError::Ambiguous {
+            input: input.trim().to_owned(),
+            candidates: close
+                .iter()
+                .take(MAX_SUGGESTIONS)
+                .map(|state| state.name.clone())
+                .collect(),
+        }),
+        Resolution::Missing => Err(StateMatchError::Unknown {
+            input: input.trim().to_owned(),
+            candidates: states.iter().map(|state| state.name.clone()).collect(),
+        }),
+    }
+}
+
+/// Position of a state type in the workflow; unknown types sort last.
+fn workflow_rank(type_name: Option<&str>) -> u8 {
+    match type_name.and_then(|name| name.parse::<StateType>().ok()) {
+        Some(StateType::Triage) => 0,
+        Some(StateType::Backlog) => 1,
+        Some(StateType::Unstarted) => 2,
+        Some(StateType::Started) => 3,
+        Some(StateType::Completed) => 4,
+        Some(StateType::Canceled) => 5,
+        None => 6,
+    }
+}
+
+/// Union of `existing` and `extra`, or `None` when `extra` adds nothing new.
+fn merged_ids(existing: &[String], extra: &[String]) -> Option<Vec<String>> {
+    let mut merged = existing.to_vec();
+    for id in extra {
+        if !merged.contains(id) {
+            merged.push(id.clone());
+        }
+    }
+    (merged.len() > existing.len()).then_some(merged)
+}
+
+/// Prefer a canceled-type state named "Duplicate", falling back to any canceled state.
+fn pick_duplicate_state(states: &[WorkflowStateSummary]) -> Option<&WorkflowStateSummary> {
+    let canceled = |state: &&WorkflowStateSummary| state.type_name.as_deref() == Some("canceled");
+    states
+        .iter()
+        .filter(canceled)
+        .find(|state| state.name.eq_ignore_ascii_case("duplicate"))
+        .or_else(|| states.iter().find(canceled))
 }
 
 /// Options used to constrain issue queries.
@@ -159,9 +638,107 @@ pub struct IssueQueryOptions {
     pub assignee_id: Option<String>,
     pub state_id: Option<String>,
     pub project_id: Option<String>,
+    pub cycle_id: Option<String>,
     pub label_ids: Vec<String>,
     pub title_contains: Option<String>,
+    /// Only issues updated at or after this instant.
+    pub updated_since: Option<DateTime<Utc>>,
+    /// Conditions the fields above cannot express, such as a parsed `issue list --query`.
+    #[serde(skip)]
+    pub conditions: Option<IssueQuery>,
     pub after: Option<String>,
+    /// Order of the list; `None` is [`IssueSort::UpdatedDesc`].
+    #[serde(default)]
+    pub sort: Option<IssueSort>,
+}
+
+/// Order of [`IssueService::list`]. Each order is followed by a secondary key (newest update
+/// first, or newest created for the update orders) so ties come back in the same order on every
+/// page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IssueSort {
+    #[default]
+    UpdatedDesc,
+    UpdatedAsc,
+    CreatedDesc,
+    CreatedAsc,
+    /// Most important first: urgent to low, then no priority.
+    PriorityDesc,
+    /// Least important first: no priority, then low to urgent.
+    PriorityAsc,
+}
+
+impl IssueSort {
+    pub const ALL: [IssueSort; 6] = [
+        IssueSort::UpdatedDesc,
+        IssueSort::UpdatedAsc,
+        IssueSort::CreatedDesc,
+        IssueSort::CreatedAsc,
+        IssueSort::PriorityDesc,
+        IssueSort::PriorityAsc,
+    ];
+
+    /// The `--sort` spelling, e.g. `priority:desc`.
+    pub fn label(self) -> &'static str {
+        match self {
+            IssueSort::UpdatedDesc => "updated:desc",
+            IssueSort::UpdatedAsc => "updated:asc",
+            IssueSort::CreatedDesc => "created:desc",
+            IssueSort::CreatedAsc => "created:asc",
+            IssueSort::PriorityDesc => "priority:desc",
+            IssueSort::PriorityAsc => "priority:asc",
+        }
+    }
+
+    /// `[IssueSortInput!]` for the `issues` connection, primary key first.
+    pub fn as_sort(self) -> serde_json::Value {
+        let updated = |order: &str| serde_json::json!({ "updatedAt": { "order": order } });
+        let created = |order: &str| serde_json::json!({ "createdAt": { "order": order } });
+        // Linear numbers priorities 1 (urgent) to 4 (low), with 0 for none.
+        let priority = |order: &str, no_priority_first: bool| {
+            serde_json::json!({
+                "priority": { "order": order, "noPriorityFirst": no_priority_first }
+            })
+        };
+        let (primary, secondary) = match self {
+            IssueSort::UpdatedDesc => (updated("Descending"), created("Descending")),
+            IssueSort::UpdatedAsc => (updated("Ascending"), created("Descending")),
+            IssueSort::CreatedDesc => (created("Descending"), updated("Descending")),
+            IssueSort::CreatedAsc => (created("Ascending"), updated("Descending")),
+            IssueSort::PriorityDesc => (priority("Ascending", false), updated("Descending")),
+            IssueSort::PriorityAsc => (priority("Descending", true), updated("Descending")),
+        };
+        serde_json::json!([primary, secondary])
+    }
+
+    /// The same order applied locally, for lists merged or regrouped after they were fetched.
+    pub fn compare(self, a: &IssueSummary, b: &IssueSummary) -> Ordering {
+        let importance = |issue: &IssueSummary| {
+            issue
+                .priority
+                .and_then(Priority::from_value)
+                .unwrap_or(Priority::None)
+        };
+        let newest_update = b.updated_at.cmp(&a.updated_at);
+        match self {
+            IssueSort::UpdatedDesc => newest_update.then(b.created_at.cmp(&a.created_at)),
+            IssueSort::UpdatedAsc => a
+                .updated_at
+                .cmp(&b.updated_at)
+                .then(b.created_at.cmp(&a.created_at)),
+            IssueSort::CreatedDesc => b.created_at.cmp(&a.created_at).then(newest_update),
+            IssueSort::CreatedAsc => a.created_at.cmp(&b.created_at).then(newest_update),
+            IssueSort::PriorityDesc => importance(b).cmp(&importance(a)).then(newest_update),
+            IssueSort::PriorityAsc => importance(a).cmp(&importance(b)).then(newest_update),
+        }
+    }
+}
+
+impl fmt::Display for IssueSort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -181,6 +758,8 @@ pub struct IssueCreateOptions {
     pub state_id: Option<String>,
     pub label_ids: Vec<String>,
     pub priority: Option<i32>,
+    pub estimate: Option<i32>,
+    pub due_date: Option<NaiveDate>,
 }
 
 impl IssueCreateOptions {
@@ -193,6 +772,8 @@ impl IssueCreateOptions {
             state_id: None,
             label_ids: Vec::new(),
             priority: None,
+            estimate: None,
+            due_date: None,
         }
     }
 }
@@ -204,45 +785,56 @@ impl IssueQueryOptions {
         } else {
             self.limit.min(200)
         };
-        let mut filter = Map::new();
-
-        if let Some(team_id) = self.team_id {
-            filter.insert("team".into(), json!({ "id": { "eq": team_id } }));
-        } else if let Some(team_key) = self.team_key {
-            filter.insert("team".into(), json!({ "key": { "eq": team_key } }));
+        let after = self.after.clone();
+        let sort = self.sort.map(IssueSort::as_sort);
+        IssueListParams {
+            first,
+            filter: IssueQuery::from(self).filter(),
+            after,
+            sort,
         }
+    }
+}
 
-        if let Some(state_id) = self.state_id {
-            filter.insert("state".into(), json!({ "id": { "eq": state_id } }));
+impl From<IssueQueryOptions> for IssueQuery {
+    fn from(options: IssueQueryOptions) -> Self {
+        let mut query = IssueQuery::new();
+        if let Some(conditions) = options.conditions {
+            query = query.and(conditions);
         }
-
-        if let Some(assignee_id) = self.assignee_id {
-            filter.insert("assignee".into(), json!({ "id": { "eq": assignee_id } }));
+        if let Some(team_id) = options.team_id {
+            query = query.team_id(team_id);
+        } else if let Some(team_key) = options.team_key {
+            query = query.team(team_key);
         }
-
-        if let Some(project_id) = self.project_id {
-            filter.insert("project".into(), json!({ "id": { "eq": project_id } }));
+        if let Some(state_id) = options.state_id {
+            query = query.state_id(state_id);
         }
-
-        if !self.label_ids.is_empty() {
-            filter.insert("labels".into(), json!({ "id": { "in": self.label_ids } }));
+        if let Some(assignee_id) = options.assignee_id {
+            query = query.assignee(assignee_id);
         }
-
-        if let Some(search) = self.title_contains {
-            filter.insert("title".into(), json!({ "contains": search }));
+        if let Some(project_id) = options.project_id {
+            query = query.project(project_id);
         }
-
-        let filter = if filter.is_empty() {
-            None
-        } else {
-            Some(Value::Object(filter))
-        };
-
-        IssueListParams {
-            first,
-            filter,
-            after: self.after,
+        if let Some(cycle_id) = options.cycle_id {
+            query = query.cycle(cycle_id);
+        }
+        if !options.label_ids.is_empty() {
+            query = query.label_ids(options.label_ids);
+        }
+        if let Some(search) = options.title_contains {
+            query = query.title_contains(search);
+        }
+        if let Some(since) = options.updated_since {
+            query = query.updated_since(since);
+        }
+        if options.limit > 0 {
+            query = query.first(options.limit);
+        }
+        if let Some(after) = options.after {
+            query = query.after(after);
         }
+        query
     }
 }
 
@@ -250,6 +842,7 @@ impl IssueQueryOptions {
 struct Cache {
     teams: Arc<RwLock<Option<Vec<TeamSummary>>>>,
     workflow_states: Arc<RwLock<HashMap<String, Vec<WorkflowStateSummary>>>>,
+    members: Arc<RwLock<HashMap<String, Vec<TeamMember>>>>,
 }
 
 impl Cache {
@@ -271,12 +864,61 @@ impl Cache {
             .await
             .insert(team_id.to_owned(), states);
     }
+
+    async fn read_members(&self, team_id: &str) -> Option<Vec<TeamMember>> {
+        self.members.read().await.get(team_id).cloned()
+    }
+
+    async fn write_members(&self, team_id: &str, members: Vec<TeamMember>) {
+        self.members
+            .write()
+            .await
+            .insert(team_id.to_owned(), members);
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn state(id: &str, name: &str, type_name: &str) -> WorkflowStateSummary {
+        WorkflowStateSummary {
+            id: id.into(),
+            name: name.into(),
+            type_name: Some(type_name.into()),
+            color: None,
+            description: None,
+        }
+    }
+
+    #[test]
+    fn matches_states_exactly_then_fuzzily() {
+        let states = [
+            state("s1", "Todo", "unstarted"),
+            state("s2", "In Progress", "started"),
+            state("s3", "In Review", "started"),
+            state("s4", "Done", "completed"),
+        ];
+        assert_eq!(match_state(&states, "done").unwrap().id, "s4");
+        assert_eq!(match_state(&states, "s3").unwrap().id, "s3");
+        assert_eq!(match_state(&states, "prog").unwrap().id, "s2");
+        assert_eq!(
+            match_state(&states, "in").unwrap_err(),
+            StateMatchError::Ambiguous {
+                input: "in".into(),
+                candidates: vec!["In Progress".into(), "In Review".into()],
+            }
+        );
+        let unknown = match_state(&states, "Blocked").unwrap_err();
+        assert_eq!(
+            unknown.to_string(),
+            "state 'Blocked' is not a state the issue can move to; \
+             valid states: Todo, In Progress, In Review, Done"
+        );
+        assert!(workflow_rank(Some("backlog")) < workflow_rank(Some("started")));
+        assert_eq!(workflow_rank(Some("custom")), 6);
+    }
+
     #[test]
     fn converts_options_to_filter() {
         let options = IssueQueryOptions {
@@ -286,7 +928,9 @@ mod tests {
             state_id: Some("state-1".into()),
             label_ids: vec!["label-1".into(), "label-2".into()],
             project_id: Some("proj-1".into()),
+            cycle_id: Some("cycle-1".into()),
             title_contains: Some("bug".into()),
+            updated_since: "2024-05-01T00:00:00Z".parse().ok(),
             after: Some("cursor".into()),
             ..Default::default()
         };
@@ -297,8 +941,185 @@ mod tests {
         assert_eq!(filter["team"]["key"]["eq"], "ENG");
         assert_eq!(filter["assignee"]["id"]["eq"], "user-1");
         assert_eq!(filter["project"]["id"]["eq"], "proj-1");
+        assert_eq!(filter["cycle"]["id"]["eq"], "cycle-1");
         assert_eq!(filter["labels"]["id"]["in"].as_array().unwrap().len(), 2);
         assert_eq!(filter["title"]["contains"], "bug");
+        assert_eq!(filter["updatedAt"]["gte"], "2024-05-01T00:00:00+00:00");
         assert_eq!(params.after.as_deref(), Some("cursor"));
     }
+
+    #[test]
+    fn sorts_by_priority_with_newest_update_breaking_ties() {
+        let issue = |id: &str, priority: i32, updated: &str| -> IssueSummary {
+            serde_json::from_value(serde_json::json!({
+                "id": id,
+                "identifier": id,
+                "title": id,
+                "url": null,
+                "state": null,
+                "assignee": null,
+                "priority": priority,
+                "createdAt": "2024-05-01T00:00:00Z",
+                "updatedAt": updated
+            }))
+            .unwrap()
+        };
+        let mut issues = [
+            issue("none", 0, "2024-05-04T00:00:00Z"),
+            issue("low", 4, "2024-05-02T00:00:00Z"),
+            issue("urgent-old", 1, "2024-05-01T00:00:00Z"),
+            issue("urgent-new", 1, "2024-05-03T00:00:00Z"),
+        ];
+        issues.sort_by(|a, b| IssueSort::PriorityDesc.compare(a, b));
+        let order: Vec<&str> = issues.iter().map(|issue| issue.id.as_str()).collect();
+        assert_eq!(order, ["urgent-new", "urgent-old", "low", "none"]);
+        issues.sort_by(|a, b| IssueSort::PriorityAsc.compare(a, b));
+        assert_eq!(issues[0].id, "none");
+        assert_eq!(issues[3].id, "urgent-old");
+
+        let options = IssueQueryOptions {
+            sort: Some(IssueSort::PriorityDesc),
+            ..Default::default()
+        };
+        assert_eq!(
+            options.into_params().sort.unwrap(),
+            serde_json::json!([
+                { "priority": { "order": "Ascending", "noPriorityFirst": false } },
+                { "updatedAt": { "order": "Descending" } }
+            ])
+        );
+    }
+
+    #[test]
+    fn merged_ids_only_reports_new_entries() {
+        let existing = vec!["a".to_string(), "b".to_string()];
+        assert_eq!(merged_ids(&existing, &["b".into()]), None);
+        assert_eq!(
+            merged_ids(&existing, &["c".into(), "a".into()]),
+            Some(vec!["a".into(), "b".into(), "c".into()])
+        );
+    }
+
+    #[test]
+    fn prefers_duplicate_state_over_other_canceled_states() {
+        let state = |id: &str, name: &str, type_name: &str| WorkflowStateSummary {
+            id: id.into(),
+            name: name.into(),
+            type_name: Some(type_name.into()),
+            color: None,
+            description: None,
+        };
+        let states = vec![
+            state("1", "Todo", "unstarted"),
+            state("2", "Canceled", "canceled"),
+            state("3", "Duplicate", "canceled"),
+        ];
+        assert_eq!(pick_duplicate_state(&states).unwrap().id, "3");
+        assert_eq!(pick_duplicate_state(&states[..2]).unwrap().id, "2");
+        assert!(pick_duplicate_state(&states[..1]).is_none());
+    }
+
+    #[tokio::test]
+    async fn merge_skips_foreign_team_labels_and_reports_applied_steps() {
+        use crate::auth::AuthSession;
+        use httpmock::prelude::*;
+        use serde_json::json;
+
+        let issue = |id: &str, key: &str, team: &str, labels: serde_json::Value| {
+            json!({
+                "id": id,
+                "identifier": key,
+                "title": "Login fails",
+                "team": { "id": team, "name": team, "key": team },
+                "labels": { "nodes": labels },
+                "createdAt": "2024-01-01T00:00:00Z",
+                "updatedAt": "2024-01-01T00:00:00Z"
+            })
+        };
+        let server = MockServer::start();
+        let duplicate = issue(
+            "issue-1",
+            "ENG-1",
+            "team-eng",
+            json!([
+                { "id": "label-eng", "name": "Backend", "color": null },
+                { "id": "label-bug", "name": "Bug", "color": null }
+            ]),
+        );
+        let target = issue("issue-2", "OPS-2", "team-ops", json!([]));
+        server.mock(|when, then| {
+            when.method(POST)
+                .body_contains("query IssueByKey")
+                .body_contains(r#""ENG-1""#);
+            then.status(200)
+                .json_body_obj(&json!({ "data": { "issue": duplicate } }));
+        });
+        server.mock(|when, then| {
+            when.method(POST)
+                .body_contains("query IssueByKey")
+                .body_contains(r#""OPS-2""#);
+            then.status(200)
+                .json_body_obj(&json!({ "data": { "issue": target } }));
+        });
+        let team_labels = server.mock(|when, then| {
+            when.method(POST)
+                .body_contains("query IssueLabels")
+                .body_contains("team-eng");
+            then.status(200)
+                .json_body_obj(&json!({ "data": { "issueLabels": { "nodes": [
+                { "id": "label-eng", "name": "Backend", "color": null }
+            ] } } }));
+        });
+        server.mock(|when, then| {
+            when.method(POST).body_contains("query WorkflowStates");
+            then.status(200)
+                .json_body_obj(&json!({ "data": { "team": { "states": { "nodes": [
+                { "id": "state-dup", "name": "Duplicate", "type": "canceled" }
+            ] } } } }));
+        });
+        let copy = server.mock(|when, then| {
+            when.method(POST)
+                .body_contains("mutation IssueUpdate")
+                .body_contains(r#""labelIds":["label-bug"]"#);
+            then.status(200).json_body_obj(&json!({
+                "data": { "issueUpdate": { "success": true, "issue": target } }
+            }));
+        });
+        let relation = server.mock(|when, then| {
+            when.method(POST)
+                .body_contains("mutation IssueRelationCreate");
+            then.status(200).json_body_obj(&json!({
+                "errors": [{ "message": "Relation already exists" }]
+            }));
+        });
+        let cancel = server.mock(|when, then| {
+            when.method(POST)
+                .body_contains("mutation IssueUpdate")
+                .body_contains("state-dup");
+            then.status(200).json_body_obj(&json!({
+                "data": { "issueUpdate": { "success": true, "issue": duplicate } }
+            }));
+        });
+
+        let service = IssueService::new(
+            LinearGraphqlClient::with_endpoint(
+                &AuthSession::new_api_key("test-key".into()),
+                &server.url("/graphql"),
+            )
+            .unwrap(),
+        );
+        let err = service.merge("ENG-1", "OPS-2", false).await.unwrap_err();
+
+        team_labels.assert();
+        copy.assert();
+        relation.assert();
+        cancel.assert_hits(0);
+        match err {
+            GraphqlError::Incomplete { completed, source } => {
+                assert_eq!(completed, vec!["copied labels and subscribers to OPS-2"]);
+                assert!(source.to_string().contains("Relation already exists"));
+            }
+            other => panic!("expected an incomplete merge, got {other:?}"),
+        }
+    }
 }
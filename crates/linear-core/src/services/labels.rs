@@ -1,3 +1,4 @@
+use crate::fuzzy::{self, Resolution};
 use crate::graphql::{
     GraphqlResult, IssueLabel, IssueLabelCreateInput, IssueLabelUpdateInput, LinearGraphqlClient,
 };
@@ -16,6 +17,24 @@ impl LabelService {
         self.client.issue_labels(team_id).await
     }
 
+    /// The team label `identifier` names: its id or name, or a close fuzzy match.
+    pub async fn find(
+        &self,
+        team_id: &str,
+        identifier: &str,
+    ) -> GraphqlResult<Resolution<IssueLabel>> {
+        let labels = self.list(team_id).await?;
+        Ok(fuzzy::resolve(identifier, &labels, |label| {
+            vec![label.id.as_str(), label.name.as_str()]
+        })
+        .map(Clone::clone))
+    }
+
+    /// Labels across the workspace, team labels included.
+    pub async fn all(&self) -> GraphqlResult<Vec<IssueLabel>> {
+        self.client.workspace_labels().await
+    }
+
     pub async fn create(&self, input: IssueLabelCreateInput) -> GraphqlResult<IssueLabel> {
         self.client.create_issue_label(input).await
     }
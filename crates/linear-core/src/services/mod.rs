@@ -1,4 +1,12 @@
+pub mod activity;
+pub mod automation;
+pub mod backup;
 pub mod cycles;
+pub mod entities;
 pub mod issues;
 pub mod labels;
+pub mod pagination;
 pub mod projects;
+pub mod search;
+pub mod sync;
+pub mod workspace;
@@ -0,0 +1,140 @@
+//! Cursor-following streams behind the services' `list_iter` methods.
+//!
+//! ```no_run
+//! # async fn run(service: linear_core::services::issues::IssueService) {
+//! use futures_util::StreamExt;
+//! use linear_core::services::issues::IssueQueryOptions;
+//!
+//! let mut issues = service.list_iter(IssueQueryOptions::default());
+//! while let Some(issue) = issues.next().await {
+//!     println!("{}", issue.unwrap().identifier);
+//! }
+//! # }
+//! ```
+
+use std::collections::VecDeque;
+use std::future::Future;
+
+use futures_util::stream::{self, BoxStream, StreamExt};
+
+use crate::graphql::GraphqlResult;
+
+/// Page size used by `list_iter` when the options leave `limit` at zero.
+pub const DEFAULT_PAGE_SIZE: usize = 50;
+
+/// Items of every page, in order; the first error ends the stream.
+pub type PageStream<T> = BoxStream<'static, GraphqlResult<T>>;
+
+/// One fetched page of a cursor-paginated connection.
+pub(crate) struct Page<T> {
+    pub(crate) nodes: Vec<T>,
+    pub(crate) end_cursor: Option<String>,
+    pub(crate) has_next_page: bool,
+}
+
+struct State<T, F> {
+    fetch: F,
+    buffer: VecDeque<T>,
+    cursor: Option<String>,
+    done: bool,
+}
+
+/// Stream the items of consecutive pages, calling `fetch` with each page's cursor (starting
+/// from `after`) only once the previous page has been consumed.
+pub(crate) fn paginate<T, F, Fut>(after: Option<String>, fetch: F) -> PageStream<T>
+where
+    T: Send + 'static,
+    F: FnMut(Option<String>) -> Fut + Send + 'static,
+    Fut: Future<Output = GraphqlResult<Page<T>>> + Send + 'static,
+{
+    let state = State {
+        fetch,
+        buffer: VecDeque::new(),
+        cursor: after,
+        done: false,
+    };
+    stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(item) = state.buffer.pop_front() {
+                return Some((Ok(item), state));
+            }
+            if state.done {
+                return None;
+            }
+            match (state.fetch)(state.cursor.take()).await {
+                Ok(page) => {
+                    state.done = !page.has_next_page || page.end_cursor.is_none();
+                    state.cursor = page.end_cursor;
+                    state.buffer.extend(page.nodes);
+                }
+                Err(err) => {
+                    state.done = true;
+                    return Some((Err(err), state));
+                }
+            }
+        }
+    })
+    .boxed()
+}
+
+/// `limit`, or [`DEFAULT_PAGE_SIZE`] when it is zero.
+pub(crate) fn page_size(limit: usize) -> usize {
+    if limit == 0 {
+        DEFAULT_PAGE_SIZE
+    } else {
+        limit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::graphql::GraphqlError;
+
+    #[tokio::test]
+    async fn follows_cursors_lazily_and_stops_on_error() {
+        let requested = Arc::new(Mutex::new(Vec::new()));
+        let seen = requested.clone();
+        let mut items = paginate(Some("start".into()), move |cursor: Option<String>| {
+            seen.lock().unwrap().push(cursor.clone());
+            async move {
+                match cursor.as_deref() {
+                    Some("start") => Ok(Page {
+                        nodes: vec![1, 2],
+                        end_cursor: Some("c2".into()),
+                        has_next_page: true,
+                    }),
+                    Some("c2") => Ok(Page {
+                        nodes: vec![3],
+                        end_cursor: Some("c3".into()),
+                        has_next_page: true,
+                    }),
+                    _ => Err(GraphqlError::NotFound),
+                }
+            }
+        });
+
+        assert_eq!(items.next().await.unwrap().unwrap(), 1);
+        assert_eq!(requested.lock().unwrap().len(), 1);
+        assert_eq!(items.next().await.unwrap().unwrap(), 2);
+        assert_eq!(items.next().await.unwrap().unwrap(), 3);
+        assert!(items.next().await.unwrap().is_err());
+        assert!(items.next().await.is_none());
+        assert_eq!(
+            *requested.lock().unwrap(),
+            [Some("start".into()), Some("c2".into()), Some("c3".into())]
+        );
+
+        let mut last = paginate(None, |_| async {
+            Ok(Page {
+                nodes: vec!["only"],
+                end_cursor: None,
+                has_next_page: true,
+            })
+        });
+        assert_eq!(last.next().await.unwrap().unwrap(), "only");
+        assert!(last.next().await.is_none());
+    }
+}
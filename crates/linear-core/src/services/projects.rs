@@ -1,9 +1,15 @@
+use std::str::FromStr;
+
+use chrono::NaiveDate;
+use serde::Serialize;
 use serde_json::{json, Map, Value};
 
 use crate::graphql::{
-    GraphqlResult, LinearGraphqlClient, ProjectCreateInput, ProjectDetail, ProjectListParams,
-    ProjectListResponse, ProjectUpdateInput,
+    GraphqlError, GraphqlResult, LinearGraphqlClient, ProjectCreateInput, ProjectDetail,
+    ProjectListParams, ProjectListResponse, ProjectMilestone, ProjectMilestoneCreateInput,
+    ProjectStatusUpdate, ProjectStatusUpdateCreateInput, ProjectSummary, ProjectUpdateInput,
 };
+use crate::services::pagination::{page_size, paginate, Page, PageStream};
 
 #[derive(Clone)]
 pub struct ProjectService {
@@ -20,6 +26,32 @@ impl ProjectService {
         self.client.projects(params).await
     }
 
+    /// Every project matching `options`, following cursors as the stream is consumed;
+    /// `options.limit` is the page size.
+    pub fn list_iter(&self, mut options: ProjectQueryOptions) -> PageStream<ProjectSummary> {
+        let service = self.clone();
+        options.limit = page_size(options.limit);
+        paginate(options.after.take(), move |after| {
+            let service = service.clone();
+            let options = ProjectQueryOptions {
+                after,
+                ..options.clone()
+            };
+            async move {
+                let page = service.list(options).await?;
+                Ok(Page {
+                    nodes: page.nodes,
+                    end_cursor: page.end_cursor,
+                    has_next_page: page.has_next_page,
+                })
+            }
+        })
+    }
+
+    pub async fn get(&self, id: &str) -> GraphqlResult<ProjectDetail> {
+        self.client.project(id).await
+    }
+
     pub async fn create(&self, input: ProjectCreateInput) -> GraphqlResult<ProjectDetail> {
         self.client.project_create(input).await
     }
@@ -35,6 +67,212 @@ impl ProjectService {
     pub async fn archive(&self, id: &str, archive: bool) -> GraphqlResult<ProjectDetail> {
         self.client.project_archive(id, archive).await
     }
+
+    /// Create a project with its members, then add `extras`. If a follow-up fails the project
+    /// is deleted again, so a failed call leaves nothing behind.
+    pub async fn create_with(
+        &self,
+        input: ProjectCreateInput,
+        extras: &ProjectExtras,
+    ) -> GraphqlResult<ProjectSetup> {
+        let project = self.client.project_create(input).await?;
+        let mut added = Added::default();
+        if let Err(err) = self.add(&project.id, extras, &mut added).await {
+            let undo = self.client.project_delete(&project.id).await.map(drop);
+            return Err(rolled_back(
+                err,
+                undo,
+                &format!("project '{}'", project.name),
+            ));
+        }
+        Ok(added.into_setup(project))
+    }
+
+    /// Add `extras` to a project, then apply `input`, whose `member_ids` are added to the
+    /// current members rather than replacing them. If any step fails, the milestones and
+    /// status update added so far are deleted again and the project is left unchanged.
+    pub async fn update_with(
+        &self,
+        id: &str,
+        mut input: ProjectUpdateInput,
+        extras: &ProjectExtras,
+    ) -> GraphqlResult<ProjectSetup> {
+        if !input.member_ids.is_empty() {
+            let mut members = self.client.project_member_ids(id).await?;
+            let before = members.len();
+            for member in std::mem::take(&mut input.member_ids) {
+                if !members.contains(&member) {
+                    members.push(member);
+                }
+            }
+            if members.len() > before {
+                input.member_ids = members;
+            }
+        }
+
+        // The project update goes last: milestones and status updates can be deleted, but
+        // overwritten fields cannot always be restored.
+        let mut added = Added::default();
+        let result = match self.add(id, extras, &mut added).await {
+            Ok(()) => self.client.project_update(id, input).await,
+            Err(err) => Err(err),
+        };
+        match result {
+            Ok(project) => Ok(added.into_setup(project)),
+            Err(err) => {
+                let undo = self.remove(&added).await;
+                Err(rolled_back(
+                    err,
+                    undo,
+                    "the added milestones and status update",
+                ))
+            }
+        }
+    }
+
+    /// Resolve a user by id, email, name, or display name; `None` unless exactly one user
+    /// matches.
+    pub async fn resolve_user_id(&self, user: &str) -> GraphqlResult<Option<String>> {
+        let users = self.client.search_users(user, 10).await?;
+        let exact: Vec<_> = users
+            .iter()
+            .filter(|candidate| {
+                candidate.id == user
+                    || [&candidate.email, &candidate.name, &candidate.display_name]
+                        .into_iter()
+                        .flatten()
+                        .any(|value| value.eq_ignore_ascii_case(user))
+            })
+            .collect();
+        Ok(match (exact.as_slice(), users.as_slice()) {
+            ([only], _) => Some(only.id.clone()),
+            ([], [only]) => Some(only.id.clone()),
+            _ => None,
+        })
+    }
+
+    async fn add(
+        &self,
+        project_id: &str,
+        extras: &ProjectExtras,
+        added: &mut Added,
+    ) -> GraphqlResult<()> {
+        for milestone in &extras.milestones {
+            let created = self
+                .client
+                .project_milestone_create(ProjectMilestoneCreateInput {
+                    project_id: project_id.to_owned(),
+                    name: milestone.name.clone(),
+                    target_date: milestone.target_date,
+                })
+                .await?;
+            added.milestones.push(created);
+        }
+        if let Some(body) = &extras.status_update {
+            let created = self
+                .client
+                .project_status_update_create(ProjectStatusUpdateCreateInput {
+                    project_id: project_id.to_owned(),
+                    body: body.clone(),
+                })
+                .await?;
+            added.status_update = Some(created);
+        }
+        Ok(())
+    }
+
+    /// Delete what [`Self::add`] created, newest first; keeps going past failures and reports
+    /// the first.
+    async fn remove(&self, added: &Added) -> GraphqlResult<()> {
+        let mut first_error = None;
+        if let Some(update) = &added.status_update {
+            if let Err(err) = self.client.project_status_update_delete(&update.id).await {
+                first_error.get_or_insert(err);
+            }
+        }
+        for milestone in added.milestones.iter().rev() {
+            if let Err(err) = self.client.project_milestone_delete(&milestone.id).await {
+                first_error.get_or_insert(err);
+            }
+        }
+        first_error.map_or(Ok(()), Err)
+    }
+}
+
+/// `err`, noting what was left behind when rolling back failed too.
+fn rolled_back(err: GraphqlError, undo: GraphqlResult<()>, leftover: &str) -> GraphqlError {
+    match undo {
+        Ok(()) => err,
+        Err(undo_err) => GraphqlError::OperationFailed(format!(
+            "{err}; rolling back also failed ({undo_err}), so {leftover} may remain"
+        )),
+    }
+}
+
+/// A milestone given as `NAME` or `NAME:YYYY-MM-DD`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MilestoneSpec {
+    pub name: String,
+    pub target_date: Option<NaiveDate>,
+}
+
+impl FromStr for MilestoneSpec {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (name, target_date) = match input.rsplit_once(':') {
+            Some((name, date)) => match NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d") {
+                Ok(date) => (name, Some(date)),
+                Err(_) => return Err(format!("expected NAME or NAME:YYYY-MM-DD, got '{input}'")),
+            },
+            None => (input, None),
+        };
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(format!("milestone name is empty in '{input}'"));
+        }
+        Ok(Self {
+            name: name.to_owned(),
+            target_date,
+        })
+    }
+}
+
+/// What [`ProjectService::create_with`] and [`ProjectService::update_with`] add besides the
+/// project's own fields.
+#[derive(Debug, Clone, Default)]
+pub struct ProjectExtras {
+    pub milestones: Vec<MilestoneSpec>,
+    /// Body of a status update to post.
+    pub status_update: Option<String>,
+}
+
+/// A project with the milestones and status update added alongside it.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectSetup {
+    #[serde(flatten)]
+    pub project: ProjectDetail,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub milestones: Vec<ProjectMilestone>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_update: Option<ProjectStatusUpdate>,
+}
+
+#[derive(Default)]
+struct Added {
+    milestones: Vec<ProjectMilestone>,
+    status_update: Option<ProjectStatusUpdate>,
+}
+
+impl Added {
+    fn into_setup(self, project: ProjectDetail) -> ProjectSetup {
+        ProjectSetup {
+            project,
+            milestones: self.milestones,
+            status_update: self.status_update,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -104,3 +342,162 @@ impl ProjectQueryOptions {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::AuthSession;
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    fn project() -> Value {
+        json!({
+            "id": "project-1",
+            "name": "Launch",
+            "updatedAt": "2024-01-01T00:00:00Z",
+            "createdAt": "2024-01-01T00:00:00Z",
+            "teams": []
+        })
+    }
+
+    fn service(server: &MockServer) -> ProjectService {
+        ProjectService::new(
+            LinearGraphqlClient::with_endpoint(
+                &AuthSession::new_api_key("test-key".into()),
+                &server.url("/graphql"),
+            )
+            .unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn get_reads_progress_and_reports_missing_projects() {
+        let server = MockServer::start();
+        let mut body = project();
+        body["progress"] = json!(0.4);
+        let found = server.mock(|when, then| {
+            when.method(POST)
+                .body_contains("query ProjectById")
+                .body_contains("project-1");
+            then.status(200)
+                .json_body_obj(&json!({ "data": { "project": body } }));
+        });
+        let missing = server.mock(|when, then| {
+            when.method(POST).body_contains("project-2");
+            then.status(200)
+                .json_body_obj(&json!({ "data": { "project": null } }));
+        });
+
+        let service = service(&server);
+        let detail = service.get("project-1").await.unwrap();
+        let err = service.get("project-2").await.unwrap_err();
+
+        found.assert();
+        missing.assert();
+        assert_eq!(detail.progress, Some(0.4));
+        assert!(matches!(err, GraphqlError::NotFound));
+    }
+
+    #[tokio::test]
+    async fn deletes_created_project_when_a_milestone_fails() {
+        let server = MockServer::start();
+        let create = server.mock(|when, then| {
+            when.method(POST).body_contains("mutation ProjectCreate");
+            then.status(200).json_body_obj(&json!({
+                "data": { "projectCreate": { "success": true, "project": project() } }
+            }));
+        });
+        let milestone = server.mock(|when, then| {
+            when.method(POST).body_contains("ProjectMilestoneCreate");
+            then.status(200).json_body_obj(&json!({
+                "errors": [{ "message": "targetDate is after the project target" }]
+            }));
+        });
+        let delete = server.mock(|when, then| {
+            when.method(POST)
+                .body_contains("ProjectDelete")
+                .body_contains("project-1");
+            then.status(200).json_body_obj(&json!({
+                "data": { "projectDelete": { "success": true } }
+            }));
+        });
+
+        let extras = ProjectExtras {
+            milestones: vec!["Beta:2025-06-01".parse().unwrap()],
+            status_update: Some("Kicking off".into()),
+        };
+        let err = service(&server)
+            .create_with(ProjectCreateInput::new("Launch"), &extras)
+            .await
+            .unwrap_err();
+
+        create.assert();
+        milestone.assert();
+        delete.assert();
+        assert!(err.to_string().contains("targetDate is after"));
+    }
+
+    #[tokio::test]
+    async fn update_adds_members_and_undoes_milestones_on_failure() {
+        let server = MockServer::start();
+        let members = server.mock(|when, then| {
+            when.method(POST).body_contains("ProjectMembers");
+            then.status(200).json_body_obj(&json!({
+                "data": { "project": { "members": { "nodes": [{ "id": "user-1" }] } } }
+            }));
+        });
+        let milestone = server.mock(|when, then| {
+            when.method(POST).body_contains("ProjectMilestoneCreate");
+            then.status(200).json_body_obj(&json!({
+                "data": { "projectMilestoneCreate": {
+                    "success": true,
+                    "projectMilestone": { "id": "m-1", "name": "GA", "targetDate": null }
+                } }
+            }));
+        });
+        let update = server.mock(|when, then| {
+            when.method(POST)
+                .body_contains("mutation ProjectUpdate(")
+                .body_contains(r#""memberIds":["user-1","user-2"]"#);
+            then.status(200).json_body_obj(&json!({
+                "errors": [{ "message": "Entity not found" }]
+            }));
+        });
+        let undo = server.mock(|when, then| {
+            when.method(POST)
+                .body_contains("ProjectMilestoneDelete")
+                .body_contains("m-1");
+            then.status(200).json_body_obj(&json!({
+                "data": { "projectMilestoneDelete": { "success": true } }
+            }));
+        });
+
+        let input = ProjectUpdateInput {
+            member_ids: vec!["user-2".into(), "user-1".into()],
+            ..Default::default()
+        };
+        let extras = ProjectExtras {
+            milestones: vec!["GA".parse().unwrap()],
+            status_update: None,
+        };
+        service(&server)
+            .update_with("project-1", input, &extras)
+            .await
+            .unwrap_err();
+
+        members.assert();
+        milestone.assert();
+        update.assert();
+        undo.assert();
+    }
+
+    #[test]
+    fn parses_milestone_specs() {
+        let spec: MilestoneSpec = "Beta: 2025-06-01".parse().unwrap();
+        assert_eq!(spec.name, "Beta");
+        assert_eq!(spec.target_date, NaiveDate::from_ymd_opt(2025, 6, 1));
+        assert_eq!("GA".parse::<MilestoneSpec>().unwrap().target_date, None);
+        assert!("Beta:June".parse::<MilestoneSpec>().is_err());
+        assert!(":2025-06-01".parse::<MilestoneSpec>().is_err());
+    }
+}
@@ -0,0 +1,190 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::graphql::{
+    DocumentSummary, GraphqlResult, IssueSummary, LinearGraphqlClient, ProjectSummary, UserProfile,
+};
+
+/// Entity types covered by workspace search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchKind {
+    Issue,
+    Project,
+    Document,
+    User,
+}
+
+impl SearchKind {
+    pub const ALL: [SearchKind; 4] = [
+        SearchKind::Issue,
+        SearchKind::Project,
+        SearchKind::Document,
+        SearchKind::User,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SearchKind::Issue => "issue",
+            SearchKind::Project => "project",
+            SearchKind::Document => "document",
+            SearchKind::User => "user",
+        }
+    }
+}
+
+impl FromStr for SearchKind {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.trim().to_ascii_lowercase().as_str() {
+            "issue" | "issues" => Ok(SearchKind::Issue),
+            "project" | "projects" => Ok(SearchKind::Project),
+            "document" | "documents" | "doc" | "docs" => Ok(SearchKind::Document),
+            "user" | "users" => Ok(SearchKind::User),
+            other => Err(format!(
+                "unknown search type '{}'; expected issue, project, document, or user",
+                other
+            )),
+        }
+    }
+}
+
+/// Search results grouped by entity type.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SearchResults {
+    pub issues: Vec<IssueSummary>,
+    pub projects: Vec<ProjectSummary>,
+    pub documents: Vec<DocumentSummary>,
+    pub users: Vec<UserProfile>,
+}
+
+impl SearchResults {
+    pub fn is_empty(&self) -> bool {
+        self.issues.is_empty()
+            && self.projects.is_empty()
+            && self.documents.is_empty()
+            && self.users.is_empty()
+    }
+}
+
+#[derive(Clone)]
+pub struct SearchService {
+    client: LinearGraphqlClient,
+}
+
+impl SearchService {
+    pub fn new(client: LinearGraphqlClient) -> Self {
+        Self { client }
+    }
+
+    /// Search the selected entity types concurrently, up to `limit` results each.
+    pub async fn search(
+        &self,
+        term: &str,
+        kinds: &[SearchKind],
+        limit: usize,
+    ) -> GraphqlResult<SearchResults> {
+        let wants = |kind| kinds.contains(&kind);
+        let issues = async {
+            if wants(SearchKind::Issue) {
+                self.client.search_issues(term, limit).await
+            } else {
+                Ok(Vec::new())
+            }
+        };
+        let projects = async {
+            if wants(SearchKind::Project) {
+                self.client.search_projects(term, limit).await
+            } else {
+                Ok(Vec::new())
+            }
+        };
+        let documents = async {
+            if wants(SearchKind::Document) {
+                self.client.search_documents(term, limit).await
+            } else {
+                Ok(Vec::new())
+            }
+        };
+        let users = async {
+            if wants(SearchKind::User) {
+                self.client.search_users(term, limit).await
+            } else {
+                Ok(Vec::new())
+            }
+        };
+        let (issues, projects, documents, users) =
+            tokio::try_join!(issues, projects, documents, users)?;
+        Ok(SearchResults {
+            issues,
+            projects,
+            documents,
+            users,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::AuthSession;
+    use httpmock::prelude::*;
+
+    #[tokio::test]
+    async fn searches_only_selected_kinds() {
+        let server = MockServer::start();
+        let issues = server.mock(|when, then| {
+            when.method(POST)
+                .path("/graphql")
+                .body_contains("searchIssues");
+            then.status(200).json_body_obj(&serde_json::json!({
+                "data": { "searchIssues": { "nodes": [{
+                    "id": "issue-1",
+                    "identifier": "ENG-1",
+                    "title": "Login fails",
+                    "createdAt": "2024-01-01T00:00:00Z",
+                    "updatedAt": "2024-01-02T00:00:00Z"
+                }] } }
+            }));
+        });
+        let users = server.mock(|when, then| {
+            when.method(POST)
+                .path("/graphql")
+                .body_contains("SearchUsers");
+            then.status(200).json_body_obj(&serde_json::json!({
+                "data": { "users": { "nodes": [{
+                    "id": "user-1",
+                    "name": "Ada Lovelace",
+                    "email": "ada@example.com",
+                    "active": true
+                }] } }
+            }));
+        });
+        let projects = server.mock(|when, then| {
+            when.method(POST)
+                .path("/graphql")
+                .body_contains("searchProjects");
+            then.status(500);
+        });
+
+        let client = LinearGraphqlClient::with_endpoint(
+            &AuthSession::new_api_key("test-key".into()),
+            &server.url("/graphql"),
+        )
+        .unwrap();
+        let results = SearchService::new(client)
+            .search("login", &[SearchKind::Issue, SearchKind::User], 5)
+            .await
+            .unwrap();
+
+        issues.assert();
+        users.assert();
+        projects.assert_hits(0);
+        assert_eq!(results.issues[0].identifier, "ENG-1");
+        assert_eq!(results.users[0].email.as_deref(), Some("ada@example.com"));
+        assert!(results.projects.is_empty() && results.documents.is_empty());
+        assert_eq!("docs".parse::<SearchKind>(), Ok(SearchKind::Document));
+    }
+}
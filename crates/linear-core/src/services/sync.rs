@@ -0,0 +1,137 @@
+//! Incremental issue refreshes keyed on `updatedAt` watermarks.
+//!
+//! A cached [`IssueSet`] remembers the newest `updatedAt` it holds; the next refresh asks only
+//! for issues updated since then ([`IssueService::changes_since`]) and folds them in with
+//! [`IssueSet::merge`] instead of downloading the whole list again.
+//!
+//! [`IssueService::changes_since`]: crate::services::issues::IssueService::changes_since
+
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::graphql::IssueSummary;
+
+/// Issues cached for one filter, newest update first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IssueSet {
+    pub issues: Vec<IssueSummary>,
+    /// Newest `updatedAt` among `issues`; `None` while the set is empty.
+    pub watermark: Option<DateTime<Utc>>,
+}
+
+/// What changed for a filter since an [`IssueSet`]'s watermark.
+#[derive(Debug, Clone, Default)]
+pub struct IssueChanges {
+    /// Issues that match the filter and are new or newer than the cached copy.
+    pub updated: Vec<IssueSummary>,
+    /// Ids of cached issues that were updated and no longer match the filter.
+    pub departed: Vec<String>,
+}
+
+impl IssueChanges {
+    pub fn is_empty(&self) -> bool {
+        self.updated.is_empty() && self.departed.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.updated.len() + self.departed.len()
+    }
+}
+
+impl IssueSet {
+    pub fn new(mut issues: Vec<IssueSummary>) -> Self {
+        issues.sort_by_key(|issue| std::cmp::Reverse(issue.updated_at));
+        let watermark = issues.first().map(|issue| issue.updated_at);
+        Self { issues, watermark }
+    }
+
+    /// Whether `issue` is cached with the same `updatedAt`.
+    pub fn holds(&self, issue: &IssueSummary) -> bool {
+        self.issues
+            .iter()
+            .any(|cached| cached.id == issue.id && cached.updated_at == issue.updated_at)
+    }
+
+    /// `true` when applying `changes` only refreshes issues already in the set, so its
+    /// membership (and any cursor taken after its last issue) stays valid.
+    pub fn keeps_membership(&self, changes: &IssueChanges) -> bool {
+        let ids: HashSet<&str> = self.issues.iter().map(|issue| issue.id.as_str()).collect();
+        changes.departed.is_empty()
+            && changes
+                .updated
+                .iter()
+                .all(|issue| ids.contains(issue.id.as_str()))
+    }
+
+    /// Replace or add the updated issues, drop departed ones, and advance the watermark.
+    pub fn merge(&mut self, changes: IssueChanges) {
+        let replaced: HashSet<String> = changes
+            .updated
+            .iter()
+            .map(|issue| issue.id.clone())
+            .chain(changes.departed)
+            .collect();
+        self.issues.retain(|issue| !replaced.contains(&issue.id));
+        self.issues.extend(changes.updated);
+        self.issues
+            .sort_by_key(|issue| std::cmp::Reverse(issue.updated_at));
+        let newest = self.issues.first().map(|issue| issue.updated_at);
+        self.watermark = self.watermark.max(newest);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn issue(id: &str, minute: u32) -> IssueSummary {
+        IssueSummary {
+            id: id.into(),
+            identifier: format!("ENG-{id}"),
+            title: format!("Issue {id}"),
+            url: None,
+            branch_name: None,
+            state: None,
+            assignee: None,
+            priority: None,
+            created_at: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+            updated_at: Utc.with_ymd_and_hms(2024, 1, 1, 9, minute, 0).unwrap(),
+            project: None,
+            labels: None,
+        }
+    }
+
+    #[test]
+    fn merges_changes_and_advances_watermark() {
+        let mut set = IssueSet::new(vec![issue("1", 5), issue("2", 10), issue("3", 1)]);
+        assert_eq!(set.watermark, Some(issue("2", 10).updated_at));
+        assert!(set.holds(&issue("1", 5)));
+        assert!(!set.holds(&issue("1", 6)));
+
+        let touch = IssueChanges {
+            updated: vec![issue("3", 20)],
+            departed: Vec::new(),
+        };
+        assert!(set.keeps_membership(&touch));
+        let churn = IssueChanges {
+            updated: vec![issue("4", 15)],
+            departed: vec!["1".into()],
+        };
+        assert!(!set.keeps_membership(&churn));
+
+        set.merge(touch);
+        set.merge(churn);
+        let ids: Vec<&str> = set.issues.iter().map(|issue| issue.id.as_str()).collect();
+        assert_eq!(ids, ["3", "4", "2"]);
+        assert_eq!(set.watermark, Some(issue("3", 20).updated_at));
+
+        set.merge(IssueChanges {
+            updated: Vec::new(),
+            departed: vec!["3".into()],
+        });
+        assert_eq!(set.watermark, Some(issue("3", 20).updated_at));
+    }
+}
@@ -0,0 +1,505 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::graphql::{
+    GraphqlError, GraphqlResult, IssueLabel, IssueLabelCreateInput, IssueLabelUpdateInput,
+    LinearGraphqlClient, TeamSummary, TemplateCreateInput, TemplateSummary, TemplateUpdateInput,
+    WorkflowStateCreateInput, WorkflowStateSummary, WorkflowStateUpdateInput,
+};
+
+/// Declarative description of team labels, workflow states, and templates (`workspace.toml`).
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct WorkspaceSpec {
+    #[serde(default)]
+    pub teams: Vec<TeamSpec>,
+}
+
+impl WorkspaceSpec {
+    pub fn from_toml(raw: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(raw)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TeamSpec {
+    pub key: String,
+    #[serde(default)]
+    pub labels: Vec<LabelSpec>,
+    #[serde(default)]
+    pub states: Vec<StateSpec>,
+    #[serde(default)]
+    pub templates: Vec<TemplateSpec>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LabelSpec {
+    pub name: String,
+    pub color: Option<String>,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct StateSpec {
+    pub name: String,
+    /// One of `triage`, `backlog`, `unstarted`, `started`, `completed`, `canceled`.
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub color: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TemplateSpec {
+    pub name: String,
+    #[serde(rename = "type", default = "default_template_type")]
+    pub type_name: String,
+    pub description: Option<String>,
+    /// Prefilled fields, e.g. `title`, `description`, `priority`.
+    #[serde(default)]
+    pub data: Value,
+}
+
+fn default_template_type() -> String {
+    "issue".into()
+}
+
+/// Current remote state of a single team.
+#[derive(Debug, Clone)]
+pub struct TeamSnapshot {
+    pub team: TeamSummary,
+    pub labels: Vec<IssueLabel>,
+    pub states: Vec<WorkflowStateSummary>,
+    pub templates: Vec<TemplateSummary>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeAction {
+    Create,
+    Update,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ResourceKind {
+    Label,
+    State,
+    Template,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldChange {
+    pub field: &'static str,
+    pub from: Option<String>,
+    pub to: String,
+}
+
+/// One planned create/update, with a field-level diff for display.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Change {
+    pub action: ChangeAction,
+    pub resource: ResourceKind,
+    pub team_key: String,
+    pub name: String,
+    pub fields: Vec<FieldChange>,
+    #[serde(skip)]
+    operation: Operation,
+}
+
+#[derive(Debug, Clone)]
+enum Operation {
+    CreateLabel(IssueLabelCreateInput),
+    UpdateLabel(String, IssueLabelUpdateInput),
+    CreateState(WorkflowStateCreateInput),
+    UpdateState(String, WorkflowStateUpdateInput),
+    CreateTemplate(TemplateCreateInput),
+    UpdateTemplate(String, TemplateUpdateInput),
+}
+
+/// Compute the changes needed to bring `snapshot` in line with `spec`.
+///
+/// Resources are matched by case-insensitive name and never deleted. Workflow state types
+/// cannot be changed through the API, so a differing `type` is ignored for existing states.
+pub fn plan_team(spec: &TeamSpec, snapshot: &TeamSnapshot) -> Vec<Change> {
+    let team_id = snapshot.team.id.clone();
+    let mut changes = Vec::new();
+    let change = |action, resource, name: &str, fields, operation| Change {
+        action,
+        resource,
+        team_key: snapshot.team.key.clone(),
+        name: name.to_owned(),
+        fields,
+        operation,
+    };
+
+    for label in &spec.labels {
+        match snapshot
+            .labels
+            .iter()
+            .find(|existing| existing.name.eq_ignore_ascii_case(&label.name))
+        {
+            None => changes.push(change(
+                ChangeAction::Create,
+                ResourceKind::Label,
+                &label.name,
+                created_fields(&[
+                    ("color", label.color.as_deref()),
+                    ("description", label.description.as_deref()),
+                ]),
+                Operation::CreateLabel(IssueLabelCreateInput {
+                    team_id: team_id.clone(),
+                    name: label.name.clone(),
+                    description: label.description.clone(),
+                    color: label.color.clone(),
+                }),
+            )),
+            Some(existing) => {
+                let mut fields = Vec::new();
+                let input = IssueLabelUpdateInput {
+                    color: diff_color(&mut fields, &existing.color, &label.color),
+                    description: diff_text(
+                        &mut fields,
+                        "description",
+                        &existing.description,
+                        &label.description,
+                    ),
+                    ..Default::default()
+                };
+                if !fields.is_empty() {
+                    changes.push(change(
+                        ChangeAction::Update,
+                        ResourceKind::Label,
+                        &existing.name,
+                        fields,
+                        Operation::UpdateLabel(existing.id.clone(), input),
+                    ));
+                }
+            }
+        }
+    }
+
+    for state in &spec.states {
+        match snapshot
+            .states
+            .iter()
+            .find(|existing| existing.name.eq_ignore_ascii_case(&state.name))
+        {
+            None => changes.push(change(
+                ChangeAction::Create,
+                ResourceKind::State,
+                &state.name,
+                created_fields(&[
+                    ("type", Some(state.type_name.as_str())),
+                    ("color", Some(state.color.as_str())),
+                    ("description", state.description.as_deref()),
+                ]),
+                Operation::CreateState(WorkflowStateCreateInput {
+                    team_id: team_id.clone(),
+                    name: state.name.clone(),
+                    type_name: state.type_name.clone(),
+                    color: state.color.clone(),
+                    description: state.description.clone(),
+                }),
+            )),
+            Some(existing) => {
+                let mut fields = Vec::new();
+                let input = WorkflowStateUpdateInput {
+                    color: diff_color(&mut fields, &existing.color, &Some(state.color.clone())),
+                    description: diff_text(
+                        &mut fields,
+                        "description",
+                        &existing.description,
+                        &state.description,
+                    ),
+                    ..Default::default()
+                };
+                if !fields.is_empty() {
+                    changes.push(change(
+                        ChangeAction::Update,
+                        ResourceKind::State,
+                        &existing.name,
+                        fields,
+                        Operation::UpdateState(existing.id.clone(), input),
+                    ));
+                }
+            }
+        }
+    }
+
+    for template in &spec.templates {
+        let data = if template.data.is_null() {
+            Value::Object(Default::default())
+        } else {
+            template.data.clone()
+        };
+        match snapshot
+            .templates
+            .iter()
+            .find(|existing| existing.name.eq_ignore_ascii_case(&template.name))
+        {
+            None => changes.push(change(
+                ChangeAction::Create,
+                ResourceKind::Template,
+                &template.name,
+                created_fields(&[
+                    ("type", Some(template.type_name.as_str())),
+                    ("description", template.description.as_deref()),
+                    ("data", Some(data.to_string().as_str())),
+                ]),
+                Operation::CreateTemplate(TemplateCreateInput {
+                    type_name: template.type_name.clone(),
+                    team_id: team_id.clone(),
+                    name: template.name.clone(),
+                    description: template.description.clone(),
+                    template_data: data,
+                }),
+            )),
+            Some(existing) => {
+                let mut fields = Vec::new();
+                let mut input = TemplateUpdateInput {
+                    description: diff_text(
+                        &mut fields,
+                        "description",
+                        &existing.description,
+                        &template.description,
+                    ),
+                    ..Default::default()
+                };
+                if !template.data.is_null() && existing.template_data != data {
+                    fields.push(FieldChange {
+                        field: "data",
+                        from: Some(existing.template_data.to_string()),
+                        to: data.to_string(),
+                    });
+                    input.template_data = Some(data);
+                }
+                if !fields.is_empty() {
+                    changes.push(change(
+                        ChangeAction::Update,
+                        ResourceKind::Template,
+                        &existing.name,
+                        fields,
+                        Operation::UpdateTemplate(existing.id.clone(), input),
+                    ));
+                }
+            }
+        }
+    }
+
+    changes
+}
+
+fn created_fields(values: &[(&'static str, Option<&str>)]) -> Vec<FieldChange> {
+    values
+        .iter()
+        .filter_map(|(field, value)| {
+            value.map(|value| FieldChange {
+                field,
+                from: None,
+                to: value.to_owned(),
+            })
+        })
+        .collect()
+}
+
+fn diff_color(
+    fields: &mut Vec<FieldChange>,
+    current: &Option<String>,
+    desired: &Option<String>,
+) -> Option<String> {
+    let desired = desired.as_ref()?;
+    let unchanged = current
+        .as_ref()
+        .is_some_and(|current| current.eq_ignore_ascii_case(desired));
+    if unchanged {
+        return None;
+    }
+    fields.push(FieldChange {
+        field: "color",
+        from: current.clone(),
+        to: desired.clone(),
+    });
+    Some(desired.clone())
+}
+
+fn diff_text(
+    fields: &mut Vec<FieldChange>,
+    field: &'static str,
+    current: &Option<String>,
+    desired: &Option<String>,
+) -> Option<String> {
+    let desired = desired.as_ref()?;
+    if current.as_deref().unwrap_or_default() == desired {
+        return None;
+    }
+    fields.push(FieldChange {
+        field,
+        from: current.clone(),
+        to: desired.clone(),
+    });
+    Some(desired.clone())
+}
+
+#[derive(Clone)]
+pub struct WorkspaceService {
+    client: LinearGraphqlClient,
+}
+
+impl WorkspaceService {
+    pub fn new(client: LinearGraphqlClient) -> Self {
+        Self { client }
+    }
+
+    /// Fetch the current state of every team named in `spec` and compute the plan.
+    pub async fn plan(&self, spec: &WorkspaceSpec) -> GraphqlResult<Vec<Change>> {
+        let teams = self.client.teams().await?;
+        let templates = if spec.teams.iter().any(|team| !team.templates.is_empty()) {
+            self.client.templates().await?
+        } else {
+            Vec::new()
+        };
+
+        let mut changes = Vec::new();
+        for team_spec in &spec.teams {
+            let team = teams
+                .iter()
+                .find(|team| team.key.eq_ignore_ascii_case(&team_spec.key))
+                .cloned()
+                .ok_or_else(|| {
+                    GraphqlError::OperationFailed(format!("team '{}' not found", team_spec.key))
+                })?;
+            let snapshot = TeamSnapshot {
+                labels: self.client.issue_labels(&team.id).await?,
+                states: self.client.workflow_states(&team.id).await?,
+                templates: templates
+                    .iter()
+                    .filter(|template| {
+                        template.team.as_ref().map(|t| t.id.as_str()) == Some(team.id.as_str())
+                    })
+                    .cloned()
+                    .collect(),
+                team,
+            };
+            changes.extend(plan_team(team_spec, &snapshot));
+        }
+        Ok(changes)
+    }
+
+    /// Execute a single planned change.
+    pub async fn apply(&self, change: &Change) -> GraphqlResult<()> {
+        match change.operation.clone() {
+            Operation::CreateLabel(input) => {
+                self.client.create_issue_label(input).await?;
+            }
+            Operation::UpdateLabel(id, input) => {
+                self.client.update_issue_label(&id, input).await?;
+            }
+            Operation::CreateState(input) => {
+                self.client.create_workflow_state(input).await?;
+            }
+            Operation::UpdateState(id, input) => {
+                self.client.update_workflow_state(&id, input).await?;
+            }
+            Operation::CreateTemplate(input) => {
+                self.client.create_template(input).await?;
+            }
+            Operation::UpdateTemplate(id, input) => {
+                self.client.update_template(&id, input).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot() -> TeamSnapshot {
+        TeamSnapshot {
+            team: TeamSummary {
+                id: "team-1".into(),
+                name: "Engineering".into(),
+                key: "ENG".into(),
+            },
+            labels: vec![IssueLabel {
+                id: "label-1".into(),
+                name: "Bug".into(),
+                color: Some("#FF0000".into()),
+                description: None,
+            }],
+            states: vec![WorkflowStateSummary {
+                id: "state-1".into(),
+                name: "In Review".into(),
+                type_name: Some("started".into()),
+                color: Some("#aaaaaa".into()),
+                description: None,
+            }],
+            templates: vec![],
+        }
+    }
+
+    #[test]
+    fn plan_creates_missing_and_updates_drifted_resources() {
+        let spec = WorkspaceSpec::from_toml(
+            r##"
+            [[teams]]
+            key = "ENG"
+
+            [[teams.labels]]
+            name = "bug"
+            color = "#ff0000"
+
+            [[teams.labels]]
+            name = "chore"
+            color = "#cccccc"
+
+            [[teams.states]]
+            name = "In Review"
+            type = "started"
+            color = "#bbbbbb"
+
+            [[teams.templates]]
+            name = "Bug report"
+            data = { title = "Bug: ", priority = 2 }
+            "##,
+        )
+        .unwrap();
+
+        let changes = plan_team(&spec.teams[0], &snapshot());
+        let summary: Vec<_> = changes
+            .iter()
+            .map(|change| (change.action, change.resource, change.name.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (ChangeAction::Create, ResourceKind::Label, "chore"),
+                (ChangeAction::Update, ResourceKind::State, "In Review"),
+                (ChangeAction::Create, ResourceKind::Template, "Bug report"),
+            ]
+        );
+        assert_eq!(
+            changes[1].fields,
+            vec![FieldChange {
+                field: "color",
+                from: Some("#aaaaaa".into()),
+                to: "#bbbbbb".into(),
+            }]
+        );
+    }
+
+    #[test]
+    fn plan_is_empty_when_in_sync() {
+        let spec = WorkspaceSpec::from_toml(
+            r##"
+            [[teams]]
+            key = "ENG"
+            labels = [{ name = "Bug", color = "#ff0000" }]
+            "##,
+        )
+        .unwrap();
+        assert!(plan_team(&spec.teams[0], &snapshot()).is_empty());
+    }
+}
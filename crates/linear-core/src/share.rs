@@ -0,0 +1,375 @@
+//! Issue lists rendered for sharing outside the terminal: a markdown table for wikis and chat,
+//! or a standalone HTML page for email, behind `issue list --output markdown|html` and the TUI's
+//! `export` command.
+
+use std::fmt::Write;
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+
+use crate::color::Rgb;
+use crate::graphql::IssueSummary;
+use crate::group::IssueGroup;
+use crate::priority;
+
+/// Document format of a [`Snapshot`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShareFormat {
+    Markdown,
+    Html,
+}
+
+impl ShareFormat {
+    /// Conventional file extension, without the dot.
+    pub fn extension(self) -> &'static str {
+        match self {
+            ShareFormat::Markdown => "md",
+            ShareFormat::Html => "html",
+        }
+    }
+}
+
+impl FromStr for ShareFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "md" | "markdown" => Ok(ShareFormat::Markdown),
+            "html" | "htm" => Ok(ShareFormat::Html),
+            other => Err(format!(
+                "unknown export format '{other}' (expected md or html)"
+            )),
+        }
+    }
+}
+
+/// A titled issue list, optionally split into sections, as of `generated_at`.
+#[derive(Debug, Clone)]
+pub struct Snapshot<'a> {
+    pub title: String,
+    /// A line under the title, e.g. the filters that produced the list.
+    pub subtitle: Option<String>,
+    pub generated_at: DateTime<Utc>,
+    /// Sections in order; a single section with an empty label renders without a heading.
+    pub groups: Vec<IssueGroup<'a>>,
+}
+
+impl<'a> Snapshot<'a> {
+    /// A snapshot of `issues` as one unheaded section.
+    pub fn new(title: impl Into<String>, issues: &'a [IssueSummary]) -> Self {
+        Self::grouped(
+            title,
+            vec![IssueGroup {
+                label: String::new(),
+                issues: issues.iter().collect(),
+            }],
+        )
+    }
+
+    /// A snapshot with a heading per group.
+    pub fn grouped(title: impl Into<String>, groups: Vec<IssueGroup<'a>>) -> Self {
+        Self {
+            title: title.into(),
+            subtitle: None,
+            generated_at: Utc::now(),
+            groups,
+        }
+    }
+
+    pub fn subtitle(mut self, subtitle: impl Into<String>) -> Self {
+        self.subtitle = Some(subtitle.into());
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.groups.iter().map(|group| group.issues.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.groups.iter().all(|group| group.issues.is_empty())
+    }
+
+    pub fn render(&self, format: ShareFormat) -> String {
+        match format {
+            ShareFormat::Markdown => self.markdown(),
+            ShareFormat::Html => self.html(),
+        }
+    }
+
+    /// A heading, the subtitle, and a GitHub-flavored table per section.
+    pub fn markdown(&self) -> String {
+        let mut out = format!("# {}\n\n", escape_markdown(&self.title));
+        if let Some(subtitle) = &self.subtitle {
+            let _ = writeln!(out, "{}\n", escape_markdown(subtitle));
+        }
+        let _ = writeln!(out, "_{}_\n", self.footer());
+        for group in &self.groups {
+            if !group.label.is_empty() {
+                let _ = writeln!(
+                    out,
+                    "## {} ({})\n",
+                    escape_markdown(&group.label),
+                    group.issues.len()
+                );
+            }
+            if group.issues.is_empty() {
+                out.push_str("No issues.\n\n");
+                continue;
+            }
+            out.push_str("| Key | Title | State | Assignee | Priority | Labels | Updated |\n");
+            out.push_str("| --- | --- | --- | --- | --- | --- | --- |\n");
+            for issue in &group.issues {
+                let key = match &issue.url {
+                    Some(url) => format!("[{}]({})", issue.identifier, url),
+                    None => issue.identifier.clone(),
+                };
+                let cells = [
+                    key,
+                    escape_markdown(&issue.title),
+                    escape_markdown(state_name(issue)),
+                    escape_markdown(assignee_name(issue)),
+                    priority::label_for(issue.priority),
+                    escape_markdown(&label_names(issue).join(", ")),
+                    issue.updated_at.format("%Y-%m-%d").to_string(),
+                ];
+                let _ = writeln!(out, "| {} |", cells.join(" | "));
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// A self-contained page with inline styles, so it survives being pasted into email.
+    pub fn html(&self) -> String {
+        let title = escape_html(&self.title);
+        let mut out = format!(
+            "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n<style>\n{STYLE}</style>\n</head>\n<body>\n<h1>{title}</h1>\n"
+        );
+        if let Some(subtitle) = &self.subtitle {
+            let _ = writeln!(out, "<p class=\"subtitle\">{}</p>", escape_html(subtitle));
+        }
+        for group in &self.groups {
+            if !group.label.is_empty() {
+                let _ = writeln!(
+                    out,
+                    "<h2>{} <span class=\"count\">{}</span></h2>",
+                    escape_html(&group.label),
+                    group.issues.len()
+                );
+            }
+            if group.issues.is_empty() {
+                out.push_str("<p class=\"empty\">No issues.</p>\n");
+                continue;
+            }
+            out.push_str("<table>\n<thead><tr><th>Key</th><th>Title</th><th>State</th><th>Assignee</th><th>Priority</th><th>Labels</th><th>Updated</th></tr></thead>\n<tbody>\n");
+            for issue in &group.issues {
+                let key = match &issue.url {
+                    Some(url) => format!(
+                        "<a href=\"{}\">{}</a>",
+                        escape_html(url),
+                        escape_html(&issue.identifier)
+                    ),
+                    None => escape_html(&issue.identifier),
+                };
+                let _ = writeln!(
+                    out,
+                    "<tr><td class=\"key\">{key}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td class=\"date\">{}</td></tr>",
+                    escape_html(&issue.title),
+                    escape_html(state_name(issue)),
+                    escape_html(assignee_name(issue)),
+                    escape_html(&priority::label_for(issue.priority)),
+                    label_chips(issue),
+                    issue.updated_at.format("%Y-%m-%d"),
+                );
+            }
+            out.push_str("</tbody>\n</table>\n");
+        }
+        let _ = write!(
+            out,
+            "<p class=\"footer\">{}</p>\n</body>\n</html>\n",
+            escape_html(&self.footer())
+        );
+        out
+    }
+
+    fn footer(&self) -> String {
+        format!(
+            "{} issue{} · exported {}",
+            self.len(),
+            if self.len() == 1 { "" } else { "s" },
+            self.generated_at.format("%Y-%m-%d %H:%M UTC")
+        )
+    }
+}
+
+const STYLE: &str = "body { font-family: -apple-system, BlinkMacSystemFont, \"Segoe UI\", Helvetica, Arial, sans-serif; color: #1f2328; margin: 2rem; }
+h1 { font-size: 1.5rem; margin-bottom: 0.25rem; }
+h2 { font-size: 1.1rem; margin-top: 1.5rem; }
+.subtitle, .footer, .count, .empty { color: #656d76; }
+table { border-collapse: collapse; width: 100%; font-size: 0.9rem; }
+th, td { border-bottom: 1px solid #d0d7de; padding: 0.4rem 0.6rem; text-align: left; vertical-align: top; }
+th { background: #f6f8fa; }
+.key, .date { white-space: nowrap; }
+.key a { color: #5e6ad2; text-decoration: none; }
+.label { display: inline-block; border-radius: 1em; padding: 0 0.5em; margin: 0 0.2em 0.2em 0; font-size: 0.8rem; background: #eaeef2; }
+";
+
+fn state_name(issue: &IssueSummary) -> &str {
+    issue
+        .state
+        .as_ref()
+        .map(|state| state.name.as_str())
+        .unwrap_or("-")
+}
+
+fn assignee_name(issue: &IssueSummary) -> &str {
+    issue
+        .assignee
+        .as_ref()
+        .and_then(|a| a.display_name.as_deref().or(a.name.as_deref()))
+        .unwrap_or("-")
+}
+
+fn label_names(issue: &IssueSummary) -> Vec<&str> {
+    issue
+        .labels
+        .iter()
+        .flat_map(|labels| labels.nodes.iter().map(|label| label.name.as_str()))
+        .collect()
+}
+
+/// Labels as pills in their own colors, with black or white text for contrast.
+fn label_chips(issue: &IssueSummary) -> String {
+    issue
+        .labels
+        .iter()
+        .flat_map(|labels| labels.nodes.iter())
+        .map(|label| {
+            let style = label
+                .color
+                .as_deref()
+                .and_then(Rgb::parse_hex)
+                .map(|background| {
+                    let foreground = background.readable_foreground();
+                    format!(
+                        " style=\"background: #{:02x}{:02x}{:02x}; color: #{:02x}{:02x}{:02x}\"",
+                        background.0,
+                        background.1,
+                        background.2,
+                        foreground.0,
+                        foreground.1,
+                        foreground.2
+                    )
+                })
+                .unwrap_or_default();
+            format!(
+                "<span class=\"label\"{style}>{}</span>",
+                escape_html(&label.name)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("")
+}
+
+/// Text safe inside a markdown table cell: pipes and emphasis markers escaped, newlines flattened.
+fn escape_markdown(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '|' | '\\' | '*' | '_' | '`' | '[' | ']' | '<' | '>' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '\n' | '\r' => out.push(' '),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn escape_html(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::group::{group_issues, GroupBy};
+
+    fn issues() -> Vec<IssueSummary> {
+        serde_json::from_value(serde_json::json!([
+            {
+                "id": "1",
+                "identifier": "ENG-1",
+                "title": "Fix <b>login</b> | signup",
+                "url": "https://linear.app/acme/issue/ENG-1",
+                "priority": 1,
+                "createdAt": "2024-01-01T00:00:00Z",
+                "updatedAt": "2024-03-02T10:00:00Z",
+                "state": { "id": "s1", "name": "In Progress", "type": "started" },
+                "assignee": { "id": "u1", "name": "Ada", "displayName": "ada" },
+                "labels": { "nodes": [{ "id": "l1", "name": "Bug", "color": "#eb5757" }] }
+            },
+            {
+                "id": "2",
+                "identifier": "ENG-2",
+                "title": "Write docs",
+                "url": null,
+                "priority": 0,
+                "createdAt": "2024-01-01T00:00:00Z",
+                "updatedAt": "2024-03-01T00:00:00Z",
+                "state": { "id": "s2", "name": "Todo", "type": "unstarted" },
+                "assignee": null
+            }
+        ]))
+        .unwrap()
+    }
+
+    #[test]
+    fn renders_markdown_tables_per_group() {
+        let issues = issues();
+        let mut snapshot = Snapshot::new("Issues", &issues).subtitle("team=ENG");
+        snapshot.generated_at = "2024-03-03T12:00:00Z".parse().unwrap();
+        let markdown = snapshot.markdown();
+        assert!(markdown.starts_with(
+            "# Issues\n\nteam=ENG\n\n_2 issues · exported 2024-03-03 12:00 UTC_\n\n| Key |"
+        ));
+        assert!(markdown.contains(
+            "| [ENG-1](https://linear.app/acme/issue/ENG-1) | Fix \\<b\\>login\\</b\\> \\| signup | In Progress | ada | Urgent | Bug | 2024-03-02 |"
+        ));
+        assert!(
+            markdown.contains("| ENG-2 | Write docs | Todo | - | No priority |  | 2024-03-01 |")
+        );
+        assert!(!markdown.contains("## "));
+
+        let grouped = Snapshot::grouped("Issues", group_issues(&issues, GroupBy::State));
+        let markdown = grouped.markdown();
+        let todo = markdown.find("## Todo (1)").unwrap();
+        assert!(todo < markdown.find("## In Progress (1)").unwrap());
+    }
+
+    #[test]
+    fn renders_standalone_escaped_html() {
+        let issues = issues();
+        let html = Snapshot::new("Q1 <review>", &issues).html();
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<title>Q1 &lt;review&gt;</title>"));
+        assert!(html.contains("Fix &lt;b&gt;login&lt;/b&gt; | signup"));
+        assert!(html.contains("<a href=\"https://linear.app/acme/issue/ENG-1\">ENG-1</a>"));
+        assert!(html.contains("style=\"background: #eb5757; color: #000000\">Bug</span>"));
+        assert!(html.trim_end().ends_with("</html>"));
+        assert_eq!("markdown".parse::<ShareFormat>(), Ok(ShareFormat::Markdown));
+        assert!("pdf".parse::<ShareFormat>().is_err());
+    }
+}
@@ -0,0 +1,34 @@
+//! Running the command lines users configure (credential helpers, hooks, editors, summarizers)
+//! through the platform shell.
+
+use std::process::Command;
+
+/// `line` run by `sh -c`, or `cmd /C` on Windows, so a configured command can carry its own
+/// arguments, pipes, and variable assignments. Arguments added to the result follow the line:
+/// on Unix the first becomes `$0` and the rest `$1`, `$2`, ….
+pub fn shell_command(line: &str) -> Command {
+    let (shell, flag) = if cfg!(windows) {
+        ("cmd", "/C")
+    } else {
+        ("sh", "-c")
+    };
+    let mut command = Command::new(shell);
+    command.arg(flag).arg(line);
+    command
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runs_the_line_with_positional_arguments() {
+        let output = shell_command("printf '%s|%s' \"$1\" \"$(echo piped | tr a-z A-Z)\"")
+            .arg("sh")
+            .arg("odd path.md")
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        assert_eq!(output.stdout, b"odd path.md|PIPED");
+    }
+}
@@ -0,0 +1,104 @@
+//! Last-seen API data persisted per profile, so front-ends can render before the network answers.
+//!
+//! Snapshots are a display cache only: callers show them immediately, then replace them with
+//! fresh results (stale-while-revalidate). Unreadable or outdated files are treated as missing.
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use chrono::{DateTime, Utc};
+use directories::ProjectDirs;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Bumped when cached payload shapes change so old files are ignored.
+const SNAPSHOT_VERSION: u32 = 1;
+
+/// A cached value and when it was fetched.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Snapshot<T> {
+    version: u32,
+    key: String,
+    pub saved_at: DateTime<Utc>,
+    pub value: T,
+}
+
+#[derive(Debug, Clone)]
+pub struct SnapshotStore {
+    dir: PathBuf,
+}
+
+impl SnapshotStore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Store for `profile` under the platform cache directory.
+    pub fn for_profile(profile: &str) -> Option<Self> {
+        let dirs = ProjectDirs::from("app", "linear", "linear-rs")?;
+        Some(Self::new(dirs.cache_dir().join("snapshots").join(profile)))
+    }
+
+    /// Load the snapshot saved under `key`, if present and readable.
+    pub fn load<T: DeserializeOwned>(&self, key: &str) -> Option<Snapshot<T>> {
+        let raw = fs::read(self.path(key)).ok()?;
+        let snapshot: Snapshot<T> = serde_json::from_slice(&raw).ok()?;
+        (snapshot.version == SNAPSHOT_VERSION && snapshot.key == key).then_some(snapshot)
+    }
+
+    /// Persist `value` under `key`, replacing any previous snapshot atomically.
+    pub fn save<T: Serialize>(&self, key: &str, value: &T) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&self.dir, fs::Permissions::from_mode(0o700))?;
+        }
+        let snapshot = Snapshot {
+            version: SNAPSHOT_VERSION,
+            key: key.to_owned(),
+            saved_at: Utc::now(),
+            value,
+        };
+        let path = self.path(key);
+        let temp = path.with_extension("json.tmp");
+        fs::write(&temp, serde_json::to_vec(&snapshot)?)?;
+        fs::rename(temp, path)
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        let digest = Sha256::digest(key.as_bytes());
+        let name: String = digest[..8].iter().map(|b| format!("{b:02x}")).collect();
+        self.dir.join(format!("{name}.json"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn round_trips_values_by_key() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = SnapshotStore::new(temp_dir.path().join("default"));
+        assert!(store.load::<Vec<String>>("teams").is_none());
+
+        store
+            .save("teams", &vec!["ENG".to_string(), "OPS".to_string()])
+            .unwrap();
+        store.save("issues:team=ENG", &vec![1, 2, 3]).unwrap();
+
+        let teams = store.load::<Vec<String>>("teams").unwrap();
+        assert_eq!(teams.value, vec!["ENG", "OPS"]);
+        assert!(teams.saved_at <= Utc::now());
+        assert_eq!(
+            store.load::<Vec<i32>>("issues:team=ENG").unwrap().value,
+            [1, 2, 3]
+        );
+        // A payload of the wrong shape is treated as missing.
+        assert!(store.load::<Vec<i32>>("teams").is_none());
+    }
+}
@@ -0,0 +1,128 @@
+//! Custom user statuses for `linear user set-status` and `linear user status`: emoji
+//! shortcodes and the time a status clears itself.
+
+use chrono::{DateTime, Local, NaiveDate, TimeZone, Utc};
+
+use crate::config::parse_duration;
+
+/// Shortcodes of the emoji people usually pick for a status, shown as the emoji itself.
+const EMOJI: &[(&str, &str)] = &[
+    ("palm_tree", "🌴"),
+    ("beach_with_umbrella", "🏖"),
+    ("airplane", "✈️"),
+    ("house", "🏠"),
+    ("house_with_garden", "🏡"),
+    ("face_with_thermometer", "🤒"),
+    ("sick", "🤒"),
+    ("mask", "😷"),
+    ("hospital", "🏥"),
+    ("calendar", "📆"),
+    ("spiral_calendar_pad", "🗓"),
+    ("coffee", "☕"),
+    ("hamburger", "🍔"),
+    ("fork_and_knife", "🍴"),
+    ("headphones", "🎧"),
+    ("red_circle", "🔴"),
+    ("no_entry", "⛔"),
+    ("speech_balloon", "💬"),
+    ("bus", "🚌"),
+    ("car", "🚗"),
+    ("train", "🚆"),
+    ("baby", "👶"),
+    ("books", "📚"),
+    ("brain", "🧠"),
+    ("rocket", "🚀"),
+    ("zzz", "💤"),
+];
+
+/// Normalise `--emoji`: a bare name such as `palm_tree` becomes `:palm_tree:`; shortcodes and
+/// emoji pass through.
+pub fn emoji_code(raw: &str) -> Option<String> {
+    let raw = raw.trim();
+    if raw.is_empty() {
+        return None;
+    }
+    let name = raw.trim_matches(':');
+    let shortcode = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '+' | '-'));
+    Some(if shortcode {
+        format!(":{name}:")
+    } else {
+        raw.to_owned()
+    })
+}
+
+/// The emoji for a status shortcode when it is a common one, else the status as set.
+pub fn emoji_glyph(code: &str) -> &str {
+    let name = code.trim_matches(':');
+    EMOJI
+        .iter()
+        .find(|(shortcode, _)| *shortcode == name)
+        .map_or(code, |(_, glyph)| glyph)
+}
+
+/// When a status set now should clear: a duration from `now` (`2h`, `3d`), a date (the start
+/// of that day, local time, so `--until 2025-07-01` means back on July 1), or an RFC 3339 time.
+pub fn parse_until(raw: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>, String> {
+    let raw = raw.trim();
+    let until = if let Ok(at) = DateTime::parse_from_rfc3339(raw) {
+        at.with_timezone(&Utc)
+    } else if let Ok(date) = NaiveDate::parse_from_str(raw, "%Y-%m-%d") {
+        Local
+            .from_local_datetime(&date.and_time(Default::default()))
+            .earliest()
+            .map(|at| at.with_timezone(&Utc))
+            .ok_or_else(|| format!("'{raw}' has no local midnight"))?
+    } else {
+        let span = parse_duration(raw).map_err(|_| {
+            format!(
+                "invalid --until '{raw}' (expected e.g. 2h, 3d, 2025-07-01, or an RFC 3339 time)"
+            )
+        })?;
+        chrono::Duration::from_std(span)
+            .ok()
+            .and_then(|span| now.checked_add_signed(span))
+            .ok_or_else(|| format!("--until '{raw}' is too far ahead"))?
+    };
+    if until <= now {
+        return Err(format!("--until '{raw}' is in the past"));
+    }
+    Ok(until)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalises_shortcodes_and_shows_common_ones() {
+        assert_eq!(emoji_code("palm_tree").as_deref(), Some(":palm_tree:"));
+        assert_eq!(emoji_code(":palm_tree:").as_deref(), Some(":palm_tree:"));
+        assert_eq!(emoji_code("🌴").as_deref(), Some("🌴"));
+        assert_eq!(emoji_code(" "), None);
+        assert_eq!(emoji_glyph(":palm_tree:"), "🌴");
+        assert_eq!(emoji_glyph(":custom_party:"), ":custom_party:");
+    }
+
+    #[test]
+    fn until_accepts_durations_and_times_ahead() {
+        let now: DateTime<Utc> = "2025-06-01T12:00:00Z".parse().unwrap();
+        assert_eq!(
+            parse_until("3d", now).unwrap(),
+            "2025-06-04T12:00:00Z".parse::<DateTime<Utc>>().unwrap()
+        );
+        assert_eq!(
+            parse_until("2025-06-02T08:00:00+02:00", now).unwrap(),
+            "2025-06-02T06:00:00Z".parse::<DateTime<Utc>>().unwrap()
+        );
+        let date = parse_until("2025-07-01", now).unwrap();
+        assert_eq!(
+            date.with_timezone(&Local).date_naive(),
+            NaiveDate::from_ymd_opt(2025, 7, 1).unwrap()
+        );
+        assert!(parse_until("2025-05-01", now).unwrap_err().contains("past"));
+        assert!(parse_until("soon", now).is_err());
+    }
+}
@@ -0,0 +1,86 @@
+//! The text `linear issue view --summary` pipes into the `summarize_command` from `config.toml`.
+//! Any command that reads Markdown on stdin and writes a summary to stdout will do, so no
+//! summarization provider is built in.
+
+use std::fmt::Write;
+
+use crate::graphql::IssueDetail;
+
+/// The issue's key, title, state, description, and comments (oldest first) as Markdown.
+pub fn transcript(issue: &IssueDetail) -> String {
+    let mut text = format!("# {}: {}\n", issue.identifier, issue.title);
+    if let Some(state) = &issue.state {
+        let _ = writeln!(text, "\nState: {}", state.name);
+    }
+    let description = issue
+        .description
+        .as_deref()
+        .map(str::trim)
+        .unwrap_or_default();
+    if !description.is_empty() {
+        let _ = write!(text, "\n{description}\n");
+    }
+
+    let mut comments: Vec<_> = issue
+        .comments
+        .as_ref()
+        .map(|comments| comments.nodes.iter().collect())
+        .unwrap_or_default();
+    comments.sort_by_key(|comment| comment.created_at);
+    for comment in comments {
+        let author = comment
+            .user
+            .as_ref()
+            .and_then(|user| user.display_name.as_deref().or(user.name.as_deref()))
+            .unwrap_or("someone");
+        let _ = write!(
+            text,
+            "\n## Comment by {author} on {}\n\n{}\n",
+            comment.created_at.format("%Y-%m-%d"),
+            comment.body.trim()
+        );
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn transcript_lists_comments_oldest_first() {
+        let issue: IssueDetail = serde_json::from_value(json!({
+            "id": "1",
+            "identifier": "ENG-7",
+            "title": "Login loops",
+            "description": "Redirects forever.\n",
+            "url": null,
+            "state": {"id": "s", "name": "In Progress", "type": "started"},
+            "assignee": null,
+            "priority": 2,
+            "labels": null,
+            "team": null,
+            "createdAt": "2024-05-01T00:00:00Z",
+            "updatedAt": "2024-05-03T00:00:00Z",
+            "comments": {"nodes": [
+                {"id": "c2", "body": "Fixed in #41", "createdAt": "2024-05-03T00:00:00Z",
+                 "updatedAt": "2024-05-03T00:00:00Z", "user": null},
+                {"id": "c1", "body": "Seen on Safari", "createdAt": "2024-05-02T00:00:00Z",
+                 "updatedAt": "2024-05-02T00:00:00Z",
+                 "user": {"id": "u", "name": "Ada Lovelace", "displayName": "ada"}}
+            ]},
+            "history": null,
+            "children": null
+        }))
+        .unwrap();
+
+        assert_eq!(
+            transcript(&issue),
+            "# ENG-7: Login loops\n\nState: In Progress\n\nRedirects forever.\n\
+             \n## Comment by ada on 2024-05-02\n\nSeen on Safari\n\
+             \n## Comment by someone on 2024-05-03\n\nFixed in #41\n"
+        );
+    }
+}
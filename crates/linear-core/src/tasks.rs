@@ -0,0 +1,256 @@
+//! Issues rendered for personal task managers: org-mode headings and Taskwarrior import JSON,
+//! behind `linear issue export --output org|taskwarrior`.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::graphql::{IssueAssignee, IssueLabelConnection, IssueState, ProjectRef, TeamSummary};
+use crate::priority::Priority;
+use crate::query::IssueFields;
+
+/// Lines that declare the TODO keywords and priority range [`org_entry`] uses.
+pub const ORG_HEADER: &str = "#+TODO: TODO STARTED | DONE CANCELED\n#+PRIORITIES: A D C\n";
+
+/// The issue fields both formats need.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskIssue {
+    pub id: String,
+    pub identifier: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub url: Option<String>,
+    pub priority: Option<i32>,
+    pub due_date: Option<NaiveDate>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub state: Option<IssueState>,
+    pub assignee: Option<IssueAssignee>,
+    pub team: Option<TeamSummary>,
+    pub project: Option<ProjectRef>,
+    pub labels: Option<IssueLabelConnection>,
+}
+
+impl TaskIssue {
+    /// Selection to pass to [`IssueQuery::select`](crate::query::IssueQuery::select).
+    pub fn fields() -> IssueFields {
+        IssueFields::SUMMARY
+            | IssueFields::DESCRIPTION
+            | IssueFields::DUE_DATE
+            | IssueFields::TEAM
+            | IssueFields::LABELS
+    }
+
+    fn state_type(&self) -> &str {
+        self.state
+            .as_ref()
+            .and_then(|state| state.kind.as_deref())
+            .unwrap_or("unstarted")
+    }
+
+    fn label_names(&self) -> impl Iterator<Item = &str> {
+        self.labels
+            .iter()
+            .flat_map(|labels| labels.nodes.iter().map(|label| label.name.as_str()))
+    }
+}
+
+/// One org-mode heading: state keyword, priority cookie, `KEY title`, label tags, a `DEADLINE`
+/// from the due date, a property drawer, and the description as the body.
+pub fn org_entry(issue: &TaskIssue) -> String {
+    let keyword = match issue.state_type() {
+        "started" => "STARTED",
+        "completed" => "DONE",
+        "canceled" => "CANCELED",
+        _ => "TODO",
+    };
+    let cookie = match issue.priority.and_then(Priority::from_value) {
+        Some(Priority::Urgent) => " [#A]",
+        Some(Priority::High) => " [#B]",
+        Some(Priority::Medium) => " [#C]",
+        Some(Priority::Low) => " [#D]",
+        _ => "",
+    };
+    let title = issue.title.replace(['\n', '\r'], " ");
+    let mut out = format!("* {keyword}{cookie} {} {title}", issue.identifier);
+    let tags: Vec<String> = issue.label_names().map(org_tag).collect();
+    if !tags.is_empty() {
+        out.push_str(&format!(" :{}:", tags.join(":")));
+    }
+    out.push('\n');
+    if let Some(due) = issue.due_date {
+        out.push_str(&format!("DEADLINE: <{}>\n", due.format("%Y-%m-%d %a")));
+    }
+
+    out.push_str(":PROPERTIES:\n");
+    let mut property = |name: &str, value: Option<&str>| {
+        if let Some(value) = value.filter(|value| !value.is_empty()) {
+            out.push_str(&format!(":{name}: {value}\n"));
+        }
+    };
+    property("LINEAR_ID", Some(&issue.identifier));
+    property("URL", issue.url.as_deref());
+    property("TEAM", issue.team.as_ref().map(|team| team.key.as_str()));
+    property(
+        "STATE",
+        issue.state.as_ref().map(|state| state.name.as_str()),
+    );
+    property(
+        "ASSIGNEE",
+        issue
+            .assignee
+            .as_ref()
+            .and_then(|user| user.display_name.as_deref().or(user.name.as_deref())),
+    );
+    property(
+        "PROJECT",
+        issue.project.as_ref().map(|project| project.name.as_str()),
+    );
+    property(
+        "CREATED",
+        Some(&issue.created_at.format("[%Y-%m-%d %a %H:%M]").to_string()),
+    );
+    out.push_str(":END:\n");
+
+    if let Some(description) = issue.description.as_deref().map(str::trim_end) {
+        for line in description.lines() {
+            // A leading `*` would start a new heading.
+            if line.starts_with('*') {
+                out.push(',');
+            }
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// One task in Taskwarrior's import format. The issue id is the task `uuid`, so importing
+/// again updates the same tasks instead of duplicating them.
+pub fn taskwarrior_task(issue: &TaskIssue) -> Value {
+    let stamp = |at: DateTime<Utc>| at.format("%Y%m%dT%H%M%SZ").to_string();
+    let status = match issue.state_type() {
+        "completed" => "completed",
+        "canceled" => "deleted",
+        _ => "pending",
+    };
+    let mut task = json!({
+        "uuid": issue.id,
+        "description": format!("{} {}", issue.identifier, issue.title.replace(['\n', '\r'], " ")),
+        "status": status,
+        "entry": stamp(issue.created_at),
+        "modified": stamp(issue.updated_at),
+    });
+    if status != "pending" {
+        task["end"] = json!(stamp(issue.updated_at));
+    }
+    if let Some(due) = issue.due_date {
+        task["due"] = json!(format!("{}T000000Z", due.format("%Y%m%d")));
+    }
+    let priority = match issue.priority.and_then(Priority::from_value) {
+        Some(Priority::Urgent | Priority::High) => Some("H"),
+        Some(Priority::Medium) => Some("M"),
+        Some(Priority::Low) => Some("L"),
+        _ => None,
+    };
+    if let Some(priority) = priority {
+        task["priority"] = json!(priority);
+    }
+    if let Some(project) = &issue.project {
+        task["project"] = json!(project.name);
+    }
+    let tags: Vec<String> = issue
+        .label_names()
+        .map(|name| name.split_whitespace().collect::<Vec<_>>().join("_"))
+        .collect();
+    if !tags.is_empty() {
+        task["tags"] = json!(tags);
+    }
+    if let Some(url) = &issue.url {
+        task["annotations"] = json!([{ "entry": stamp(issue.created_at), "description": url }]);
+    }
+    task
+}
+
+/// Org tags allow letters, digits, `_`, `@`, `#`, and `%`; anything else becomes `_`.
+fn org_tag(label: &str) -> String {
+    label
+        .trim()
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || matches!(c, '_' | '@' | '#' | '%') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue() -> TaskIssue {
+        serde_json::from_value(json!({
+            "id": "5c1c7b4e-0000-4000-8000-000000000001",
+            "identifier": "ENG-7",
+            "title": "Fix login",
+            "description": "Steps:\n* open app\nthen fail",
+            "url": "https://linear.app/acme/issue/ENG-7",
+            "priority": 2,
+            "dueDate": "2024-05-03",
+            "createdAt": "2024-05-01T09:30:00Z",
+            "updatedAt": "2024-05-02T10:00:00Z",
+            "state": {"id": "s1", "name": "In Progress", "type": "started"},
+            "assignee": {"id": "u1", "name": "Ada", "displayName": "ada"},
+            "team": {"id": "t1", "key": "ENG", "name": "Engineering"},
+            "project": null,
+            "labels": {"nodes": [{"id": "l1", "name": "bug", "color": null},
+                                 {"id": "l2", "name": "needs review", "color": null}]}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn renders_org_headings() {
+        assert_eq!(
+            org_entry(&issue()),
+            "* STARTED [#B] ENG-7 Fix login :bug:needs_review:\n\
+             DEADLINE: <2024-05-03 Fri>\n\
+             :PROPERTIES:\n\
+             :LINEAR_ID: ENG-7\n\
+             :URL: https://linear.app/acme/issue/ENG-7\n\
+             :TEAM: ENG\n\
+             :STATE: In Progress\n\
+             :ASSIGNEE: ada\n\
+             :CREATED: [2024-05-01 Wed 09:30]\n\
+             :END:\n\
+             Steps:\n\
+             ,* open app\n\
+             then fail\n"
+        );
+    }
+
+    #[test]
+    fn renders_taskwarrior_tasks() {
+        let mut done = issue();
+        done.state.as_mut().unwrap().kind = Some("completed".into());
+        done.priority = Some(0);
+        let task = taskwarrior_task(&done);
+        assert_eq!(task["uuid"], "5c1c7b4e-0000-4000-8000-000000000001");
+        assert_eq!(task["description"], "ENG-7 Fix login");
+        assert_eq!(task["status"], "completed");
+        assert_eq!(task["entry"], "20240501T093000Z");
+        assert_eq!(task["end"], "20240502T100000Z");
+        assert_eq!(task["due"], "20240503T000000Z");
+        assert_eq!(task["tags"], json!(["bug", "needs_review"]));
+        assert!(task.get("priority").is_none());
+        assert_eq!(
+            taskwarrior_task(&issue())["priority"],
+            "H",
+            "high maps to H"
+        );
+    }
+}
@@ -0,0 +1,358 @@
+//! The team settings `linear team settings` shows, and the `key=value` assignments its `--set`
+//! turns into a [`TeamUpdateInput`], e.g. `cycles.enabled=true` or `estimates.type=fibonacci`.
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::Weekday;
+
+use crate::graphql::{TeamSettings, TeamUpdateInput, TemplateRef};
+
+/// One setting, named by its `--set` key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Setting {
+    CyclesEnabled,
+    CyclesDuration,
+    CyclesCooldown,
+    CyclesStartDay,
+    CyclesUpcoming,
+    CyclesAutoAddStarted,
+    CyclesAutoAddCompleted,
+    EstimatesType,
+    EstimatesAllowZero,
+    EstimatesExtended,
+    EstimatesDefault,
+    TriageEnabled,
+    TemplatesMembers,
+    TemplatesNonMembers,
+    TemplatesProjects,
+}
+
+/// Linear's `issueEstimationType` values and the names `--set` uses for them.
+const ESTIMATION_TYPES: [(&str, &str); 5] = [
+    ("notUsed", "not_used"),
+    ("exponential", "exponential"),
+    ("fibonacci", "fibonacci"),
+    ("linear", "linear"),
+    ("tShirt", "t_shirt"),
+];
+
+impl Setting {
+    pub const ALL: [Setting; 15] = [
+        Setting::CyclesEnabled,
+        Setting::CyclesDuration,
+        Setting::CyclesCooldown,
+        Setting::CyclesStartDay,
+        Setting::CyclesUpcoming,
+        Setting::CyclesAutoAddStarted,
+        Setting::CyclesAutoAddCompleted,
+        Setting::EstimatesType,
+        Setting::EstimatesAllowZero,
+        Setting::EstimatesExtended,
+        Setting::EstimatesDefault,
+        Setting::TriageEnabled,
+        Setting::TemplatesMembers,
+        Setting::TemplatesNonMembers,
+        Setting::TemplatesProjects,
+    ];
+
+    pub fn key(self) -> &'static str {
+        match self {
+            Setting::CyclesEnabled => "cycles.enabled",
+            Setting::CyclesDuration => "cycles.duration",
+            Setting::CyclesCooldown => "cycles.cooldown",
+            Setting::CyclesStartDay => "cycles.start_day",
+            Setting::CyclesUpcoming => "cycles.upcoming",
+            Setting::CyclesAutoAddStarted => "cycles.auto_add_started",
+            Setting::CyclesAutoAddCompleted => "cycles.auto_add_completed",
+            Setting::EstimatesType => "estimates.type",
+            Setting::EstimatesAllowZero => "estimates.allow_zero",
+            Setting::EstimatesExtended => "estimates.extended",
+            Setting::EstimatesDefault => "estimates.default",
+            Setting::TriageEnabled => "triage.enabled",
+            Setting::TemplatesMembers => "templates.members",
+            Setting::TemplatesNonMembers => "templates.non_members",
+            Setting::TemplatesProjects => "templates.projects",
+        }
+    }
+
+    /// The setting's current value as `--set` would take it, with units where they help.
+    pub fn display(self, settings: &TeamSettings) -> String {
+        let weeks = |count: f64| match count {
+            1.0 => "1 week".to_owned(),
+            count => format!("{count} weeks"),
+        };
+        let template = |template: &Option<TemplateRef>| match template {
+            Some(template) => template.name.clone(),
+            None => "none".to_owned(),
+        };
+        match self {
+            Setting::CyclesEnabled => settings.cycles_enabled.to_string(),
+            Setting::CyclesDuration => weeks(settings.cycle_duration),
+            Setting::CyclesCooldown => weeks(settings.cycle_cooldown_time),
+            Setting::CyclesStartDay => weekday_name(settings.cycle_start_day),
+            Setting::CyclesUpcoming => settings.upcoming_cycle_count.to_string(),
+            Setting::CyclesAutoAddStarted => settings.cycle_issue_auto_assign_started.to_string(),
+            Setting::CyclesAutoAddCompleted => {
+                settings.cycle_issue_auto_assign_completed.to_string()
+            }
+            Setting::EstimatesType => ESTIMATION_TYPES
+                .iter()
+                .find(|(api, _)| *api == settings.issue_estimation_type)
+                .map_or_else(
+                    || settings.issue_estimation_type.clone(),
+                    |(_, name)| (*name).to_owned(),
+                ),
+            Setting::EstimatesAllowZero => settings.issue_estimation_allow_zero.to_string(),
+            Setting::EstimatesExtended => settings.issue_estimation_extended.to_string(),
+            Setting::EstimatesDefault => settings.default_issue_estimate.to_string(),
+            Setting::TriageEnabled => settings.triage_enabled.to_string(),
+            Setting::TemplatesMembers => template(&settings.default_template_for_members),
+            Setting::TemplatesNonMembers => template(&settings.default_template_for_non_members),
+            Setting::TemplatesProjects => template(&settings.default_project_template),
+        }
+    }
+}
+
+impl fmt::Display for Setting {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.key())
+    }
+}
+
+impl FromStr for Setting {
+    type Err = String;
+
+    fn from_str(key: &str) -> Result<Self, Self::Err> {
+        let key = key.trim().to_ascii_lowercase().replace('-', "_");
+        Setting::ALL
+            .into_iter()
+            .find(|setting| setting.key() == key)
+            .ok_or_else(|| {
+                let keys: Vec<&str> = Setting::ALL.iter().map(|setting| setting.key()).collect();
+                format!(
+                    "unknown team setting '{key}'; expected one of {}",
+                    keys.join(", ")
+                )
+            })
+    }
+}
+
+/// A parsed `--set key=value`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Assignment {
+    pub setting: Setting,
+    value: Value,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Flag(bool),
+    Weeks(i32),
+    Number(f64),
+    Text(String),
+    /// A template name, `None` for `none`.
+    Template(Option<String>),
+}
+
+impl FromStr for Assignment {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (key, raw) = input
+            .split_once('=')
+            .ok_or_else(|| format!("invalid setting '{input}' (expected key=value)"))?;
+        let setting: Setting = key.parse()?;
+        let raw = raw.trim();
+        if raw.is_empty() {
+            return Err(format!("{setting} has no value"));
+        }
+        let value = match setting {
+            Setting::CyclesEnabled
+            | Setting::CyclesAutoAddStarted
+            | Setting::CyclesAutoAddCompleted
+            | Setting::EstimatesAllowZero
+            | Setting::EstimatesExtended
+            | Setting::TriageEnabled => Value::Flag(parse_flag(setting, raw)?),
+            Setting::CyclesDuration => Value::Weeks(parse_weeks(setting, raw, 1..=8)?),
+            Setting::CyclesCooldown => Value::Weeks(parse_weeks(setting, raw, 0..=6)?),
+            Setting::CyclesStartDay => Value::Number(parse_weekday(raw)?),
+            Setting::CyclesUpcoming | Setting::EstimatesDefault => match raw.parse::<f64>() {
+                Ok(number) if number >= 0.0 && number.fract() == 0.0 => Value::Number(number),
+                _ => return Err(format!("{setting} takes a whole number, not '{raw}'")),
+            },
+            Setting::EstimatesType => {
+                let name = raw.to_ascii_lowercase().replace('-', "_");
+                let (api, _) = ESTIMATION_TYPES
+                    .iter()
+                    .find(|(api, option)| *option == name || api.eq_ignore_ascii_case(&name))
+                    .ok_or_else(|| {
+                        format!(
+                            "unknown estimation type '{raw}'; expected not_used, exponential, \
+                             fibonacci, linear, or t_shirt"
+                        )
+                    })?;
+                Value::Text((*api).to_owned())
+            }
+            Setting::TemplatesMembers
+            | Setting::TemplatesNonMembers
+            | Setting::TemplatesProjects => {
+                Value::Template((!raw.eq_ignore_ascii_case("none")).then(|| raw.to_owned()))
+            }
+        };
+        Ok(Self { setting, value })
+    }
+}
+
+impl Assignment {
+    /// The template name to look up for a `templates.*` setting.
+    pub fn template_name(&self) -> Option<&str> {
+        match &self.value {
+            Value::Template(name) => name.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Write the assignment onto `input`; `template_id` is the id found for
+    /// [`template_name`](Self::template_name), and is ignored for other settings.
+    pub fn apply(&self, input: &mut TeamUpdateInput, template_id: Option<String>) {
+        match (&self.value, self.setting) {
+            (Value::Flag(flag), Setting::CyclesEnabled) => input.cycles_enabled = Some(*flag),
+            (Value::Flag(flag), Setting::CyclesAutoAddStarted) => {
+                input.cycle_issue_auto_assign_started = Some(*flag)
+            }
+            (Value::Flag(flag), Setting::CyclesAutoAddCompleted) => {
+                input.cycle_issue_auto_assign_completed = Some(*flag)
+            }
+            (Value::Flag(flag), Setting::EstimatesAllowZero) => {
+                input.issue_estimation_allow_zero = Some(*flag)
+            }
+            (Value::Flag(flag), Setting::EstimatesExtended) => {
+                input.issue_estimation_extended = Some(*flag)
+            }
+            (Value::Flag(flag), _) => input.triage_enabled = Some(*flag),
+            (Value::Weeks(weeks), Setting::CyclesDuration) => input.cycle_duration = Some(*weeks),
+            (Value::Weeks(weeks), _) => input.cycle_cooldown_time = Some(*weeks),
+            (Value::Number(day), Setting::CyclesStartDay) => input.cycle_start_day = Some(*day),
+            (Value::Number(count), Setting::CyclesUpcoming) => {
+                input.upcoming_cycle_count = Some(*count)
+            }
+            (Value::Number(estimate), _) => input.default_issue_estimate = Some(*estimate),
+            (Value::Text(kind), _) => input.issue_estimation_type = Some(kind.clone()),
+            (Value::Template(_), Setting::TemplatesMembers) => {
+                input.default_template_for_members_id = Some(template_id)
+            }
+            (Value::Template(_), Setting::TemplatesNonMembers) => {
+                input.default_template_for_non_members_id = Some(template_id)
+            }
+            (Value::Template(_), _) => input.default_project_template_id = Some(template_id),
+        }
+    }
+}
+
+fn parse_flag(setting: Setting, raw: &str) -> Result<bool, String> {
+    match raw.to_ascii_lowercase().as_str() {
+        "true" | "yes" | "on" => Ok(true),
+        "false" | "no" | "off" => Ok(false),
+        _ => Err(format!("{setting} takes true or false, not '{raw}'")),
+    }
+}
+
+fn parse_weeks(
+    setting: Setting,
+    raw: &str,
+    range: std::ops::RangeInclusive<i32>,
+) -> Result<i32, String> {
+    let count = raw.trim_end_matches('w');
+    match count.parse::<i32>() {
+        Ok(weeks) if range.contains(&weeks) => Ok(weeks),
+        _ => Err(format!(
+            "{setting} takes {} to {} weeks, not '{raw}'",
+            range.start(),
+            range.end()
+        )),
+    }
+}
+
+/// A weekday name (`monday`, `mon`) or number (0 for Sunday to 6) as Linear's `cycleStartDay`.
+fn parse_weekday(raw: &str) -> Result<f64, String> {
+    if let Ok(day) = raw.parse::<u32>() {
+        return match day {
+            0..=6 => Ok(day as f64),
+            _ => Err(format!("cycles.start_day takes 0 (Sunday) to 6, not {day}")),
+        };
+    }
+    raw.parse::<Weekday>()
+        .map(|day| day.num_days_from_sunday() as f64)
+        .map_err(|_| format!("cycles.start_day takes a weekday, not '{raw}'"))
+}
+
+fn weekday_name(day: f64) -> String {
+    const NAMES: [&str; 7] = [
+        "Sunday",
+        "Monday",
+        "Tuesday",
+        "Wednesday",
+        "Thursday",
+        "Friday",
+        "Saturday",
+    ];
+    NAMES
+        .get(day as usize)
+        .map_or_else(|| day.to_string(), |name| (*name).to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn assignments_fill_the_update_input() {
+        let mut input = TeamUpdateInput::default();
+        for raw in [
+            "cycles.enabled=true",
+            "cycles.duration=2w",
+            "cycles.start-day=mon",
+            "estimates.type=T-Shirt",
+            "triage.enabled=off",
+            "templates.projects=none",
+        ] {
+            raw.parse::<Assignment>().unwrap().apply(&mut input, None);
+        }
+        let members: Assignment = "templates.members=Bug report".parse().unwrap();
+        assert_eq!(members.template_name(), Some("Bug report"));
+        members.apply(&mut input, Some("t1".into()));
+
+        assert_eq!(
+            serde_json::to_value(&input).unwrap(),
+            json!({
+                "cyclesEnabled": true,
+                "cycleDuration": 2,
+                "cycleStartDay": 1.0,
+                "issueEstimationType": "tShirt",
+                "triageEnabled": false,
+                "defaultTemplateForMembersId": "t1",
+                "defaultProjectTemplateId": null
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_keys_and_bad_values() {
+        assert!("cycles.colour=red"
+            .parse::<Assignment>()
+            .unwrap_err()
+            .starts_with("unknown team setting 'cycles.colour'"));
+        assert_eq!(
+            "cycles.duration=12".parse::<Assignment>().unwrap_err(),
+            "cycles.duration takes 1 to 8 weeks, not '12'"
+        );
+        assert_eq!(
+            "triage.enabled=maybe".parse::<Assignment>().unwrap_err(),
+            "triage.enabled takes true or false, not 'maybe'"
+        );
+        assert!("cycles.enabled".parse::<Assignment>().is_err());
+    }
+}
@@ -0,0 +1,486 @@
+//! A small subset of Jinja for `--template`: `{{ path | filter }}` output, `{% if %}` and
+//! `{% for %}` blocks, and `-` whitespace control, rendered against a serialized record such as
+//! an issue. Paths are dotted like `--fields` (`state.name`, `labels.0.name`), and a name
+//! applied to a list maps over it, so `{{ labels.nodes.name | join(", ") }}` lists label names.
+//! Missing values render as nothing.
+
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, Local, NaiveDate};
+use serde_json::Value;
+
+/// A parsed template.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Template {
+    nodes: Vec<Node>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Text(String),
+    Output(Expr),
+    If {
+        negate: bool,
+        condition: Expr,
+        then: Vec<Node>,
+        otherwise: Vec<Node>,
+    },
+    For {
+        name: String,
+        list: Expr,
+        body: Vec<Node>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Expr {
+    path: Vec<String>,
+    filters: Vec<Filter>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Filter {
+    Default(String),
+    Upper,
+    Lower,
+    Truncate(usize),
+    Join(String),
+    Length,
+    Date(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Text(String),
+    Output(String),
+    Tag(String),
+}
+
+impl Template {
+    /// Parse `source`, reporting the first malformed tag or unknown filter.
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let mut tokens = tokenize(source)?.into_iter();
+        let (nodes, end) = parse_block(&mut tokens)?;
+        match end {
+            Some(tag) => Err(format!("unexpected {{% {tag} %}}")),
+            None => Ok(Self { nodes }),
+        }
+    }
+
+    /// Render the template against `record`.
+    pub fn render(&self, record: &Value) -> String {
+        let mut scope = Scope {
+            root: record,
+            locals: Vec::new(),
+        };
+        let mut out = String::new();
+        render_nodes(&self.nodes, &mut scope, &mut out);
+        out
+    }
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut rest = source;
+    let mut trim_next = false;
+    loop {
+        let next = [rest.find("{{"), rest.find("{%")]
+            .into_iter()
+            .flatten()
+            .min();
+        let Some(open) = next else {
+            push_text(&mut tokens, rest, trim_next);
+            return Ok(tokens);
+        };
+        let close_marker = if rest[open..].starts_with("{{") {
+            "}}"
+        } else {
+            "%}"
+        };
+        let inner_start = open + 2;
+        let Some(close) = rest[inner_start..].find(close_marker) else {
+            return Err(format!("unclosed '{}'", &rest[open..inner_start]));
+        };
+        let mut inner = &rest[inner_start..inner_start + close];
+        if let Some(trimmed) = inner.strip_prefix('-') {
+            inner = trimmed;
+            push_text(&mut tokens, rest[..open].trim_end(), trim_next);
+        } else {
+            push_text(&mut tokens, &rest[..open], trim_next);
+        }
+        trim_next = false;
+        if let Some(trimmed) = inner.strip_suffix('-') {
+            inner = trimmed;
+            trim_next = true;
+        }
+        let inner = inner.trim().to_owned();
+        tokens.push(if close_marker == "}}" {
+            Token::Output(inner)
+        } else {
+            Token::Tag(inner)
+        });
+        rest = &rest[inner_start + close + 2..];
+    }
+}
+
+fn push_text(tokens: &mut Vec<Token>, text: &str, trim_start: bool) {
+    let text = if trim_start { text.trim_start() } else { text };
+    if !text.is_empty() {
+        tokens.push(Token::Text(text.to_owned()));
+    }
+}
+
+/// Nodes up to the end of the input or to an `else`/`endif`/`endfor` tag, which is returned.
+fn parse_block(
+    tokens: &mut impl Iterator<Item = Token>,
+) -> Result<(Vec<Node>, Option<String>), String> {
+    let mut nodes = Vec::new();
+    while let Some(token) = tokens.next() {
+        match token {
+            Token::Text(text) => nodes.push(Node::Text(text)),
+            Token::Output(expr) => nodes.push(Node::Output(parse_expr(&expr)?)),
+            Token::Tag(tag) => {
+                let (keyword, rest) = tag.split_once(char::is_whitespace).unwrap_or((&tag, ""));
+                match keyword {
+                    "if" => {
+                        let (negate, condition) = match rest.trim().strip_prefix("not ") {
+                            Some(condition) => (true, condition),
+                            None => (false, rest),
+                        };
+                        let condition = parse_expr(condition)?;
+                        let (then, end) = parse_block(tokens)?;
+                        let otherwise = match end.as_deref() {
+                            Some("endif") => Vec::new(),
+                            Some("else") => match parse_block(tokens)? {
+                                (otherwise, Some(end)) if end == "endif" => otherwise,
+                                _ => return Err("{% else %} without a matching {% endif %}".into()),
+                            },
+                            _ => return Err("{% if %} without a matching {% endif %}".into()),
+                        };
+                        nodes.push(Node::If {
+                            negate,
+                            condition,
+                            then,
+                            otherwise,
+                        });
+                    }
+                    "for" => {
+                        let (name, list) = rest
+                            .split_once(" in ")
+                            .map(|(name, list)| (name.trim(), list))
+                            .filter(|(name, _)| is_name(name))
+                            .ok_or_else(|| {
+                                format!("expected {{% for NAME in PATH %}}, got '{tag}'")
+                            })?;
+                        let list = parse_expr(list)?;
+                        let (body, end) = parse_block(tokens)?;
+                        if end.as_deref() != Some("endfor") {
+                            return Err("{% for %} without a matching {% endfor %}".into());
+                        }
+                        nodes.push(Node::For {
+                            name: name.to_owned(),
+                            list,
+                            body,
+                        });
+                    }
+                    "else" | "endif" | "endfor" if rest.is_empty() => {
+                        return Ok((nodes, Some(keyword.to_owned())));
+                    }
+                    _ => return Err(format!("unknown tag '{{% {tag} %}}'")),
+                }
+            }
+        }
+    }
+    Ok((nodes, None))
+}
+
+fn parse_expr(source: &str) -> Result<Expr, String> {
+    let mut parts = split_filters(source).into_iter();
+    let path = parts.next().unwrap_or_default();
+    let path = path.trim();
+    let segments: Vec<String> = path.split('.').map(str::to_owned).collect();
+    if segments.iter().any(|segment| !is_name(segment)) {
+        return Err(format!("invalid path '{path}' in '{{{{ {source} }}}}'"));
+    }
+    let filters = parts
+        .map(|filter| parse_filter(filter.trim()))
+        .collect::<Result<_, _>>()?;
+    Ok(Expr {
+        path: segments,
+        filters,
+    })
+}
+
+/// Split on `|` outside quoted strings.
+fn split_filters(source: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut quote = None;
+    let mut start = 0;
+    for (index, c) in source.char_indices() {
+        match (quote, c) {
+            (Some(open), c) if c == open => quote = None,
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '|') => {
+                parts.push(&source[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&source[start..]);
+    parts
+}
+
+fn parse_filter(source: &str) -> Result<Filter, String> {
+    let (name, argument) = match source.split_once('(') {
+        Some((name, rest)) => {
+            let argument = rest
+                .trim_end()
+                .strip_suffix(')')
+                .ok_or_else(|| format!("unclosed '(' in filter '{source}'"))?;
+            (name.trim(), Some(argument.trim()))
+        }
+        None => (source, None),
+    };
+    let text = |argument: Option<&str>| -> Result<Option<String>, String> {
+        let Some(argument) = argument else {
+            return Ok(None);
+        };
+        let unquoted = ['"', '\''].into_iter().find_map(|quote| {
+            argument
+                .strip_prefix(quote)
+                .and_then(|rest| rest.strip_suffix(quote))
+        });
+        unquoted
+            .map(|text| Some(text.to_owned()))
+            .ok_or_else(|| format!("filter '{name}' expects a quoted string, got {argument}"))
+    };
+    Ok(match name {
+        "default" => Filter::Default(text(argument)?.unwrap_or_default()),
+        "upper" => Filter::Upper,
+        "lower" => Filter::Lower,
+        "length" => Filter::Length,
+        "join" => Filter::Join(text(argument)?.unwrap_or_else(|| ", ".into())),
+        "truncate" => Filter::Truncate(match argument {
+            Some(length) => length
+                .parse()
+                .ok()
+                .filter(|length| *length > 0)
+                .ok_or_else(|| format!("truncate expects a positive length, got {length}"))?,
+            None => 40,
+        }),
+        "date" => {
+            let format = text(argument)?.unwrap_or_else(|| "%Y-%m-%d".into());
+            if StrftimeItems::new(&format).any(|item| matches!(item, Item::Error)) {
+                return Err(format!("invalid date format '{format}'"));
+            }
+            Filter::Date(format)
+        }
+        other => {
+            return Err(format!(
+                "unknown filter '{other}' (expected default, upper, lower, truncate, join, \
+                 length, or date)"
+            ))
+        }
+    })
+}
+
+fn is_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+struct Scope<'a> {
+    root: &'a Value,
+    /// Loop variables, innermost last.
+    locals: Vec<(String, Value)>,
+}
+
+impl Scope<'_> {
+    fn eval(&self, expr: &Expr) -> Value {
+        let (first, rest) = expr.path.split_first().expect("paths have a segment");
+        let mut value = match self.locals.iter().rev().find(|(name, _)| name == first) {
+            Some((_, local)) => resolve(local, rest),
+            None => resolve(self.root, &expr.path),
+        };
+        for filter in &expr.filters {
+            value = apply(filter, value);
+        }
+        value
+    }
+}
+
+/// Walk `path` from `value`; a name applied to a list maps over its elements.
+fn resolve(value: &Value, path: &[String]) -> Value {
+    let Some((segment, rest)) = path.split_first() else {
+        return value.clone();
+    };
+    match value {
+        Value::Object(map) => map
+            .get(segment)
+            .map_or(Value::Null, |field| resolve(field, rest)),
+        Value::Array(items) => match segment.parse::<usize>() {
+            Ok(index) => items
+                .get(index)
+                .map_or(Value::Null, |item| resolve(item, rest)),
+            Err(_) => Value::Array(
+                items
+                    .iter()
+                    .map(|item| resolve(item, path))
+                    .filter(|item| !item.is_null())
+                    .collect(),
+            ),
+        },
+        _ => Value::Null,
+    }
+}
+
+fn apply(filter: &Filter, value: Value) -> Value {
+    match filter {
+        Filter::Default(fallback) if !truthy(&value) => Value::String(fallback.clone()),
+        Filter::Default(_) => value,
+        Filter::Upper => Value::String(text(&value).to_uppercase()),
+        Filter::Lower => Value::String(text(&value).to_lowercase()),
+        Filter::Truncate(length) => {
+            let text = text(&value);
+            if text.chars().count() <= *length {
+                Value::String(text)
+            } else {
+                let kept: String = text.chars().take(length - 1).collect();
+                Value::String(format!("{}…", kept.trim_end()))
+            }
+        }
+        Filter::Join(separator) => match &value {
+            Value::Array(items) => {
+                Value::String(items.iter().map(text).collect::<Vec<_>>().join(separator))
+            }
+            _ => value,
+        },
+        Filter::Length => Value::from(match &value {
+            Value::Array(items) => items.len(),
+            Value::Object(map) => map.len(),
+            Value::Null => 0,
+            other => text(other).chars().count(),
+        }),
+        Filter::Date(format) => {
+            let raw = text(&value);
+            if let Ok(at) = DateTime::parse_from_rfc3339(&raw) {
+                Value::String(at.with_timezone(&Local).format(format).to_string())
+            } else if let Ok(date) = NaiveDate::parse_from_str(&raw, "%Y-%m-%d") {
+                Value::String(date.format(format).to_string())
+            } else {
+                value
+            }
+        }
+    }
+}
+
+fn truthy(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Bool(flag) => *flag,
+        Value::Number(number) => number.as_f64() != Some(0.0),
+        Value::String(text) => !text.is_empty(),
+        Value::Array(items) => !items.is_empty(),
+        Value::Object(map) => !map.is_empty(),
+    }
+}
+
+/// How a value prints: strings as they are, lists comma-separated, nothing for null.
+fn text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        Value::Array(items) => items.iter().map(text).collect::<Vec<_>>().join(", "),
+        other => other.to_string(),
+    }
+}
+
+fn render_nodes(nodes: &[Node], scope: &mut Scope, out: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(text),
+            Node::Output(expr) => out.push_str(&text(&scope.eval(expr))),
+            Node::If {
+                negate,
+                condition,
+                then,
+                otherwise,
+            } => {
+                let branch = if truthy(&scope.eval(condition)) != *negate {
+                    then
+                } else {
+                    otherwise
+                };
+                render_nodes(branch, scope, out);
+            }
+            Node::For { name, list, body } => {
+                let items = match scope.eval(list) {
+                    Value::Array(items) => items,
+                    Value::Null => Vec::new(),
+                    single => vec![single],
+                };
+                for item in items {
+                    scope.locals.push((name.clone(), item));
+                    render_nodes(body, scope, out);
+                    scope.locals.pop();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn issue() -> Value {
+        json!({
+            "identifier": "ENG-1",
+            "title": "Fix the login redirect after SSO",
+            "state": {"name": "In Progress"},
+            "assignee": null,
+            "dueDate": "2025-07-01",
+            "labels": {"nodes": [{"name": "bug"}, {"name": "auth"}]},
+        })
+    }
+
+    #[test]
+    fn renders_paths_filters_and_blocks() {
+        let render = |source: &str| Template::parse(source).unwrap().render(&issue());
+        assert_eq!(
+            render("{{identifier}} [{{ state.name }}] {{title | truncate(12)}} ({{assignee.displayName | default(\"unassigned\")}})"),
+            "ENG-1 [In Progress] Fix the log… (unassigned)"
+        );
+        assert_eq!(
+            render("{{ labels.nodes.name | join('|') | upper }} {{ labels.nodes | length }}"),
+            "BUG|AUTH 2"
+        );
+        assert_eq!(render("{{ dueDate | date('%d %b') }}"), "01 Jul");
+        assert_eq!(
+            render("{% if assignee %}taken{% else %}free{% endif %}{% if not state %}!{% endif %}"),
+            "free"
+        );
+        assert_eq!(
+            render("{{ identifier }}\n{%- for label in labels.nodes %}\n  - {{ label.name }}{% endfor %}"),
+            "ENG-1\n  - bug\n  - auth"
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_templates() {
+        assert!(Template::parse("{{ title")
+            .unwrap_err()
+            .contains("unclosed"));
+        assert!(Template::parse("{{ title | shout }}")
+            .unwrap_err()
+            .contains("unknown filter"));
+        assert!(Template::parse("{% if title %}x").is_err());
+        assert!(Template::parse("{% endfor %}").is_err());
+        assert!(Template::parse("{{ state..name }}").is_err());
+        assert!(Template::parse("{{ dueDate | date('%Q') }}").is_err());
+        assert_eq!(
+            Template::parse("plain").unwrap().render(&json!({})),
+            "plain"
+        );
+    }
+}
@@ -0,0 +1,231 @@
+//! Local work-session log behind `linear time`, stored per profile.
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use chrono::{DateTime, Duration, Utc};
+use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// One work session on an issue; `stopped_at` is `None` while the timer runs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeEntry {
+    pub issue: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    pub started_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stopped_at: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+impl TimeEntry {
+    /// Time spent, counting a running session up to `now`.
+    pub fn elapsed(&self, now: DateTime<Utc>) -> Duration {
+        (self.stopped_at.unwrap_or(now) - self.started_at).max(Duration::zero())
+    }
+}
+
+/// Time spent on one issue within a report window.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct IssueTotal {
+    pub issue: String,
+    pub title: Option<String>,
+    #[serde(rename = "seconds", serialize_with = "serialize_seconds")]
+    pub spent: Duration,
+    pub sessions: usize,
+}
+
+fn serialize_seconds<S: serde::Serializer>(
+    value: &Duration,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_i64(value.num_seconds())
+}
+
+/// Every recorded session, oldest first; at most the last one is running.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TimeLog {
+    #[serde(default)]
+    pub entries: Vec<TimeEntry>,
+}
+
+impl TimeLog {
+    /// The running session, if any.
+    pub fn active(&self) -> Option<&TimeEntry> {
+        self.entries
+            .last()
+            .filter(|entry| entry.stopped_at.is_none())
+    }
+
+    /// Start timing `issue`, stopping and returning any session already running.
+    pub fn start(
+        &mut self,
+        issue: impl Into<String>,
+        title: Option<String>,
+        now: DateTime<Utc>,
+    ) -> Option<TimeEntry> {
+        let stopped = self.stop(now, None);
+        self.entries.push(TimeEntry {
+            issue: issue.into(),
+            title,
+            started_at: now,
+            stopped_at: None,
+            note: None,
+        });
+        stopped
+    }
+
+    /// Stop the running session, returning it.
+    pub fn stop(&mut self, now: DateTime<Utc>, note: Option<String>) -> Option<TimeEntry> {
+        let entry = self.entries.last_mut()?;
+        if entry.stopped_at.is_some() {
+            return None;
+        }
+        entry.stopped_at = Some(now.max(entry.started_at));
+        entry.note = note;
+        Some(entry.clone())
+    }
+
+    /// Time per issue spent between `since` and `now`, longest first. Sessions straddling
+    /// `since` count only their part inside the window.
+    pub fn totals(&self, since: DateTime<Utc>, now: DateTime<Utc>) -> Vec<IssueTotal> {
+        let mut totals: Vec<IssueTotal> = Vec::new();
+        for entry in &self.entries {
+            let end = entry.stopped_at.unwrap_or(now);
+            if end <= since {
+                continue;
+            }
+            let spent = end - entry.started_at.max(since);
+            match totals.iter_mut().find(|total| total.issue == entry.issue) {
+                Some(total) => {
+                    total.spent += spent;
+                    total.sessions += 1;
+                    if entry.title.is_some() {
+                        total.title = entry.title.clone();
+                    }
+                }
+                None => totals.push(IssueTotal {
+                    issue: entry.issue.clone(),
+                    title: entry.title.clone(),
+                    spent,
+                    sessions: 1,
+                }),
+            }
+        }
+        totals.sort_by(|a, b| b.spent.cmp(&a.spent).then_with(|| a.issue.cmp(&b.issue)));
+        totals
+    }
+}
+
+/// `1h 05m`, `12m`, or `40s` for short sessions.
+pub fn format_elapsed(duration: Duration) -> String {
+    let minutes = duration.num_minutes();
+    match (minutes / 60, minutes % 60) {
+        (0, 0) => format!("{}s", duration.num_seconds().max(0)),
+        (0, minutes) => format!("{minutes}m"),
+        (hours, minutes) => format!("{hours}h {minutes:02}m"),
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum TimeLogError {
+    #[error("failed to access the time log: {0}")]
+    Io(#[from] io::Error),
+    #[error("time log is corrupt: {0}")]
+    Parse(#[from] serde_json::Error),
+}
+
+/// JSON file holding one profile's [`TimeLog`].
+#[derive(Debug, Clone)]
+pub struct TimeLogStore {
+    path: PathBuf,
+}
+
+impl TimeLogStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Store for `profile` under the platform data directory.
+    pub fn for_profile(profile: &str) -> Option<Self> {
+        let dirs = ProjectDirs::from("app", "linear", "linear-rs")?;
+        Some(Self::new(
+            dirs.data_dir().join("time").join(format!("{profile}.json")),
+        ))
+    }
+
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    /// Load the log, treating a missing file as empty.
+    pub fn load(&self) -> Result<TimeLog, TimeLogError> {
+        match fs::read(&self.path) {
+            Ok(raw) => Ok(serde_json::from_slice(&raw)?),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(TimeLog::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Replace the log on disk atomically.
+    pub fn save(&self, log: &TimeLog) -> Result<(), TimeLogError> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let temp = self.path.with_extension("json.tmp");
+        fs::write(&temp, serde_json::to_vec_pretty(log)?)?;
+        fs::rename(temp, &self.path)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        format!("2024-05-01T{hour:02}:{minute:02}:00Z")
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn tracks_sessions_and_totals_within_window() {
+        let mut log = TimeLog::default();
+        assert!(log.start("ENG-1", Some("Login".into()), at(9, 0)).is_none());
+        let switched = log.start("ENG-2", None, at(10, 30)).unwrap();
+        assert_eq!(switched.issue, "ENG-1");
+        assert_eq!(switched.elapsed(at(23, 0)), Duration::minutes(90));
+        log.stop(at(11, 0), Some("review".into()));
+        assert!(log.active().is_none());
+        assert!(log.stop(at(12, 0), None).is_none());
+        log.start("ENG-1", None, at(13, 0));
+
+        let totals = log.totals(at(10, 0), at(13, 20));
+        assert_eq!(totals.len(), 2);
+        assert_eq!(totals[0].issue, "ENG-1");
+        assert_eq!(totals[0].spent, Duration::minutes(50));
+        assert_eq!(totals[0].sessions, 2);
+        assert_eq!(totals[0].title.as_deref(), Some("Login"));
+        assert_eq!(totals[1].spent, Duration::minutes(30));
+        assert_eq!(format_elapsed(totals[0].spent), "50m");
+        assert_eq!(format_elapsed(Duration::minutes(65)), "1h 05m");
+    }
+
+    #[test]
+    fn round_trips_through_store() {
+        let temp = TempDir::new().unwrap();
+        let store = TimeLogStore::new(temp.path().join("time").join("default.json"));
+        assert!(store.load().unwrap().entries.is_empty());
+        let mut log = TimeLog::default();
+        log.start("ENG-1", None, at(9, 0));
+        store.save(&log).unwrap();
+        assert_eq!(store.load().unwrap().active().unwrap().issue, "ENG-1");
+    }
+}
@@ -0,0 +1,214 @@
+//! Find Linear issue references in free text — issue URLs pasted from Slack or a browser, and
+//! bare keys like `ENG-123` in commit messages — for `linear unfurl`, and parse the issue a
+//! command-line argument names.
+
+use url::Url;
+
+/// Where an issue is mentioned in a piece of text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mention {
+    /// Byte range of the mention, URL included.
+    pub start: usize,
+    pub end: usize,
+    /// Uppercase issue key, e.g. `ENG-123`.
+    pub key: String,
+    /// Whether the mention is a URL rather than a bare key.
+    pub url: bool,
+}
+
+/// `ENG-123` from `https://linear.app/acme/issue/ENG-123/fix-login`.
+pub fn issue_key(url: &Url) -> Option<String> {
+    if url.host_str() != Some("linear.app") {
+        return None;
+    }
+    let segments: Vec<&str> = url.path_segments()?.collect();
+    let [_, "issue", key, ..] = segments.as_slice() else {
+        return None;
+    };
+    is_key(key).then(|| key.to_ascii_uppercase())
+}
+
+/// An issue as a command-line argument names it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IssueRef {
+    /// Uppercase issue key, from a key in any case or a Linear issue URL.
+    Key(String),
+    /// A bare number such as `123`, meaning that issue of the default team.
+    Number(u32),
+}
+
+impl IssueRef {
+    /// Parse a Linear issue URL, a key in any case, or a bare issue number.
+    pub fn parse(input: &str) -> Option<Self> {
+        let input = input.trim();
+        if let Ok(url) = Url::parse(input) {
+            return issue_key(&url).map(Self::Key);
+        }
+        if !input.is_empty() && input.chars().all(|c| c.is_ascii_digit()) {
+            return input.parse().ok().filter(|&n| n > 0).map(Self::Number);
+        }
+        is_key(input).then(|| Self::Key(input.to_ascii_uppercase()))
+    }
+
+    /// The issue key, taking the team from `default_team` for a bare number. `None` when a
+    /// number is given but `default_team` is not a team key.
+    pub fn key(&self, default_team: Option<&str>) -> Option<String> {
+        match self {
+            Self::Key(key) => Some(key.clone()),
+            Self::Number(number) => {
+                let key = format!("{}-{number}", default_team?.trim());
+                is_key(&key).then(|| key.to_ascii_uppercase())
+            }
+        }
+    }
+}
+
+/// Every Linear issue URL and bare uppercase key in `text`, in order. Bare keys need a word
+/// boundary on both sides, so `UTF-8` still matches but `v2-3rc` and `x-ENG-1` do not; callers
+/// check the team prefix against the workspace to drop the rest.
+pub fn references(text: &str) -> Vec<Mention> {
+    let mut found = Vec::new();
+    let mut covered = 0;
+    for (start, _) in text.match_indices("linear.app/") {
+        let start = [start.checked_sub(8), start.checked_sub(7)]
+            .into_iter()
+            .flatten()
+            .find(|&scheme| matches!(text.get(scheme..start), Some("https://" | "http://")))
+            .unwrap_or(start);
+        if start < covered {
+            continue;
+        }
+        let rest = &text[start..];
+        let len = rest
+            .find(|c: char| {
+                c.is_whitespace() || matches!(c, '<' | '>' | '"' | '\'' | '|' | ')' | ']')
+            })
+            .unwrap_or(rest.len());
+        let candidate = rest[..len].trim_end_matches(['.', ',', ';', ':', '!', '?']);
+        let parsed = if candidate.contains("://") {
+            Url::parse(candidate)
+        } else {
+            Url::parse(&format!("https://{candidate}"))
+        };
+        if let Some(key) = parsed.ok().as_ref().and_then(issue_key) {
+            found.push(Mention {
+                start,
+                end: start + candidate.len(),
+                key,
+                url: true,
+            });
+            covered = start + candidate.len();
+        }
+    }
+
+    let word = |c: char| c.is_alphanumeric() || c == '_' || c == '-' || c == '/';
+    let mut bare = Vec::new();
+    let mut index = 0;
+    while index < text.len() {
+        let rest = &text[index..];
+        let Some(first) = rest.chars().next() else {
+            break;
+        };
+        let preceded = text[..index].chars().next_back().is_some_and(word);
+        if !first.is_ascii_uppercase() || preceded {
+            index += first.len_utf8();
+            continue;
+        }
+        let len = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+            .unwrap_or(rest.len());
+        let token = &rest[..len];
+        let followed = rest[len..].chars().next().is_some_and(word);
+        if !followed && is_key(token) && token == token.to_ascii_uppercase() {
+            let (start, end) = (index, index + len);
+            if !found
+                .iter()
+                .any(|url: &Mention| start < url.end && url.start < end)
+            {
+                bare.push(Mention {
+                    start,
+                    end,
+                    key: token.to_owned(),
+                    url: false,
+                });
+            }
+        }
+        index += len.max(1);
+    }
+    found.extend(bare);
+    found.sort_by_key(|found| found.start);
+    found
+}
+
+fn is_key(key: &str) -> bool {
+    let Some((team, number)) = key.split_once('-') else {
+        return false;
+    };
+    team.chars().next().is_some_and(|c| c.is_ascii_alphabetic())
+        && team.chars().all(|c| c.is_ascii_alphanumeric())
+        && !number.is_empty()
+        && number.chars().all(|c| c.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(text: &str) -> Vec<(String, bool)> {
+        references(text)
+            .into_iter()
+            .map(|found| (found.key, found.url))
+            .collect()
+    }
+
+    #[test]
+    fn parses_urls_keys_and_numbers_given_as_arguments() {
+        let key = |input: &str| IssueRef::parse(input).and_then(|found| found.key(Some("eng")));
+        assert_eq!(
+            key("https://linear.app/acme/issue/eng-123/fix-login"),
+            Some("ENG-123".into())
+        );
+        assert_eq!(key(" eng-7 "), Some("ENG-7".into()));
+        assert_eq!(key("123"), Some("ENG-123".into()));
+        assert_eq!(IssueRef::parse("42"), Some(IssueRef::Number(42)));
+        assert_eq!(IssueRef::Number(42).key(None), None);
+        assert_eq!(IssueRef::Number(42).key(Some("Engineering team")), None);
+        assert_eq!(IssueRef::parse("0"), None);
+        assert_eq!(
+            IssueRef::parse("https://github.com/acme/web/issues/4"),
+            None
+        );
+        assert_eq!(
+            IssueRef::parse("https://linear.app/acme/project/roadmap"),
+            None
+        );
+        assert_eq!(IssueRef::parse("ENG-"), None);
+        assert_eq!(IssueRef::parse(""), None);
+    }
+
+    #[test]
+    fn finds_urls_and_bare_keys_in_text() {
+        let text = "See <https://linear.app/acme/issue/ENG-12/fix-login|ENG-12>, \
+                    then linear.app/acme/issue/OPS-3. Fixes ENG-4; not x-ENG-5 or ENG-6b.";
+        assert_eq!(
+            keys(text),
+            [
+                ("ENG-12".to_owned(), true),
+                ("ENG-12".to_owned(), false),
+                ("OPS-3".to_owned(), true),
+                ("ENG-4".to_owned(), false),
+            ]
+        );
+        let found = references(text);
+        assert_eq!(
+            &text[found[0].start..found[0].end],
+            "https://linear.app/acme/issue/ENG-12/fix-login"
+        );
+        assert_eq!(
+            &text[found[2].start..found[2].end],
+            "linear.app/acme/issue/OPS-3"
+        );
+        assert_eq!(keys("naïve ENG-1 café"), [("ENG-1".to_owned(), false)]);
+        assert!(keys("eng-1 is lowercase").is_empty());
+    }
+}
@@ -0,0 +1,150 @@
+//! Links into the Linear web app.
+//!
+//! The web app's filter encoding is not a public API, so only filters with a stable URL shape
+//! (team views, "My issues", search) are mapped; everything else is reported back to the caller.
+
+use serde::Serialize;
+use url::Url;
+
+const WEB_BASE: &str = "https://linear.app";
+
+/// Issue filters as understood by the web app.
+#[derive(Debug, Clone, Default)]
+pub struct IssueListFilter {
+    pub team_key: Option<String>,
+    /// Workflow state type (`backlog`, `unstarted`, `started`, `completed`, `canceled`, ...).
+    pub state_type: Option<String>,
+    /// Display name of the state filter, used when it can only be approximated.
+    pub state_name: Option<String>,
+    /// Assignee filter; `assignee_is_viewer` marks it as the authenticated user.
+    pub assignee_id: Option<String>,
+    pub assignee_is_viewer: bool,
+    pub label_count: usize,
+    pub title_contains: Option<String>,
+}
+
+/// Web URL for a filter, plus filters the link does not carry over.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebLink {
+    pub url: String,
+    pub unmapped: Vec<String>,
+}
+
+/// Build the closest web-app view for `filter` in the workspace `org_key`.
+///
+/// Returns `None` when nothing scopes the view (no team, search term, or own assignee).
+pub fn issue_list_link(org_key: &str, filter: &IssueListFilter) -> Option<WebLink> {
+    let mut unmapped = Vec::new();
+    let mut url = Url::parse(WEB_BASE).ok()?;
+
+    if let Some(term) = filter.title_contains.as_deref() {
+        url.path_segments_mut().ok()?.extend([org_key, "search"]);
+        url.query_pairs_mut().append_pair("q", term);
+        if let Some(team) = &filter.team_key {
+            unmapped.push(format!("team {} (search covers the whole workspace)", team));
+        }
+        if let Some(state) = state_label(filter) {
+            unmapped.push(format!("state {}", state));
+        }
+        if filter.assignee_id.is_some() {
+            unmapped.push("assignee".to_owned());
+        }
+    } else if let Some(team) = &filter.team_key {
+        let view = match filter.state_type.as_deref() {
+            Some("backlog") => "backlog",
+            Some("unstarted" | "started") => "active",
+            _ => "all",
+        };
+        url.path_segments_mut()
+            .ok()?
+            .extend([org_key, "team", team, view]);
+        if let Some(state) = state_label(filter) {
+            match filter.state_type.as_deref() {
+                Some("backlog") => {}
+                Some("unstarted" | "started") => {
+                    unmapped.push(format!("state {} (approximated by the active view)", state))
+                }
+                _ => unmapped.push(format!("state {}", state)),
+            }
+        }
+        if filter.assignee_id.is_some() {
+            unmapped.push("assignee".to_owned());
+        }
+    } else if filter.assignee_is_viewer {
+        url.path_segments_mut()
+            .ok()?
+            .extend([org_key, "my-issues", "assigned"]);
+        if let Some(state) = state_label(filter) {
+            unmapped.push(format!("state {}", state));
+        }
+    } else {
+        return None;
+    }
+
+    if filter.label_count > 0 {
+        unmapped.push(format!("{} label filter(s)", filter.label_count));
+    }
+    Some(WebLink {
+        url: url.to_string(),
+        unmapped,
+    })
+}
+
+fn state_label(filter: &IssueListFilter) -> Option<&str> {
+    filter
+        .state_name
+        .as_deref()
+        .or(filter.state_type.as_deref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_team_and_state_type_to_team_views() {
+        let filter = IssueListFilter {
+            team_key: Some("ENG".into()),
+            state_type: Some("backlog".into()),
+            state_name: Some("Backlog".into()),
+            ..Default::default()
+        };
+        let link = issue_list_link("acme", &filter).unwrap();
+        assert_eq!(link.url, "https://linear.app/acme/team/ENG/backlog");
+        assert!(link.unmapped.is_empty());
+
+        let filter = IssueListFilter {
+            team_key: Some("ENG".into()),
+            state_type: Some("started".into()),
+            state_name: Some("In Progress".into()),
+            label_count: 2,
+            ..Default::default()
+        };
+        let link = issue_list_link("acme", &filter).unwrap();
+        assert_eq!(link.url, "https://linear.app/acme/team/ENG/active");
+        assert_eq!(link.unmapped.len(), 2);
+    }
+
+    #[test]
+    fn search_and_own_assignments_use_workspace_views() {
+        let filter = IssueListFilter {
+            team_key: Some("ENG".into()),
+            title_contains: Some("login bug".into()),
+            ..Default::default()
+        };
+        let link = issue_list_link("acme", &filter).unwrap();
+        assert_eq!(link.url, "https://linear.app/acme/search?q=login+bug");
+        assert_eq!(link.unmapped.len(), 1);
+
+        let filter = IssueListFilter {
+            assignee_id: Some("user-1".into()),
+            assignee_is_viewer: true,
+            ..Default::default()
+        };
+        let link = issue_list_link("acme", &filter).unwrap();
+        assert_eq!(link.url, "https://linear.app/acme/my-issues/assigned");
+
+        assert!(issue_list_link("acme", &IssueListFilter::default()).is_none());
+    }
+}
@@ -0,0 +1,31 @@
+# German messages (demo locale). Missing ids fall back to English.
+
+## CLI
+
+cli-error = Fehler: { $message }
+cli-hint = Hinweis: { $hint }
+cli-dry-run = Probelauf: { $operation } wurde nicht gesendet
+cli-copied = { $what } in die Zwischenablage kopiert ({ $method }): { $value }
+no-value = { $issue } hat keinen Wert für { $what }
+
+copy-what-url = URL
+copy-what-key = Schlüssel
+copy-what-branch = Branch-Name
+
+## TUI status line
+
+tui-back-to-issues = Zurück zu den Issues
+tui-select-issue-first = Zuerst ein Issue auswählen
+tui-still-waiting = Die vorherige Anfrage läuft noch
+tui-detail-unavailable = Issue-Details nicht verfügbar
+tui-copy-prompt = Kopieren: y Schlüssel  u URL  b Branch-Name  (jede andere Taste bricht ab)
+tui-copy-cancelled = Kopieren abgebrochen
+tui-copied = { $what } kopiert ({ $method }): { $value }
+tui-copy-failed = Kopieren fehlgeschlagen: { $message }
+tui-failed = { $operation } fehlgeschlagen: { $message }
+
+## TUI error console
+
+tui-errors-title = Fehler ({ $count }) · Enter/r wiederholen  c leeren  ! oder Esc schließen
+tui-errors-empty = Keine Fehler in dieser Sitzung
+tui-errors-cleared = Fehlerkonsole geleert
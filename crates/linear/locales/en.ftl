@@ -0,0 +1,32 @@
+# English messages; every other locale falls back to these.
+# Syntax: `message-id = text`, `{ $name }` placeables, `#` comments.
+
+## CLI
+
+cli-error = Error: { $message }
+cli-hint = hint: { $hint }
+cli-dry-run = Dry run: { $operation } was not sent
+cli-copied = Copied { $what } to the clipboard ({ $method }): { $value }
+no-value = { $issue } has no { $what }
+
+copy-what-url = URL
+copy-what-key = key
+copy-what-branch = branch name
+
+## TUI status line
+
+tui-back-to-issues = Back to issues
+tui-select-issue-first = Select an issue first
+tui-still-waiting = Still waiting for the previous request
+tui-detail-unavailable = Issue detail unavailable
+tui-copy-prompt = Copy: y key  u URL  b branch name  (any other key cancels)
+tui-copy-cancelled = Copy cancelled
+tui-copied = Copied { $what } ({ $method }): { $value }
+tui-copy-failed = Copy failed: { $message }
+tui-failed = Failed to { $operation }: { $message }
+
+## TUI error console
+
+tui-errors-title = Errors ({ $count }) · Enter/r retry  c clear  ! or Esc close
+tui-errors-empty = No errors this session
+tui-errors-cleared = Error console cleared
@@ -0,0 +1,19 @@
+use std::env;
+use std::sync::OnceLock;
+
+static ENABLED: OnceLock<bool> = OnceLock::new();
+
+/// Fix accessibility mode for the process; `flag` comes from `--accessible`.
+pub(crate) fn init(flag: bool) {
+    let _ = ENABLED.set(flag || from_env());
+}
+
+/// Whether output should suit screen readers: no animation, line-by-line prompts, and a text
+/// label beside anything shown only through color or highlighting.
+pub(crate) fn enabled() -> bool {
+    *ENABLED.get_or_init(from_env)
+}
+
+fn from_env() -> bool {
+    env::var("LINEAR_ACCESSIBLE").is_ok_and(|value| !value.is_empty() && value != "0")
+}
@@ -0,0 +1,161 @@
+use anyhow::{Context, Result};
+use chrono::{Local, Utc};
+use clap::ValueEnum;
+use linear_core::error::LinearError;
+use linear_core::services::activity::{
+    parse_since, ActivityEvent, ActivityFeed, ActivityKind, ActivityService,
+};
+use linear_core::services::issues::IssueService;
+
+use crate::fields::print_json;
+use crate::table::{Cell, Table};
+use crate::{build_client, load_session, resolve, ActivityArgs};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub(crate) enum ActivityFormat {
+    Table,
+    Markdown,
+    Json,
+}
+
+/// Chronological feed of issue creations, state changes, and comments.
+pub(crate) async fn run(args: ActivityArgs) -> Result<()> {
+    let since = parse_since(&args.since, Utc::now()).map_err(LinearError::validation)?;
+    let format = if args.json {
+        ActivityFormat::Json
+    } else {
+        args.format
+    };
+
+    args.budget
+        .check(&ActivityService::estimate(args.limit), "this feed")?;
+
+    let session = load_session(&args.profile).await?;
+    let client = build_client(&session)?;
+    let team_id = match args.team.as_deref() {
+        Some(team) => Some(resolve::team_id(&IssueService::new(client.clone()), team).await?),
+        None => None,
+    };
+    let feed = ActivityService::new(client)
+        .feed(team_id.as_deref(), since, args.limit)
+        .await
+        .context("GraphQL request failed")?;
+
+    match format {
+        ActivityFormat::Json => print_json(&feed)?,
+        ActivityFormat::Markdown => print!("{}", render_markdown(&feed, args.team.as_deref())),
+        ActivityFormat::Table if feed.events.is_empty() => {
+            println!(
+                "No activity since {}.",
+                feed.since.with_timezone(&Local).format("%Y-%m-%d %H:%M")
+            );
+        }
+        ActivityFormat::Table => {
+            let mut table = Table::new()
+                .column("TIME", 11)
+                .column("KEY", 12)
+                .flex_column("EVENT", 12, 40)
+                .flex_column("BY", 6, 20)
+                .flex_column("TITLE", 16, 60);
+            for event in &feed.events {
+                table.row([
+                    event
+                        .at
+                        .with_timezone(&Local)
+                        .format("%m-%d %H:%M")
+                        .to_string()
+                        .into(),
+                    Cell::link(event.issue.clone(), event.url.as_deref()),
+                    describe(&event.kind).into(),
+                    event.actor.as_deref().unwrap_or("-").into(),
+                    event.title.as_str().into(),
+                ]);
+            }
+            table.print();
+        }
+    }
+    if feed.truncated {
+        eprintln!(
+            "… more updated issues available; raise --limit (currently {}) for a complete feed",
+            args.limit
+        );
+    }
+    Ok(())
+}
+
+fn describe(kind: &ActivityKind) -> String {
+    match kind {
+        ActivityKind::Created => "created".into(),
+        ActivityKind::StateChanged { from, to } => format!("{} → {}", from, to),
+        ActivityKind::Commented { .. } => "commented".into(),
+        ActivityKind::LabelAdded { label } => format!("+{}", label),
+        ActivityKind::Stale { days } => format!("stale {}d", days),
+    }
+}
+
+/// Bullet list per local day, ready to paste into standup notes.
+fn render_markdown(feed: &ActivityFeed, team: Option<&str>) -> String {
+    let since = feed.since.with_timezone(&Local).format("%Y-%m-%d %H:%M");
+    let mut out = match team {
+        Some(team) => format!("## {} activity since {}\n", team, since),
+        None => format!("## Activity since {}\n", since),
+    };
+    if feed.events.is_empty() {
+        out.push_str("\n_No activity._\n");
+        return out;
+    }
+    let mut day = None;
+    for event in &feed.events {
+        let at = event.at.with_timezone(&Local);
+        if day != Some(at.date_naive()) {
+            day = Some(at.date_naive());
+            out.push_str(&format!("\n### {}\n\n", at.format("%A %Y-%m-%d")));
+        }
+        out.push_str(&format!(
+            "- {} {}",
+            at.format("%H:%M"),
+            markdown_issue(event)
+        ));
+        let actor = event.actor.as_deref().unwrap_or("someone");
+        match &event.kind {
+            ActivityKind::Created => out.push_str(&format!(" created by {}", actor)),
+            ActivityKind::StateChanged { from, to } => {
+                out.push_str(&format!(": {} → **{}** ({})", from, to, actor))
+            }
+            ActivityKind::Commented { body } => {
+                out.push_str(&format!(" — {} commented", actor));
+                if let Some(excerpt) = excerpt(body) {
+                    out.push_str(&format!(": “{}”", excerpt));
+                }
+            }
+            ActivityKind::LabelAdded { label } => {
+                out.push_str(&format!(" labelled `{}` by {}", label, actor))
+            }
+            ActivityKind::Stale { days } => {
+                out.push_str(&format!(" not updated for {} days", days))
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn markdown_issue(event: &ActivityEvent) -> String {
+    let title = event.title.replace(['[', ']'], "");
+    match &event.url {
+        Some(url) => format!("[{} {}]({})", event.issue, title, url),
+        None => format!("**{}** {}", event.issue, title),
+    }
+}
+
+/// First line of a comment, shortened for a one-line bullet.
+fn excerpt(body: &str) -> Option<String> {
+    const MAX_CHARS: usize = 80;
+    let line = body.lines().map(str::trim).find(|line| !line.is_empty())?;
+    if line.chars().count() <= MAX_CHARS {
+        Some(line.to_owned())
+    } else {
+        let cut: String = line.chars().take(MAX_CHARS - 1).collect();
+        Some(format!("{}…", cut.trim_end()))
+    }
+}
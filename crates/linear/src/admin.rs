@@ -0,0 +1,111 @@
+use std::fs;
+
+use anyhow::{Context, Result};
+use linear_core::config::Impact;
+use linear_core::error::LinearError;
+use linear_core::permissions::Requirement;
+use linear_core::services::workspace::{Change, ChangeAction, WorkspaceService, WorkspaceSpec};
+use serde_json::json;
+
+use crate::fields::print_json;
+use crate::{build_client, load_session, AdminApplyArgs};
+
+/// Diff `workspace.toml` against the API, show the plan, and apply it once confirmed.
+pub(crate) async fn apply(args: AdminApplyArgs) -> Result<()> {
+    let raw = fs::read_to_string(&args.file)
+        .with_context(|| format!("failed to read {}", args.file.display()))?;
+    let spec = WorkspaceSpec::from_toml(&raw).map_err(|err| {
+        LinearError::validation(format!("invalid {}: {}", args.file.display(), err))
+    })?;
+
+    let session = load_session(&args.profile).await?;
+    let client = build_client(&session)?;
+    if !args.plan {
+        // Check up front rather than failing on the first change the API refuses.
+        let permissions = client
+            .viewer_permissions()
+            .await
+            .context("unable to check workspace permissions")?;
+        for team in &spec.teams {
+            permissions.require(Requirement::TeamAdmin(&team.key))?;
+        }
+    }
+    let service = WorkspaceService::new(client);
+    let changes = service
+        .plan(&spec)
+        .await
+        .context("GraphQL request failed")?;
+
+    if args.json && (args.plan || changes.is_empty()) {
+        print_json(&json!({ "plan": changes, "applied": 0 }))?;
+        return Ok(());
+    }
+    if !args.json {
+        render_plan(&changes);
+    }
+    if changes.is_empty() || args.plan {
+        return Ok(());
+    }
+
+    args.confirmation
+        .ensure(
+            Impact::Destructive,
+            &format!("apply {} change(s)", changes.len()),
+        )
+        .await?;
+
+    for change in &changes {
+        service
+            .apply(change)
+            .await
+            .with_context(|| format!("failed to apply {}", change_title(change)))?;
+        if !args.json {
+            println!("applied {}", change_title(change));
+        }
+    }
+
+    if args.json {
+        print_json(&json!({ "plan": changes, "applied": changes.len() }))?;
+    } else {
+        println!("Applied {} change(s).", changes.len());
+    }
+    Ok(())
+}
+
+fn change_title(change: &Change) -> String {
+    let resource = serde_json::to_value(change.resource)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_owned))
+        .unwrap_or_default();
+    format!("{} {}/{}", resource, change.team_key, change.name)
+}
+
+fn render_plan(changes: &[Change]) {
+    if changes.is_empty() {
+        println!("No changes. Workspace matches the spec.");
+        return;
+    }
+    for change in changes {
+        let marker = match change.action {
+            ChangeAction::Create => '+',
+            ChangeAction::Update => '~',
+        };
+        println!("{} {}", marker, change_title(change));
+        for field in &change.fields {
+            match &field.from {
+                Some(from) => println!("    {:<12} {} -> {}", field.field, from, field.to),
+                None => println!("    {:<12} {}", field.field, field.to),
+            }
+        }
+    }
+    let creates = changes
+        .iter()
+        .filter(|change| change.action == ChangeAction::Create)
+        .count();
+    println!();
+    println!(
+        "Plan: {} to create, {} to update.",
+        creates,
+        changes.len() - creates
+    );
+}
@@ -0,0 +1,193 @@
+use std::fs;
+use std::io::{self, IsTerminal, Read};
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use linear_core::budget::Estimate;
+use linear_core::error::LinearError;
+use linear_core::graphql::{complexity, operations, Operation, OperationKind};
+use serde_json::{json, Map, Value};
+
+use crate::budget;
+use crate::fields::print_json;
+use crate::{build_client, load_session, ApiEstimateArgs, ApiQueryArgs};
+
+/// Run the document from `--file` or `--raw` and print the response `data`.
+pub(crate) async fn query(args: ApiQueryArgs) -> Result<()> {
+    let document = document(args.file.as_deref(), args.raw.as_deref())?;
+    let operation = select_operation(&document, args.operation.as_deref())?;
+    if operation.kind == OperationKind::Subscription {
+        return Err(LinearError::validation("subscriptions are not supported over HTTP").into());
+    }
+    let variables = variables(&operation, &args.vars)?;
+
+    let session = load_session(&args.profile).await?;
+    let client = build_client(&session)?;
+    let data = client
+        .execute(&document, variables, args.operation.as_deref())
+        .await
+        .context("GraphQL request failed")?;
+    print_json(&data)
+}
+
+/// What the document from `--file` or `--raw` would cost against the rate limit, sent
+/// `--requests` times, checked against the `[budget]` setting. Nothing is sent.
+pub(crate) fn estimate(args: ApiEstimateArgs) -> Result<()> {
+    let document = document(args.file.as_deref(), args.raw.as_deref())?;
+    let operation = select_operation(&document, args.operation.as_deref())?;
+    let variables = variables(&operation, &args.vars)?;
+    let per_request = complexity(&document, operation.name.as_deref(), &variables)
+        .ok_or_else(|| LinearError::validation("no query or mutation found in the document"))?;
+    let requests = args.requests as usize;
+    let estimate = Estimate {
+        requests: Some(requests),
+        per_request,
+    };
+    let budget = budget::budget()?;
+    let over = estimate.exceeds(&budget);
+
+    if args.json {
+        return print_json(&json!({
+            "operation": operation.name,
+            "complexity": per_request,
+            "requests": requests,
+            "total": estimate.complexity(),
+            "withinBudget": over.is_empty(),
+            "over": over,
+        }));
+    }
+    println!("Complexity per request  {per_request}");
+    if requests > 1 {
+        println!("Requests                {requests}");
+        println!(
+            "Total                   {}",
+            estimate.complexity().unwrap_or_default()
+        );
+    }
+    if over.is_empty() {
+        println!(
+            "Within budget of {} requests and {} points",
+            budget.requests, budget.complexity
+        );
+    } else {
+        for reason in &over {
+            println!("Over budget: {reason}");
+        }
+    }
+    Ok(())
+}
+
+/// Split `KEY=VALUE`; the value may itself contain `=`.
+pub(crate) fn parse_var(raw: &str) -> Result<(String, String), String> {
+    let (key, value) = raw
+        .split_once('=')
+        .ok_or_else(|| format!("expected KEY=VALUE, got '{raw}'"))?;
+    let key = key.trim().trim_start_matches('$');
+    if key.is_empty() {
+        return Err(format!("missing variable name in '{raw}'"));
+    }
+    Ok((key.to_owned(), value.to_owned()))
+}
+
+fn document(file: Option<&Path>, raw: Option<&str>) -> Result<String> {
+    let document = match (file, raw) {
+        (_, Some(raw)) => raw.to_owned(),
+        (Some(path), None) => read_document(path)?,
+        (None, None) => unreachable!("clap requires --file or --raw"),
+    };
+    if document.trim().is_empty() {
+        return Err(LinearError::validation("the GraphQL document is empty").into());
+    }
+    Ok(document)
+}
+
+fn read_document(path: &Path) -> Result<String> {
+    if path == Path::new("-") {
+        if io::stdin().is_terminal() {
+            return Err(
+                LinearError::validation("--file - expects a GraphQL document on stdin").into(),
+            );
+        }
+        let mut document = String::new();
+        io::stdin().read_to_string(&mut document)?;
+        return Ok(document);
+    }
+    fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))
+}
+
+fn select_operation(document: &str, name: Option<&str>) -> Result<Operation> {
+    let mut found = operations(document);
+    let names = || {
+        found
+            .iter()
+            .map(|operation| operation.name.as_deref().unwrap_or("(anonymous)"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    match name {
+        Some(name) => {
+            let Some(index) = found
+                .iter()
+                .position(|operation| operation.name.as_deref() == Some(name))
+            else {
+                return Err(LinearError::validation(format!(
+                    "the document has no operation named '{}' (found: {})",
+                    name,
+                    names()
+                ))
+                .into());
+            };
+            Ok(found.swap_remove(index))
+        }
+        None if found.len() > 1 => Err(LinearError::validation(format!(
+            "the document defines several operations ({}); pick one with --operation",
+            names()
+        ))
+        .into()),
+        None => found.pop().ok_or_else(|| {
+            LinearError::validation("no query or mutation found in the document").into()
+        }),
+    }
+}
+
+/// Coerce each `--var` to its declared type and check that required variables are present.
+fn variables(operation: &Operation, vars: &[(String, String)]) -> Result<Value> {
+    let mut values = Map::new();
+    for (key, raw) in vars {
+        let Some(definition) = operation
+            .variables
+            .iter()
+            .find(|definition| &definition.name == key)
+        else {
+            let declared: Vec<String> = operation
+                .variables
+                .iter()
+                .map(|definition| format!("${}", definition.name))
+                .collect();
+            let message = if declared.is_empty() {
+                format!("${} is not declared; the operation takes no variables", key)
+            } else {
+                format!(
+                    "${} is not declared (declared: {})",
+                    key,
+                    declared.join(", ")
+                )
+            };
+            return Err(LinearError::validation(message).into());
+        };
+        let value = definition.coerce(raw).map_err(LinearError::validation)?;
+        values.insert(key.clone(), value);
+    }
+    if let Some(missing) = operation
+        .variables
+        .iter()
+        .find(|definition| definition.is_required() && !values.contains_key(&definition.name))
+    {
+        return Err(LinearError::validation(format!(
+            "missing --var {}=… for required ${}: {}",
+            missing.name, missing.name, missing.type_name
+        ))
+        .into());
+    }
+    Ok(Value::Object(values))
+}
@@ -0,0 +1,167 @@
+use std::fs;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use linear_core::config::{self, ConfigLocator};
+use linear_core::error::LinearError;
+use linear_core::graphql::RequestPriority;
+use linear_core::services::activity::{parse_since, ActivityKind};
+use linear_core::services::automation::{AutomationService, PlannedRun, RuleSet};
+use linear_core::snapshot::SnapshotStore;
+use serde_json::json;
+
+use crate::fields::print_json;
+use crate::table::{Cell, Table};
+use crate::{build_client, load_session, AutomateRunArgs};
+
+/// Snapshot key of the newest event already handled by `automate run`.
+const WATERMARK_KEY: &str = "automate:watermark";
+
+/// Evaluate `rules.toml` against recent activity and stale issues, then apply the matches.
+pub(crate) async fn run(args: AutomateRunArgs) -> Result<()> {
+    let path = match &args.rules {
+        Some(path) => path.clone(),
+        None => ConfigLocator::new()?.rules_file(),
+    };
+    let raw =
+        fs::read_to_string(&path).with_context(|| format!("failed to read {}", path.display()))?;
+    let rules = RuleSet::from_toml(&raw)
+        .map_err(|err| LinearError::validation(format!("invalid {}: {}", path.display(), err)))?;
+    if rules.rules.is_empty() {
+        return Err(LinearError::validation(format!("no rules in {}", path.display())).into());
+    }
+
+    let store = SnapshotStore::for_profile(&args.profile);
+    let mut watermark = match &args.since {
+        Some(raw) => parse_since(raw, Utc::now()).map_err(LinearError::validation)?,
+        None => store
+            .as_ref()
+            .and_then(|store| store.load::<DateTime<Utc>>(WATERMARK_KEY))
+            .map(|snapshot| snapshot.value)
+            .unwrap_or_else(|| Utc::now() - chrono::Duration::hours(1)),
+    };
+
+    let session = load_session(&args.profile).await?;
+    let mut client = build_client(&session)?;
+    if args.watch {
+        client = client.with_priority(RequestPriority::Background);
+    }
+    let service = AutomationService::new(client);
+    if args.watch {
+        eprintln!(
+            "Evaluating {} rule(s) every {}; Ctrl-C stops.",
+            rules.rules.len(),
+            config::format_duration(args.interval)
+        );
+    }
+
+    loop {
+        let (plan, events) = service
+            .plan(&rules, watermark, Utc::now())
+            .await
+            .context("GraphQL request failed")?;
+        if !args.json {
+            render_plan(&plan);
+        }
+
+        let mut applied = Vec::new();
+        let mut failures = 0;
+        let mut first_failure = None;
+        if !args.plan {
+            for run in &plan {
+                match service.apply(run).await {
+                    Ok(outcome) => {
+                        if !args.json {
+                            let mut parts = outcome.changes.clone();
+                            parts.extend(outcome.skipped.iter().map(|s| format!("({s})")));
+                            println!("{} {}: {}", run.event.issue, run.rule, parts.join(", "));
+                        }
+                        applied.push(json!({
+                            "rule": run.rule,
+                            "issue": run.event.issue,
+                            "outcome": outcome,
+                        }));
+                    }
+                    Err(err) => {
+                        failures += 1;
+                        eprintln!("warning: {} {} failed: {}", run.event.issue, run.rule, err);
+                        first_failure.get_or_insert(err);
+                    }
+                }
+            }
+            // Stale events are stamped with the issue's last update, so only feed events move
+            // the watermark forward.
+            if let Some(newest) = events.iter().map(|event| event.at).max() {
+                watermark = watermark.max(newest + chrono::Duration::milliseconds(1));
+            }
+            if let Some(store) = &store {
+                if let Err(err) = store.save(WATERMARK_KEY, &watermark) {
+                    eprintln!("warning: failed to save automation watermark: {}", err);
+                }
+            }
+        }
+
+        if args.json {
+            print_json(&json!({ "plan": plan, "applied": applied }))?;
+        }
+        if !args.watch {
+            return match first_failure {
+                Some(err) => {
+                    Err(anyhow::Error::new(err).context(format!("{} rule run(s) failed", failures)))
+                }
+                None => Ok(()),
+            };
+        }
+        tokio::time::sleep(args.interval.max(Duration::from_secs(1))).await;
+    }
+}
+
+fn render_plan(plan: &[PlannedRun]) {
+    if plan.is_empty() {
+        println!("No rules matched.");
+        return;
+    }
+    let mut table = Table::new()
+        .flex_column("RULE", 8, 30)
+        .column("KEY", 12)
+        .flex_column("TRIGGER", 10, 30)
+        .flex_column("ACTIONS", 12, 60);
+    for run in plan {
+        table.row([
+            run.rule.as_str().into(),
+            Cell::link(run.event.issue.clone(), run.event.url.as_deref()),
+            trigger(&run.event.kind).into(),
+            actions(run).into(),
+        ]);
+    }
+    table.print();
+}
+
+fn trigger(kind: &ActivityKind) -> String {
+    match kind {
+        ActivityKind::Created => "created".into(),
+        ActivityKind::StateChanged { to, .. } => format!("→ {}", to),
+        ActivityKind::Commented { .. } => "commented".into(),
+        ActivityKind::LabelAdded { label } => format!("+{}", label),
+        ActivityKind::Stale { days } => format!("stale {}d", days),
+    }
+}
+
+fn actions(run: &PlannedRun) -> String {
+    let actions = &run.actions;
+    let mut parts = Vec::new();
+    if let Some(state) = &actions.state {
+        parts.push(format!("state={}", state));
+    }
+    if let Some(priority) = &actions.priority {
+        parts.push(format!("priority={}", priority));
+    }
+    if let Some(assign) = &actions.assign {
+        parts.push(format!("assign={}", assign));
+    }
+    if actions.comment.is_some() {
+        parts.push("comment".into());
+    }
+    parts.join(" ")
+}
@@ -0,0 +1,159 @@
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::process::{Child, Command, Stdio};
+
+use anyhow::{bail, Context, Result};
+use chrono::Utc;
+use futures_util::StreamExt;
+use linear_core::backup::{self, BackupEntity, EntityRecord, Manifest};
+use linear_core::graphql::RequestPriority;
+use linear_core::services::backup::BackupService;
+use serde_json::{json, Value};
+
+use crate::fields::print_json;
+use crate::{build_client, load_session, BackupArgs};
+
+/// Walk every page of the selected entities into `<out>/<timestamp>/`, finishing with the
+/// manifest. `--incremental` starts each entity at the watermark of the latest backup.
+pub(crate) async fn run(args: BackupArgs) -> Result<()> {
+    let mut include = if args.include.is_empty() {
+        BackupEntity::ALL.to_vec()
+    } else {
+        args.include.clone()
+    };
+    include.sort();
+    include.dedup();
+
+    let previous = if args.incremental {
+        let previous = backup::latest(&args.out)
+            .with_context(|| format!("failed to read backups in {}", args.out.display()))?;
+        if previous.is_none() {
+            eprintln!(
+                "No earlier backup in {}; saving everything.",
+                args.out.display()
+            );
+        }
+        previous
+    } else {
+        None
+    };
+
+    let session = load_session(&args.profile).await?;
+    // A backup walks the whole workspace; anything interactive sharing the budget goes first.
+    let client = build_client(&session)?.with_priority(RequestPriority::Background);
+    let workspace = client
+        .organization()
+        .await
+        .context("GraphQL request failed")?;
+
+    let started = Utc::now();
+    let dir = args.out.join(backup::dir_name(started));
+    fs::create_dir_all(&dir).with_context(|| format!("failed to create {}", dir.display()))?;
+    let mut manifest = Manifest::new(started, Some(workspace));
+    manifest.previous = previous
+        .as_ref()
+        .and_then(|(path, _)| path.file_name())
+        .map(|name| name.to_string_lossy().into_owned());
+
+    let service = BackupService::new(client);
+    for entity in include {
+        let since = previous
+            .as_ref()
+            .and_then(|(_, manifest)| manifest.watermark(entity));
+        let file = entity.file_name(args.compress);
+        let path = dir.join(&file);
+        let mut writer = ArrayWriter::create(&path, args.compress)?;
+        let mut watermark = since;
+        let mut records = service.records(entity, since);
+        while let Some(record) = records.next().await {
+            let record = record.with_context(|| format!("failed to fetch {entity}"))?;
+            watermark = watermark.max(backup::updated_at(&record));
+            writer
+                .push(&record)
+                .with_context(|| format!("failed to write {}", path.display()))?;
+        }
+        let count = writer
+            .finish()
+            .with_context(|| format!("failed to write {}", path.display()))?;
+        if !args.json {
+            eprintln!("{entity}: {count}");
+        }
+        manifest.entities.insert(
+            entity,
+            EntityRecord {
+                file,
+                count,
+                since,
+                watermark,
+            },
+        );
+    }
+    manifest
+        .save(&dir)
+        .with_context(|| format!("failed to write the manifest in {}", dir.display()))?;
+
+    if args.json {
+        print_json(&json!({ "dir": dir, "manifest": manifest }))?;
+    } else {
+        println!("Backed up to {}", dir.display());
+    }
+    Ok(())
+}
+
+/// Writes records as a JSON array, one per line, piped through `gzip` when compressing.
+struct ArrayWriter {
+    out: BufWriter<Box<dyn Write>>,
+    gzip: Option<Child>,
+    count: usize,
+}
+
+impl ArrayWriter {
+    fn create(path: &Path, compress: bool) -> Result<Self> {
+        let file =
+            File::create(path).with_context(|| format!("failed to create {}", path.display()))?;
+        let (out, gzip): (Box<dyn Write>, _) = if compress {
+            let mut child = Command::new("gzip")
+                .arg("-c")
+                .stdin(Stdio::piped())
+                .stdout(file)
+                .spawn()
+                .context("failed to run gzip for --compress")?;
+            let stdin = child.stdin.take().context("gzip has no stdin")?;
+            (Box::new(stdin), Some(child))
+        } else {
+            (Box::new(file), None)
+        };
+        let mut writer = Self {
+            out: BufWriter::new(out),
+            gzip,
+            count: 0,
+        };
+        writer.out.write_all(b"[\n")?;
+        Ok(writer)
+    }
+
+    fn push(&mut self, record: &Value) -> Result<()> {
+        if self.count > 0 {
+            self.out.write_all(b",\n")?;
+        }
+        serde_json::to_writer(&mut self.out, record)?;
+        self.count += 1;
+        Ok(())
+    }
+
+    /// Close the array and wait for `gzip`, returning how many records were written.
+    fn finish(mut self) -> Result<usize> {
+        self.out.write_all(b"\n]\n")?;
+        self.out.flush()?;
+        // Dropping the pipe tells gzip the input is complete.
+        drop(self.out);
+        if let Some(mut gzip) = self.gzip {
+            let status = gzip.wait()?;
+            if !status.success() {
+                bail!("gzip exited with {status}");
+            }
+        }
+        Ok(self.count)
+    }
+}
@@ -0,0 +1,173 @@
+use std::future::Future;
+use std::io::{self, IsTerminal, Read};
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use clap::Args;
+use linear_core::error::{ErrorKind, LinearError};
+use serde_json::json;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+use crate::dry_run_request;
+use crate::fields::print_json;
+use crate::settings;
+use crate::table::Table;
+
+/// Flags shared by mutating issue commands that can fan out over keys read from stdin.
+#[derive(Args, Debug, Clone)]
+pub(crate) struct BatchArgs {
+    /// Read issue keys, URLs, or numbers from stdin (whitespace, comma, or newline separated)
+    #[arg(long)]
+    pub(crate) stdin_keys: bool,
+    /// Maximum number of keys processed concurrently with --stdin-keys
+    #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u16).range(1..=32))]
+    pub(crate) concurrency: u16,
+}
+
+/// What happened to one key: a short detail on success.
+pub(crate) struct BatchResult {
+    pub(crate) key: String,
+    pub(crate) outcome: Result<String>,
+}
+
+/// Run `op` for every key on stdin, at most `--concurrency` at a time, then print a summary.
+///
+/// Under `--dry-run` each key reports the mutation it would have sent. Fails (with the first failure's exit code) if any key failed.
+pub(crate) async fn run<F, Fut>(batch: &BatchArgs, json: bool, op: F) -> Result<()>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<String>> + Send + 'static,
+{
+    let keys = read_stdin_keys()?;
+    let semaphore = Arc::new(Semaphore::new(batch.concurrency as usize));
+    let mut tasks = JoinSet::new();
+    for (index, key) in keys.iter().cloned().enumerate() {
+        let permit = semaphore.clone().acquire_owned().await?;
+        let future = op(key);
+        tasks.spawn(async move {
+            let outcome = future.await;
+            drop(permit);
+            (index, outcome)
+        });
+    }
+
+    let mut outcomes: Vec<Option<Result<String>>> = keys.iter().map(|_| None).collect();
+    while let Some(joined) = tasks.join_next().await {
+        let (index, outcome) = joined?;
+        outcomes[index] = Some(outcome);
+    }
+    let results: Vec<BatchResult> = keys
+        .into_iter()
+        .zip(outcomes)
+        .map(|(key, outcome)| BatchResult {
+            key,
+            outcome: outcome
+                .unwrap_or_else(|| Err(anyhow!("task did not complete")))
+                .or_else(would_send),
+        })
+        .collect();
+    report(&results, json)
+}
+
+/// Under `--dry-run` a key succeeds with the mutation it would have sent.
+fn would_send(err: anyhow::Error) -> Result<String> {
+    match dry_run_request(&err) {
+        Some(request) => Ok(format!(
+            "would send {} {}",
+            request.operation, request.variables
+        )),
+        None => Err(err),
+    }
+}
+
+/// Print per-key results as a table (or JSON), failing with the first failure's exit code if
+/// any key failed.
+pub(crate) fn report(results: &[BatchResult], json: bool) -> Result<()> {
+    if json {
+        render_json(results)?;
+    } else {
+        render_table(results);
+    }
+
+    let failed: Vec<&anyhow::Error> = results
+        .iter()
+        .filter_map(|result| result.outcome.as_ref().err())
+        .collect();
+    match failed.first() {
+        None => Ok(()),
+        Some(first) => Err(LinearError::new(
+            ErrorKind::classify(first),
+            format!("{} of {} key(s) failed", failed.len(), results.len()),
+        )
+        .into()),
+    }
+}
+
+fn read_stdin_keys() -> Result<Vec<String>> {
+    if io::stdin().is_terminal() {
+        return Err(
+            LinearError::validation("--stdin-keys expects issue keys piped on stdin").into(),
+        );
+    }
+    let mut raw = String::new();
+    io::stdin().read_to_string(&mut raw)?;
+    let keys = parse_keys(&raw);
+    if keys.is_empty() {
+        return Err(LinearError::validation("no issue keys found on stdin").into());
+    }
+    let mut parsed: Vec<String> = Vec::new();
+    for key in keys {
+        let key = settings::parse_issue_key(&key).map_err(LinearError::validation)?;
+        if !parsed.contains(&key) {
+            parsed.push(key);
+        }
+    }
+    Ok(parsed)
+}
+
+fn parse_keys(raw: &str) -> Vec<String> {
+    let mut keys: Vec<String> = Vec::new();
+    for token in raw.split(|c: char| c.is_whitespace() || c == ',') {
+        let key = token.trim_matches(|c| c == '"' || c == '\'').trim();
+        if !key.is_empty() && !keys.iter().any(|existing| existing == key) {
+            keys.push(key.to_owned());
+        }
+    }
+    keys
+}
+
+fn render_table(results: &[BatchResult]) {
+    let mut table = Table::new()
+        .column("KEY", 12)
+        .column("RESULT", 6)
+        .flex_column("DETAIL", 20, 80);
+    for result in results {
+        let (status, detail) = match &result.outcome {
+            Ok(detail) => ("ok", detail.clone()),
+            Err(err) => ("failed", format!("{:#}", err)),
+        };
+        table.row([result.key.clone(), status.to_owned(), detail]);
+    }
+    table.print();
+    let failed = results.iter().filter(|r| r.outcome.is_err()).count();
+    println!();
+    println!("{} succeeded, {} failed", results.len() - failed, failed);
+}
+
+fn render_json(results: &[BatchResult]) -> Result<()> {
+    let payload: Vec<_> = results
+        .iter()
+        .map(|result| match &result.outcome {
+            Ok(detail) => json!({ "key": result.key, "ok": true, "detail": detail }),
+            Err(err) => json!({
+                "key": result.key,
+                "ok": false,
+                "error": format!("{:#}", err),
+                "kind": ErrorKind::classify(err).as_str(),
+            }),
+        })
+        .collect();
+    print_json(&payload)?;
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use anyhow::Result;
+use clap::Args;
+use linear_core::budget::Estimate;
+use linear_core::config::{Budget, Config, ConfigLocator};
+use linear_core::error::LinearError;
+
+/// `--force` for commands that check what they will cost against the `[budget]` setting first.
+#[derive(Args, Debug, Clone)]
+pub(crate) struct BudgetArgs {
+    /// Run even when the estimated cost is over the budget in config.toml
+    #[arg(long)]
+    pub(crate) force: bool,
+}
+
+impl BudgetArgs {
+    /// Check `estimate` before `what` (e.g. `this export`) runs: over budget it warns on stderr,
+    /// or, when the budget is enforced, fails unless `--force` was given.
+    pub(crate) fn check(&self, estimate: &Estimate, what: &str) -> Result<()> {
+        if self.force {
+            return Ok(());
+        }
+        let budget = budget()?;
+        over_budget(&budget, estimate, what)
+    }
+
+    /// A meter for `what`, which fetches `estimate.per_request` points a page of `page_size`
+    /// results at a time. Only an estimate without a request count, one that pages until the
+    /// results run out, needs metering as it goes.
+    pub(crate) fn meter(&self, estimate: &Estimate, page_size: usize, what: &str) -> Result<Meter> {
+        if self.force || estimate.requests.is_some() {
+            return Ok(Meter::default());
+        }
+        Ok(Meter {
+            budget: Some(budget()?),
+            per_request: estimate.per_request,
+            page_size,
+            what: what.to_owned(),
+            taken: 0,
+        })
+    }
+}
+
+/// Counts results as a paged stream is consumed, applying the budget check at the first page
+/// that goes over it.
+#[derive(Debug, Default)]
+pub(crate) struct Meter {
+    /// `None` when nothing is metered.
+    budget: Option<Budget>,
+    per_request: f64,
+    page_size: usize,
+    what: String,
+    taken: usize,
+}
+
+impl Meter {
+    /// Call before taking each result, since taking the first result of a page fetches it.
+    pub(crate) fn next(&mut self) -> Result<()> {
+        let Some(budget) = self.budget else {
+            return Ok(());
+        };
+        let index = self.taken;
+        self.taken += 1;
+        if self.page_size == 0 || index % self.page_size != 0 {
+            return Ok(());
+        }
+        let estimate = Estimate {
+            requests: Some(index / self.page_size + 1),
+            per_request: self.per_request,
+        };
+        if estimate.exceeds(&budget).is_empty() {
+            return Ok(());
+        }
+        // Past the budget once is enough to warn about; later pages stay quiet.
+        self.budget = None;
+        over_budget(&budget, &estimate, &self.what)
+    }
+}
+
+fn over_budget(budget: &Budget, estimate: &Estimate, what: &str) -> Result<()> {
+    let reasons = estimate.exceeds(budget);
+    if reasons.is_empty() {
+        return Ok(());
+    }
+    let reasons = reasons.join("; ");
+    if budget.enforce {
+        return Err(LinearError::validation(format!(
+            "{what} would go over budget ({reasons}); narrow it down or re-run with --force"
+        ))
+        .into());
+    }
+    eprintln!("warning: {what} goes over budget ({reasons})");
+    Ok(())
+}
+
+/// The `[budget]` setting, or its default when no config directory is available.
+pub(crate) fn budget() -> Result<Budget> {
+    let Ok(locator) = ConfigLocator::new() else {
+        return Ok(Budget::default());
+    };
+    Ok(Config::load(&locator)?.budget)
+}
@@ -0,0 +1,123 @@
+use anyhow::{anyhow, Context, Result};
+use linear_core::config::{self, Config, ConfigLocator};
+use linear_core::error::LinearError;
+use linear_core::graphql::ResponseCache;
+use linear_core::services::projects::ProjectService;
+use linear_core::snapshot::SnapshotStore;
+use serde::Serialize;
+use serde_json::json;
+
+use crate::fields::print_json;
+use crate::table::Table;
+use crate::tui::app::{
+    members_snapshot_key, project_filter_list, projects_snapshot_key, states_snapshot_key,
+    TEAMS_SNAPSHOT,
+};
+use crate::{build_client, load_session, CacheWarmArgs};
+
+#[derive(Debug, Default, Serialize)]
+struct Counts {
+    teams: usize,
+    states: usize,
+    labels: usize,
+    members: usize,
+    projects: usize,
+    templates: usize,
+}
+
+/// Fetch the metadata commands and the TUI start from, refreshing every response cache entry
+/// and offline snapshot it touches, then print how much was cached.
+pub(crate) async fn warm(args: CacheWarmArgs) -> Result<()> {
+    let ttl = match args.ttl {
+        Some(ttl) => ttl,
+        None => match ConfigLocator::new() {
+            Ok(locator) => Config::load(&locator)?
+                .cache_ttl()
+                .unwrap_or(config::DEFAULT_CACHE_TTL),
+            Err(_) => config::DEFAULT_CACHE_TTL,
+        },
+    };
+    if ttl.is_zero() {
+        return Err(LinearError::validation("--ttl must be longer than zero").into());
+    }
+    let cache = ResponseCache::in_cache_dir(ttl)
+        .ok_or_else(|| anyhow!("unable to locate the cache directory"))?;
+    let dir = cache.dir().to_path_buf();
+    let snapshots = SnapshotStore::for_profile(&args.profile)
+        .ok_or_else(|| anyhow!("unable to locate the offline cache directory"))?;
+
+    let session = load_session(&args.profile).await?;
+    let client = build_client(&session)?.with_cache(Some(cache.refreshing()));
+    let projects = ProjectService::new(client.clone());
+
+    let mut counts = Counts::default();
+    let teams = client.teams().await.context("unable to load teams")?;
+    save(&snapshots, TEAMS_SNAPSHOT, &teams)?;
+    counts.teams = teams.len();
+    for team in &teams {
+        let (states, members, labels, team_projects) = tokio::join!(
+            client.workflow_states(&team.id),
+            client.team_members(&team.id),
+            client.issue_labels(&team.id),
+            project_filter_list(&projects, Some(team.id.clone())),
+        );
+        let context = |what: &str| format!("unable to load {what} of team {}", team.key);
+        let states = states.with_context(|| context("workflow states"))?;
+        let members = members.with_context(|| context("members"))?;
+        let labels = labels.with_context(|| context("labels"))?;
+        let team_projects = team_projects.with_context(|| context("projects"))?;
+        save(&snapshots, &states_snapshot_key(&team.id), &states)?;
+        save(&snapshots, &members_snapshot_key(&team.id), &members)?;
+        save(
+            &snapshots,
+            &projects_snapshot_key(Some(&team.id)),
+            &team_projects,
+        )?;
+        counts.states += states.len();
+        counts.members += members.len();
+        counts.labels += labels.len();
+    }
+    let (labels, templates, all_projects) = tokio::join!(
+        client.workspace_labels(),
+        client.templates(),
+        project_filter_list(&projects, None),
+    );
+    counts.labels += labels.context("unable to load workspace labels")?.len();
+    counts.templates = templates.context("unable to load templates")?.len();
+    let all_projects = all_projects.context("unable to load projects")?;
+    save(&snapshots, &projects_snapshot_key(None), &all_projects)?;
+    counts.projects = all_projects.len();
+
+    if args.json {
+        return print_json(&json!({
+            "profile": args.profile,
+            "ttl_secs": ttl.as_secs(),
+            "cache_dir": dir,
+            "cached": counts,
+        }));
+    }
+    let mut table = Table::new().column("CACHED", 12).column("COUNT", 6);
+    for (what, count) in [
+        ("teams", counts.teams),
+        ("states", counts.states),
+        ("labels", counts.labels),
+        ("members", counts.members),
+        ("projects", counts.projects),
+        ("templates", counts.templates),
+    ] {
+        table.row([what.to_owned(), count.to_string()]);
+    }
+    table.print();
+    println!(
+        "Responses kept for {} in {}",
+        config::format_duration(ttl),
+        dir.display()
+    );
+    Ok(())
+}
+
+fn save<T: Serialize>(snapshots: &SnapshotStore, key: &str, value: &T) -> Result<()> {
+    snapshots
+        .save(key, value)
+        .with_context(|| format!("unable to write offline cache entry {key}"))
+}
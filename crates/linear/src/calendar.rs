@@ -0,0 +1,261 @@
+use std::fs;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use futures_util::{StreamExt, TryStreamExt};
+use linear_core::calendar::{self, DueIssue, Event, Source};
+use linear_core::error::LinearError;
+use linear_core::query::IssueQuery;
+use linear_core::services::cycles::{CycleQueryOptions, CycleService, CycleSort};
+use linear_core::services::issues::IssueService;
+use linear_core::services::projects::{ProjectQueryOptions, ProjectService};
+use serde_json::json;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::time::timeout;
+
+use crate::{build_client, load_session, resolve, CalendarExportArgs};
+
+/// Paths `--serve` answers with the feed.
+const FEED_PATHS: [&str; 3] = ["/", "/linear.ics", "/calendar.ics"];
+/// How long `--serve` waits for a client's request line before hanging up.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+/// Pause after a failed `accept` before trying again.
+const ACCEPT_BACKOFF: Duration = Duration::from_millis(100);
+
+struct Feed {
+    issues: IssueService,
+    projects: ProjectService,
+    cycles: CycleService,
+    team_id: Option<String>,
+    name: String,
+    include: Vec<Source>,
+    past: chrono::Duration,
+}
+
+/// Write the ICS feed to `--out` or stdout, or serve it with `--serve`.
+pub(crate) async fn export(args: CalendarExportArgs) -> Result<()> {
+    let past = chrono::Duration::from_std(args.past)
+        .map_err(|_| LinearError::validation("--past is too large"))?;
+    let session = load_session(&args.profile).await?;
+    let client = build_client(&session)?;
+    let issues = IssueService::new(client.clone());
+    let team_id = match &args.team {
+        Some(team) => Some(resolve::team_id(&issues, team).await?),
+        None => None,
+    };
+    let feed = Feed {
+        issues,
+        projects: ProjectService::new(client.clone()),
+        cycles: CycleService::new(client),
+        team_id,
+        name: match &args.team {
+            Some(team) => format!("Linear {}", team),
+            None => "Linear".into(),
+        },
+        include: args.include.clone(),
+        past,
+    };
+
+    if args.serve {
+        return serve(feed, &args.listen, args.refresh).await;
+    }
+    let ics = feed.render().await?;
+    match &args.out {
+        Some(path) => {
+            fs::write(path, &ics).with_context(|| format!("failed to write {}", path.display()))?;
+            eprintln!("Wrote {}", path.display());
+        }
+        None => print!("{}", ics),
+    }
+    Ok(())
+}
+
+impl Feed {
+    async fn render(&self) -> Result<String> {
+        let now = Utc::now();
+        let cutoff = now - self.past;
+        let mut events: Vec<Event> = Vec::new();
+
+        if self.include.contains(&Source::Cycles) {
+            // Latest end first, so the stream can stop at the first cycle that ended too long ago.
+            let mut cycles = Vec::new();
+            let mut stream = self.cycles.list_iter(CycleQueryOptions {
+                team_id: self.team_id.clone(),
+                sort: Some(CycleSort::EndDesc),
+                ..Default::default()
+            });
+            while let Some(cycle) = stream.next().await {
+                let cycle = cycle.context("GraphQL request failed")?;
+                let ended = cycle
+                    .ends_at
+                    .as_deref()
+                    .and_then(|at| chrono::DateTime::parse_from_rfc3339(at).ok())
+                    .is_some_and(|at| at < cutoff);
+                if ended {
+                    break;
+                }
+                cycles.push(cycle);
+            }
+            events.extend(calendar::cycle_events(&cycles));
+        }
+
+        if self.include.contains(&Source::Projects) {
+            let projects: Vec<_> = self
+                .projects
+                .list_iter(ProjectQueryOptions {
+                    team_id: self.team_id.clone(),
+                    ..Default::default()
+                })
+                .try_collect()
+                .await
+                .context("GraphQL request failed")?;
+            events.extend(
+                calendar::project_events(&projects)
+                    .into_iter()
+                    .filter(|event| event.end > cutoff.date_naive()),
+            );
+        }
+
+        if self.include.contains(&Source::Issues) {
+            let mut query = IssueQuery::new()
+                .open()
+                .raw("dueDate", json!({ "gte": cutoff.date_naive().to_string() }))
+                .select(DueIssue::fields())
+                .first(100);
+            if let Some(team_id) = &self.team_id {
+                query = query.team_id(team_id.clone());
+            }
+            let issues: Vec<DueIssue> = self
+                .issues
+                .query_iter(query)
+                .try_collect()
+                .await
+                .context("GraphQL request failed")?;
+            events.extend(calendar::issue_events(&issues));
+        }
+
+        events.sort_by(|a, b| a.start.cmp(&b.start).then_with(|| a.uid.cmp(&b.uid)));
+        Ok(calendar::render(&self.name, &events, now))
+    }
+}
+
+/// Answer `GET` requests for the feed, regenerating it at most once per `refresh`.
+async fn serve(feed: Feed, listen: &str, refresh: Duration) -> Result<()> {
+    let listener = TcpListener::bind(listen)
+        .await
+        .with_context(|| format!("failed to listen on {}", listen))?;
+    let address = listener.local_addr()?;
+    eprintln!(
+        "Serving calendar at http://{}/linear.ics; Ctrl-C stops.",
+        address
+    );
+
+    if !address.ip().is_loopback() {
+        eprintln!(
+            "warning: the feed has no authentication and lists issue titles; anyone who can \
+             reach {address} can read it"
+        );
+    }
+
+    let feed = Arc::new(feed);
+    let cached = Arc::new(Mutex::new(None));
+    loop {
+        let stream = match listener.accept().await {
+            Ok((stream, _)) => stream,
+            // Running out of file descriptors, or a connection aborted before it was accepted,
+            // is usually over by the next attempt; keep serving.
+            Err(err) => {
+                eprintln!("warning: failed to accept a connection: {err}");
+                tokio::time::sleep(ACCEPT_BACKOFF).await;
+                continue;
+            }
+        };
+        // One task per connection, so a client that never finishes its request stalls no one.
+        tokio::spawn(answer(feed.clone(), cached.clone(), refresh, stream));
+    }
+}
+
+/// The last feed built and when.
+type Cached = Arc<Mutex<Option<(Instant, Arc<String>)>>>;
+
+async fn answer(feed: Arc<Feed>, cached: Cached, refresh: Duration, mut stream: TcpStream) {
+    let Ok(Some((method, path))) = timeout(REQUEST_TIMEOUT, read_request(&mut stream)).await else {
+        return;
+    };
+    let path = path.split('?').next().unwrap_or_default();
+    let response = if !matches!(method.as_str(), "GET" | "HEAD") {
+        response(405, "text/plain", "method not allowed\n")
+    } else if !FEED_PATHS.contains(&path) {
+        response(404, "text/plain", "not found\n")
+    } else {
+        match current_feed(&feed, &cached, refresh).await {
+            Some(ics) => response(200, "text/calendar; charset=utf-8", &ics),
+            None => response(502, "text/plain", "calendar unavailable\n"),
+        }
+    };
+    let body = if method == "HEAD" {
+        response
+            .split("\r\n\r\n")
+            .next()
+            .unwrap_or_default()
+            .to_owned()
+            + "\r\n\r\n"
+    } else {
+        response
+    };
+    // A client that hangs up early only loses its own response.
+    let _ = stream.write_all(body.as_bytes()).await;
+    let _ = stream.shutdown().await;
+}
+
+/// The cached feed while it is fresh, else a newly built one; the last good feed when the
+/// rebuild fails. The lock is only held to read or store the snapshot, never while building.
+async fn current_feed(feed: &Feed, cached: &Cached, refresh: Duration) -> Option<Arc<String>> {
+    let snapshot = cached
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone();
+    if let Some((built, ics)) = &snapshot {
+        if built.elapsed() < refresh {
+            return Some(ics.clone());
+        }
+    }
+    match feed.render().await {
+        Ok(ics) => {
+            let ics = Arc::new(ics);
+            *cached.lock().unwrap_or_else(PoisonError::into_inner) =
+                Some((Instant::now(), ics.clone()));
+            Some(ics)
+        }
+        Err(err) => {
+            eprintln!("warning: failed to build calendar: {:#}", err);
+            snapshot.map(|(_, ics)| ics)
+        }
+    }
+}
+
+/// Method and path from the request line, or `None` for an unreadable request.
+async fn read_request(stream: &mut TcpStream) -> Option<(String, String)> {
+    let mut buffer = [0u8; 4096];
+    let read = stream.read(&mut buffer).await.ok()?;
+    let request = String::from_utf8_lossy(&buffer[..read]);
+    let mut parts = request.lines().next()?.split_whitespace();
+    Some((parts.next()?.to_owned(), parts.next()?.to_owned()))
+}
+
+fn response(status: u16, content_type: &str, body: &str) -> String {
+    let reason = match status {
+        200 => "OK",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        _ => "Bad Gateway",
+    };
+    format!(
+        "HTTP/1.1 {status} {reason}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\n\
+         Cache-Control: no-cache\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
@@ -0,0 +1,150 @@
+//! System clipboard access through the platform's command-line tools, with an OSC 52
+//! terminal escape as the fallback (and the only option over SSH).
+
+use std::env;
+use std::fmt;
+use std::io::{self, IsTerminal, Write};
+use std::process::{Command, Stdio};
+
+use anyhow::Result;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use linear_core::error::LinearError;
+
+/// Commands that print the clipboard, tried in order.
+#[cfg(target_os = "macos")]
+const PASTE_COMMANDS: &[&[&str]] = &[&["pbpaste"]];
+#[cfg(windows)]
+const PASTE_COMMANDS: &[&[&str]] = &[&["powershell", "-NoProfile", "-Command", "Get-Clipboard"]];
+#[cfg(not(any(target_os = "macos", windows)))]
+const PASTE_COMMANDS: &[&[&str]] = &[
+    &["wl-paste", "--no-newline"],
+    &["xclip", "-selection", "clipboard", "-out"],
+    &["xsel", "--clipboard", "--output"],
+];
+
+/// Commands that replace the clipboard with their stdin, tried in order.
+#[cfg(target_os = "macos")]
+const COPY_COMMANDS: &[&[&str]] = &[&["pbcopy"]];
+#[cfg(windows)]
+const COPY_COMMANDS: &[&[&str]] = &[&["clip"]];
+#[cfg(not(any(target_os = "macos", windows)))]
+const COPY_COMMANDS: &[&[&str]] = &[
+    &["wl-copy"],
+    &["xclip", "-selection", "clipboard", "-in"],
+    &["xsel", "--clipboard", "--input"],
+];
+
+/// Text on the clipboard, from the first paste tool that is installed and succeeds.
+pub(crate) fn read() -> Result<String> {
+    for command in PASTE_COMMANDS {
+        let Ok(output) = Command::new(command[0])
+            .args(&command[1..])
+            .stdin(Stdio::null())
+            .stderr(Stdio::null())
+            .output()
+        else {
+            continue;
+        };
+        if output.status.success() {
+            return Ok(String::from_utf8_lossy(&output.stdout).into_owned());
+        }
+    }
+    let tools: Vec<&str> = PASTE_COMMANDS.iter().map(|command| command[0]).collect();
+    Err(LinearError::validation(format!(
+        "could not read the clipboard; install one of: {}",
+        tools.join(", ")
+    ))
+    .into())
+}
+
+/// How copied text reached the clipboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CopyMethod {
+    /// A platform tool such as `pbcopy` or `wl-copy`.
+    Tool(&'static str),
+    /// An OSC 52 escape asking the terminal to set its clipboard.
+    Osc52,
+}
+
+impl fmt::Display for CopyMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CopyMethod::Tool(name) => f.write_str(name),
+            CopyMethod::Osc52 => f.write_str("OSC 52"),
+        }
+    }
+}
+
+/// Put `text` on the clipboard with the first copy tool that is installed and succeeds, or
+/// through the terminal when none is.
+///
+/// Over SSH the tools would fill the remote machine's clipboard, so the terminal is asked
+/// directly.
+pub(crate) fn write(text: &str) -> Result<CopyMethod> {
+    if in_ssh_session() {
+        return osc52(text).map(|()| CopyMethod::Osc52);
+    }
+    for command in COPY_COMMANDS {
+        let Ok(mut child) = Command::new(command[0])
+            .args(&command[1..])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+        else {
+            continue;
+        };
+        let written = child
+            .stdin
+            .take()
+            .is_some_and(|mut stdin| stdin.write_all(text.as_bytes()).is_ok());
+        if child.wait().is_ok_and(|status| status.success()) && written {
+            return Ok(CopyMethod::Tool(command[0]));
+        }
+    }
+    osc52(text).map(|()| CopyMethod::Osc52).map_err(|_| {
+        let tools: Vec<&str> = COPY_COMMANDS.iter().map(|command| command[0]).collect();
+        LinearError::validation(format!(
+            "could not write the clipboard; install one of {} or run in a terminal",
+            tools.join(", ")
+        ))
+        .into()
+    })
+}
+
+fn in_ssh_session() -> bool {
+    ["SSH_TTY", "SSH_CONNECTION", "SSH_CLIENT"]
+        .iter()
+        .any(|name| env::var_os(name).is_some())
+}
+
+/// Send the OSC 52 sequence for `text` to whichever of stdout or stderr is a terminal.
+fn osc52(text: &str) -> Result<()> {
+    let sequence = osc52_sequence(text, env::var_os("TMUX").is_some());
+    // Stdout may be piped (e.g. with `--json`); the sequence has to reach the terminal itself.
+    if io::stdout().is_terminal() {
+        let mut stdout = io::stdout();
+        stdout.write_all(sequence.as_bytes())?;
+        stdout.flush()?;
+    } else if io::stderr().is_terminal() {
+        let mut stderr = io::stderr();
+        stderr.write_all(sequence.as_bytes())?;
+        stderr.flush()?;
+    } else {
+        return Err(
+            LinearError::validation("no terminal to send the clipboard sequence to").into(),
+        );
+    }
+    Ok(())
+}
+
+/// `ESC ] 52 ; c ; <base64> BEL`, wrapped in tmux's passthrough when running inside tmux.
+fn osc52_sequence(text: &str, tmux: bool) -> String {
+    let sequence = format!("\x1b]52;c;{}\x07", STANDARD.encode(text));
+    if tmux {
+        format!("\x1bPtmux;{}\x1b\\", sequence.replace('\x1b', "\x1b\x1b"))
+    } else {
+        sequence
+    }
+}
@@ -0,0 +1,55 @@
+//! Label color chips for the TUI (ratatui).
+//!
+//! The CLI's crossterm chips live in `linear_commands::color`; this module converts the same
+//! colors to ratatui's.
+
+use linear_core::color::{self, TermColor};
+use linear_core::graphql::IssueLabel;
+use ratatui::style::Style;
+use ratatui::text::Span;
+
+pub(crate) use linear_commands::color::{depth, label_chips};
+
+/// A label as a TUI chip; labels without a usable color get a plain `[name]`.
+pub(crate) fn label_span(label: &IssueLabel) -> Span<'static> {
+    match label
+        .color
+        .as_deref()
+        .and_then(|hex| color::chip(hex, depth()))
+    {
+        Some((background, foreground)) => Span::styled(
+            format!(" {} ", label.name),
+            Style::default()
+                .fg(to_ratatui(foreground))
+                .bg(to_ratatui(background)),
+        ),
+        None => Span::raw(format!("[{}]", label.name)),
+    }
+}
+
+fn to_ratatui(color: TermColor) -> ratatui::style::Color {
+    use ratatui::style::Color;
+    const BASIC: [Color; 16] = [
+        Color::Black,
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+        Color::Gray,
+        Color::DarkGray,
+        Color::LightRed,
+        Color::LightGreen,
+        Color::LightYellow,
+        Color::LightBlue,
+        Color::LightMagenta,
+        Color::LightCyan,
+        Color::White,
+    ];
+    match color {
+        TermColor::Rgb(rgb) => Color::Rgb(rgb.0, rgb.1, rgb.2),
+        TermColor::Indexed(index) if index < 16 => BASIC[index as usize],
+        TermColor::Indexed(index) => Color::Indexed(index),
+    }
+}
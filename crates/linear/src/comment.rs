@@ -0,0 +1,110 @@
+use std::fs;
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use linear_core::config::Impact;
+use linear_core::error::LinearError;
+use linear_core::graphql::LinearGraphqlClient;
+use linear_core::query::IssueFields;
+use linear_core::services::issues::IssueService;
+use linear_core::template::Template;
+use serde_json::{json, Value};
+
+use crate::batch::{self, BatchResult};
+use crate::fields::print_json;
+use crate::{actor, build_client, client_options, load_session_as, CommentBulkArgs};
+
+/// Post `--body-template`, rendered against each issue, on every issue matching `--filter`,
+/// one at a time with `--interval` between comments, then print a result per issue.
+pub(crate) async fn bulk(args: CommentBulkArgs) -> Result<()> {
+    let path = &args.body_template;
+    let source =
+        fs::read_to_string(path).with_context(|| format!("unable to read {}", path.display()))?;
+    let template = Template::parse(&source)
+        .map_err(|err| LinearError::validation(format!("{}: {err}", path.display())))?;
+
+    let session = load_session_as(&args.profile, actor(args.as_app)).await?;
+    let client = build_client(&session)?;
+    let service = IssueService::new(client.clone());
+    let query = args
+        .filter
+        .query()
+        .select(IssueFields::SUMMARY | IssueFields::TEAM)
+        .first(args.limit as usize);
+    let page = service
+        .query::<Value>(&query)
+        .await
+        .context("GraphQL request failed")?;
+    if page.nodes.is_empty() {
+        if args.json {
+            return print_json(&Vec::<Value>::new());
+        }
+        println!("No issues match '{}'.", args.filter);
+        return Ok(());
+    }
+    if page.has_next_page {
+        eprintln!(
+            "note: more issues match; only the first {} get a comment (--limit)",
+            args.limit
+        );
+    }
+
+    args.confirmation
+        .ensure(
+            Impact::Destructive,
+            &format!(
+                "comment on {} issue(s) matching '{}'",
+                page.nodes.len(),
+                args.filter
+            ),
+        )
+        .await?;
+
+    if client_options().dry_run {
+        for issue in &page.nodes {
+            println!(
+                "{}",
+                json!({ "issue": issue["identifier"], "comment": template.render(issue) })
+            );
+        }
+        return Ok(());
+    }
+
+    let mut results = Vec::new();
+    for (index, issue) in page.nodes.iter().enumerate() {
+        if index > 0 {
+            tokio::time::sleep(args.interval).await;
+        }
+        wait_for_rate_limit(&client).await;
+        let key = issue["identifier"].as_str().unwrap_or_default().to_owned();
+        let body = template.render(issue);
+        let outcome = if body.trim().is_empty() {
+            Ok("skipped: the template rendered nothing".to_owned())
+        } else {
+            service
+                .comment(issue["id"].as_str().unwrap_or_default(), &body)
+                .await
+                .map(|comment| format!("comment {}", comment.id))
+                .context("GraphQL request failed")
+        };
+        results.push(BatchResult { key, outcome });
+    }
+    batch::report(&results, args.json)
+}
+
+/// Sleep until the request budget resets when the last response said none is left.
+async fn wait_for_rate_limit(client: &LinearGraphqlClient) {
+    let Some(limits) = client.rate_limit() else {
+        return;
+    };
+    let (Some(0), Some(reset)) = (limits.requests_remaining, limits.requests_reset) else {
+        return;
+    };
+    if let Ok(wait) = (reset - Utc::now()).to_std() {
+        eprintln!(
+            "note: API rate limit reached; waiting until {}",
+            reset.format("%H:%M:%S UTC")
+        );
+        tokio::time::sleep(wait).await;
+    }
+}
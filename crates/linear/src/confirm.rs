@@ -0,0 +1,109 @@
+use std::io::{self, IsTerminal, Write};
+
+use anyhow::Result;
+use clap::Args;
+use linear_core::config::{Config, ConfigLocator, ConfirmPolicy, Impact};
+use linear_core::error::LinearError;
+use tokio::task;
+
+use crate::client_options;
+
+/// `--yes` for commands that change the workspace and may ask first, per the `confirm` policy.
+#[derive(Args, Debug, Clone)]
+pub(crate) struct ConfirmArgs {
+    /// Apply without asking for confirmation
+    #[arg(long, visible_alias = "force")]
+    pub(crate) yes: bool,
+}
+
+impl ConfirmArgs {
+    /// Ask before applying a change of `impact` when the configured policy calls for it.
+    ///
+    /// `action` completes "refusing to …", e.g. `delete ENG-1`. Without a terminal to ask on the
+    /// command fails instead of guessing, and `--yes` or `--dry-run` skip the question.
+    pub(crate) async fn ensure(&self, impact: Impact, action: &str) -> Result<()> {
+        if self.yes || client_options().dry_run || !policy()?.asks(impact) {
+            return Ok(());
+        }
+        if !interactive() {
+            return Err(LinearError::validation(format!(
+                "refusing to {action} without confirmation; re-run with --yes if you are sure"
+            ))
+            .into());
+        }
+        require(capitalize(action) + "?").await
+    }
+}
+
+/// Prompt `question [y/N]` and fail with a validation error unless the answer is yes.
+pub(crate) async fn require(question: String) -> Result<()> {
+    if ask(question).await? {
+        Ok(())
+    } else {
+        Err(LinearError::validation("not confirmed; nothing was changed").into())
+    }
+}
+
+/// Ask a yes/no question on stderr, so stdout stays parseable; anything but `y`/`yes`, or no
+/// terminal to ask on, counts as no.
+pub(crate) async fn ask(question: String) -> Result<bool> {
+    if !interactive() {
+        return Ok(false);
+    }
+    task::spawn_blocking(move || {
+        let mut stderr = io::stderr();
+        write!(stderr, "{question} [y/N] ")?;
+        stderr.flush()?;
+        let mut input = String::new();
+        io::stdin().read_line(&mut input)?;
+        Ok(matches!(input.trim(), "y" | "Y" | "yes"))
+    })
+    .await?
+}
+
+/// Ask which of `options` was meant, on stderr so stdout stays parseable. `None` without a
+/// terminal to ask on, or when the answer is not one of the numbers offered.
+pub(crate) async fn choose(question: String, options: Vec<String>) -> Result<Option<usize>> {
+    if !interactive() {
+        return Ok(None);
+    }
+    task::spawn_blocking(move || {
+        let mut stderr = io::stderr();
+        writeln!(stderr, "{question}")?;
+        for (index, option) in options.iter().enumerate() {
+            writeln!(stderr, "  {}) {option}", index + 1)?;
+        }
+        write!(stderr, "Which one? [1-{}] ", options.len())?;
+        stderr.flush()?;
+        let mut input = String::new();
+        io::stdin().read_line(&mut input)?;
+        Ok(input
+            .trim()
+            .parse::<usize>()
+            .ok()
+            .filter(|choice| (1..=options.len()).contains(choice))
+            .map(|choice| choice - 1))
+    })
+    .await?
+}
+
+/// Whether someone is there to answer: questions go to stderr and answers come from stdin.
+fn interactive() -> bool {
+    io::stdin().is_terminal() && io::stderr().is_terminal()
+}
+
+/// The `confirm` setting, or its default when no config directory is available.
+fn policy() -> Result<ConfirmPolicy> {
+    let Ok(locator) = ConfigLocator::new() else {
+        return Ok(ConfirmPolicy::default());
+    };
+    Ok(Config::load(&locator)?.confirm)
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,744 @@
+use std::env;
+
+use anyhow::Result;
+use linear_core::auth::AuthSession;
+use linear_core::config::ConfigLocator;
+use linear_core::daemon::IssuesChanged;
+use linear_core::error::LinearError;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use tokio::sync::mpsc;
+
+use crate::fields::print_json;
+use crate::{client_options, DaemonRunArgs, DaemonStatusArgs, DaemonStopArgs};
+
+/// Setting this sends every command straight to the API even when a daemon is running.
+const NO_DAEMON_ENV: &str = "LINEAR_NO_DAEMON";
+
+/// Ask the daemon of `profile` to run `method`. `None` means no daemon could answer and the
+/// caller should go to the API itself: none is running, it is disabled, or the command was
+/// started with flags a daemon would not honour, such as `--replay` or a token in the
+/// environment.
+pub(crate) async fn call<T: DeserializeOwned>(
+    profile: &str,
+    method: &str,
+    params: Value,
+) -> Option<Result<T>> {
+    if bypassed() {
+        return None;
+    }
+    let socket = ConfigLocator::new().ok()?.daemon_socket(profile);
+    unix::call(&socket, method, params).await
+}
+
+/// Subscribe to the issue changes the daemon of `profile` sees. They arrive on the returned
+/// channel until the daemon goes away and it closes; `None` when no daemon answered, for the
+/// same reasons as [`call`].
+pub(crate) async fn subscribe(profile: &str) -> Option<mpsc::UnboundedReceiver<IssuesChanged>> {
+    if bypassed() {
+        return None;
+    }
+    let socket = ConfigLocator::new().ok()?.daemon_socket(profile);
+    unix::subscribe(&socket).await
+}
+
+/// Whether this run must not use a daemon.
+fn bypassed() -> bool {
+    let options = client_options();
+    env::var_os(NO_DAEMON_ENV).is_some()
+        || options.dry_run
+        || options.record.is_some()
+        || options.replay.is_some()
+        || AuthSession::from_env().is_some()
+}
+
+/// `linear daemon run`: serve JSON-RPC on the profile's socket until stopped.
+pub(crate) async fn run(args: DaemonRunArgs) -> Result<()> {
+    crate::resolve::disable_prompts();
+    unix::run(args).await
+}
+
+/// `linear daemon status`: the running daemon's pid, uptime, and cache size.
+pub(crate) async fn status(args: DaemonStatusArgs) -> Result<()> {
+    let Some(ping) = ping(&args.profile).await? else {
+        return Err(not_running(&args.profile));
+    };
+    if args.json {
+        return print_json(&ping);
+    }
+    let field = |name: &str| ping.get(name).cloned().unwrap_or(Value::Null);
+    println!("Profile : {}", args.profile);
+    println!("PID     : {}", field("pid"));
+    println!(
+        "Uptime  : {}",
+        linear_core::config::format_duration(std::time::Duration::from_secs(
+            field("uptimeSecs").as_u64().unwrap_or_default()
+        ))
+    );
+    println!("Cached  : {} response(s)", field("cached"));
+    println!("Socket  : {}", field("socket").as_str().unwrap_or_default());
+    Ok(())
+}
+
+/// `linear daemon stop`: ask the running daemon to exit.
+pub(crate) async fn stop(args: DaemonStopArgs) -> Result<()> {
+    let socket = ConfigLocator::new()?.daemon_socket(&args.profile);
+    match unix::call::<bool>(&socket, "shutdown", Value::Null).await {
+        Some(result) => {
+            result?;
+            eprintln!("Stopped the daemon for profile '{}'.", args.profile);
+            Ok(())
+        }
+        None => Err(not_running(&args.profile)),
+    }
+}
+
+/// The daemon's `ping` result, or `None` when it is not running.
+async fn ping(profile: &str) -> Result<Option<Value>> {
+    let socket = ConfigLocator::new()?.daemon_socket(profile);
+    unix::call(&socket, "ping", Value::Null).await.transpose()
+}
+
+fn not_running(profile: &str) -> anyhow::Error {
+    LinearError::not_found(format!(
+        "no daemon is running for profile '{profile}'; start one with `linear daemon run`"
+    ))
+    .into()
+}
+
+#[cfg(not(unix))]
+mod unix {
+    use std::path::Path;
+
+    use super::*;
+
+    pub(super) async fn call<T: DeserializeOwned>(
+        _socket: &Path,
+        _method: &str,
+        _params: Value,
+    ) -> Option<Result<T>> {
+        None
+    }
+
+    pub(super) async fn subscribe(
+        _socket: &Path,
+    ) -> Option<mpsc::UnboundedReceiver<IssuesChanged>> {
+        None
+    }
+
+    pub(super) async fn run(_args: DaemonRunArgs) -> Result<()> {
+        Err(LinearError::validation("linear daemon needs unix domain sockets").into())
+    }
+}
+
+#[cfg(unix)]
+mod unix {
+    use std::collections::HashMap;
+    use std::fs;
+    use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+    use std::path::{Path, PathBuf};
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+
+    use anyhow::Context;
+    use chrono::{DateTime, Utc};
+    use linear_core::daemon::{
+        ChangedIssue, DaemonClient, DaemonError, RpcError, RpcRequest, RpcResponse, INVALID_PARAMS,
+        INVALID_REQUEST, ISSUES_CHANGED, JSONRPC_VERSION, METHOD_NOT_FOUND, PARSE_ERROR,
+    };
+    use linear_core::error::ErrorKind;
+    use linear_core::graphql::{IssueDetail, IssueUpdateInput, LinearGraphqlClient};
+    use linear_core::priority::Priority;
+    use linear_core::services::issues::{IssueQueryOptions, IssueService, IssueSort};
+    use linear_core::services::search::{SearchKind, SearchService};
+    use linear_core::unfurl::IssueRef;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::unix::OwnedWriteHalf;
+    use tokio::net::{UnixListener, UnixStream};
+    use tokio::sync::{broadcast, Mutex, Notify, RwLock};
+
+    use super::*;
+    use crate::{build_client, issue_query_options, load_session, remind, IssueFilterArgs};
+
+    /// How often due `linear remind` reminders are checked.
+    const REMINDER_INTERVAL: Duration = Duration::from_secs(60);
+    /// Most recently updated issues looked at per `--watch-interval` check.
+    const WATCH_LIMIT: usize = 50;
+
+    pub(super) async fn call<T: DeserializeOwned>(
+        socket: &Path,
+        method: &str,
+        params: Value,
+    ) -> Option<Result<T>> {
+        let mut client = DaemonClient::connect(socket).await.ok()?;
+        match client.call(method, params).await {
+            Ok(result) => Some(Ok(result)),
+            Err(DaemonError::Rpc(error)) => {
+                Some(Err(LinearError::new(error.kind(), error.message).into()))
+            }
+            // A daemon that went away mid-call, or speaks another version: go direct.
+            Err(_) => None,
+        }
+    }
+
+    pub(super) async fn subscribe(socket: &Path) -> Option<mpsc::UnboundedReceiver<IssuesChanged>> {
+        let mut client = DaemonClient::connect(socket).await.ok()?;
+        // Daemons from before `subscribe` refuse it, leaving the caller to poll.
+        client.call::<bool>("subscribe", Value::Null).await.ok()?;
+        let (sender, receiver) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            while let Ok(Some(notification)) = client.notification().await {
+                if notification.method != ISSUES_CHANGED {
+                    continue;
+                }
+                let Ok(changed) = serde_json::from_value(notification.params) else {
+                    continue;
+                };
+                if sender.send(changed).is_err() {
+                    break;
+                }
+            }
+        });
+        Some(receiver)
+    }
+
+    pub(super) async fn run(args: DaemonRunArgs) -> Result<()> {
+        let session = load_session(&args.profile).await?;
+        let client = build_client(&session)?;
+        let socket = ConfigLocator::new()?.daemon_socket(&args.profile);
+        let listener = bind(&socket, &args.profile).await?;
+        let _cleanup = RemoveOnDrop(socket.clone());
+
+        let server = Arc::new(Server {
+            profile: args.profile,
+            socket,
+            started: Instant::now(),
+            ttl: args.cache_ttl,
+            client: RwLock::new(client),
+            cache: Mutex::new(HashMap::new()),
+            last_request: Mutex::new(Instant::now()),
+            shutdown: Notify::new(),
+            events: broadcast::channel(64).0,
+        });
+        eprintln!(
+            "Listening on {} for profile '{}'; stop with `linear daemon stop` or Ctrl-C.",
+            server.socket.display(),
+            server.profile
+        );
+
+        let mut reminders = tokio::time::interval(REMINDER_INTERVAL);
+        let mut watch =
+            (!args.watch_interval.is_zero()).then(|| tokio::time::interval(args.watch_interval));
+        let mut watermark = Utc::now();
+        loop {
+            let idle = async {
+                match args.idle_timeout {
+                    Some(timeout) => {
+                        let last = *server.last_request.lock().await;
+                        tokio::time::sleep_until((last + timeout).into()).await;
+                        server.last_request.lock().await.elapsed() >= timeout
+                    }
+                    None => std::future::pending().await,
+                }
+            };
+            tokio::select! {
+                accepted = listener.accept() => {
+                    let (stream, _) = accepted.context("daemon socket failed")?;
+                    tokio::spawn(serve(server.clone(), stream));
+                }
+                _ = server.shutdown.notified() => break,
+                _ = reminders.tick() => {
+                    if let Err(err) = remind::deliver_due(&server.profile).await {
+                        eprintln!("warning: failed to deliver reminders: {:#}", err);
+                    }
+                }
+                _ = async {
+                    match &mut watch {
+                        Some(watch) => {
+                            watch.tick().await;
+                        }
+                        None => std::future::pending().await,
+                    }
+                } => server.watch(&mut watermark).await,
+                expired = idle => if expired {
+                    eprintln!("No requests for a while; exiting.");
+                    break;
+                },
+            }
+        }
+        Ok(())
+    }
+
+    /// Listen on `socket`, replacing a socket file left behind by a daemon that did not exit
+    /// cleanly, but never one a live daemon still answers on.
+    async fn bind(socket: &Path, profile: &str) -> Result<UnixListener> {
+        if socket.exists() {
+            if UnixStream::connect(socket).await.is_ok() {
+                return Err(LinearError::validation(format!(
+                    "a daemon is already running for profile '{profile}'"
+                ))
+                .into());
+            }
+            fs::remove_file(socket)
+                .with_context(|| format!("failed to remove stale {}", socket.display()))?;
+        }
+        // The socket hands out the profile's session, so only its owner may connect. It is
+        // bound inside an owner-only directory and narrowed to 0600 there, so it is never
+        // reachable at the umask's permissions, then moved into place.
+        let staging = socket.with_file_name(format!(".daemon-{profile}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&staging);
+        fs::DirBuilder::new()
+            .mode(0o700)
+            .create(&staging)
+            .with_context(|| format!("failed to create {}", staging.display()))?;
+        let bound = staging.join("daemon.sock");
+        let listener = UnixListener::bind(&bound)
+            .and_then(|listener| {
+                fs::set_permissions(&bound, fs::Permissions::from_mode(0o600))?;
+                fs::rename(&bound, socket)?;
+                Ok(listener)
+            })
+            .with_context(|| format!("failed to listen on {}", socket.display()));
+        let _ = fs::remove_dir_all(&staging);
+        listener
+    }
+
+    struct RemoveOnDrop(PathBuf);
+
+    impl Drop for RemoveOnDrop {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.0);
+        }
+    }
+
+    struct Server {
+        profile: String,
+        socket: PathBuf,
+        started: Instant,
+        ttl: Duration,
+        client: RwLock<LinearGraphqlClient>,
+        /// Read results by `method params`, with when they were fetched.
+        cache: Mutex<HashMap<String, (Instant, Value)>>,
+        last_request: Mutex<Instant>,
+        shutdown: Notify,
+        /// Issue changes for connections that called `subscribe`.
+        events: broadcast::Sender<IssuesChanged>,
+    }
+
+    /// Answer each request line on `stream` until the client hangs up, and once it has
+    /// subscribed, send it every change as an `issuesChanged` notification.
+    async fn serve(server: Arc<Server>, stream: UnixStream) -> std::io::Result<()> {
+        let (reader, mut writer) = stream.into_split();
+        let mut lines = BufReader::new(reader).lines();
+        let mut events = None;
+        loop {
+            let line = tokio::select! {
+                line = lines.next_line() => match line? {
+                    Some(line) => line,
+                    None => break,
+                },
+                changed = next_change(&mut events) => {
+                    let params = serde_json::to_value(changed)?;
+                    send(&mut writer, &RpcRequest::notification(ISSUES_CHANGED, params)).await?;
+                    continue;
+                }
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+            *server.last_request.lock().await = Instant::now();
+            if let Some(response) = server.handle(&line, &mut events).await {
+                send(&mut writer, &response).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The next change for a subscribed connection, skipping any it fell too far behind to
+    /// receive; never ready before `subscribe`.
+    async fn next_change(events: &mut Option<broadcast::Receiver<IssuesChanged>>) -> IssuesChanged {
+        if let Some(receiver) = events {
+            loop {
+                match receiver.recv().await {
+                    Ok(changed) => return changed,
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        }
+        std::future::pending().await
+    }
+
+    async fn send<T: Serialize>(writer: &mut OwnedWriteHalf, message: &T) -> std::io::Result<()> {
+        let mut out = serde_json::to_string(message)?;
+        out.push('\n');
+        writer.write_all(out.as_bytes()).await
+    }
+
+    /// A request's method and decoded params.
+    #[derive(Debug, Clone)]
+    enum Method {
+        Ping,
+        Shutdown,
+        List(ListParams),
+        View(ViewParams),
+        Search(SearchParams),
+        Update(UpdateParams),
+        Subscribe,
+    }
+
+    #[derive(Debug, Clone, Deserialize)]
+    struct ListParams {
+        #[serde(default)]
+        filter: IssueFilterArgs,
+        #[serde(default = "default_list_limit")]
+        limit: usize,
+        #[serde(default)]
+        sort: Option<IssueSort>,
+    }
+
+    fn default_list_limit() -> usize {
+        20
+    }
+
+    #[derive(Debug, Clone, Deserialize)]
+    struct ViewParams {
+        key: String,
+    }
+
+    #[derive(Debug, Clone, Deserialize)]
+    struct SearchParams {
+        query: String,
+        #[serde(default)]
+        types: Vec<SearchKind>,
+        #[serde(default = "default_search_limit")]
+        limit: usize,
+    }
+
+    fn default_search_limit() -> usize {
+        10
+    }
+
+    #[derive(Debug, Clone, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct UpdateParams {
+        key: String,
+        title: Option<String>,
+        description: Option<String>,
+        /// State name, resolved within the issue's team.
+        state: Option<String>,
+        state_id: Option<String>,
+        assignee_id: Option<String>,
+        /// `urgent`, `high`, `medium`, `low`, `none`, or 0-4.
+        priority: Option<String>,
+        label_ids: Option<Vec<String>>,
+        project_id: Option<String>,
+    }
+
+    impl Method {
+        fn parse(method: &str, params: Value) -> Result<Self, RpcError> {
+            fn decode<T: DeserializeOwned>(params: Value) -> Result<T, RpcError> {
+                let params = if params.is_null() { json!({}) } else { params };
+                serde_json::from_value(params)
+                    .map_err(|err| RpcError::new(INVALID_PARAMS, err.to_string()))
+            }
+            Ok(match method {
+                "ping" => Method::Ping,
+                "shutdown" => Method::Shutdown,
+                "list" => Method::List(decode(params)?),
+                "view" => Method::View(decode(params)?),
+                "search" => Method::Search(decode(params)?),
+                "update" => Method::Update(decode(params)?),
+                "subscribe" => Method::Subscribe,
+                other => {
+                    return Err(RpcError::new(
+                        METHOD_NOT_FOUND,
+                        format!("unknown method '{other}'; expected ping, list, view, search, update, subscribe, or shutdown"),
+                    ))
+                }
+            })
+        }
+    }
+
+    impl Server {
+        /// The response to one request line; `None` for notifications, which get none. A
+        /// `subscribe` sets `events`.
+        async fn handle(
+            &self,
+            line: &str,
+            events: &mut Option<broadcast::Receiver<IssuesChanged>>,
+        ) -> Option<RpcResponse> {
+            let value: Value = match serde_json::from_str(line) {
+                Ok(value) => value,
+                Err(err) => {
+                    return Some(RpcResponse::failure(
+                        Value::Null,
+                        RpcError::new(PARSE_ERROR, err.to_string()),
+                    ))
+                }
+            };
+            let request: RpcRequest = match serde_json::from_value(value) {
+                Ok(request) => request,
+                Err(err) => {
+                    return Some(RpcResponse::failure(
+                        Value::Null,
+                        RpcError::new(INVALID_REQUEST, err.to_string()),
+                    ))
+                }
+            };
+            let result = if request.jsonrpc == JSONRPC_VERSION {
+                self.dispatch(&request.method, request.params, events).await
+            } else {
+                Err(RpcError::new(
+                    INVALID_REQUEST,
+                    format!("expected jsonrpc \"{JSONRPC_VERSION}\""),
+                ))
+            };
+            if request.id.is_null() {
+                return None;
+            }
+            Some(match result {
+                Ok(result) => RpcResponse::success(request.id, result),
+                Err(error) => RpcResponse::failure(request.id, error),
+            })
+        }
+
+        async fn dispatch(
+            &self,
+            method: &str,
+            params: Value,
+            events: &mut Option<broadcast::Receiver<IssuesChanged>>,
+        ) -> Result<Value, RpcError> {
+            let key = format!("{method} {params}");
+            let method = Method::parse(method, params)?;
+            match method {
+                Method::Ping => return Ok(self.ping().await),
+                Method::Shutdown => {
+                    self.shutdown.notify_one();
+                    return Ok(json!(true));
+                }
+                Method::Subscribe => {
+                    *events = Some(self.events.subscribe());
+                    return Ok(json!(true));
+                }
+                Method::Update(_) => {
+                    let result = self.invoke(&method).await;
+                    // Any cached list or view may now show the issue as it was.
+                    self.cache.lock().await.clear();
+                    let changed = result.as_ref().ok().and_then(|value| {
+                        serde_json::from_value::<ChangedIssue>(value.clone()).ok()
+                    });
+                    if let Some(issue) = changed {
+                        self.publish(vec![issue]).await;
+                    }
+                    return result;
+                }
+                Method::List(_) | Method::View(_) | Method::Search(_) => {}
+            }
+
+            if let Some((fetched, value)) = self.cache.lock().await.get(&key) {
+                if fetched.elapsed() < self.ttl {
+                    return Ok(value.clone());
+                }
+            }
+            let value = self.invoke(&method).await?;
+            if !self.ttl.is_zero() {
+                let mut cache = self.cache.lock().await;
+                cache.retain(|_, (fetched, _)| fetched.elapsed() < self.ttl);
+                cache.insert(key, (Instant::now(), value.clone()));
+            }
+            Ok(value)
+        }
+
+        /// Run `method` against the API, signing in again once if the session was rejected,
+        /// e.g. after `linear auth login` replaced the stored token.
+        async fn invoke(&self, method: &Method) -> Result<Value, RpcError> {
+            let client = self.client.read().await.clone();
+            let result = match execute(client, method).await {
+                Err(err) if ErrorKind::classify(&err) == ErrorKind::Auth => {
+                    match self.reconnect().await {
+                        Ok(client) => execute(client, method).await,
+                        Err(_) => Err(err),
+                    }
+                }
+                result => result,
+            };
+            result.map_err(|err| RpcError::failed(ErrorKind::classify(&err), format!("{err:#}")))
+        }
+
+        /// Tell subscribers about issues updated after `watermark`, then move it to the newest
+        /// update seen. With nobody subscribed it only moves to now, so a later subscriber is
+        /// not sent what happened before it connected.
+        async fn watch(&self, watermark: &mut DateTime<Utc>) {
+            if self.events.receiver_count() == 0 {
+                *watermark = Utc::now();
+                return;
+            }
+            // A subscribed client keeps the daemon from exiting on `--idle-timeout`.
+            *self.last_request.lock().await = Instant::now();
+            let client = self.client.read().await.clone();
+            let options = IssueQueryOptions {
+                limit: WATCH_LIMIT,
+                updated_since: Some(*watermark),
+                sort: Some(IssueSort::UpdatedDesc),
+                ..Default::default()
+            };
+            match IssueService::new(client).list(options).await {
+                Ok(result) => {
+                    let issues: Vec<ChangedIssue> = result
+                        .issues
+                        .into_iter()
+                        .filter(|issue| issue.updated_at > *watermark)
+                        .map(|issue| ChangedIssue {
+                            id: issue.id,
+                            identifier: issue.identifier,
+                            updated_at: issue.updated_at,
+                        })
+                        .collect();
+                    if let Some(newest) = issues.iter().map(|issue| issue.updated_at).max() {
+                        *watermark = newest;
+                    }
+                    self.publish(issues).await;
+                }
+                Err(err) => eprintln!("warning: failed to check for changed issues: {err:#}"),
+            }
+        }
+
+        async fn publish(&self, issues: Vec<ChangedIssue>) {
+            if issues.is_empty() {
+                return;
+            }
+            self.cache.lock().await.clear();
+            // Fails only when nobody is subscribed.
+            let _ = self.events.send(IssuesChanged { issues });
+        }
+
+        async fn reconnect(&self) -> Result<LinearGraphqlClient> {
+            let client = build_client(&load_session(&self.profile).await?)?;
+            *self.client.write().await = client.clone();
+            Ok(client)
+        }
+
+        async fn ping(&self) -> Value {
+            json!({
+                "profile": self.profile,
+                "pid": std::process::id(),
+                "version": env!("CARGO_PKG_VERSION"),
+                "uptimeSecs": self.started.elapsed().as_secs(),
+                "cached": self.cache.lock().await.len(),
+                "socket": self.socket.display().to_string(),
+            })
+        }
+    }
+
+    async fn execute(client: LinearGraphqlClient, method: &Method) -> Result<Value> {
+        let value = match method {
+            Method::List(params) => {
+                let service = IssueService::new(client);
+                let mut options =
+                    issue_query_options(&service, &params.filter, params.limit).await?;
+                options.sort = params.sort;
+                let issues = service
+                    .list(options)
+                    .await
+                    .context("GraphQL request failed")?;
+                serde_json::to_value(issues)?
+            }
+            Method::View(params) => {
+                let issue = IssueService::new(client)
+                    .get_by_key(&issue_key(&params.key)?)
+                    .await
+                    .context("GraphQL request failed")?;
+                serde_json::to_value(issue)?
+            }
+            Method::Search(params) => {
+                let kinds = if params.types.is_empty() {
+                    SearchKind::ALL.to_vec()
+                } else {
+                    params.types.clone()
+                };
+                let results = SearchService::new(client)
+                    .search(&params.query, &kinds, params.limit)
+                    .await
+                    .context("GraphQL request failed")?;
+                serde_json::to_value(results)?
+            }
+            Method::Update(params) => serde_json::to_value(update(client, params).await?)?,
+            Method::Ping | Method::Shutdown | Method::Subscribe => {
+                unreachable!("answered by the server")
+            }
+        };
+        Ok(value)
+    }
+
+    /// A key or Linear issue URL. Bare numbers are refused: the default team depends on the
+    /// caller's checkout, which the daemon does not know.
+    fn issue_key(input: &str) -> Result<String> {
+        match IssueRef::parse(input) {
+            Some(IssueRef::Key(key)) => Ok(key),
+            _ => Err(LinearError::validation(format!(
+                "'{input}' is not an issue key or Linear issue URL"
+            ))
+            .into()),
+        }
+    }
+
+    async fn update(client: LinearGraphqlClient, params: &UpdateParams) -> Result<IssueDetail> {
+        let priority = params
+            .priority
+            .as_deref()
+            .map(|raw| raw.parse::<Priority>().map_err(LinearError::validation))
+            .transpose()?;
+        let service = IssueService::new(client);
+        let issue = service
+            .get_by_key(&issue_key(&params.key)?)
+            .await
+            .with_context(|| format!("unable to load issue {}", params.key))?;
+        let mut input = IssueUpdateInput {
+            title: params.title.clone(),
+            description: params.description.clone(),
+            assignee_id: params.assignee_id.clone(),
+            state_id: params.state_id.clone(),
+            label_ids: params.label_ids.clone(),
+            priority: priority.map(Priority::value),
+            project_id: params.project_id.clone(),
+            ..Default::default()
+        };
+        if let (None, Some(state)) = (&input.state_id, &params.state) {
+            if issue.team.is_none() {
+                return Err(LinearError::validation(format!(
+                    "issue '{}' has no team; state name cannot be resolved",
+                    issue.identifier
+                ))
+                .into());
+            }
+            input.state_id = Some(service.resolve_transition(&issue, state).await??.id);
+        }
+        service
+            .update(&issue.id, input)
+            .await
+            .context("GraphQL request failed")
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[tokio::test]
+        async fn binds_an_owner_only_socket_in_place() {
+            let dir = tempfile::tempdir().unwrap();
+            let socket = dir.path().join("daemon-default.sock");
+            let _listener = bind(&socket, "default").await.unwrap();
+            let mode = fs::metadata(&socket).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+            assert!(UnixStream::connect(&socket).await.is_ok());
+            let entries: Vec<_> = fs::read_dir(dir.path()).unwrap().collect();
+            assert_eq!(entries.len(), 1, "staging directory left behind");
+
+            let err = bind(&socket, "default").await.unwrap_err();
+            assert!(err.to_string().contains("already running"));
+        }
+    }
+}
@@ -0,0 +1,411 @@
+use std::env;
+use std::io::IsTerminal;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Result};
+use chrono::{Duration as ChronoDuration, Utc};
+use linear_core::auth::{configured_store, Actor, ACCESS_TOKEN_ENV, API_KEY_ENV};
+use linear_core::config::{Config, ConfigLocator};
+use linear_core::graphql::schema::Schema;
+use linear_core::graphql::LinearGraphqlClient;
+use serde_json::json;
+
+use crate::fields::print_json;
+use crate::{build_client, load_session, DoctorArgs};
+
+const SLOW_LATENCY: Duration = Duration::from_secs(2);
+const MIN_TERMINAL_SIZE: (u16, u16) = (80, 24);
+/// The schema this build's queries were checked against by `cargo xtask verify-schema`.
+const VENDORED_SCHEMA: &str = include_str!("../../../schema/linear.json");
+/// Drifted schema items listed before the rest are only counted.
+const SCHEMA_DRIFT_SHOWN: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CheckStatus {
+    Ok,
+    Warn,
+    Fail,
+    Skip,
+}
+
+impl CheckStatus {
+    fn label(self) -> &'static str {
+        match self {
+            CheckStatus::Ok => "ok",
+            CheckStatus::Warn => "warn",
+            CheckStatus::Fail => "fail",
+            CheckStatus::Skip => "skip",
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Check {
+    name: &'static str,
+    status: CheckStatus,
+    detail: String,
+    hint: Option<String>,
+}
+
+impl Check {
+    fn new(name: &'static str, status: CheckStatus, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status,
+            detail: detail.into(),
+            hint: None,
+        }
+    }
+
+    fn hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+}
+
+/// Run every diagnostic and report the results; fails when any check fails.
+pub(crate) async fn run(args: DoctorArgs) -> Result<()> {
+    let mut checks = Vec::new();
+
+    let locator = match ConfigLocator::new() {
+        Ok(locator) => Some(locator),
+        Err(err) => {
+            checks.push(
+                Check::new("config", CheckStatus::Fail, err.to_string())
+                    .hint("ensure your home directory is set and writable"),
+            );
+            None
+        }
+    };
+
+    if let Some(locator) = &locator {
+        checks.push(check_config(locator));
+        checks.push(check_credentials(locator, &args.profile));
+    }
+
+    let client = match load_session(&args.profile).await {
+        Ok(session) => match build_client(&session) {
+            Ok(client) => Some(client),
+            Err(err) => {
+                checks.push(
+                    Check::new("client", CheckStatus::Fail, format!("{err:#}"))
+                        .hint("check the --record/--replay path and --timeout"),
+                );
+                None
+            }
+        },
+        Err(_) => None,
+    };
+    match &client {
+        Some(client) => {
+            checks.push(check_api(client).await);
+            checks.push(check_rate_limit(client));
+            checks.push(check_schema(client).await);
+        }
+        None => {
+            checks.push(Check::new(
+                "api",
+                CheckStatus::Skip,
+                "no usable credentials; connectivity not tested",
+            ));
+            checks.push(Check::new(
+                "rate-limit",
+                CheckStatus::Skip,
+                "no API response to inspect",
+            ));
+            checks.push(Check::new(
+                "schema",
+                CheckStatus::Skip,
+                "no usable credentials; live schema not compared",
+            ));
+        }
+    }
+
+    checks.push(check_terminal());
+
+    if args.json {
+        let payload: Vec<_> = checks
+            .iter()
+            .map(|check| {
+                json!({
+                    "name": check.name,
+                    "status": check.status.label(),
+                    "detail": check.detail,
+                    "hint": check.hint,
+                })
+            })
+            .collect();
+        print_json(&payload)?;
+    } else {
+        render_checks(&checks);
+    }
+
+    let failures = checks
+        .iter()
+        .filter(|check| check.status == CheckStatus::Fail)
+        .count();
+    if failures > 0 {
+        return Err(anyhow!("{} diagnostic check(s) failed", failures));
+    }
+    Ok(())
+}
+
+fn check_config(locator: &ConfigLocator) -> Check {
+    let path = locator.config_file();
+    if !path.exists() {
+        return Check::new(
+            "config",
+            CheckStatus::Ok,
+            format!("{} not present; using defaults", path.display()),
+        );
+    }
+    match Config::load(locator) {
+        Ok(_) => Check::new("config", CheckStatus::Ok, path.display().to_string()),
+        Err(err) => Check::new("config", CheckStatus::Fail, err.to_string())
+            .hint(format!("fix or remove {}", path.display())),
+    }
+}
+
+fn check_credentials(locator: &ConfigLocator, profile: &str) -> Check {
+    if let Some(var) = [API_KEY_ENV, ACCESS_TOKEN_ENV]
+        .into_iter()
+        .find(|var| env::var(var).is_ok_and(|value| !value.trim().is_empty()))
+    {
+        return Check::new(
+            "credentials",
+            CheckStatus::Ok,
+            format!("using {var} from the environment; stored credentials are ignored"),
+        );
+    }
+    let login_hint = format!("run `linear auth login --profile {}`", profile);
+    let others = configured_store(locator.clone(), Actor::User)
+        .and_then(|store| store.profiles())
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|name| name != profile)
+        .collect::<Vec<_>>();
+    let suffix = if others.is_empty() {
+        String::new()
+    } else {
+        format!(" (other profiles: {})", others.join(", "))
+    };
+
+    let session = match configured_store(locator.clone(), Actor::User)
+        .and_then(|store| store.load(profile))
+    {
+        Ok(Some(session)) => session,
+        Ok(None) => {
+            return Check::new(
+                "credentials",
+                CheckStatus::Fail,
+                format!("no credentials for profile '{}'{}", profile, suffix),
+            )
+            .hint(login_hint)
+        }
+        Err(err) => {
+            return Check::new("credentials", CheckStatus::Fail, err.to_string()).hint(login_hint)
+        }
+    };
+
+    let Some(expires_at) = session.expires_at else {
+        return Check::new(
+            "credentials",
+            CheckStatus::Ok,
+            format!("profile '{}' uses a non-expiring token{}", profile, suffix),
+        );
+    };
+    let expiry = expires_at.format("%Y-%m-%d %H:%M UTC");
+    if session.is_expired() {
+        if session.refresh_token.is_some() {
+            Check::new(
+                "credentials",
+                CheckStatus::Warn,
+                format!("token expired {}; it will be refreshed on next use", expiry),
+            )
+        } else {
+            Check::new(
+                "credentials",
+                CheckStatus::Fail,
+                format!("token expired {} and cannot be refreshed", expiry),
+            )
+            .hint(login_hint)
+        }
+    } else if session.will_expire_within(ChronoDuration::hours(24))
+        && session.refresh_token.is_none()
+    {
+        Check::new(
+            "credentials",
+            CheckStatus::Warn,
+            format!("token expires {} and cannot be refreshed", expiry),
+        )
+        .hint(login_hint)
+    } else {
+        let remaining = expires_at - Utc::now();
+        Check::new(
+            "credentials",
+            CheckStatus::Ok,
+            format!(
+                "profile '{}' valid until {} ({}h left){}",
+                profile,
+                expiry,
+                remaining.num_hours(),
+                suffix
+            ),
+        )
+    }
+}
+
+async fn check_api(client: &LinearGraphqlClient) -> Check {
+    let started = Instant::now();
+    match client.viewer().await {
+        Ok(viewer) => {
+            let elapsed = started.elapsed();
+            let who = viewer
+                .display_name
+                .or(viewer.name)
+                .unwrap_or_else(|| viewer.id.clone());
+            let detail = format!("reachable as {} in {} ms", who, elapsed.as_millis());
+            if elapsed > SLOW_LATENCY {
+                Check::new("api", CheckStatus::Warn, detail)
+                    .hint("requests are slow; check your network or proxy settings")
+            } else {
+                Check::new("api", CheckStatus::Ok, detail)
+            }
+        }
+        Err(err) => Check::new("api", CheckStatus::Fail, err.to_string())
+            .hint("check network connectivity and that your token has not been revoked"),
+    }
+}
+
+/// Compare the live schema with the vendored one, reporting what the queries rely on that the
+/// API no longer serves.
+async fn check_schema(client: &LinearGraphqlClient) -> Check {
+    let vendored = match serde_json::from_str(VENDORED_SCHEMA).map(Schema::from_introspection) {
+        Ok(Ok(schema)) => schema,
+        Ok(Err(err)) | Err(err) => {
+            return Check::new(
+                "schema",
+                CheckStatus::Fail,
+                format!("vendored schema: {err}"),
+            )
+        }
+    };
+    let live = match client.schema().await {
+        Ok(schema) => schema,
+        Err(err) => {
+            return Check::new("schema", CheckStatus::Warn, err.to_string())
+                .hint("the API refused introspection; drift could not be checked")
+        }
+    };
+    let missing = vendored.missing_from(&live);
+    if missing.is_empty() {
+        return Check::new(
+            "schema",
+            CheckStatus::Ok,
+            format!(
+                "live API serves all {} types the queries use",
+                vendored.types.len()
+            ),
+        );
+    }
+    let mut detail = format!(
+        "{} item(s) changed or missing: {}",
+        missing.len(),
+        missing[..missing.len().min(SCHEMA_DRIFT_SHOWN)].join(", ")
+    );
+    if missing.len() > SCHEMA_DRIFT_SHOWN {
+        detail.push_str(&format!(", +{} more", missing.len() - SCHEMA_DRIFT_SHOWN));
+    }
+    Check::new("schema", CheckStatus::Warn, detail)
+        .hint("commands using these may fail; update linear or report the drift")
+}
+
+fn check_rate_limit(client: &LinearGraphqlClient) -> Check {
+    let Some(limits) = client.rate_limit() else {
+        return Check::new(
+            "rate-limit",
+            CheckStatus::Skip,
+            "response carried no rate-limit headers",
+        );
+    };
+    let (Some(limit), Some(remaining)) = (limits.requests_limit, limits.requests_remaining) else {
+        return Check::new(
+            "rate-limit",
+            CheckStatus::Skip,
+            "request limits not reported",
+        );
+    };
+    let mut detail = format!("{}/{} requests remaining", remaining, limit);
+    if let Some(reset) = limits.requests_reset {
+        detail.push_str(&format!(", resets {}", reset.format("%H:%M:%S UTC")));
+    }
+    if remaining.saturating_mul(10) < limit {
+        Check::new("rate-limit", CheckStatus::Warn, detail)
+            .hint("less than 10% of the hourly budget left; slow down bulk commands")
+    } else {
+        Check::new("rate-limit", CheckStatus::Ok, detail)
+    }
+}
+
+fn check_terminal() -> Check {
+    if !std::io::stdout().is_terminal() {
+        return Check::new(
+            "terminal",
+            CheckStatus::Warn,
+            "stdout is not a terminal; the TUI will not start",
+        );
+    }
+    let term = env::var("TERM").unwrap_or_default();
+    let colors = crate::color::depth().label();
+    let size = crossterm::terminal::size().ok();
+    let mut detail = format!(
+        "TERM={}, {}",
+        if term.is_empty() { "unset" } else { &term },
+        colors
+    );
+    if let Some((cols, rows)) = size {
+        detail.push_str(&format!(", {}x{}", cols, rows));
+    }
+    if term.is_empty() || term == "dumb" {
+        return Check::new("terminal", CheckStatus::Warn, detail)
+            .hint("set TERM (e.g. xterm-256color) for the TUI to render correctly");
+    }
+    match size {
+        Some((cols, rows)) if cols < MIN_TERMINAL_SIZE.0 || rows < MIN_TERMINAL_SIZE.1 => {
+            Check::new("terminal", CheckStatus::Warn, detail).hint(format!(
+                "the TUI is best at {}x{} or larger",
+                MIN_TERMINAL_SIZE.0, MIN_TERMINAL_SIZE.1
+            ))
+        }
+        _ => Check::new("terminal", CheckStatus::Ok, detail),
+    }
+}
+
+fn render_checks(checks: &[Check]) {
+    for check in checks {
+        println!(
+            "{:<6} {:<12} {}",
+            format!("[{}]", check.status.label()),
+            check.name,
+            check.detail
+        );
+        if let Some(hint) = &check.hint {
+            println!("{:<6} {:<12} hint: {}", "", "", hint);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vendored_schema_parses() {
+        let value = serde_json::from_str(VENDORED_SCHEMA).unwrap();
+        let schema = Schema::from_introspection(value).unwrap();
+        assert!(schema
+            .get("Issue")
+            .is_some_and(|issue| issue.field("identifier").is_some()));
+        assert!(schema.missing_from(&schema).is_empty());
+    }
+}
@@ -0,0 +1,279 @@
+use std::cmp::Reverse;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use futures_util::{StreamExt, TryStreamExt};
+use linear_core::error::LinearError;
+use linear_core::graphql::{CycleSummary, IssueState, IssueUpdateInput};
+use linear_core::priority::{self, Priority};
+use linear_core::query::{IssueFields, IssueQuery};
+use linear_core::services::cycles::{CycleQueryOptions, CycleService};
+use linear_core::services::issues::IssueService;
+use serde::Deserialize;
+use serde_json::json;
+use textwrap::wrap;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+use crate::table::{Cell, Table};
+use crate::{build_client, load_session, markdown, resolve, EstimateArgs};
+
+/// Description lines shown per issue; `?` prints the rest.
+const PREVIEW_LINES: usize = 12;
+
+/// An unestimated issue with what the session shows of it.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Candidate {
+    id: String,
+    identifier: String,
+    title: String,
+    description: Option<String>,
+    url: Option<String>,
+    state: Option<IssueState>,
+    priority: Option<i32>,
+}
+
+enum Answer {
+    Points(i32),
+    Skip,
+    Back,
+    More,
+    Quit,
+}
+
+/// Step through a team's open, unestimated issues and write back the points given for each.
+pub(crate) async fn run(args: EstimateArgs) -> Result<()> {
+    let session = load_session(&args.profile).await?;
+    let client = build_client(&session)?;
+    let issues = IssueService::new(client.clone());
+    let team_id = resolve::team_id(&issues, &args.team).await?;
+
+    let mut query = IssueQuery::new()
+        .team_id(team_id.clone())
+        .open()
+        .raw("estimate", json!({ "null": true }))
+        .select(
+            IssueFields::ID
+                | IssueFields::IDENTIFIER
+                | IssueFields::TITLE
+                | IssueFields::DESCRIPTION
+                | IssueFields::URL
+                | IssueFields::STATE
+                | IssueFields::PRIORITY,
+        );
+    let mut scope = format!("team {}", args.team);
+    if let Some(spec) = &args.cycle {
+        let cycles: Vec<CycleSummary> = CycleService::new(client)
+            .list_iter(CycleQueryOptions {
+                team_id: Some(team_id),
+                ..Default::default()
+            })
+            .try_collect()
+            .await
+            .context("GraphQL request failed")?;
+        let cycle = select_cycle(&cycles, spec, Utc::now()).ok_or_else(|| {
+            LinearError::not_found(format!("no {spec} cycle for team '{}'", args.team))
+        })?;
+        scope = format!("{} cycle {}", args.team, cycle_label(cycle));
+        query = query.cycle(cycle.id.clone());
+    }
+
+    let mut stream = issues.query_iter::<Candidate>(query);
+    let mut candidates = Vec::new();
+    while candidates.len() < args.limit {
+        match stream.next().await {
+            Some(issue) => candidates.push(issue.context("GraphQL request failed")?),
+            None => break,
+        }
+    }
+    if candidates.is_empty() {
+        println!("Every open issue in {scope} has an estimate.");
+        return Ok(());
+    }
+    candidates.sort_by_key(|issue| {
+        Reverse(
+            issue
+                .priority
+                .and_then(Priority::from_value)
+                .unwrap_or(Priority::None),
+        )
+    });
+
+    println!(
+        "{} unestimated issue(s) in {scope}. Type points, Enter or s to skip, b to go back, ? for the full description, q to stop.",
+        candidates.len()
+    );
+    let mut estimates: Vec<Option<i32>> = vec![None; candidates.len()];
+    let mut lines = BufReader::new(tokio::io::stdin()).lines();
+    let mut index = 0;
+    while index < candidates.len() {
+        let issue = &candidates[index];
+        show(issue, index, candidates.len(), false);
+        let answer = loop {
+            prompt(estimates[index]).await?;
+            let Some(line) = lines.next_line().await? else {
+                break Answer::Quit;
+            };
+            match parse_answer(&line) {
+                Some(Answer::More) => show(issue, index, candidates.len(), true),
+                Some(answer) => break answer,
+                None => println!("Enter a whole number of points, or s, b, ?, q."),
+            }
+        };
+        match answer {
+            Answer::Points(points) => {
+                let input = IssueUpdateInput {
+                    estimate: Some(Some(points)),
+                    ..Default::default()
+                };
+                issues
+                    .update(&issue.id, input)
+                    .await
+                    .with_context(|| format!("failed to estimate {}", issue.identifier))?;
+                estimates[index] = Some(points);
+                index += 1;
+            }
+            Answer::Skip => index += 1,
+            Answer::Back => index = index.saturating_sub(1),
+            Answer::More => unreachable!("handled while prompting"),
+            Answer::Quit => break,
+        }
+    }
+
+    summarize(&candidates, &estimates);
+    Ok(())
+}
+
+/// `current` (the active cycle), `next` (the first one not started yet), or a cycle number.
+fn select_cycle<'a>(
+    cycles: &'a [CycleSummary],
+    spec: &str,
+    now: DateTime<Utc>,
+) -> Option<&'a CycleSummary> {
+    let starts = |cycle: &CycleSummary| {
+        cycle
+            .starts_at
+            .as_deref()
+            .and_then(|at| DateTime::parse_from_rfc3339(at).ok())
+            .map(|at| at.with_timezone(&Utc))
+    };
+    match spec.trim().to_ascii_lowercase().as_str() {
+        "current" => cycles.iter().find(|cycle| cycle.is_active),
+        "next" => cycles
+            .iter()
+            .filter(|cycle| starts(cycle).is_some_and(|at| at > now))
+            .min_by_key(|cycle| starts(cycle)),
+        other => {
+            let number: i64 = other.parse().ok()?;
+            cycles.iter().find(|cycle| cycle.number == number)
+        }
+    }
+}
+
+fn cycle_label(cycle: &CycleSummary) -> String {
+    match &cycle.name {
+        Some(name) if !name.is_empty() => format!("{} ({name})", cycle.number),
+        _ => cycle.number.to_string(),
+    }
+}
+
+fn parse_answer(line: &str) -> Option<Answer> {
+    match line.trim().to_ascii_lowercase().as_str() {
+        "" | "s" | "skip" => Some(Answer::Skip),
+        "b" | "back" => Some(Answer::Back),
+        "?" => Some(Answer::More),
+        "q" | "quit" => Some(Answer::Quit),
+        points => points
+            .parse::<i32>()
+            .ok()
+            .filter(|points| *points >= 0)
+            .map(Answer::Points),
+    }
+}
+
+fn show(issue: &Candidate, index: usize, total: usize, full: bool) {
+    let state = issue
+        .state
+        .as_ref()
+        .map(|state| state.name.as_str())
+        .unwrap_or("-");
+    println!();
+    println!(
+        "[{}/{}] {} · {} · {}",
+        index + 1,
+        total,
+        issue.identifier,
+        state,
+        priority::label_for(issue.priority)
+    );
+    println!("{}", issue.title);
+    if let Some(url) = &issue.url {
+        println!("{url}");
+    }
+    let Some(description) = issue
+        .description
+        .as_deref()
+        .map(str::trim)
+        .filter(|text| !text.is_empty())
+    else {
+        return;
+    };
+    println!();
+    let rendered = markdown::render(description);
+    let lines = wrap(rendered.text.trim(), markdown::wrap_width());
+    let shown = if full {
+        lines.len()
+    } else {
+        lines.len().min(PREVIEW_LINES)
+    };
+    for line in &lines[..shown] {
+        println!("  {line}");
+    }
+    if shown < lines.len() {
+        println!("  … {} more line(s); ? shows them", lines.len() - shown);
+    }
+}
+
+async fn prompt(current: Option<i32>) -> Result<()> {
+    let text = match current {
+        Some(points) => format!("Estimate [{points}]: "),
+        None => "Estimate: ".to_owned(),
+    };
+    let mut stdout = tokio::io::stdout();
+    stdout.write_all(text.as_bytes()).await?;
+    stdout.flush().await?;
+    Ok(())
+}
+
+fn summarize(candidates: &[Candidate], estimates: &[Option<i32>]) {
+    let estimated: Vec<(&Candidate, i32)> = candidates
+        .iter()
+        .zip(estimates)
+        .filter_map(|(issue, points)| points.map(|points| (issue, points)))
+        .collect();
+    println!();
+    if estimated.is_empty() {
+        println!("No estimates written.");
+    } else {
+        let mut table = Table::new()
+            .column("KEY", 12)
+            .column("POINTS", 6)
+            .flex_column("TITLE", 16, 80);
+        for (issue, points) in &estimated {
+            table.row([
+                Cell::link(issue.identifier.clone(), issue.url.as_deref()),
+                points.to_string().into(),
+                issue.title.as_str().into(),
+            ]);
+        }
+        table.print();
+    }
+    let total: i32 = estimated.iter().map(|(_, points)| points).sum();
+    println!(
+        "Estimated {} of {} issue(s) for {} point(s); {} left without an estimate.",
+        estimated.len(),
+        candidates.len(),
+        total,
+        candidates.len() - estimated.len()
+    );
+}
@@ -0,0 +1,190 @@
+use std::io::{self, IsTerminal, Write};
+
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use futures_util::{Stream, StreamExt};
+use linear_core::budget::Estimate;
+use linear_core::graphql::{GraphqlResult, IssueSummary, RequestPriority};
+use linear_core::query::IssueQuery;
+use linear_core::services::issues::{IssueQueryOptions, IssueService};
+use linear_core::share::ShareFormat;
+use linear_core::tasks::{org_entry, taskwarrior_task, TaskIssue, ORG_HEADER};
+
+use crate::budget::Meter;
+use crate::fields::{json_line, print_json};
+use crate::{build_client, issue_query_options, load_session, IssueExportArgs};
+
+/// Issues requested per page while streaming.
+const PAGE_SIZE: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub(crate) enum OutputFormat {
+    Table,
+    Json,
+    Ndjson,
+    /// Markdown table for wikis and chat
+    Markdown,
+    /// Standalone HTML page for email
+    Html,
+}
+
+impl OutputFormat {
+    /// The shareable document format, for `markdown` and `html`.
+    pub(crate) fn share(self) -> Option<ShareFormat> {
+        match self {
+            OutputFormat::Markdown => Some(ShareFormat::Markdown),
+            OutputFormat::Html => Some(ShareFormat::Html),
+            OutputFormat::Table | OutputFormat::Json | OutputFormat::Ndjson => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub(crate) enum ExportFormat {
+    Json,
+    Ndjson,
+    /// Org-mode TODO headings
+    Org,
+    /// Taskwarrior `task import` JSON
+    Taskwarrior,
+}
+
+pub(crate) async fn run(args: IssueExportArgs) -> Result<()> {
+    let session = load_session(&args.profile).await?;
+    // A large export should not hold up anything interactive sharing the rate limit.
+    let client = build_client(&session)?.with_priority(RequestPriority::Background);
+    let service = IssueService::new(client);
+    let options = issue_query_options(&service, &args.filter, PAGE_SIZE).await?;
+
+    let mut page = IssueQuery::from(options.clone()).first(page_size(args.limit));
+    if matches!(args.output, ExportFormat::Org | ExportFormat::Taskwarrior) {
+        page = page.select(TaskIssue::fields());
+    }
+    let estimate = Estimate::pages(args.limit, PAGE_SIZE, page.complexity());
+    args.budget.check(&estimate, "this export")?;
+    let mut meter = args.budget.meter(&estimate, PAGE_SIZE, "this export")?;
+
+    match args.output {
+        ExportFormat::Ndjson => stream_ndjson(&service, options, args.limit, &mut meter).await,
+        ExportFormat::Json => {
+            let mut issues = Vec::new();
+            let mut stream = matching_issues(&service, options, args.limit);
+            while let Some(issue) = next(&mut stream, &mut meter).await? {
+                issues.push(issue.context("GraphQL request failed")?);
+            }
+            print_json(&issues)?;
+            Ok(())
+        }
+        ExportFormat::Org => {
+            if !write_text(ORG_HEADER)? {
+                return Ok(());
+            }
+            let mut stream = matching_tasks(&service, options, args.limit);
+            while let Some(issue) = next(&mut stream, &mut meter).await? {
+                let entry = org_entry(&issue.context("GraphQL request failed")?);
+                if !write_text(&entry)? {
+                    break;
+                }
+            }
+            Ok(())
+        }
+        ExportFormat::Taskwarrior => {
+            let mut tasks = Vec::new();
+            let mut stream = matching_tasks(&service, options, args.limit);
+            while let Some(issue) = next(&mut stream, &mut meter).await? {
+                tasks.push(taskwarrior_task(&issue.context("GraphQL request failed")?));
+            }
+            print_json(&tasks)?;
+            Ok(())
+        }
+    }
+}
+
+/// The next item of `stream`, once `meter` allows fetching it.
+async fn next<S: Stream + Unpin>(stream: &mut S, meter: &mut Meter) -> Result<Option<S::Item>> {
+    meter.next()?;
+    Ok(stream.next().await)
+}
+
+/// Print issues as one JSON object per line, writing each as soon as its page arrives.
+///
+/// Memory use is bounded by the page size. A closed pipe (e.g. `| head`) ends the stream quietly.
+pub(crate) async fn stream_ndjson(
+    service: &IssueService,
+    options: IssueQueryOptions,
+    limit: Option<usize>,
+    meter: &mut Meter,
+) -> Result<()> {
+    let mut written = 0;
+    let mut stream = matching_issues(service, options, limit);
+    while let Some(issue) = next(&mut stream, meter).await? {
+        let issue = issue.context("GraphQL request failed")?;
+        if !write_lines(std::slice::from_ref(&issue))? {
+            break;
+        }
+        written += 1;
+    }
+    if io::stderr().is_terminal() && !io::stdout().is_terminal() {
+        eprintln!("Exported {} issue(s)", written);
+    }
+    Ok(())
+}
+
+/// Print a single page of issues as NDJSON.
+pub(crate) fn print_ndjson(issues: &[IssueSummary]) -> Result<()> {
+    write_lines(issues)?;
+    Ok(())
+}
+
+/// Up to `limit` issues, fetched a page at a time as the stream is consumed.
+fn matching_issues(
+    service: &IssueService,
+    mut options: IssueQueryOptions,
+    limit: Option<usize>,
+) -> impl Stream<Item = GraphqlResult<IssueSummary>> {
+    options.limit = page_size(limit);
+    service.list_iter(options).take(limit.unwrap_or(usize::MAX))
+}
+
+/// Like [`matching_issues`], with the extra fields the org and Taskwarrior formats use.
+fn matching_tasks(
+    service: &IssueService,
+    options: IssueQueryOptions,
+    limit: Option<usize>,
+) -> impl Stream<Item = GraphqlResult<TaskIssue>> {
+    let query = IssueQuery::from(options)
+        .select(TaskIssue::fields())
+        .first(page_size(limit));
+    service.query_iter(query).take(limit.unwrap_or(usize::MAX))
+}
+
+fn page_size(limit: Option<usize>) -> usize {
+    limit.map_or(PAGE_SIZE, |limit| limit.min(PAGE_SIZE))
+}
+
+/// Returns `false` once stdout has been closed by the reader.
+fn write_text(text: &str) -> Result<bool> {
+    let mut out = io::stdout().lock();
+    match out.write_all(text.as_bytes()).and_then(|()| out.flush()) {
+        Ok(()) => Ok(true),
+        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => Ok(false),
+        Err(err) => Err(err).context("failed to write output"),
+    }
+}
+
+/// Returns `false` once stdout has been closed by the reader.
+fn write_lines(issues: &[IssueSummary]) -> Result<bool> {
+    let mut out = io::stdout().lock();
+    let result = issues
+        .iter()
+        .try_for_each(|issue| {
+            let line = json_line(issue).map_err(io::Error::from)?;
+            writeln!(out, "{}", line)
+        })
+        .and_then(|()| out.flush());
+    match result {
+        Ok(()) => Ok(true),
+        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => Ok(false),
+        Err(err) => Err(err).context("failed to write output"),
+    }
+}
@@ -0,0 +1,84 @@
+use anyhow::{anyhow, Context, Result};
+use linear_core::error::{ErrorKind, LinearError};
+use linear_core::graphql::GraphqlError;
+use linear_core::services::entities::{EntityKind, EntityRef, EntityService};
+use serde_json::{Map, Value};
+
+use crate::fields::print_json;
+use crate::table::Table;
+use crate::{build_client, load_session, settings, GetArgs};
+
+/// Clap value parser for `kind/id`; issues take anything an issue argument does.
+pub(crate) fn parse_entity(input: &str) -> Result<EntityRef, String> {
+    let mut entity: EntityRef = input.parse()?;
+    if entity.kind == EntityKind::Issue {
+        entity.id = settings::parse_issue_key(&entity.id)?;
+    }
+    Ok(entity)
+}
+
+/// Fetch every entity in one request and print them as a table, or as a JSON object keyed by
+/// `kind/id` with `null` for the ones not found. Fails when any is missing, after printing the
+/// rest.
+pub(crate) async fn run(args: GetArgs) -> Result<()> {
+    let session = load_session(&args.profile).await?;
+    let client = build_client(&session)?;
+    let results = EntityService::new(client)
+        .fetch(&args.entities)
+        .await
+        .context("GraphQL request failed")?;
+
+    let mut found = Map::new();
+    let mut failed = Vec::new();
+    let mut table = Table::new()
+        .column("ENTITY", 16)
+        .flex_column("NAME", 16, 60)
+        .flex_column("URL", 8, 80);
+    for (entity, result) in args.entities.iter().zip(results) {
+        let value = match result {
+            Ok(value) => value,
+            Err(err) => {
+                let err = match err {
+                    GraphqlError::NotFound => {
+                        anyhow!(LinearError::not_found(format!("{entity} not found")))
+                    }
+                    err => anyhow!(err).context(format!("unable to load {entity}")),
+                };
+                eprintln!("{err:#}");
+                failed.push(ErrorKind::classify(&err));
+                found.insert(entity.to_string(), Value::Null);
+                continue;
+            }
+        };
+        let name = ["identifier", "title", "name", "displayName"]
+            .iter()
+            .filter_map(|field| value[field].as_str())
+            .take(2)
+            .collect::<Vec<_>>()
+            .join(" ");
+        table.row([
+            entity.to_string(),
+            name,
+            value["url"].as_str().unwrap_or_default().to_owned(),
+        ]);
+        found.insert(entity.to_string(), value);
+    }
+    if args.json {
+        print_json(&found)?;
+    } else if failed.len() < args.entities.len() {
+        table.print();
+    }
+
+    match failed.first() {
+        None => Ok(()),
+        Some(&kind) => Err(LinearError::new(
+            kind,
+            format!(
+                "{} of {} entities could not be loaded",
+                failed.len(),
+                args.entities.len()
+            ),
+        )
+        .into()),
+    }
+}
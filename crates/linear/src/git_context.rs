@@ -0,0 +1,182 @@
+//! Infer the issue being worked on from the current git branch, and find the checkout's root.
+
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+/// Longest team key prefix accepted in a branch name.
+const MAX_TEAM_KEY_LEN: usize = 7;
+
+/// Issue key named by the checked-out branch, e.g. `ENG-123` for `ada/eng-123-fix-login`.
+pub(crate) fn current_issue_key() -> Option<(String, String)> {
+    let branch = current_branch()?;
+    let key = branch_issue_key(&branch)?;
+    Some((key, branch))
+}
+
+/// Top directory of the current git checkout, or `None` outside a repository.
+pub(crate) fn repo_root() -> Option<PathBuf> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--show-toplevel"])
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let root = String::from_utf8(output.stdout).ok()?;
+    let root = root.trim();
+    (!root.is_empty()).then(|| PathBuf::from(root))
+}
+
+/// Name of the checked-out branch, or `None` outside a repository or on a detached HEAD.
+fn current_branch() -> Option<String> {
+    let output = Command::new("git")
+        .args(["symbolic-ref", "--quiet", "--short", "HEAD"])
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let branch = String::from_utf8(output.stdout).ok()?;
+    let branch = branch.trim();
+    (!branch.is_empty()).then(|| branch.to_owned())
+}
+
+/// Extract an issue key from a branch name.
+///
+/// The key must open a path segment (`eng-123-…`, `ada/ENG-123`); later segments win, so
+/// `release-2/eng-7-fix` yields `ENG-7`.
+pub(crate) fn branch_issue_key(branch: &str) -> Option<String> {
+    branch.rsplit('/').find_map(segment_issue_key)
+}
+
+/// Add a `<magic word> <KEY>` trailer to a commit message that does not mention `key` yet.
+///
+/// The trailer goes after the message text and before git's `#` comment block, so verbose
+/// commit diffs below the scissors line are left alone. Returns `None` when nothing changes.
+pub(crate) fn add_issue_reference(message: &str, key: &str, magic_word: &str) -> Option<String> {
+    let split = message
+        .match_indices('\n')
+        .map(|(index, _)| index + 1)
+        .chain([0])
+        .filter(|&start| message[start..].starts_with('#'))
+        .min()
+        .unwrap_or(message.len());
+    let (text, comments) = message.split_at(split);
+    let text = text.trim_end();
+    let subject = text.lines().next().unwrap_or_default();
+    if text.is_empty()
+        || ["fixup!", "squash!", "amend!", "Merge "]
+            .iter()
+            .any(|prefix| subject.starts_with(prefix))
+        || references_issue(text, key)
+    {
+        return None;
+    }
+    let in_trailers = text
+        .rsplit_once("\n\n")
+        .is_some_and(|(_, last)| last.lines().all(is_trailer));
+    let separator = if in_trailers { "\n" } else { "\n\n" };
+    Some(format!(
+        "{text}{separator}{magic_word} {key}\n{}{comments}",
+        if comments.is_empty() { "" } else { "\n" }
+    ))
+}
+
+/// Whether `text` mentions `key` as a whole word, ignoring case.
+pub(crate) fn references_issue(text: &str, key: &str) -> bool {
+    let text = text.to_ascii_uppercase();
+    let key = key.to_ascii_uppercase();
+    text.match_indices(&key).any(|(start, _)| {
+        let before = text[..start].chars().next_back();
+        let after = text[start + key.len()..].chars().next();
+        !before.is_some_and(|c| c.is_ascii_alphanumeric())
+            && !after.is_some_and(|c| c.is_ascii_alphanumeric())
+    })
+}
+
+/// `Token: value` or `Token value`-style trailer line such as `Refs ENG-1`.
+fn is_trailer(line: &str) -> bool {
+    line.split_once([':', ' ']).is_some_and(|(token, value)| {
+        !token.is_empty()
+            && !value.trim().is_empty()
+            && token.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+            && value.split_whitespace().count() <= 2
+    })
+}
+
+fn segment_issue_key(segment: &str) -> Option<String> {
+    let mut parts = segment.splitn(3, ['-', '_']);
+    let team = parts.next()?;
+    let number = parts.next()?;
+    let number = number
+        .find(|c: char| !c.is_ascii_digit())
+        .map_or(number, |end| &number[..end]);
+    let valid_team = (1..=MAX_TEAM_KEY_LEN).contains(&team.len())
+        && team.starts_with(|c: char| c.is_ascii_alphabetic())
+        && team.chars().all(|c| c.is_ascii_alphanumeric());
+    let valid_number = !number.is_empty() && !number.starts_with('0');
+    (valid_team && valid_number).then(|| format!("{}-{}", team.to_ascii_uppercase(), number))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_keys_in_common_branch_names() {
+        let key = |branch: &str| branch_issue_key(branch);
+        assert_eq!(key("eng-123-fix-login").as_deref(), Some("ENG-123"));
+        assert_eq!(key("ada/eng-42-cache").as_deref(), Some("ENG-42"));
+        assert_eq!(key("feature/ENG-7").as_deref(), Some("ENG-7"));
+        assert_eq!(key("ops2_19_rotate").as_deref(), Some("OPS2-19"));
+        assert_eq!(key("release-2/eng-7-fix").as_deref(), Some("ENG-7"));
+    }
+
+    #[test]
+    fn appends_issue_trailers_before_comments() {
+        let message = "Fix login\n\n# Please enter the commit message\n# ------------------------ >8 ------------------------\ndiff --git a/x b/x\n";
+        assert_eq!(
+            add_issue_reference(message, "ENG-12", "Refs").unwrap(),
+            "Fix login\n\nRefs ENG-12\n\n# Please enter the commit message\n# ------------------------ >8 ------------------------\ndiff --git a/x b/x\n"
+        );
+        assert_eq!(
+            add_issue_reference("Fix login\n\nSigned-off-by: Ada <a@x>\n", "ENG-12", "Fixes")
+                .unwrap(),
+            "Fix login\n\nSigned-off-by: Ada <a@x>\nFixes ENG-12\n"
+        );
+        assert_eq!(
+            add_issue_reference("ENG-12: fix login", "ENG-12", "Refs"),
+            None
+        );
+        assert_eq!(
+            add_issue_reference("fix eng-12 login", "ENG-12", "Refs"),
+            None
+        );
+        assert!(add_issue_reference("Fix ENG-123", "ENG-12", "Refs").is_some());
+        assert_eq!(
+            add_issue_reference("fixup! Fix login", "ENG-12", "Refs"),
+            None
+        );
+        assert_eq!(
+            add_issue_reference("# only comments\n", "ENG-12", "Refs"),
+            None
+        );
+    }
+
+    #[test]
+    fn ignores_branches_without_keys() {
+        for branch in [
+            "main",
+            "fix-login",
+            "v1.2-hotfix",
+            "deps/bump-0",
+            "refactoring-12",
+        ] {
+            assert_eq!(branch_issue_key(branch), None, "{branch}");
+        }
+    }
+}
@@ -0,0 +1,225 @@
+//! A small GitHub REST client: just the issue reads the CLI needs to bring GitHub issues into
+//! Linear.
+
+use std::env;
+
+use anyhow::{Context, Result};
+use linear_core::error::{ErrorKind, LinearError};
+use reqwest::header::{ACCEPT, AUTHORIZATION, USER_AGENT};
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use url::Url;
+
+const DEFAULT_API: &str = "https://api.github.com";
+/// Largest page the REST API serves.
+const PER_PAGE: usize = 100;
+
+/// `owner/name` of a repository.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Repo {
+    pub owner: String,
+    pub name: String,
+}
+
+impl std::fmt::Display for Repo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.owner, self.name)
+    }
+}
+
+/// `owner/name`, or a repository URL such as `https://github.com/owner/name`.
+pub(crate) fn parse_repo(raw: &str) -> Result<Repo, String> {
+    let trimmed = raw.trim().trim_end_matches('/').trim_end_matches(".git");
+    let path = trimmed
+        .strip_prefix("https://github.com/")
+        .or_else(|| trimmed.strip_prefix("github.com/"))
+        .unwrap_or(trimmed);
+    match path.split('/').collect::<Vec<_>>().as_slice() {
+        [owner, name] if !owner.is_empty() && !name.is_empty() => Ok(Repo {
+            owner: (*owner).to_owned(),
+            name: (*name).to_owned(),
+        }),
+        _ => Err(format!("expected owner/name, got '{raw}'")),
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct GithubIssue {
+    pub number: u64,
+    pub title: String,
+    pub body: Option<String>,
+    pub html_url: String,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+    #[serde(default)]
+    pub labels: Vec<GithubLabel>,
+    /// Number of comments.
+    #[serde(default)]
+    pub comments: u64,
+    /// Present when the issue is a pull request.
+    #[serde(default)]
+    pub pull_request: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct GithubLabel {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct GithubComment {
+    pub id: u64,
+    pub body: Option<String>,
+    pub html_url: String,
+    pub user: Option<GithubUser>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct GithubUser {
+    pub login: String,
+}
+
+/// Repository and number named by an issue or pull request URL, e.g.
+/// `https://github.com/owner/name/issues/42`.
+pub(crate) fn parse_issue_url(url: &Url) -> Option<(Repo, u64)> {
+    if !matches!(url.host_str(), Some("github.com" | "www.github.com")) {
+        return None;
+    }
+    let segments: Vec<&str> = url.path_segments()?.collect();
+    match segments.as_slice() {
+        [owner, name, "issues" | "pull", number, ..] if !owner.is_empty() && !name.is_empty() => {
+            Some((
+                Repo {
+                    owner: (*owner).to_owned(),
+                    name: (*name).to_owned(),
+                },
+                number.parse().ok()?,
+            ))
+        }
+        _ => None,
+    }
+}
+
+pub(crate) struct GithubClient {
+    http: reqwest::Client,
+    api: String,
+    token: Option<String>,
+}
+
+impl GithubClient {
+    /// Client authenticated with `GITHUB_TOKEN` or `GH_TOKEN` when set, which private
+    /// repositories and higher rate limits need. `GITHUB_API_URL` points it at GitHub Enterprise.
+    pub(crate) fn from_env() -> Self {
+        let token = ["GITHUB_TOKEN", "GH_TOKEN"]
+            .iter()
+            .find_map(|name| env::var(name).ok().filter(|value| !value.is_empty()));
+        let api = env::var("GITHUB_API_URL")
+            .ok()
+            .filter(|value| !value.is_empty())
+            .unwrap_or_else(|| DEFAULT_API.to_owned());
+        Self {
+            http: linear_core::http::shared().unwrap_or_default(),
+            api: api.trim_end_matches('/').to_owned(),
+            token,
+        }
+    }
+
+    pub(crate) async fn issue(&self, repo: &Repo, number: u64) -> Result<GithubIssue> {
+        self.get(&format!("repos/{repo}/issues/{number}"))
+            .await
+            .with_context(|| format!("failed to fetch GitHub issue {repo}#{number}"))
+    }
+
+    /// Open issues, oldest first, skipping pull requests; stops after `limit` when given.
+    pub(crate) async fn open_issues(
+        &self,
+        repo: &Repo,
+        limit: Option<usize>,
+    ) -> Result<Vec<GithubIssue>> {
+        let mut issues = Vec::new();
+        for page in 1.. {
+            let batch: Vec<GithubIssue> = self
+                .get(&format!(
+                    "repos/{repo}/issues?state=open&sort=created&direction=asc&per_page={PER_PAGE}&page={page}"
+                ))
+                .await
+                .with_context(|| format!("failed to list GitHub issues of {repo}"))?;
+            let last = batch.len() < PER_PAGE;
+            issues.extend(
+                batch
+                    .into_iter()
+                    .filter(|issue| issue.pull_request.is_none()),
+            );
+            if let Some(limit) = limit.filter(|&limit| issues.len() >= limit) {
+                issues.truncate(limit);
+                break;
+            }
+            if last {
+                break;
+            }
+        }
+        Ok(issues)
+    }
+
+    /// Comments on an issue, oldest first.
+    pub(crate) async fn comments(&self, repo: &Repo, number: u64) -> Result<Vec<GithubComment>> {
+        let mut comments = Vec::new();
+        for page in 1.. {
+            let batch: Vec<GithubComment> = self
+                .get(&format!(
+                    "repos/{repo}/issues/{number}/comments?per_page={PER_PAGE}&page={page}"
+                ))
+                .await
+                .with_context(|| format!("failed to fetch comments on {repo}#{number}"))?;
+            let last = batch.len() < PER_PAGE;
+            comments.extend(batch);
+            if last {
+                break;
+            }
+        }
+        Ok(comments)
+    }
+
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        let mut request = self
+            .http
+            .get(format!("{}/{}", self.api, path))
+            .header(ACCEPT, "application/vnd.github+json")
+            .header(USER_AGENT, concat!("linear-rs/", env!("CARGO_PKG_VERSION")));
+        if let Some(token) = &self.token {
+            request = request.header(AUTHORIZATION, format!("Bearer {token}"));
+        }
+        let response = request.send().await?;
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response.json().await?);
+        }
+        let exhausted = response
+            .headers()
+            .get("x-ratelimit-remaining")
+            .is_some_and(|value| value == "0");
+        let message = response
+            .json::<serde_json::Value>()
+            .await
+            .ok()
+            .and_then(|body| body["message"].as_str().map(str::to_owned))
+            .unwrap_or_else(|| status.to_string());
+        let (kind, hint) = match status {
+            StatusCode::NOT_FOUND if self.token.is_none() => (
+                ErrorKind::NotFound,
+                "; set GITHUB_TOKEN for private repositories",
+            ),
+            StatusCode::NOT_FOUND => (ErrorKind::NotFound, ""),
+            StatusCode::FORBIDDEN | StatusCode::TOO_MANY_REQUESTS if exhausted => (
+                ErrorKind::RateLimited,
+                "; set GITHUB_TOKEN for a higher limit",
+            ),
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                (ErrorKind::Auth, "; check GITHUB_TOKEN")
+            }
+            _ => (ErrorKind::Network, ""),
+        };
+        Err(LinearError::new(kind, format!("GitHub: {message}{hint}")).into())
+    }
+}
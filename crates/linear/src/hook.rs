@@ -0,0 +1,103 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use linear_core::error::LinearError;
+
+use crate::git_context;
+use crate::{HookInstallArgs, HookRunArgs};
+
+/// Marker line identifying hooks this binary wrote, so reinstalling can replace them.
+const MARKER: &str = "# Installed by `linear hook install`";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub(crate) enum HookKind {
+    CommitMsg,
+}
+
+impl HookKind {
+    fn file_name(self) -> &'static str {
+        match self {
+            Self::CommitMsg => "commit-msg",
+        }
+    }
+}
+
+/// Write a hook script that calls back into this binary.
+pub(crate) fn install(args: HookInstallArgs) -> Result<()> {
+    let dir = hooks_dir()?;
+    let path = dir.join(args.hook.file_name());
+    if let Ok(existing) = fs::read_to_string(&path) {
+        if !existing.contains(MARKER) && !args.force {
+            return Err(LinearError::validation(format!(
+                "{} already exists and was not installed by linear; pass --force to replace it",
+                path.display()
+            ))
+            .into());
+        }
+    }
+
+    let exe = env::current_exe().context("failed to locate the linear binary")?;
+    let mut command = format!(
+        "{} hook run {} --magic-word {}",
+        shell_quote(&exe.to_string_lossy()),
+        args.hook.file_name(),
+        shell_quote(&args.magic_word)
+    );
+    if args.check {
+        command.push_str(" --check");
+    }
+    let script =
+        format!("#!/bin/sh\n{MARKER}; delete this file to uninstall.\nexec {command} \"$1\"\n");
+    fs::create_dir_all(&dir).with_context(|| format!("failed to create {}", dir.display()))?;
+    fs::write(&path, script).with_context(|| format!("failed to write {}", path.display()))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755))?;
+    }
+    println!("Installed {}", path.display());
+    Ok(())
+}
+
+/// Hook entry point: reference the branch's issue in the commit message, or reject it with
+/// `--check`.
+pub(crate) fn run(args: HookRunArgs) -> Result<()> {
+    let Some((key, branch)) = git_context::current_issue_key() else {
+        return Ok(());
+    };
+    let message = fs::read_to_string(&args.file)
+        .with_context(|| format!("failed to read {}", args.file.display()))?;
+    let Some(updated) = git_context::add_issue_reference(&message, &key, &args.magic_word) else {
+        return Ok(());
+    };
+    if args.check {
+        return Err(LinearError::validation(format!(
+            "commit message on branch {} must reference {}",
+            branch, key
+        ))
+        .into());
+    }
+    fs::write(&args.file, updated)
+        .with_context(|| format!("failed to write {}", args.file.display()))?;
+    Ok(())
+}
+
+fn hooks_dir() -> Result<PathBuf> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--git-path", "hooks"])
+        .output()
+        .context("failed to run git")?;
+    if !output.status.success() {
+        return Err(LinearError::validation("not inside a git repository").into());
+    }
+    let dir = String::from_utf8_lossy(&output.stdout).trim().to_owned();
+    Ok(PathBuf::from(dir))
+}
+
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
@@ -0,0 +1,66 @@
+//! Localized user-facing strings.
+//!
+//! Catalogs live in `locales/<tag>.ftl` and are compiled in; `cargo xtask verify-locales` checks
+//! them against the English one. The locale comes from `--lang`, else `LC_ALL`, `LC_MESSAGES`,
+//! or `LANG`, else English.
+
+use std::env;
+use std::sync::OnceLock;
+
+use linear_core::i18n::{self, Catalog, Localizer, FALLBACK_LOCALE};
+
+/// Every bundled catalog, English first.
+const LOCALES: &[(&str, &str)] = &[
+    ("en", include_str!("../locales/en.ftl")),
+    ("de", include_str!("../locales/de.ftl")),
+];
+
+static LOCALIZER: OnceLock<Localizer> = OnceLock::new();
+
+/// Fix the locale for the process; `lang` comes from `--lang` and was checked by [`parse_lang`].
+pub(crate) fn init(lang: Option<&str>) {
+    let _ = LOCALIZER.set(build(lang));
+}
+
+/// `--lang` value parser: accepts any bundled locale, or a regional variant of one.
+pub(crate) fn parse_lang(value: &str) -> Result<String, String> {
+    let tags: Vec<&str> = LOCALES.iter().map(|(tag, _)| *tag).collect();
+    i18n::negotiate(value.trim(), &tags)
+        .map(str::to_owned)
+        .ok_or_else(|| {
+            format!(
+                "no translation for '{value}' (available: {})",
+                tags.join(", ")
+            )
+        })
+}
+
+/// The message `id` in the current locale.
+pub(crate) fn tr(id: &str) -> String {
+    tr_args(id, &[])
+}
+
+/// The message `id` with its `{ $name }` placeables filled from `args`.
+pub(crate) fn tr_args(id: &str, args: &[(&str, &str)]) -> String {
+    LOCALIZER.get_or_init(|| build(None)).message(id, args)
+}
+
+fn build(lang: Option<&str>) -> Localizer {
+    let tags: Vec<&str> = LOCALES.iter().map(|(tag, _)| *tag).collect();
+    let requested = lang
+        .map(str::to_owned)
+        .or_else(|| i18n::locale_from_env(|name| env::var(name).ok()));
+    let locale = requested
+        .as_deref()
+        .and_then(|requested| i18n::negotiate(requested, &tags))
+        .unwrap_or(FALLBACK_LOCALE);
+    Localizer::new(locale, catalog(locale), catalog(FALLBACK_LOCALE))
+}
+
+fn catalog(tag: &str) -> Catalog {
+    LOCALES
+        .iter()
+        .find(|(candidate, _)| *candidate == tag)
+        .and_then(|(_, source)| Catalog::parse(source).ok())
+        .unwrap_or_default()
+}
@@ -0,0 +1,118 @@
+use std::env;
+use std::io::{self, IsTerminal, Write};
+
+use anyhow::{anyhow, Context, Result};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use crossterm::terminal;
+use linear_core::graphql::LinearGraphqlClient;
+
+use crate::markdown::ImageRef;
+
+const MAX_IMAGE_BYTES: usize = 10 * 1024 * 1024;
+const MAX_PREVIEW_COLUMNS: u16 = 80;
+const KITTY_CHUNK: usize = 4096;
+const PNG_MAGIC: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+/// Inline image protocols we can emit without decoding the image ourselves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Protocol {
+    /// Kitty graphics protocol (kitty, Ghostty, WezTerm); PNG payloads only.
+    Kitty,
+    /// iTerm2 inline images (iTerm2, WezTerm); any format the terminal decodes.
+    Iterm,
+}
+
+fn detect() -> Option<Protocol> {
+    if !io::stdout().is_terminal() {
+        return None;
+    }
+    let term = env::var("TERM").unwrap_or_default();
+    let program = env::var("TERM_PROGRAM").unwrap_or_default();
+    if env::var_os("KITTY_WINDOW_ID").is_some() || term.contains("kitty") || program == "ghostty" {
+        Some(Protocol::Kitty)
+    } else if program == "iTerm.app" || program == "WezTerm" {
+        Some(Protocol::Iterm)
+    } else {
+        None
+    }
+}
+
+/// Download each image and draw it below the text. Images that cannot be previewed keep the
+/// link rendered in the body, with a note on stderr explaining why.
+pub(crate) async fn preview(client: &LinearGraphqlClient, images: &[ImageRef]) {
+    if images.is_empty() {
+        return;
+    }
+    let Some(protocol) = detect() else {
+        eprintln!("note: --images needs a terminal with kitty or iTerm2 image support; showing links only");
+        return;
+    };
+    let columns = terminal::size()
+        .map(|(cols, _)| cols)
+        .unwrap_or(MAX_PREVIEW_COLUMNS)
+        .min(MAX_PREVIEW_COLUMNS);
+    for image in images {
+        if let Err(err) = preview_one(client, image, protocol, columns).await {
+            eprintln!("note: could not preview {}: {:#}", image.url, err);
+        }
+    }
+}
+
+async fn preview_one(
+    client: &LinearGraphqlClient,
+    image: &ImageRef,
+    protocol: Protocol,
+    columns: u16,
+) -> Result<()> {
+    let bytes = client
+        .fetch_upload(&image.url)
+        .await
+        .context("download failed")?;
+    if bytes.len() > MAX_IMAGE_BYTES {
+        return Err(anyhow!(
+            "image is larger than {} MiB",
+            MAX_IMAGE_BYTES >> 20
+        ));
+    }
+    let sequence = match protocol {
+        Protocol::Kitty if !bytes.starts_with(PNG_MAGIC) => {
+            return Err(anyhow!("only PNG images can be shown in this terminal"));
+        }
+        Protocol::Kitty => kitty_sequence(&bytes, columns),
+        Protocol::Iterm => iterm_sequence(&bytes, columns),
+    };
+    let mut stdout = io::stdout().lock();
+    stdout.write_all(sequence.as_bytes())?;
+    stdout.write_all(b"\n")?;
+    stdout.flush()?;
+    Ok(())
+}
+
+fn kitty_sequence(bytes: &[u8], columns: u16) -> String {
+    let encoded = STANDARD.encode(bytes);
+    let chunks: Vec<&[u8]> = encoded.as_bytes().chunks(KITTY_CHUNK).collect();
+    let mut out = String::new();
+    for (index, chunk) in chunks.iter().enumerate() {
+        let more = u8::from(index + 1 < chunks.len());
+        let chunk = std::str::from_utf8(chunk).unwrap_or_default();
+        if index == 0 {
+            out.push_str(&format!(
+                "\x1b_Ga=T,f=100,c={},m={};{}\x1b\\",
+                columns, more, chunk
+            ));
+        } else {
+            out.push_str(&format!("\x1b_Gm={};{}\x1b\\", more, chunk));
+        }
+    }
+    out
+}
+
+fn iterm_sequence(bytes: &[u8], columns: u16) -> String {
+    format!(
+        "\x1b]1337;File=inline=1;size={};width={};preserveAspectRatio=1:{}\x07",
+        bytes.len(),
+        columns,
+        STANDARD.encode(bytes)
+    )
+}
@@ -0,0 +1,666 @@
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::io::{self, IsTerminal, Write};
+
+use anyhow::{Context, Result};
+use linear_core::config::Impact;
+use linear_core::error::{ErrorKind, LinearError};
+use linear_core::graphql::{IssueDetail, IssueUpdateInput};
+use linear_core::import::csv::{self, ColumnMap, CsvIssue, IssueField};
+use linear_core::import::{ImportLedger, ImportLedgerStore, LedgerEntry};
+use linear_core::services::issues::{IssueCreateOptions, IssueService};
+use linear_core::services::labels::LabelService;
+use serde::Serialize;
+use tokio::task;
+
+use crate::fields::print_json;
+use crate::github::{GithubClient, GithubIssue, Repo};
+use crate::table::Table;
+use crate::{build_client, client_options, load_session, resolve, ImportCsvArgs, ImportGithubArgs};
+
+/// What a run did with one source issue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Action {
+    Created,
+    Updated,
+    Unchanged,
+}
+
+impl Action {
+    fn as_str(self) -> &'static str {
+        match self {
+            Action::Created => "created",
+            Action::Updated => "updated",
+            Action::Unchanged => "unchanged",
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Imported {
+    source: String,
+    identifier: String,
+    title: String,
+    action: Action,
+    comments: usize,
+}
+
+/// `GITHUB=LINEAR` label mapping; names are matched case-insensitively.
+pub(crate) fn parse_label_map(raw: &str) -> Result<(String, String), String> {
+    let (github, linear) = raw
+        .split_once('=')
+        .ok_or_else(|| format!("expected GITHUB=LINEAR, got '{raw}'"))?;
+    let (github, linear) = (github.trim(), linear.trim());
+    if github.is_empty() || linear.is_empty() {
+        return Err(format!("expected GITHUB=LINEAR, got '{raw}'"));
+    }
+    Ok((github.to_ascii_lowercase(), linear.to_owned()))
+}
+
+/// Import open GitHub issues into a team, updating issues imported by earlier runs.
+pub(crate) async fn github(args: ImportGithubArgs) -> Result<()> {
+    let store = ImportLedgerStore::for_profile(&args.profile).ok_or_else(|| {
+        anyhow::anyhow!("could not determine the data directory for the import ledger")
+    })?;
+    let mut ledger = store.load()?;
+
+    let session = load_session(&args.profile).await?;
+    let client = build_client(&session)?;
+    let service = IssueService::new(client.clone());
+    let team_id = resolve::team_id(&service, &args.team).await?;
+    let labels: HashMap<String, String> = LabelService::new(client)
+        .list(&team_id)
+        .await
+        .context("GraphQL request failed")?
+        .into_iter()
+        .map(|label| (label.name.to_ascii_lowercase(), label.id))
+        .collect();
+    let label_map: HashMap<String, String> = args.label_map.iter().cloned().collect();
+
+    let github = GithubClient::from_env();
+    let issues = github.open_issues(&args.repo, args.limit).await?;
+    if issues.is_empty() && !args.json {
+        println!("No open issues in {}", args.repo);
+        return Ok(());
+    }
+
+    let importer = Importer {
+        service: &service,
+        github: &github,
+        repo: &args.repo,
+        team_id: &team_id,
+        labels: &labels,
+        label_map: &label_map,
+        comments: args.comments,
+    };
+    let mut results = Vec::new();
+    let mut unmapped = BTreeSet::new();
+    for issue in &issues {
+        let (label_ids, missing) = importer.label_ids(issue);
+        unmapped.extend(missing);
+        let result = importer.import(&mut ledger, issue, label_ids).await;
+        // Keep what was imported so far even when a later issue fails.
+        store.save(&ledger)?;
+        let imported = result?;
+        if !args.json {
+            println!(
+                "{:<9} {:<10} {}#{}  {}",
+                imported.action.as_str(),
+                imported.identifier,
+                args.repo,
+                issue.number,
+                imported.title
+            );
+        }
+        results.push(imported);
+    }
+
+    if args.json {
+        return print_json(&results);
+    }
+    let count = |action| results.iter().filter(|r| r.action == action).count();
+    println!(
+        "\n{} issues from {}: {} created, {} updated, {} unchanged",
+        results.len(),
+        args.repo,
+        count(Action::Created),
+        count(Action::Updated),
+        count(Action::Unchanged)
+    );
+    if !unmapped.is_empty() {
+        eprintln!(
+            "Skipped GitHub labels with no Linear label in {}: {} (map them with --label-map)",
+            args.team,
+            unmapped.into_iter().collect::<Vec<_>>().join(", ")
+        );
+    }
+    Ok(())
+}
+
+struct Importer<'a> {
+    service: &'a IssueService,
+    github: &'a GithubClient,
+    repo: &'a Repo,
+    team_id: &'a str,
+    /// Team label ids by lowercase name.
+    labels: &'a HashMap<String, String>,
+    label_map: &'a HashMap<String, String>,
+    comments: bool,
+}
+
+impl Importer<'_> {
+    /// Linear label ids for the issue's GitHub labels, plus the names that match none.
+    fn label_ids(&self, issue: &GithubIssue) -> (Vec<String>, Vec<String>) {
+        let mut ids = Vec::new();
+        let mut missing = Vec::new();
+        for label in &issue.labels {
+            let name = self
+                .label_map
+                .get(&label.name.to_ascii_lowercase())
+                .unwrap_or(&label.name);
+            match self.labels.get(&name.to_ascii_lowercase()) {
+                Some(id) if !ids.contains(id) => ids.push(id.clone()),
+                Some(_) => {}
+                None => missing.push(name.clone()),
+            }
+        }
+        (ids, missing)
+    }
+
+    async fn import(
+        &self,
+        ledger: &mut ImportLedger,
+        issue: &GithubIssue,
+        label_ids: Vec<String>,
+    ) -> Result<Imported> {
+        let source = format!("github:{}#{}", self.repo, issue.number);
+        let description = description(self.repo, issue);
+
+        let mut action = Action::Unchanged;
+        let existing = ledger.get(&source).cloned();
+        let mut entry = match existing {
+            Some(entry) if entry.source_updated_at == issue.updated_at => entry,
+            Some(entry) => match self.update(&entry, issue, &description, &label_ids).await {
+                Ok(()) => {
+                    action = Action::Updated;
+                    entry
+                }
+                // The Linear issue was deleted since; import it again.
+                Err(err) if ErrorKind::classify(&err) == ErrorKind::NotFound => {
+                    action = Action::Created;
+                    self.create(issue, &description, label_ids).await?
+                }
+                Err(err) => return Err(err),
+            },
+            None => {
+                action = Action::Created;
+                self.create(issue, &description, label_ids).await?
+            }
+        };
+        entry.source_updated_at = issue.updated_at.clone();
+        let identifier = entry.identifier.clone();
+        let issue_id = entry.issue_id.clone();
+        // Recorded before copying comments, so a failure there cannot cause a duplicate.
+        ledger.insert(source.clone(), entry);
+
+        let mut copied = 0;
+        if self.comments && issue.comments > 0 {
+            for comment in self.github.comments(self.repo, issue.number).await? {
+                let id = comment.id.to_string();
+                if ledger
+                    .get(&source)
+                    .is_some_and(|entry| entry.has_comment(&id))
+                {
+                    continue;
+                }
+                let author = comment
+                    .user
+                    .as_ref()
+                    .map_or("someone", |user| user.login.as_str());
+                let body = format!(
+                    "**@{}** [commented on GitHub]({}):\n\n{}",
+                    author,
+                    comment.html_url,
+                    comment.body.as_deref().unwrap_or_default().trim()
+                );
+                self.service
+                    .comment(&issue_id, &body)
+                    .await
+                    .context("GraphQL request failed")?;
+                if let Some(entry) = ledger.get_mut(&source) {
+                    entry.comments.push(id);
+                }
+                copied += 1;
+            }
+        }
+        if copied > 0 && action == Action::Unchanged {
+            action = Action::Updated;
+        }
+
+        Ok(Imported {
+            source,
+            identifier,
+            title: issue.title.clone(),
+            action,
+            comments: copied,
+        })
+    }
+
+    async fn create(
+        &self,
+        issue: &GithubIssue,
+        description: &str,
+        label_ids: Vec<String>,
+    ) -> Result<LedgerEntry> {
+        let mut options = IssueCreateOptions::new(self.team_id, issue.title.clone());
+        options.description = Some(description.to_owned());
+        options.label_ids = label_ids;
+        let created = self
+            .service
+            .create(options)
+            .await
+            .context("GraphQL request failed")?;
+        Ok(LedgerEntry::new(created.id, created.identifier))
+    }
+
+    /// Sync title and description, and add GitHub labels the Linear issue lacks, keeping
+    /// labels added in Linear.
+    async fn update(
+        &self,
+        entry: &LedgerEntry,
+        issue: &GithubIssue,
+        description: &str,
+        label_ids: &[String],
+    ) -> Result<()> {
+        let updated = self
+            .service
+            .update(
+                &entry.issue_id,
+                IssueUpdateInput {
+                    title: Some(issue.title.clone()),
+                    description: Some(description.to_owned()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .context("GraphQL request failed")?;
+        let mut current = label_ids_of(&updated);
+        let before = current.len();
+        for id in label_ids {
+            if !current.contains(id) {
+                current.push(id.clone());
+            }
+        }
+        if current.len() > before {
+            self.service
+                .update(
+                    &entry.issue_id,
+                    IssueUpdateInput {
+                        label_ids: Some(current),
+                        ..Default::default()
+                    },
+                )
+                .await
+                .context("GraphQL request failed")?;
+        }
+        Ok(())
+    }
+}
+
+fn label_ids_of(issue: &IssueDetail) -> Vec<String> {
+    issue
+        .labels
+        .as_ref()
+        .map(|labels| labels.nodes.iter().map(|label| label.id.clone()).collect())
+        .unwrap_or_default()
+}
+
+fn description(repo: &Repo, issue: &GithubIssue) -> String {
+    let link = format!(
+        "Imported from [{}#{}]({})",
+        repo, issue.number, issue.html_url
+    );
+    match issue.body.as_deref().map(str::trim) {
+        Some(body) if !body.is_empty() => format!("{body}\n\n---\n\n{link}"),
+        _ => link,
+    }
+}
+
+/// `FIELD=COLUMN` column mapping for `import csv`.
+pub(crate) fn parse_column_map(raw: &str) -> Result<(IssueField, String), String> {
+    let (field, column) = raw
+        .split_once('=')
+        .ok_or_else(|| format!("expected FIELD=COLUMN, got '{raw}'"))?;
+    if column.trim().is_empty() {
+        return Err(format!("expected FIELD=COLUMN, got '{raw}'"));
+    }
+    Ok((field.parse()?, column.trim().to_owned()))
+}
+
+/// A validated row ready to create, numbered like a spreadsheet (the header is row 1).
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CsvRow {
+    row: usize,
+    #[serde(flatten)]
+    issue: CsvIssue,
+    #[serde(skip)]
+    options: IssueCreateOptions,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CsvCreated {
+    row: usize,
+    identifier: String,
+    title: String,
+}
+
+/// Create an issue per CSV row after validating every row and previewing the result.
+pub(crate) async fn csv(args: ImportCsvArgs) -> Result<()> {
+    let text = fs::read_to_string(&args.file)
+        .with_context(|| format!("failed to read {}", args.file.display()))?;
+    let table = csv::parse(&text)
+        .map_err(|err| LinearError::validation(format!("{}: {}", args.file.display(), err)))?;
+
+    let map = if !args.map.is_empty() {
+        ColumnMap::from_pairs(&args.map, &table.headers).map_err(LinearError::validation)?
+    } else if io::stdin().is_terminal() && !args.json {
+        let headers = table.headers.clone();
+        task::spawn_blocking(move || wizard(&headers)).await??
+    } else {
+        let map = ColumnMap::suggest(&table.headers);
+        if map.column(IssueField::Title).is_none() {
+            return Err(LinearError::validation(format!(
+                "no column looks like a title; pass --map title=<column> (columns: {})",
+                table.headers.join(", ")
+            ))
+            .into());
+        }
+        map
+    };
+
+    // Types are checked before anything is fetched, names once the team is known.
+    let mut issues = Vec::new();
+    let mut errors = Vec::new();
+    let mut total = 0;
+    for (index, cells) in table.rows.iter().enumerate() {
+        // Spreadsheets often export trailing rows of empty cells.
+        if cells.iter().all(|cell| cell.trim().is_empty()) {
+            continue;
+        }
+        let row = index + 2;
+        total += 1;
+        match map.read(cells) {
+            Ok(issue) => issues.push((row, issue)),
+            Err(problems) => errors.extend(
+                problems
+                    .into_iter()
+                    .map(|problem| format!("row {row}: {problem}")),
+            ),
+        }
+    }
+    row_errors(&errors, total - issues.len(), total)?;
+    if issues.is_empty() {
+        return Err(LinearError::validation(format!("{} has no rows", args.file.display())).into());
+    }
+
+    let session = load_session(&args.profile).await?;
+    let client = build_client(&session)?;
+    let service = IssueService::new(client.clone());
+    let team_id = resolve::team_id(&service, &args.team).await?;
+    let names = TeamNames::load(&service, &LabelService::new(client), &team_id, &map).await?;
+
+    let mut rows = Vec::new();
+    for (row, issue) in issues {
+        match names.options(&team_id, &args.team, &issue) {
+            Ok(options) => rows.push(CsvRow {
+                row,
+                issue,
+                options,
+            }),
+            Err(problems) => errors.extend(
+                problems
+                    .into_iter()
+                    .map(|problem| format!("row {row}: {problem}")),
+            ),
+        }
+    }
+    row_errors(&errors, total - rows.len(), total)?;
+
+    let dry_run = client_options().dry_run;
+    if args.json && dry_run {
+        return print_json(&rows);
+    }
+    if !args.json {
+        preview(&map, &rows);
+        println!();
+    }
+    if dry_run {
+        println!(
+            "Dry run: {} issues would be created in {}",
+            rows.len(),
+            args.team
+        );
+        return Ok(());
+    }
+    // Creating issues in bulk is hard to undo, so it asks like a destructive change.
+    args.confirmation
+        .ensure(
+            Impact::Destructive,
+            &format!("create {} issues in {}", rows.len(), args.team),
+        )
+        .await?;
+
+    let mut created = Vec::new();
+    for row in rows {
+        let issue = service.create(row.options).await.with_context(|| {
+            format!(
+                "row {} failed after {} issues were created",
+                row.row,
+                created.len()
+            )
+        })?;
+        if !args.json {
+            println!("Created {:<10} {}", issue.identifier, issue.title);
+        }
+        created.push(CsvCreated {
+            row: row.row,
+            identifier: issue.identifier,
+            title: issue.title,
+        });
+    }
+    if args.json {
+        return print_json(&created);
+    }
+    println!("\n{} issues created in {}", created.len(), args.team);
+    Ok(())
+}
+
+/// Print every row error and fail, so nothing is created from a partly valid file.
+fn row_errors(errors: &[String], invalid: usize, total: usize) -> Result<()> {
+    if errors.is_empty() {
+        return Ok(());
+    }
+    for error in errors {
+        eprintln!("{error}");
+    }
+    Err(LinearError::validation(format!(
+        "{invalid} of {total} rows have errors; nothing was created"
+    ))
+    .into())
+}
+
+/// Ask which column feeds each field, offering the columns matched by name as defaults.
+fn wizard(headers: &[String]) -> Result<ColumnMap> {
+    let mut map = ColumnMap::suggest(headers);
+    println!("Columns:");
+    for (index, header) in headers.iter().enumerate() {
+        println!("  {:>2}. {}", index + 1, header);
+    }
+    println!("Pick a column for each field: a number, Enter for the suggestion, 0 to skip.");
+    for field in IssueField::ALL {
+        loop {
+            let suggestion = map.column(field);
+            let shown = suggestion.map_or_else(
+                || "skip".to_owned(),
+                |index| format!("{}. {}", index + 1, headers[index]),
+            );
+            print!("{} ({}) [{}]: ", field, field.expects(), shown);
+            io::stdout().flush()?;
+            let mut input = String::new();
+            if io::stdin().read_line(&mut input)? == 0 {
+                return Err(LinearError::validation("mapping cancelled").into());
+            }
+            let choice = match input.trim() {
+                "" => suggestion,
+                "0" => None,
+                raw => match raw.parse::<usize>() {
+                    Ok(number) if (1..=headers.len()).contains(&number) => Some(number - 1),
+                    _ => {
+                        println!("  enter a number from 0 to {}", headers.len());
+                        continue;
+                    }
+                },
+            };
+            if field == IssueField::Title && choice.is_none() {
+                println!("  title is required");
+                continue;
+            }
+            map.set(field, choice);
+            break;
+        }
+    }
+    println!();
+    Ok(map)
+}
+
+fn preview(map: &ColumnMap, rows: &[CsvRow]) {
+    let fields: Vec<IssueField> = map
+        .fields()
+        .into_iter()
+        .filter(|field| *field != IssueField::Description)
+        .collect();
+    let mut table = Table::new().column("ROW", 5);
+    for field in &fields {
+        table = match field {
+            IssueField::Title => table.flex_column("TITLE", 16, 60),
+            IssueField::Priority => table.column("PRIORITY", 10),
+            IssueField::Estimate => table.column("ESTIMATE", 8),
+            IssueField::DueDate => table.column("DUE", 10),
+            IssueField::State => table.column("STATE", 16),
+            IssueField::Labels => table.flex_column("LABELS", 8, 30),
+            IssueField::Assignee => table.column("ASSIGNEE", 20),
+            IssueField::Description => table,
+        };
+    }
+    for row in rows {
+        let issue = &row.issue;
+        let mut cells = vec![row.row.to_string()];
+        cells.extend(fields.iter().map(|field| match field {
+            IssueField::Title => issue.title.clone(),
+            IssueField::Priority => issue.priority.map(|p| p.to_string()).unwrap_or_default(),
+            IssueField::Estimate => issue.estimate.map(|e| e.to_string()).unwrap_or_default(),
+            IssueField::DueDate => issue.due_date.map(|d| d.to_string()).unwrap_or_default(),
+            IssueField::State => issue.state.clone().unwrap_or_default(),
+            IssueField::Labels => issue.labels.join(", "),
+            IssueField::Assignee => issue.assignee.clone().unwrap_or_default(),
+            IssueField::Description => String::new(),
+        }));
+        table.row(cells);
+    }
+    table.print();
+}
+
+/// Team states, labels, and members by lowercase name, loaded only for mapped fields.
+#[derive(Default)]
+struct TeamNames {
+    states: HashMap<String, String>,
+    labels: HashMap<String, String>,
+    members: HashMap<String, String>,
+}
+
+impl TeamNames {
+    async fn load(
+        service: &IssueService,
+        labels: &LabelService,
+        team_id: &str,
+        map: &ColumnMap,
+    ) -> Result<Self> {
+        let mut names = TeamNames::default();
+        if map.column(IssueField::State).is_some() {
+            for state in service
+                .workflow_states(team_id)
+                .await
+                .context("GraphQL request failed")?
+            {
+                names.states.insert(state.name.to_lowercase(), state.id);
+            }
+        }
+        if map.column(IssueField::Labels).is_some() {
+            for label in labels
+                .list(team_id)
+                .await
+                .context("GraphQL request failed")?
+            {
+                names.labels.insert(label.name.to_lowercase(), label.id);
+            }
+        }
+        if map.column(IssueField::Assignee).is_some() {
+            for member in service
+                .team_members(team_id)
+                .await
+                .context("GraphQL request failed")?
+            {
+                let keys = [Some(member.name), Some(member.display_name), member.email];
+                for key in keys.into_iter().flatten() {
+                    names.members.insert(key.to_lowercase(), member.id.clone());
+                }
+            }
+        }
+        Ok(names)
+    }
+
+    /// Create options for `issue`, or a message per name the team does not have.
+    fn options(
+        &self,
+        team_id: &str,
+        team: &str,
+        issue: &CsvIssue,
+    ) -> Result<IssueCreateOptions, Vec<String>> {
+        let mut errors = Vec::new();
+        let mut options = IssueCreateOptions::new(team_id, issue.title.clone());
+        options.description = issue.description.clone();
+        options.priority = issue.priority.map(|priority| priority.value());
+        options.estimate = issue.estimate;
+        options.due_date = issue.due_date;
+        if let Some(state) = &issue.state {
+            match self.states.get(&state.to_lowercase()) {
+                Some(id) => options.state_id = Some(id.clone()),
+                None => errors.push(format!(
+                    "state: '{state}' is not a workflow state of {team}"
+                )),
+            }
+        }
+        for label in &issue.labels {
+            match self.labels.get(&label.to_lowercase()) {
+                Some(id) if !options.label_ids.contains(id) => options.label_ids.push(id.clone()),
+                Some(_) => {}
+                None => errors.push(format!("labels: '{label}' is not a label of {team}")),
+            }
+        }
+        if let Some(assignee) = &issue.assignee {
+            match self.members.get(&assignee.to_lowercase()) {
+                Some(id) => options.assignee_id = Some(id.clone()),
+                None => errors.push(format!("assignee: '{assignee}' is not a member of {team}")),
+            }
+        }
+        if errors.is_empty() {
+            Ok(options)
+        } else {
+            Err(errors)
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use linear_core::config::{Config, ConfigLocator, Impact};
+use linear_core::error::LinearError;
+use linear_core::graphql::{IssueSummary, IssueUpdateInput};
+use linear_core::junit::{self, TestSuite};
+use linear_core::priority::Priority;
+use linear_core::query::IssueQuery;
+use linear_core::services::issues::{IssueCreateOptions, IssueService};
+use linear_core::services::labels::LabelService;
+use serde::Serialize;
+use serde_json::json;
+
+use crate::fields::print_json;
+use crate::{resolve, IssueCreateArgs};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Action {
+    Created,
+    Updated,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Filed {
+    suite: String,
+    fingerprint: String,
+    identifier: String,
+    title: String,
+    url: Option<String>,
+    action: Action,
+}
+
+/// File one issue per failing suite in a JUnit report, updating the open issue an earlier run
+/// filed for the same suite. The issue's team, state, assignee, priority, and labels come from
+/// `args`, plus the `junit_label` from `config.toml`.
+pub(crate) async fn file_failures(
+    service: &IssueService,
+    args: &IssueCreateArgs,
+    report: &Path,
+    team_id: String,
+    state_id: Option<String>,
+) -> Result<()> {
+    let text = fs::read_to_string(report)
+        .with_context(|| format!("unable to read {}", report.display()))?;
+    let suites = junit::parse(&text).map_err(|err| {
+        LinearError::validation(format!("{} is not a JUnit report: {err}", report.display()))
+    })?;
+    if suites.is_empty() {
+        if args.json {
+            return print_json(&Vec::<Filed>::new());
+        }
+        println!("No failing tests in {}", report.display());
+        return Ok(());
+    }
+
+    let mut label_ids = args.label_ids.clone();
+    if let Some(name) = Config::load(&ConfigLocator::new()?)?.junit_label {
+        let labels = LabelService::new(service.client().clone());
+        let label_id = resolve::label_id(&labels, &team_id, &name)
+            .await
+            .with_context(|| format!("junit_label '{name}' from config.toml"))?;
+        if !label_ids.contains(&label_id) {
+            label_ids.push(label_id);
+        }
+    }
+
+    args.confirmation
+        .ensure(
+            Impact::Mutation,
+            &format!("file issues for the failing suites in {}", report.display()),
+        )
+        .await?;
+
+    let mut filed = Vec::new();
+    for suite in &suites {
+        let result = match existing(service, &team_id, suite).await? {
+            Some(issue) => update(service, issue, suite, &label_ids).await?,
+            None => create(service, args, suite, &team_id, &state_id, &label_ids).await?,
+        };
+        if !args.json {
+            println!(
+                "{:<8} {:<10} {}",
+                match result.action {
+                    Action::Created => "created",
+                    Action::Updated => "updated",
+                },
+                result.identifier,
+                result.title
+            );
+        }
+        filed.push(result);
+    }
+    if args.json {
+        print_json(&filed)?;
+    }
+    Ok(())
+}
+
+/// The open issue in the team whose description carries the suite's fingerprint. Closed ones
+/// are left alone, so a suite that fails again after its fix gets a fresh issue.
+async fn existing(
+    service: &IssueService,
+    team_id: &str,
+    suite: &TestSuite,
+) -> Result<Option<IssueSummary>> {
+    let query = IssueQuery::new()
+        .team_id(team_id)
+        .open()
+        .raw("description", json!({ "contains": suite.fingerprint() }))
+        .first(1);
+    let page = service
+        .query::<IssueSummary>(&query)
+        .await
+        .context("GraphQL request failed")?;
+    Ok(page.nodes.into_iter().next())
+}
+
+async fn update(
+    service: &IssueService,
+    issue: IssueSummary,
+    suite: &TestSuite,
+    label_ids: &[String],
+) -> Result<Filed> {
+    let mut input = IssueUpdateInput {
+        title: Some(suite.title()),
+        description: Some(suite.description()),
+        ..Default::default()
+    };
+    let mut current: Vec<String> = issue
+        .labels
+        .map(|labels| labels.nodes.into_iter().map(|label| label.id).collect())
+        .unwrap_or_default();
+    let before = current.len();
+    for id in label_ids {
+        if !current.contains(id) {
+            current.push(id.clone());
+        }
+    }
+    if current.len() > before {
+        input.label_ids = Some(current);
+    }
+    let updated = service
+        .update(&issue.id, input)
+        .await
+        .context("GraphQL request failed")?;
+    Ok(Filed {
+        suite: suite.name.clone(),
+        fingerprint: suite.fingerprint(),
+        identifier: updated.identifier,
+        title: updated.title,
+        url: updated.url,
+        action: Action::Updated,
+    })
+}
+
+async fn create(
+    service: &IssueService,
+    args: &IssueCreateArgs,
+    suite: &TestSuite,
+    team_id: &str,
+    state_id: &Option<String>,
+    label_ids: &[String],
+) -> Result<Filed> {
+    let mut options = IssueCreateOptions::new(team_id.to_owned(), suite.title());
+    options.description = Some(suite.description());
+    options.assignee_id = args.assignee_id.clone();
+    options.state_id = state_id.clone();
+    options.label_ids = label_ids.to_vec();
+    options.priority = args.priority.map(Priority::value);
+    let created = service
+        .create(options)
+        .await
+        .context("GraphQL request failed")?;
+    Ok(Filed {
+        suite: suite.name.clone(),
+        fingerprint: suite.fingerprint(),
+        identifier: created.identifier,
+        title: created.title,
+        url: created.url,
+        action: Action::Created,
+    })
+}
@@ -1,30 +1,132 @@
 use std::env;
-
+use std::io;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
+use std::time::Duration;
+
+mod accessible;
+mod activity;
+mod admin;
+mod api;
+mod automate;
+mod backup;
+mod batch;
+mod budget;
+mod cache;
+mod calendar;
+mod clipboard;
+mod color;
+mod comment;
+mod confirm;
+mod daemon;
+mod doctor;
+mod estimate;
+mod export;
+mod get;
+mod git_context;
+mod github;
+mod hook;
+mod i18n;
+mod images;
+mod import;
+mod junit;
+mod notify;
+mod pick;
+mod prefill;
+mod profiles;
+mod remind;
+mod report;
+mod resolve;
+mod restore;
+mod search;
+mod settings;
+mod summary;
+mod team;
+mod time;
 mod tui;
-
-use anyhow::{anyhow, Context, Result};
-use clap::{Args, Parser, Subcommand};
+mod unfurl;
+
+use activity::ActivityFormat;
+use anyhow::{Context, Result};
+use batch::BatchArgs;
+use budget::{BudgetArgs, Meter};
+use chrono::{Local, Utc};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use confirm::ConfirmArgs;
+use crossterm::style::Color;
+use export::{ExportFormat, OutputFormat};
+use fields::{json_line, print_json};
+use futures_util::stream::{self, StreamExt};
+use hook::HookKind;
+use linear_commands::{
+    cycle, fields, hyperlink, label, markdown, project, sort, state, table, user, Mutation,
+};
 use linear_core::auth::{
-    default_redirect_ports, AuthError, AuthManager, CredentialStore, FileCredentialStore,
-    OAuthClient, OAuthConfig,
+    self, default_redirect_ports, Actor, AuthError, AuthManager, AuthSession, CredentialStore,
+    MemoryCredentialStore, OAuthClient, OAuthConfig, TokenType, ACCESS_TOKEN_ENV, API_KEY_ENV,
 };
+use linear_core::backup::BackupEntity;
+use linear_core::calendar::Source as CalendarSource;
+use linear_core::config::{self, Config, ConfigLocator, Impact};
+use linear_core::diff;
+use linear_core::error::{ErrorKind, LinearError};
+use linear_core::extract::{FieldSelection, Separator};
 use linear_core::graphql::{
-    Comment, CycleSummary, CycleUpdateInput, IssueDetail, IssueLabel, IssueLabelCreateInput,
-    IssueLabelUpdateInput, IssueSummary, IssueUpdateInput, LinearGraphqlClient, ProjectCreateInput,
-    ProjectDetail, ProjectSummary, ProjectUpdateInput, TeamSummary, Viewer, WorkflowStateSummary,
+    Comment, DryRunRequest, GraphqlError, IssueDetail, IssueSummary, IssueUpdateInput,
+    LinearGraphqlClient, ResponseCache, SessionTape, UserStatus, UserStatusInput,
+};
+use linear_core::group::{group_issues, GroupBy, IssueGroup};
+use linear_core::import::csv::IssueField;
+use linear_core::notify::rules::IssueFilter;
+use linear_core::notify::{Destination, EventFilter};
+use linear_core::phrase;
+use linear_core::priority::{self, Priority};
+use linear_core::query::IssueQuery;
+use linear_core::services::entities::EntityRef;
+use linear_core::services::issues::{
+    IssueCreateOptions, IssueListResult, IssueQueryOptions, IssueService, IssueSort,
 };
-use linear_core::services::cycles::{CycleQueryOptions, CycleService, CycleSort};
-use linear_core::services::issues::{IssueCreateOptions, IssueQueryOptions, IssueService};
 use linear_core::services::labels::LabelService;
-use linear_core::services::projects::{ProjectQueryOptions, ProjectService, ProjectSort};
-use pulldown_cmark::{Event, Options, Parser as MarkdownParser, Tag, TagEnd};
+use linear_core::services::search::SearchKind;
+use linear_core::share::Snapshot;
+use linear_core::status;
+use linear_core::team_settings::Assignment;
+use linear_core::template::Template;
+use linear_core::web::{self, IssueListFilter};
+use markdown::{markdown_to_text, ImageRef};
+use profiles::{with_workspace, ProfileSelection};
+use report::DigestFormat;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
+use table::{Cell, Table};
 use textwrap::wrap;
 use tokio::task;
 use url::Url;
 
 const DEFAULT_PROFILE: &str = "default";
 
+static CLIENT_OPTIONS: OnceLock<ClientOptions> = OnceLock::new();
+
+/// Profile and actor of the first stored session the command loaded.
+static LOADED_SESSION: OnceLock<(String, Actor)> = OnceLock::new();
+
+/// Global flags that change how every GraphQL client behaves.
+#[derive(Debug, Default)]
+struct ClientOptions {
+    dry_run: bool,
+    record: Option<PathBuf>,
+    replay: Option<PathBuf>,
+    timeout: Option<Duration>,
+    no_cache: bool,
+}
+
+/// Request timeout when neither `--timeout` nor `config.toml` sets one.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+fn client_options() -> &'static ClientOptions {
+    CLIENT_OPTIONS.get_or_init(ClientOptions::default)
+}
+
 #[derive(Parser, Debug)]
 #[command(
     author,
@@ -34,6 +136,40 @@ const DEFAULT_PROFILE: &str = "default";
     arg_required_else_help = true
 )]
 struct Cli {
+    /// Print mutations that would be sent instead of calling the API
+    #[arg(long, global = true)]
+    dry_run: bool,
+    /// Record API requests and responses to a session file (tokens are not stored)
+    #[arg(long, global = true, value_name = "FILE", conflicts_with = "replay")]
+    record: Option<PathBuf>,
+    /// Serve API responses from a recorded session file instead of the network
+    #[arg(long, global = true, value_name = "FILE")]
+    replay: Option<PathBuf>,
+    /// Never emit OSC 8 hyperlinks, even in terminals that support them
+    #[arg(long, global = true)]
+    no_hyperlinks: bool,
+    /// Give up on any API request after this long, e.g. 10s or 500ms (0 disables)
+    #[arg(long, global = true, value_name = "DURATION", value_parser = config::parse_duration)]
+    timeout: Option<Duration>,
+    /// Skip the response cache for team, state, member, label, and template lookups
+    #[arg(long, global = true)]
+    no_cache: bool,
+    /// Print only these fields of --json output, one line per item, e.g. identifier,state.name
+    #[arg(long, global = true, value_name = "PATHS", value_parser = FieldSelection::parse)]
+    fields: Option<FieldSelection>,
+    /// Separator between --fields values: tab or csv
+    #[arg(long, global = true, value_name = "SEP", default_value = "tab", value_parser = str::parse::<Separator>)]
+    field_separator: Separator,
+    /// Wrap descriptions and comments at this many columns (default: the terminal width, or 80
+    /// when output is piped)
+    #[arg(long, global = true, value_name = "COLS", value_parser = clap::value_parser!(u16).range(20..))]
+    width: Option<u16>,
+    /// Language for messages, e.g. de (default: from LC_ALL, LC_MESSAGES, or LANG)
+    #[arg(long, global = true, value_name = "LANG", value_parser = i18n::parse_lang)]
+    lang: Option<String>,
+    /// Screen reader friendly output: no animation, line-by-line prompts, text labels for colors
+    #[arg(long, global = true)]
+    accessible: bool,
     #[command(subcommand)]
     command: Commands,
 }
@@ -64,8 +200,236 @@ enum Commands {
     /// Issue label operations
     #[command(subcommand)]
     Label(LabelCommand),
+    /// Comments across many issues
+    #[command(subcommand)]
+    Comment(CommentCommand),
     /// Launch interactive TUI
     Tui(TuiArgs),
+    /// Diagnose configuration, credentials, connectivity, and terminal support
+    Doctor(DoctorArgs),
+    /// Workspace administration
+    #[command(subcommand)]
+    Admin(AdminCommand),
+    /// Fuzzy-find a recent issue and print its key
+    Pick(PickArgs),
+    /// Search issues, projects, documents, and users
+    Search(SearchArgs),
+    /// Fetch issues, projects, teams, users, and documents named as kind/id in one request
+    Get(GetArgs),
+    /// Feed of issue creations, state changes, and comments, e.g. for standup prep
+    Activity(ActivityArgs),
+    /// Step through unestimated issues and give each a point value
+    Estimate(EstimateArgs),
+    /// Forward activity to Slack, Discord, or webhooks
+    #[command(subcommand)]
+    Notify(NotifyCommand),
+    /// Local rules that assign, comment, prioritise, or move issues on events
+    #[command(subcommand)]
+    Automate(AutomateCommand),
+    /// Reports over open issues
+    #[command(subcommand)]
+    Report(ReportCommand),
+    /// Git hooks that tie commits to the branch's issue
+    #[command(subcommand)]
+    Hook(HookCommand),
+    /// Track time spent on issues locally
+    #[command(subcommand)]
+    Time(TimeCommand),
+    /// Local reminders to come back to an issue, e.g. `linear remind ENG-1 --in 3d`
+    Remind(RemindArgs),
+    /// iCalendar feeds of cycles, project targets, and due dates
+    #[command(subcommand)]
+    Calendar(CalendarCommand),
+    /// Run raw GraphQL against the authenticated endpoint
+    #[command(subcommand)]
+    Api(ApiCommand),
+    /// Bring issues over from other trackers
+    #[command(subcommand)]
+    Import(ImportCommand),
+    /// Save issues, projects, comments, and labels to a dated directory of JSON files
+    Backup(BackupArgs),
+    /// Re-create a backup's labels, projects, issues, and comments in this workspace
+    Restore(RestoreArgs),
+    /// Print a one-line summary of Linear issue URLs or keys, or annotate text piped on stdin
+    Unfurl(UnfurlArgs),
+    /// Keep a signed-in session and warm cache for editor plugins and repeated CLI calls
+    #[command(subcommand)]
+    Daemon(DaemonCommand),
+    /// Change settings in config.toml or the repository's .linear.toml
+    #[command(subcommand)]
+    Config(ConfigCommand),
+    /// Response and offline caches
+    #[command(subcommand)]
+    Cache(CacheCommand),
+}
+
+#[derive(Subcommand, Debug)]
+#[command(subcommand_required = true, arg_required_else_help = true)]
+enum CacheCommand {
+    /// Prefetch teams, states, labels, members, and projects, e.g. from a login hook or CI image
+    Warm(CacheWarmArgs),
+}
+
+#[derive(Args, Debug)]
+struct CacheWarmArgs {
+    /// Profile name for stored credentials
+    #[arg(long, default_value = DEFAULT_PROFILE)]
+    profile: String,
+    /// How long the fetched responses stay fresh; defaults to cache_ttl in config.toml
+    #[arg(long, value_name = "DURATION", value_parser = config::parse_duration)]
+    ttl: Option<Duration>,
+    /// Print what was cached as JSON
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Subcommand, Debug)]
+#[command(subcommand_required = true, arg_required_else_help = true)]
+enum ConfigCommand {
+    /// Set a setting, e.g. `linear config set team ENG --local`
+    Set(ConfigSetArgs),
+}
+
+#[derive(Args, Debug)]
+struct ConfigSetArgs {
+    /// Setting to change
+    #[arg(value_enum)]
+    setting: settings::Setting,
+    /// New value
+    value: String,
+    /// Write to .linear.toml at the top of the current git checkout instead of config.toml
+    #[arg(long)]
+    local: bool,
+}
+
+#[derive(Subcommand, Debug)]
+#[command(subcommand_required = true, arg_required_else_help = true)]
+enum DaemonCommand {
+    /// Serve JSON-RPC on the profile's unix socket until stopped
+    Run(DaemonRunArgs),
+    /// Show whether a daemon is running
+    Status(DaemonStatusArgs),
+    /// Stop the running daemon
+    Stop(DaemonStopArgs),
+}
+
+#[derive(Subcommand, Debug)]
+#[command(subcommand_required = true, arg_required_else_help = true)]
+enum CommentCommand {
+    /// Post a comment rendered from a template on every issue matching a filter
+    Bulk(CommentBulkArgs),
+}
+
+#[derive(Subcommand, Debug)]
+#[command(subcommand_required = true, arg_required_else_help = true)]
+enum NotifyCommand {
+    /// Poll for activity and post matching events to each destination
+    Forward(NotifyForwardArgs),
+    /// Manage rules that run a local command when an issue newly matches a filter
+    #[command(subcommand)]
+    Rules(NotifyRulesCommand),
+    /// Poll every rule and run its command for issues that newly match
+    Watch(NotifyWatchArgs),
+}
+
+#[derive(Subcommand, Debug)]
+#[command(subcommand_required = true, arg_required_else_help = true)]
+enum NotifyRulesCommand {
+    /// Add a rule
+    Add(NotifyRulesAddArgs),
+    /// List rules
+    List(NotifyRulesListArgs),
+    /// Remove a rule by name
+    Remove(NotifyRulesRemoveArgs),
+}
+
+#[derive(Subcommand, Debug)]
+#[command(subcommand_required = true, arg_required_else_help = true)]
+enum AutomateCommand {
+    /// Match rules against recent activity and stale issues, then apply their actions
+    Run(AutomateRunArgs),
+}
+
+#[derive(Subcommand, Debug)]
+#[command(subcommand_required = true, arg_required_else_help = true)]
+enum ReportCommand {
+    /// Open issues with no updates for a number of days, optionally nudging each with a comment
+    Stale(ReportStaleArgs),
+    /// States over their WIP limit and issues past their state's age threshold, exiting 2 on any
+    Wip(ReportWipArgs),
+    /// Changes and comments on the issues you subscribe to, grouped per issue for mail
+    Digest(ReportDigestArgs),
+}
+
+#[derive(Subcommand, Debug)]
+#[command(subcommand_required = true, arg_required_else_help = true)]
+enum HookCommand {
+    /// Install a hook into the current repository
+    Install(HookInstallArgs),
+    /// Run a hook (called by the installed script)
+    Run(HookRunArgs),
+}
+
+#[derive(Subcommand, Debug)]
+#[command(subcommand_required = true, arg_required_else_help = true)]
+enum TimeCommand {
+    /// Start a timer on an issue (stops the running one)
+    Start(TimeStartArgs),
+    /// Stop the running timer
+    Stop(TimeStopArgs),
+    /// Show the running timer
+    Status(TimeStatusArgs),
+    /// Summarise tracked time per issue
+    Report(TimeReportArgs),
+}
+
+#[derive(Args, Debug)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+struct RemindArgs {
+    #[command(subcommand)]
+    command: Option<RemindCommand>,
+    #[command(flatten)]
+    set: RemindSetArgs,
+}
+
+#[derive(Subcommand, Debug)]
+enum RemindCommand {
+    /// Show reminders that have not come up yet
+    List(RemindListArgs),
+    /// Drop a reminder
+    Cancel(RemindCancelArgs),
+}
+
+#[derive(Subcommand, Debug)]
+#[command(subcommand_required = true, arg_required_else_help = true)]
+enum CalendarCommand {
+    /// Write an ICS file, or serve it over HTTP for calendar subscriptions
+    Export(CalendarExportArgs),
+}
+
+#[derive(Subcommand, Debug)]
+#[command(subcommand_required = true, arg_required_else_help = true)]
+enum ApiCommand {
+    /// Execute a query or mutation and pretty-print the response data
+    Query(ApiQueryArgs),
+    /// Estimate an operation's rate-limit complexity without sending it
+    Estimate(ApiEstimateArgs),
+}
+
+#[derive(Subcommand, Debug)]
+#[command(subcommand_required = true, arg_required_else_help = true)]
+enum ImportCommand {
+    /// Import open GitHub issues into a team; re-runs update issues imported before
+    Github(ImportGithubArgs),
+    /// Create issues from CSV rows, mapping columns to fields; previews before creating
+    Csv(ImportCsvArgs),
+}
+
+#[derive(Subcommand, Debug)]
+#[command(subcommand_required = true, arg_required_else_help = true)]
+enum AdminCommand {
+    /// Create or update team labels, workflow states, and templates from a spec file
+    Apply(AdminApplyArgs),
 }
 
 #[derive(Subcommand, Debug)]
@@ -81,11 +445,45 @@ enum AuthCommand {
 #[command(subcommand_required = true, arg_required_else_help = true)]
 enum UserCommand {
     /// Show the current authenticated user (viewer)
-    Me(MeArgs),
+    Me(OnProfile<user::MeArgs>),
+    /// Set or clear your custom status
+    SetStatus(SetStatusArgs),
+    /// Show your custom status, or that of teammates matching a name or email
+    Status(UserStatusArgs),
+}
+
+#[derive(Args, Debug)]
+struct SetStatusArgs {
+    /// Emoji shortcode or emoji, e.g. :palm_tree:
+    #[arg(long)]
+    emoji: Option<String>,
+    /// Status text, e.g. "On PTO"
+    #[arg(long)]
+    text: Option<String>,
+    /// Clear the status at this time: a duration (2h, 3d), a date (local midnight), or an
+    /// RFC 3339 time
+    #[arg(long, value_name = "WHEN")]
+    until: Option<String>,
+    /// Remove the current status
+    #[arg(long, conflicts_with_all = ["emoji", "text", "until"])]
+    clear: bool,
+    /// Profile name for stored credentials
+    #[arg(long, default_value = DEFAULT_PROFILE)]
+    profile: String,
+    #[command(flatten)]
+    confirmation: ConfirmArgs,
+    /// Output raw JSON
+    #[arg(long)]
+    json: bool,
 }
 
 #[derive(Args, Debug)]
-struct MeArgs {
+struct UserStatusArgs {
+    /// Part of a teammate's name, display name, or email (default: you)
+    name: Option<String>,
+    /// Maximum number of matching users to show
+    #[arg(long, default_value_t = 10)]
+    limit: usize,
     /// Profile name for stored credentials
     #[arg(long, default_value = DEFAULT_PROFILE)]
     profile: String,
@@ -111,63 +509,110 @@ enum IssueCommand {
     Delete(IssueDeleteArgs),
     /// Add a comment to an issue
     Comment(IssueCommentArgs),
+    /// Merge a duplicate issue into another issue
+    Merge(IssueMergeArgs),
+    /// Export every matching issue (NDJSON by default, streamed page by page)
+    Export(IssueExportArgs),
 }
 
 #[derive(Subcommand, Debug)]
 #[command(subcommand_required = true, arg_required_else_help = true)]
 enum TeamCommand {
     /// List all accessible teams
-    List(TeamListArgs),
+    List(OnProfiles<linear_commands::team::ListArgs>),
+    /// Show a team's cycle, estimation, triage, and template settings, or change them with --set
+    Settings(TeamSettingsArgs),
 }
 
 #[derive(Subcommand, Debug)]
 #[command(subcommand_required = true, arg_required_else_help = true)]
 enum StateCommand {
     /// List workflow states for a team
-    List(StateListArgs),
+    List(OnProfile<state::ListArgs>),
 }
 
 #[derive(Subcommand, Debug)]
 #[command(subcommand_required = true, arg_required_else_help = true)]
 enum ProjectCommand {
     /// List projects
-    List(ProjectListArgs),
+    List(OnProfiles<project::ListArgs>),
     /// Create a project
-    Create(ProjectCreateArgs),
+    Create(Confirmed<project::CreateArgs>),
     /// Update a project
-    Update(ProjectUpdateArgs),
+    Update(Confirmed<project::UpdateArgs>),
     /// Archive or restore a project
-    Archive(ProjectArchiveArgs),
+    Archive(Confirmed<project::ArchiveArgs>),
 }
 
 #[derive(Subcommand, Debug)]
 #[command(subcommand_required = true, arg_required_else_help = true)]
 enum CycleCommand {
     /// List team cycles
-    List(CycleListArgs),
+    List(OnProfile<cycle::ListArgs>),
     /// Update a cycle
-    Update(CycleUpdateArgs),
+    Update(Confirmed<cycle::UpdateArgs>),
 }
 
 #[derive(Subcommand, Debug)]
 #[command(subcommand_required = true, arg_required_else_help = true)]
 enum LabelCommand {
     /// List labels for a team
-    List(LabelListArgs),
+    List(OnProfile<label::ListArgs>),
     /// Create a label
-    Create(LabelCreateArgs),
+    Create(Confirmed<label::CreateArgs>),
     /// Update a label
-    Update(LabelUpdateArgs),
+    Update(Confirmed<label::UpdateArgs>),
 }
 
+/// A `linear_commands` command run against `--profile`.
 #[derive(Args, Debug)]
-struct IssueListArgs {
+struct OnProfile<A: Args> {
     /// Profile name for stored credentials
     #[arg(long, default_value = DEFAULT_PROFILE)]
     profile: String,
-    /// Maximum number of issues to return
-    #[arg(long, default_value_t = 20)]
-    limit: usize,
+    #[command(flatten)]
+    command: A,
+}
+
+/// A `linear_commands` read command that can also fan out over several profiles.
+#[derive(Args, Debug)]
+struct OnProfiles<A: Args> {
+    /// Profile name for stored credentials
+    #[arg(long, default_value = DEFAULT_PROFILE)]
+    profile: String,
+    #[command(flatten)]
+    workspaces: ProfileSelection,
+    #[command(flatten)]
+    command: A,
+}
+
+/// A mutating `linear_commands` command, confirmed according to `confirm` before it runs.
+#[derive(Args, Debug)]
+struct Confirmed<A: Args> {
+    /// Profile name for stored credentials
+    #[arg(long, default_value = DEFAULT_PROFILE)]
+    profile: String,
+    #[command(flatten)]
+    confirmation: ConfirmArgs,
+    #[command(flatten)]
+    command: A,
+}
+
+impl<A: Args + Mutation> Confirmed<A> {
+    /// The profile's client, once the user agreed to the command.
+    async fn client(&self) -> Result<LinearGraphqlClient> {
+        let client = profile_client(&self.profile).await?;
+        self.confirmation
+            .ensure(self.command.impact(), &self.command.action())
+            .await?;
+        Ok(client)
+    }
+}
+
+// Also the `filter` param of the daemon's `list` method.
+#[derive(Args, Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct IssueFilterArgs {
     /// Filter by team key (e.g. ENG)
     #[arg(long = "team-key")]
     team_key: Option<String>,
@@ -192,15 +637,118 @@ struct IssueListArgs {
     /// Match issues whose title contains the term
     #[arg(long = "contains")]
     contains: Option<String>,
-    /// Output raw JSON
+    /// Filter described in plain English, e.g. 'urgent bugs assigned to me updated this week'
+    #[arg(long = "query", value_name = "TEXT")]
+    query: Option<String>,
+}
+
+#[derive(Args, Debug)]
+struct IssueListArgs {
+    /// Profile name for stored credentials
+    #[arg(long, default_value = DEFAULT_PROFILE)]
+    profile: String,
+    #[command(flatten)]
+    workspaces: ProfileSelection,
+    /// Maximum number of issues to return (0 = no limit with --stream); the page size with
+    /// --page and --all
+    #[arg(long, default_value_t = 20)]
+    limit: usize,
+    /// Cursor for pagination, from a previous page's end_cursor
+    #[arg(long, conflicts_with_all = ["all_profiles", "profiles", "page", "stream"])]
+    after: Option<String>,
+    /// Show this page of --limit issues (1 is the first)
+    #[arg(
+        long,
+        value_parser = clap::value_parser!(u32).range(1..),
+        conflicts_with_all = ["all", "stream"]
+    )]
+    page: Option<u32>,
+    /// Follow pagination and list every matching issue
+    #[arg(long, conflicts_with = "stream")]
+    all: bool,
+    #[command(flatten)]
+    filter: IssueFilterArgs,
+    /// Sort results (updated|created|priority) with optional :asc/:desc
+    #[arg(long, default_value = "updated:desc")]
+    sort: String,
+    /// Print the equivalent Linear web app URL instead of listing issues
+    #[arg(
+        long,
+        conflicts_with_all = ["all_profiles", "profiles", "query", "after", "page", "all"]
+    )]
+    as_url: bool,
+    /// Print how --query was read and the resulting filter JSON instead of listing issues
+    #[arg(long, requires = "query", conflicts_with_all = ["all_profiles", "profiles"])]
+    explain: bool,
+    /// Open the web app URL in the browser (with --as-url)
+    #[arg(long, requires = "as_url")]
+    open: bool,
+    /// Output format; markdown and html write a shareable snapshot of the list
+    #[arg(long, visible_alias = "format", value_enum, default_value_t = OutputFormat::Table, conflicts_with = "json")]
+    output: OutputFormat,
+    /// Follow pagination and print each page as it arrives (with --output ndjson)
+    #[arg(long, conflicts_with_all = ["all_profiles", "profiles"])]
+    stream: bool,
+    /// Split the list into sections: state, assignee, project, or priority
+    #[arg(
+        long,
+        value_name = "FIELD",
+        conflicts_with_all = ["stream", "as_url", "all_profiles", "profiles"]
+    )]
+    group_by: Option<GroupBy>,
+    /// Print each issue through a template, e.g. '{{identifier}} [{{state.name}}] {{title}}'
+    #[arg(
+        long,
+        value_parser = Template::parse,
+        conflicts_with_all = ["json", "output", "stream", "as_url", "group_by", "all_profiles", "profiles"]
+    )]
+    template: Option<Template>,
+    /// Output raw JSON (same as --output json)
     #[arg(long)]
     json: bool,
 }
 
+#[derive(Args, Debug)]
+struct IssueExportArgs {
+    /// Profile name for stored credentials
+    #[arg(long, default_value = DEFAULT_PROFILE)]
+    profile: String,
+    /// Stop after this many issues (default: all matching issues)
+    #[arg(long)]
+    limit: Option<usize>,
+    #[command(flatten)]
+    filter: IssueFilterArgs,
+    /// Output format; ndjson streams one issue per line, json buffers a single array, org and
+    /// taskwarrior target personal task managers
+    #[arg(long, visible_alias = "format", value_enum, default_value_t = ExportFormat::Ndjson)]
+    output: ExportFormat,
+    #[command(flatten)]
+    budget: BudgetArgs,
+}
+
 #[derive(Args, Debug)]
 struct IssueViewArgs {
-    /// Issue key (e.g. ENG-123)
-    key: String,
+    /// Issue keys, URLs, or numbers in the default team (e.g. ENG-123 or 123); defaults to the
+    /// key in the current git branch name
+    #[arg(value_name = "KEY", value_parser = settings::parse_issue_key)]
+    keys: Vec<String>,
+    /// Preview embedded images inline (kitty or iTerm2 image protocol)
+    #[arg(long)]
+    images: bool,
+    /// Also put the issue's URL, key, or git branch name on the clipboard (a single key only)
+    #[arg(long, value_enum, value_name = "WHAT")]
+    copy: Option<CopyTarget>,
+    /// Maximum number of issues fetched concurrently when several keys are given
+    #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u16).range(1..=32))]
+    concurrency: u16,
+    /// Print each issue through a template instead of the detail view, e.g.
+    /// '{{identifier}} {{title}} ({{comments.nodes | length}} comments)'
+    #[arg(long, value_parser = Template::parse, conflicts_with_all = ["json", "images"])]
+    template: Option<Template>,
+    /// Add a summary of the description and comments, written by `summarize_command` from
+    /// config.toml (also `summary` in --json and --template)
+    #[arg(long)]
+    summary: bool,
     /// Profile name for stored credentials
     #[arg(long, default_value = DEFAULT_PROFILE)]
     profile: String,
@@ -209,23 +757,55 @@ struct IssueViewArgs {
     json: bool,
 }
 
+/// Issue value `issue view --copy` puts on the clipboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum CopyTarget {
+    Url,
+    Key,
+    Branch,
+}
+
 #[derive(Args, Debug)]
 struct IssueCreateArgs {
     /// Profile name for stored credentials
     #[arg(long, default_value = DEFAULT_PROFILE)]
     profile: String,
-    /// Team key/name/id for the issue (resolved automatically)
-    #[arg(long = "team", required_unless_present = "team_id")]
+    #[command(flatten)]
+    confirmation: ConfirmArgs,
+    /// Post as the OAuth app instead of yourself (needs `linear auth login --as-app`)
+    #[arg(long)]
+    as_app: bool,
+    /// Team key/name/id for the issue (resolved automatically); defaults to a copied Linear
+    /// issue's team
+    #[arg(long = "team")]
     team: Option<String>,
     /// Explicit team id for the issue
-    #[arg(long = "team-id", required_unless_present = "team")]
+    #[arg(long = "team-id")]
     team_id: Option<String>,
-    /// Issue title
-    #[arg(long)]
-    title: String,
-    /// Issue description (Markdown supported)
+    /// Issue title; defaults to the source issue's with --from-url/--from-clipboard
+    #[arg(long, required_unless_present_any = ["from_url", "from_clipboard", "from_junit"])]
+    title: Option<String>,
+    /// Issue description (Markdown supported); replaces the source issue's body
     #[arg(long)]
     description: Option<String>,
+    /// Copy the title and body of a Linear or GitHub issue URL, linking back to it
+    #[arg(
+        long = "from-url",
+        value_name = "URL",
+        conflicts_with = "from_clipboard"
+    )]
+    from_url: Option<String>,
+    /// Like --from-url, taking the first URL on the system clipboard
+    #[arg(long = "from-clipboard")]
+    from_clipboard: bool,
+    /// File an issue per failing suite in a JUnit XML report, updating the open issue an
+    /// earlier run filed for the same suite
+    #[arg(
+        long = "from-junit",
+        value_name = "REPORT",
+        conflicts_with_all = ["from_url", "from_clipboard", "title", "description"]
+    )]
+    from_junit: Option<PathBuf>,
     /// Assign to a user by id
     #[arg(long = "assignee-id")]
     assignee_id: Option<String>,
@@ -238,9 +818,9 @@ struct IssueCreateArgs {
     /// Apply label ids (repeatable)
     #[arg(long = "label-id")]
     label_ids: Vec<String>,
-    /// Priority (0-4)
-    #[arg(long, value_parser = clap::value_parser!(i32).range(0..=4))]
-    priority: Option<i32>,
+    /// Priority: urgent, high, medium, low, none (or 0-4)
+    #[arg(long)]
+    priority: Option<Priority>,
     /// Output raw JSON instead of formatted text
     #[arg(long)]
     json: bool,
@@ -248,11 +828,17 @@ struct IssueCreateArgs {
 
 #[derive(Args, Debug)]
 struct IssueUpdateArgs {
-    /// Issue key (e.g. ENG-123)
-    key: String,
+    /// Issue key, URL, or number in the default team (e.g. ENG-123); defaults to the key in the
+    /// current git branch name
+    #[arg(conflicts_with = "stdin_keys", value_parser = settings::parse_issue_key)]
+    key: Option<String>,
+    #[command(flatten)]
+    batch: BatchArgs,
     /// Profile name for stored credentials
     #[arg(long, default_value = DEFAULT_PROFILE)]
     profile: String,
+    #[command(flatten)]
+    confirmation: ConfirmArgs,
     /// New title
     #[arg(long)]
     title: Option<String>,
@@ -274,12 +860,15 @@ struct IssueUpdateArgs {
     /// Clear all labels on update
     #[arg(long)]
     clear_labels: bool,
-    /// Set issue priority (0-4)
-    #[arg(long, value_parser = clap::value_parser!(i32).range(0..=4))]
-    priority: Option<i32>,
+    /// Set issue priority: urgent, high, medium, low, none (or 0-4)
+    #[arg(long)]
+    priority: Option<Priority>,
     /// Attach to project id
     #[arg(long = "project-id")]
     project_id: Option<String>,
+    /// Show the title/description diff and ask before applying it, whatever the confirm policy
+    #[arg(long, conflicts_with_all = ["stdin_keys", "yes"])]
+    confirm: bool,
     /// Output raw JSON detail
     #[arg(long)]
     json: bool,
@@ -287,11 +876,20 @@ struct IssueUpdateArgs {
 
 #[derive(Args, Debug)]
 struct IssueCloseArgs {
-    /// Issue key (e.g. ENG-123)
-    key: String,
+    /// Issue key, URL, or number in the default team (e.g. ENG-123)
+    #[arg(
+        required_unless_present = "stdin_keys",
+        conflicts_with = "stdin_keys",
+        value_parser = settings::parse_issue_key
+    )]
+    key: Option<String>,
+    #[command(flatten)]
+    batch: BatchArgs,
     /// Profile name for stored credentials
     #[arg(long, default_value = DEFAULT_PROFILE)]
     profile: String,
+    #[command(flatten)]
+    confirmation: ConfirmArgs,
     /// Restore instead of archiving
     #[arg(long)]
     restore: bool,
@@ -302,272 +900,764 @@ struct IssueCloseArgs {
 
 #[derive(Args, Debug)]
 struct IssueDeleteArgs {
-    /// Issue key (e.g. ENG-123)
-    key: String,
+    /// Issue key, URL, or number in the default team (e.g. ENG-123)
+    #[arg(
+        required_unless_present = "stdin_keys",
+        conflicts_with = "stdin_keys",
+        value_parser = settings::parse_issue_key
+    )]
+    key: Option<String>,
+    #[command(flatten)]
+    batch: BatchArgs,
     /// Profile name for stored credentials
     #[arg(long, default_value = DEFAULT_PROFILE)]
     profile: String,
-    /// Confirm deletion without prompt
-    #[arg(long = "yes")]
-    yes: bool,
+    #[command(flatten)]
+    confirmation: ConfirmArgs,
 }
 
 #[derive(Args, Debug)]
 struct IssueCommentArgs {
-    /// Issue key (e.g. ENG-123)
-    key: String,
+    /// Issue key, URL, or number in the default team (e.g. ENG-123); defaults to the key in the
+    /// current git branch name
+    #[arg(conflicts_with = "stdin_keys", value_parser = settings::parse_issue_key)]
+    key: Option<String>,
+    #[command(flatten)]
+    batch: BatchArgs,
     /// Comment body (markdown supported)
     #[arg(long)]
     body: String,
     /// Profile name for stored credentials
     #[arg(long, default_value = DEFAULT_PROFILE)]
     profile: String,
+    #[command(flatten)]
+    confirmation: ConfirmArgs,
+    /// Post as the OAuth app instead of yourself (needs `linear auth login --as-app`)
+    #[arg(long)]
+    as_app: bool,
     /// Output raw JSON
     #[arg(long)]
     json: bool,
 }
 
 #[derive(Args, Debug)]
-struct TuiArgs {
+struct IssueMergeArgs {
+    /// Key, URL, or number of the duplicate issue (e.g. ENG-124)
+    #[arg(value_parser = settings::parse_issue_key)]
+    key: String,
+    /// Key, URL, or number of the issue to keep
+    #[arg(long, value_parser = settings::parse_issue_key)]
+    into: String,
+    /// Re-parent the duplicate's sub-issues under the kept issue
+    #[arg(long)]
+    move_sub_issues: bool,
     /// Profile name for stored credentials
     #[arg(long, default_value = DEFAULT_PROFILE)]
     profile: String,
+    #[command(flatten)]
+    confirmation: ConfirmArgs,
+    /// Output raw JSON
+    #[arg(long)]
+    json: bool,
 }
 
 #[derive(Args, Debug)]
-struct TeamListArgs {
+struct TuiArgs {
     /// Profile name for stored credentials
     #[arg(long, default_value = DEFAULT_PROFILE)]
     profile: String,
-    /// Output raw JSON
-    #[arg(long)]
-    json: bool,
 }
 
 #[derive(Args, Debug)]
-struct StateListArgs {
+struct CalendarExportArgs {
+    /// Team key, name, or id (default: every team)
+    #[arg(long)]
+    team: Option<String>,
+    /// Dates to include
+    #[arg(long, value_delimiter = ',', default_value = "cycles,projects,issues")]
+    include: Vec<CalendarSource>,
+    /// Keep events that ended up to this long ago
+    #[arg(long, default_value = "30d", value_parser = config::parse_duration)]
+    past: Duration,
+    /// Write the feed to this file instead of stdout
+    #[arg(long, value_name = "PATH", conflicts_with = "serve")]
+    out: Option<PathBuf>,
+    /// Serve the feed over HTTP until interrupted
+    #[arg(long)]
+    serve: bool,
+    /// Address to listen on with --serve
+    #[arg(long, default_value = "127.0.0.1:8765", requires = "serve")]
+    listen: String,
+    /// How long --serve reuses a generated feed before fetching again
+    #[arg(long, default_value = "5m", value_parser = config::parse_duration, requires = "serve")]
+    refresh: Duration,
     /// Profile name for stored credentials
     #[arg(long, default_value = DEFAULT_PROFILE)]
     profile: String,
-    /// Team identifier (key, name, or id)
-    #[arg(long = "team")]
-    team: String,
-    /// Output raw JSON
-    #[arg(long)]
-    json: bool,
 }
 
 #[derive(Args, Debug)]
-struct ProjectListArgs {
+struct ApiQueryArgs {
+    /// File holding the GraphQL document (`-` reads stdin)
+    #[arg(
+        long,
+        value_name = "PATH",
+        required_unless_present = "raw",
+        conflicts_with = "raw"
+    )]
+    file: Option<PathBuf>,
+    /// GraphQL document given inline, e.g. '{ viewer { id name } }'
+    #[arg(long, value_name = "DOCUMENT")]
+    raw: Option<String>,
+    /// Variable as KEY=VALUE, converted to the type the document declares (repeatable)
+    #[arg(long = "var", value_name = "KEY=VALUE", value_parser = api::parse_var)]
+    vars: Vec<(String, String)>,
+    /// Operation to run when the document defines several
+    #[arg(long, value_name = "NAME")]
+    operation: Option<String>,
     /// Profile name for stored credentials
     #[arg(long, default_value = DEFAULT_PROFILE)]
     profile: String,
-    /// Maximum projects to return
-    #[arg(long, default_value_t = 20)]
-    limit: usize,
-    /// Cursor for pagination
-    #[arg(long)]
-    after: Option<String>,
-    /// Filter by project state
-    #[arg(long)]
-    state: Option<String>,
-    /// Filter by project status
-    #[arg(long)]
-    status: Option<String>,
-    /// Filter by team id
-    #[arg(long = "team-id")]
-    team_id: Option<String>,
-    /// Sort results (updated|created|target) with optional :asc/:desc
-    #[arg(long, default_value = "updated:desc")]
-    sort: String,
+}
+
+#[derive(Args, Debug)]
+struct ApiEstimateArgs {
+    /// File holding the GraphQL document (`-` reads stdin)
+    #[arg(
+        long,
+        value_name = "PATH",
+        required_unless_present = "raw",
+        conflicts_with = "raw"
+    )]
+    file: Option<PathBuf>,
+    /// GraphQL document given inline, e.g. '{ viewer { id name } }'
+    #[arg(long, value_name = "DOCUMENT")]
+    raw: Option<String>,
+    /// Variable as KEY=VALUE; page sizes such as $first count toward the estimate (repeatable)
+    #[arg(long = "var", value_name = "KEY=VALUE", value_parser = api::parse_var)]
+    vars: Vec<(String, String)>,
+    /// Operation to estimate when the document defines several
+    #[arg(long, value_name = "NAME")]
+    operation: Option<String>,
+    /// How many times the operation will be sent, e.g. the pages a script fetches
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+    requests: u64,
     /// Output raw JSON
     #[arg(long)]
     json: bool,
 }
 
 #[derive(Args, Debug)]
-struct ProjectCreateArgs {
-    /// Profile name for stored credentials
-    #[arg(long, default_value = DEFAULT_PROFILE)]
-    profile: String,
-    /// Project name
+struct ImportGithubArgs {
+    /// GitHub repository as owner/name
+    #[arg(long, value_name = "OWNER/NAME", value_parser = github::parse_repo)]
+    repo: github::Repo,
+    /// Team key/name/id to create the issues in
     #[arg(long)]
-    name: String,
-    /// Project description
+    team: String,
+    /// Map a GitHub label to a differently named Linear label, e.g. bugs=Bug (repeatable);
+    /// other labels apply when a team label has the same name
+    #[arg(long = "label-map", value_name = "GITHUB=LINEAR", value_parser = import::parse_label_map)]
+    label_map: Vec<(String, String)>,
+    /// Copy GitHub comments too, each once
     #[arg(long)]
-    description: Option<String>,
-    /// Project state
+    comments: bool,
+    /// Import at most this many issues, oldest first
     #[arg(long)]
-    state: Option<String>,
-    /// Start date (YYYY-MM-DD)
-    #[arg(long = "start-date")]
-    start_date: Option<String>,
-    /// Target date (YYYY-MM-DD)
-    #[arg(long = "target-date")]
-    target_date: Option<String>,
-    /// Lead id
-    #[arg(long = "lead-id")]
-    lead_id: Option<String>,
-    /// Associate teams (repeatable)
-    #[arg(long = "team-id")]
-    team_ids: Vec<String>,
-    /// Output raw JSON
+    limit: Option<usize>,
+    /// Profile name for stored credentials; the import ledger is kept per profile
+    #[arg(long, default_value = DEFAULT_PROFILE)]
+    profile: String,
+    /// Output raw JSON instead of formatted text
     #[arg(long)]
     json: bool,
 }
 
 #[derive(Args, Debug)]
-struct ProjectUpdateArgs {
-    /// Project id
+struct BackupArgs {
+    /// Directory that holds the dated backups
+    #[arg(long, default_value = "backup")]
+    out: PathBuf,
+    /// Entities to save (comma separated: issues,projects,comments,labels; default all)
+    #[arg(long, value_delimiter = ',')]
+    include: Vec<BackupEntity>,
+    /// Only save records updated since the latest backup in --out
     #[arg(long)]
-    id: String,
+    incremental: bool,
+    /// Compress each file with gzip
+    #[arg(long)]
+    compress: bool,
     /// Profile name for stored credentials
     #[arg(long, default_value = DEFAULT_PROFILE)]
     profile: String,
-    /// New name
-    #[arg(long)]
-    name: Option<String>,
-    /// New description
-    #[arg(long)]
-    description: Option<String>,
-    /// State
-    #[arg(long)]
-    state: Option<String>,
-    /// Start date (YYYY-MM-DD)
-    #[arg(long = "start-date")]
-    start_date: Option<String>,
-    /// Target date (YYYY-MM-DD)
-    #[arg(long = "target-date")]
-    target_date: Option<String>,
-    /// Replace teams (repeatable)
-    #[arg(long = "team-id")]
-    team_ids: Vec<String>,
-    /// Lead id
-    #[arg(long = "lead-id")]
-    lead_id: Option<String>,
-    /// Output raw JSON
+    /// Print the manifest as JSON
     #[arg(long)]
     json: bool,
 }
 
 #[derive(Args, Debug)]
-struct ProjectArchiveArgs {
-    /// Project id
-    #[arg(long)]
-    id: String,
+struct GetArgs {
+    /// Entities as kind/id, e.g. issue/ENG-123, project/<id>, team/ENG, user/me, document/<id>
+    #[arg(value_name = "KIND/ID", required = true, value_parser = get::parse_entity)]
+    entities: Vec<EntityRef>,
     /// Profile name for stored credentials
     #[arg(long, default_value = DEFAULT_PROFILE)]
     profile: String,
-    /// Restore instead of archiving
-    #[arg(long)]
-    restore: bool,
-    /// Output raw JSON
+    /// Print one JSON object keyed by kind/id
     #[arg(long)]
     json: bool,
 }
 
 #[derive(Args, Debug)]
-struct CycleListArgs {
+struct UnfurlArgs {
+    /// Linear issue URLs, keys, or numbers in the default team, e.g.
+    /// https://linear.app/acme/issue/ENG-123, ENG-123, or 123
+    #[arg(value_name = "URL|KEY", conflicts_with = "stdin", value_parser = settings::parse_issue_key)]
+    references: Vec<String>,
+    /// Copy stdin to stdout, adding each referenced issue's title, state, and assignee after it
+    #[arg(long)]
+    stdin: bool,
     /// Profile name for stored credentials
     #[arg(long, default_value = DEFAULT_PROFILE)]
     profile: String,
-    /// Team id to filter
-    #[arg(long = "team-id")]
-    team_id: Option<String>,
-    /// Include only cycles in state
-    #[arg(long)]
-    state: Option<String>,
-    /// Sort field (start|end) with optional :asc/:desc
-    #[arg(long, default_value = "start:desc")]
-    sort: String,
-    /// Maximum cycles to return
-    #[arg(long, default_value_t = 20)]
-    limit: usize,
-    /// Cursor for pagination
-    #[arg(long)]
-    after: Option<String>,
-    /// Output raw JSON
+    /// Print the issues as JSON
     #[arg(long)]
     json: bool,
 }
 
 #[derive(Args, Debug)]
-struct CycleUpdateArgs {
-    /// Cycle id
-    #[arg(long)]
-    id: String,
+struct DaemonRunArgs {
     /// Profile name for stored credentials
     #[arg(long, default_value = DEFAULT_PROFILE)]
     profile: String,
-    /// New name
-    #[arg(long)]
-    name: Option<String>,
-    /// Start date (YYYY-MM-DD)
-    #[arg(long = "start-date")]
-    start_date: Option<String>,
-    /// End date (YYYY-MM-DD)
-    #[arg(long = "end-date")]
-    end_date: Option<String>,
-    /// Cycle state
-    #[arg(long)]
-    state: Option<String>,
-    /// Output raw JSON
-    #[arg(long)]
-    json: bool,
+    /// How long list, view, and search results are served from the cache (0s disables it)
+    #[arg(long, default_value = "30s", value_parser = config::parse_duration)]
+    cache_ttl: Duration,
+    /// Exit after this long without a request (default: run until stopped)
+    #[arg(long, value_name = "DURATION", value_parser = config::parse_duration)]
+    idle_timeout: Option<Duration>,
+    /// How often to check for changed issues while a TUI is subscribed (0s only forwards
+    /// changes made through this daemon)
+    #[arg(long, default_value = "30s", value_parser = config::parse_duration)]
+    watch_interval: Duration,
 }
 
 #[derive(Args, Debug)]
-struct LabelListArgs {
+struct DaemonStatusArgs {
     /// Profile name for stored credentials
     #[arg(long, default_value = DEFAULT_PROFILE)]
     profile: String,
-    /// Team id
-    #[arg(long = "team-id")]
-    team_id: String,
     /// Output raw JSON
     #[arg(long)]
     json: bool,
 }
 
 #[derive(Args, Debug)]
-struct LabelCreateArgs {
+struct DaemonStopArgs {
     /// Profile name for stored credentials
     #[arg(long, default_value = DEFAULT_PROFILE)]
     profile: String,
-    /// Team id
-    #[arg(long = "team-id")]
-    team_id: String,
-    /// Label name
-    #[arg(long)]
-    name: String,
-    /// Description
-    #[arg(long)]
-    description: Option<String>,
-    /// Hex color (#RGB/ #RRGGBB)
-    #[arg(long)]
-    color: Option<String>,
-    /// Output raw JSON
-    #[arg(long)]
-    json: bool,
 }
 
 #[derive(Args, Debug)]
-struct LabelUpdateArgs {
-    /// Label id
+struct RestoreArgs {
+    /// Backup directory, or the directory holding dated backups to restore the latest of
+    #[arg(long, default_value = "backup")]
+    from: PathBuf,
+    /// Restore a team of the backup into another team (repeatable, e.g. ENG=PLAT); other teams
+    /// go to the team with the same key
+    #[arg(long, value_name = "OLD=NEW", value_parser = restore::parse_team_map)]
+    team_map: Vec<(String, String)>,
+    /// Entities to restore (comma separated: labels,projects,issues,comments; default all)
+    #[arg(long, value_delimiter = ',')]
+    include: Vec<BackupEntity>,
+    /// Where to write the old→new mapping; an existing file resumes an interrupted restore
+    /// [default: restore-map.json in the backup]
     #[arg(long)]
-    id: String,
-    /// Profile name for stored credentials
+    report: Option<PathBuf>,
+    /// Profile name for the workspace to restore into
     #[arg(long, default_value = DEFAULT_PROFILE)]
     profile: String,
-    /// New name
+    #[command(flatten)]
+    confirmation: ConfirmArgs,
+    /// Print the plan or the result as JSON
     #[arg(long)]
-    name: Option<String>,
-    /// Description
+    json: bool,
+}
+
+#[derive(Args, Debug)]
+struct ImportCsvArgs {
+    /// CSV file whose first row names the columns
+    file: PathBuf,
+    /// Team key/name/id to create the issues in
     #[arg(long)]
-    description: Option<String>,
-    /// Hex color
+    team: String,
+    /// Feed a field from a column, e.g. title=Summary (repeatable). Fields: title, description,
+    /// priority, estimate, due, state, labels, assignee. Without it, columns are matched by
+    /// name, asking on a terminal
+    #[arg(long = "map", value_name = "FIELD=COLUMN", value_parser = import::parse_column_map)]
+    map: Vec<(IssueField, String)>,
+    /// Profile name for stored credentials
+    #[arg(long, default_value = DEFAULT_PROFILE)]
+    profile: String,
+    #[command(flatten)]
+    confirmation: ConfirmArgs,
+    /// Output raw JSON instead of formatted text
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Args, Debug)]
+struct AdminApplyArgs {
+    /// Path to the workspace spec (TOML)
+    #[arg(long, default_value = "workspace.toml")]
+    file: PathBuf,
+    /// Profile name for stored credentials
+    #[arg(long, default_value = DEFAULT_PROFILE)]
+    profile: String,
+    #[command(flatten)]
+    confirmation: ConfirmArgs,
+    /// Only print the plan; do not apply it
+    #[arg(long)]
+    plan: bool,
+    /// Output raw JSON
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Args, Debug)]
+struct PickArgs {
+    /// Restrict candidates to a team key
+    #[arg(long)]
+    team: Option<String>,
+    /// Number of recent issues to load
+    #[arg(long, default_value_t = 100)]
+    limit: usize,
+    /// Initial search text
+    #[arg(long)]
+    query: Option<String>,
+    /// Print the issue's git branch name instead of its key
+    #[arg(long, conflicts_with = "url")]
+    branch: bool,
+    /// Print the issue URL instead of its key
+    #[arg(long)]
+    url: bool,
+    /// Profile name for stored credentials
+    #[arg(long, default_value = DEFAULT_PROFILE)]
+    profile: String,
+    /// Output the selected issue as JSON
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Args, Debug)]
+struct SearchArgs {
+    /// Search text
+    #[arg(required = true)]
+    query: Vec<String>,
+    /// Restrict to entity types (comma separated: issue,project,document,user)
+    #[arg(long = "type", value_delimiter = ',')]
+    types: Vec<SearchKind>,
+    /// Maximum results per type
+    #[arg(long, default_value_t = 10)]
+    limit: usize,
+    /// Print tab-separated `type key title url` lines without headers
+    #[arg(long, conflicts_with = "json")]
+    plain: bool,
+    /// Profile name for stored credentials
+    #[arg(long, default_value = DEFAULT_PROFILE)]
+    profile: String,
+    /// Output results grouped by type as JSON
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Args, Debug)]
+struct EstimateArgs {
+    /// Team key, name, or id
+    #[arg(long)]
+    team: String,
+    /// Only issues in this cycle: `current`, `next`, or a cycle number
+    #[arg(long)]
+    cycle: Option<String>,
+    /// Most issues to step through; they are shown highest priority first
+    #[arg(long, default_value_t = 50)]
+    limit: usize,
+    /// Profile name for stored credentials
+    #[arg(long, default_value = DEFAULT_PROFILE)]
+    profile: String,
+}
+
+#[derive(Args, Debug)]
+struct ActivityArgs {
+    /// Team key, name, or id (defaults to every team)
+    #[arg(long)]
+    team: Option<String>,
+    /// Window start: a duration back from now (24h, 7d), a date, or an RFC 3339 time
+    #[arg(long, default_value = "24h")]
+    since: String,
+    /// Maximum number of updated issues to scan
+    #[arg(long, default_value_t = 250)]
+    limit: usize,
+    /// Output format; markdown is ready to paste into notes
+    #[arg(long, value_enum, default_value_t = ActivityFormat::Table, conflicts_with = "json")]
+    format: ActivityFormat,
+    #[command(flatten)]
+    budget: BudgetArgs,
+    /// Profile name for stored credentials
+    #[arg(long, default_value = DEFAULT_PROFILE)]
+    profile: String,
+    /// Output raw JSON (same as --format json)
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Args, Debug)]
+struct NotifyForwardArgs {
+    /// Destination: slack://hooks.slack.com/…, discord://discord.com/api/webhooks/…, or https://…
+    #[arg(long, required = true, value_name = "URL")]
+    to: Vec<Destination>,
+    /// Only forward events matching all conditions, e.g. "team=ENG priority>=high"
+    #[arg(long, value_name = "EXPR")]
+    filter: Option<EventFilter>,
+    /// Message template with {issue}, {title}, {url}, {summary}, {actor}, {team}, {priority}, ...
+    #[arg(long)]
+    template: Option<String>,
+    /// Time between polls
+    #[arg(long, default_value = "60s", value_parser = config::parse_duration)]
+    interval: Duration,
+    /// Also forward events from this far back at startup (duration, date, or RFC 3339 time)
+    #[arg(long, default_value = "0s")]
+    since: String,
+    /// Poll once and exit instead of running until interrupted
+    #[arg(long)]
+    once: bool,
+    /// Maximum number of updated issues to scan per poll
+    #[arg(long, default_value_t = 250)]
+    limit: usize,
+    /// Profile name for stored credentials
+    #[arg(long, default_value = DEFAULT_PROFILE)]
+    profile: String,
+}
+
+#[derive(Args, Debug)]
+struct TeamSettingsArgs {
+    /// Team key, name, or id
+    team: String,
+    /// Change a setting, e.g. cycles.enabled=true or estimates.type=fibonacci (repeatable)
+    #[arg(long = "set", value_name = "KEY=VALUE")]
+    set: Vec<Assignment>,
+    /// Profile name for stored credentials
+    #[arg(long, default_value = DEFAULT_PROFILE)]
+    profile: String,
+    #[command(flatten)]
+    confirmation: ConfirmArgs,
+    /// Output the settings as JSON
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Args, Debug)]
+struct CommentBulkArgs {
+    /// Issues to comment on, e.g. "label=needs-info state=Open" (team, state, label, assignee, priority)
+    #[arg(long, value_name = "EXPR")]
+    filter: IssueFilter,
+    /// Markdown file rendered per issue, e.g. 'Hi {{ assignee.displayName | default("there") }}'
+    #[arg(long, value_name = "FILE")]
+    body_template: PathBuf,
+    /// Maximum number of matching issues to comment on
+    #[arg(long, default_value_t = 100, value_parser = clap::value_parser!(u16).range(1..=250))]
+    limit: u16,
+    /// Pause between comments, to stay well inside the API rate limit
+    #[arg(long, default_value = "1s", value_parser = config::parse_duration)]
+    interval: Duration,
+    /// Profile name for stored credentials
+    #[arg(long, default_value = DEFAULT_PROFILE)]
+    profile: String,
+    #[command(flatten)]
+    confirmation: ConfirmArgs,
+    /// Post as the OAuth app instead of yourself (needs `linear auth login --as-app`)
+    #[arg(long)]
+    as_app: bool,
+    /// Output per-issue results as JSON
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Args, Debug)]
+struct NotifyRulesAddArgs {
+    /// Unique rule name
+    #[arg(long)]
+    name: String,
+    /// Issues to watch, e.g. "team=ENG priority=urgent" (team, state, label, assignee, priority)
+    #[arg(long, value_name = "EXPR")]
+    filter: IssueFilter,
+    /// Shell command run per newly matching issue, with $LINEAR_ISSUE, $LINEAR_TITLE, $LINEAR_URL, ...
+    #[arg(long)]
+    command: String,
+}
+
+#[derive(Args, Debug)]
+struct NotifyRulesListArgs {
+    /// Emit JSON instead of a table
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Args, Debug)]
+struct NotifyRulesRemoveArgs {
+    /// Rule name
+    name: String,
+}
+
+#[derive(Args, Debug)]
+struct NotifyWatchArgs {
+    /// Only evaluate these rules (repeatable); defaults to all
+    #[arg(long = "rule", value_name = "NAME")]
+    rules: Vec<String>,
+    /// Time between polls
+    #[arg(long, default_value = "60s", value_parser = config::parse_duration)]
+    interval: Duration,
+    /// Poll once and exit instead of running until interrupted
+    #[arg(long)]
+    once: bool,
+    /// Maximum number of matching issues fetched per rule and poll
+    #[arg(long, default_value_t = 250)]
+    limit: usize,
+    /// Profile name for stored credentials
+    #[arg(long, default_value = DEFAULT_PROFILE)]
+    profile: String,
+}
+
+#[derive(Args, Debug)]
+struct AutomateRunArgs {
+    /// Rules file (defaults to rules.toml in the config directory)
+    #[arg(long, value_name = "PATH")]
+    rules: Option<PathBuf>,
+    /// Evaluate events from this point instead of the last run (duration, date, or RFC 3339 time)
+    #[arg(long)]
+    since: Option<String>,
+    /// Show matching rules without changing any issue
+    #[arg(long)]
+    plan: bool,
+    /// Keep evaluating rules until interrupted
+    #[arg(long)]
+    watch: bool,
+    /// Time between evaluations with --watch
+    #[arg(long, default_value = "60s", value_parser = config::parse_duration)]
+    interval: Duration,
+    /// Profile name for stored credentials
+    #[arg(long, default_value = DEFAULT_PROFILE)]
+    profile: String,
+    /// Output the plan and applied changes as JSON
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Args, Debug)]
+struct ReportStaleArgs {
+    /// Team key (defaults to every team)
+    #[arg(long)]
+    team: Option<String>,
+    /// Days without updates before an issue counts as stale
+    #[arg(long, default_value_t = 30)]
+    days: u32,
+    /// Maximum number of stale issues to list
+    #[arg(long, default_value_t = 250)]
+    limit: usize,
+    /// Comment on each stale issue asking whether it is still relevant
+    #[arg(long)]
+    nudge: bool,
+    /// Nudge comment template with {issue}, {title}, {days}, {team}, {priority}, ...
+    #[arg(long, requires = "nudge")]
+    template: Option<String>,
+    /// Do not nudge an issue again within this window
+    #[arg(long, default_value = "7d", value_parser = config::parse_duration)]
+    cooldown: Duration,
+    /// Maximum number of comments posted per run
+    #[arg(long, default_value_t = 20)]
+    max_nudges: usize,
+    /// Profile name for stored credentials
+    #[arg(long, default_value = DEFAULT_PROFILE)]
+    profile: String,
+    /// Output stale issues and nudged keys as JSON
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Args, Debug)]
+struct ReportWipArgs {
+    /// Team key (defaults to every team)
+    #[arg(long)]
+    team: Option<String>,
+    /// Maximum number of open issues to check
+    #[arg(long, default_value_t = 1000)]
+    limit: usize,
+    /// Profile name for stored credentials
+    #[arg(long, default_value = DEFAULT_PROFILE)]
+    profile: String,
+    /// Output violations as JSON
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Args, Debug)]
+struct ReportDigestArgs {
+    /// Issues you subscribe to
+    #[arg(long, required_unless_present = "team")]
+    subscribed: bool,
+    /// Team key, name, or id; with --subscribed, only that team's subscribed issues
+    #[arg(long)]
+    team: Option<String>,
+    /// Window start: a duration back from now (1d, 12h), a date, or an RFC 3339 time
+    #[arg(long, default_value = "1d")]
+    since: String,
+    /// Maximum number of updated issues to scan
+    #[arg(long, default_value_t = 250)]
+    limit: usize,
+    /// Output format; text and markdown print nothing when there are no changes
+    #[arg(long, value_enum, default_value_t = DigestFormat::Text, conflicts_with = "json")]
+    format: DigestFormat,
+    #[command(flatten)]
+    budget: BudgetArgs,
+    /// Profile name for stored credentials
+    #[arg(long, default_value = DEFAULT_PROFILE)]
+    profile: String,
+    /// Output raw JSON (same as --format json)
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Args, Debug)]
+struct HookInstallArgs {
+    /// Hook to install
+    #[arg(value_enum)]
+    hook: HookKind,
+    /// Word placed before the issue key, e.g. Fixes to close the issue on merge
+    #[arg(long, default_value = "Refs")]
+    magic_word: String,
+    /// Reject commits on issue branches that do not reference the issue instead of editing them
+    #[arg(long)]
+    check: bool,
+    /// Replace an existing hook that linear did not install
+    #[arg(long)]
+    force: bool,
+}
+
+#[derive(Args, Debug)]
+struct HookRunArgs {
+    /// Hook to run
+    #[arg(value_enum)]
+    hook: HookKind,
+    /// Commit message file passed by git
+    file: PathBuf,
+    /// Word placed before the issue key
+    #[arg(long, default_value = "Refs")]
+    magic_word: String,
+    /// Fail instead of editing the message when it does not reference the issue
+    #[arg(long)]
+    check: bool,
+}
+
+#[derive(Args, Debug)]
+struct TimeStartArgs {
+    /// Issue key, URL, or number in the default team (e.g. ENG-123); defaults to the key in the
+    /// current git branch name
+    #[arg(value_parser = settings::parse_issue_key)]
+    key: Option<String>,
+    /// Profile name for stored credentials and the time log
+    #[arg(long, default_value = DEFAULT_PROFILE)]
+    profile: String,
+}
+
+#[derive(Args, Debug)]
+struct RemindSetArgs {
+    /// Issue key, URL, or number in the default team (e.g. ENG-123); defaults to the key in the
+    /// current git branch name
+    #[arg(value_parser = settings::parse_issue_key)]
+    key: Option<String>,
+    /// When to be reminded, from now, e.g. 2h or 3d
+    #[arg(
+        long = "in",
+        value_name = "DURATION",
+        value_parser = config::parse_duration,
+        required = true
+    )]
+    delay: Option<Duration>,
+    /// What to do when it comes up
+    #[arg(long)]
+    note: Option<String>,
+    /// Also subscribe to the issue in Linear to hear about changes meanwhile
+    #[arg(long)]
+    subscribe: bool,
+    /// Profile name for stored credentials and reminders
+    #[arg(long, default_value = DEFAULT_PROFILE)]
+    profile: String,
+    /// Output raw JSON
     #[arg(long)]
-    color: Option<String>,
+    json: bool,
+}
+
+#[derive(Args, Debug)]
+struct RemindListArgs {
+    /// Profile name for stored reminders
+    #[arg(long, default_value = DEFAULT_PROFILE)]
+    profile: String,
+    /// Output raw JSON
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Args, Debug)]
+struct RemindCancelArgs {
+    /// Reminder id from `remind list`
+    id: u64,
+    /// Profile name for stored reminders
+    #[arg(long, default_value = DEFAULT_PROFILE)]
+    profile: String,
+}
+
+#[derive(Args, Debug)]
+struct TimeStopArgs {
+    /// Post the session length as a comment on the issue
+    #[arg(long)]
+    comment: bool,
+    /// Note stored with the session (and added to the comment)
+    #[arg(long)]
+    note: Option<String>,
+    /// Profile name for stored credentials and the time log
+    #[arg(long, default_value = DEFAULT_PROFILE)]
+    profile: String,
+}
+
+#[derive(Args, Debug)]
+struct TimeStatusArgs {
+    /// Profile name for the time log
+    #[arg(long, default_value = DEFAULT_PROFILE)]
+    profile: String,
+    /// Output raw JSON
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Args, Debug)]
+struct TimeReportArgs {
+    /// Window start: a duration back from now (1d, 1w), a date, or an RFC 3339 time
+    #[arg(long, default_value = "1w")]
+    since: String,
+    /// Profile name for the time log
+    #[arg(long, default_value = DEFAULT_PROFILE)]
+    profile: String,
+    /// Output raw JSON
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Args, Debug)]
+struct DoctorArgs {
+    /// Profile name for stored credentials
+    #[arg(long, default_value = DEFAULT_PROFILE)]
+    profile: String,
     /// Output raw JSON
     #[arg(long)]
     json: bool,
@@ -581,25 +1671,288 @@ struct LoginArgs {
     /// Use manual copy/paste flow instead of launching a browser
     #[arg(long)]
     manual: bool,
+    /// Authorize the OAuth app to act as itself (actor=application); the token is stored beside
+    /// your own and used by commands run with --as-app
+    #[arg(long, conflicts_with = "api_key")]
+    as_app: bool,
+    /// Request these OAuth scopes too (comma separated), on top of the defaults and the scopes
+    /// the profile's current token already has
+    #[arg(
+        long = "scope",
+        value_name = "SCOPES",
+        value_delimiter = ',',
+        conflicts_with = "api_key"
+    )]
+    scopes: Vec<String>,
+    /// Profile name to store the credentials under
+    #[arg(long, default_value = DEFAULT_PROFILE)]
+    profile: String,
+    /// Store nothing; print the access token to export as LINEAR_ACCESS_TOKEN instead, e.g. to
+    /// hand a session to CI
+    #[arg(long, conflicts_with = "api_key")]
+    no_store: bool,
+}
+
+#[derive(Args, Debug)]
+struct LogoutArgs {
+    /// Profile name for stored credentials
+    #[arg(long, default_value = DEFAULT_PROFILE)]
+    profile: String,
+}
+
+#[tokio::main]
+async fn main() {
+    let json_errors = env::args().any(|arg| arg == "--json");
+    let cli = Cli::parse();
+    let _ = CLIENT_OPTIONS.set(ClientOptions {
+        dry_run: cli.dry_run,
+        record: cli.record.clone(),
+        replay: cli.replay.clone(),
+        timeout: cli.timeout,
+        no_cache: cli.no_cache,
+    });
+    hyperlink::init(cli.no_hyperlinks);
+    markdown::init_width(cli.width);
+    i18n::init(cli.lang.as_deref());
+    accessible::init(cli.accessible);
+    fields::init(cli.fields.clone(), cli.field_separator);
+    // Dropping `run` on Ctrl-C cancels whatever request is in flight.
+    let result = tokio::select! {
+        result = run(cli) => result,
+        _ = tokio::signal::ctrl_c() => Err(LinearError::interrupted().into()),
+    };
+    if let Err(err) = result {
+        if let Some(request) = dry_run_request(&err) {
+            render_dry_run(request, json_errors);
+            return;
+        }
+        let code = report_error(&err, json_errors);
+        if !json_errors {
+            offer_scope_upgrade(&err).await;
+        }
+        std::process::exit(code);
+    }
+}
+
+/// After a failure for lack of an OAuth scope, offer to log in to the session's profile again
+/// requesting it on top of the token's current scopes.
+async fn offer_scope_upgrade(err: &anyhow::Error) {
+    let Some(scope) = err
+        .chain()
+        .find_map(|cause| cause.downcast_ref::<GraphqlError>())
+        .and_then(GraphqlError::missing_scope)
+    else {
+        return;
+    };
+    let Some((profile, actor)) = LOADED_SESSION.get() else {
+        return;
+    };
+    let Ok(store) = credential_store(*actor) else {
+        return;
+    };
+    let Ok(Some(session)) = store.load(profile) else {
+        return;
+    };
+    // API keys carry their permissions with the key; only OAuth tokens can be re-scoped.
+    if session.token_type != TokenType::Bearer || session.scope.contains(&scope) {
+        return;
+    }
+    let question = format!(
+        "Log in to profile '{}' again, adding the `{}` scope to {}?",
+        profile,
+        scope,
+        session.scope.join(", ")
+    );
+    if !confirm::ask(question).await.unwrap_or(false) {
+        return;
+    }
+    let login = LoginArgs {
+        api_key: None,
+        manual: false,
+        as_app: *actor == Actor::Application,
+        scopes: vec![scope],
+        profile: profile.clone(),
+        no_store: false,
+    };
+    match auth_login(login).await {
+        Ok(()) => eprintln!("Run the command again to use the new scope."),
+        Err(err) => {
+            report_error(&err, false);
+        }
+    }
+}
+
+pub(crate) fn dry_run_request(err: &anyhow::Error) -> Option<&DryRunRequest> {
+    err.chain()
+        .find_map(|cause| match cause.downcast_ref::<GraphqlError>() {
+            Some(GraphqlError::DryRun(request)) => Some(request.as_ref()),
+            _ => None,
+        })
+}
+
+fn render_dry_run(request: &DryRunRequest, json: bool) {
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&json!({ "dryRun": request })).unwrap_or_default()
+        );
+        return;
+    }
+    println!(
+        "{}",
+        i18n::tr_args("cli-dry-run", &[("operation", &request.operation)])
+    );
+    println!("POST {}", request.endpoint);
+    println!("Authorization: {}", request.authorization);
+    println!();
+    println!("{}", request.query);
+    println!();
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&request.variables).unwrap_or_default()
+    );
+}
+
+/// Build a GraphQL client for the session, honouring `--dry-run`, `--record`, and `--replay`.
+pub(crate) fn build_client(session: &AuthSession) -> Result<LinearGraphqlClient> {
+    let options = client_options();
+    let mut client = LinearGraphqlClient::from_session(session)
+        .context("failed to build GraphQL client")?
+        .with_dry_run(options.dry_run)
+        .with_timeout(request_timeout()?)
+        .with_cache(response_cache()?);
+    if let Some(tape) = session_tape()? {
+        client = client.with_tape(tape);
+    }
+    Ok(client)
+}
+
+/// Client for `profile`, built like every other command's.
+async fn profile_client(profile: &str) -> Result<LinearGraphqlClient> {
+    build_client(&load_session(profile).await?)
+}
+
+/// [`profile_client`] for one profile of a `--profiles` fan-out.
+async fn connect(profile: String) -> Result<LinearGraphqlClient> {
+    profile_client(&profile).await
+}
+
+/// The `--record`/`--replay` tape, opened by the first client and shared by every later one,
+/// so commands that build several clients keep one complete recording.
+fn session_tape() -> Result<Option<SessionTape>> {
+    static TAPE: Mutex<Option<SessionTape>> = Mutex::new(None);
+    let mut tape = TAPE.lock().unwrap_or_else(PoisonError::into_inner);
+    if tape.is_none() {
+        let options = client_options();
+        *tape = match (&options.record, &options.replay) {
+            (Some(path), _) => {
+                Some(SessionTape::record(path).context("failed to start session recording")?)
+            }
+            (None, Some(path)) => {
+                Some(SessionTape::replay(path).context("failed to load recorded session")?)
+            }
+            (None, None) => None,
+        };
+    }
+    Ok(tape.clone())
+}
+
+/// Per-request timeout from `--timeout` or config, `None` when disabled.
+pub(crate) fn request_timeout() -> Result<Option<Duration>> {
+    let timeout = match client_options().timeout {
+        Some(timeout) => timeout,
+        None => configured_timeout()?,
+    };
+    Ok((!timeout.is_zero()).then_some(timeout))
+}
+
+/// The response cache `config.toml` asks for, `None` with `--no-cache` or `cache_ttl = "0"`.
+fn response_cache() -> Result<Option<ResponseCache>> {
+    if client_options().no_cache {
+        return Ok(None);
+    }
+    let ttl = match ConfigLocator::new() {
+        Ok(locator) => Config::load(&locator)?.cache_ttl(),
+        Err(_) => Some(config::DEFAULT_CACHE_TTL),
+    };
+    Ok(ttl.and_then(ResponseCache::in_cache_dir))
+}
+
+/// Idle time after which the TUI marks open issues stale, `None` when disabled.
+pub(crate) fn stale_threshold() -> Result<Option<Duration>> {
+    let Ok(locator) = ConfigLocator::new() else {
+        return Ok(Some(config::DEFAULT_STALE_AFTER));
+    };
+    Ok(Config::load(&locator)?.stale_threshold())
+}
+
+/// The TUI's saved pane layout, or the default when no config directory is available.
+pub(crate) fn tui_layout() -> Result<config::TuiLayout> {
+    let Ok(locator) = ConfigLocator::new() else {
+        return Ok(config::TuiLayout::default());
+    };
+    Ok(Config::load(&locator)?.tui)
 }
 
-#[derive(Args, Debug)]
-struct LogoutArgs {
-    /// Profile name for stored credentials
-    #[arg(long, default_value = DEFAULT_PROFILE)]
-    profile: String,
+/// WIP limits and age thresholds from the `[board]` table, empty when no config directory is available.
+pub(crate) fn board_limits() -> Result<config::BoardLimits> {
+    let Ok(locator) = ConfigLocator::new() else {
+        return Ok(config::BoardLimits::default());
+    };
+    Ok(Config::load(&locator)?.board)
 }
 
-#[tokio::main]
-async fn main() -> Result<()> {
-    let cli = Cli::parse();
+fn configured_timeout() -> Result<Duration> {
+    let Ok(locator) = ConfigLocator::new() else {
+        return Ok(DEFAULT_TIMEOUT);
+    };
+    let config = Config::load(&locator)?;
+    Ok(config.timeout.unwrap_or(DEFAULT_TIMEOUT))
+}
+
+/// Print a failure to stderr (as a JSON envelope when `--json` was requested) and return its exit code.
+fn report_error(err: &anyhow::Error, json_errors: bool) -> i32 {
+    let kind = ErrorKind::classify(err);
+    let hint = err
+        .chain()
+        .find_map(|cause| cause.downcast_ref::<GraphqlError>())
+        .and_then(GraphqlError::hint);
+    if json_errors {
+        let mut envelope = json!({
+            "error": {
+                "kind": kind.as_str(),
+                "exitCode": kind.exit_code(),
+                "message": err.to_string(),
+                "causes": err.chain().skip(1).map(|cause| cause.to_string()).collect::<Vec<_>>(),
+            }
+        });
+        if let Some(hint) = hint {
+            envelope["error"]["hint"] = json!(hint);
+        }
+        eprintln!("{}", envelope);
+    } else {
+        let message = format!("{err:#}");
+        eprintln!("{}", i18n::tr_args("cli-error", &[("message", &message)]));
+        if let Some(hint) = hint {
+            eprintln!("{}", i18n::tr_args("cli-hint", &[("hint", &hint)]));
+        }
+    }
+    kind.exit_code()
+}
+
+async fn run(cli: Cli) -> Result<()> {
     match cli.command {
         Commands::Auth(cmd) => match cmd {
             AuthCommand::Login(args) => auth_login(args).await?,
             AuthCommand::Logout(args) => auth_logout(args).await?,
         },
         Commands::User(cmd) => match cmd {
-            UserCommand::Me(args) => user_me(args).await?,
+            UserCommand::Me(cmd) => {
+                let client = profile_client(&cmd.profile).await?;
+                user::me(&client, &mut io::stdout(), cmd.command).await?
+            }
+            UserCommand::SetStatus(args) => user_set_status(args).await?,
+            UserCommand::Status(args) => user_status(args).await?,
         },
         Commands::Issue(cmd) => match cmd {
             IssueCommand::List(args) => issue_list(args).await?,
@@ -609,47 +1962,180 @@ async fn main() -> Result<()> {
             IssueCommand::Close(args) => issue_close(args).await?,
             IssueCommand::Delete(args) => issue_delete(args).await?,
             IssueCommand::Comment(args) => issue_comment(args).await?,
+            IssueCommand::Merge(args) => issue_merge(args).await?,
+            IssueCommand::Export(args) => export::run(args).await?,
         },
         Commands::Team(cmd) => match cmd {
-            TeamCommand::List(args) => team_list(args).await?,
+            TeamCommand::List(cmd) => match cmd.workspaces.resolve()? {
+                Some(profiles) => {
+                    linear_commands::team::list_workspaces(
+                        profiles,
+                        connect,
+                        &mut io::stdout(),
+                        cmd.command,
+                    )
+                    .await?
+                }
+                None => {
+                    let client = profile_client(&cmd.profile).await?;
+                    linear_commands::team::list(&client, &mut io::stdout(), cmd.command).await?
+                }
+            },
+            TeamCommand::Settings(args) => team::settings(args).await?,
         },
         Commands::State(cmd) => match cmd {
-            StateCommand::List(args) => state_list(args).await?,
+            StateCommand::List(cmd) => {
+                let client = profile_client(&cmd.profile).await?;
+                state::list(&client, &mut io::stdout(), cmd.command).await?
+            }
         },
         Commands::Project(cmd) => match cmd {
-            ProjectCommand::List(args) => project_list(args).await?,
-            ProjectCommand::Create(args) => project_create(args).await?,
-            ProjectCommand::Update(args) => project_update(args).await?,
-            ProjectCommand::Archive(args) => project_archive(args).await?,
+            ProjectCommand::List(cmd) => match cmd.workspaces.resolve()? {
+                Some(profiles) => {
+                    project::list_workspaces(profiles, connect, &mut io::stdout(), cmd.command)
+                        .await?
+                }
+                None => {
+                    let client = profile_client(&cmd.profile).await?;
+                    project::list(&client, &mut io::stdout(), cmd.command).await?
+                }
+            },
+            ProjectCommand::Create(cmd) => {
+                project::create(&cmd.client().await?, &mut io::stdout(), cmd.command).await?
+            }
+            ProjectCommand::Update(cmd) => {
+                project::update(&cmd.client().await?, &mut io::stdout(), cmd.command).await?
+            }
+            ProjectCommand::Archive(cmd) => {
+                project::archive(&cmd.client().await?, &mut io::stdout(), cmd.command).await?
+            }
         },
         Commands::Cycle(cmd) => match cmd {
-            CycleCommand::List(args) => cycle_list(args).await?,
-            CycleCommand::Update(args) => cycle_update(args).await?,
+            CycleCommand::List(cmd) => {
+                let client = profile_client(&cmd.profile).await?;
+                cycle::list(&client, &mut io::stdout(), cmd.command).await?
+            }
+            CycleCommand::Update(cmd) => {
+                cycle::update(&cmd.client().await?, &mut io::stdout(), cmd.command).await?
+            }
         },
         Commands::Label(cmd) => match cmd {
-            LabelCommand::List(args) => label_list(args).await?,
-            LabelCommand::Create(args) => label_create(args).await?,
-            LabelCommand::Update(args) => label_update(args).await?,
+            LabelCommand::List(cmd) => {
+                let client = profile_client(&cmd.profile).await?;
+                label::list(&client, &mut io::stdout(), cmd.command).await?
+            }
+            LabelCommand::Create(cmd) => {
+                label::create(&cmd.client().await?, &mut io::stdout(), cmd.command).await?
+            }
+            LabelCommand::Update(cmd) => {
+                label::update(&cmd.client().await?, &mut io::stdout(), cmd.command).await?
+            }
+        },
+        Commands::Comment(cmd) => match cmd {
+            CommentCommand::Bulk(args) => comment::bulk(args).await?,
         },
         Commands::Tui(args) => tui::run(&args.profile).await?,
+        Commands::Doctor(args) => doctor::run(args).await?,
+        Commands::Pick(args) => pick::run(args).await?,
+        Commands::Search(args) => search::run(args).await?,
+        Commands::Get(args) => get::run(args).await?,
+        Commands::Activity(args) => activity::run(args).await?,
+        Commands::Estimate(args) => estimate::run(args).await?,
+        Commands::Notify(cmd) => match cmd {
+            NotifyCommand::Forward(args) => notify::forward(args).await?,
+            NotifyCommand::Rules(cmd) => match cmd {
+                NotifyRulesCommand::Add(args) => notify::rules_add(args)?,
+                NotifyRulesCommand::List(args) => notify::rules_list(args)?,
+                NotifyRulesCommand::Remove(args) => notify::rules_remove(args)?,
+            },
+            NotifyCommand::Watch(args) => notify::watch(args).await?,
+        },
+        Commands::Automate(cmd) => match cmd {
+            AutomateCommand::Run(args) => automate::run(args).await?,
+        },
+        Commands::Report(cmd) => match cmd {
+            ReportCommand::Stale(args) => report::stale(args).await?,
+            ReportCommand::Wip(args) => report::wip(args).await?,
+            ReportCommand::Digest(args) => report::digest(args).await?,
+        },
+        Commands::Hook(cmd) => match cmd {
+            HookCommand::Install(args) => hook::install(args)?,
+            HookCommand::Run(args) => hook::run(args)?,
+        },
+        Commands::Remind(args) => match args.command {
+            Some(RemindCommand::List(args)) => remind::list(args)?,
+            Some(RemindCommand::Cancel(args)) => remind::cancel(args)?,
+            None => remind::add(args.set).await?,
+        },
+        Commands::Calendar(cmd) => match cmd {
+            CalendarCommand::Export(args) => calendar::export(args).await?,
+        },
+        Commands::Api(cmd) => match cmd {
+            ApiCommand::Query(args) => api::query(args).await?,
+            ApiCommand::Estimate(args) => api::estimate(args)?,
+        },
+        Commands::Import(cmd) => match cmd {
+            ImportCommand::Github(args) => import::github(args).await?,
+            ImportCommand::Csv(args) => import::csv(args).await?,
+        },
+        Commands::Backup(args) => backup::run(args).await?,
+        Commands::Restore(args) => restore::run(args).await?,
+        Commands::Unfurl(args) => unfurl::run(args).await?,
+        Commands::Config(cmd) => match cmd {
+            ConfigCommand::Set(args) => settings::set(args)?,
+        },
+        Commands::Cache(cmd) => match cmd {
+            CacheCommand::Warm(args) => cache::warm(args).await?,
+        },
+        Commands::Daemon(cmd) => match cmd {
+            DaemonCommand::Run(args) => daemon::run(args).await?,
+            DaemonCommand::Status(args) => daemon::status(args).await?,
+            DaemonCommand::Stop(args) => daemon::stop(args).await?,
+        },
+        Commands::Time(cmd) => match cmd {
+            TimeCommand::Start(args) => time::start(args).await?,
+            TimeCommand::Stop(args) => time::stop(args).await?,
+            TimeCommand::Status(args) => time::status(args)?,
+            TimeCommand::Report(args) => time::report(args)?,
+        },
+        Commands::Admin(cmd) => match cmd {
+            AdminCommand::Apply(args) => admin::apply(args).await?,
+        },
     }
     Ok(())
 }
 
 async fn auth_login(args: LoginArgs) -> Result<()> {
-    let store = FileCredentialStore::with_default_locator()
-        .context("unable to initialise credential store")?;
+    let mut config = build_oauth_config()?;
+    if args.as_app {
+        config = config.with_actor(Actor::Application);
+    }
+    let actor = config.actor;
+    let store: Box<dyn CredentialStore + Send + Sync> = if args.no_store {
+        Box::new(MemoryCredentialStore::default())
+    } else {
+        credential_store(actor)?
+    };
+    // Logging in again keeps what the current token could do, so adding a scope never drops one.
+    let previous = store
+        .load(&args.profile)
+        .context("failed to read stored credentials")?
+        .filter(|session| session.token_type == TokenType::Bearer);
+    if let Some(previous) = &previous {
+        config = config.with_additional_scopes(previous.scope.iter().cloned());
+    }
+    config = config.with_additional_scopes(args.scopes.iter().cloned());
 
-    let oauth = OAuthClient::new(build_oauth_config()?).context("failed to build OAuth client")?;
+    let oauth = OAuthClient::new(config).context("failed to build OAuth client")?;
 
-    let manager = AuthManager::new(store, oauth, DEFAULT_PROFILE);
+    let manager = AuthManager::new(store, oauth, args.profile.as_str());
 
     if let Some(api_key) = args.api_key {
         manager
             .authenticate_api_key(api_key)
             .await
             .context("failed to store API key")?;
-        println!("Personal API key stored for profile '{}'.", DEFAULT_PROFILE);
+        println!("Personal API key stored for profile '{}'.", args.profile);
         return Ok(());
     }
 
@@ -688,6 +2174,17 @@ async fn auth_login(args: LoginArgs) -> Result<()> {
             Err(other) => Err(other),
         }
     }?;
+    let session = match previous.and_then(|previous| previous.refresh_token) {
+        Some(refresh_token) if session.refresh_token.is_none() => {
+            let mut session = session;
+            session.refresh_token = Some(refresh_token);
+            credential_store(actor)?
+                .save(&args.profile, &session)
+                .context("failed to store credentials")?;
+            session
+        }
+        _ => session,
+    };
 
     let identity = match LinearGraphqlClient::from_session(&session) {
         Ok(client) => match client.viewer().await {
@@ -708,10 +2205,21 @@ async fn auth_login(args: LoginArgs) -> Result<()> {
         }
     };
 
-    println!(
-        "Login succeeded. Credentials stored for profile '{}'.",
-        DEFAULT_PROFILE
-    );
+    if args.no_store {
+        println!("Login succeeded. Nothing was stored; export the token to use it:");
+        println!("  export {}={}", ACCESS_TOKEN_ENV, session.access_token);
+    } else {
+        match actor {
+            Actor::User => println!(
+                "Login succeeded. Credentials stored for profile '{}'.",
+                args.profile
+            ),
+            Actor::Application => println!(
+                "Login succeeded. Application credentials stored for profile '{}'; pass --as-app to act as the app.",
+                args.profile
+            ),
+        }
+    }
     if !identity.is_empty() {
         println!("Logged in as {}", identity);
     }
@@ -723,12 +2231,18 @@ async fn auth_login(args: LoginArgs) -> Result<()> {
 }
 
 async fn auth_logout(args: LogoutArgs) -> Result<()> {
-    let store = FileCredentialStore::with_default_locator()
-        .context("unable to initialise credential store")?;
-    store
+    credential_store(Actor::User)?
         .delete(&args.profile)
         .context("failed to remove stored credentials")?;
+    credential_store(Actor::Application)?
+        .delete(&args.profile)
+        .context("failed to remove stored application credentials")?;
     println!("Deleted credentials for profile '{}'.", args.profile);
+    if AuthSession::from_env().is_some() {
+        println!(
+            "The token in {API_KEY_ENV} or {ACCESS_TOKEN_ENV} is still used until you unset it."
+        );
+    }
     Ok(())
 }
 
@@ -764,6 +2278,19 @@ fn build_oauth_config() -> Result<OAuthConfig> {
         }
     }
 
+    if let Ok(actor) = env::var("LINEAR_ACTOR") {
+        match actor.trim() {
+            "" | "user" => {}
+            "application" | "app" => config = config.with_actor(Actor::Application),
+            other => {
+                return Err(LinearError::validation(format!(
+                    "invalid LINEAR_ACTOR '{other}' (expected user or application)"
+                ))
+                .into())
+            }
+        }
+    }
+
     Ok(config)
 }
 
@@ -789,143 +2316,630 @@ fn print_authorization_url(url: &Url) -> Result<(), linear_core::auth::AuthError
     Ok(())
 }
 
-async fn user_me(args: MeArgs) -> Result<()> {
+async fn user_set_status(args: SetStatusArgs) -> Result<()> {
+    if !args.clear && args.emoji.is_none() && args.text.is_none() {
+        return Err(LinearError::validation("pass --emoji and/or --text, or --clear").into());
+    }
+    let until = args
+        .until
+        .as_deref()
+        .map(|raw| status::parse_until(raw, Utc::now()))
+        .transpose()
+        .map_err(LinearError::validation)?;
+    let input = UserStatusInput {
+        status_emoji: args.emoji.as_deref().and_then(status::emoji_code),
+        status_label: args
+            .text
+            .as_deref()
+            .map(str::trim)
+            .filter(|text| !text.is_empty())
+            .map(str::to_owned),
+        status_until_at: until,
+    };
+
     let session = load_session(&args.profile).await?;
-    let client =
-        LinearGraphqlClient::from_session(&session).context("failed to build GraphQL client")?;
-    let viewer = client.viewer().await.context("GraphQL request failed")?;
+    let client = build_client(&session)?;
+    let viewer = client
+        .viewer_status()
+        .await
+        .context("GraphQL request failed")?;
+    let action = if args.clear {
+        "clear your status"
+    } else {
+        "set your status"
+    };
+    args.confirmation.ensure(Impact::Mutation, action).await?;
+    let user = client
+        .update_user_status(&viewer.id, input)
+        .await
+        .context("GraphQL request failed")?;
 
     if args.json {
-        let json = serde_json::to_string_pretty(&viewer)?;
-        println!("{}", json);
+        print_json(&user)?;
+    } else if user.has_status() {
+        println!("Status set: {}", status_line(&user));
     } else {
-        render_viewer(&viewer);
+        println!("Status cleared");
     }
+    Ok(())
+}
+
+async fn user_status(args: UserStatusArgs) -> Result<()> {
+    let session = load_session(&args.profile).await?;
+    let client = build_client(&session)?;
+    let users = match args.name.as_deref().map(str::trim) {
+        Some(name) if !name.is_empty() => {
+            let users: Vec<UserStatus> = client
+                .user_statuses(name, args.limit)
+                .await
+                .context("GraphQL request failed")?
+                .into_iter()
+                .filter(|user| user.active)
+                .collect();
+            if users.is_empty() {
+                return Err(
+                    LinearError::not_found(format!("no active user matches '{name}'")).into(),
+                );
+            }
+            users
+        }
+        _ => vec![client
+            .viewer_status()
+            .await
+            .context("GraphQL request failed")?],
+    };
 
+    if args.json {
+        return print_json(&users);
+    }
+    let mut table = Table::new()
+        .column("NAME", 24)
+        .flex_column("STATUS", 20, 80);
+    for user in &users {
+        let name = user
+            .display_name
+            .clone()
+            .or_else(|| user.name.clone())
+            .unwrap_or_else(|| user.id.clone());
+        let line = if user.has_status() {
+            status_line(user)
+        } else {
+            "-".to_owned()
+        };
+        table.row([name, line]);
+    }
+    table.print();
     Ok(())
 }
 
-pub(crate) async fn load_session(profile: &str) -> Result<linear_core::auth::AuthSession> {
-    let store = FileCredentialStore::with_default_locator()
-        .context("unable to initialise credential store")?;
-    let oauth = OAuthClient::new(build_oauth_config()?).context("failed to build OAuth client")?;
+/// `🌴 On PTO (until Tue 1 Jul 00:00)`.
+fn status_line(user: &UserStatus) -> String {
+    let mut line = [
+        user.status_emoji.as_deref().map(status::emoji_glyph),
+        user.status_label.as_deref(),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>()
+    .join(" ");
+    if let Some(until) = user.status_until_at {
+        let until = until.with_timezone(&Local);
+        line.push_str(&format!(" (until {})", until.format("%a %-d %b %H:%M")));
+    }
+    line
+}
+
+/// Where `actor`'s credentials are kept: the `credential_helper` from config.toml, or files.
+pub(crate) fn credential_store(actor: Actor) -> Result<Box<dyn CredentialStore + Send + Sync>> {
+    let locator = ConfigLocator::new().context("unable to initialise credential store")?;
+    auth::configured_store(locator, actor).context("unable to initialise credential store")
+}
+
+/// The actor selected by an `--as-app` flag.
+pub(crate) fn actor(as_app: bool) -> Actor {
+    if as_app {
+        Actor::Application
+    } else {
+        Actor::User
+    }
+}
+
+pub(crate) async fn load_session(profile: &str) -> Result<AuthSession> {
+    load_session_as(profile, Actor::User).await
+}
+
+/// The stored session of `profile` for `actor`; `Actor::Application` loads the credentials
+/// from `auth login --as-app`, whose mutations Linear attributes to the OAuth app.
+pub(crate) async fn load_session_as(profile: &str, actor: Actor) -> Result<AuthSession> {
+    if client_options().replay.is_some() {
+        // Replayed sessions never reach the network, so no credentials are needed.
+        return Ok(AuthSession::new_api_key("replay".into()));
+    }
+    if let Some(session) = AuthSession::from_env() {
+        // A token from the environment replaces the stored credentials of every profile.
+        return Ok(session);
+    }
+    let _ = LOADED_SESSION.set((profile.to_owned(), actor));
+    let store = credential_store(actor)?;
+    let oauth = OAuthClient::new(build_oauth_config()?.with_actor(actor))
+        .context("failed to build OAuth client")?;
     let manager = AuthManager::new(store, oauth, profile);
-    manager.ensure_fresh_session().await?.ok_or_else(|| {
-        anyhow!(
-            "no credentials stored for profile '{}'; run `linear auth login`",
+    let session = manager
+        .ensure_fresh_session()
+        .await?
+        .ok_or_else(|| match actor {
+            Actor::User => LinearError::auth(format!(
+                "no credentials stored for profile '{}'; run `linear auth login`",
+                profile
+            )),
+            Actor::Application => LinearError::auth(format!(
+            "no application credentials stored for profile '{}'; run `linear auth login --as-app`",
             profile
-        )
-    })
+        )),
+        })?;
+    Ok(session)
+}
+
+async fn issue_list(mut args: IssueListArgs) -> Result<()> {
+    let output = if args.json {
+        OutputFormat::Json
+    } else {
+        args.output
+    };
+    if args.stream && output != OutputFormat::Ndjson {
+        return Err(LinearError::validation("--stream requires --output ndjson").into());
+    }
+    let sort = sort::issue(&args.sort)?;
+    if let Some(profiles) = args.workspaces.resolve()? {
+        return issue_list_profiles(args, sort, profiles, output).await;
+    }
+    let filter = &args.filter;
+    if filter.team.is_none() && filter.team_key.is_none() && filter.team_id.is_none() {
+        args.filter.team = settings::default_team()?;
+    }
+    if args.explain {
+        return issue_list_explain(&args).await;
+    }
+
+    let paginated = args.after.is_some() || args.page.is_some() || args.all;
+    if !args.as_url && !args.stream && !paginated {
+        let params = json!({ "filter": &args.filter, "limit": args.limit, "sort": sort });
+        if let Some(issues) = daemon::call(&args.profile, "list", params).await {
+            return print_issue_list(&args, output, issues?);
+        }
+    }
+
+    let session = load_session(&args.profile).await?;
+    let client = build_client(&session)?;
+    let service = IssueService::new(client.clone());
+    let mut options = issue_query_options(&service, &args.filter, args.limit).await?;
+    options.after = args.after.clone();
+    options.sort = Some(sort);
+
+    if args.as_url {
+        return issue_list_url(&client, &service, &args, &options).await;
+    }
+
+    if args.stream {
+        let limit = (args.limit > 0).then_some(args.limit);
+        return export::stream_ndjson(&service, options, limit, &mut Meter::default()).await;
+    }
+
+    let issues = list_issue_pages(&service, options, &args).await?;
+    print_issue_list(&args, output, issues)
 }
 
-fn render_viewer(viewer: &Viewer) {
-    println!("Viewer ID: {}", viewer.id);
-    if let Some(name) = &viewer.name {
-        println!("Name      : {}", name);
+/// The page `--page` asks for, every page from the cursor on with `--all`, or else one page.
+async fn list_issue_pages(
+    service: &IssueService,
+    mut options: IssueQueryOptions,
+    args: &IssueListArgs,
+) -> Result<IssueListResult> {
+    let mut result = service
+        .list(options.clone())
+        .await
+        .context("GraphQL request failed")?;
+    let mut skip = args.page.map_or(0, |page| page - 1);
+    while (skip > 0 || args.all) && result.has_next_page {
+        options.after = result.end_cursor.clone();
+        let next = service
+            .list(options.clone())
+            .await
+            .context("GraphQL request failed")?;
+        if skip > 0 {
+            skip -= 1;
+            result = next;
+        } else {
+            result.issues.extend(next.issues);
+            result.end_cursor = next.end_cursor;
+            result.has_next_page = next.has_next_page;
+        }
+    }
+    if skip > 0 {
+        // Past the last page.
+        result.issues.clear();
+    }
+    Ok(result)
+}
+
+/// Print one page of `issue list` results in the chosen output.
+fn print_issue_list(
+    args: &IssueListArgs,
+    output: OutputFormat,
+    issues: IssueListResult,
+) -> Result<()> {
+    if let Some(template) = &args.template {
+        for issue in &issues.issues {
+            print_template(template, issue)?;
+        }
+        return Ok(());
+    }
+
+    if let Some(format) = output.share() {
+        let snapshot = match args.group_by {
+            Some(by) => Snapshot::grouped("Linear issues", group_issues(&issues.issues, by)),
+            None => Snapshot::new("Linear issues", &issues.issues),
+        };
+        let snapshot = match filter_summary(&args.filter) {
+            Some(summary) => snapshot.subtitle(summary),
+            None => snapshot,
+        };
+        print!("{}", snapshot.render(format));
+        if issues.has_next_page {
+            eprintln!(
+                "… more issues available; the snapshot covers the first {}",
+                args.limit
+            );
+        }
+        return Ok(());
+    }
+
+    if let Some(by) = args.group_by {
+        let groups = group_issues(&issues.issues, by);
+        match output {
+            OutputFormat::Json => {
+                let groups: Vec<_> = groups.iter().map(group_json).collect();
+                print_json(&groups)?;
+            }
+            OutputFormat::Ndjson => {
+                for group in &groups {
+                    println!("{}", json_line(&group_json(group))?);
+                }
+            }
+            OutputFormat::Table => render_grouped_issue_list(&groups),
+            OutputFormat::Markdown | OutputFormat::Html => unreachable!("rendered as a snapshot"),
+        }
+        if output == OutputFormat::Table && issues.has_next_page {
+            eprintln!(
+                "… more issues available; groups only cover the first {}",
+                args.limit
+            );
+        }
+        return Ok(());
+    }
+
+    match output {
+        OutputFormat::Json => print_json(&issues)?,
+        OutputFormat::Ndjson => export::print_ndjson(&issues.issues)?,
+        OutputFormat::Table => {
+            render_issue_list(&issues.issues);
+            if issues.has_next_page {
+                match (&issues.end_cursor, args.page) {
+                    (Some(cursor), Some(page)) => eprintln!(
+                        "… more issues available. Use --page {} or --after \"{}\" to continue.",
+                        page + 1,
+                        cursor
+                    ),
+                    (Some(cursor), None) => eprintln!(
+                        "… more issues available. Use --after \"{}\" or --all to continue.",
+                        cursor
+                    ),
+                    (None, _) => eprintln!("… more issues available."),
+                }
+            }
+        }
+        OutputFormat::Markdown | OutputFormat::Html => unreachable!("rendered as a snapshot"),
     }
-    if let Some(display) = &viewer.display_name {
-        println!("Display   : {}", display);
+
+    Ok(())
+}
+
+/// `issue list` across several profiles, merged in `sort` order with a WORKSPACE column.
+async fn issue_list_profiles(
+    args: IssueListArgs,
+    sort: IssueSort,
+    profiles: Vec<String>,
+    output: OutputFormat,
+) -> Result<()> {
+    let args = Arc::new(args);
+    let results = profiles::fan_out(profiles, |profile| {
+        let args = args.clone();
+        async move {
+            let session = load_session(&profile).await?;
+            let service = IssueService::new(build_client(&session)?);
+            let mut options = issue_query_options(&service, &args.filter, args.limit).await?;
+            options.sort = Some(sort);
+            list_issue_pages(&service, options, &args).await
+        }
+    })
+    .await?;
+
+    let mut issues: Vec<(&str, &IssueSummary)> = results
+        .iter()
+        .flat_map(|(profile, result)| {
+            result
+                .issues
+                .iter()
+                .map(move |issue| (profile.as_str(), issue))
+        })
+        .collect();
+    issues.sort_by(|(_, a), (_, b)| sort.compare(a, b));
+
+    if let Some(format) = output.share() {
+        // A section per workspace stands in for the WORKSPACE column.
+        let groups = results
+            .iter()
+            .map(|(profile, result)| IssueGroup {
+                label: profile.clone(),
+                issues: result.issues.iter().collect(),
+            })
+            .collect();
+        print!(
+            "{}",
+            Snapshot::grouped("Linear issues", groups).render(format)
+        );
+        return Ok(());
     }
-    if let Some(email) = &viewer.email {
-        println!("Email     : {}", email);
+
+    match output {
+        OutputFormat::Json => {
+            let issues = issues
+                .iter()
+                .map(|(profile, issue)| with_workspace(issue, profile))
+                .collect::<Result<Vec<_>>>()?;
+            print_json(&issues)?;
+        }
+        OutputFormat::Ndjson => {
+            for (profile, issue) in &issues {
+                println!("{}", json_line(&with_workspace(issue, profile)?)?);
+            }
+        }
+        OutputFormat::Table => {
+            let mut table = Table::new().column("WORKSPACE", 10);
+            table = issue_columns(table);
+            for (profile, issue) in &issues {
+                let mut cells = vec![Cell::from(*profile)];
+                cells.extend(issue_cells(issue));
+                table.row(cells);
+            }
+            table.print();
+            for (profile, result) in &results {
+                if result.has_next_page {
+                    eprintln!("… more issues available in {}", profile);
+                }
+            }
+        }
+        OutputFormat::Markdown | OutputFormat::Html => unreachable!("rendered as a snapshot"),
     }
-    println!("Created   : {}", viewer.created_at.to_rfc3339());
+    Ok(())
 }
 
-async fn issue_list(args: IssueListArgs) -> Result<()> {
+/// `issue list --explain`: each phrase of `--query` with the condition it became, and the
+/// filter the list would send.
+async fn issue_list_explain(args: &IssueListArgs) -> Result<()> {
     let session = load_session(&args.profile).await?;
-    let client =
-        LinearGraphqlClient::from_session(&session).context("failed to build GraphQL client")?;
-    let service = IssueService::new(client);
+    let service = IssueService::new(build_client(&session)?);
+    let text = args.filter.query.as_deref().unwrap_or_default();
+    let interpretation = interpret_query(&service, text).await?;
+    let flags = IssueFilterArgs {
+        query: None,
+        ..args.filter.clone()
+    };
+    let mut options = issue_query_options(&service, &flags, args.limit).await?;
+    options.conditions = Some(interpretation.query);
+    print_json(&json!({
+        "query": text,
+        "phrases": interpretation.phrases,
+        "filter": IssueQuery::from(options).filter(),
+    }))
+}
+
+/// Read a plain-English `--query` against the workspace's label names and the local clock.
+async fn interpret_query(service: &IssueService, text: &str) -> Result<phrase::Interpretation> {
+    let labels = LabelService::new(service.client().clone())
+        .all()
+        .await
+        .context("failed to load labels")?;
+    let labels: Vec<String> = labels.into_iter().map(|label| label.name).collect();
+    phrase::interpret(text, &labels, Local::now())
+        .map_err(|err| LinearError::validation(format!("--query: {err}")).into())
+}
+
+/// The filter flags that were given, e.g. `team=ENG, state=Todo`, for snapshot subtitles.
+fn filter_summary(filter: &IssueFilterArgs) -> Option<String> {
+    let team = filter
+        .team
+        .as_ref()
+        .or(filter.team_key.as_ref())
+        .or(filter.team_id.as_ref());
+    let state = filter.state.as_ref().or(filter.state_id.as_ref());
+    let mut parts = Vec::new();
+    if let Some(team) = team {
+        parts.push(format!("team={team}"));
+    }
+    if let Some(state) = state {
+        parts.push(format!("state={state}"));
+    }
+    if let Some(assignee) = &filter.assignee_id {
+        parts.push(format!("assignee={assignee}"));
+    }
+    if !filter.label_ids.is_empty() {
+        parts.push(format!("labels={}", filter.label_ids.join(",")));
+    }
+    if let Some(contains) = &filter.contains {
+        parts.push(format!("title~'{contains}'"));
+    }
+    if let Some(query) = &filter.query {
+        parts.push(format!("'{query}'"));
+    }
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
+
+/// Translate filter flags into query options, resolving team and state names to ids.
+async fn issue_query_options(
+    service: &IssueService,
+    filter: &IssueFilterArgs,
+    limit: usize,
+) -> Result<IssueQueryOptions> {
     let mut options = IssueQueryOptions {
-        limit: args.limit,
-        team_key: args.team_key.clone(),
-        team_id: args.team_id.clone(),
-        assignee_id: args.assignee_id.clone(),
-        state_id: args.state_id.clone(),
-        label_ids: args.label_ids.clone(),
-        title_contains: args.contains.clone(),
+        limit,
+        team_key: filter.team_key.clone(),
+        team_id: filter.team_id.clone(),
+        assignee_id: filter.assignee_id.clone(),
+        state_id: filter.state_id.clone(),
+        label_ids: filter.label_ids.clone(),
+        title_contains: filter.contains.clone(),
         after: None,
         ..Default::default()
     };
+    if let Some(text) = &filter.query {
+        options.conditions = Some(interpret_query(service, text).await?.query);
+    }
 
     if options.team_id.is_none() {
-        if let Some(team_input) = args.team.clone() {
-            options.team_id = Some(
-                service
-                    .resolve_team_id(&team_input)
-                    .await?
-                    .ok_or_else(|| anyhow!("team '{}' not found", team_input))?,
-            );
+        if let Some(team_input) = &filter.team {
+            options.team_id = Some(resolve::team_id(service, team_input).await?);
             options.team_key = None;
-        } else if let Some(team_id) = args.team_id.clone() {
-            options.team_id = Some(team_id);
         }
     }
 
-    if let Some(state_name) = args.state.clone() {
-        let team_id = options
-            .team_id
-            .as_ref()
-            .ok_or_else(|| anyhow!("--state requires --team/--team-id to resolve workflow"))?;
-        options.state_id = Some(
-            service
-                .resolve_state_id(team_id, &state_name)
-                .await?
-                .ok_or_else(|| anyhow!("state '{}' not found for team", state_name))?,
-        );
+    if let Some(state_name) = &filter.state {
+        let team_id = options.team_id.as_ref().ok_or_else(|| {
+            LinearError::validation("--state requires --team/--team-id to resolve workflow")
+        })?;
+        options.state_id = Some(resolve::state_id(service, team_id, state_name).await?);
+    }
+
+    Ok(options)
+}
+
+async fn issue_list_url(
+    client: &LinearGraphqlClient,
+    service: &IssueService,
+    args: &IssueListArgs,
+    options: &IssueQueryOptions,
+) -> Result<()> {
+    let mut filter = IssueListFilter {
+        team_key: args.filter.team_key.clone(),
+        assignee_id: options.assignee_id.clone(),
+        label_count: options.label_ids.len(),
+        title_contains: options.title_contains.clone(),
+        ..Default::default()
+    };
+    if let Some(team_id) = &options.team_id {
+        let teams = service.teams().await.context("GraphQL request failed")?;
+        filter.team_key = teams
+            .into_iter()
+            .find(|team| &team.id == team_id)
+            .map(|team| team.key);
+        if let Some(state_id) = &options.state_id {
+            let state = service
+                .workflow_states(team_id)
+                .await
+                .context("GraphQL request failed")?
+                .into_iter()
+                .find(|state| &state.id == state_id);
+            filter.state_type = state.as_ref().and_then(|state| state.type_name.clone());
+            filter.state_name = state.map(|state| state.name);
+        }
+    }
+    if filter.state_name.is_none() {
+        filter.state_name = options.state_id.clone();
+    }
+    if let Some(assignee_id) = &options.assignee_id {
+        let viewer = client.viewer().await.context("GraphQL request failed")?;
+        filter.assignee_is_viewer = &viewer.id == assignee_id;
     }
 
-    let issues = service
-        .list(options)
+    let organization = client
+        .organization()
         .await
         .context("GraphQL request failed")?;
+    let link = web::issue_list_link(&organization.url_key, &filter).ok_or_else(|| {
+        LinearError::validation(
+            "--as-url needs a team, --contains, or your own --assignee-id to build a web view",
+        )
+    })?;
 
     if args.json {
-        println!("{}", serde_json::to_string_pretty(&issues)?);
+        print_json(&link)?;
     } else {
-        render_issue_list(&issues.issues);
-        if issues.has_next_page {
-            eprintln!("… more issues available (use pagination commands in the TUI)");
+        println!("{}", link.url);
+        for filter in &link.unmapped {
+            eprintln!("note: not reflected in the web URL: {}", filter);
         }
     }
-
+    if args.open {
+        open::that(&link.url).map_err(|err| anyhow::anyhow!("failed to open browser: {}", err))?;
+    }
     Ok(())
 }
 
 async fn issue_create(args: IssueCreateArgs) -> Result<()> {
-    let session = load_session(&args.profile).await?;
-    let client =
-        LinearGraphqlClient::from_session(&session).context("failed to build GraphQL client")?;
+    let session = load_session_as(&args.profile, actor(args.as_app)).await?;
+    let client = build_client(&session)?;
     let service = IssueService::new(client);
 
+    let prefill = match (&args.from_url, args.from_clipboard) {
+        (Some(url), _) => Some(prefill::from_url(&service, url).await?),
+        (None, true) => Some(prefill::from_clipboard(&service).await?),
+        (None, false) => None,
+    };
+
     let team_id = match (&args.team_id, &args.team) {
         (Some(id), _) => id.clone(),
-        (None, Some(team_input)) => service
-            .resolve_team_id(team_input)
-            .await?
-            .ok_or_else(|| anyhow!("team '{}' not found", team_input))?,
-        (None, None) => return Err(anyhow!("--team or --team-id is required")),
+        (None, Some(team_input)) => resolve::team_id(&service, team_input).await?,
+        (None, None) => match prefill.as_ref().and_then(|source| source.team_id.clone()) {
+            Some(team_id) => team_id,
+            None => match settings::default_team()? {
+                Some(team_input) => resolve::team_id(&service, &team_input).await?,
+                None => return Err(LinearError::validation(
+                    "--team or --team-id is required (or set one with `linear config set team`)",
+                )
+                .into()),
+            },
+        },
     };
 
     let mut state_id = args.state_id.clone();
     if state_id.is_none() {
         if let Some(state_name) = &args.state {
-            state_id = Some(
-                service
-                    .resolve_state_id(&team_id, state_name)
-                    .await?
-                    .ok_or_else(|| anyhow!("state '{}' not found for team", state_name))?,
-            );
+            state_id = Some(resolve::state_id(&service, &team_id, state_name).await?);
         }
     }
+    if let Some(report) = &args.from_junit {
+        return junit::file_failures(&service, &args, report, team_id, state_id).await;
+    }
 
-    let mut options = IssueCreateOptions::new(team_id, args.title.clone());
-    options.description = args.description.clone();
+    let (title, description) = match &prefill {
+        Some(source) => (
+            args.title.clone().unwrap_or_else(|| source.title.clone()),
+            Some(source.description(args.description.as_deref())),
+        ),
+        None => (
+            args.title.clone().expect("clap requires --title"),
+            args.description.clone(),
+        ),
+    };
+    args.confirmation
+        .ensure(Impact::Mutation, &format!("create issue \"{}\"", title))
+        .await?;
+    let mut options = IssueCreateOptions::new(team_id, title);
+    options.description = description;
     options.assignee_id = args.assignee_id.clone();
     options.state_id = state_id;
     options.label_ids = args.label_ids.clone();
-    options.priority = args.priority;
+    options.priority = args.priority.map(Priority::value);
 
     let issue = service
         .create(options)
@@ -933,7 +2947,7 @@ async fn issue_create(args: IssueCreateArgs) -> Result<()> {
         .context("GraphQL request failed")?;
 
     if args.json {
-        println!("{}", serde_json::to_string_pretty(&issue)?);
+        print_json(&issue)?;
     } else {
         println!("Created {}", issue.identifier);
         println!();
@@ -945,75 +2959,194 @@ async fn issue_create(args: IssueCreateArgs) -> Result<()> {
 
 async fn issue_update(args: IssueUpdateArgs) -> Result<()> {
     let session = load_session(&args.profile).await?;
-    let client =
-        LinearGraphqlClient::from_session(&session).context("failed to build GraphQL client")?;
+    let client = build_client(&session)?;
     let service = IssueService::new(client.clone());
-    let mut issue = service
-        .get_by_key(&args.key)
-        .await
-        .with_context(|| format!("unable to load issue {}", args.key))?;
-
-    let mut input = IssueUpdateInput::default();
-    input.title = args.title.clone();
-    input.description = args.description.clone();
-    input.assignee_id = args.assignee_id.clone();
-    input.priority = args.priority;
-    input.project_id = args.project_id.clone();
 
-    if args.clear_labels {
-        input.label_ids = Some(Vec::new());
-    } else if !args.label_ids.is_empty() {
-        input.label_ids = Some(args.label_ids.clone());
+    if args.batch.stdin_keys {
+        args.confirmation
+            .ensure(Impact::Mutation, "update the issues read from stdin")
+            .await?;
+        let args = Arc::new(args);
+        return batch::run(&args.batch, args.json, |key| {
+            let service = service.clone();
+            let args = args.clone();
+            async move {
+                let issue = apply_issue_update(&service, &key, &args).await?;
+                Ok(format!(
+                    "updated ({})",
+                    issue.state.map(|state| state.name).unwrap_or_default()
+                ))
+            }
+        })
+        .await;
     }
 
-    if let Some(state_id) = &args.state_id {
-        input.state_id = Some(state_id.clone());
-    } else if let Some(state_name) = &args.state {
-        let team_id = issue.team.as_ref().map(|t| t.id.clone()).ok_or_else(|| {
-            anyhow!(
-                "issue '{}' has no team; state name cannot be resolved",
-                args.key
+    let key = key_or_branch(&args.key)?;
+    let current = service
+        .get_by_key(&key)
+        .await
+        .with_context(|| format!("unable to load issue {}", key))?;
+    let input = issue_update_input(&service, &current, &args).await?;
+    let diff = issue_update_diff(&current, &input);
+    if !diff.is_empty() {
+        // Keep stdout parseable for --json; the diff is only needed for the prompt there.
+        if !args.json {
+            println!("{diff}");
+        } else if args.confirm {
+            eprint!("{diff}");
+        }
+    }
+    if args.confirm {
+        if !confirm::ask(format!("Update {}?", current.identifier)).await? {
+            return Err(LinearError::validation(
+                "update not applied; re-run without --confirm to apply without prompting",
             )
-        })?;
-        let resolved = service
-            .resolve_state_id(&team_id, state_name)
-            .await?
-            .ok_or_else(|| anyhow!("state '{}' not found for issue team", state_name))?;
-        input.state_id = Some(resolved);
+            .into());
+        }
+    } else {
+        args.confirmation
+            .ensure(Impact::Mutation, &format!("update {}", current.identifier))
+            .await?;
     }
-
-    let updated = service
-        .update(&issue.id, input)
+    let issue = service
+        .update(&current.id, input)
         .await
         .context("GraphQL request failed")?;
-    issue = updated;
 
     if args.json {
-        println!("{}", serde_json::to_string_pretty(&issue)?);
+        print_json(&issue)?;
     } else {
         println!("Updated {}", issue.identifier);
         println!();
         render_issue_detail(&issue);
     }
 
-    Ok(())
+    Ok(())
+}
+
+async fn apply_issue_update(
+    service: &IssueService,
+    key: &str,
+    args: &IssueUpdateArgs,
+) -> Result<IssueDetail> {
+    let issue = service
+        .get_by_key(key)
+        .await
+        .with_context(|| format!("unable to load issue {}", key))?;
+    let input = issue_update_input(service, &issue, args).await?;
+    service
+        .update(&issue.id, input)
+        .await
+        .context("GraphQL request failed")
+}
+
+async fn issue_update_input(
+    service: &IssueService,
+    issue: &IssueDetail,
+    args: &IssueUpdateArgs,
+) -> Result<IssueUpdateInput> {
+    let mut input = IssueUpdateInput {
+        title: args.title.clone(),
+        description: args.description.clone(),
+        assignee_id: args.assignee_id.clone(),
+        priority: args.priority.map(Priority::value),
+        project_id: args.project_id.clone(),
+        ..Default::default()
+    };
+
+    if args.clear_labels {
+        input.label_ids = Some(Vec::new());
+    } else if !args.label_ids.is_empty() {
+        input.label_ids = Some(args.label_ids.clone());
+    }
+
+    if let Some(state_id) = &args.state_id {
+        input.state_id = Some(state_id.clone());
+    } else if let Some(state_name) = &args.state {
+        if issue.team.is_none() {
+            return Err(LinearError::validation(format!(
+                "issue '{}' has no team; state name cannot be resolved",
+                issue.identifier
+            ))
+            .into());
+        }
+        let state = service.resolve_transition(issue, state_name).await??;
+        input.state_id = Some(state.id);
+    }
+    Ok(input)
+}
+
+/// Unified diffs of the title and description changes in `input`, empty when neither changes.
+fn issue_update_diff(issue: &IssueDetail, input: &IssueUpdateInput) -> String {
+    let field = |name: &str, old: &str, new: Option<&String>| {
+        new.and_then(|new| {
+            diff::unified(
+                &format!("{}/{}", issue.identifier, name),
+                old,
+                new,
+                diff::DEFAULT_CONTEXT,
+            )
+        })
+    };
+    [
+        field("title", &issue.title, input.title.as_ref()),
+        field(
+            "description",
+            issue.description.as_deref().unwrap_or_default(),
+            input.description.as_ref(),
+        ),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
 }
 
 async fn issue_close(args: IssueCloseArgs) -> Result<()> {
     let session = load_session(&args.profile).await?;
-    let client =
-        LinearGraphqlClient::from_session(&session).context("failed to build GraphQL client")?;
+    let client = build_client(&session)?;
     let service = IssueService::new(client);
+    let archive = !args.restore;
+    let (impact, verb) = if archive {
+        (Impact::Destructive, "archive")
+    } else {
+        (Impact::Mutation, "restore")
+    };
+
+    if args.batch.stdin_keys {
+        args.confirmation
+            .ensure(impact, &format!("{verb} the issues read from stdin"))
+            .await?;
+        return batch::run(&args.batch, args.json, |key| {
+            let service = service.clone();
+            async move {
+                let issue = service
+                    .get_by_key(&key)
+                    .await
+                    .with_context(|| format!("unable to load issue {}", key))?;
+                service
+                    .archive(&issue.id, archive)
+                    .await
+                    .context("GraphQL request failed")?;
+                Ok(if archive { "archived" } else { "restored" }.to_string())
+            }
+        })
+        .await;
+    }
+
+    let key = single_key(&args.key);
     let issue = service
-        .get_by_key(&args.key)
+        .get_by_key(&key)
         .await
-        .with_context(|| format!("unable to load issue {}", args.key))?;
+        .with_context(|| format!("unable to load issue {}", key))?;
+    args.confirmation
+        .ensure(impact, &format!("{verb} {}", issue.identifier))
+        .await?;
     let archived = service
-        .archive(&issue.id, !args.restore)
+        .archive(&issue.id, archive)
         .await
         .context("GraphQL request failed")?;
     if args.json {
-        println!("{}", serde_json::to_string_pretty(&archived)?);
+        print_json(&archived)?;
     } else {
         if args.restore {
             println!("Restored {}", archived.identifier);
@@ -1027,19 +3160,39 @@ async fn issue_close(args: IssueCloseArgs) -> Result<()> {
 }
 
 async fn issue_delete(args: IssueDeleteArgs) -> Result<()> {
-    if !args.yes {
-        return Err(anyhow!(
-            "deletion requires confirmation; re-run with --yes if you are sure"
-        ));
-    }
     let session = load_session(&args.profile).await?;
-    let client =
-        LinearGraphqlClient::from_session(&session).context("failed to build GraphQL client")?;
+    let client = build_client(&session)?;
     let service = IssueService::new(client);
+
+    if args.batch.stdin_keys {
+        args.confirmation
+            .ensure(Impact::Destructive, "delete the issues read from stdin")
+            .await?;
+        return batch::run(&args.batch, false, |key| {
+            let service = service.clone();
+            async move {
+                let issue = service
+                    .get_by_key(&key)
+                    .await
+                    .with_context(|| format!("unable to load issue {}", key))?;
+                service
+                    .delete(&issue.id)
+                    .await
+                    .context("GraphQL request failed")?;
+                Ok("deleted".to_string())
+            }
+        })
+        .await;
+    }
+
+    let key = single_key(&args.key);
     let issue = service
-        .get_by_key(&args.key)
+        .get_by_key(&key)
         .await
-        .with_context(|| format!("unable to load issue {}", args.key))?;
+        .with_context(|| format!("unable to load issue {}", key))?;
+    args.confirmation
+        .ensure(Impact::Destructive, &format!("delete {}", issue.identifier))
+        .await?;
     service
         .delete(&issue.id)
         .await
@@ -1049,20 +3202,50 @@ async fn issue_delete(args: IssueDeleteArgs) -> Result<()> {
 }
 
 async fn issue_comment(args: IssueCommentArgs) -> Result<()> {
-    let session = load_session(&args.profile).await?;
-    let client =
-        LinearGraphqlClient::from_session(&session).context("failed to build GraphQL client")?;
+    let session = load_session_as(&args.profile, actor(args.as_app)).await?;
+    let client = build_client(&session)?;
     let service = IssueService::new(client);
+
+    if args.batch.stdin_keys {
+        args.confirmation
+            .ensure(Impact::Mutation, "comment on the issues read from stdin")
+            .await?;
+        let body = Arc::new(args.body.clone());
+        return batch::run(&args.batch, args.json, |key| {
+            let service = service.clone();
+            let body = body.clone();
+            async move {
+                let issue = service
+                    .get_by_key(&key)
+                    .await
+                    .with_context(|| format!("unable to load issue {}", key))?;
+                let comment = service
+                    .comment(&issue.id, &body)
+                    .await
+                    .context("GraphQL request failed")?;
+                Ok(format!("comment {}", comment.id))
+            }
+        })
+        .await;
+    }
+
+    let key = key_or_branch(&args.key)?;
     let issue = service
-        .get_by_key(&args.key)
+        .get_by_key(&key)
         .await
-        .with_context(|| format!("unable to load issue {}", args.key))?;
+        .with_context(|| format!("unable to load issue {}", key))?;
+    args.confirmation
+        .ensure(
+            Impact::Mutation,
+            &format!("comment on {}", issue.identifier),
+        )
+        .await?;
     let comment = service
         .comment(&issue.id, &args.body)
         .await
         .context("GraphQL request failed")?;
     if args.json {
-        println!("{}", serde_json::to_string_pretty(&comment)?);
+        print_json(&comment)?;
     } else {
         println!("Comment added to {}.", issue.identifier);
         println!();
@@ -1071,357 +3254,323 @@ async fn issue_comment(args: IssueCommentArgs) -> Result<()> {
     Ok(())
 }
 
-async fn issue_view(args: IssueViewArgs) -> Result<()> {
-    let session = load_session(&args.profile).await?;
-    let client =
-        LinearGraphqlClient::from_session(&session).context("failed to build GraphQL client")?;
-    let service = IssueService::new(client);
-    let issue = service
-        .get_by_key(&args.key)
-        .await
-        .context("GraphQL request failed")?;
-
-    if args.json {
-        println!("{}", serde_json::to_string_pretty(&issue)?);
-    } else {
-        render_issue_detail(&issue);
-    }
-
-    Ok(())
+/// Positional key for commands that also accept `--stdin-keys`; clap guarantees one is present.
+fn single_key(key: &Option<String>) -> String {
+    key.clone().unwrap_or_default()
 }
 
-async fn team_list(args: TeamListArgs) -> Result<()> {
-    let session = load_session(&args.profile).await?;
-    let client =
-        LinearGraphqlClient::from_session(&session).context("failed to build GraphQL client")?;
-    let service = IssueService::new(client);
-    let teams = service.teams().await.context("GraphQL request failed")?;
-
-    if args.json {
-        println!("{}", serde_json::to_string_pretty(&teams)?);
-    } else {
-        render_team_list(&teams);
+/// The given key, or the one encoded in the current git branch name.
+fn key_or_branch(key: &Option<String>) -> Result<String> {
+    if let Some(key) = key {
+        return Ok(key.clone());
+    }
+    match git_context::current_issue_key() {
+        Some((key, branch)) => {
+            eprintln!("Using {} from branch {}", key, branch);
+            Ok(key)
+        }
+        None => Err(LinearError::validation(
+            "no issue key given and the current git branch does not name one (e.g. eng-123-fix-login)",
+        )
+        .into()),
     }
-
-    Ok(())
 }
 
-async fn state_list(args: StateListArgs) -> Result<()> {
+async fn issue_merge(args: IssueMergeArgs) -> Result<()> {
     let session = load_session(&args.profile).await?;
-    let client =
-        LinearGraphqlClient::from_session(&session).context("failed to build GraphQL client")?;
+    let client = build_client(&session)?;
     let service = IssueService::new(client);
-    let result = service
-        .workflow_states_for_team(&args.team)
-        .await?
-        .ok_or_else(|| anyhow!("team '{}' not found", args.team))?;
-    let (team, states) = result;
-
+    args.confirmation
+        .ensure(
+            Impact::Destructive,
+            &format!("merge {} into {}", args.key, args.into),
+        )
+        .await?;
+    let outcome = service
+        .merge(&args.key, &args.into, args.move_sub_issues)
+        .await
+        .with_context(|| format!("unable to merge {} into {}", args.key, args.into))?;
     if args.json {
+        print_json(&outcome)?;
+        return Ok(());
+    }
+    println!(
+        "Merged {} into {}.",
+        outcome.duplicate.identifier, outcome.target.identifier
+    );
+    match &outcome.canceled_state {
+        Some(state) => println!("  {} moved to '{}'", outcome.duplicate.identifier, state),
+        None => println!(
+            "  no canceled state found; {} left in its current state",
+            outcome.duplicate.identifier
+        ),
+    }
+    println!(
+        "  {} label(s) and {} subscriber(s) copied",
+        outcome.labels_added, outcome.subscribers_added
+    );
+    if !outcome.labels_skipped.is_empty() {
         println!(
-            "{}",
-            serde_json::to_string_pretty(&json!({
-                "team": team,
-                "states": states,
-            }))?
+            "  not copied (labels of another team): {}",
+            outcome.labels_skipped.join(", ")
+        );
+    }
+    if !outcome.moved_sub_issues.is_empty() {
+        println!(
+            "  sub-issues moved: {}",
+            outcome.moved_sub_issues.join(", ")
         );
-    } else {
-        println!("Team: {} ({})", team.name, team.key);
-        render_state_list(&states);
     }
-
     Ok(())
 }
 
-async fn project_list(args: ProjectListArgs) -> Result<()> {
-    let session = load_session(&args.profile).await?;
-    let client =
-        LinearGraphqlClient::from_session(&session).context("failed to build GraphQL client")?;
-    let service = ProjectService::new(client);
-
-    let sort = parse_project_sort(&args.sort)?;
-    let options = ProjectQueryOptions {
-        limit: args.limit,
-        after: args.after.clone(),
-        state: args.state.clone(),
-        status: args.status.clone(),
-        team_id: args.team_id.clone(),
-        sort: Some(sort),
+async fn issue_view(args: IssueViewArgs) -> Result<()> {
+    if args.keys.len() > 1 {
+        return issue_view_many(args).await;
+    }
+    let key = key_or_branch(&args.keys.first().cloned())?;
+    let summarize = args.summary.then(summary::command).transpose()?;
+    // Image previews download with the client, so they skip the daemon.
+    let from_daemon = if args.images {
+        None
+    } else {
+        daemon::call(&args.profile, "view", json!({ "key": key })).await
+    };
+    let (issue, client): (IssueDetail, _) = match from_daemon {
+        Some(issue) => (issue?, None),
+        None => {
+            let session = load_session(&args.profile).await?;
+            let client = build_client(&session)?;
+            let issue = IssueService::new(client.clone())
+                .get_by_key(&key)
+                .await
+                .context("GraphQL request failed")?;
+            (issue, Some(client))
+        }
     };
 
-    let projects = service
-        .list(options)
-        .await
-        .context("GraphQL request failed")?;
+    let summary = match &summarize {
+        Some(command) => Some(summary::summarize(command, &issue).await?),
+        None => None,
+    };
 
-    if args.json {
-        println!("{}", serde_json::to_string_pretty(&projects)?);
+    if let Some(template) = &args.template {
+        print_template(template, &issue_record(&issue, summary.as_deref())?)?;
+    } else if args.json {
+        print_json(&issue_record(&issue, summary.as_deref())?)?;
     } else {
-        render_project_list(&projects.nodes);
-        if projects.has_next_page {
-            if let Some(cursor) = &projects.end_cursor {
-                eprintln!(
-                    "… more projects available. Use --after \"{}\" to continue.",
-                    cursor
-                );
-            } else {
-                eprintln!("… more projects available.");
-            }
+        let images = render_issue_detail(&issue);
+        if let Some(client) = client.filter(|_| args.images) {
+            images::preview(&client, &images).await;
+        }
+        if let Some(summary) = &summary {
+            render_summary(&issue, summary);
         }
     }
-    Ok(())
-}
-
-async fn project_create(args: ProjectCreateArgs) -> Result<()> {
-    let session = load_session(&args.profile).await?;
-    let client =
-        LinearGraphqlClient::from_session(&session).context("failed to build GraphQL client")?;
-    let service = ProjectService::new(client);
-
-    let mut input = ProjectCreateInput::new(args.name.clone());
-    input.description = args.description.clone();
-    input.state = args.state.clone();
-    input.start_date = args.start_date.clone();
-    input.target_date = args.target_date.clone();
-    input.lead_id = args.lead_id.clone();
-    if !args.team_ids.is_empty() {
-        input.team_ids = args.team_ids.clone();
-    }
-
-    let project = service
-        .create(input)
-        .await
-        .context("GraphQL request failed")?;
 
-    if args.json {
-        println!("{}", serde_json::to_string_pretty(&project)?);
-    } else {
-        println!("Created project {}", project.name);
-        println!();
-        render_project_detail(&project);
+    if let Some(target) = args.copy {
+        let (label, value) = match target {
+            CopyTarget::Url => ("copy-what-url", issue.url.as_deref()),
+            CopyTarget::Key => ("copy-what-key", Some(issue.identifier.as_str())),
+            CopyTarget::Branch => ("copy-what-branch", issue.branch_name.as_deref()),
+        };
+        let what = i18n::tr(label);
+        let value = value.ok_or_else(|| {
+            LinearError::not_found(i18n::tr_args(
+                "no-value",
+                &[("issue", &issue.identifier), ("what", &what)],
+            ))
+        })?;
+        let method = clipboard::write(value)?.to_string();
+        eprintln!(
+            "{}",
+            i18n::tr_args(
+                "cli-copied",
+                &[("what", &what), ("method", &method), ("value", value)]
+            )
+        );
     }
+
     Ok(())
 }
 
-async fn project_update(args: ProjectUpdateArgs) -> Result<()> {
-    let session = load_session(&args.profile).await?;
-    let client =
-        LinearGraphqlClient::from_session(&session).context("failed to build GraphQL client")?;
-    let service = ProjectService::new(client);
-
-    let mut input = ProjectUpdateInput::default();
-    input.name = args.name.clone();
-    input.description = args.description.clone();
-    input.state = args.state.clone();
-    input.start_date = args.start_date.clone();
-    input.target_date = args.target_date.clone();
-    input.lead_id = args.lead_id.clone();
-    if !args.team_ids.is_empty() {
-        input.team_ids = args.team_ids.clone();
-    }
-
-    let project = service
-        .update(&args.id, input)
-        .await
-        .context("GraphQL request failed")?;
+/// `issue view` with several keys: up to `--concurrency` are fetched at once, and each is
+/// printed in the order given as soon as the ones before it are. Keys that fail are reported on
+/// stderr without stopping the rest; `--json` prints an array of the issues found.
+async fn issue_view_many(args: IssueViewArgs) -> Result<()> {
+    if args.copy.is_some() {
+        return Err(LinearError::validation("--copy takes a single issue key").into());
+    }
+    let mut keys: Vec<String> = Vec::new();
+    for key in &args.keys {
+        if !keys.iter().any(|seen| seen.eq_ignore_ascii_case(key)) {
+            keys.push(key.clone());
+        }
+    }
+
+    let summarize = args.summary.then(summary::command).transpose()?;
 
+    let session = load_session(&args.profile).await?;
+    let client = build_client(&session)?;
+    let service = IssueService::new(client.clone());
+    let mut fetched = stream::iter(keys)
+        .map(|key| {
+            let service = service.clone();
+            async move {
+                let issue = service.get_by_key(&key).await;
+                (key, issue)
+            }
+        })
+        .buffered(args.concurrency as usize);
+
+    let mut issues = Vec::new();
+    let mut failed = Vec::new();
+    while let Some((key, issue)) = fetched.next().await {
+        let issue = match issue.context("GraphQL request failed") {
+            Ok(issue) => issue,
+            Err(err) => {
+                eprintln!("{key}: {err:#}");
+                failed.push(ErrorKind::classify(&err));
+                continue;
+            }
+        };
+        let summary = match &summarize {
+            Some(command) => match summary::summarize(command, &issue).await {
+                Ok(summary) => Some(summary),
+                Err(err) => {
+                    eprintln!("{key}: {err:#}");
+                    failed.push(ErrorKind::classify(&err));
+                    continue;
+                }
+            },
+            None => None,
+        };
+        if let Some(template) = &args.template {
+            print_template(template, &issue_record(&issue, summary.as_deref())?)?;
+        } else if !args.json {
+            if !issues.is_empty() {
+                println!();
+            }
+            let images = render_issue_detail(&issue);
+            if args.images {
+                images::preview(&client, &images).await;
+            }
+            if let Some(summary) = &summary {
+                render_summary(&issue, summary);
+            }
+        }
+        issues.push(issue_record(&issue, summary.as_deref())?);
+    }
     if args.json {
-        println!("{}", serde_json::to_string_pretty(&project)?);
-    } else {
-        println!("Updated project {}", project.name);
-        println!();
-        render_project_detail(&project);
+        print_json(&issues)?;
+    }
+
+    match failed.first() {
+        None => Ok(()),
+        Some(&kind) => Err(LinearError::new(
+            kind,
+            format!(
+                "{} of {} issue(s) could not be loaded",
+                failed.len(),
+                failed.len() + issues.len()
+            ),
+        )
+        .into()),
     }
-    Ok(())
 }
 
-async fn project_archive(args: ProjectArchiveArgs) -> Result<()> {
-    let session = load_session(&args.profile).await?;
-    let client =
-        LinearGraphqlClient::from_session(&session).context("failed to build GraphQL client")?;
-    let service = ProjectService::new(client);
-    let project = service
-        .archive(&args.id, !args.restore)
-        .await
-        .context("GraphQL request failed")?;
-    if args.json {
-        println!("{}", serde_json::to_string_pretty(&project)?);
-    } else if args.restore {
-        println!("Restored project {}", project.name);
-    } else {
-        println!("Archived project {}", project.name);
+fn render_issue_list(issues: &[IssueSummary]) {
+    let mut table = issue_table();
+    for issue in issues {
+        issue_row(&mut table, issue);
     }
-    Ok(())
+    table.print();
 }
 
-async fn cycle_list(args: CycleListArgs) -> Result<()> {
-    let session = load_session(&args.profile).await?;
-    let client =
-        LinearGraphqlClient::from_session(&session).context("failed to build GraphQL client")?;
-    let service = CycleService::new(client);
-    let sort = parse_cycle_sort(&args.sort)?;
-    let options = CycleQueryOptions {
-        limit: args.limit,
-        after: args.after.clone(),
-        team_id: args.team_id.clone(),
-        state: args.state.clone(),
-        sort: Some(sort),
-    };
-    let cycles = service
-        .list(options)
-        .await
-        .context("GraphQL request failed")?;
-    if args.json {
-        println!("{}", serde_json::to_string_pretty(&cycles)?);
-    } else {
-        render_cycle_list(&cycles.nodes);
-        if cycles.has_next_page {
-            if let Some(cursor) = &cycles.end_cursor {
-                eprintln!(
-                    "… more cycles available. Use --after \"{}\" to continue.",
-                    cursor
-                );
-            } else {
-                eprintln!("… more cycles available.");
-            }
+/// One table with a `Label (count)` heading per group, so columns line up across groups.
+fn render_grouped_issue_list(groups: &[IssueGroup<'_>]) {
+    let mut table = issue_table();
+    for group in groups {
+        table.section(format!("{} ({})", group.label, group.issues.len()));
+        for issue in &group.issues {
+            issue_row(&mut table, issue);
         }
     }
-    Ok(())
+    table.print();
 }
 
-async fn cycle_update(args: CycleUpdateArgs) -> Result<()> {
-    let session = load_session(&args.profile).await?;
-    let client =
-        LinearGraphqlClient::from_session(&session).context("failed to build GraphQL client")?;
-    let service = CycleService::new(client);
-    let mut input = CycleUpdateInput::default();
-    input.name = args.name.clone();
-    input.starts_at = args.start_date.clone();
-    input.ends_at = args.end_date.clone();
-    input.state = args.state.clone();
-    let cycle = service
-        .update(&args.id, input)
-        .await
-        .context("GraphQL request failed")?;
-    if args.json {
-        println!("{}", serde_json::to_string_pretty(&cycle)?);
-    } else {
-        println!(
-            "Updated cycle {}",
-            cycle.name.as_deref().unwrap_or(&cycle.id)
-        );
-        println!(
-            "Team: {}  Number: {}  Span: {} → {}",
-            cycle
-                .team
-                .as_ref()
-                .map(|t| t.key.clone())
-                .unwrap_or_else(|| "-".into()),
-            cycle.number,
-            cycle.starts_at.as_deref().unwrap_or("-"),
-            cycle.ends_at.as_deref().unwrap_or("-")
-        );
-    }
-    Ok(())
+fn group_json(group: &IssueGroup<'_>) -> serde_json::Value {
+    json!({
+        "group": group.label,
+        "count": group.issues.len(),
+        "issues": group.issues,
+    })
 }
 
-async fn label_list(args: LabelListArgs) -> Result<()> {
-    let session = load_session(&args.profile).await?;
-    let client =
-        LinearGraphqlClient::from_session(&session).context("failed to build GraphQL client")?;
-    let service = LabelService::new(client);
-    let labels = service
-        .list(&args.team_id)
-        .await
-        .context("GraphQL request failed")?;
-    if args.json {
-        println!("{}", serde_json::to_string_pretty(&labels)?);
-    } else {
-        render_label_list(&labels);
-    }
-    Ok(())
+fn issue_table() -> Table {
+    issue_columns(Table::new())
 }
 
-async fn label_create(args: LabelCreateArgs) -> Result<()> {
-    let session = load_session(&args.profile).await?;
-    let client =
-        LinearGraphqlClient::from_session(&session).context("failed to build GraphQL client")?;
-    let service = LabelService::new(client);
-    let mut input = IssueLabelCreateInput {
-        team_id: args.team_id.clone(),
-        name: args.name.clone(),
-        description: args.description.clone(),
-        color: args.color.clone(),
+fn issue_columns(table: Table) -> Table {
+    table
+        .column("IDENTIFIER", 12)
+        .flex_column("TITLE", 16, 60)
+        .flex_column("STATE", 8, 16)
+        .flex_column("ASSIGNEE", 8, 20)
+        .column("PRIORITY", 11)
+}
+
+fn issue_row(table: &mut Table, issue: &IssueSummary) {
+    table.row(issue_cells(issue));
+}
+
+fn issue_cells(issue: &IssueSummary) -> [Cell; 5] {
+    let state = issue.state.as_ref().map(|s| s.name.as_str()).unwrap_or("-");
+    let assignee = issue
+        .assignee
+        .as_ref()
+        .and_then(|a| a.display_name.as_deref().or(a.name.as_deref()))
+        .unwrap_or("-");
+    [
+        Cell::link(issue.identifier.clone(), issue.url.as_deref()),
+        issue.title.as_str().into(),
+        state.into(),
+        assignee.into(),
+        priority_cell(issue.priority),
+    ]
+}
+
+fn priority_cell(value: Option<i32>) -> Cell {
+    let color = match value.and_then(Priority::from_value) {
+        Some(Priority::Urgent) => Color::Red,
+        Some(Priority::High) => Color::DarkYellow,
+        Some(Priority::Medium) => Color::Blue,
+        Some(Priority::Low) | Some(Priority::None) => Color::DarkGrey,
+        None => return priority::label_for(value).into(),
     };
-    if input
-        .color
-        .as_deref()
-        .map(|c| c.trim())
-        .map_or(false, |c| c.is_empty())
-    {
-        input.color = None;
-    }
-    let label = service
-        .create(input)
-        .await
-        .context("GraphQL request failed")?;
-    if args.json {
-        println!("{}", serde_json::to_string_pretty(&label)?);
-    } else {
-        println!("Created label {} ({})", label.name, label.id);
-    }
-    Ok(())
+    Cell::colored(priority::label_for(value), color)
 }
 
-async fn label_update(args: LabelUpdateArgs) -> Result<()> {
-    let session = load_session(&args.profile).await?;
-    let client =
-        LinearGraphqlClient::from_session(&session).context("failed to build GraphQL client")?;
-    let service = LabelService::new(client);
-    let mut input = IssueLabelUpdateInput::default();
-    input.name = args.name.clone();
-    input.description = args.description.clone();
-    input.color = args.color.clone();
-    let label = service
-        .update(&args.id, input)
-        .await
-        .context("GraphQL request failed")?;
-    if args.json {
-        println!("{}", serde_json::to_string_pretty(&label)?);
+/// Print the issue header and description, returning images embedded in the description.
+/// Print `record` through `template`, ending the output with a newline if it has none.
+fn print_template(template: &Template, record: &impl serde::Serialize) -> Result<()> {
+    let rendered = template.render(&serde_json::to_value(record)?);
+    if rendered.ends_with('\n') {
+        print!("{rendered}");
     } else {
-        println!("Updated label {} ({})", label.name, label.id);
+        println!("{rendered}");
     }
     Ok(())
 }
 
-fn render_issue_list(issues: &[IssueSummary]) {
-    println!(
-        "{:<12} {:<40} {:<16} {:<20} {:<8}",
-        "IDENTIFIER", "TITLE", "STATE", "ASSIGNEE", "PRIOR"
-    );
-    println!("{}", "-".repeat(100));
-    for issue in issues {
-        let state = issue.state.as_ref().map(|s| s.name.as_str()).unwrap_or("-");
-        let assignee = issue
-            .assignee
-            .as_ref()
-            .and_then(|a| a.display_name.as_deref().or(a.name.as_deref()))
-            .unwrap_or("-");
-        println!(
-            "{:<12} {:<40} {:<16} {:<20} {:<8}",
-            issue.identifier,
-            truncate(&issue.title, 40),
-            truncate(state, 16),
-            truncate(assignee, 20),
-            issue.priority.map_or("-".to_string(), |p| p.to_string())
-        );
-    }
-}
-
-fn render_issue_detail(issue: &IssueDetail) {
-    println!("{} — {}", issue.identifier, issue.title);
+fn render_issue_detail(issue: &IssueDetail) -> Vec<ImageRef> {
+    let identifier = match &issue.url {
+        Some(url) => hyperlink::link(url, &issue.identifier),
+        None => issue.identifier.clone(),
+    };
+    println!("{} — {}", identifier, issue.title);
     if let Some(url) = &issue.url {
-        println!("URL       : {}", url);
+        println!("URL       : {}", hyperlink::link(url, url));
     }
     if let Some(state) = &issue.state {
         println!("State     : {}", state.name);
@@ -1438,169 +3587,60 @@ fn render_issue_detail(issue: &IssueDetail) {
             .unwrap_or_else(|| "Unassigned".into());
         println!("Assignee  : {}", name);
     }
-    if let Some(priority) = issue.priority {
-        println!("Priority  : {}", priority);
+    if issue.priority.is_some() {
+        println!("Priority  : {}", priority::label_for(issue.priority));
     }
     let labels = issue
         .labels
         .as_ref()
-        .map(|c| c.nodes.iter().map(|l| l.name.as_str()).collect::<Vec<_>>())
+        .map(|c| c.nodes.as_slice())
         .unwrap_or_default();
     if !labels.is_empty() {
-        println!("Labels    : {}", labels.join(", "));
+        println!("Labels    : {}", color::label_chips(labels));
     }
     println!("Created   : {}", issue.created_at.to_rfc3339());
     println!("Updated   : {}", issue.updated_at.to_rfc3339());
 
+    let mut images = Vec::new();
     if let Some(description) = &issue.description {
         let trimmed = description.trim();
         if !trimmed.is_empty() {
             println!();
-            let width = 80;
-            let plain = markdown_to_text(trimmed);
-            for line in wrap(plain.trim(), width) {
+            let rendered = markdown::render(trimmed);
+            for line in wrap(rendered.text.trim(), markdown::wrap_width()) {
                 println!("{}", line);
             }
             println!();
+            images = rendered.images;
         }
     }
+    images
 }
 
-fn truncate(value: &str, max_len: usize) -> String {
-    let mut chars = value.chars();
-    let mut collected = String::new();
-    for _ in 0..max_len.saturating_sub(1) {
-        match chars.next() {
-            Some(ch) => collected.push(ch),
-            None => return value.to_owned(),
-        }
-    }
-    if chars.next().is_some() {
-        collected.push('…');
-        collected
-    } else {
-        value.to_owned()
-    }
-}
-
-fn render_team_list(teams: &[TeamSummary]) {
-    println!("{:<8} {:<32} {:<36}", "KEY", "NAME", "ID");
-    println!("{}", "-".repeat(80));
-    for team in teams {
-        println!(
-            "{:<8} {:<32} {:<36}",
-            team.key,
-            truncate(&team.name, 32),
-            truncate(&team.id, 36)
-        );
-    }
-}
-
-fn render_state_list(states: &[WorkflowStateSummary]) {
-    println!("{:<25} {:<15} {:<36}", "NAME", "TYPE", "ID");
-    println!("{}", "-".repeat(80));
-    for state in states {
-        println!(
-            "{:<25} {:<15} {:<36}",
-            truncate(&state.name, 25),
-            truncate(state.type_name.as_deref().unwrap_or("-"), 15),
-            truncate(&state.id, 36)
-        );
-    }
-}
-
-fn render_project_list(projects: &[ProjectSummary]) {
-    println!(
-        "{:<28} {:<10} {:<10} {:<20} {:<20}",
-        "NAME", "STATE", "STATUS", "START", "TARGET"
-    );
-    println!("{}", "-".repeat(96));
-    for project in projects {
-        println!(
-            "{:<28} {:<10} {:<10} {:<20} {:<20}",
-            truncate(&project.name, 28),
-            truncate(project.state.as_deref().unwrap_or("-"), 10),
-            truncate(project.status.as_deref().unwrap_or("-"), 10),
-            truncate(project.start_date.as_deref().unwrap_or("-"), 20),
-            truncate(project.target_date.as_deref().unwrap_or("-"), 20),
-        );
-    }
-}
-
-fn render_project_detail(project: &ProjectDetail) {
-    println!("ID        : {}", project.id);
-    println!("Name      : {}", project.name);
-    if let Some(desc) = &project.description {
-        if !desc.trim().is_empty() {
-            println!("Description:\n{}", desc.trim());
-        }
-    }
-    if let Some(state) = &project.state {
-        println!("State     : {}", state);
-    }
-    if let Some(status) = &project.status {
-        println!("Status    : {}", status);
-    }
-    if let Some(start) = &project.start_date {
-        println!("Start     : {}", start);
-    }
-    if let Some(target) = &project.target_date {
-        println!("Target    : {}", target);
-    }
-    if let Some(lead) = &project.lead {
-        let name = lead
-            .display_name
-            .as_ref()
-            .or(lead.name.as_ref())
-            .cloned()
-            .unwrap_or_else(|| "-".into());
-        println!("Lead      : {} ({})", name, lead.id);
-    }
-    if !project.teams.is_empty() {
-        let teams = project
-            .teams
-            .iter()
-            .map(|t| format!("{} ({})", t.name, t.key))
-            .collect::<Vec<_>>()
-            .join(", ");
-        println!("Teams     : {}", teams);
+/// `issue` as printed by `--json` and `--template`, with its `summary` when one was made.
+fn issue_record(issue: &IssueDetail, summary: Option<&str>) -> Result<serde_json::Value> {
+    let mut record = serde_json::to_value(issue)?;
+    if let Some(summary) = summary {
+        record["summary"] = json!(summary);
     }
+    Ok(record)
 }
 
-fn render_cycle_list(cycles: &[CycleSummary]) {
-    println!(
-        "{:<8} {:<8} {:<8} {:<18} {:<18} {:<12}",
-        "NUMBER", "TEAM", "STATE", "START", "END", "NAME"
-    );
-    println!("{}", "-".repeat(80));
-    for cycle in cycles {
-        println!(
-            "{:<8} {:<8} {:<8} {:<18} {:<18} {:<12}",
-            cycle.number,
-            cycle
-                .team
-                .as_ref()
-                .map(|t| t.key.clone())
-                .unwrap_or_else(|| "-".into()),
-            cycle.state.as_deref().unwrap_or("-"),
-            cycle.starts_at.as_deref().unwrap_or("-"),
-            cycle.ends_at.as_deref().unwrap_or("-"),
-            truncate(cycle.name.as_deref().unwrap_or("-"), 12)
-        );
+/// The `--summary` section under an issue's detail view.
+fn render_summary(issue: &IssueDetail, summary: &str) {
+    let described = issue
+        .description
+        .as_deref()
+        .is_some_and(|description| !description.trim().is_empty());
+    if !described {
+        println!();
     }
-}
-
-fn render_label_list(labels: &[IssueLabel]) {
-    println!("{:<32} {:<32} {:<12}", "ID", "NAME", "COLOR");
-    println!("{}", "-".repeat(80));
-    for label in labels {
-        println!(
-            "{:<32} {:<32} {:<12}",
-            truncate(&label.id, 32),
-            truncate(&label.name, 32),
-            truncate(label.color.as_deref().unwrap_or("-"), 12)
-        );
+    println!("Summary");
+    let rendered = markdown::render(summary);
+    for line in wrap(rendered.text.trim(), markdown::wrap_width()) {
+        println!("{}", line);
     }
+    println!();
 }
 
 fn render_comment(comment: &Comment) {
@@ -1612,124 +3652,10 @@ fn render_comment(comment: &Comment) {
         .cloned()
         .unwrap_or_else(|| "Unknown".into());
     println!("{} — {}", author, date);
-    println!("{}", comment.body.trim());
-}
-
-fn parse_project_sort(sort: &str) -> Result<ProjectSort> {
-    let (field, dir) = parse_sort_pair(sort)?;
-    match field.as_str() {
-        "updated" => Ok(match dir {
-            Direction::Asc => ProjectSort::UpdatedAsc,
-            Direction::Desc => ProjectSort::UpdatedDesc,
-        }),
-        "created" => Ok(match dir {
-            Direction::Asc => ProjectSort::CreatedAsc,
-            Direction::Desc => ProjectSort::CreatedDesc,
-        }),
-        "target" => Ok(match dir {
-            Direction::Asc => ProjectSort::TargetAsc,
-            Direction::Desc => ProjectSort::TargetDesc,
-        }),
-        other => Err(anyhow!("unknown project sort field '{}'", other)),
-    }
-}
-
-fn parse_cycle_sort(sort: &str) -> Result<CycleSort> {
-    let (field, dir) = parse_sort_pair(sort)?;
-    match field.as_str() {
-        "start" => Ok(match dir {
-            Direction::Asc => CycleSort::StartAsc,
-            Direction::Desc => CycleSort::StartDesc,
-        }),
-        "end" => Ok(match dir {
-            Direction::Asc => CycleSort::EndAsc,
-            Direction::Desc => CycleSort::EndDesc,
-        }),
-        other => Err(anyhow!("unknown cycle sort field '{}'", other)),
-    }
-}
-
-fn parse_sort_pair(input: &str) -> Result<(String, Direction)> {
-    let mut parts = input.split(':');
-    let field = parts
-        .next()
-        .map(|s| s.trim().to_ascii_lowercase())
-        .filter(|s| !s.is_empty())
-        .ok_or_else(|| anyhow!("sort option must include a field"))?;
-    let direction = parts
-        .next()
-        .map(|s| s.trim().to_ascii_lowercase())
-        .filter(|s| !s.is_empty())
-        .unwrap_or_else(|| "desc".into());
-    let direction = match direction.as_str() {
-        "asc" => Direction::Asc,
-        "desc" => Direction::Desc,
-        other => return Err(anyhow!("invalid sort direction '{}'", other)),
-    };
-    Ok((field, direction))
-}
-
-#[derive(Clone, Copy)]
-enum Direction {
-    Asc,
-    Desc,
-}
-
-fn markdown_to_text(input: &str) -> String {
-    let mut options = Options::empty();
-    options.insert(Options::ENABLE_STRIKETHROUGH);
-    let parser = MarkdownParser::new_ext(input, options);
-    let mut out = String::new();
-    let mut need_space = false;
-    for event in parser {
-        match event {
-            Event::Text(text) | Event::Code(text) => {
-                if need_space && !out.ends_with([' ', '\n']) {
-                    out.push(' ');
-                }
-                out.push_str(&text);
-                need_space = true;
-            }
-            Event::SoftBreak => {
-                out.push(' ');
-                need_space = false;
-            }
-            Event::HardBreak => {
-                out.push('\n');
-                need_space = false;
-            }
-            Event::Start(Tag::Paragraph) => {
-                if !out.ends_with('\n') && !out.is_empty() {
-                    out.push('\n');
-                }
-                need_space = false;
-            }
-            Event::End(TagEnd::Paragraph) => {
-                if !out.ends_with('\n') {
-                    out.push('\n');
-                }
-                need_space = false;
-            }
-            Event::Start(Tag::List(_)) => {
-                if !out.ends_with('\n') && !out.is_empty() {
-                    out.push('\n');
-                }
-            }
-            Event::Start(Tag::Item) => {
-                if !out.ends_with('\n') && !out.is_empty() {
-                    out.push('\n');
-                }
-                out.push_str("- ");
-                need_space = false;
-            }
-            Event::End(TagEnd::Item) => {
-                if !out.ends_with('\n') {
-                    out.push('\n');
-                }
-                need_space = false;
-            }
-            _ => {}
-        }
+    for line in wrap(
+        &markdown_to_text(comment.body.trim()),
+        markdown::wrap_width(),
+    ) {
+        println!("{}", line);
     }
-    out.trim().to_string()
 }
@@ -0,0 +1,327 @@
+use std::collections::HashSet;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use linear_core::config::ConfigLocator;
+use linear_core::error::LinearError;
+use linear_core::graphql::RequestPriority;
+use linear_core::notify::rules::{newly_matching, NotifyRule, NotifyRules, WatchedIssue};
+use linear_core::notify::{self, Notifier};
+use linear_core::services::activity::{parse_since, ActivityEvent, ActivityService};
+use linear_core::services::issues::IssueService;
+use linear_core::shell::shell_command;
+use linear_core::snapshot::SnapshotStore;
+use serde_json::json;
+use tokio::process::Command;
+
+use crate::fields::print_json;
+use crate::table::{Cell, Table};
+use crate::{
+    build_client, client_options, load_session, remind, request_timeout, resolve,
+    NotifyForwardArgs, NotifyRulesAddArgs, NotifyRulesListArgs, NotifyRulesRemoveArgs,
+    NotifyWatchArgs,
+};
+
+/// Poll the activity feed and post matching events to every destination.
+pub(crate) async fn forward(args: NotifyForwardArgs) -> Result<()> {
+    let mut watermark = parse_since(&args.since, Utc::now()).map_err(LinearError::validation)?;
+    let filter = args.filter.clone().unwrap_or_default();
+    let template = args.template.as_deref().unwrap_or(notify::DEFAULT_TEMPLATE);
+    let dry_run = client_options().dry_run;
+
+    let session = load_session(&args.profile).await?;
+    let client = build_client(&session)?.with_priority(RequestPriority::Background);
+    let team_id = match filter.team() {
+        Some(team) => Some(resolve::team_id(&IssueService::new(client.clone()), team).await?),
+        None => None,
+    };
+    let activity = ActivityService::new(client);
+    let notifier = Notifier::new(request_timeout()?);
+    if !args.once {
+        eprintln!(
+            "Forwarding activity to {} every {}; Ctrl-C stops.",
+            args.to
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", "),
+            linear_core::config::format_duration(args.interval)
+        );
+    }
+
+    // Events at the watermark itself may have been sent by the previous poll.
+    let mut sent_at_watermark: HashSet<String> = HashSet::new();
+    loop {
+        let feed = activity
+            .feed(team_id.as_deref(), watermark, args.limit)
+            .await
+            .context("GraphQL request failed")?;
+        if feed.truncated {
+            eprintln!(
+                "warning: more than {} issues changed since {}; some events were skipped",
+                args.limit,
+                watermark.to_rfc3339()
+            );
+        }
+
+        let mut failures = 0;
+        let mut first_failure = None;
+        for event in feed
+            .events
+            .iter()
+            .filter(|event| !sent_at_watermark.contains(&event_key(event)))
+            .filter(|event| filter.matches(event))
+        {
+            let text = notify::render(template, event);
+            for destination in &args.to {
+                let payload = destination.payload(&text, event);
+                if dry_run {
+                    println!(
+                        "{}",
+                        serde_json::json!({ "to": destination.to_string(), "payload": payload })
+                    );
+                    continue;
+                }
+                match notifier.send(destination, &payload).await {
+                    Ok(()) => eprintln!("{} → {}", event.issue, destination),
+                    Err(err) => {
+                        failures += 1;
+                        eprintln!("warning: {} → {} failed: {}", event.issue, destination, err);
+                        first_failure.get_or_insert(err);
+                    }
+                }
+            }
+        }
+
+        (watermark, sent_at_watermark) = advance(watermark, sent_at_watermark, &feed.events);
+        if args.once {
+            return match first_failure {
+                Some(err) => {
+                    Err(anyhow::Error::new(err)
+                        .context(format!("{} notification(s) failed", failures)))
+                }
+                None => Ok(()),
+            };
+        }
+        tokio::time::sleep(args.interval.max(Duration::from_secs(1))).await;
+    }
+}
+
+/// Move the watermark to the newest event seen, remembering every event at that instant.
+fn advance(
+    watermark: DateTime<Utc>,
+    mut sent: HashSet<String>,
+    events: &[ActivityEvent],
+) -> (DateTime<Utc>, HashSet<String>) {
+    let Some(newest) = events.iter().map(|event| event.at).max() else {
+        return (watermark, sent);
+    };
+    if newest > watermark {
+        sent.clear();
+    }
+    sent.extend(
+        events
+            .iter()
+            .filter(|event| event.at == newest)
+            .map(event_key),
+    );
+    (newest.max(watermark), sent)
+}
+
+fn event_key(event: &ActivityEvent) -> String {
+    format!(
+        "{}|{}|{}",
+        event.issue,
+        event.at.to_rfc3339(),
+        serde_json::to_string(&event.kind).unwrap_or_default()
+    )
+}
+
+fn load_rules() -> Result<(std::path::PathBuf, NotifyRules)> {
+    let path = ConfigLocator::new()?.notify_rules_file();
+    let rules = NotifyRules::load(&path).map_err(LinearError::validation)?;
+    Ok((path, rules))
+}
+
+/// Append a rule to `notify-rules.toml`.
+pub(crate) fn rules_add(args: NotifyRulesAddArgs) -> Result<()> {
+    let (path, mut rules) = load_rules()?;
+    rules
+        .add(NotifyRule {
+            name: args.name.clone(),
+            filter: args.filter,
+            command: args.command,
+        })
+        .map_err(LinearError::validation)?;
+    rules
+        .save(&path)
+        .with_context(|| format!("failed to write {}", path.display()))?;
+    println!("Added rule '{}' to {}", args.name, path.display());
+    Ok(())
+}
+
+pub(crate) fn rules_list(args: NotifyRulesListArgs) -> Result<()> {
+    let (path, rules) = load_rules()?;
+    if args.json {
+        return print_json(&rules.rules);
+    }
+    if rules.rules.is_empty() {
+        println!(
+            "No notification rules in {}; add one with `linear notify rules add`.",
+            path.display()
+        );
+        return Ok(());
+    }
+    let mut table = Table::new()
+        .flex_column("NAME", 8, 30)
+        .flex_column("FILTER", 12, 50)
+        .flex_column("COMMAND", 12, 60);
+    for rule in &rules.rules {
+        table.row([
+            Cell::from(rule.name.clone()),
+            rule.filter.to_string().into(),
+            rule.command.clone().into(),
+        ]);
+    }
+    table.print();
+    Ok(())
+}
+
+pub(crate) fn rules_remove(args: NotifyRulesRemoveArgs) -> Result<()> {
+    let (path, mut rules) = load_rules()?;
+    let removed = rules
+        .remove(&args.name)
+        .ok_or_else(|| LinearError::not_found(format!("no rule named '{}'", args.name)))?;
+    rules
+        .save(&path)
+        .with_context(|| format!("failed to write {}", path.display()))?;
+    println!("Removed rule '{}'", removed.name);
+    Ok(())
+}
+
+/// Poll each rule's filter and run its command for issues that were not matching last time.
+///
+/// The ids matching each rule are saved per profile, so a restart only reports issues that
+/// started matching while it was down; a rule's first poll just records the current matches.
+pub(crate) async fn watch(args: NotifyWatchArgs) -> Result<()> {
+    let (path, rules) = load_rules()?;
+    let mut selected = Vec::new();
+    for name in &args.rules {
+        let rule = rules
+            .get(name)
+            .ok_or_else(|| LinearError::not_found(format!("no rule named '{}'", name)))?;
+        selected.push(rule.clone());
+    }
+    if args.rules.is_empty() {
+        selected = rules.rules;
+    }
+    if selected.is_empty() {
+        return Err(LinearError::validation(format!("no rules in {}", path.display())).into());
+    }
+    let dry_run = client_options().dry_run;
+
+    let session = load_session(&args.profile).await?;
+    let service =
+        IssueService::new(build_client(&session)?.with_priority(RequestPriority::Background));
+    let store = SnapshotStore::for_profile(&args.profile);
+    if !args.once {
+        eprintln!(
+            "Watching {} rule(s) every {}; Ctrl-C stops.",
+            selected.len(),
+            linear_core::config::format_duration(args.interval)
+        );
+    }
+
+    loop {
+        let mut failures = 0;
+        for rule in &selected {
+            let page = service
+                .query::<WatchedIssue>(&rule.filter.query().first(args.limit))
+                .await
+                .with_context(|| format!("rule '{}': GraphQL request failed", rule.name))?;
+            if page.has_next_page {
+                eprintln!(
+                    "warning: rule '{}' matches more than {} issues; only the most recently \
+                     updated are tracked",
+                    rule.name, args.limit
+                );
+            }
+            // Keyed on the filter too, so editing a rule starts it afresh.
+            let key = format!("notify:rule:{}:{}", rule.name, rule.filter);
+            let previous = store
+                .as_ref()
+                .and_then(|store| store.load::<HashSet<String>>(&key))
+                .map(|snapshot| snapshot.value);
+
+            match &previous {
+                None => eprintln!(
+                    "{}: watching {} matching issue(s)",
+                    rule.name,
+                    page.nodes.len()
+                ),
+                Some(previous) => {
+                    for issue in newly_matching(previous, &page.nodes) {
+                        if dry_run {
+                            println!(
+                                "{}",
+                                json!({ "rule": rule.name, "issue": issue, "command": rule.command })
+                            );
+                            continue;
+                        }
+                        match run_command(&rule.command, issue.env(&rule.name)).await {
+                            Ok(()) => eprintln!("{} → {}", issue.identifier, rule.name),
+                            Err(err) => {
+                                failures += 1;
+                                eprintln!(
+                                    "warning: {} → {} failed: {:#}",
+                                    issue.identifier, rule.name, err
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+
+            if !dry_run {
+                if let Some(store) = &store {
+                    let current: HashSet<String> =
+                        page.nodes.iter().map(|issue| issue.id.clone()).collect();
+                    if let Err(err) = store.save(&key, &current) {
+                        eprintln!(
+                            "warning: failed to save matches for '{}': {}",
+                            rule.name, err
+                        );
+                    }
+                }
+            }
+        }
+
+        if !dry_run {
+            if let Err(err) = remind::deliver_due(&args.profile).await {
+                eprintln!("warning: failed to deliver reminders: {:#}", err);
+            }
+        }
+
+        if args.once {
+            if failures > 0 {
+                anyhow::bail!("{} rule command(s) failed", failures);
+            }
+            return Ok(());
+        }
+        tokio::time::sleep(args.interval.max(Duration::from_secs(1))).await;
+    }
+}
+
+/// Run `command` through the platform shell with `env` added; non-zero exits are errors.
+pub(crate) async fn run_command(command: &str, env: Vec<(&'static str, String)>) -> Result<()> {
+    let status = Command::from(shell_command(command))
+        .envs(env)
+        .status()
+        .await
+        .context("failed to start command")?;
+    if !status.success() {
+        anyhow::bail!("command exited with {}", status);
+    }
+    Ok(())
+}
@@ -0,0 +1,249 @@
+use std::io::{self, IsTerminal, Write};
+
+use anyhow::{anyhow, Context, Result};
+use crossterm::cursor::{MoveToColumn, MoveUp};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::style::{Attribute, Print, SetAttribute};
+use crossterm::terminal::{self, disable_raw_mode, enable_raw_mode, Clear, ClearType};
+use crossterm::{queue, QueueableCommand};
+use linear_core::fuzzy;
+use linear_core::graphql::IssueSummary;
+use linear_core::services::issues::{IssueQueryOptions, IssueService};
+
+use crate::fields::print_json;
+use crate::table::{pad, truncate};
+use crate::{build_client, load_session, PickArgs};
+
+const VISIBLE_ROWS: usize = 10;
+const PROMPT: &str = "> ";
+
+/// Fuzzy-pick a recent issue and print its key (or branch/URL) to stdout.
+pub(crate) async fn run(args: PickArgs) -> Result<()> {
+    // The accessible prompt reads plain lines, so it also works through pipes.
+    if !crate::accessible::enabled() && !io::stderr().is_terminal() {
+        return Err(anyhow!("linear pick requires an interactive terminal"));
+    }
+
+    let session = load_session(&args.profile).await?;
+    let client = build_client(&session)?;
+    let service = IssueService::new(client);
+    let options = IssueQueryOptions {
+        limit: args.limit,
+        team_key: args.team.clone(),
+        ..Default::default()
+    };
+    let issues = service
+        .list(options)
+        .await
+        .context("GraphQL request failed")?
+        .issues;
+    if issues.is_empty() {
+        return Err(anyhow!("no issues available to pick from"));
+    }
+
+    let selected = tokio::task::spawn_blocking(move || {
+        let query = args.query.unwrap_or_default();
+        let picked = if crate::accessible::enabled() {
+            prompt_lines(&issues, query)
+        } else {
+            prompt(&issues, query)
+        };
+        picked.map(|index| index.map(|index| issues[index].clone()))
+    })
+    .await??;
+    let Some(issue) = selected else {
+        return Err(anyhow!("no issue selected"));
+    };
+
+    if args.json {
+        print_json(&issue)?;
+    } else if args.branch {
+        let branch = issue
+            .branch_name
+            .clone()
+            .unwrap_or_else(|| issue.identifier.to_lowercase());
+        println!("{}", branch);
+    } else if args.url {
+        println!("{}", issue.url.as_deref().unwrap_or(&issue.identifier));
+    } else {
+        println!("{}", issue.identifier);
+    }
+    Ok(())
+}
+
+fn haystack(issue: &IssueSummary) -> String {
+    format!("{} {}", issue.identifier, issue.title)
+}
+
+/// Run the interactive prompt on stderr, returning the chosen index (None when cancelled).
+fn prompt(issues: &[IssueSummary], initial: String) -> Result<Option<usize>> {
+    let mut stderr = io::stderr();
+    enable_raw_mode()?;
+    let result = prompt_loop(&mut stderr, issues, initial);
+    queue!(stderr, MoveToColumn(0), Clear(ClearType::FromCursorDown))?;
+    stderr.flush()?;
+    disable_raw_mode()?;
+    result
+}
+
+/// Line-by-line prompt for screen readers: list numbered matches, then read a number to pick,
+/// other text to filter again, or an empty line to cancel.
+fn prompt_lines(issues: &[IssueSummary], mut query: String) -> Result<Option<usize>> {
+    let mut stderr = io::stderr();
+    let stdin = io::stdin();
+    let haystacks: Vec<String> = issues.iter().map(haystack).collect();
+    let indices: Vec<usize> = (0..issues.len()).collect();
+    loop {
+        let matches: Vec<usize> = fuzzy::rank(&query, &indices, |index| haystacks[*index].clone())
+            .into_iter()
+            .map(|(_, index)| *index)
+            .take(VISIBLE_ROWS)
+            .collect();
+        if matches.is_empty() {
+            writeln!(stderr, "No issues match \"{query}\".")?;
+        } else {
+            writeln!(stderr, "{} matching issue(s):", matches.len())?;
+        }
+        for (number, index) in matches.iter().enumerate() {
+            let issue = &issues[*index];
+            let state = issue
+                .state
+                .as_ref()
+                .map(|state| state.name.as_str())
+                .unwrap_or("no state");
+            writeln!(
+                stderr,
+                "{}. {}, {}, {}",
+                number + 1,
+                issue.identifier,
+                state,
+                issue.title
+            )?;
+        }
+        write!(
+            stderr,
+            "Type a number to pick, text to filter, or press Enter to cancel: "
+        )?;
+        stderr.flush()?;
+
+        let mut line = String::new();
+        if stdin.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        let line = line.trim();
+        if line.is_empty() {
+            return Ok(None);
+        }
+        match line.parse::<usize>() {
+            Ok(number) if (1..=matches.len()).contains(&number) => {
+                return Ok(Some(matches[number - 1]));
+            }
+            Ok(_) => writeln!(stderr, "Pick a number from 1 to {}.", matches.len())?,
+            Err(_) => query = line.to_owned(),
+        }
+    }
+}
+
+fn prompt_loop(
+    out: &mut impl Write,
+    issues: &[IssueSummary],
+    mut query: String,
+) -> Result<Option<usize>> {
+    let haystacks: Vec<String> = issues.iter().map(haystack).collect();
+    let indices: Vec<usize> = (0..issues.len()).collect();
+    let mut cursor = 0usize;
+    loop {
+        let matches: Vec<usize> = fuzzy::rank(&query, &indices, |index| haystacks[*index].clone())
+            .into_iter()
+            .map(|(_, index)| *index)
+            .collect();
+        cursor = cursor.min(matches.len().saturating_sub(1));
+        draw(out, issues, &matches, &query, cursor)?;
+
+        let Event::Key(KeyEvent {
+            code,
+            modifiers,
+            kind,
+            ..
+        }) = event::read()?
+        else {
+            continue;
+        };
+        if kind == KeyEventKind::Release {
+            continue;
+        }
+        let ctrl = modifiers.contains(KeyModifiers::CONTROL);
+        match code {
+            KeyCode::Enter => return Ok(matches.get(cursor).copied()),
+            KeyCode::Esc => return Ok(None),
+            KeyCode::Char('c') | KeyCode::Char('g') if ctrl => return Ok(None),
+            KeyCode::Up => cursor = cursor.saturating_sub(1),
+            KeyCode::Char('p') | KeyCode::Char('k') if ctrl => cursor = cursor.saturating_sub(1),
+            KeyCode::Down | KeyCode::Tab => cursor += 1,
+            KeyCode::Char('n') | KeyCode::Char('j') if ctrl => cursor += 1,
+            KeyCode::Char('u') if ctrl => query.clear(),
+            KeyCode::Backspace => {
+                query.pop();
+            }
+            KeyCode::Char(c) if !ctrl => {
+                query.push(c);
+                cursor = 0;
+            }
+            _ => {}
+        }
+    }
+}
+
+fn draw(
+    out: &mut impl Write,
+    issues: &[IssueSummary],
+    matches: &[usize],
+    query: &str,
+    cursor: usize,
+) -> Result<()> {
+    let width = terminal::size()
+        .map(|(cols, _)| cols as usize)
+        .unwrap_or(80);
+    let offset = cursor.saturating_sub(VISIBLE_ROWS - 1);
+    let rows: Vec<usize> = matches
+        .iter()
+        .skip(offset)
+        .take(VISIBLE_ROWS)
+        .copied()
+        .collect();
+
+    out.queue(MoveToColumn(0))?
+        .queue(Clear(ClearType::FromCursorDown))?
+        .queue(Print(PROMPT))?
+        .queue(Print(query))?
+        .queue(Print(format!("  {}/{}", matches.len(), issues.len())))?;
+    for (row, index) in rows.iter().enumerate() {
+        let issue = &issues[*index];
+        let state = issue
+            .state
+            .as_ref()
+            .map(|state| state.name.as_str())
+            .unwrap_or("-");
+        let line = format!(
+            "{} {} {}",
+            pad(&issue.identifier, 10),
+            pad(&truncate(state, 12), 12),
+            issue.title
+        );
+        let line = truncate(&line, width.saturating_sub(3));
+        out.queue(Print("\r\n"))?;
+        if row + offset == cursor {
+            out.queue(SetAttribute(Attribute::Reverse))?
+                .queue(Print(format!("> {}", line)))?
+                .queue(SetAttribute(Attribute::Reset))?;
+        } else {
+            out.queue(Print(format!("  {}", line)))?;
+        }
+    }
+    if !rows.is_empty() {
+        out.queue(MoveUp(rows.len() as u16))?;
+    }
+    out.queue(MoveToColumn((PROMPT.len() + query.chars().count()) as u16))?;
+    out.flush()?;
+    Ok(())
+}
@@ -0,0 +1,80 @@
+//! Pre-fill `issue create` from an existing Linear or GitHub issue, for escalating work from
+//! another team or tracker.
+
+use anyhow::{Context, Result};
+use linear_core::error::LinearError;
+use linear_core::services::issues::IssueService;
+use linear_core::unfurl;
+use url::Url;
+
+use crate::clipboard;
+use crate::github::{self, GithubClient};
+
+/// Title, body, and origin of the issue being copied.
+pub(crate) struct Prefill {
+    pub title: String,
+    pub body: Option<String>,
+    /// `ENG-123` or `owner/name#42`.
+    pub reference: String,
+    pub url: String,
+    /// Team of a Linear source, used when `--team` is not given.
+    pub team_id: Option<String>,
+}
+
+impl Prefill {
+    /// `body` (the source's when `None`) followed by a link back to the source.
+    pub(crate) fn description(&self, body: Option<&str>) -> String {
+        let link = format!("Escalated from [{}]({})", self.reference, self.url);
+        match body.or(self.body.as_deref()).map(str::trim) {
+            Some(body) if !body.is_empty() => format!("{body}\n\n---\n\n{link}"),
+            _ => link,
+        }
+    }
+}
+
+/// Pre-fill from the first URL on the clipboard.
+pub(crate) async fn from_clipboard(service: &IssueService) -> Result<Prefill> {
+    let text = clipboard::read()?;
+    let Some(url) = text
+        .split_whitespace()
+        .find(|word| word.starts_with("https://") || word.starts_with("http://"))
+    else {
+        return Err(LinearError::validation("the clipboard does not contain a URL").into());
+    };
+    eprintln!("Using {} from the clipboard", url);
+    from_url(service, url).await
+}
+
+pub(crate) async fn from_url(service: &IssueService, raw: &str) -> Result<Prefill> {
+    let url = Url::parse(raw.trim())
+        .map_err(|_| LinearError::validation(format!("'{}' is not a URL", raw.trim())))?;
+    if let Some(key) = unfurl::issue_key(&url) {
+        let issue = service
+            .get_by_key(&key)
+            .await
+            .with_context(|| format!("unable to load issue {}", key))?;
+        return Ok(Prefill {
+            url: issue.url.clone().unwrap_or_else(|| url.to_string()),
+            title: issue.title,
+            body: issue.description,
+            reference: issue.identifier,
+            team_id: issue.team.map(|team| team.id),
+        });
+    }
+    if let Some((repo, number)) = github::parse_issue_url(&url) {
+        let issue = GithubClient::from_env().issue(&repo, number).await?;
+        return Ok(Prefill {
+            title: issue.title,
+            body: issue.body,
+            reference: format!("{}#{}", repo, issue.number),
+            url: issue.html_url,
+            team_id: None,
+        });
+    }
+    Err(LinearError::validation(format!(
+        "'{}' is not a Linear issue URL (https://linear.app/<workspace>/issue/ENG-123) or \
+         GitHub issue URL (https://github.com/<owner>/<repo>/issues/42)",
+        url
+    ))
+    .into())
+}
@@ -0,0 +1,47 @@
+use anyhow::Result;
+use clap::Args;
+use linear_core::auth::Actor;
+use linear_core::error::LinearError;
+
+use crate::credential_store;
+
+pub(crate) use linear_commands::workspaces::{fan_out, with_workspace};
+
+/// Flags for read commands that can run against several stored profiles at once.
+#[derive(Args, Debug, Clone, Default)]
+pub(crate) struct ProfileSelection {
+    /// Query every stored profile concurrently and add a WORKSPACE column
+    #[arg(long, conflicts_with_all = ["profiles", "profile"])]
+    pub(crate) all_profiles: bool,
+    /// Query these profiles concurrently (comma separated) and add a WORKSPACE column
+    #[arg(
+        long,
+        value_delimiter = ',',
+        value_name = "NAMES",
+        conflicts_with = "profile"
+    )]
+    pub(crate) profiles: Vec<String>,
+}
+
+impl ProfileSelection {
+    /// Profiles to fan out over, or `None` for the usual single `--profile` run.
+    pub(crate) fn resolve(&self) -> Result<Option<Vec<String>>> {
+        let mut profiles = if self.all_profiles {
+            let profiles = credential_store(Actor::User)?.profiles()?;
+            if profiles.is_empty() {
+                return Err(LinearError::auth(
+                    "no stored profiles; run `linear auth login --profile <name>` first",
+                )
+                .into());
+            }
+            profiles
+        } else if self.profiles.is_empty() {
+            return Ok(None);
+        } else {
+            self.profiles.clone()
+        };
+        profiles.retain(|profile| !profile.trim().is_empty());
+        profiles.dedup();
+        Ok(Some(profiles))
+    }
+}
@@ -0,0 +1,176 @@
+use anyhow::{Context, Result};
+use chrono::{Local, Utc};
+use linear_core::config::{Config, ConfigLocator};
+use linear_core::error::LinearError;
+use linear_core::remind::{Reminder, ReminderStore};
+use linear_core::services::issues::IssueService;
+
+use crate::fields::print_json;
+use crate::notify::run_command;
+use crate::table::Table;
+use crate::{
+    build_client, key_or_branch, load_session, RemindCancelArgs, RemindListArgs, RemindSetArgs,
+};
+
+fn store(profile: &str) -> Result<ReminderStore> {
+    ReminderStore::for_profile(profile)
+        .ok_or_else(|| anyhow::anyhow!("could not determine the data directory for reminders"))
+}
+
+/// Remember to come back to an issue after `--in`, optionally subscribing to it as well.
+pub(crate) async fn add(args: RemindSetArgs) -> Result<()> {
+    let key = key_or_branch(&args.key)?;
+    let delay = args
+        .delay
+        .ok_or_else(|| LinearError::validation("--in is required, e.g. --in 3d"))?;
+    let delay = chrono::Duration::from_std(delay)
+        .map_err(|_| LinearError::validation("--in is too far in the future"))?;
+
+    let session = load_session(&args.profile).await?;
+    let client = build_client(&session)?;
+    let service = IssueService::new(client.clone());
+    let issue = service
+        .get_by_key(&key)
+        .await
+        .with_context(|| format!("unable to load issue {}", key))?;
+    if args.subscribe {
+        let viewer = client.viewer().await.context("GraphQL request failed")?;
+        let added = service
+            .subscribe(&issue, &viewer.id)
+            .await
+            .context("GraphQL request failed")?;
+        if added && !args.json {
+            println!("Subscribed to {}", issue.identifier);
+        }
+    }
+
+    let store = store(&args.profile)?;
+    let mut reminders = store.load()?;
+    let now = Utc::now();
+    let reminder = reminders.add(
+        issue.identifier,
+        Some(issue.title),
+        issue.url,
+        args.note,
+        now,
+        now + delay,
+    );
+    store.save(&reminders)?;
+    if args.json {
+        return print_json(&reminder);
+    }
+    println!(
+        "Reminder {} for {} at {}",
+        reminder.id,
+        reminder.issue,
+        reminder
+            .due_at
+            .with_timezone(&Local)
+            .format("%Y-%m-%d %H:%M")
+    );
+    Ok(())
+}
+
+/// Reminders not surfaced yet, soonest first.
+pub(crate) fn list(args: RemindListArgs) -> Result<()> {
+    let reminders = store(&args.profile)?.load()?;
+    if args.json {
+        return print_json(&reminders.pending);
+    }
+    if reminders.pending.is_empty() {
+        println!("No reminders.");
+        return Ok(());
+    }
+    let now = Utc::now();
+    let mut table = Table::new()
+        .column("ID", 4)
+        .column("KEY", 12)
+        .column("DUE", 16)
+        .flex_column("NOTE", 16, 80);
+    for reminder in &reminders.pending {
+        let due = if reminder.is_due(now) {
+            "due".to_owned()
+        } else {
+            reminder
+                .due_at
+                .with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string()
+        };
+        table.row([
+            reminder.id.to_string(),
+            reminder.issue.clone(),
+            due,
+            reminder
+                .note
+                .clone()
+                .or_else(|| reminder.title.clone())
+                .unwrap_or_else(|| "-".into()),
+        ]);
+    }
+    table.print();
+    Ok(())
+}
+
+/// Drop a reminder before it is due.
+pub(crate) fn cancel(args: RemindCancelArgs) -> Result<()> {
+    let store = store(&args.profile)?;
+    let mut reminders = store.load()?;
+    let reminder = reminders
+        .cancel(args.id)
+        .ok_or_else(|| LinearError::not_found(format!("no reminder with id {}", args.id)))?;
+    store.save(&reminders)?;
+    println!("Cancelled reminder {} for {}", reminder.id, reminder.issue);
+    Ok(())
+}
+
+/// Surface the profile's due reminders through `reminder_command` from `config.toml`, or on
+/// stdout without one, and forget them. Returns how many were due.
+pub(crate) async fn deliver_due(profile: &str) -> Result<usize> {
+    let store = store(profile)?;
+    let mut reminders = store.load()?;
+    let due = reminders.take_due(Utc::now());
+    if due.is_empty() {
+        return Ok(0);
+    }
+    store.save(&reminders)?;
+    let command = Config::load(&ConfigLocator::new()?)?.reminder_command;
+    for reminder in &due {
+        match &command {
+            Some(command) => {
+                if let Err(err) = run_command(command, env(reminder)).await {
+                    eprintln!(
+                        "warning: reminder {} for {} failed: {:#}",
+                        reminder.id, reminder.issue, err
+                    );
+                }
+            }
+            None => println!("{}", line(reminder)),
+        }
+    }
+    Ok(due.len())
+}
+
+/// `⏰ ENG-123 Fix login — ping QA`
+fn line(reminder: &Reminder) -> String {
+    let mut line = format!("⏰ {}", reminder.issue);
+    if let Some(title) = &reminder.title {
+        line.push(' ');
+        line.push_str(title);
+    }
+    if let Some(note) = &reminder.note {
+        line.push_str(" — ");
+        line.push_str(note);
+    }
+    line
+}
+
+fn env(reminder: &Reminder) -> Vec<(&'static str, String)> {
+    vec![
+        ("LINEAR_ISSUE", reminder.issue.clone()),
+        ("LINEAR_TITLE", reminder.title.clone().unwrap_or_default()),
+        ("LINEAR_URL", reminder.url.clone().unwrap_or_default()),
+        ("LINEAR_NOTE", reminder.note.clone().unwrap_or_default()),
+        ("LINEAR_MESSAGE", line(reminder)),
+    ]
+}
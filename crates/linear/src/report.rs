@@ -0,0 +1,332 @@
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Local, Utc};
+use clap::ValueEnum;
+use futures_util::{StreamExt, TryStreamExt};
+use linear_core::board::short_age;
+use linear_core::config;
+use linear_core::digest::Digest;
+use linear_core::error::LinearError;
+use linear_core::graphql::IssueSummary;
+use linear_core::notify;
+use linear_core::priority;
+use linear_core::query::{IssueFields, IssueQuery};
+use linear_core::services::activity::{parse_since, ActivityEvent, ActivityKind, ActivityService};
+use linear_core::services::issues::IssueService;
+use linear_core::snapshot::SnapshotStore;
+use serde_json::json;
+
+use crate::fields::print_json;
+use crate::table::{Cell, Table};
+use crate::{
+    board_limits, build_client, client_options, load_session, resolve, ReportDigestArgs,
+    ReportStaleArgs, ReportWipArgs,
+};
+
+const DEFAULT_NUDGE: &str =
+    "Is this still relevant? {issue} has had no updates for {days} days; please update or close it.";
+
+/// Snapshot key of the last nudge time per issue, used for the `--cooldown` cap.
+const NUDGES_KEY: &str = "report:nudged";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub(crate) enum DigestFormat {
+    Text,
+    Markdown,
+    Json,
+}
+
+/// List open issues without updates for `--days`, optionally commenting on each.
+pub(crate) async fn stale(args: ReportStaleArgs) -> Result<()> {
+    if args.days == 0 {
+        return Err(LinearError::validation("--days must be at least 1").into());
+    }
+    let cooldown = Duration::from_std(args.cooldown)
+        .map_err(|_| LinearError::validation("--cooldown is too large"))?;
+    let now = Utc::now();
+
+    let session = load_session(&args.profile).await?;
+    let client = build_client(&session)?;
+    let mut issues = ActivityService::new(client.clone())
+        .stale(
+            args.team.as_deref(),
+            now - Duration::days(i64::from(args.days)),
+            now,
+            args.limit,
+        )
+        .await
+        .context("GraphQL request failed")?;
+    issues.sort_by_key(|event| event.at);
+
+    let mut nudged = Vec::new();
+    if args.nudge {
+        nudged = nudge(&args, IssueService::new(client), &issues, now, cooldown).await?;
+    }
+
+    if args.json {
+        print_json(&json!({ "issues": issues, "nudged": nudged }))?;
+        return Ok(());
+    }
+    if issues.is_empty() {
+        println!("No open issues idle for {} days or more.", args.days);
+        return Ok(());
+    }
+    let mut table = Table::new()
+        .column("KEY", 12)
+        .column("IDLE", 5)
+        .column("UPDATED", 10)
+        .column("PRIORITY", 11)
+        .flex_column("TITLE", 16, 80);
+    for event in &issues {
+        let days = match event.kind {
+            ActivityKind::Stale { days } => days,
+            _ => 0,
+        };
+        table.row([
+            Cell::link(event.issue.clone(), event.url.as_deref()),
+            format!("{}d", days).into(),
+            event
+                .at
+                .with_timezone(&Local)
+                .format("%Y-%m-%d")
+                .to_string()
+                .into(),
+            priority::label_for(event.priority).into(),
+            event.title.as_str().into(),
+        ]);
+    }
+    table.print();
+    if issues.len() >= args.limit {
+        eprintln!(
+            "… more stale issues may exist; raise --limit (currently {})",
+            args.limit
+        );
+    }
+    if args.nudge {
+        println!("Nudged {} issue(s).", nudged.len());
+    }
+    Ok(())
+}
+
+/// Check open issues against the `[board]` WIP limits and age thresholds, failing when any is
+/// exceeded so CI jobs can alert on it.
+pub(crate) async fn wip(args: ReportWipArgs) -> Result<()> {
+    let limits = board_limits()?;
+    if limits.is_empty() {
+        return Err(LinearError::validation(
+            "no WIP limits or age thresholds configured; add [board.wip] or [board.max_age] to config.toml",
+        )
+        .into());
+    }
+    let session = load_session(&args.profile).await?;
+    let client = build_client(&session)?;
+    let mut query = IssueQuery::new().open().select(IssueFields::SUMMARY);
+    if let Some(team) = &args.team {
+        query = query.team(team.clone());
+    }
+    let issues: Vec<IssueSummary> = IssueService::new(client)
+        .query_iter(query)
+        .take(args.limit)
+        .try_collect()
+        .await
+        .context("GraphQL request failed")?;
+    let violations = limits.violations(&issues, Utc::now());
+    let max_age = |max_age| {
+        Duration::from_std(max_age)
+            .map(short_age)
+            .unwrap_or_default()
+    };
+
+    if args.json {
+        print_json(&json!({
+            "checked": issues.len(),
+            "wip": violations.wip.iter().map(|violation| json!({
+                "state": violation.state,
+                "count": violation.count,
+                "limit": violation.limit,
+            })).collect::<Vec<_>>(),
+            "age": violations.age.iter().map(|violation| json!({
+                "issue": violation.issue.identifier,
+                "title": violation.issue.title,
+                "url": violation.issue.url,
+                "state": violation.state,
+                "ageDays": violation.age.num_days(),
+                "maxAge": max_age(violation.max_age),
+            })).collect::<Vec<_>>(),
+        }))?;
+    } else if violations.is_empty() {
+        println!(
+            "All {} open issue(s) are within the board limits.",
+            issues.len()
+        );
+    } else {
+        if !violations.wip.is_empty() {
+            let mut table = Table::new()
+                .column("STATE", 20)
+                .column("ISSUES", 6)
+                .column("LIMIT", 5);
+            for violation in &violations.wip {
+                table.row([
+                    Cell::from(violation.state.as_str()),
+                    violation.count.to_string().into(),
+                    violation.limit.to_string().into(),
+                ]);
+            }
+            table.print();
+        }
+        if !violations.age.is_empty() {
+            if !violations.wip.is_empty() {
+                println!();
+            }
+            let mut table = Table::new()
+                .column("KEY", 12)
+                .column("STATE", 16)
+                .column("AGE", 5)
+                .column("MAX", 5)
+                .flex_column("TITLE", 16, 80);
+            for violation in &violations.age {
+                table.row([
+                    Cell::link(
+                        violation.issue.identifier.clone(),
+                        violation.issue.url.as_deref(),
+                    ),
+                    violation.state.as_str().into(),
+                    short_age(violation.age).into(),
+                    max_age(violation.max_age).into(),
+                    violation.issue.title.as_str().into(),
+                ]);
+            }
+            table.print();
+        }
+    }
+    if issues.len() >= args.limit {
+        eprintln!(
+            "… more open issues may exist; raise --limit (currently {})",
+            args.limit
+        );
+    }
+    if !violations.is_empty() {
+        return Err(LinearError::validation(format!(
+            "{} state(s) over their WIP limit, {} issue(s) past their age threshold",
+            violations.wip.len(),
+            violations.age.len()
+        ))
+        .into());
+    }
+    Ok(())
+}
+
+/// What changed on subscribed (or the team's) issues since `--since`, one section per issue.
+/// Text and markdown print nothing without changes so `ifne mail …` skips empty digests.
+pub(crate) async fn digest(args: ReportDigestArgs) -> Result<()> {
+    let since = parse_since(&args.since, Utc::now()).map_err(LinearError::validation)?;
+    let format = if args.json {
+        DigestFormat::Json
+    } else {
+        args.format
+    };
+    args.budget
+        .check(&ActivityService::estimate(args.limit), "this digest")?;
+
+    let session = load_session(&args.profile).await?;
+    let client = build_client(&session)?;
+    let mut filter = IssueQuery::new();
+    if args.subscribed {
+        filter = filter.subscribed_me();
+    }
+    if let Some(team) = &args.team {
+        let team_id = resolve::team_id(&IssueService::new(client.clone()), team).await?;
+        filter = filter.team_id(team_id);
+    }
+    let feed = ActivityService::new(client)
+        .feed_matching(filter, since, args.limit)
+        .await
+        .context("GraphQL request failed")?;
+    let digest = Digest::from_feed(feed);
+
+    match format {
+        DigestFormat::Json => print_json(&digest)?,
+        _ if digest.is_empty() => eprintln!(
+            "No changes since {}.",
+            since.with_timezone(&Local).format("%Y-%m-%d %H:%M")
+        ),
+        DigestFormat::Text => print!("{}", digest.text(&Local)),
+        DigestFormat::Markdown => print!("{}", digest.markdown(&Local)),
+    }
+    if digest.truncated {
+        eprintln!(
+            "… more updated issues available; raise --limit (currently {}) for a complete digest",
+            args.limit
+        );
+    }
+    Ok(())
+}
+
+/// Comment on stale issues not nudged within the cooldown, at most `--max-nudges` per run.
+async fn nudge(
+    args: &ReportStaleArgs,
+    service: IssueService,
+    issues: &[ActivityEvent],
+    now: DateTime<Utc>,
+    cooldown: Duration,
+) -> Result<Vec<String>> {
+    let template = args.template.as_deref().unwrap_or(DEFAULT_NUDGE);
+    let dry_run = client_options().dry_run;
+    let store = SnapshotStore::for_profile(&args.profile);
+    let mut log: HashMap<String, DateTime<Utc>> = store
+        .as_ref()
+        .and_then(|store| store.load(NUDGES_KEY))
+        .map(|snapshot| snapshot.value)
+        .unwrap_or_default();
+    log.retain(|_, at| now - *at < cooldown);
+
+    let mut nudged = Vec::new();
+    let mut failure = None;
+    for event in issues {
+        if nudged.len() >= args.max_nudges {
+            eprintln!(
+                "note: stopped after {} nudges (--max-nudges)",
+                args.max_nudges
+            );
+            break;
+        }
+        if log.contains_key(&event.issue) {
+            eprintln!(
+                "{} skipped: nudged within {}",
+                event.issue,
+                config::format_duration(args.cooldown)
+            );
+            continue;
+        }
+        let body = notify::render(template, event);
+        if dry_run {
+            println!("{}", json!({ "issue": event.issue, "comment": body }));
+        } else {
+            let result = match service.get_by_key(&event.issue).await {
+                Ok(issue) => service.comment(&issue.id, &body).await.map(|_| ()),
+                Err(err) => Err(err),
+            };
+            if let Err(err) = result {
+                failure = Some(
+                    anyhow::Error::new(err).context(format!("failed to nudge {}", event.issue)),
+                );
+                break;
+            }
+            log.insert(event.issue.clone(), now);
+        }
+        nudged.push(event.issue.clone());
+    }
+
+    if let Some(store) = &store {
+        if !dry_run {
+            if let Err(err) = store.save(NUDGES_KEY, &log) {
+                eprintln!("warning: failed to save nudge history: {}", err);
+            }
+        }
+    }
+    match failure {
+        Some(err) => Err(err),
+        None => Ok(nudged),
+    }
+}
@@ -0,0 +1,115 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use anyhow::{Context, Result};
+use linear_core::error::LinearError;
+use linear_core::fuzzy::{self, Resolution};
+use linear_core::graphql::LinearGraphqlClient;
+use linear_core::services::issues::IssueService;
+use linear_core::services::labels::LabelService;
+
+use crate::confirm;
+
+static NO_PROMPTS: AtomicBool = AtomicBool::new(false);
+
+/// Fail on ambiguous names instead of asking, for processes whose terminal is not the user's
+/// (`linear daemon run`).
+pub(crate) fn disable_prompts() {
+    NO_PROMPTS.store(true, Ordering::Relaxed);
+}
+
+/// The id of the team `input` names (id, key, name, or a close match), asking which one is
+/// meant when several are about as close.
+pub(crate) async fn team_id(service: &IssueService, input: &str) -> Result<String> {
+    let found = service
+        .find_team(input)
+        .await
+        .context("GraphQL request failed")?;
+    let team = choose("team", input, found, |team| {
+        format!("{} ({})", team.key, team.name)
+    })
+    .await?
+    .ok_or_else(|| LinearError::not_found(format!("team '{input}' not found")))?;
+    Ok(team.id)
+}
+
+/// The id of the state of `team_id` that `input` names, asking when it is ambiguous.
+pub(crate) async fn state_id(service: &IssueService, team_id: &str, input: &str) -> Result<String> {
+    let found = service
+        .find_state(team_id, input)
+        .await
+        .context("GraphQL request failed")?;
+    let state = choose("state", input, found, |state| state.name.clone())
+        .await?
+        .ok_or_else(|| LinearError::not_found(format!("state '{input}' not found for team")))?;
+    Ok(state.id)
+}
+
+/// The id of the label of `team_id` that `input` names, asking when it is ambiguous.
+pub(crate) async fn label_id(labels: &LabelService, team_id: &str, input: &str) -> Result<String> {
+    let found = labels
+        .find(team_id, input)
+        .await
+        .context("GraphQL request failed")?;
+    let label = choose("label", input, found, |label| label.name.clone())
+        .await?
+        .ok_or_else(|| LinearError::not_found(format!("label '{input}' not found for team")))?;
+    Ok(label.id)
+}
+
+/// The id of the `kind` (`issue` or `project`) template that `input` names, among the team's
+/// own templates and the workspace-wide ones.
+pub(crate) async fn template_id(
+    client: &LinearGraphqlClient,
+    team_id: &str,
+    kind: &str,
+    input: &str,
+) -> Result<String> {
+    let templates: Vec<_> = client
+        .templates()
+        .await
+        .context("GraphQL request failed")?
+        .into_iter()
+        .filter(|template| template.type_name.eq_ignore_ascii_case(kind))
+        .filter(|template| match &template.team {
+            Some(team) => team.id == team_id,
+            None => true,
+        })
+        .collect();
+    let found = fuzzy::resolve(input, &templates, |template| {
+        vec![template.id.as_str(), template.name.as_str()]
+    })
+    .map(Clone::clone);
+    let template = choose("template", input, found, |template| template.name.clone())
+        .await?
+        .ok_or_else(|| {
+            LinearError::not_found(format!("{kind} template '{input}' not found for team"))
+        })?;
+    Ok(template.id)
+}
+
+/// The unique match, or the one picked from a close call on the terminal; `None` when nothing
+/// matched. Without a terminal a close call fails with the candidates.
+async fn choose<T>(
+    what: &str,
+    input: &str,
+    found: Resolution<T>,
+    describe: impl Fn(&T) -> String,
+) -> Result<Option<T>> {
+    let mut candidates = match found {
+        Resolution::Unique(item) => return Ok(Some(item)),
+        Resolution::Missing => return Ok(None),
+        Resolution::Ambiguous(candidates) => candidates,
+    };
+    let names: Vec<String> = candidates.iter().map(describe).collect();
+    if !NO_PROMPTS.load(Ordering::Relaxed) {
+        let question = format!("{what} '{input}' matches several:");
+        if let Some(index) = confirm::choose(question, names.clone()).await? {
+            return Ok(Some(candidates.swap_remove(index)));
+        }
+    }
+    Err(LinearError::validation(format!(
+        "{what} '{input}' is ambiguous: {}; use the full name",
+        names.join(", ")
+    ))
+    .into())
+}
@@ -0,0 +1,701 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{bail, Context, Result};
+use chrono::NaiveDate;
+use linear_core::backup::{self, BackupEntity, Manifest, RestoreMap, Restored};
+use linear_core::config::Impact;
+use linear_core::error::LinearError;
+use linear_core::graphql::{
+    CommentCreateInput, IssueCreateInput, IssueLabelCreateInput, IssueUpdateInput,
+    LinearGraphqlClient, ProjectCreateInput, RequestPriority, TeamSummary,
+};
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::fields::print_json;
+use crate::{build_client, client_options, load_session, RestoreArgs};
+
+/// Default name of the mapping report, written next to the newest backup it restores.
+const REPORT_FILE: &str = "restore-map.json";
+
+/// `OLD=NEW` team key mapping; keys are matched case-insensitively.
+pub(crate) fn parse_team_map(raw: &str) -> Result<(String, String), String> {
+    let (old, new) = raw
+        .split_once('=')
+        .ok_or_else(|| format!("expected OLD=NEW, got '{raw}'"))?;
+    let (old, new) = (old.trim(), new.trim());
+    if old.is_empty() || new.is_empty() {
+        return Err(format!("expected OLD=NEW, got '{raw}'"));
+    }
+    Ok((old.to_ascii_uppercase(), new.to_ascii_uppercase()))
+}
+
+/// What a restore did, or would do, with one entity's records.
+#[derive(Debug, Default, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Tally {
+    /// Records to create; after the run, the ones created.
+    created: usize,
+    /// Records matched to one already in the target, e.g. a label of the same name.
+    reused: usize,
+    /// Records restored by an earlier run with the same report.
+    done: usize,
+    /// Archived records and records whose team has no counterpart in the target.
+    skipped: usize,
+}
+
+/// Re-create the records of a backup in the signed-in workspace, mapping team keys with
+/// `--team-map`, and record every old→new id in the report so a re-run resumes.
+pub(crate) async fn run(args: RestoreArgs) -> Result<()> {
+    let chain = backup::chain(&args.from)
+        .with_context(|| format!("failed to read backups in {}", args.from.display()))?;
+    let (newest, manifest) = chain.last().expect("a chain holds at least one backup");
+    let report = args
+        .report
+        .clone()
+        .unwrap_or_else(|| newest.join(REPORT_FILE));
+    let mut include = if args.include.is_empty() {
+        BackupEntity::ALL.to_vec()
+    } else {
+        args.include.clone()
+    };
+    include.sort();
+    include.dedup();
+
+    let mut records = BTreeMap::new();
+    for &entity in &include {
+        records.insert(entity, load(&chain, entity)?);
+    }
+    let mut map = RestoreMap::load(&report)
+        .with_context(|| format!("failed to read {}", report.display()))?;
+
+    let session = load_session(&args.profile).await?;
+    let client = build_client(&session)?.with_priority(RequestPriority::Background);
+    let target = client
+        .organization()
+        .await
+        .context("GraphQL request failed")?;
+    if let Some(previous) = map
+        .target
+        .as_ref()
+        .filter(|previous| previous.id != target.id)
+    {
+        return Err(LinearError::validation(format!(
+            "{} maps records into {}, not {}; pass --report to start a new one",
+            report.display(),
+            previous.name,
+            target.name
+        ))
+        .into());
+    }
+    map.source = manifest.workspace.clone();
+    map.target = Some(target.clone());
+
+    let teams = client.teams().await.context("GraphQL request failed")?;
+    let team_map: HashMap<String, String> = args.team_map.iter().cloned().collect();
+    for new in team_map.values() {
+        if !teams.iter().any(|team| team.key.eq_ignore_ascii_case(new)) {
+            return Err(LinearError::not_found(format!("team '{new}' not found")).into());
+        }
+    }
+
+    let mut restorer = Restorer {
+        client,
+        teams,
+        team_map,
+        map,
+        report,
+        json: args.json,
+        labels: HashMap::new(),
+        states: HashMap::new(),
+        users: HashMap::new(),
+    };
+    let plan: BTreeMap<BackupEntity, Tally> = records
+        .iter()
+        .map(|(&entity, records)| (entity, restorer.plan(entity, records)))
+        .collect();
+    let creating: usize = plan.values().map(|tally| tally.created).sum();
+
+    let dry_run = client_options().dry_run;
+    if args.json && dry_run {
+        return print_json(&json!({ "report": restorer.report, "plan": plan }));
+    }
+    if !args.json {
+        println!("Restoring {} into {}", newest.display(), target.name);
+        print_tallies(&plan, "to create");
+    }
+    if dry_run || creating == 0 {
+        if !args.json {
+            println!(
+                "{}: {creating} records would be created",
+                if dry_run { "Dry run" } else { "Nothing to do" }
+            );
+        }
+        return Ok(());
+    }
+    // Hundreds of new records are hard to take back, so it asks like a destructive change.
+    args.confirmation
+        .ensure(
+            Impact::Destructive,
+            &format!("create {creating} records in {}", target.name),
+        )
+        .await?;
+
+    let mut done = BTreeMap::new();
+    for (entity, records) in &records {
+        let tally = match entity {
+            BackupEntity::Labels => restorer.labels(records).await,
+            BackupEntity::Projects => restorer.projects(records).await,
+            BackupEntity::Issues => restorer.issues(records).await,
+            BackupEntity::Comments => restorer.comments(records).await,
+        }
+        .with_context(|| {
+            format!(
+                "restoring {entity} failed; re-run to resume from {}",
+                restorer.report.display()
+            )
+        })?;
+        done.insert(*entity, tally);
+    }
+
+    if args.json {
+        return print_json(&json!({ "report": restorer.report, "restored": done }));
+    }
+    println!();
+    print_tallies(&done, "created");
+    println!(
+        "Mapping of old to new ids written to {}",
+        restorer.report.display()
+    );
+    Ok(())
+}
+
+fn print_tallies(tallies: &BTreeMap<BackupEntity, Tally>, created: &str) {
+    for (entity, tally) in tallies {
+        println!(
+            "  {:<9} {} {created}, {} reused, {} already restored, {} skipped",
+            entity, tally.created, tally.reused, tally.done, tally.skipped
+        );
+    }
+}
+
+/// Every record of `entity` across the chain, the newest copy of each.
+fn load(chain: &[(PathBuf, Manifest)], entity: BackupEntity) -> Result<Vec<Value>> {
+    let mut layers = Vec::new();
+    for (dir, manifest) in chain {
+        if let Some(record) = manifest.entities.get(&entity) {
+            layers.push(read_array(&dir.join(&record.file))?);
+        }
+    }
+    let mut records = backup::merge(layers);
+    // Oldest first, so restored issues keep their relative numbering and comments their order.
+    records.sort_by(|a, b| text(a, "/createdAt").cmp(&text(b, "/createdAt")));
+    Ok(records)
+}
+
+/// Parse a JSON array written by `linear backup`, through `gzip -dc` when compressed.
+fn read_array(path: &Path) -> Result<Vec<Value>> {
+    let raw = if path.extension().is_some_and(|extension| extension == "gz") {
+        let output = Command::new("gzip")
+            .arg("-dc")
+            .arg(path)
+            .output()
+            .context("failed to run gzip to read a compressed backup")?;
+        if !output.status.success() {
+            bail!("gzip could not read {}", path.display());
+        }
+        output.stdout
+    } else {
+        fs::read(path).with_context(|| format!("failed to read {}", path.display()))?
+    };
+    serde_json::from_slice(&raw).with_context(|| format!("{} is not a backup file", path.display()))
+}
+
+fn text<'a>(record: &'a Value, pointer: &str) -> Option<&'a str> {
+    record.pointer(pointer).and_then(Value::as_str)
+}
+
+fn archived(record: &Value) -> bool {
+    record.get("archivedAt").is_some_and(|at| !at.is_null())
+}
+
+struct Restorer {
+    client: LinearGraphqlClient,
+    teams: Vec<TeamSummary>,
+    /// Old team key → new team key, both uppercase.
+    team_map: HashMap<String, String>,
+    map: RestoreMap,
+    report: PathBuf,
+    json: bool,
+    /// Label ids by lowercase name, per target team id.
+    labels: HashMap<String, HashMap<String, String>>,
+    /// Workflow state ids by lowercase name, per target team id.
+    states: HashMap<String, HashMap<String, String>>,
+    /// User ids by lowercase email, `None` when no member of the target has the address.
+    users: HashMap<String, Option<String>>,
+}
+
+impl Restorer {
+    /// The target team for a team of the backup: the one `--team-map` names, else the team
+    /// with the same key.
+    fn team(&self, old_key: Option<&str>) -> Option<&TeamSummary> {
+        let old_key = old_key?.to_ascii_uppercase();
+        let key = self.team_map.get(&old_key).unwrap_or(&old_key);
+        self.teams
+            .iter()
+            .find(|team| team.key.eq_ignore_ascii_case(key))
+    }
+
+    fn restorable(&self, entity: BackupEntity, record: &Value) -> bool {
+        if archived(record) {
+            return false;
+        }
+        match entity {
+            BackupEntity::Labels => {
+                record.get("isGroup") != Some(&Value::Bool(true))
+                    && self.team(text(record, "/team/key")).is_some()
+            }
+            BackupEntity::Projects => record
+                .pointer("/teams")
+                .and_then(Value::as_array)
+                .is_some_and(|teams| {
+                    teams
+                        .iter()
+                        .any(|team| self.team(text(team, "/key")).is_some())
+                }),
+            BackupEntity::Issues => self.team(text(record, "/team/key")).is_some(),
+            // Comments follow their issue, which may be restored earlier in the same run.
+            BackupEntity::Comments => true,
+        }
+    }
+
+    fn plan(&self, entity: BackupEntity, records: &[Value]) -> Tally {
+        let mut tally = Tally::default();
+        for record in records {
+            let id = text(record, "/id").unwrap_or_default();
+            if self.map.get(entity, id).is_some() {
+                tally.done += 1;
+            } else if self.restorable(entity, record) {
+                tally.created += 1;
+            } else {
+                tally.skipped += 1;
+            }
+        }
+        tally
+    }
+
+    fn record(&mut self, entity: BackupEntity, old_id: &str, restored: Restored) -> Result<()> {
+        if !self.json {
+            let from = restored.from.as_deref().unwrap_or(old_id);
+            let to = restored.to.as_deref().unwrap_or(&restored.id);
+            let action = if restored.reused { "reused" } else { "created" };
+            println!("{action:<8} {:<9} {from} → {to}", entity.as_str());
+        }
+        self.map.insert(entity, old_id, restored);
+        // Saved after every record, so an interrupted run resumes without duplicates.
+        self.map
+            .save(&self.report)
+            .with_context(|| format!("failed to write {}", self.report.display()))
+    }
+
+    async fn team_labels(&mut self, team_id: &str) -> Result<&mut HashMap<String, String>> {
+        if !self.labels.contains_key(team_id) {
+            let labels = self
+                .client
+                .issue_labels(team_id)
+                .await
+                .context("GraphQL request failed")?
+                .into_iter()
+                .map(|label| (label.name.to_ascii_lowercase(), label.id))
+                .collect();
+            self.labels.insert(team_id.to_owned(), labels);
+        }
+        Ok(self
+            .labels
+            .get_mut(team_id)
+            .expect("labels were just loaded"))
+    }
+
+    async fn state_id(&mut self, team_id: &str, name: Option<&str>) -> Result<Option<String>> {
+        let Some(name) = name else {
+            return Ok(None);
+        };
+        if !self.states.contains_key(team_id) {
+            let states = self
+                .client
+                .workflow_states(team_id)
+                .await
+                .context("GraphQL request failed")?
+                .into_iter()
+                .map(|state| (state.name.to_ascii_lowercase(), state.id))
+                .collect();
+            self.states.insert(team_id.to_owned(), states);
+        }
+        Ok(self.states[team_id]
+            .get(&name.to_ascii_lowercase())
+            .cloned())
+    }
+
+    /// The target member with the same email as a user of the backup.
+    async fn user_id(&mut self, user: Option<&Value>) -> Result<Option<String>> {
+        let Some(email) = user.and_then(|user| text(user, "/email")) else {
+            return Ok(None);
+        };
+        let email = email.to_ascii_lowercase();
+        if let Some(id) = self.users.get(&email) {
+            return Ok(id.clone());
+        }
+        let id = self
+            .client
+            .search_users(&email, 5)
+            .await
+            .context("GraphQL request failed")?
+            .into_iter()
+            .find(|user| {
+                user.email
+                    .as_deref()
+                    .is_some_and(|candidate| candidate.eq_ignore_ascii_case(&email))
+            })
+            .map(|user| user.id);
+        self.users.insert(email, id.clone());
+        Ok(id)
+    }
+
+    /// Team labels, matched by name to the target team's labels and created where missing.
+    async fn labels(&mut self, records: &[Value]) -> Result<Tally> {
+        let mut tally = Tally::default();
+        for record in records {
+            let id = text(record, "/id").unwrap_or_default();
+            if self.map.get(BackupEntity::Labels, id).is_some() {
+                tally.done += 1;
+                continue;
+            }
+            if !self.restorable(BackupEntity::Labels, record) {
+                tally.skipped += 1;
+                continue;
+            }
+            let team_id = self
+                .team(text(record, "/team/key"))
+                .map(|team| team.id.clone())
+                .expect("restorable labels have a team");
+            let name = text(record, "/name").unwrap_or_default().to_owned();
+            let existing = self
+                .team_labels(&team_id)
+                .await?
+                .get(&name.to_ascii_lowercase())
+                .cloned();
+            let restored = match existing {
+                Some(existing) => {
+                    tally.reused += 1;
+                    Restored {
+                        id: existing,
+                        from: Some(name.clone()),
+                        to: Some(name),
+                        reused: true,
+                    }
+                }
+                None => {
+                    let label = self
+                        .client
+                        .create_issue_label(IssueLabelCreateInput {
+                            team_id: team_id.clone(),
+                            name: name.clone(),
+                            description: text(record, "/description").map(str::to_owned),
+                            color: text(record, "/color").map(str::to_owned),
+                        })
+                        .await
+                        .context("GraphQL request failed")?;
+                    self.team_labels(&team_id)
+                        .await?
+                        .insert(label.name.to_ascii_lowercase(), label.id.clone());
+                    tally.created += 1;
+                    Restored {
+                        id: label.id,
+                        from: Some(name),
+                        to: Some(label.name),
+                        reused: false,
+                    }
+                }
+            };
+            self.record(BackupEntity::Labels, id, restored)?;
+        }
+        Ok(tally)
+    }
+
+    /// Projects in every mapped team they belonged to, with lead and members matched by email.
+    async fn projects(&mut self, records: &[Value]) -> Result<Tally> {
+        let mut tally = Tally::default();
+        for record in records {
+            let id = text(record, "/id").unwrap_or_default();
+            if self.map.get(BackupEntity::Projects, id).is_some() {
+                tally.done += 1;
+                continue;
+            }
+            if !self.restorable(BackupEntity::Projects, record) {
+                tally.skipped += 1;
+                continue;
+            }
+            let name = text(record, "/name").unwrap_or_default();
+            let mut input = ProjectCreateInput::new(name);
+            input.description = text(record, "/description").map(str::to_owned);
+            input.state = text(record, "/state").map(str::to_owned);
+            input.start_date = text(record, "/startDate").map(str::to_owned);
+            input.target_date = text(record, "/targetDate").map(str::to_owned);
+            for team in nodes(record, "/teams") {
+                if let Some(team) = self.team(text(team, "/key")) {
+                    if !input.team_ids.contains(&team.id) {
+                        input.team_ids.push(team.id.clone());
+                    }
+                }
+            }
+            input.lead_id = self.user_id(record.get("lead")).await?;
+            for member in nodes(record, "/members/nodes") {
+                if let Some(member) = self.user_id(Some(member)).await? {
+                    input.member_ids.push(member);
+                }
+            }
+            let project = self
+                .client
+                .project_create(input)
+                .await
+                .context("GraphQL request failed")?;
+            tally.created += 1;
+            self.record(
+                BackupEntity::Projects,
+                id,
+                Restored {
+                    id: project.id,
+                    from: Some(name.to_owned()),
+                    to: Some(project.name),
+                    reused: false,
+                },
+            )?;
+        }
+        Ok(tally)
+    }
+
+    /// Issues, oldest first, then a second pass that links parents and rewrites the old keys
+    /// in descriptions once every new key is known.
+    async fn issues(&mut self, records: &[Value]) -> Result<Tally> {
+        let mut tally = Tally::default();
+        for record in records {
+            let id = text(record, "/id").unwrap_or_default();
+            if self.map.get(BackupEntity::Issues, id).is_some() {
+                tally.done += 1;
+                continue;
+            }
+            if !self.restorable(BackupEntity::Issues, record) {
+                tally.skipped += 1;
+                continue;
+            }
+            let team_id = self
+                .team(text(record, "/team/key"))
+                .map(|team| team.id.clone())
+                .expect("restorable issues have a team");
+            let old_key = text(record, "/identifier").unwrap_or(id).to_owned();
+            let mut input =
+                IssueCreateInput::new(team_id.clone(), text(record, "/title").unwrap_or_default());
+            // Keys of issues not restored yet are rewritten in the second pass.
+            input.description = Some(restored_description(record, &old_key, &HashMap::new()));
+            input.state_id = self.state_id(&team_id, text(record, "/state/name")).await?;
+            input.assignee_id = self.user_id(record.get("assignee")).await?;
+            input.priority = record
+                .get("priority")
+                .and_then(Value::as_i64)
+                .map(|priority| priority as i32);
+            input.estimate = record
+                .get("estimate")
+                .and_then(Value::as_f64)
+                .map(|estimate| estimate.round() as i32);
+            input.due_date = text(record, "/dueDate").and_then(|due| due.parse::<NaiveDate>().ok());
+            input.project_id = text(record, "/project/id")
+                .and_then(|project| self.map.id(BackupEntity::Projects, project))
+                .map(str::to_owned);
+            for label in nodes(record, "/labels/nodes") {
+                let mapped = match text(label, "/id")
+                    .and_then(|label| self.map.id(BackupEntity::Labels, label))
+                {
+                    Some(mapped) => Some(mapped.to_owned()),
+                    // Workspace labels are not restored on their own; use the team's namesake.
+                    None => {
+                        let name = text(label, "/name")
+                            .unwrap_or_default()
+                            .to_ascii_lowercase();
+                        self.team_labels(&team_id).await?.get(&name).cloned()
+                    }
+                };
+                if let Some(mapped) = mapped.filter(|mapped| !input.label_ids.contains(mapped)) {
+                    input.label_ids.push(mapped);
+                }
+            }
+            let issue = self
+                .client
+                .create_issue(input)
+                .await
+                .context("GraphQL request failed")?;
+            tally.created += 1;
+            self.record(
+                BackupEntity::Issues,
+                id,
+                Restored {
+                    id: issue.id,
+                    from: Some(old_key),
+                    to: Some(issue.identifier),
+                    reused: false,
+                },
+            )?;
+        }
+
+        let keys = self.map.issue_keys();
+        for record in records {
+            let id = text(record, "/id").unwrap_or_default();
+            let Some(new_id) = self.map.id(BackupEntity::Issues, id).map(str::to_owned) else {
+                continue;
+            };
+            let parent_id = text(record, "/parent/id")
+                .and_then(|parent| self.map.id(BackupEntity::Issues, parent))
+                .map(str::to_owned);
+            let old_key = text(record, "/identifier").unwrap_or(id);
+            let description = restored_description(record, old_key, &HashMap::new());
+            let rewritten = restored_description(record, old_key, &keys);
+            if parent_id.is_none() && rewritten == description {
+                continue;
+            }
+            self.client
+                .update_issue(
+                    &new_id,
+                    IssueUpdateInput {
+                        parent_id,
+                        description: (rewritten != description).then_some(rewritten),
+                        ..Default::default()
+                    },
+                )
+                .await
+                .context("GraphQL request failed")?;
+        }
+        Ok(tally)
+    }
+
+    /// Comments on restored issues, each opening with who wrote it and when.
+    async fn comments(&mut self, records: &[Value]) -> Result<Tally> {
+        let keys = self.map.issue_keys();
+        let mut tally = Tally::default();
+        for record in records {
+            let id = text(record, "/id").unwrap_or_default();
+            if self.map.get(BackupEntity::Comments, id).is_some() {
+                tally.done += 1;
+                continue;
+            }
+            let issue_id = text(record, "/issue/id")
+                .and_then(|issue| self.map.id(BackupEntity::Issues, issue))
+                .map(str::to_owned);
+            let Some(issue_id) = issue_id.filter(|_| !archived(record)) else {
+                tally.skipped += 1;
+                continue;
+            };
+            let author = record
+                .get("user")
+                .and_then(|user| text(user, "/displayName").or_else(|| text(user, "/name")))
+                .unwrap_or("someone");
+            let date = text(record, "/createdAt")
+                .and_then(|at| at.get(..10))
+                .unwrap_or("an earlier date");
+            let body = format!(
+                "**{author}** commented on {date}:\n\n{}",
+                backup::rewrite_keys(text(record, "/body").unwrap_or_default(), &keys)
+            );
+            let comment = self
+                .client
+                .create_comment(CommentCreateInput { issue_id, body })
+                .await
+                .context("GraphQL request failed")?;
+            tally.created += 1;
+            let issue = text(record, "/issue/identifier").map(str::to_owned);
+            let to = issue.as_ref().and_then(|issue| keys.get(issue).cloned());
+            self.record(
+                BackupEntity::Comments,
+                id,
+                Restored {
+                    id: comment.id,
+                    from: issue,
+                    to,
+                    reused: false,
+                },
+            )?;
+        }
+        Ok(tally)
+    }
+}
+
+fn nodes<'a>(record: &'a Value, pointer: &str) -> impl Iterator<Item = &'a Value> {
+    record
+        .pointer(pointer)
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+}
+
+/// The backed-up description, with restored keys rewritten, and a footer naming the issue it
+/// was restored from.
+fn restored_description(record: &Value, old_key: &str, keys: &HashMap<String, String>) -> String {
+    let creator = record
+        .get("creator")
+        .and_then(|user| text(user, "/displayName").or_else(|| text(user, "/name")));
+    let created = text(record, "/createdAt").and_then(|at| at.get(..10));
+    let mut footer = format!("Restored from {old_key}");
+    if let Some(created) = created {
+        footer.push_str(&format!(", created {created}"));
+    }
+    if let Some(creator) = creator {
+        footer.push_str(&format!(" by {creator}"));
+    }
+    match text(record, "/description").map(str::trim) {
+        Some(body) if !body.is_empty() => {
+            format!("{}\n\n---\n\n{footer}", backup::rewrite_keys(body, keys))
+        }
+        _ => footer,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn team_map_needs_both_keys() {
+        assert_eq!(
+            parse_team_map("eng = Plat"),
+            Ok(("ENG".to_owned(), "PLAT".to_owned()))
+        );
+        assert!(parse_team_map("ENG").is_err());
+        assert!(parse_team_map("ENG=").is_err());
+    }
+
+    #[test]
+    fn description_names_the_original_issue() {
+        let record = json!({
+            "description": "Steps to reproduce\n",
+            "createdAt": "2024-05-01T09:00:00.000Z",
+            "creator": { "name": "Ada Lovelace", "displayName": "ada" }
+        });
+        let keys = HashMap::from([
+            ("ENG-12".to_owned(), "PLAT-40".to_owned()),
+            ("ENG-7".to_owned(), "PLAT-31".to_owned()),
+        ]);
+        assert_eq!(
+            restored_description(&record, "ENG-12", &keys),
+            "Steps to reproduce\n\n---\n\nRestored from ENG-12, created 2024-05-01 by ada"
+        );
+        assert_eq!(
+            restored_description(&json!({ "description": "Follows ENG-7" }), "ENG-12", &keys),
+            "Follows PLAT-31\n\n---\n\nRestored from ENG-12"
+        );
+        assert_eq!(
+            restored_description(&json!({ "description": null }), "ENG-3", &keys),
+            "Restored from ENG-3"
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use anyhow::{Context, Result};
+use linear_core::error::LinearError;
+use linear_core::priority;
+use linear_core::services::search::{SearchKind, SearchResults, SearchService};
+use serde_json::json;
+
+use crate::daemon;
+use crate::fields::print_json;
+use crate::table::{Cell, Table};
+use crate::{build_client, load_session, SearchArgs};
+
+/// Search issues, projects, documents, and users, grouped by type.
+pub(crate) async fn run(args: SearchArgs) -> Result<()> {
+    let term = args.query.join(" ");
+    if term.trim().is_empty() {
+        return Err(LinearError::validation("search query must not be empty").into());
+    }
+    let kinds: Vec<SearchKind> = if args.types.is_empty() {
+        SearchKind::ALL.to_vec()
+    } else {
+        args.types.clone()
+    };
+
+    let params = json!({ "query": term, "types": kinds, "limit": args.limit });
+    let results = match daemon::call(&args.profile, "search", params).await {
+        Some(results) => results?,
+        None => {
+            let session = load_session(&args.profile).await?;
+            let client = build_client(&session)?;
+            SearchService::new(client)
+                .search(&term, &kinds, args.limit)
+                .await
+                .context("GraphQL request failed")?
+        }
+    };
+
+    if args.json {
+        print_json(&results)?;
+    } else if args.plain {
+        render_plain(&results);
+    } else if results.is_empty() {
+        println!("No results for '{}'.", term);
+    } else {
+        render_grouped(&results);
+    }
+    Ok(())
+}
+
+/// One `type<TAB>key<TAB>title<TAB>url` line per hit, for `cut`/`fzf`/`xargs`.
+fn render_plain(results: &SearchResults) {
+    let line = |kind: SearchKind, key: &str, title: &str, url: Option<&str>| {
+        println!(
+            "{}\t{}\t{}\t{}",
+            kind.as_str(),
+            key,
+            title.replace(['\t', '\n'], " "),
+            url.unwrap_or_default()
+        );
+    };
+    for issue in &results.issues {
+        line(
+            SearchKind::Issue,
+            &issue.identifier,
+            &issue.title,
+            issue.url.as_deref(),
+        );
+    }
+    for project in &results.projects {
+        line(
+            SearchKind::Project,
+            &project.id,
+            &project.name,
+            project.url.as_deref(),
+        );
+    }
+    for document in &results.documents {
+        line(
+            SearchKind::Document,
+            &document.id,
+            &document.title,
+            document.url.as_deref(),
+        );
+    }
+    for user in &results.users {
+        let name = user
+            .display_name
+            .as_deref()
+            .or(user.name.as_deref())
+            .unwrap_or_default();
+        line(SearchKind::User, &user.id, name, None);
+    }
+}
+
+fn render_grouped(results: &SearchResults) {
+    let mut first = true;
+    let mut section = |title: &str, count: usize| {
+        if !first {
+            println!();
+        }
+        first = false;
+        println!("{} ({})", title, count);
+    };
+
+    if !results.issues.is_empty() {
+        section("Issues", results.issues.len());
+        let mut table = Table::new()
+            .column("KEY", 12)
+            .flex_column("TITLE", 16, 60)
+            .flex_column("STATE", 8, 16)
+            .column("PRIORITY", 11);
+        for issue in &results.issues {
+            table.row([
+                Cell::link(issue.identifier.clone(), issue.url.as_deref()),
+                issue.title.as_str().into(),
+                issue
+                    .state
+                    .as_ref()
+                    .map_or("-", |state| state.name.as_str())
+                    .into(),
+                priority::label_for(issue.priority).into(),
+            ]);
+        }
+        table.print();
+    }
+
+    if !results.projects.is_empty() {
+        section("Projects", results.projects.len());
+        let mut table = Table::new()
+            .flex_column("NAME", 12, 40)
+            .flex_column("STATE", 6, 12)
+            .column("TARGET", 12);
+        for project in &results.projects {
+            table.row([
+                Cell::link(project.name.clone(), project.url.as_deref()),
+                project.state.as_deref().unwrap_or("-").into(),
+                project.target_date.as_deref().unwrap_or("-").into(),
+            ]);
+        }
+        table.print();
+    }
+
+    if !results.documents.is_empty() {
+        section("Documents", results.documents.len());
+        let mut table = Table::new()
+            .flex_column("TITLE", 16, 60)
+            .flex_column("PROJECT", 8, 30)
+            .column("UPDATED", 10);
+        for document in &results.documents {
+            table.row([
+                Cell::link(document.title.clone(), document.url.as_deref()),
+                document
+                    .project
+                    .as_ref()
+                    .map_or("-", |project| project.name.as_str())
+                    .into(),
+                document.updated_at.format("%Y-%m-%d").to_string().into(),
+            ]);
+        }
+        table.print();
+    }
+
+    if !results.users.is_empty() {
+        section("Users", results.users.len());
+        let mut table = Table::new()
+            .flex_column("NAME", 12, 32)
+            .flex_column("EMAIL", 12, 40)
+            .column("ACTIVE", 6);
+        for user in &results.users {
+            table.row([
+                user.display_name
+                    .as_deref()
+                    .or(user.name.as_deref())
+                    .unwrap_or("-"),
+                user.email.as_deref().unwrap_or("-"),
+                if user.active { "yes" } else { "no" },
+            ]);
+        }
+        table.print();
+    }
+}
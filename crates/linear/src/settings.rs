@@ -0,0 +1,76 @@
+//! `linear config set`, and the settings that depend on where the command runs.
+
+use anyhow::Result;
+use clap::ValueEnum;
+use linear_core::config::{self, Config, ConfigLocator, LocalConfig};
+use linear_core::error::LinearError;
+use linear_core::unfurl::IssueRef;
+
+use crate::{git_context, ConfigSetArgs};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub(crate) enum Setting {
+    /// `default_team`: the team key, name, or id used when `--team` is not given
+    Team,
+}
+
+impl Setting {
+    fn key(self) -> &'static str {
+        match self {
+            Self::Team => "default_team",
+        }
+    }
+}
+
+/// Write a setting to `config.toml`, or with `--local` to the checkout's `.linear.toml`.
+pub(crate) fn set(args: ConfigSetArgs) -> Result<()> {
+    let path = if args.local {
+        let root = git_context::repo_root().ok_or_else(|| {
+            LinearError::validation("--local needs a git checkout to put .linear.toml in")
+        })?;
+        LocalConfig::file(&root)
+    } else {
+        ConfigLocator::new()?.config_file()
+    };
+    config::store_setting(&path, args.setting.key(), &args.value)?;
+    println!(
+        "Set {} = \"{}\" in {}",
+        args.setting.key(),
+        args.value,
+        path.display()
+    );
+    Ok(())
+}
+
+/// The team to use when no team flag is given: the checkout's `.linear.toml`, then
+/// `config.toml`.
+pub(crate) fn default_team() -> Result<Option<String>> {
+    if let Some(root) = git_context::repo_root() {
+        if let Some(team) = LocalConfig::load(&root)?.default_team {
+            return Ok(Some(team));
+        }
+    }
+    Ok(Config::load(&ConfigLocator::new()?)?.default_team)
+}
+
+/// Clap value parser for issue arguments: a key, a Linear issue URL, or a bare number in the
+/// [`default_team`], which must then be a team key.
+pub(crate) fn parse_issue_key(input: &str) -> Result<String, String> {
+    match IssueRef::parse(input) {
+        Some(IssueRef::Key(key)) => Ok(key),
+        Some(found @ IssueRef::Number(_)) => {
+            let team = default_team().map_err(|err| format!("{err:#}"))?;
+            found.key(team.as_deref()).ok_or_else(|| match team {
+                Some(team) => format!(
+                    "'{input}' needs default_team to be a team key such as ENG, not '{team}'"
+                ),
+                None => format!(
+                    "'{input}' needs a default team; run `linear config set team ENG` or pass the full key"
+                ),
+            })
+        }
+        None => Err(format!(
+            "'{input}' is not an issue key, Linear issue URL, or issue number"
+        )),
+    }
+}
@@ -0,0 +1,52 @@
+use std::process::Stdio;
+
+use anyhow::{Context, Result};
+use linear_core::config::{Config, ConfigLocator};
+use linear_core::error::LinearError;
+use linear_core::graphql::IssueDetail;
+use linear_core::shell::shell_command;
+use linear_core::summary::transcript;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+/// The `summarize_command` from `config.toml`, checked before any issue is fetched.
+pub(crate) fn command() -> Result<String> {
+    let config = Config::load(&ConfigLocator::new()?)?;
+    config
+        .summarize_command
+        .filter(|command| !command.trim().is_empty())
+        .ok_or_else(|| {
+            LinearError::validation(
+                "--summary needs summarize_command in config.toml, \
+                 e.g. summarize_command = \"llm -s 'Summarize this issue'\"",
+            )
+            .into()
+        })
+}
+
+/// Pipe the issue's transcript into `command` and return what it prints. Its stderr is left
+/// on the terminal so progress and errors from the tool stay visible.
+pub(crate) async fn summarize(command: &str, issue: &IssueDetail) -> Result<String> {
+    let mut child = Command::from(shell_command(command))
+        .env("LINEAR_ISSUE", &issue.identifier)
+        .env("LINEAR_TITLE", &issue.title)
+        .env("LINEAR_URL", issue.url.as_deref().unwrap_or_default())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .context("failed to start summarize_command")?;
+
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    let input = transcript(issue);
+    // A command that exits without reading all of its input is judged by its exit status.
+    let write = async move {
+        let _ = stdin.write_all(input.as_bytes()).await;
+    };
+    let (_, output) = tokio::join!(write, child.wait_with_output());
+    let output = output.context("summarize_command failed")?;
+    if !output.status.success() {
+        anyhow::bail!("summarize_command exited with {}", output.status);
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_owned())
+}
@@ -0,0 +1,92 @@
+use anyhow::{Context, Result};
+use linear_core::config::Impact;
+use linear_core::graphql::{TeamSettings, TeamUpdateInput};
+use linear_core::permissions::Requirement;
+use linear_core::services::issues::IssueService;
+use linear_core::team_settings::Setting;
+
+use crate::fields::print_json;
+use crate::table::Table;
+use crate::{build_client, load_session, resolve, TeamSettingsArgs};
+
+/// Show a team's settings, or apply each `--set` after checking the viewer may change them.
+pub(crate) async fn settings(args: TeamSettingsArgs) -> Result<()> {
+    let session = load_session(&args.profile).await?;
+    let client = build_client(&session)?;
+    let service = IssueService::new(client.clone());
+    let team_id = resolve::team_id(&service, &args.team).await?;
+    let before = client
+        .team_settings(&team_id)
+        .await
+        .context("GraphQL request failed")?;
+    if args.set.is_empty() {
+        if args.json {
+            return print_json(&before);
+        }
+        render(&before);
+        return Ok(());
+    }
+
+    // Check up front rather than sending a mutation the API refuses.
+    client
+        .viewer_permissions()
+        .await
+        .context("unable to check workspace permissions")?
+        .require(Requirement::TeamAdmin(&before.key))?;
+
+    let mut input = TeamUpdateInput::default();
+    for assignment in &args.set {
+        let template_id = match assignment.template_name() {
+            Some(name) => {
+                let kind = match assignment.setting {
+                    Setting::TemplatesProjects => "project",
+                    _ => "issue",
+                };
+                Some(resolve::template_id(&client, &team_id, kind, name).await?)
+            }
+            None => None,
+        };
+        assignment.apply(&mut input, template_id);
+    }
+    args.confirmation
+        .ensure(
+            Impact::Mutation,
+            &format!(
+                "change {} setting(s) of team {}",
+                args.set.len(),
+                before.key
+            ),
+        )
+        .await?;
+    let after = client
+        .update_team(&team_id, input)
+        .await
+        .context("GraphQL request failed")?;
+
+    if args.json {
+        return print_json(&after);
+    }
+    println!("Updated {} ({})", after.key, after.name);
+    for assignment in &args.set {
+        let setting = assignment.setting;
+        println!(
+            "  {}: {} → {}",
+            setting,
+            setting.display(&before),
+            setting.display(&after)
+        );
+    }
+    Ok(())
+}
+
+fn render(settings: &TeamSettings) {
+    println!("{} ({})", settings.key, settings.name);
+    println!();
+    let mut table = Table::new()
+        .column("SETTING", 26)
+        .flex_column("VALUE", 12, 48);
+    for setting in Setting::ALL {
+        table.row([setting.key().to_owned(), setting.display(settings)]);
+    }
+    table.print();
+}
@@ -0,0 +1,161 @@
+use anyhow::{Context, Result};
+use chrono::{Local, Utc};
+use linear_core::error::LinearError;
+use linear_core::services::activity::parse_since;
+use linear_core::services::issues::IssueService;
+use linear_core::timelog::{format_elapsed, TimeEntry, TimeLogStore};
+use serde_json::json;
+
+use crate::fields::print_json;
+use crate::table::Table;
+use crate::{
+    build_client, key_or_branch, load_session, TimeReportArgs, TimeStartArgs, TimeStatusArgs,
+    TimeStopArgs,
+};
+
+fn store(profile: &str) -> Result<TimeLogStore> {
+    TimeLogStore::for_profile(profile)
+        .ok_or_else(|| anyhow::anyhow!("could not determine the data directory for the time log"))
+}
+
+/// Start a session on an issue, stopping the one already running.
+pub(crate) async fn start(args: TimeStartArgs) -> Result<()> {
+    let key = key_or_branch(&args.key)?;
+    let store = store(&args.profile)?;
+    let mut log = store.load()?;
+    if log
+        .active()
+        .is_some_and(|entry| entry.issue.eq_ignore_ascii_case(&key))
+    {
+        return Err(
+            LinearError::validation(format!("timer for {} is already running", key)).into(),
+        );
+    }
+
+    let session = load_session(&args.profile).await?;
+    let issue = IssueService::new(build_client(&session)?)
+        .get_by_key(&key)
+        .await
+        .with_context(|| format!("unable to load issue {}", key))?;
+    let now = Utc::now();
+    if let Some(stopped) = log.start(issue.identifier.clone(), Some(issue.title.clone()), now) {
+        println!(
+            "Stopped {} after {}",
+            stopped.issue,
+            format_elapsed(stopped.elapsed(now))
+        );
+    }
+    store.save(&log)?;
+    println!("Started timer for {} {}", issue.identifier, issue.title);
+    Ok(())
+}
+
+/// Stop the running session, optionally posting it as a comment on the issue.
+pub(crate) async fn stop(args: TimeStopArgs) -> Result<()> {
+    let store = store(&args.profile)?;
+    let mut log = store.load()?;
+    let now = Utc::now();
+    let Some(entry) = log.stop(now, args.note.clone()) else {
+        return Err(LinearError::validation("no timer is running").into());
+    };
+    store.save(&log)?;
+    let spent = format_elapsed(entry.elapsed(now));
+    println!("Stopped {} after {}", entry.issue, spent);
+
+    if args.comment {
+        let session = load_session(&args.profile).await?;
+        let service = IssueService::new(build_client(&session)?);
+        let issue = service
+            .get_by_key(&entry.issue)
+            .await
+            .with_context(|| format!("unable to load issue {}", entry.issue))?;
+        service
+            .comment(&issue.id, &session_comment(&entry, &spent))
+            .await
+            .context("GraphQL request failed")?;
+        println!("Posted time summary on {}", entry.issue);
+    }
+    Ok(())
+}
+
+fn session_comment(entry: &TimeEntry, spent: &str) -> String {
+    let started = entry.started_at.with_timezone(&Local);
+    let mut body = format!(
+        "⏱ Worked {} on this issue ({}–{}).",
+        spent,
+        started.format("%Y-%m-%d %H:%M"),
+        entry
+            .stopped_at
+            .map(|at| at.with_timezone(&Local).format("%H:%M").to_string())
+            .unwrap_or_default()
+    );
+    if let Some(note) = entry.note.as_deref().filter(|note| !note.trim().is_empty()) {
+        body.push_str("\n\n");
+        body.push_str(note.trim());
+    }
+    body
+}
+
+/// Show the running timer.
+pub(crate) fn status(args: TimeStatusArgs) -> Result<()> {
+    let log = store(&args.profile)?.load()?;
+    let now = Utc::now();
+    match log.active() {
+        Some(entry) if args.json => print_json(&json!({
+            "active": entry,
+            "seconds": entry.elapsed(now).num_seconds(),
+        }))?,
+        Some(entry) => println!(
+            "{} {} — running for {}",
+            entry.issue,
+            entry.title.as_deref().unwrap_or_default(),
+            format_elapsed(entry.elapsed(now))
+        ),
+        None if args.json => print_json(&json!({ "active": null }))?,
+        None => println!("No timer running."),
+    }
+    Ok(())
+}
+
+/// Time per issue since `--since`, including the running session.
+pub(crate) fn report(args: TimeReportArgs) -> Result<()> {
+    let now = Utc::now();
+    let since = parse_since(&args.since, now).map_err(LinearError::validation)?;
+    let log = store(&args.profile)?.load()?;
+    let totals = log.totals(since, now);
+    let total = totals
+        .iter()
+        .fold(chrono::Duration::zero(), |sum, item| sum + item.spent);
+
+    if args.json {
+        print_json(&json!({
+            "since": since,
+            "issues": totals,
+            "seconds": total.num_seconds(),
+        }))?;
+        return Ok(());
+    }
+    if totals.is_empty() {
+        println!(
+            "No time tracked since {}.",
+            since.with_timezone(&Local).format("%Y-%m-%d %H:%M")
+        );
+        return Ok(());
+    }
+    let mut table = Table::new()
+        .column("KEY", 12)
+        .column("TIME", 8)
+        .column("SESSIONS", 8)
+        .flex_column("TITLE", 16, 80);
+    for item in &totals {
+        table.row([
+            item.issue.clone(),
+            format_elapsed(item.spent),
+            item.sessions.to_string(),
+            item.title.clone().unwrap_or_else(|| "-".into()),
+        ]);
+    }
+    table.print();
+    println!("Total: {}", format_elapsed(total));
+    Ok(())
+}
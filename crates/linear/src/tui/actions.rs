@@ -0,0 +1,110 @@
+use linear_core::graphql::{IssueLabel, WorkflowStateSummary};
+
+/// Something that can be done to the selected issue from the `.` menu or the palette.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum IssueAction {
+    OpenInBrowser,
+    CopyKey,
+    CopyUrl,
+    CopyBranch,
+    ChangeState,
+    AssignToMe,
+    AddLabel,
+}
+
+/// How an action is offered: its palette name, menu label, and menu shortcut.
+#[derive(Clone, Copy, Debug)]
+pub struct ActionSpec {
+    pub action: IssueAction,
+    pub name: &'static str,
+    pub label: &'static str,
+    pub key: char,
+}
+
+/// The issue actions in menu order, looked up by palette name or shortcut key.
+pub struct ActionRegistry {
+    actions: Vec<ActionSpec>,
+}
+
+impl ActionRegistry {
+    /// Every built-in issue action.
+    pub fn standard() -> Self {
+        let spec = |action, name, label, key| ActionSpec {
+            action,
+            name,
+            label,
+            key,
+        };
+        Self {
+            actions: vec![
+                spec(IssueAction::OpenInBrowser, "open", "Open in browser", 'o'),
+                spec(IssueAction::CopyKey, "copy-key", "Copy key", 'c'),
+                spec(IssueAction::CopyUrl, "copy-url", "Copy URL", 'u'),
+                spec(
+                    IssueAction::CopyBranch,
+                    "copy-branch",
+                    "Copy branch name",
+                    'b',
+                ),
+                spec(IssueAction::ChangeState, "state", "Change state…", 's'),
+                spec(IssueAction::AssignToMe, "assign-me", "Assign to me", 'm'),
+                spec(IssueAction::AddLabel, "label", "Add label…", 'l'),
+            ],
+        }
+    }
+
+    pub fn actions(&self) -> &[ActionSpec] {
+        &self.actions
+    }
+
+    pub fn get(&self, index: usize) -> Option<&ActionSpec> {
+        self.actions.get(index)
+    }
+
+    pub fn find(&self, name: &str) -> Option<&ActionSpec> {
+        let name = name.trim();
+        self.actions
+            .iter()
+            .find(|spec| spec.name.eq_ignore_ascii_case(name))
+    }
+
+    pub fn by_key(&self, key: char) -> Option<&ActionSpec> {
+        self.actions.iter().find(|spec| spec.key == key)
+    }
+}
+
+/// What the quick actions menu currently lists.
+#[derive(Clone, Debug)]
+pub enum QuickMenu {
+    Actions {
+        index: usize,
+    },
+    States {
+        states: Vec<WorkflowStateSummary>,
+        index: usize,
+    },
+    Labels {
+        labels: Vec<IssueLabel>,
+        index: usize,
+    },
+}
+
+impl QuickMenu {
+    /// Move the highlight by `delta`, wrapping around; `actions` is the registry length.
+    pub fn move_cursor(&mut self, delta: isize, actions: usize) {
+        let (index, len) = match self {
+            QuickMenu::Actions { index } => (index, actions),
+            QuickMenu::States { states, index } => {
+                let len = states.len();
+                (index, len)
+            }
+            QuickMenu::Labels { labels, index } => {
+                let len = labels.len();
+                (index, len)
+            }
+        };
+        if len > 0 {
+            *index = (*index as isize + delta).rem_euclid(len as isize) as usize;
+        }
+    }
+}
@@ -1,20 +1,71 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::env;
 use std::process::Stdio;
+use std::time::Instant;
 
 use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Local, NaiveDate, Utc};
+use linear_core::auth::Actor;
+use linear_core::board::Board;
+use linear_core::config::{BoardLimits, ConfigLocator, LayoutPreset, TuiLayout};
+use linear_core::daemon::IssuesChanged;
+use linear_core::fuzzy::{self, Resolution};
+use linear_core::graphql::schema::Schema;
 use linear_core::graphql::{
-    CycleSummary, IssueDetail, IssueSummary, ProjectSummary, TeamSummary, WorkflowStateSummary,
+    ClientMetrics, CycleSummary, GraphqlResult, IssueDetail, IssueLabel, IssueSummary,
+    IssueUpdateInput, MetricsHandle, Organization, ProjectDetail, ProjectSummary,
+    ProjectUpdateInput, RequestPriority, TeamMember, TeamSummary, WorkflowStateSummary,
 };
+use linear_core::group::{group_issues, GroupBy, IssueGroup};
+use linear_core::mention;
+use linear_core::priority::Priority;
+use linear_core::query::IssueQuery;
 use linear_core::services::cycles::{CycleQueryOptions, CycleService, CycleSort};
-use linear_core::services::issues::{IssueListResult, IssueQueryOptions, IssueService};
+use linear_core::services::issues::{IssueListResult, IssueQueryOptions, IssueService, IssueSort};
 use linear_core::services::projects::{ProjectQueryOptions, ProjectService, ProjectSort};
+use linear_core::services::sync::IssueSet;
+use linear_core::share::{ShareFormat, Snapshot};
+use linear_core::snapshot::SnapshotStore;
 use ratatui::text::Line;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
 use tokio::process::Command;
+use tokio::sync::mpsc::{self, error::TryRecvError};
 use tokio::task::JoinHandle;
 
+use crate::i18n::{tr, tr_args};
+
+use super::actions::{ActionRegistry, IssueAction, QuickMenu};
+use super::board::BoardTab;
+use super::cycles::{cycle_label, CyclesTab};
+use super::errors::{ErrorLog, Retry};
+use super::explorer::Explorer;
+use super::keys::{KeySequence, Motion};
+use super::projects::{ProjectEdit, ProjectsTab};
+
 const SPINNER_FRAMES: [char; 4] = ['-', '\\', '|', '/'];
 const PAGE_SIZE: usize = 20;
+/// Terminals narrower than this get the compact layout: sidebar drawer, full-screen detail.
+pub(crate) const NARROW_WIDTH: u16 = 100;
+pub(crate) const TEAMS_SNAPSHOT: &str = "teams";
+const ORGANIZATION_SNAPSHOT: &str = "organization";
+/// Cached member lists older than this are refetched before offering mentions.
+const MEMBERS_MAX_AGE_HOURS: i64 = 24;
+const MENTION_SUGGESTIONS: usize = 5;
+const SCHEMA_SNAPSHOT: &str = "schema";
+/// The cached schema is refetched after this long; `:api refresh` forces it sooner.
+const SCHEMA_MAX_AGE_DAYS: i64 = 7;
+const EXPLORER_HINT: &str =
+    "API explorer: Space picks, Enter opens, a arguments, x runs, Tab result, Esc closes";
+const CYCLES_HINT: &str =
+    "Cycles: Enter issues, Tab switches pane, Space picks, m moves to the active cycle, Y back";
+const PROJECTS_HINT: &str =
+    "Projects: Enter opens, s state, d target date, l lead, r refresh, P back to issues";
+/// How often the issue list is checked for changes when no daemon pushes them.
+const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+/// Issues shown under an opened project or cycle.
+const PROJECT_ISSUES_LIMIT: usize = 50;
+const CYCLES_LIMIT: usize = 20;
 
 pub struct App {
     service: IssueService,
@@ -39,15 +90,22 @@ pub struct App {
     palette_history: Vec<String>,
     palette_history_index: Option<usize>,
     title_contains: Option<String>,
+    assignee_filter: Option<AssigneeFilter>,
+    issue_sort: IssueSort,
+    stale_after: Option<Duration>,
+    board_limits: BoardLimits,
+    layout: TuiLayout,
+    narrow: bool,
     show_help_overlay: bool,
     show_projects_overlay: bool,
-    show_cycles_overlay: bool,
+    show_profiles_overlay: bool,
+    profile_options: Vec<String>,
+    profile_option_index: usize,
     page: usize,
     has_next_page: bool,
     page_cache: HashMap<usize, PageData>,
     page_cursors: Vec<Option<String>>,
     projects: Vec<ProjectSummary>,
-    cycles: Vec<CycleSummary>,
     project_filter_options: Vec<ProjectSummary>,
     project_filter_index: Option<usize>,
     project_filter_cache: HashMap<Option<String>, Vec<ProjectSummary>>,
@@ -55,6 +113,46 @@ pub struct App {
     automation_task: Option<JoinHandle<AutomationOutcome>>,
     detail_tab: DetailTab,
     detail_tab_memory: HashMap<String, DetailTab>,
+    snapshots: Option<SnapshotStore>,
+    refreshing: bool,
+    /// First page to revalidate incrementally on the next load, with its snapshot key.
+    stale_first_page: Option<(String, PageData)>,
+    /// Issue changes pushed by `linear daemon run`; without a daemon the list is polled.
+    live: Option<mpsc::UnboundedReceiver<IssuesChanged>>,
+    /// Keys of pushed changes not merged yet, held while a refresh would get in the way.
+    live_changes: HashSet<String>,
+    next_poll: Instant,
+    field_index: usize,
+    field_editor: Option<FieldEditor>,
+    field_status: HashMap<(String, EditField), FieldStatus>,
+    field_task: Option<JoinHandle<FieldSaveOutcome>>,
+    composer: Option<Composer>,
+    /// Set when the composer's draft should go to `$EDITOR`; `true` sends it on return.
+    editor_request: Option<bool>,
+    members: Vec<TeamMember>,
+    compose_task: Option<JoinHandle<ComposeOutcome>>,
+    group_by: Option<GroupBy>,
+    explorer: Option<Explorer>,
+    explorer_task: Option<JoinHandle<ExplorerOutcome>>,
+    projects_tab: Option<ProjectsTab>,
+    project_task: Option<JoinHandle<ProjectOutcome>>,
+    actions: ActionRegistry,
+    quick_menu: Option<QuickMenu>,
+    yank_pending: bool,
+    keys: KeySequence,
+    errors: ErrorLog,
+    show_error_console: bool,
+    /// Issue keys saved with `m a`, jumped to with `' a`.
+    marks: HashMap<char, String>,
+    /// Where the last `gg`/`G`/mark jump started, for `''`.
+    jump_origin: Option<String>,
+    action_task: Option<JoinHandle<ActionOutcome>>,
+    metrics: MetricsHandle,
+    workspace: Option<String>,
+    workspace_task: Option<JoinHandle<GraphqlResult<Organization>>>,
+    cycles_tab: Option<CyclesTab>,
+    cycle_task: Option<JoinHandle<CycleOutcome>>,
+    board_tab: Option<BoardTab>,
 }
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -62,6 +160,7 @@ pub enum Focus {
     Teams,
     States,
     Issues,
+    Detail,
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
@@ -90,6 +189,17 @@ impl StatusTab {
             StatusTab::Done => "Done",
         }
     }
+
+    /// The tab bound to digit key `digit`.
+    pub fn from_digit(digit: usize) -> Option<Self> {
+        match digit {
+            1 => Some(StatusTab::Todo),
+            2 => Some(StatusTab::Doing),
+            3 => Some(StatusTab::Done),
+            4 => Some(StatusTab::All),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
@@ -120,6 +230,67 @@ impl DetailTab {
     }
 }
 
+/// Summary fields that can be edited in place from the detail pane.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum EditField {
+    Priority,
+    Estimate,
+    DueDate,
+}
+
+impl EditField {
+    pub const fn all() -> [EditField; 3] {
+        [EditField::Priority, EditField::Estimate, EditField::DueDate]
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            EditField::Priority => "Priority",
+            EditField::Estimate => "Estimate",
+            EditField::DueDate => "Due",
+        }
+    }
+}
+
+/// Open inline editor: a selector for priority, free text for estimate and due date.
+#[derive(Clone, Debug)]
+pub enum FieldEditor {
+    Priority { index: usize },
+    Text { field: EditField, input: String },
+}
+
+impl FieldEditor {
+    pub fn field(&self) -> EditField {
+        match self {
+            FieldEditor::Priority { .. } => EditField::Priority,
+            FieldEditor::Text { field, .. } => *field,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum FieldStatus {
+    Pending,
+    Saved,
+    Failed(String),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ComposeTarget {
+    Comment,
+    Description,
+}
+
+/// Multi-line editor for a new comment or a replacement description.
+#[derive(Clone, Debug)]
+pub struct Composer {
+    pub target: ComposeTarget,
+    pub identifier: String,
+    pub text: String,
+    pub suggestion: usize,
+    issue_id: String,
+}
+
 impl App {
     pub(crate) fn new(
         service: IssueService,
@@ -127,11 +298,20 @@ impl App {
         cycle_service: CycleService,
         profile: impl Into<String>,
     ) -> Self {
+        let profile = profile.into();
+        // Every service wraps a clone of one client, so they all feed the same metrics.
+        let metrics = service.client().metrics();
         Self {
+            snapshots: SnapshotStore::for_profile(&profile),
+            refreshing: false,
+            stale_first_page: None,
+            live: None,
+            live_changes: HashSet::new(),
+            next_poll: Instant::now() + POLL_INTERVAL,
             service,
             project_service,
             cycle_service,
-            profile: profile.into(),
+            profile,
             issues: Vec::new(),
             detail: None,
             status_base: "Press 'r' to refresh, arrows to navigate, 'q' to quit".into(),
@@ -150,15 +330,22 @@ impl App {
             palette_history: Vec::new(),
             palette_history_index: None,
             title_contains: None,
+            assignee_filter: None,
+            issue_sort: IssueSort::default(),
+            stale_after: None,
+            board_limits: BoardLimits::default(),
+            layout: TuiLayout::default(),
+            narrow: false,
             show_help_overlay: false,
             show_projects_overlay: false,
-            show_cycles_overlay: false,
+            show_profiles_overlay: false,
+            profile_options: Vec::new(),
+            profile_option_index: 0,
             page: 0,
             has_next_page: false,
             page_cache: HashMap::new(),
             page_cursors: Vec::new(),
             projects: Vec::new(),
-            cycles: Vec::new(),
             project_filter_options: Vec::new(),
             project_filter_index: None,
             project_filter_cache: HashMap::new(),
@@ -166,11 +353,40 @@ impl App {
             automation_task: None,
             detail_tab: DetailTab::Summary,
             detail_tab_memory: HashMap::new(),
+            field_index: 0,
+            field_editor: None,
+            field_status: HashMap::new(),
+            field_task: None,
+            composer: None,
+            editor_request: None,
+            members: Vec::new(),
+            compose_task: None,
+            group_by: None,
+            explorer: None,
+            explorer_task: None,
+            projects_tab: None,
+            project_task: None,
+            actions: ActionRegistry::standard(),
+            quick_menu: None,
+            yank_pending: false,
+            keys: KeySequence::default(),
+            errors: ErrorLog::default(),
+            show_error_console: false,
+            marks: HashMap::new(),
+            jump_origin: None,
+            action_task: None,
+            metrics,
+            workspace: None,
+            workspace_task: None,
+            cycles_tab: None,
+            cycle_task: None,
+            board_tab: None,
         }
     }
 
     pub(crate) fn set_status(&mut self, message: impl Into<String>, spinner: bool) {
         self.status_base = message.into();
+        tracing::debug!(status = %self.status_base);
         self.status_spinner = spinner;
         if spinner {
             self.spinner_index = 0;
@@ -181,8 +397,114 @@ impl App {
         self.set_status(message, true);
     }
 
+    /// Keep a failure for the `!` console without touching the status line.
+    fn log_error(
+        &mut self,
+        operation: impl Into<String>,
+        message: impl Into<String>,
+        retry: Option<Retry>,
+    ) {
+        self.errors.push(operation.into(), message.into(), retry);
+        if self.show_error_console {
+            self.errors.mark_seen();
+        }
+    }
+
+    /// Show "Failed to {operation}" with the first line of `message`, and keep all of it for
+    /// the `!` console.
+    fn report_error(
+        &mut self,
+        operation: impl Into<String>,
+        message: impl Into<String>,
+        retry: Option<Retry>,
+    ) {
+        let (operation, message) = (operation.into(), message.into());
+        tracing::warn!(%operation, %message, "failed");
+        let first = message.lines().next().unwrap_or_default();
+        self.set_status(
+            tr_args(
+                "tui-failed",
+                &[("operation", &operation), ("message", first)],
+            ),
+            false,
+        );
+        self.log_error(operation, message, retry);
+    }
+
+    pub(crate) fn error_log(&self) -> &ErrorLog {
+        &self.errors
+    }
+
+    pub(crate) fn show_error_console(&self) -> bool {
+        self.show_error_console
+    }
+
+    pub(crate) fn toggle_error_console(&mut self) {
+        self.show_error_console = !self.show_error_console;
+        if self.show_error_console {
+            self.show_help_overlay = false;
+            self.show_projects_overlay = false;
+            self.errors.mark_seen();
+        }
+    }
+
+    pub(crate) fn move_error_selection(&mut self, delta: isize) {
+        self.errors.move_cursor(delta);
+    }
+
+    pub(crate) fn clear_errors(&mut self) {
+        self.errors.clear();
+        self.set_status(tr("tui-errors-cleared"), false);
+    }
+
+    /// Repeat the operation behind the highlighted error, closing the console.
+    pub(crate) async fn retry_selected_error(&mut self) {
+        let Some(entry) = self.errors.selected().cloned() else {
+            return;
+        };
+        let Some(retry) = entry.retry else {
+            self.set_status(
+                format!("\"{}\" cannot be retried from here", entry.operation),
+                false,
+            );
+            return;
+        };
+        self.show_error_console = false;
+        match retry {
+            Retry::Issues => self.refresh_issues(false).await,
+            Retry::Detail(key) => {
+                let selected = self
+                    .selected_issue()
+                    .is_some_and(|issue| issue.identifier == key);
+                if selected {
+                    self.abort_pending();
+                    self.set_spinner_status(format!("Loading {key}..."));
+                    self.queue_detail_fetch(key);
+                } else if !self.jump_to_issue(&key) {
+                    self.set_status(format!("{key} is not on this page"), false);
+                }
+            }
+            Retry::Projects if self.projects_tab.is_some() => self.load_project_list(),
+            Retry::Projects => self.toggle_projects_tab(),
+            Retry::Cycles if self.cycles_tab.is_some() => self.load_cycle_list(None),
+            Retry::Cycles => self.toggle_cycles_tab(),
+            Retry::FieldSave {
+                issue_id,
+                identifier,
+                field,
+                input,
+            } => self.start_field_save(issue_id, identifier, field, input),
+            Retry::Update {
+                issue_id,
+                identifier,
+                change,
+                input,
+            } => self.start_update(issue_id, identifier, change, input),
+        }
+    }
+
     pub(crate) fn status_text(&self) -> String {
-        if self.status_spinner {
+        if self.status_spinner && !crate::accessible::enabled() {
             let frame = SPINNER_FRAMES[self.spinner_index % SPINNER_FRAMES.len()];
             format!("{} {}", self.status_base, frame)
         } else {
@@ -215,6 +537,12 @@ impl App {
         parts.push(format!("state={}", state));
         parts.push(format!("status={}", status));
         parts.push(format!("title~{}", contains));
+        if let Some(filter) = &self.assignee_filter {
+            parts.push(format!("assignee={}", filter.label()));
+        }
+        if self.issue_sort != IssueSort::default() {
+            parts.push(format!("sort={}", self.issue_sort));
+        }
         parts.push(format!("page={}", self.page + 1));
         if let Some(issue) = self.issues.get(self.selected) {
             parts.push(format!("selected={}", issue.identifier));
@@ -246,7 +574,15 @@ impl App {
         if !self.status_spinner {
             return;
         }
-        if self.pending_detail.is_some() || self.automation_task.is_some() {
+        if self.pending_detail.is_some()
+            || self.automation_task.is_some()
+            || self.field_task.is_some()
+            || self.compose_task.is_some()
+            || self.explorer_task.is_some()
+            || self.project_task.is_some()
+            || self.cycle_task.is_some()
+            || self.action_task.is_some()
+        {
             self.status_tick();
         } else {
             self.status_spinner = false;
@@ -261,8 +597,158 @@ impl App {
         self.show_projects_overlay
     }
 
-    pub(crate) fn show_cycles_overlay(&self) -> bool {
-        self.show_cycles_overlay
+    pub(crate) fn show_profiles_overlay(&self) -> bool {
+        self.show_profiles_overlay
+    }
+
+    /// What the HTTP layer has seen: rate-limit headroom, queue depth, last sync, and connectivity.
+    pub(crate) fn client_metrics(&self) -> ClientMetrics {
+        self.metrics.snapshot()
+    }
+
+    /// Name of the Linear workspace behind the profile, once known.
+    pub(crate) fn workspace(&self) -> Option<&str> {
+        self.workspace.as_deref()
+    }
+
+    /// Show the cached workspace name and fetch the current one in the background.
+    pub(crate) fn load_workspace(&mut self) {
+        if self.workspace.is_none() {
+            self.workspace = self
+                .snapshots
+                .as_ref()
+                .and_then(|store| store.load::<Organization>(ORGANIZATION_SNAPSHOT))
+                .map(|snapshot| snapshot.value.name);
+        }
+        if self.workspace_task.is_none() {
+            let client = self
+                .service
+                .client()
+                .clone()
+                .with_priority(RequestPriority::Background);
+            self.workspace_task = Some(tokio::spawn(async move { client.organization().await }));
+        }
+    }
+
+    pub(crate) async fn process_workspace(&mut self) {
+        let Some(handle) = self.workspace_task.as_mut() else {
+            return;
+        };
+        if !handle.is_finished() {
+            return;
+        }
+        let handle = self.workspace_task.take().unwrap();
+        // A failure keeps the cached name; the connectivity indicator already reports it.
+        if let Ok(Ok(organization)) = handle.await {
+            self.save_snapshot(ORGANIZATION_SNAPSHOT, &organization);
+            self.workspace = Some(organization.name);
+        }
+    }
+
+    /// Merge issue changes the daemon pushed, or those since the last check every
+    /// [`POLL_INTERVAL`] without one, into the first page; the detail pane reloads when its
+    /// issue changed. Waits while the user is on another page, composing, or saving.
+    pub(crate) async fn process_live(&mut self) {
+        let mut due = false;
+        let mut disconnected = false;
+        match self.live.as_mut() {
+            Some(feed) => loop {
+                match feed.try_recv() {
+                    Ok(changed) => self
+                        .live_changes
+                        .extend(changed.issues.into_iter().map(|issue| issue.identifier)),
+                    Err(TryRecvError::Empty) => break,
+                    Err(TryRecvError::Disconnected) => {
+                        disconnected = true;
+                        break;
+                    }
+                }
+            },
+            None => due = Instant::now() >= self.next_poll,
+        }
+        if disconnected {
+            self.live = None;
+            self.next_poll = Instant::now() + POLL_INTERVAL;
+            self.set_status(
+                "Daemon stopped; checking for changes every minute instead",
+                false,
+            );
+        }
+        if !due && self.live_changes.is_empty() {
+            return;
+        }
+        let busy = self.page != 0
+            || self.refreshing
+            || self.composer.is_some()
+            || self.field_editor.is_some()
+            || self.field_task.is_some()
+            || self.compose_task.is_some();
+        if busy {
+            return;
+        }
+        self.next_poll = Instant::now() + POLL_INTERVAL;
+        let mut changed = std::mem::take(&mut self.live_changes);
+        tracing::debug!(changed = changed.len(), polled = due, "live refresh");
+        let Some(stale) = self.page_cache.get(&0).cloned() else {
+            return;
+        };
+        let updated_at = |page: &PageData, key: &str| {
+            page.issues
+                .iter()
+                .find(|issue| issue.identifier == key)
+                .map(|issue| issue.updated_at)
+        };
+        let detail = self.detail.as_ref().map(|detail| detail.identifier.clone());
+        match self.revalidate_first_page(stale.clone()).await {
+            Ok(Some((_, 0))) => {}
+            Ok(Some((page_data, merged))) => {
+                if let Some(key) = &detail {
+                    if updated_at(&stale, key) != updated_at(&page_data, key) {
+                        changed.insert(key.clone());
+                    }
+                }
+                self.save_snapshot(&self.issues_snapshot_key(), &page_data);
+                self.page_cache.insert(0, page_data.clone());
+                let selected = self
+                    .issues
+                    .get(self.selected)
+                    .map(|issue| issue.identifier.clone());
+                self.apply_page_data(page_data, selected, false);
+                if !self.status_spinner_active() {
+                    self.set_status(format!("Merged {merged} updated issue(s)"), false);
+                }
+            }
+            // Merging would misplace the page, so fetch it again.
+            Ok(None) => self.refresh_issues(true).await,
+            // Shown only in the error console: the next change or poll tries again.
+            Err(err) => self.log_error("refresh issues", err.to_string(), Some(Retry::Issues)),
+        }
+        let detail = detail.filter(|key| changed.contains(key));
+        if let (Some(key), Some(_)) = (detail, &self.detail) {
+            self.queue_detail_fetch(key);
+        }
+    }
+
+    /// Receive issue changes from `feed` instead of polling for them.
+    pub(crate) fn set_live(&mut self, feed: Option<mpsc::UnboundedReceiver<IssuesChanged>>) {
+        self.live = feed;
+    }
+
+    /// Whether a daemon is pushing issue changes.
+    pub(crate) fn live(&self) -> bool {
+        self.live.is_some()
+    }
+
+    pub(crate) fn profile(&self) -> &str {
+        &self.profile
+    }
+
+    pub(crate) fn profile_options(&self) -> &[String] {
+        &self.profile_options
+    }
+
+    pub(crate) fn profile_option_index(&self) -> usize {
+        self.profile_option_index
     }
 
     pub(crate) fn palette_active(&self) -> bool {
@@ -273,6 +759,53 @@ impl App {
         self.focus
     }
 
+    /// Track the terminal width; crossing [`NARROW_WIDTH`] switches between layouts.
+    pub(crate) fn set_terminal_width(&mut self, width: u16) {
+        self.narrow = width < NARROW_WIDTH;
+    }
+
+    pub(crate) fn narrow(&self) -> bool {
+        self.narrow
+    }
+
+    /// Whether the sidebar shows as a drawer over the narrow layout; it follows team or state
+    /// focus.
+    pub(crate) fn sidebar_drawer_open(&self) -> bool {
+        self.narrow && matches!(self.focus, Focus::Teams | Focus::States)
+    }
+
+    pub(crate) fn toggle_sidebar_drawer(&mut self) {
+        if !self.narrow {
+            self.set_status("The sidebar is always shown at this width", false);
+            return;
+        }
+        if self.sidebar_drawer_open() {
+            self.focus = Focus::Issues;
+            self.set_status("Focus: issues", false);
+        } else {
+            self.focus = Focus::Teams;
+            self.set_status("Sidebar: j/k pick a team, Tab states, b/Esc close", false);
+        }
+    }
+
+    /// Whether the detail pane covers the narrow layout.
+    pub(crate) fn detail_overlay_open(&self) -> bool {
+        self.narrow && self.focus == Focus::Detail
+    }
+
+    pub(crate) fn open_detail_overlay(&mut self) {
+        self.focus = Focus::Detail;
+        self.set_status(
+            "Details: j/k pick a field, Enter edits, Esc back to issues",
+            false,
+        );
+    }
+
+    pub(crate) fn close_detail_overlay(&mut self) {
+        self.focus = Focus::Issues;
+        self.set_status("Focus: issues", false);
+    }
+
     pub(crate) fn has_next_page(&self) -> bool {
         self.has_next_page
     }
@@ -310,6 +843,101 @@ impl App {
         self.title_contains.as_deref()
     }
 
+    pub(crate) fn set_stale_after(&mut self, threshold: Option<std::time::Duration>) {
+        self.stale_after = threshold.and_then(|threshold| Duration::from_std(threshold).ok());
+    }
+
+    pub(crate) fn set_board_limits(&mut self, limits: BoardLimits) {
+        self.board_limits = limits;
+    }
+
+    pub(crate) fn board_limits(&self) -> &BoardLimits {
+        &self.board_limits
+    }
+
+    pub(crate) fn layout(&self) -> TuiLayout {
+        self.layout
+    }
+
+    pub(crate) fn set_layout(&mut self, layout: TuiLayout) {
+        self.layout = layout;
+    }
+
+    /// Switch to the next layout preset.
+    pub(crate) fn cycle_layout(&mut self) {
+        self.set_layout_preset(self.layout.layout.next());
+    }
+
+    pub(crate) fn set_layout_preset(&mut self, preset: LayoutPreset) {
+        self.layout.layout = preset;
+        if !self.pane_visible(self.focus) {
+            self.focus = Focus::Issues;
+        }
+        self.store_layout(self.layout_summary());
+    }
+
+    fn layout_summary(&self) -> String {
+        match self.layout.split() {
+            Some(split) => format!("Layout: {} (list {split}%)", self.layout.layout),
+            None => format!("Layout: {}", self.layout.layout),
+        }
+    }
+
+    /// Grow the issue list's share of the split by `delta` percent (shrink when negative).
+    pub(crate) fn resize_split(&mut self, delta: i16) {
+        match self.layout.resize(delta) {
+            Some(split) => self.store_layout(format!("Issue list takes {split}%")),
+            None => self.set_status(
+                format!("The {} layout has no split to resize", self.layout.layout),
+                false,
+            ),
+        }
+    }
+
+    pub(crate) fn reset_split(&mut self) {
+        self.layout.reset_split();
+        match self.layout.split() {
+            Some(split) => self.store_layout(format!("Issue list takes {split}% (default)")),
+            None => self.set_status(
+                format!("The {} layout has no split to resize", self.layout.layout),
+                false,
+            ),
+        }
+    }
+
+    /// Save the layout to the config file, noting a failed write in the status.
+    fn store_layout(&mut self, message: String) {
+        let stored = ConfigLocator::new()
+            .map_err(anyhow::Error::from)
+            .and_then(|locator| Ok(self.layout.store(&locator)?));
+        match stored {
+            Ok(()) => self.set_status(message, false),
+            Err(err) => self.set_status(format!("{message} (not saved: {err})"), false),
+        }
+    }
+
+    /// Whether `focus` names a pane the current layout shows.
+    fn pane_visible(&self, focus: Focus) -> bool {
+        match (self.layout.layout, focus) {
+            (LayoutPreset::List, Focus::Detail) => false,
+            // j/k still walk the hidden list in zen, so issues keep focus there.
+            (LayoutPreset::Zen, Focus::Teams | Focus::States) => false,
+            _ => true,
+        }
+    }
+
+    /// Days since an open issue was last updated, when that reaches the stale threshold.
+    pub(crate) fn stale_days(&self, issue: &IssueSummary) -> Option<i64> {
+        let threshold = self.stale_after?;
+        let closed = issue
+            .state
+            .as_ref()
+            .and_then(|state| state.kind.as_deref())
+            .is_some_and(|kind| matches!(kind, "completed" | "canceled"));
+        let idle = Utc::now() - issue.updated_at;
+        (!closed && idle >= threshold).then(|| idle.num_days())
+    }
+
     pub(crate) fn issues(&self) -> &[IssueSummary] {
         &self.issues
     }
@@ -391,10 +1019,6 @@ impl App {
         &self.projects
     }
 
-    pub(crate) fn cycles(&self) -> &[CycleSummary] {
-        &self.cycles
-    }
-
     pub(crate) async fn process_pending_detail(&mut self) {
         if let Some(handle) = self.pending_detail.as_mut() {
             if handle.is_finished() {
@@ -405,15 +1029,23 @@ impl App {
                         self.detail = Some(detail);
                     }
                     Ok(Ok(None)) => {
-                        self.set_status("Issue detail unavailable", false);
+                        self.set_status(tr("tui-detail-unavailable"), false);
                         self.detail = None;
                     }
                     Ok(Err(err)) => {
-                        self.set_status(format!("Error loading detail: {err}"), false);
+                        let key = self
+                            .selected_issue()
+                            .map(|issue| issue.identifier.clone())
+                            .unwrap_or_default();
+                        self.report_error(
+                            format!("load {key}"),
+                            err.to_string(),
+                            Some(Retry::Detail(key)),
+                        );
                         self.detail = None;
                     }
                     Err(err) => {
-                        self.set_status(format!("Task error loading detail: {err}"), false);
+                        self.report_error("load issue detail", err.to_string(), None);
                         self.detail = None;
                     }
                 }
@@ -427,130 +1059,2024 @@ impl App {
                 let handle = self.automation_task.take().unwrap();
                 match handle.await {
                     Ok(outcome) => {
-                        if outcome.success {
-                            self.set_status(outcome.message, false);
-                        } else {
-                            self.set_status(outcome.message, false);
-                        }
+                        self.set_status(outcome.message, false);
                     }
                     Err(err) => {
-                        self.set_status(format!("Automation task error: {err}"), false);
+                        self.report_error("run the CLI agent", err.to_string(), None);
                     }
                 }
             }
         }
     }
 
-    fn reset_pagination(&mut self) {
-        self.page = 0;
-        self.has_next_page = false;
-        self.page_cache.clear();
-        self.page_cursors.clear();
+    pub(crate) fn selected_field(&self) -> EditField {
+        EditField::all()[self.field_index]
     }
 
-    pub(crate) async fn load_issues(&mut self) {
-        self.abort_pending();
-        self.ensure_teams().await;
-        self.ensure_states().await;
-        self.ensure_project_filters().await;
-        self.load_issues_with_filters().await;
+    pub(crate) fn field_editor(&self) -> Option<&FieldEditor> {
+        self.field_editor.as_ref()
     }
 
-    pub(crate) async fn open_projects_overlay(&mut self) {
-        self.show_help_overlay = false;
-        self.show_cycles_overlay = false;
-        self.set_spinner_status("Loading projects…");
-        self.ensure_project_filters().await;
-        if self.project_filter_options.is_empty() {
-            self.projects.clear();
-            self.set_status("No projects available (press o or Esc to close)", false);
-        } else {
-            self.projects = self.project_filter_options.clone();
-            self.show_projects_overlay = true;
-            self.set_status("Projects loaded (press o or Esc to close)", false);
-        }
+    pub(crate) fn field_status(&self, identifier: &str, field: EditField) -> Option<&FieldStatus> {
+        self.field_status
+            .get(&(identifier.to_ascii_uppercase(), field))
     }
 
-    pub(crate) fn close_projects_overlay(&mut self) {
-        if self.show_projects_overlay {
-            self.show_projects_overlay = false;
-            self.set_status("Projects overlay closed", false);
+    pub(crate) fn move_field_selection(&mut self, delta: isize) {
+        let total = EditField::all().len() as isize;
+        let next = (self.field_index as isize + delta).rem_euclid(total);
+        self.field_index = next as usize;
+        if self.detail_tab != DetailTab::Summary {
+            self.set_detail_tab(DetailTab::Summary);
         }
     }
 
-    pub(crate) async fn open_cycles_overlay(&mut self) {
-        self.show_help_overlay = false;
-        self.show_projects_overlay = false;
-        let team_id = self.current_team_id();
-        self.set_spinner_status("Loading cycles…");
-        let request = CycleQueryOptions {
-            limit: 10,
-            after: None,
-            team_id,
-            state: None,
-            sort: Some(CycleSort::StartDesc),
+    /// Open the editor for the highlighted summary field of the loaded issue.
+    pub(crate) fn begin_field_edit(&mut self) {
+        let Some(detail) = self.detail.as_ref() else {
+            self.set_status("Select an issue before editing fields", false);
+            return;
         };
-        match self.cycle_service.list(request).await {
-            Ok(response) => {
-                self.cycles = response.nodes;
-                self.show_cycles_overlay = true;
-                self.set_status("Cycles loaded (press y or Esc to close)", false);
-            }
-            Err(err) => {
-                self.cycles.clear();
-                self.set_status(format!("Failed to load cycles: {err}"), false);
+        if self.field_task.is_some() {
+            self.set_status("Still saving the previous change", false);
+            return;
+        }
+        let field = self.selected_field();
+        let editor = match field {
+            EditField::Priority => {
+                let current = detail
+                    .priority
+                    .and_then(Priority::from_value)
+                    .unwrap_or(Priority::None);
+                let index = Priority::ALL
+                    .iter()
+                    .position(|p| *p == current)
+                    .unwrap_or(0);
+                FieldEditor::Priority { index }
             }
+            EditField::Estimate => FieldEditor::Text {
+                field,
+                input: detail
+                    .estimate
+                    .map(|estimate| estimate.to_string())
+                    .unwrap_or_default(),
+            },
+            EditField::DueDate => FieldEditor::Text {
+                field,
+                input: detail
+                    .due_date
+                    .map(|date| date.format("%Y-%m-%d").to_string())
+                    .unwrap_or_default(),
+            },
+        };
+        if self.detail_tab != DetailTab::Summary {
+            self.detail_tab = DetailTab::Summary;
+            self.persist_detail_tab_selection();
         }
+        let hint = match field {
+            EditField::Priority => "j/k choose",
+            EditField::Estimate => "type points, empty clears",
+            EditField::DueDate => "YYYY-MM-DD, today, tomorrow or +N days; empty clears",
+        };
+        self.set_status(
+            format!(
+                "Editing {}: {hint}; Enter saves, Esc cancels",
+                field.label()
+            ),
+            false,
+        );
+        self.field_editor = Some(editor);
     }
 
-    pub(crate) fn close_cycles_overlay(&mut self) {
-        if self.show_cycles_overlay {
-            self.show_cycles_overlay = false;
-            self.set_status("Cycles overlay closed", false);
+    pub(crate) fn cancel_field_edit(&mut self) {
+        if self.field_editor.take().is_some() {
+            self.set_status("Edit cancelled", false);
         }
     }
 
-    pub(crate) async fn next_page(&mut self) {
-        if !self.has_next_page {
-            self.set_status("No more issues", false);
-            return;
+    pub(crate) fn move_field_editor(&mut self, delta: isize) {
+        if let Some(FieldEditor::Priority { index }) = self.field_editor.as_mut() {
+            let total = Priority::ALL.len() as isize;
+            *index = (*index as isize + delta).rem_euclid(total) as usize;
         }
-        if self
-            .page_cursors
-            .get(self.page)
-            .and_then(|c| c.clone())
-            .is_none()
-            && !self.page_cache.contains_key(&(self.page + 1))
-        {
-            self.set_status("Next page not yet available", false);
-            return;
+    }
+
+    pub(crate) fn push_field_char(&mut self, c: char) {
+        if let Some(FieldEditor::Text { input, .. }) = self.field_editor.as_mut() {
+            input.push(c);
         }
-        self.selected = 0;
-        self.issues.clear();
-        self.detail = None;
-        self.page += 1;
-        self.load_issues_with_filters().await;
     }
 
-    pub(crate) async fn previous_page(&mut self) {
-        if self.page == 0 {
-            self.set_status("Already at first page", false);
-            return;
+    pub(crate) fn pop_field_char(&mut self) {
+        if let Some(FieldEditor::Text { input, .. }) = self.field_editor.as_mut() {
+            input.pop();
         }
-        self.selected = 0;
-        self.issues.clear();
-        self.detail = None;
-        self.page -= 1;
-        self.load_issues_with_filters().await;
     }
 
-    pub(crate) async fn go_to_page(&mut self, page: usize) {
-        if page == self.page {
-            self.set_status(format!("Already on page {}", page + 1), false);
+    /// Validate the open editor and persist the change in the background.
+    pub(crate) fn commit_field_edit(&mut self) {
+        let Some(editor) = self.field_editor.take() else {
             return;
-        }
-        if page > 0 && page > self.page_cursors.len() && !self.page_cache.contains_key(&page) {
-            self.set_status("Page not available; advance sequentially", false);
+        };
+        let Some(detail) = self.detail.as_ref() else {
+            self.set_status(tr("tui-detail-unavailable"), false);
+            return;
+        };
+        let field = editor.field();
+        let identifier = detail.identifier.to_ascii_uppercase();
+        let mut input = IssueUpdateInput::default();
+        let parsed = match &editor {
+            FieldEditor::Priority { index } => {
+                input.priority = Some(Priority::ALL[*index].value());
+                Ok(())
+            }
+            FieldEditor::Text { input: text, .. } if field == EditField::Estimate => {
+                parse_estimate(text).map(|estimate| input.estimate = Some(estimate))
+            }
+            FieldEditor::Text { input: text, .. } => {
+                parse_due_date(text, Local::now().date_naive())
+                    .map(|due_date| input.due_date = Some(due_date))
+            }
+        };
+        if let Err(message) = parsed {
+            self.set_status(format!("{}: {message}", field.label()), false);
+            self.field_editor = Some(editor);
+            return;
+        }
+
+        let issue_id = detail.id.clone();
+        self.start_field_save(issue_id, identifier, field, input);
+    }
+
+    fn start_field_save(
+        &mut self,
+        issue_id: String,
+        identifier: String,
+        field: EditField,
+        input: IssueUpdateInput,
+    ) {
+        let service = self.service.clone();
+        self.field_status
+            .insert((identifier.clone(), field), FieldStatus::Pending);
+        self.set_spinner_status(format!("Saving {} for {}", field.label(), identifier));
+        self.field_task = Some(tokio::spawn(async move {
+            let result = service
+                .update(&issue_id, input.clone())
+                .await
+                .map_err(|err| err.to_string());
+            FieldSaveOutcome {
+                issue_id,
+                identifier,
+                field,
+                input,
+                result,
+            }
+        }));
+    }
+
+    pub(crate) async fn process_field_save(&mut self) {
+        let Some(handle) = self.field_task.as_mut() else {
+            return;
+        };
+        if !handle.is_finished() {
+            return;
+        }
+        let handle = self.field_task.take().unwrap();
+        let outcome = match handle.await {
+            Ok(outcome) => outcome,
+            Err(err) => {
+                self.report_error("save the field", err.to_string(), None);
+                return;
+            }
+        };
+        let key = (outcome.identifier.clone(), outcome.field);
+        match outcome.result {
+            Ok(updated) => {
+                self.apply_field_update(&updated);
+                self.field_status.insert(key, FieldStatus::Saved);
+                self.set_status(
+                    format!("Saved {} for {}", outcome.field.label(), outcome.identifier),
+                    false,
+                );
+            }
+            Err(err) => {
+                self.report_error(
+                    format!(
+                        "save {} for {}",
+                        outcome.field.label().to_lowercase(),
+                        outcome.identifier
+                    ),
+                    err.clone(),
+                    Some(Retry::FieldSave {
+                        issue_id: outcome.issue_id,
+                        identifier: outcome.identifier,
+                        field: outcome.field,
+                        input: outcome.input,
+                    }),
+                );
+                self.field_status.insert(key, FieldStatus::Failed(err));
+            }
+        }
+    }
+
+    /// Copy edited fields from a mutation result into the loaded detail and issue list.
+    fn apply_field_update(&mut self, updated: &IssueDetail) {
+        if let Some(detail) = self.detail.as_mut().filter(|d| d.id == updated.id) {
+            detail.priority = updated.priority;
+            detail.estimate = updated.estimate;
+            detail.due_date = updated.due_date;
+            detail.updated_at = updated.updated_at;
+        }
+        let cached = self
+            .page_cache
+            .get_mut(&self.page)
+            .map(|page| page.issues.iter_mut())
+            .into_iter()
+            .flatten();
+        for issue in self.issues.iter_mut().chain(cached) {
+            if issue.id == updated.id {
+                issue.priority = updated.priority;
+                issue.updated_at = updated.updated_at;
+            }
+        }
+        self.regroup();
+    }
+
+    /// Groups of the loaded page, in display order, when `group` is active.
+    pub(crate) fn issue_groups(&self) -> Option<Vec<IssueGroup<'_>>> {
+        self.group_by.map(|by| group_issues(&self.issues, by))
+    }
+
+    /// Partition the issue list by `by`, or restore the fetched order with `None`.
+    pub(crate) fn set_group_by(&mut self, by: Option<GroupBy>) {
+        self.group_by = by;
+        if by.is_none() {
+            let selected_key = self.selected_issue().map(|issue| issue.identifier.clone());
+            match self.page_cache.get(&self.page) {
+                Some(page) if page.issues.len() == self.issues.len() => {
+                    self.issues = page.issues.clone();
+                }
+                _ => {
+                    let sort = self.issue_sort;
+                    self.issues.sort_by(|a, b| sort.compare(a, b));
+                }
+            }
+            self.select_identifier(selected_key);
+            self.set_status("Grouping off", false);
+            return;
+        }
+        self.regroup();
+        let count = self.issue_groups().map_or(0, |groups| groups.len());
+        self.set_status(
+            format!(
+                "Grouped by {} ({count} group{})",
+                self.group_by.map_or("-", GroupBy::as_str),
+                if count == 1 { "" } else { "s" }
+            ),
+            false,
+        );
+    }
+
+    /// Reorder issues so each group is contiguous; navigation then follows the visual order.
+    fn regroup(&mut self) {
+        let Some(by) = self.group_by else {
+            return;
+        };
+        let selected_key = self.selected_issue().map(|issue| issue.identifier.clone());
+        let ordered: Vec<IssueSummary> = group_issues(&self.issues, by)
+            .into_iter()
+            .flat_map(|group| group.issues.into_iter().cloned())
+            .collect();
+        self.issues = ordered;
+        self.select_identifier(selected_key);
+    }
+
+    fn select_identifier(&mut self, identifier: Option<String>) {
+        if let Some(identifier) = identifier {
+            if let Some(index) = self
+                .issues
+                .iter()
+                .position(|issue| issue.identifier == identifier)
+            {
+                self.selected = index;
+            }
+        }
+    }
+
+    pub(crate) fn composer(&self) -> Option<&Composer> {
+        self.composer.as_ref()
+    }
+
+    /// Open the composer for the loaded issue, fetching its team's members for `@` mentions.
+    pub(crate) async fn begin_compose(&mut self, target: ComposeTarget) {
+        let Some(detail) = self.detail.as_ref() else {
+            self.set_status("Select an issue before writing", false);
+            return;
+        };
+        if self.compose_task.is_some() {
+            self.set_status("Still sending the previous text", false);
+            return;
+        }
+        let composer = Composer {
+            target,
+            identifier: detail.identifier.to_ascii_uppercase(),
+            text: match target {
+                ComposeTarget::Comment => String::new(),
+                ComposeTarget::Description => detail.description.clone().unwrap_or_default(),
+            },
+            suggestion: 0,
+            issue_id: detail.id.clone(),
+        };
+        let team_id = detail.team.as_ref().map(|team| team.id.clone());
+        self.members = match team_id {
+            Some(team_id) => self.team_members(&team_id).await,
+            None => Vec::new(),
+        };
+        let verb = match target {
+            ComposeTarget::Comment => "Commenting on",
+            ComposeTarget::Description => "Editing description of",
+        };
+        self.set_status(
+            format!(
+                "{verb} {}: @ mentions, Ctrl+E opens $EDITOR, Ctrl+S sends, Esc cancels",
+                composer.identifier
+            ),
+            false,
+        );
+        self.composer = Some(composer);
+    }
+
+    /// Members of `team_id`, from a recent snapshot when possible, else from the API.
+    async fn team_members(&mut self, team_id: &str) -> Vec<TeamMember> {
+        let key = members_snapshot_key(team_id);
+        let cached = self
+            .snapshots
+            .as_ref()
+            .and_then(|store| store.load::<Vec<TeamMember>>(&key));
+        if let Some(cached) = cached.as_ref() {
+            if Utc::now() - cached.saved_at < Duration::hours(MEMBERS_MAX_AGE_HOURS) {
+                return cached.value.clone();
+            }
+        }
+        match self.service.team_members(team_id).await {
+            Ok(members) => {
+                self.save_snapshot(&key, &members);
+                members
+            }
+            Err(err) => match cached {
+                Some(cached) => cached.value,
+                None => {
+                    self.set_status(format!("Team members unavailable: {err}"), false);
+                    Vec::new()
+                }
+            },
+        }
+    }
+
+    /// Members matching the `@handle` being typed, best first.
+    pub(crate) fn mention_suggestions(&self) -> Vec<&TeamMember> {
+        self.composer
+            .as_ref()
+            .and_then(|composer| mention::active_query(&composer.text))
+            .map(|query| mention::suggest(query.partial, &self.members, MENTION_SUGGESTIONS))
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn push_composer_char(&mut self, c: char) {
+        if let Some(composer) = self.composer.as_mut() {
+            composer.text.push(c);
+            composer.suggestion = 0;
+        }
+    }
+
+    pub(crate) fn pop_composer_char(&mut self) {
+        if let Some(composer) = self.composer.as_mut() {
+            composer.text.pop();
+            composer.suggestion = 0;
+        }
+    }
+
+    pub(crate) fn move_suggestion(&mut self, delta: isize) {
+        let total = self.mention_suggestions().len() as isize;
+        if let Some(composer) = self.composer.as_mut().filter(|_| total > 0) {
+            composer.suggestion = (composer.suggestion as isize + delta).rem_euclid(total) as usize;
+        }
+    }
+
+    /// Complete the highlighted mention; returns `false` when no suggestion is showing.
+    pub(crate) fn accept_suggestion(&mut self) -> bool {
+        let Some(composer) = self.composer.as_ref() else {
+            return false;
+        };
+        let Some(query) = mention::active_query(&composer.text) else {
+            return false;
+        };
+        let start = query.start;
+        let Some(handle) = self
+            .mention_suggestions()
+            .get(composer.suggestion)
+            .map(|member| format!("@{} ", member.display_name))
+        else {
+            return false;
+        };
+        let composer = self.composer.as_mut().unwrap();
+        composer.text.truncate(start);
+        composer.text.push_str(&handle);
+        composer.suggestion = 0;
+        true
+    }
+
+    /// Enter completes a showing mention, otherwise starts a new line.
+    pub(crate) fn composer_enter(&mut self) {
+        if !self.accept_suggestion() {
+            self.push_composer_char('\n');
+        }
+    }
+
+    /// Open the selected issue's description in `$EDITOR` and save it when the editor exits.
+    pub(crate) async fn edit_description_externally(&mut self) {
+        self.begin_compose(ComposeTarget::Description).await;
+        if self.composer.is_some() {
+            self.editor_request = Some(true);
+        }
+    }
+
+    /// Move the open draft to `$EDITOR`; it comes back to the composer for review.
+    pub(crate) fn open_draft_in_editor(&mut self) {
+        if self.composer.is_some() {
+            self.editor_request = Some(false);
+        }
+    }
+
+    /// The draft to hand to the editor, as `(file name, text)`, if one was requested.
+    pub(crate) fn editor_draft(&self) -> Option<(String, String)> {
+        self.editor_request?;
+        let composer = self.composer.as_ref()?;
+        Some((composer.identifier.clone(), composer.text.clone()))
+    }
+
+    /// Take back what the editor saved: `None` when it exited with an error.
+    pub(crate) fn finish_external_edit(&mut self, edited: Result<Option<String>>) {
+        let Some(submit) = self.editor_request.take() else {
+            return;
+        };
+        let Some(composer) = self.composer.as_mut() else {
+            return;
+        };
+        let identifier = composer.identifier.clone();
+        match edited {
+            Ok(Some(text)) => {
+                let text = text.trim_end().to_owned();
+                let unchanged = text == composer.text.trim_end();
+                composer.text = text;
+                composer.suggestion = 0;
+                if !submit {
+                    self.set_status(
+                        format!("Draft for {identifier} updated from the editor; Ctrl+S sends"),
+                        false,
+                    );
+                } else if unchanged && composer.target == ComposeTarget::Description {
+                    self.composer = None;
+                    self.set_status(format!("Description of {identifier} unchanged"), false);
+                } else {
+                    self.submit_compose();
+                }
+            }
+            Ok(None) => {
+                if submit {
+                    self.composer = None;
+                }
+                self.set_status(
+                    format!("Editor exited with an error; nothing sent for {identifier}"),
+                    false,
+                );
+            }
+            Err(err) => {
+                if submit {
+                    self.composer = None;
+                }
+                self.report_error(format!("edit {identifier}"), format!("{err:#}"), None);
+            }
+        }
+    }
+
+    pub(crate) fn cancel_compose(&mut self) {
+        if self.composer.take().is_some() {
+            self.set_status("Discarded draft", false);
+        }
+    }
+
+    /// Expand mentions and send the draft in the background.
+    pub(crate) fn submit_compose(&mut self) {
+        let Some(composer) = self.composer.take() else {
+            return;
+        };
+        if composer.target == ComposeTarget::Comment && composer.text.trim().is_empty() {
+            self.set_status("Comment is empty", false);
+            self.composer = Some(composer);
+            return;
+        }
+        let body = mention::expand(&composer.text, &self.members);
+        let service = self.service.clone();
+        self.set_spinner_status(format!("Sending to {}", composer.identifier));
+        self.compose_task = Some(tokio::spawn(async move {
+            let result = match composer.target {
+                ComposeTarget::Comment => {
+                    service.comment(&composer.issue_id, &body).await.map(|_| ())
+                }
+                ComposeTarget::Description => service
+                    .update(
+                        &composer.issue_id,
+                        IssueUpdateInput {
+                            description: Some(body),
+                            ..Default::default()
+                        },
+                    )
+                    .await
+                    .map(|_| ()),
+            };
+            ComposeOutcome {
+                identifier: composer.identifier,
+                target: composer.target,
+                text: composer.text,
+                issue_id: composer.issue_id,
+                result: result.map_err(|err| err.to_string()),
+            }
+        }));
+    }
+
+    pub(crate) async fn process_compose(&mut self) {
+        let Some(handle) = self.compose_task.as_mut() else {
+            return;
+        };
+        if !handle.is_finished() {
+            return;
+        }
+        let handle = self.compose_task.take().unwrap();
+        let outcome = match handle.await {
+            Ok(outcome) => outcome,
+            Err(err) => {
+                self.report_error("send", err.to_string(), None);
+                return;
+            }
+        };
+        match outcome.result {
+            Ok(()) => {
+                let message = match outcome.target {
+                    ComposeTarget::Comment => format!("Commented on {}", outcome.identifier),
+                    ComposeTarget::Description => {
+                        format!("Updated description of {}", outcome.identifier)
+                    }
+                };
+                self.set_status(message, false);
+                let showing = self.detail.as_ref().is_some_and(|detail| {
+                    detail.identifier.eq_ignore_ascii_case(&outcome.identifier)
+                });
+                if showing {
+                    self.pending_detail = Some(tokio::spawn(fetch_issue_detail(
+                        self.service.clone(),
+                        outcome.identifier,
+                    )));
+                }
+            }
+            Err(err) => {
+                // Reopen the draft so nothing typed is lost; sending it again is the retry.
+                let what = match outcome.target {
+                    ComposeTarget::Comment => "comment on",
+                    ComposeTarget::Description => "description of",
+                };
+                self.report_error(format!("send {what} {}", outcome.identifier), err, None);
+                self.composer = Some(Composer {
+                    target: outcome.target,
+                    identifier: outcome.identifier,
+                    text: outcome.text,
+                    suggestion: 0,
+                    issue_id: outcome.issue_id,
+                });
+            }
+        }
+    }
+
+    /// Cancel background requests and the automation subprocess when the TUI exits.
+    pub(crate) fn abort_tasks(&mut self) {
+        if let Some(handle) = self.pending_detail.take() {
+            handle.abort();
+        }
+        if let Some(handle) = self.automation_task.take() {
+            handle.abort();
+        }
+        if let Some(handle) = self.field_task.take() {
+            handle.abort();
+        }
+        if let Some(handle) = self.compose_task.take() {
+            handle.abort();
+        }
+        if let Some(handle) = self.explorer_task.take() {
+            handle.abort();
+        }
+        if let Some(handle) = self.project_task.take() {
+            handle.abort();
+        }
+        if let Some(handle) = self.cycle_task.take() {
+            handle.abort();
+        }
+        if let Some(handle) = self.workspace_task.take() {
+            handle.abort();
+        }
+        if let Some(handle) = self.action_task.take() {
+            handle.abort();
+        }
+    }
+
+    pub(crate) fn explorer(&self) -> Option<&Explorer> {
+        self.explorer.as_ref()
+    }
+
+    pub(crate) fn explorer_mut(&mut self) -> Option<&mut Explorer> {
+        self.explorer.as_mut()
+    }
+
+    /// Open the `:api` explorer on the cached schema, introspecting when it is missing, stale,
+    /// or `refresh` is set.
+    pub(crate) fn open_explorer(&mut self, refresh: bool) {
+        self.show_help_overlay = false;
+        let explorer = self.explorer.get_or_insert_with(Explorer::default);
+        if !refresh && explorer.schema().is_some() {
+            self.set_status(EXPLORER_HINT, false);
+            return;
+        }
+        let cached = self
+            .snapshots
+            .as_ref()
+            .and_then(|store| store.load::<Schema>(SCHEMA_SNAPSHOT));
+        if let Some(cached) = cached {
+            let fresh = Utc::now() - cached.saved_at < Duration::days(SCHEMA_MAX_AGE_DAYS);
+            if !refresh && fresh {
+                explorer.set_schema(cached.value);
+                self.set_status(EXPLORER_HINT, false);
+                return;
+            }
+            // Browse the old schema while the new one loads.
+            if explorer.schema().is_none() {
+                explorer.set_schema(cached.value);
+            }
+        }
+        if self.explorer_task.is_some() {
+            return;
+        }
+        let client = self.service.client().clone();
+        self.set_spinner_status("Loading schema…");
+        self.explorer_task = Some(tokio::spawn(async move {
+            ExplorerOutcome::Schema(client.schema().await.map_err(|err| err.to_string()))
+        }));
+    }
+
+    pub(crate) fn close_explorer(&mut self) {
+        if self.explorer.take().is_some() {
+            self.set_status("Closed API explorer", false);
+        }
+    }
+
+    /// Send the query built from the picked fields in the background.
+    pub(crate) fn run_explorer_query(&mut self) {
+        let Some(document) = self.explorer.as_ref().and_then(Explorer::document) else {
+            self.set_status("Select fields with Space first", false);
+            return;
+        };
+        if self.explorer_task.is_some() {
+            self.set_status(tr("tui-still-waiting"), false);
+            return;
+        }
+        let client = self.service.client().clone();
+        self.set_spinner_status("Running query…");
+        self.explorer_task = Some(tokio::spawn(async move {
+            ExplorerOutcome::Response(
+                client
+                    .execute(&document, json!({}), None)
+                    .await
+                    .map_err(|err| err.to_string()),
+            )
+        }));
+    }
+
+    pub(crate) async fn process_explorer(&mut self) {
+        let Some(handle) = self.explorer_task.as_mut() else {
+            return;
+        };
+        if !handle.is_finished() {
+            return;
+        }
+        let handle = self.explorer_task.take().unwrap();
+        let outcome = match handle.await {
+            Ok(outcome) => outcome,
+            Err(err) => {
+                self.report_error("run the explorer request", err.to_string(), None);
+                return;
+            }
+        };
+        match outcome {
+            ExplorerOutcome::Schema(Ok(schema)) => {
+                self.save_snapshot(SCHEMA_SNAPSHOT, &schema);
+                if let Some(explorer) = self.explorer.as_mut() {
+                    explorer.set_schema(schema);
+                    self.set_status(EXPLORER_HINT, false);
+                }
+            }
+            ExplorerOutcome::Schema(Err(err)) => {
+                if self.explorer.as_ref().is_some_and(|e| e.schema().is_none()) {
+                    self.explorer = None;
+                }
+                self.report_error("load schema", err, None);
+            }
+            ExplorerOutcome::Response(result) => {
+                let (lines, status) = match result {
+                    Ok(data) => {
+                        let text = serde_json::to_string_pretty(&data).unwrap_or_default();
+                        let lines: Vec<String> = text.lines().map(str::to_owned).collect();
+                        let status = format!("Query returned {} lines", lines.len());
+                        (lines, status)
+                    }
+                    Err(err) => (
+                        err.lines().map(str::to_owned).collect(),
+                        "Query failed; see the result pane".to_owned(),
+                    ),
+                };
+                if let Some(explorer) = self.explorer.as_mut() {
+                    explorer.set_result(lines);
+                    self.set_status(status, false);
+                }
+            }
+        }
+    }
+
+    pub(crate) fn projects_tab(&self) -> Option<&ProjectsTab> {
+        self.projects_tab.as_ref()
+    }
+
+    pub(crate) fn projects_tab_mut(&mut self) -> Option<&mut ProjectsTab> {
+        self.projects_tab.as_mut()
+    }
+
+    /// Switch between the issue list and the projects tab, loading projects on the way in.
+    pub(crate) fn toggle_projects_tab(&mut self) {
+        if self.projects_tab.take().is_some() {
+            self.set_status(tr("tui-back-to-issues"), false);
+            return;
+        }
+        self.show_help_overlay = false;
+        self.show_projects_overlay = false;
+        self.cycles_tab = None;
+        self.board_tab = None;
+        self.projects_tab = Some(ProjectsTab::default());
+        self.load_project_list();
+    }
+
+    /// Fetch the projects of the current team filter, most recently updated first.
+    pub(crate) fn load_project_list(&mut self) {
+        if !self.start_project_task() {
+            return;
+        }
+        let service = self.project_service.clone();
+        let request = ProjectQueryOptions {
+            limit: 50,
+            after: None,
+            state: None,
+            status: None,
+            team_id: self.current_team_id(),
+            sort: Some(ProjectSort::UpdatedDesc),
+        };
+        self.set_spinner_status("Loading projects…");
+        self.project_task = Some(tokio::spawn(async move {
+            ProjectOutcome::List(
+                service
+                    .list(request)
+                    .await
+                    .map(|response| response.nodes)
+                    .map_err(|err| err.to_string()),
+            )
+        }));
+    }
+
+    /// Load the selected project's detail and issues.
+    pub(crate) fn open_selected_project(&mut self) {
+        let Some(id) = self.selected_project_id() else {
+            return;
+        };
+        if !self.start_project_task() {
+            return;
+        }
+        let projects = self.project_service.clone();
+        let issues = self.service.clone();
+        self.set_spinner_status("Loading project…");
+        self.project_task = Some(tokio::spawn(async move {
+            let result = async {
+                let detail = projects.get(&id).await?;
+                let list = fetch_issue_summaries(
+                    &issues,
+                    None,
+                    None,
+                    Some(id),
+                    None,
+                    None,
+                    PROJECT_ISSUES_LIMIT,
+                )
+                .await?;
+                anyhow::Ok((detail, list.issues))
+            };
+            ProjectOutcome::Detail(result.await.map_err(|err| err.to_string()))
+        }));
+    }
+
+    pub(crate) fn begin_project_state_edit(&mut self) {
+        if let Some(tab) = self
+            .projects_tab
+            .as_mut()
+            .filter(|tab| tab.selected().is_some())
+        {
+            tab.begin_state_edit();
+            self.set_status("Pick a state: j/k move, Enter save, Esc cancel", false);
+        }
+    }
+
+    pub(crate) fn begin_project_target_date_edit(&mut self) {
+        if let Some(tab) = self
+            .projects_tab
+            .as_mut()
+            .filter(|tab| tab.selected().is_some())
+        {
+            tab.begin_target_date_edit();
+            self.set_status(
+                "Target date: YYYY-MM-DD, today, tomorrow or +N; Enter save, Esc cancel",
+                false,
+            );
+        }
+    }
+
+    /// Fetch the members of the selected project's teams, then open the lead picker.
+    pub(crate) fn begin_project_lead_edit(&mut self) {
+        let Some(id) = self.selected_project_id() else {
+            return;
+        };
+        if !self.start_project_task() {
+            return;
+        }
+        let projects = self.project_service.clone();
+        let issues = self.service.clone();
+        self.set_spinner_status("Loading team members…");
+        self.project_task = Some(tokio::spawn(async move {
+            let result = async {
+                let detail = projects.get(&id).await?;
+                let mut members: Vec<TeamMember> = Vec::new();
+                for team in &detail.teams {
+                    for member in issues.team_members(&team.id).await? {
+                        if !members.iter().any(|m| m.id == member.id) {
+                            members.push(member);
+                        }
+                    }
+                }
+                members.sort_by_key(|member| member.display_name.to_lowercase());
+                anyhow::Ok(members)
+            };
+            ProjectOutcome::Members(result.await.map_err(|err| err.to_string()))
+        }));
+    }
+
+    pub(crate) fn cancel_project_edit(&mut self) {
+        if let Some(tab) = self.projects_tab.as_mut() {
+            tab.cancel_edit();
+            self.set_status(PROJECTS_HINT, false);
+        }
+    }
+
+    /// Save the change picked in the open project editor in the background.
+    pub(crate) fn commit_project_edit(&mut self) {
+        let Some(id) = self.selected_project_id() else {
+            return;
+        };
+        if !self.start_project_task() {
+            return;
+        }
+        let Some(tab) = self.projects_tab.as_mut() else {
+            return;
+        };
+        let edit = match tab.take_edit(Local::now().date_naive()) {
+            Ok(Some(edit)) => edit,
+            Ok(None) => return,
+            Err(err) => {
+                self.set_status(err, false);
+                return;
+            }
+        };
+        let (input, change) = match edit {
+            ProjectEdit::State(state) => (
+                ProjectUpdateInput {
+                    state: Some(state.clone()),
+                    ..Default::default()
+                },
+                format!("state {state}"),
+            ),
+            ProjectEdit::TargetDate(date) => (
+                ProjectUpdateInput {
+                    target_date: Some(date.to_string()),
+                    ..Default::default()
+                },
+                format!("target date {date}"),
+            ),
+            ProjectEdit::Lead { id, name } => (
+                ProjectUpdateInput {
+                    lead_id: Some(id),
+                    ..Default::default()
+                },
+                format!("lead {name}"),
+            ),
+        };
+        let service = self.project_service.clone();
+        self.set_spinner_status(format!("Saving {change}…"));
+        self.project_task = Some(tokio::spawn(async move {
+            let result = service
+                .update(&id, input)
+                .await
+                .map_err(|err| err.to_string());
+            ProjectOutcome::Saved { change, result }
+        }));
+    }
+
+    fn selected_project_id(&self) -> Option<String> {
+        self.projects_tab
+            .as_ref()
+            .and_then(ProjectsTab::selected)
+            .map(|project| project.id.clone())
+    }
+
+    /// Whether a new project request may start; one runs at a time.
+    fn start_project_task(&mut self) -> bool {
+        if self.project_task.is_some() {
+            self.set_status(tr("tui-still-waiting"), false);
+            return false;
+        }
+        true
+    }
+
+    pub(crate) async fn process_projects(&mut self) {
+        let Some(handle) = self.project_task.as_mut() else {
+            return;
+        };
+        if !handle.is_finished() {
+            return;
+        }
+        let handle = self.project_task.take().unwrap();
+        let outcome = match handle.await {
+            Ok(outcome) => outcome,
+            Err(err) => {
+                self.report_error("finish the project request", err.to_string(), None);
+                return;
+            }
+        };
+        let Some(tab) = self.projects_tab.as_mut() else {
+            return;
+        };
+        let status = match outcome {
+            ProjectOutcome::List(Ok(projects)) => {
+                let count = projects.len();
+                tab.set_projects(projects);
+                if count == 0 {
+                    "No projects for the current team filter (P back to issues)".to_owned()
+                } else {
+                    PROJECTS_HINT.to_owned()
+                }
+            }
+            ProjectOutcome::List(Err(err)) => {
+                return self.report_error("load projects", err, Some(Retry::Projects));
+            }
+            ProjectOutcome::Detail(Ok((detail, issues))) => {
+                let noun = if issues.len() == 1 { "issue" } else { "issues" };
+                let status = format!("{}: {} {noun}", detail.name, issues.len());
+                tab.set_detail(detail, issues);
+                status
+            }
+            ProjectOutcome::Detail(Err(err)) => {
+                return self.report_error("load project", err, None);
+            }
+            ProjectOutcome::Members(Ok(members)) if members.is_empty() => {
+                "The project's teams have no members to pick from".to_owned()
+            }
+            ProjectOutcome::Members(Ok(members)) => {
+                tab.begin_lead_edit(members);
+                "Pick a lead: j/k move, Enter save, Esc cancel".to_owned()
+            }
+            ProjectOutcome::Members(Err(err)) => {
+                return self.report_error("load team members", err, None);
+            }
+            ProjectOutcome::Saved {
+                change,
+                result: Ok(project),
+            } => {
+                let status = format!("Set {} {change}", project.name);
+                tab.apply_update(project);
+                status
+            }
+            ProjectOutcome::Saved {
+                change,
+                result: Err(err),
+            } => return self.report_error(format!("set {change}"), err, None),
+        };
+        self.set_status(status, false);
+    }
+
+    pub(crate) fn board_tab(&self) -> Option<&BoardTab> {
+        self.board_tab.as_ref()
+    }
+
+    /// The loaded issue page laid out by state, split into the board tab's label lanes.
+    pub(crate) fn board(&self) -> Board<'_> {
+        let lanes = self
+            .board_tab
+            .as_ref()
+            .map(BoardTab::lanes)
+            .unwrap_or_default();
+        Board::build(&self.issues, lanes)
+    }
+
+    /// Switch between the issue list and the board of the loaded page.
+    pub(crate) fn toggle_board_tab(&mut self) {
+        if self.board_tab.take().is_some() {
+            self.set_status(tr("tui-back-to-issues"), false);
+            return;
+        }
+        self.show_help_overlay = false;
+        self.show_projects_overlay = false;
+        self.projects_tab = None;
+        self.cycles_tab = None;
+        self.board_tab = Some(BoardTab::default());
+        self.announce_board();
+    }
+
+    fn announce_board(&mut self) {
+        let board = self.board();
+        let mut message = format!(
+            "Board: {} issue{} in {} column{}",
+            self.issues.len(),
+            if self.issues.len() == 1 { "" } else { "s" },
+            board.columns.len(),
+            if board.columns.len() == 1 { "" } else { "s" },
+        );
+        let lanes = self
+            .board_tab
+            .as_ref()
+            .map(BoardTab::lanes)
+            .unwrap_or_default();
+        if !lanes.is_empty() {
+            message.push_str(&format!(" · lanes: {}", lanes.join(", ")));
+        }
+        let violations = self.board_limits.violations(&self.issues, Utc::now());
+        if !violations.is_empty() {
+            message.push_str(&format!(
+                " · {} WIP, {} age limit{} exceeded",
+                violations.wip.len(),
+                violations.age.len(),
+                if violations.len() == 1 { "" } else { "s" },
+            ));
+        }
+        message.push_str(" · h/l columns, j/k cards, Enter opens");
+        self.set_status(message, false);
+    }
+
+    pub(crate) fn move_board_cursor(&mut self, columns: isize, rows: isize) {
+        let Some(tab) = self.board_tab.as_mut() else {
+            return;
+        };
+        let board = Board::build(&self.issues, tab.lanes());
+        tab.move_cursor(&board, columns, rows);
+    }
+
+    /// Leave the board with the card under the cursor selected in the issue list.
+    pub(crate) fn open_board_card(&mut self) {
+        let Some(tab) = self.board_tab.as_ref() else {
+            return;
+        };
+        let board = Board::build(&self.issues, tab.lanes());
+        let (column, row) = tab.cursor(&board);
+        let Some(id) = board
+            .column_cards(column)
+            .get(row)
+            .map(|issue| issue.id.clone())
+        else {
+            self.set_status("No issue under the cursor", false);
+            return;
+        };
+        let Some(index) = self.issues.iter().position(|issue| issue.id == id) else {
+            return;
+        };
+        self.board_tab = None;
+        self.focus = Focus::Issues;
+        self.select_issue(index);
+    }
+
+    /// `export md|html [path]` writes the loaded page, grouped as shown, to a file.
+    fn export_view(&mut self, args: &str) {
+        let mut parts = args.split_whitespace();
+        let format = match parts.next().map(str::parse::<ShareFormat>) {
+            Some(Ok(format)) => format,
+            Some(Err(err)) => {
+                self.set_status(err, false);
+                return;
+            }
+            None => {
+                self.set_status("Usage: export <md|html> [path]", false);
+                return;
+            }
+        };
+        let rest: Vec<&str> = parts.collect();
+        let path = if rest.is_empty() {
+            format!("linear-issues.{}", format.extension())
+        } else {
+            rest.join(" ")
+        };
+        let snapshot = match self.issue_groups() {
+            Some(groups) => Snapshot::grouped("Linear issues", groups),
+            None => Snapshot::new("Linear issues", &self.issues),
+        }
+        .subtitle(self.filter_context());
+        let count = snapshot.len();
+        let message = match std::fs::write(&path, snapshot.render(format)) {
+            Ok(()) => format!(
+                "Exported {count} issue{} to {path}",
+                if count == 1 { "" } else { "s" }
+            ),
+            Err(err) => format!("Export to {path} failed: {err}"),
+        };
+        self.set_status(message, false);
+    }
+
+    /// `lanes label <name>` adds or removes a label swimlane; `lanes off` removes them all.
+    fn run_lanes_command(&mut self, args: &str) {
+        let args = args.trim();
+        if matches!(args.to_ascii_lowercase().as_str(), "off" | "none" | "clear") {
+            if let Some(tab) = self.board_tab.as_mut() {
+                tab.clear_lanes();
+            }
+            self.set_status("Lanes cleared", false);
+            return;
+        }
+        let Some(name) = args
+            .strip_prefix("label ")
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+        else {
+            self.set_status("Usage: lanes label <name> | lanes off", false);
+            return;
+        };
+        // Prefer the label's own spelling when a loaded issue carries it.
+        let mut loaded: Vec<&str> = self
+            .issues
+            .iter()
+            .filter_map(|issue| issue.labels.as_ref())
+            .flat_map(|labels| labels.nodes.iter())
+            .map(|label| label.name.as_str())
+            .collect();
+        loaded.sort_unstable();
+        loaded.dedup();
+        let known = match match_name("Label", name, &loaded, |label| vec![*label]) {
+            Ok(index) => index.map(|index| loaded[index].to_owned()),
+            Err(message) => {
+                self.set_status(message, false);
+                return;
+            }
+        };
+        if self.board_tab.is_none() {
+            self.toggle_board_tab();
+        }
+        let Some(tab) = self.board_tab.as_mut() else {
+            return;
+        };
+        let added = tab.toggle_lane(known.clone().unwrap_or_else(|| name.to_owned()));
+        if added && known.is_none() {
+            self.set_status(format!("No loaded issue has the label '{name}'"), false);
+        } else {
+            self.announce_board();
+        }
+    }
+
+    pub(crate) fn cycles_tab(&self) -> Option<&CyclesTab> {
+        self.cycles_tab.as_ref()
+    }
+
+    pub(crate) fn cycles_tab_mut(&mut self) -> Option<&mut CyclesTab> {
+        self.cycles_tab.as_mut()
+    }
+
+    /// Switch between the issue list and the cycles tab, loading cycles on the way in.
+    pub(crate) fn toggle_cycles_tab(&mut self) {
+        if self.cycles_tab.take().is_some() {
+            self.set_status(tr("tui-back-to-issues"), false);
+            return;
+        }
+        self.show_help_overlay = false;
+        self.show_projects_overlay = false;
+        self.projects_tab = None;
+        self.board_tab = None;
+        self.cycles_tab = Some(CyclesTab::default());
+        self.load_cycle_list(None);
+    }
+
+    /// Fetch the latest cycles of the current team filter, newest first; `notice` replaces
+    /// the usual hint once they arrive.
+    pub(crate) fn load_cycle_list(&mut self, notice: Option<String>) {
+        if !self.start_cycle_task() {
+            return;
+        }
+        let service = self.cycle_service.clone();
+        let request = CycleQueryOptions {
+            limit: CYCLES_LIMIT,
+            after: None,
+            team_id: self.current_team_id(),
+            state: None,
+            sort: Some(CycleSort::StartDesc),
+        };
+        self.set_spinner_status("Loading cycles…");
+        self.cycle_task = Some(tokio::spawn(async move {
+            let result = service
+                .list(request)
+                .await
+                .map(|response| response.nodes)
+                .map_err(|err| err.to_string());
+            CycleOutcome::List { result, notice }
+        }));
+    }
+
+    /// Load the issues of the highlighted cycle.
+    pub(crate) fn open_selected_cycle(&mut self) {
+        let Some(cycle_id) = self
+            .cycles_tab
+            .as_ref()
+            .and_then(CyclesTab::selected)
+            .map(|cycle| cycle.id.clone())
+        else {
+            return;
+        };
+        if !self.start_cycle_task() {
+            return;
+        }
+        let service = self.service.clone();
+        self.set_spinner_status("Loading cycle issues…");
+        self.cycle_task = Some(tokio::spawn(async move {
+            let result = service
+                .list(IssueQueryOptions {
+                    limit: PROJECT_ISSUES_LIMIT,
+                    cycle_id: Some(cycle_id.clone()),
+                    ..Default::default()
+                })
+                .await
+                .map(|list| list.issues)
+                .map_err(|err| err.to_string());
+            CycleOutcome::Issues { cycle_id, result }
+        }));
+    }
+
+    /// Move the picked issues (or the highlighted one) into the team's active cycle.
+    pub(crate) fn move_to_active_cycle(&mut self) {
+        let Some(tab) = self.cycles_tab.as_ref() else {
+            return;
+        };
+        let Some(opened) = tab.opened() else {
+            self.set_status("Open a cycle with Enter first", false);
+            return;
+        };
+        let Some(active) = tab.active_cycle() else {
+            self.set_status("This team has no active cycle", false);
+            return;
+        };
+        if active.id == opened.id {
+            self.set_status("These issues are already in the active cycle", false);
+            return;
+        }
+        let targets = tab.move_targets();
+        if targets.is_empty() {
+            return;
+        }
+        let cycle_id = active.id.clone();
+        let cycle = cycle_label(active);
+        if !self.start_cycle_task() {
+            return;
+        }
+        let service = self.service.clone();
+        self.set_spinner_status(format!("Moving {} issue(s) to {cycle}…", targets.len()));
+        self.cycle_task = Some(tokio::spawn(async move {
+            let mut moved = Vec::new();
+            let mut failure = None;
+            for issue in &targets {
+                let input = IssueUpdateInput {
+                    cycle_id: Some(cycle_id.clone()),
+                    ..Default::default()
+                };
+                match service.update(&issue.id, input).await {
+                    Ok(_) => moved.push(issue.id.clone()),
+                    Err(err) => {
+                        failure = Some(format!("{}: {err}", issue.identifier));
+                        break;
+                    }
+                }
+            }
+            CycleOutcome::Moved {
+                cycle,
+                moved,
+                total: targets.len(),
+                failure,
+            }
+        }));
+    }
+
+    /// Whether a new cycle request may start; one runs at a time.
+    fn start_cycle_task(&mut self) -> bool {
+        if self.cycle_task.is_some() {
+            self.set_status(tr("tui-still-waiting"), false);
+            return false;
+        }
+        true
+    }
+
+    pub(crate) async fn process_cycles(&mut self) {
+        let Some(handle) = self.cycle_task.as_mut() else {
+            return;
+        };
+        if !handle.is_finished() {
+            return;
+        }
+        let handle = self.cycle_task.take().unwrap();
+        let outcome = match handle.await {
+            Ok(outcome) => outcome,
+            Err(err) => {
+                self.report_error("finish the cycle request", err.to_string(), None);
+                return;
+            }
+        };
+        let Some(tab) = self.cycles_tab.as_mut() else {
+            return;
+        };
+        let status = match outcome {
+            CycleOutcome::List {
+                result: Ok(cycles),
+                notice,
+            } => {
+                let empty = cycles.is_empty();
+                tab.set_cycles(cycles);
+                match notice {
+                    Some(notice) => notice,
+                    None if empty => {
+                        "No cycles for the current team filter (Y back to issues)".to_owned()
+                    }
+                    None => CYCLES_HINT.to_owned(),
+                }
+            }
+            CycleOutcome::List {
+                result: Err(err), ..
+            } => return self.report_error("load cycles", err, Some(Retry::Cycles)),
+            CycleOutcome::Issues {
+                cycle_id,
+                result: Ok(issues),
+            } => {
+                let count = issues.len();
+                tab.set_issues(cycle_id, issues);
+                let name = tab.opened().map(cycle_label).unwrap_or_default();
+                let noun = if count == 1 { "issue" } else { "issues" };
+                format!("{name}: {count} {noun} (Space picks, m moves to the active cycle)")
+            }
+            CycleOutcome::Issues {
+                result: Err(err), ..
+            } => return self.report_error("load cycle issues", err, None),
+            CycleOutcome::Moved {
+                cycle,
+                moved,
+                total,
+                failure,
+            } => {
+                tab.remove_issues(&moved);
+                let status = match failure {
+                    None => format!("Moved {} issue(s) to {cycle}", moved.len()),
+                    Some(err) => {
+                        let status = format!("Moved {} of {total} to {cycle}; {err}", moved.len());
+                        self.log_error(format!("move issues to {cycle}"), err, None);
+                        status
+                    }
+                };
+                // Scope counts changed on both cycles.
+                self.load_cycle_list(Some(status));
+                return;
+            }
+        };
+        self.set_status(status, false);
+    }
+
+    fn reset_pagination(&mut self) {
+        self.page = 0;
+        self.has_next_page = false;
+        self.page_cache.clear();
+        self.page_cursors.clear();
+    }
+
+    /// Show the last saved teams and first issue page for the current filters, if any.
+    ///
+    /// Returns `true` when cached issues were restored; the next load revalidates them.
+    pub(crate) fn restore_snapshot(&mut self) -> bool {
+        let Some(store) = self.snapshots.as_ref() else {
+            return false;
+        };
+        let Some(snapshot) = store.load::<PageData>(&self.issues_snapshot_key()) else {
+            return false;
+        };
+        if let Some(teams) = store.load::<Vec<TeamSummary>>(TEAMS_SNAPSHOT) {
+            self.teams = teams.value;
+        }
+        let saved_at = snapshot.saved_at;
+        self.refreshing = true;
+        self.stale_first_page = Some((self.issues_snapshot_key(), snapshot.value.clone()));
+        self.apply_cached_page(snapshot.value);
+        self.set_spinner_status(format!(
+            "Showing issues cached {} — refreshing…",
+            cache_age(saved_at)
+        ));
+        true
+    }
+
+    pub(crate) fn refreshing(&self) -> bool {
+        self.refreshing
+    }
+
+    fn apply_cached_page(&mut self, data: PageData) {
+        self.page = 0;
+        self.has_next_page = data.has_next_page;
+        self.page_cursors = vec![data.end_cursor];
+        self.issues = data.issues;
+        self.selected = 0;
+        self.regroup();
+    }
+
+    fn issues_snapshot_key(&self) -> String {
+        let part = |value: Option<String>| value.unwrap_or_else(|| "-".into());
+        format!(
+            "issues:team={}:state={}:project={}:contains={}:assignee={}:sort={}",
+            part(self.current_team_id()),
+            part(self.current_state_id()),
+            part(self.current_project_id()),
+            part(self.current_contains()),
+            part(self.assignee_filter.as_ref().map(AssigneeFilter::key)),
+            self.issue_sort,
+        )
+    }
+
+    fn save_snapshot<T: Serialize>(&self, key: &str, value: &T) {
+        if let Some(store) = &self.snapshots {
+            // A failed write only costs the next startup its instant render.
+            let _ = store.save(key, value);
+        }
+    }
+
+    /// Reload the current page. The first page is revalidated with only the issues updated
+    /// since its newest `updatedAt`; `full` refetches it outright.
+    pub(crate) async fn refresh_issues(&mut self, full: bool) {
+        let cached = self.page_cache.remove(&self.page);
+        self.stale_first_page = match cached {
+            Some(data) if self.page == 0 && !full => Some((self.issues_snapshot_key(), data)),
+            _ => None,
+        };
+        if full {
+            self.page_cache.clear();
+        }
+        self.load_issues().await;
+    }
+
+    pub(crate) async fn load_issues(&mut self) {
+        self.abort_pending();
+        self.ensure_teams().await;
+        self.ensure_states().await;
+        self.ensure_project_filters().await;
+        self.load_issues_with_filters().await;
+    }
+
+    pub(crate) async fn open_projects_overlay(&mut self) {
+        self.show_help_overlay = false;
+        self.set_spinner_status("Loading projects…");
+        self.ensure_project_filters().await;
+        if self.project_filter_options.is_empty() {
+            self.projects.clear();
+            self.set_status("No projects available (press o or Esc to close)", false);
+        } else {
+            self.projects = self.project_filter_options.clone();
+            self.show_projects_overlay = true;
+            self.set_status("Projects loaded (press o or Esc to close)", false);
+        }
+    }
+
+    pub(crate) fn close_projects_overlay(&mut self) {
+        if self.show_projects_overlay {
+            self.show_projects_overlay = false;
+            self.set_status("Projects overlay closed", false);
+        }
+    }
+
+    pub(crate) fn action_registry(&self) -> &ActionRegistry {
+        &self.actions
+    }
+
+    pub(crate) fn quick_menu(&self) -> Option<&QuickMenu> {
+        self.quick_menu.as_ref()
+    }
+
+    /// Open the `.` menu of actions for the selected issue.
+    pub(crate) fn open_quick_menu(&mut self) {
+        let Some(issue) = self.selected_issue() else {
+            self.set_status(tr("tui-select-issue-first"), false);
+            return;
+        };
+        let message = format!(
+            "Actions for {}: j/k move, Enter or shortcut runs, Esc closes",
+            issue.identifier
+        );
+        self.quick_menu = Some(QuickMenu::Actions { index: 0 });
+        self.set_status(message, false);
+    }
+
+    pub(crate) fn close_quick_menu(&mut self) {
+        if self.quick_menu.take().is_some() {
+            self.set_status("Actions closed", false);
+        }
+    }
+
+    pub(crate) fn move_quick_menu(&mut self, delta: isize) {
+        let actions = self.actions.actions().len();
+        if let Some(menu) = self.quick_menu.as_mut() {
+            menu.move_cursor(delta, actions);
+        }
+    }
+
+    /// Run the action bound to `key` when the menu lists actions.
+    pub(crate) fn quick_menu_shortcut(&mut self, key: char) {
+        if !matches!(self.quick_menu, Some(QuickMenu::Actions { .. })) {
+            return;
+        }
+        if let Some(action) = self.actions.by_key(key).map(|spec| spec.action) {
+            self.run_action(action);
+        }
+    }
+
+    /// Run the highlighted action, or apply the highlighted state or label.
+    pub(crate) fn select_quick_menu(&mut self) {
+        match self.quick_menu.take() {
+            Some(QuickMenu::Actions { index }) => {
+                if let Some(action) = self.actions.get(index).map(|spec| spec.action) {
+                    self.run_action(action);
+                }
+            }
+            Some(QuickMenu::States { states, index }) => {
+                let Some(state) = states.get(index) else {
+                    return;
+                };
+                let input = IssueUpdateInput {
+                    state_id: Some(state.id.clone()),
+                    ..IssueUpdateInput::default()
+                };
+                self.start_issue_update(format!("state → {}", state.name), input);
+            }
+            Some(QuickMenu::Labels { labels, index }) => {
+                let Some(label) = labels.get(index) else {
+                    return;
+                };
+                // The update replaces the label set, so send the current labels along.
+                let mut label_ids: Vec<String> = self
+                    .selected_detail()
+                    .and_then(|detail| detail.labels.as_ref())
+                    .map(|labels| labels.nodes.iter().map(|l| l.id.clone()).collect())
+                    .unwrap_or_default();
+                label_ids.push(label.id.clone());
+                let input = IssueUpdateInput {
+                    label_ids: Some(label_ids),
+                    ..IssueUpdateInput::default()
+                };
+                self.start_issue_update(format!("label + {}", label.name), input);
+            }
+            None => {}
+        }
+    }
+
+    pub(crate) fn yank_pending(&self) -> bool {
+        self.yank_pending
+    }
+
+    /// Start a `y` copy chord; the next key picks what to copy.
+    pub(crate) fn begin_yank(&mut self) {
+        if self.selected_issue().is_none() {
+            self.set_status(tr("tui-select-issue-first"), false);
+            return;
+        }
+        self.yank_pending = true;
+        self.set_status(tr("tui-copy-prompt"), false);
+    }
+
+    /// Finish a `y` chord with `key`: `y` copies the issue key, `u` its URL, `b` its branch.
+    pub(crate) fn finish_yank(&mut self, key: Option<char>) {
+        self.yank_pending = false;
+        match key {
+            Some('y') => self.run_action(IssueAction::CopyKey),
+            Some('u') => self.run_action(IssueAction::CopyUrl),
+            Some('b') => self.run_action(IssueAction::CopyBranch),
+            _ => self.set_status(tr("tui-copy-cancelled"), false),
+        }
+    }
+
+    /// Run `action` on the selected issue; the menu closes unless a picker follows.
+    pub(crate) fn run_action(&mut self, action: IssueAction) {
+        self.quick_menu = None;
+        let Some(issue) = self.selected_issue().cloned() else {
+            self.set_status(tr("tui-select-issue-first"), false);
+            return;
+        };
+        let copy = |label: &str, value: Option<&str>| {
+            let what = tr(label);
+            match value {
+                Some(value) => match crate::clipboard::write(value) {
+                    Ok(method) => tr_args(
+                        "tui-copied",
+                        &[
+                            ("what", &what),
+                            ("method", &method.to_string()),
+                            ("value", value),
+                        ],
+                    ),
+                    Err(err) => tr_args("tui-copy-failed", &[("message", &err.to_string())]),
+                },
+                None => tr_args("no-value", &[("issue", &issue.identifier), ("what", &what)]),
+            }
+        };
+        let message = match action {
+            IssueAction::OpenInBrowser => match issue.url.as_deref() {
+                Some(url) => match open::that(url) {
+                    Ok(()) => format!("Opened {} in the browser", issue.identifier),
+                    Err(err) => format!("Failed to open browser: {err}"),
+                },
+                None => format!("{} has no URL", issue.identifier),
+            },
+            IssueAction::CopyKey => copy("copy-what-key", Some(&issue.identifier)),
+            IssueAction::CopyUrl => copy("copy-what-url", issue.url.as_deref()),
+            IssueAction::CopyBranch => copy("copy-what-branch", issue.branch_name.as_deref()),
+            IssueAction::ChangeState | IssueAction::AddLabel => {
+                let Some(team_id) = self
+                    .selected_detail()
+                    .and_then(|detail| detail.team.as_ref())
+                    .map(|team| team.id.clone())
+                else {
+                    self.set_status("Wait for the issue details to load", false);
+                    return;
+                };
+                if !self.start_action_task() {
+                    return;
+                }
+                let service = self.service.clone();
+                self.action_task = Some(if action == IssueAction::ChangeState {
+                    self.set_spinner_status("Loading workflow states");
+                    let detail = self.selected_detail().cloned();
+                    tokio::spawn(async move {
+                        let Some(detail) = detail else {
+                            return ActionOutcome::States(Ok(Vec::new()));
+                        };
+                        ActionOutcome::States(
+                            service
+                                .valid_transitions(&detail)
+                                .await
+                                .map_err(|err| err.to_string()),
+                        )
+                    })
+                } else {
+                    self.set_spinner_status("Loading labels");
+                    tokio::spawn(async move {
+                        ActionOutcome::Labels(
+                            service
+                                .client()
+                                .issue_labels(&team_id)
+                                .await
+                                .map_err(|err| err.to_string()),
+                        )
+                    })
+                });
+                return;
+            }
+            IssueAction::AssignToMe => {
+                if !self.start_action_task() {
+                    return;
+                }
+                let service = self.service.clone();
+                let identifier = issue.identifier.clone();
+                self.set_spinner_status(format!("Assigning {identifier} to you"));
+                self.action_task = Some(tokio::spawn(async move {
+                    let result = async {
+                        let viewer = service.client().viewer().await?;
+                        let input = IssueUpdateInput {
+                            assignee_id: Some(viewer.id),
+                            ..IssueUpdateInput::default()
+                        };
+                        service.update(&issue.id, input).await
+                    }
+                    .await
+                    .map(Box::new)
+                    .map_err(|err| err.to_string());
+                    ActionOutcome::Updated {
+                        identifier,
+                        change: "assignee → you".into(),
+                        result,
+                        retry: None,
+                    }
+                }));
+                return;
+            }
+        };
+        self.set_status(message, false);
+    }
+
+    /// The loaded detail, when it belongs to the selected issue.
+    fn selected_detail(&self) -> Option<&IssueDetail> {
+        let selected = self.selected_issue()?;
+        self.detail
+            .as_ref()
+            .filter(|detail| detail.id == selected.id)
+    }
+
+    fn start_action_task(&mut self) -> bool {
+        if self.action_task.is_some() {
+            self.set_status(tr("tui-still-waiting"), false);
+            return false;
+        }
+        true
+    }
+
+    fn start_issue_update(&mut self, change: String, input: IssueUpdateInput) {
+        let Some(issue) = self.selected_issue().cloned() else {
+            return;
+        };
+        self.start_update(issue.id, issue.identifier, change, input);
+    }
+
+    fn start_update(
+        &mut self,
+        issue_id: String,
+        identifier: String,
+        change: String,
+        input: IssueUpdateInput,
+    ) {
+        if !self.start_action_task() {
+            return;
+        }
+        let service = self.service.clone();
+        self.set_spinner_status(format!("Saving {change} for {identifier}"));
+        self.action_task = Some(tokio::spawn(async move {
+            let result = service
+                .update(&issue_id, input.clone())
+                .await
+                .map(Box::new)
+                .map_err(|err| err.to_string());
+            let retry = result.is_err().then(|| {
+                Box::new(Retry::Update {
+                    issue_id,
+                    identifier: identifier.clone(),
+                    change: change.clone(),
+                    input,
+                })
+            });
+            ActionOutcome::Updated {
+                identifier,
+                change,
+                result,
+                retry,
+            }
+        }));
+    }
+
+    pub(crate) async fn process_actions(&mut self) {
+        let Some(handle) = self.action_task.as_mut() else {
+            return;
+        };
+        if !handle.is_finished() {
+            return;
+        }
+        let handle = self.action_task.take().unwrap();
+        let outcome = match handle.await {
+            Ok(outcome) => outcome,
+            Err(err) => {
+                self.report_error("finish the action", err.to_string(), None);
+                return;
+            }
+        };
+        let status = match outcome {
+            ActionOutcome::States(Ok(states)) if states.is_empty() => {
+                "No other states in this team's workflow".to_owned()
+            }
+            ActionOutcome::States(Ok(states)) => {
+                self.quick_menu = Some(QuickMenu::States { states, index: 0 });
+                "Pick a state: j/k move, Enter saves, Esc closes".to_owned()
+            }
+            ActionOutcome::Labels(Ok(labels)) => {
+                let applied: Vec<String> = self
+                    .selected_detail()
+                    .and_then(|detail| detail.labels.as_ref())
+                    .map(|labels| labels.nodes.iter().map(|l| l.id.clone()).collect())
+                    .unwrap_or_default();
+                let labels: Vec<IssueLabel> = labels
+                    .into_iter()
+                    .filter(|label| !applied.contains(&label.id))
+                    .collect();
+                if labels.is_empty() {
+                    "No more labels to add".to_owned()
+                } else {
+                    self.quick_menu = Some(QuickMenu::Labels { labels, index: 0 });
+                    "Pick a label: j/k move, Enter adds, Esc closes".to_owned()
+                }
+            }
+            ActionOutcome::States(Err(err)) => return self.report_error("load states", err, None),
+            ActionOutcome::Labels(Err(err)) => return self.report_error("load labels", err, None),
+            ActionOutcome::Updated {
+                identifier,
+                change,
+                result: Ok(updated),
+                ..
+            } => {
+                self.apply_action_update(&updated);
+                format!("Saved {change} for {identifier}")
+            }
+            ActionOutcome::Updated {
+                identifier,
+                change,
+                result: Err(err),
+                retry,
+            } => {
+                let retry = retry.map(|retry| *retry);
+                return self.report_error(format!("save {change} for {identifier}"), err, retry);
+            }
+        };
+        self.set_status(status, false);
+    }
+
+    /// Copy the fields quick actions change into the loaded detail and issue list.
+    fn apply_action_update(&mut self, updated: &IssueDetail) {
+        if let Some(detail) = self.detail.as_mut().filter(|d| d.id == updated.id) {
+            detail.state = updated.state.clone();
+            detail.assignee = updated.assignee.clone();
+            detail.labels = updated.labels.clone();
+            detail.updated_at = updated.updated_at;
+        }
+        let cached = self
+            .page_cache
+            .get_mut(&self.page)
+            .map(|page| page.issues.iter_mut())
+            .into_iter()
+            .flatten();
+        for issue in self.issues.iter_mut().chain(cached) {
+            if issue.id == updated.id {
+                issue.state = updated.state.clone();
+                issue.assignee = updated.assignee.clone();
+                issue.updated_at = updated.updated_at;
+            }
+        }
+        self.regroup();
+    }
+
+    pub(crate) fn open_profiles_overlay(&mut self) {
+        self.show_help_overlay = false;
+        self.show_projects_overlay = false;
+        self.palette_active = false;
+        if let Err(err) = self.refresh_profile_options() {
+            self.report_error("list profiles", err.to_string(), None);
+            return;
+        }
+        if self.profile_options.is_empty() {
+            self.set_status(
+                "No stored profiles; run `linear auth login --profile <name>`",
+                false,
+            );
+            return;
+        }
+        self.profile_option_index = self
+            .profile_options
+            .iter()
+            .position(|name| *name == self.profile)
+            .unwrap_or(0);
+        self.show_profiles_overlay = true;
+        self.set_status(
+            "Pick a profile (j/k move, Enter switch, w or Esc close)",
+            false,
+        );
+    }
+
+    pub(crate) fn close_profiles_overlay(&mut self) {
+        if self.show_profiles_overlay {
+            self.show_profiles_overlay = false;
+            self.set_status("Profile picker closed", false);
+        }
+    }
+
+    pub(crate) fn move_profile_selection(&mut self, delta: isize) {
+        let len = self.profile_options.len();
+        if len == 0 {
+            return;
+        }
+        self.profile_option_index =
+            (self.profile_option_index as isize + delta).rem_euclid(len as isize) as usize;
+    }
+
+    pub(crate) async fn confirm_profile_selection(&mut self) {
+        let Some(name) = self.profile_options.get(self.profile_option_index).cloned() else {
+            return;
+        };
+        self.show_profiles_overlay = false;
+        self.switch_profile(&name).await;
+    }
+
+    fn refresh_profile_options(&mut self) -> Result<()> {
+        self.profile_options = crate::credential_store(Actor::User)?.profiles()?;
+        Ok(())
+    }
+
+    /// Replace the services with ones for `name` and reload everything from that workspace.
+    ///
+    /// The current workspace stays loaded when the profile has no usable credentials.
+    pub(crate) async fn switch_profile(&mut self, name: &str) {
+        if name == self.profile {
+            self.set_status(format!("Already using profile '{name}'"), false);
+            return;
+        }
+        self.set_spinner_status(format!("Switching to profile '{name}'…"));
+        let client = match crate::load_session(name)
+            .await
+            .and_then(|session| crate::build_client(&session))
+        {
+            Ok(client) => client,
+            Err(err) => {
+                self.report_error(format!("switch to '{name}'"), format!("{err:#}"), None);
+                return;
+            }
+        };
+
+        self.abort_tasks();
+        let mut next = App::new(
+            IssueService::new(client.clone()),
+            ProjectService::new(client.clone()),
+            CycleService::new(client),
+            name,
+        );
+        next.palette_history = std::mem::take(&mut self.palette_history);
+        next.profile_options = std::mem::take(&mut self.profile_options);
+        next.stale_after = self.stale_after;
+        next.board_limits = std::mem::take(&mut self.board_limits);
+        next.layout = self.layout;
+        next.narrow = self.narrow;
+        next.live = crate::daemon::subscribe(name).await;
+        *self = next;
+
+        self.restore_snapshot();
+        self.load_issues().await;
+        self.load_workspace();
+        let loaded = self.status_base.clone();
+        self.set_status(format!("Profile '{name}': {loaded}"), false);
+    }
+
+    pub(crate) async fn next_page(&mut self) {
+        if !self.has_next_page {
+            self.set_status("No more issues", false);
+            return;
+        }
+        if self
+            .page_cursors
+            .get(self.page)
+            .and_then(|c| c.clone())
+            .is_none()
+            && !self.page_cache.contains_key(&(self.page + 1))
+        {
+            self.set_status("Next page not yet available", false);
+            return;
+        }
+        self.selected = 0;
+        self.issues.clear();
+        self.detail = None;
+        self.page += 1;
+        self.load_issues_with_filters().await;
+    }
+
+    pub(crate) async fn previous_page(&mut self) {
+        if self.page == 0 {
+            self.set_status("Already at first page", false);
+            return;
+        }
+        self.selected = 0;
+        self.issues.clear();
+        self.detail = None;
+        self.page -= 1;
+        self.load_issues_with_filters().await;
+    }
+
+    pub(crate) async fn go_to_page(&mut self, page: usize) {
+        if page == self.page {
+            self.set_status(format!("Already on page {}", page + 1), false);
+            return;
+        }
+        if page > 0 && page > self.page_cursors.len() && !self.page_cache.contains_key(&page) {
+            self.set_status("Page not available; advance sequentially", false);
             return;
         }
         if page > 0
@@ -604,31 +3130,136 @@ impl App {
             return;
         }
 
-        match fetch_issue_summaries(
-            &self.service,
-            self.current_team_id(),
-            self.current_state_id(),
-            self.current_project_id(),
-            contains,
-            after,
-            PAGE_SIZE,
-        )
-        .await
+        let stale = self
+            .stale_first_page
+            .take()
+            .filter(|(key, _)| self.page == 0 && *key == self.issues_snapshot_key());
+        if let Some((_, stale)) = stale {
+            match self.revalidate_first_page(stale).await {
+                Ok(Some((page_data, changed))) => {
+                    self.save_snapshot(&self.issues_snapshot_key(), &page_data);
+                    self.page_cache.insert(0, page_data.clone());
+                    self.refreshing = false;
+                    self.apply_page_data(page_data, previous_key, false);
+                    if changed == 0 {
+                        self.set_status("Issues up to date", false);
+                    } else {
+                        self.set_status(format!("Merged {changed} updated issue(s)"), false);
+                    }
+                    return;
+                }
+                Ok(None) => {}
+                Err(err) if self.refreshing => {
+                    self.refreshing = false;
+                    self.set_status(
+                        format!("Showing cached issues; refresh failed: {err}"),
+                        false,
+                    );
+                    self.log_error("refresh issues", err.to_string(), Some(Retry::Issues));
+                    return;
+                }
+                Err(_) => {}
+            }
+        }
+
+        match self
+            .service
+            .list(self.issue_query_options(contains, after))
+            .await
+            .context("failed to fetch issues")
         {
             Ok(result) => {
                 let page_data = PageData::from(result);
+                if self.page == 0 {
+                    self.save_snapshot(&self.issues_snapshot_key(), &page_data);
+                }
                 self.page_cache.insert(self.page, page_data.clone());
+                self.refreshing = false;
                 self.apply_page_data(page_data, previous_key, false);
             }
+            Err(err) if self.refreshing => {
+                self.refreshing = false;
+                self.set_status(
+                    format!("Showing cached issues; refresh failed: {err}"),
+                    false,
+                );
+                self.log_error("refresh issues", err.to_string(), Some(Retry::Issues));
+            }
             Err(err) => {
                 self.issues.clear();
                 self.detail = None;
                 self.selected = 0;
-                self.set_status(format!("Error: {err}"), false);
+                self.report_error("load issues", err.to_string(), Some(Retry::Issues));
             }
         }
     }
 
+    /// Fold issues updated since `stale`'s watermark into it. `None` means the merge cannot keep
+    /// the page's cursor valid (issues joined or left a page with more after it, or it would
+    /// overflow), so the page must be fetched in full.
+    async fn revalidate_first_page(&self, stale: PageData) -> Result<Option<(PageData, usize)>> {
+        if stale.issues.is_empty() {
+            return Ok(None);
+        }
+        let mut set = IssueSet::new(stale.issues.clone());
+        let changes = self
+            .service
+            .changes_since(
+                self.issue_query_options(self.current_contains(), None),
+                &set,
+            )
+            .await
+            .context("failed to fetch updated issues")?;
+        if changes.is_empty() {
+            return Ok(Some((stale, 0)));
+        }
+        // The next page starts after the last cached issue; it must stay put. Under any order
+        // but newest update first, an updated issue may also belong on another page.
+        let anchor = stale.issues.last().map(|issue| issue.id.as_str());
+        let anchor_moved = changes
+            .updated
+            .iter()
+            .any(|issue| Some(issue.id.as_str()) == anchor);
+        let reorders = self.issue_sort != IssueSort::UpdatedDesc;
+        if stale.has_next_page && (!set.keeps_membership(&changes) || anchor_moved || reorders) {
+            return Ok(None);
+        }
+        let changed = changes.len();
+        set.merge(changes);
+        let sort = self.issue_sort;
+        set.issues.sort_by(|a, b| sort.compare(a, b));
+        if set.issues.len() > PAGE_SIZE {
+            return Ok(None);
+        }
+        Ok(Some((
+            PageData {
+                issues: set.issues,
+                end_cursor: stale.end_cursor,
+                has_next_page: stale.has_next_page,
+            },
+            changed,
+        )))
+    }
+
+    /// A page of the issue list under the current filters.
+    fn issue_query_options(
+        &self,
+        contains: Option<String>,
+        after: Option<String>,
+    ) -> IssueQueryOptions {
+        IssueQueryOptions {
+            limit: PAGE_SIZE,
+            team_id: self.current_team_id(),
+            state_id: self.current_state_id(),
+            project_id: self.current_project_id(),
+            title_contains: contains,
+            conditions: self.assignee_filter.as_ref().map(AssigneeFilter::query),
+            after,
+            sort: Some(self.issue_sort),
+            ..Default::default()
+        }
+    }
+
     async fn load_issues_with_contains(&mut self, contains: Option<String>) {
         self.title_contains = contains;
         self.reset_pagination();
@@ -681,6 +3312,7 @@ impl App {
 
         self.issues = data.issues;
         self.selected = selected_index;
+        self.regroup();
 
         if detail_matches {
             self.set_status(
@@ -724,10 +3356,22 @@ impl App {
         self.selected = index;
         if let Some(issue) = self.issues.get(self.selected) {
             let key = issue.identifier.clone();
+            let title = issue.title.clone();
             self.detail = None;
             self.abort_pending();
             self.detail_tab = self.remembered_detail_tab(&key);
-            self.set_spinner_status(format!("Loading {}...", key));
+            let message = if crate::accessible::enabled() {
+                // Say which issue is now selected, since the highlight is not read aloud.
+                format!(
+                    "{key} ({} of {}): {}. Loading...",
+                    index + 1,
+                    self.issues.len(),
+                    title
+                )
+            } else {
+                format!("Loading {}...", key)
+            };
+            self.set_spinner_status(message);
             self.queue_detail_fetch(key);
         }
     }
@@ -770,6 +3414,70 @@ impl App {
         }
     }
 
+    pub(crate) fn key_sequence_mut(&mut self) -> &mut KeySequence {
+        &mut self.keys
+    }
+
+    /// Keys of an unfinished count, `g`, or mark sequence.
+    pub(crate) fn pending_keys(&self) -> String {
+        self.keys.text()
+    }
+
+    /// A lone `1`-`4` that timed out waiting for a motion switches the status tab after all.
+    pub(crate) async fn process_key_sequence(&mut self) {
+        if let Some(tab) = self
+            .keys
+            .expire(Instant::now())
+            .and_then(StatusTab::from_digit)
+        {
+            self.set_status_tab(tab).await;
+        }
+    }
+
+    pub(crate) async fn apply_motion(&mut self, motion: Motion) {
+        let len = self.issues.len();
+        match motion {
+            Motion::Down(count) => self.move_issue_selection(count as isize).await,
+            Motion::Up(count) => self.move_issue_selection(-(count as isize)).await,
+            Motion::First => self.jump_to_index(0),
+            Motion::Last => self.jump_to_index(len.saturating_sub(1)),
+            Motion::Line(line) => self.jump_to_index(line.clamp(1, len.max(1)) - 1),
+            Motion::SetMark(mark) => {
+                let Some(key) = self.selected_issue().map(|issue| issue.identifier.clone()) else {
+                    self.set_status(tr("tui-select-issue-first"), false);
+                    return;
+                };
+                self.set_status(format!("Mark '{mark}' set on {key}"), false);
+                self.marks.insert(mark, key);
+            }
+            Motion::JumpMark(mark) => {
+                let target = match mark {
+                    '\'' | '`' => self.jump_origin.clone(),
+                    _ => self.marks.get(&mark).cloned(),
+                };
+                let Some(key) = target else {
+                    self.set_status(format!("Mark '{mark}' not set"), false);
+                    return;
+                };
+                match self.issues.iter().position(|issue| issue.identifier == key) {
+                    Some(index) => self.jump_to_index(index),
+                    None => {
+                        self.set_status(format!("Mark '{mark}' ({key}) is not on this page"), false)
+                    }
+                }
+            }
+        }
+    }
+
+    /// Select `index`, remembering the issue we left for `''`.
+    fn jump_to_index(&mut self, index: usize) {
+        if index >= self.issues.len() || index == self.selected {
+            return;
+        }
+        self.jump_origin = self.selected_issue().map(|issue| issue.identifier.clone());
+        self.select_issue(index);
+    }
+
     fn jump_relative(&mut self, delta: isize) -> bool {
         if self.issues.is_empty() {
             return false;
@@ -813,6 +3521,7 @@ impl App {
         self.states_team_id = None;
         self.states.clear();
         self.title_contains = None;
+        self.assignee_filter = None;
         self.project_filter_index = None;
         self.project_filter_options.clear();
         self.status_tab = StatusTab::All;
@@ -864,11 +3573,21 @@ impl App {
     }
 
     async fn ensure_teams(&mut self) {
-        if self.teams.is_empty() {
+        if self.teams.is_empty() || self.refreshing {
             match self.service.teams().await {
-                Ok(teams) => self.teams = teams,
+                Ok(teams) => {
+                    self.save_snapshot(TEAMS_SNAPSHOT, &teams);
+                    self.teams = teams;
+                }
                 Err(err) => {
-                    self.set_status(format!("Failed to load teams: {err}"), false);
+                    let cached = self
+                        .snapshots
+                        .as_ref()
+                        .and_then(|store| store.load::<Vec<TeamSummary>>(TEAMS_SNAPSHOT));
+                    if let (true, Some(cached)) = (self.teams.is_empty(), cached) {
+                        self.teams = cached.value;
+                    }
+                    self.report_error("load teams", err.to_string(), None);
                 }
             }
         }
@@ -935,7 +3654,20 @@ impl App {
     async fn ensure_states(&mut self) {
         if let Some(team_id) = self.current_team_id() {
             if self.states_team_id.as_deref() != Some(&team_id) {
-                match self.service.workflow_states(&team_id).await {
+                let key = states_snapshot_key(&team_id);
+                let result = match self.service.workflow_states(&team_id).await {
+                    Ok(states) => {
+                        self.save_snapshot(&key, &states);
+                        Ok(states)
+                    }
+                    Err(err) => self
+                        .snapshots
+                        .as_ref()
+                        .and_then(|store| store.load::<Vec<WorkflowStateSummary>>(&key))
+                        .map(|cached| cached.value)
+                        .ok_or(err),
+                };
+                match result {
                     Ok(states) => {
                         self.states = states;
                         self.states_team_id = Some(team_id);
@@ -943,7 +3675,7 @@ impl App {
                         self.apply_current_status_tab();
                     }
                     Err(err) => {
-                        self.set_status(format!("Failed to load states: {err}"), false);
+                        self.report_error("load states", err.to_string(), None);
                     }
                 }
             }
@@ -968,32 +3700,31 @@ impl App {
             return;
         }
 
-        let request = ProjectQueryOptions {
-            limit: 50,
-            after: None,
-            state: None,
-            status: None,
-            team_id: cache_key.clone(),
-            sort: Some(ProjectSort::UpdatedDesc),
-        };
-
-        match self.project_service.list(request).await {
-            Ok(response) => {
-                let mut projects = response.nodes;
-                projects.sort_by(|a, b| {
-                    a.name
-                        .to_ascii_lowercase()
-                        .cmp(&b.name.to_ascii_lowercase())
-                });
+        let key = projects_snapshot_key(cache_key.as_deref());
+        match project_filter_list(&self.project_service, cache_key.clone()).await {
+            Ok(projects) => {
+                self.save_snapshot(&key, &projects);
                 self.project_filter_cache
                     .insert(cache_key, projects.clone());
                 self.project_filter_options = projects;
                 self.normalize_project_index();
             }
             Err(err) => {
-                self.project_filter_options.clear();
-                self.project_filter_index = None;
-                self.set_status(format!("Failed to load projects: {err}"), false);
+                let cached = self
+                    .snapshots
+                    .as_ref()
+                    .and_then(|store| store.load::<Vec<ProjectSummary>>(&key));
+                match cached {
+                    Some(cached) => {
+                        self.project_filter_options = cached.value;
+                        self.normalize_project_index();
+                    }
+                    None => {
+                        self.project_filter_options.clear();
+                        self.project_filter_index = None;
+                        self.report_error("load projects", err.to_string(), None);
+                    }
+                }
             }
         }
     }
@@ -1078,6 +3809,73 @@ impl App {
         }
     }
 
+    pub(crate) fn assignee_filter(&self) -> Option<&AssigneeFilter> {
+        self.assignee_filter.as_ref()
+    }
+
+    /// `M`: show only issues assigned to the viewer, or everyone's again.
+    pub(crate) async fn toggle_mine(&mut self) {
+        let filter = match self.assignee_filter {
+            Some(AssigneeFilter::Me) => None,
+            _ => Some(AssigneeFilter::Me),
+        };
+        self.set_assignee_filter(filter).await;
+    }
+
+    /// `:assignee <name|me|none|clear>`; names are matched against the selected team's members.
+    pub(crate) async fn set_assignee_filter_by_name(&mut self, name: &str) {
+        let filter = match name.to_ascii_lowercase().as_str() {
+            "clear" | "all" | "off" => None,
+            "me" | "mine" => Some(AssigneeFilter::Me),
+            "none" | "unassigned" => Some(AssigneeFilter::Unassigned),
+            _ => {
+                let Some(team_id) = self.current_team_id() else {
+                    self.set_status("Select a team to filter by one of its members", false);
+                    return;
+                };
+                let members = self.team_members(&team_id).await;
+                match match_name("Member", name, &members, |member| {
+                    let mut keys = vec![member.display_name.as_str(), member.name.as_str()];
+                    keys.extend(member.email.as_deref());
+                    keys
+                }) {
+                    Ok(Some(index)) => Some(AssigneeFilter::Member {
+                        id: members[index].id.clone(),
+                        name: members[index].display_name.clone(),
+                    }),
+                    Ok(None) => {
+                        self.set_status(format!("No member '{name}' in this team"), false);
+                        return;
+                    }
+                    Err(message) => {
+                        self.set_status(message, false);
+                        return;
+                    }
+                }
+            }
+        };
+        self.set_assignee_filter(filter).await;
+    }
+
+    /// Reload the list in `sort` order from the first page.
+    async fn set_issue_sort(&mut self, sort: IssueSort) {
+        self.issue_sort = sort;
+        self.set_spinner_status(format!("Sorted by {sort}"));
+        self.reset_pagination();
+        self.load_issues_with_filters().await;
+    }
+
+    async fn set_assignee_filter(&mut self, filter: Option<AssigneeFilter>) {
+        let message = match &filter {
+            Some(filter) => format!("Assignee filter: {}", filter.label()),
+            None => "Assignee filter cleared".into(),
+        };
+        self.assignee_filter = filter;
+        self.set_spinner_status(message);
+        self.reset_pagination();
+        self.load_issues_with_filters().await;
+    }
+
     pub(crate) async fn move_state_selection(&mut self, delta: isize) {
         self.ensure_states().await;
         if self.states.is_empty() {
@@ -1175,18 +3973,18 @@ impl App {
             return;
         }
         self.ensure_project_filters().await;
-        if let Some(idx) = self
-            .project_filter_options
-            .iter()
-            .position(|project| project.name.eq_ignore_ascii_case(name))
-        {
-            self.project_filter_index = Some(idx);
-            let label = self.current_project_label();
-            self.set_spinner_status(format!("Project filter: {}", label));
-            self.reset_pagination();
-            self.load_issues_with_filters().await;
-        } else {
-            self.set_status(format!("Project '{}' not found", name), false);
+        match match_name("Project", name, &self.project_filter_options, |project| {
+            vec![project.name.as_str()]
+        }) {
+            Ok(Some(idx)) => {
+                self.project_filter_index = Some(idx);
+                let label = self.current_project_label();
+                self.set_spinner_status(format!("Project filter: {}", label));
+                self.reset_pagination();
+                self.load_issues_with_filters().await;
+            }
+            Ok(None) => self.set_status(format!("Project '{}' not found", name), false),
+            Err(message) => self.set_status(message, false),
         }
     }
 
@@ -1211,7 +4009,6 @@ impl App {
                 Ok(path) => path,
                 Err(err) => {
                     return AutomationOutcome {
-                        success: false,
                         message: format!("CLI error resolving binary: {err}"),
                     };
                 }
@@ -1224,7 +4021,8 @@ impl App {
                 .arg(&issue_key)
                 .stdin(Stdio::null())
                 .stdout(Stdio::piped())
-                .stderr(Stdio::piped());
+                .stderr(Stdio::piped())
+                .kill_on_drop(true);
 
             if !profile.is_empty() {
                 command.arg("--profile").arg(&profile);
@@ -1243,10 +4041,9 @@ impl App {
                         }
                         if first_line.len() > 80 {
                             first_line.truncate(77);
-                            first_line.push_str("…");
+                            first_line.push('…');
                         }
                         AutomationOutcome {
-                            success: true,
                             message: format!(
                                 "CLI issue view {} succeeded: {}",
                                 issue_key, first_line
@@ -1264,13 +4061,11 @@ impl App {
                             first_line = "unknown error".into();
                         }
                         AutomationOutcome {
-                            success: false,
                             message: format!("CLI issue view {} failed: {}", issue_key, first_line),
                         }
                     }
                 }
                 Err(err) => AutomationOutcome {
-                    success: false,
                     message: format!("CLI execution error: {err}"),
                 },
             }
@@ -1350,6 +4145,67 @@ impl App {
                 }
             }
             lines
+        } else if let Some(rest) = input.strip_prefix("profile ") {
+            let term = rest.trim();
+            let mut lines: Vec<Line> = self
+                .profile_options
+                .iter()
+                .filter(|name| name.to_ascii_lowercase().starts_with(term))
+                .take(5)
+                .map(|name| Line::from(format!("profile {name}")))
+                .collect();
+            if lines.is_empty() {
+                lines.push(Line::from("profile <name>"));
+            }
+            lines
+        } else if let Some(rest) = input.strip_prefix("lanes label ") {
+            let term = rest.trim().to_ascii_lowercase();
+            let mut names: Vec<&str> = self
+                .issues
+                .iter()
+                .filter_map(|issue| issue.labels.as_ref())
+                .flat_map(|labels| labels.nodes.iter().map(|label| label.name.as_str()))
+                .filter(|name| name.to_ascii_lowercase().starts_with(&term))
+                .collect();
+            names.sort_unstable();
+            names.dedup();
+            names
+                .into_iter()
+                .take(5)
+                .map(|name| Line::from(format!("lanes label {name}")))
+                .collect()
+        } else if let Some(rest) = input.strip_prefix("group ") {
+            let term = rest.trim();
+            GroupBy::ALL
+                .iter()
+                .map(|by| by.as_str())
+                .chain(["off"])
+                .filter(|name| name.starts_with(term))
+                .map(|name| Line::from(format!("group {name}")))
+                .collect()
+        } else if let Some(rest) = input.strip_prefix("action ") {
+            let term = rest.trim();
+            self.actions
+                .actions()
+                .iter()
+                .filter(|spec| spec.name.starts_with(term))
+                .map(|spec| Line::from(format!("action {:<12} {}", spec.name, spec.label)))
+                .collect()
+        } else if let Some(rest) = input.strip_prefix("export ") {
+            let term = rest.trim();
+            ["md", "html"]
+                .into_iter()
+                .filter(|format| format.starts_with(term))
+                .map(|format| Line::from(format!("export {format} linear-issues.{format}")))
+                .collect()
+        } else if let Some(rest) = input.strip_prefix("layout ") {
+            let term = rest.trim();
+            LayoutPreset::ALL
+                .iter()
+                .map(|preset| preset.as_str())
+                .filter(|name| name.starts_with(term))
+                .map(|name| Line::from(format!("layout {name}")))
+                .collect()
         } else if let Some(rest) = input.strip_prefix("status ") {
             let term = rest.trim();
             let mut lines = Vec::new();
@@ -1473,8 +4329,26 @@ impl App {
                 Line::from("page <number>"),
                 Line::from("clear"),
                 Line::from("reload"),
+                Line::from("api"),
+                Line::from("api refresh"),
+                Line::from("projects"),
+                Line::from("cycles"),
+                Line::from("board"),
+                Line::from("lanes label <name>"),
+                Line::from("lanes off"),
+                Line::from("export <md|html> [path]"),
+                Line::from("edit (description in $EDITOR)"),
+                Line::from("errors"),
+                Line::from("layout <list|stacked|side-by-side|zen>"),
                 Line::from("help"),
             ];
+            lines.push(Line::from("action (menu of the actions below)"));
+            lines.extend(
+                self.actions
+                    .actions()
+                    .iter()
+                    .map(|spec| Line::from(format!("action {}", spec.name))),
+            );
             if self.has_activity_data() {
                 lines.push(Line::from("activity"));
             }
@@ -1505,15 +4379,26 @@ impl App {
     }
 
     pub(crate) fn toggle_focus(&mut self) {
-        self.focus = match self.focus {
+        let next = |focus| match focus {
             Focus::Issues => Focus::Teams,
             Focus::Teams => Focus::States,
-            Focus::States => Focus::Issues,
+            Focus::States => Focus::Detail,
+            Focus::Detail => Focus::Issues,
         };
+        let mut focus = next(self.focus);
+        // Skip panes the layout hides; at most three steps reach a visible one.
+        for _ in 0..3 {
+            if self.pane_visible(focus) {
+                break;
+            }
+            focus = next(focus);
+        }
+        self.focus = focus;
         let message = match self.focus {
             Focus::Issues => "Focus: issues",
             Focus::Teams => "Focus: teams",
             Focus::States => "Focus: states",
+            Focus::Detail => "Focus: details (j/k pick a field, Enter edits)",
         };
         self.set_status(message, false);
     }
@@ -1523,6 +4408,8 @@ impl App {
         self.show_help_overlay = false;
         self.palette_input.clear();
         self.palette_history_index = None;
+        // Only feeds `profile <name>` suggestions, so a listing failure is not worth reporting.
+        let _ = self.refresh_profile_options();
         self.set_status("Command mode (: to exit, ↑/↓ history)", false);
     }
 
@@ -1540,15 +4427,14 @@ impl App {
     pub(crate) async fn execute_command(&mut self, command: String) {
         let cmd = command.trim();
         self.palette_history_index = None;
-        if !cmd.is_empty() {
-            if self
+        if !cmd.is_empty()
+            && self
                 .palette_history
                 .last()
                 .map(|last| last != cmd)
                 .unwrap_or(true)
-            {
-                self.palette_history.push(cmd.to_string());
-            }
+        {
+            self.palette_history.push(cmd.to_string());
         }
         if cmd.eq_ignore_ascii_case("activity") {
             if self.has_activity_data() {
@@ -1566,22 +4452,171 @@ impl App {
             }
             return;
         }
+        if cmd.eq_ignore_ascii_case("api") {
+            self.open_explorer(false);
+            return;
+        }
+        if cmd.eq_ignore_ascii_case("api refresh") {
+            self.open_explorer(true);
+            return;
+        }
+        if cmd.eq_ignore_ascii_case("projects") {
+            self.toggle_projects_tab();
+            return;
+        }
+        if cmd.eq_ignore_ascii_case("cycles") {
+            self.toggle_cycles_tab();
+            return;
+        }
+        if cmd.eq_ignore_ascii_case("board") {
+            self.toggle_board_tab();
+            return;
+        }
+        if cmd.eq_ignore_ascii_case("lanes") {
+            self.run_lanes_command("");
+            return;
+        }
+        if cmd.eq_ignore_ascii_case("edit") {
+            self.edit_description_externally().await;
+            return;
+        }
+        if cmd.eq_ignore_ascii_case("export") {
+            self.export_view("");
+            return;
+        }
+        if let Some(args) = cmd.strip_prefix("export ") {
+            self.export_view(args);
+            return;
+        }
+        if let Some(args) = cmd.strip_prefix("lanes ") {
+            self.run_lanes_command(args);
+            return;
+        }
+        if cmd.eq_ignore_ascii_case("errors") {
+            self.toggle_error_console();
+            return;
+        }
+        if cmd.eq_ignore_ascii_case("action") {
+            self.open_quick_menu();
+            return;
+        }
+        if let Some(name) = cmd.strip_prefix("action ") {
+            match self.actions.find(name).map(|spec| spec.action) {
+                Some(action) => self.run_action(action),
+                None => {
+                    let names: Vec<&str> = self
+                        .actions
+                        .actions()
+                        .iter()
+                        .map(|spec| spec.name)
+                        .collect();
+                    self.set_status(
+                        format!(
+                            "Unknown action '{}' (try {})",
+                            name.trim(),
+                            names.join(", ")
+                        ),
+                        false,
+                    );
+                }
+            }
+            return;
+        }
+        if cmd.eq_ignore_ascii_case("layout") {
+            self.set_status(self.layout_summary(), false);
+            return;
+        }
+        if let Some(name) = cmd.strip_prefix("layout ") {
+            match name.parse::<LayoutPreset>() {
+                Ok(preset) => self.set_layout_preset(preset),
+                Err(err) => self.set_status(err, false),
+            }
+            return;
+        }
+        if cmd.eq_ignore_ascii_case("group") {
+            let message = match self.group_by {
+                Some(by) => format!("Grouped by {by}; `group off` restores the list"),
+                None => "Usage: group <state|assignee|project|priority|off>".into(),
+            };
+            self.set_status(message, false);
+            return;
+        }
+        if let Some(field) = cmd.strip_prefix("group ") {
+            match field.trim().to_ascii_lowercase().as_str() {
+                "off" | "none" | "clear" => self.set_group_by(None),
+                field => match field.parse::<GroupBy>() {
+                    Ok(by) => self.set_group_by(Some(by)),
+                    Err(err) => self.set_status(err, false),
+                },
+            }
+            return;
+        }
+        if cmd.eq_ignore_ascii_case("profile") {
+            self.open_profiles_overlay();
+            return;
+        }
+        if cmd.eq_ignore_ascii_case("assignee") {
+            let message = match &self.assignee_filter {
+                Some(filter) => format!("Assignee filter: {}", filter.label()),
+                None => "Usage: assignee <name|me|none|clear>".into(),
+            };
+            self.set_status(message, false);
+            return;
+        }
+        if let Some(name) = cmd.strip_prefix("assignee ") {
+            self.set_assignee_filter_by_name(name.trim()).await;
+            return;
+        }
+        if cmd.eq_ignore_ascii_case("sort") {
+            self.set_status(
+                format!(
+                    "Sorted by {}; usage: sort <updated|created|priority>[:asc|:desc]",
+                    self.issue_sort
+                ),
+                false,
+            );
+            return;
+        }
+        if let Some(sort) = cmd.strip_prefix("sort ") {
+            match linear_commands::sort::issue(sort.trim()) {
+                Ok(sort) => self.set_issue_sort(sort).await,
+                Err(err) => self.set_status(err.to_string(), false),
+            }
+            return;
+        }
+        if let Some(name) = cmd.strip_prefix("profile ") {
+            let name = name.trim();
+            if name.is_empty() {
+                self.open_profiles_overlay();
+            } else {
+                self.switch_profile(name).await;
+            }
+            return;
+        }
         if let Some(team_key) = cmd.strip_prefix("team ") {
             let team_key = team_key.trim();
             self.ensure_teams().await;
-            self.team_index = self
-                .teams
-                .iter()
-                .position(|team| team.key.eq_ignore_ascii_case(team_key));
-            if self.team_index.is_none() {
-                self.set_status(format!("Team '{}' not found", team_key), false);
-            } else {
-                self.states.clear();
-                self.state_index = None;
-                self.states_team_id = None;
-                self.set_status(format!("Command: team {}", team_key), false);
-                self.reset_pagination();
-                self.load_issues_with_filters().await;
+            match match_name("Team", team_key, &self.teams, |team| {
+                vec![team.key.as_str(), team.name.as_str()]
+            }) {
+                Ok(Some(index)) => {
+                    self.team_index = Some(index);
+                    self.states.clear();
+                    self.state_index = None;
+                    self.states_team_id = None;
+                    let key = self.teams[index].key.clone();
+                    self.set_status(format!("Command: team {}", key), false);
+                    self.reset_pagination();
+                    self.load_issues_with_filters().await;
+                }
+                Ok(None) => {
+                    self.team_index = None;
+                    self.set_status(format!("Team '{}' not found", team_key), false);
+                }
+                Err(message) => {
+                    self.team_index = None;
+                    self.set_status(message, false);
+                }
             }
             return;
         }
@@ -1592,16 +4627,24 @@ impl App {
             if self.states.is_empty() {
                 self.set_status("Load a team with workflow states first", false);
             } else {
-                self.state_index = self
-                    .states
-                    .iter()
-                    .position(|state| state.name.eq_ignore_ascii_case(state_name));
-                if self.state_index.is_none() {
-                    self.set_status(format!("State '{}' not found", state_name), false);
-                } else {
-                    self.set_status(format!("Command: state {}", state_name), false);
-                    self.reset_pagination();
-                    self.load_issues_with_filters().await;
+                match match_name("State", state_name, &self.states, |state| {
+                    vec![state.name.as_str()]
+                }) {
+                    Ok(Some(index)) => {
+                        self.state_index = Some(index);
+                        let name = self.states[index].name.clone();
+                        self.set_status(format!("Command: state {}", name), false);
+                        self.reset_pagination();
+                        self.load_issues_with_filters().await;
+                    }
+                    Ok(None) => {
+                        self.state_index = None;
+                        self.set_status(format!("State '{}' not found", state_name), false);
+                    }
+                    Err(message) => {
+                        self.state_index = None;
+                        self.set_status(message, false);
+                    }
                 }
             }
             return;
@@ -1869,18 +4912,111 @@ async fn fetch_issue_detail(
     Ok(service.get_by_key(&key).await.ok())
 }
 
-#[derive(Clone)]
+/// Whose issues the list shows, set with `M` or `:assignee`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum AssigneeFilter {
+    Me,
+    Unassigned,
+    Member { id: String, name: String },
+}
+
+impl AssigneeFilter {
+    pub(crate) fn label(&self) -> &str {
+        match self {
+            Self::Me => "me",
+            Self::Unassigned => "none",
+            Self::Member { name, .. } => name,
+        }
+    }
+
+    /// Part of the snapshot key, stable across renames.
+    fn key(&self) -> String {
+        match self {
+            Self::Me => "me".into(),
+            Self::Unassigned => "none".into(),
+            Self::Member { id, .. } => id.clone(),
+        }
+    }
+
+    fn query(&self) -> IssueQuery {
+        match self {
+            Self::Me => IssueQuery::new().assignee_me(),
+            Self::Unassigned => IssueQuery::new().unassigned(),
+            Self::Member { id, .. } => IssueQuery::new().assignee(id.clone()),
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
 struct PageData {
     issues: Vec<linear_core::graphql::IssueSummary>,
     end_cursor: Option<String>,
     has_next_page: bool,
 }
 
+enum ActionOutcome {
+    States(Result<Vec<WorkflowStateSummary>, String>),
+    Labels(Result<Vec<IssueLabel>, String>),
+    Updated {
+        identifier: String,
+        change: String,
+        result: Result<Box<IssueDetail>, String>,
+        retry: Option<Box<Retry>>,
+    },
+}
+
 struct AutomationOutcome {
-    success: bool,
     message: String,
 }
 
+enum CycleOutcome {
+    List {
+        result: Result<Vec<CycleSummary>, String>,
+        notice: Option<String>,
+    },
+    Issues {
+        cycle_id: String,
+        result: Result<Vec<IssueSummary>, String>,
+    },
+    Moved {
+        cycle: String,
+        moved: Vec<String>,
+        total: usize,
+        failure: Option<String>,
+    },
+}
+
+enum ProjectOutcome {
+    List(Result<Vec<ProjectSummary>, String>),
+    Detail(Result<(ProjectDetail, Vec<IssueSummary>), String>),
+    Members(Result<Vec<TeamMember>, String>),
+    Saved {
+        change: String,
+        result: Result<ProjectDetail, String>,
+    },
+}
+
+enum ExplorerOutcome {
+    Schema(Result<Schema, String>),
+    Response(Result<Value, String>),
+}
+
+struct ComposeOutcome {
+    identifier: String,
+    target: ComposeTarget,
+    text: String,
+    issue_id: String,
+    result: Result<(), String>,
+}
+
+struct FieldSaveOutcome {
+    issue_id: String,
+    identifier: String,
+    field: EditField,
+    input: IssueUpdateInput,
+    result: Result<IssueDetail, String>,
+}
+
 impl From<IssueListResult> for PageData {
     fn from(result: IssueListResult) -> Self {
         Self {
@@ -1890,3 +5026,115 @@ impl From<IssueListResult> for PageData {
         }
     }
 }
+
+/// The index of the item `input` names, matched like `--team`/`--state` on the command line;
+/// `None` when nothing matches. The palette has no room for a picker, so a close call is an
+/// error listing the candidates.
+fn match_name<T, F>(
+    what: &str,
+    input: &str,
+    items: &[T],
+    keys: F,
+) -> std::result::Result<Option<usize>, String>
+where
+    F: Fn(&T) -> Vec<&str>,
+{
+    match fuzzy::resolve(input, items, &keys) {
+        Resolution::Unique(item) => Ok(items
+            .iter()
+            .position(|candidate| std::ptr::eq(candidate, item))),
+        Resolution::Missing => Ok(None),
+        Resolution::Ambiguous(candidates) => {
+            let names: Vec<&str> = candidates
+                .iter()
+                .filter_map(|item| keys(item).first().copied())
+                .collect();
+            Err(format!(
+                "{what} '{input}' could be {}; type more of the name",
+                names.join(", ")
+            ))
+        }
+    }
+}
+
+/// Whole story points; empty, `-` or `none` clears the estimate.
+fn parse_estimate(input: &str) -> std::result::Result<Option<i32>, String> {
+    let input = input.trim();
+    if matches!(input, "" | "-" | "none") {
+        return Ok(None);
+    }
+    match input.parse::<i32>() {
+        Ok(points) if points >= 0 => Ok(Some(points)),
+        _ => Err(format!("'{input}' is not a whole number of points")),
+    }
+}
+
+/// `YYYY-MM-DD`, `today`, `tomorrow` or `+N` days from `today`; empty, `-` or `none` clears it.
+pub(crate) fn parse_due_date(
+    input: &str,
+    today: NaiveDate,
+) -> std::result::Result<Option<NaiveDate>, String> {
+    let input = input.trim().to_ascii_lowercase();
+    let date = match input.as_str() {
+        "" | "-" | "none" => return Ok(None),
+        "today" => Some(today),
+        "tomorrow" => Some(today + Duration::days(1)),
+        _ => match input.strip_prefix('+') {
+            Some(days) => days
+                .trim_end_matches('d')
+                .parse::<i64>()
+                .ok()
+                .map(|days| today + Duration::days(days)),
+            None => NaiveDate::parse_from_str(&input, "%Y-%m-%d").ok(),
+        },
+    };
+    date.map(Some)
+        .ok_or_else(|| format!("'{input}' is not a date (use YYYY-MM-DD)"))
+}
+
+/// Snapshot key of `team_id`'s workflow states; `linear cache warm` writes it ahead of time.
+pub(crate) fn states_snapshot_key(team_id: &str) -> String {
+    format!("states:{team_id}")
+}
+
+/// Snapshot key of `team_id`'s members.
+pub(crate) fn members_snapshot_key(team_id: &str) -> String {
+    format!("members:{team_id}")
+}
+
+/// Snapshot key of the project filter's choices for `team_id`, or for every team.
+pub(crate) fn projects_snapshot_key(team_id: Option<&str>) -> String {
+    format!("projects:{}", team_id.unwrap_or("-"))
+}
+
+/// The projects the project filter offers for `team_id` (every team's when `None`), by name.
+pub(crate) async fn project_filter_list(
+    service: &ProjectService,
+    team_id: Option<String>,
+) -> GraphqlResult<Vec<ProjectSummary>> {
+    let request = ProjectQueryOptions {
+        limit: 50,
+        after: None,
+        state: None,
+        status: None,
+        team_id,
+        sort: Some(ProjectSort::UpdatedDesc),
+    };
+    let mut projects = service.list(request).await?.nodes;
+    projects.sort_by(|a, b| {
+        a.name
+            .to_ascii_lowercase()
+            .cmp(&b.name.to_ascii_lowercase())
+    });
+    Ok(projects)
+}
+
+fn cache_age(saved_at: DateTime<Utc>) -> String {
+    let minutes = (Utc::now() - saved_at).num_minutes().max(0);
+    match minutes {
+        0 => "just now".into(),
+        1..=59 => format!("{minutes}m ago"),
+        60..=1439 => format!("{}h ago", minutes / 60),
+        _ => format!("{}d ago", minutes / 1440),
+    }
+}
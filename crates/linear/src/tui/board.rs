@@ -0,0 +1,57 @@
+use linear_core::board::Board;
+
+/// State of the board tab: the label swimlanes and the card under the cursor.
+///
+/// The board itself is rebuilt from the loaded issue page on every draw, so the cursor is
+/// clamped against the current layout whenever it is read.
+#[derive(Default)]
+pub struct BoardTab {
+    lanes: Vec<String>,
+    column: usize,
+    row: usize,
+}
+
+impl BoardTab {
+    pub fn lanes(&self) -> &[String] {
+        &self.lanes
+    }
+
+    /// Add a lane for `label`, or remove it if it is already shown; returns whether it was added.
+    pub fn toggle_lane(&mut self, label: String) -> bool {
+        match self
+            .lanes
+            .iter()
+            .position(|lane| lane.eq_ignore_ascii_case(&label))
+        {
+            Some(index) => {
+                self.lanes.remove(index);
+                false
+            }
+            None => {
+                self.lanes.push(label);
+                true
+            }
+        }
+    }
+
+    pub fn clear_lanes(&mut self) {
+        self.lanes.clear();
+    }
+
+    /// Column and card index of the cursor within `board`.
+    pub fn cursor(&self, board: &Board<'_>) -> (usize, usize) {
+        let column = self.column.min(board.columns.len().saturating_sub(1));
+        let row = self
+            .row
+            .min(board.column_cards(column).len().saturating_sub(1));
+        (column, row)
+    }
+
+    pub fn move_cursor(&mut self, board: &Board<'_>, columns: isize, rows: isize) {
+        let (column, row) = self.cursor(board);
+        let last_column = board.columns.len().saturating_sub(1) as isize;
+        self.column = (column as isize + columns).clamp(0, last_column) as usize;
+        let last_row = board.column_cards(self.column).len().saturating_sub(1) as isize;
+        self.row = (row as isize + rows).clamp(0, last_row) as usize;
+    }
+}
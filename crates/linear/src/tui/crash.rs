@@ -0,0 +1,257 @@
+//! Keeping the terminal usable when the TUI panics.
+//!
+//! [`TerminalGuard`] owns raw mode, the alternate screen, and mouse capture, and hands them back
+//! when dropped, so an early return or unwinding panic cannot leave the shell unusable. The
+//! panic hook it installs does the same before anything is printed, then writes the panic, a
+//! backtrace, and the last [`TRAIL_LEN`] tracing events to a crash log. Panics in spawned
+//! tasks only join the trail: the TUI reports their failed `JoinHandle` and keeps running.
+
+use std::collections::VecDeque;
+use std::fmt::{self, Write as _};
+use std::fs;
+use std::io;
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, Once, PoisonError};
+use std::thread;
+
+use chrono::{SecondsFormat, Utc};
+use crossterm::cursor::Show;
+use crossterm::event::{DisableMouseCapture, EnableMouseCapture};
+use crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Level, Metadata, Subscriber};
+
+/// Events the crash log keeps.
+pub(crate) const TRAIL_LEN: usize = 200;
+
+static TRAIL: Mutex<VecDeque<String>> = Mutex::new(VecDeque::new());
+/// Whether the terminal is ours to restore; outside the TUI the previous hook runs.
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+static INSTALL: Once = Once::new();
+
+/// Raw mode, the alternate screen, and mouse capture, on until [`TerminalGuard::restore`] or
+/// until the guard drops.
+pub(crate) struct TerminalGuard {
+    restored: bool,
+}
+
+impl TerminalGuard {
+    /// Take over the terminal, installing the trail and panic hook on first use; a crash is
+    /// written to `crash_log`, or only printed when there is none.
+    pub(crate) fn enter(crash_log: Option<PathBuf>) -> io::Result<Self> {
+        INSTALL.call_once(|| install(crash_log));
+        enable_raw_mode()?;
+        let guard = Self { restored: false };
+        ACTIVE.store(true, Ordering::SeqCst);
+        crossterm::execute!(io::stdout(), EnterAlternateScreen, EnableMouseCapture)?;
+        Ok(guard)
+    }
+
+    pub(crate) fn restore(mut self) -> io::Result<()> {
+        self.restored = true;
+        ACTIVE.store(false, Ordering::SeqCst);
+        leave()
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        if !self.restored {
+            ACTIVE.store(false, Ordering::SeqCst);
+            let _ = leave();
+        }
+    }
+}
+
+fn leave() -> io::Result<()> {
+    let disabled = disable_raw_mode();
+    crossterm::execute!(
+        io::stdout(),
+        DisableMouseCapture,
+        LeaveAlternateScreen,
+        Show
+    )?;
+    disabled
+}
+
+fn install(crash_log: Option<PathBuf>) {
+    // Fails only when the process already set a subscriber, which then keeps the events.
+    let _ = tracing::subscriber::set_global_default(Trail);
+    let ui_thread = thread::current().id();
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if !ACTIVE.load(Ordering::SeqCst) {
+            return previous(info);
+        }
+        if thread::current().id() != ui_thread {
+            tracing::error!("task panicked: {info}");
+            return;
+        }
+        ACTIVE.store(false, Ordering::SeqCst);
+        let _ = leave();
+        eprintln!("linear tui crashed: {info}");
+        if let Some(path) = &crash_log {
+            match write_log(path, info) {
+                Ok(()) => eprintln!(
+                    "A backtrace and the last {TRAIL_LEN} events were written to {}",
+                    path.display()
+                ),
+                Err(err) => eprintln!("unable to write the crash log: {err}"),
+            }
+        }
+    }));
+}
+
+fn write_log(path: &Path, info: &dyn fmt::Display) -> io::Result<()> {
+    let mut log = format!(
+        "linear {} TUI crashed at {}\n{info}\n\nBacktrace:\n{}\n\nLast {TRAIL_LEN} events:\n",
+        env!("CARGO_PKG_VERSION"),
+        Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+        std::backtrace::Backtrace::force_capture(),
+    );
+    // `try_lock`: the panic may have happened while recording an event.
+    if let Ok(trail) = TRAIL.try_lock() {
+        for line in trail.iter() {
+            log.push_str(line);
+            log.push('\n');
+        }
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, log)
+}
+
+/// Subscriber that keeps the last [`TRAIL_LEN`] events: everything from this crate, and
+/// warnings and errors from its dependencies.
+struct Trail;
+
+impl Subscriber for Trail {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.target().starts_with("linear") || *metadata.level() <= Level::WARN
+    }
+
+    fn new_span(&self, _: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let metadata = event.metadata();
+        let mut line = format!(
+            "{} {:5} {}:",
+            Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+            metadata.level(),
+            metadata.target()
+        );
+        event.record(&mut Fields(&mut line));
+        let mut trail = TRAIL.lock().unwrap_or_else(PoisonError::into_inner);
+        if trail.len() == TRAIL_LEN {
+            trail.pop_front();
+        }
+        trail.push_back(line);
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+/// Appends an event's message, then its other fields as `name=value`.
+struct Fields<'a>(&'a mut String);
+
+impl Visit for Fields<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        let _ = match field.name() {
+            "message" => write!(self.0, " {value:?}"),
+            name => write!(self.0, " {name}={value:?}"),
+        };
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        let _ = match field.name() {
+            "message" => write!(self.0, " {value}"),
+            name => write!(self.0, " {name}={value:?}"),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests share the one trail.
+    static SERIAL: Mutex<()> = Mutex::new(());
+
+    fn recorded() -> Vec<String> {
+        TRAIL.lock().unwrap().iter().cloned().collect()
+    }
+
+    #[test]
+    fn trail_keeps_the_latest_events_and_only_dependency_warnings() {
+        let _serial = SERIAL.lock().unwrap_or_else(PoisonError::into_inner);
+        tracing::subscriber::with_default(Trail, || {
+            tracing::info!(target: "hyper::proto", "dependency chatter");
+            tracing::warn!(target: "hyper::proto", "dependency warning");
+            for index in 0..TRAIL_LEN + 5 {
+                tracing::debug!(target: "linear::tui", index, "event");
+            }
+        });
+        let trail = recorded();
+        assert_eq!(trail.len(), TRAIL_LEN);
+        assert!(
+            trail[0].ends_with("linear::tui: event index=5"),
+            "{}",
+            trail[0]
+        );
+        assert!(trail[TRAIL_LEN - 1].ends_with(&format!("event index={}", TRAIL_LEN + 4)));
+
+        TRAIL.lock().unwrap().clear();
+        tracing::subscriber::with_default(Trail, || {
+            tracing::info!(target: "hyper::proto", "dependency chatter");
+            tracing::warn!(target: "hyper::proto", "dependency warning");
+        });
+        let trail = recorded();
+        assert_eq!(trail.len(), 1);
+        assert!(trail[0].contains("WARN  hyper::proto: dependency warning"));
+    }
+
+    #[test]
+    fn crash_log_holds_the_panic_and_the_trail() {
+        let _serial = SERIAL.lock().unwrap_or_else(PoisonError::into_inner);
+        TRAIL.lock().unwrap().clear();
+        tracing::subscriber::with_default(Trail, || {
+            tracing::info!(target: "linear::tui", key = "ENG-1", "opened");
+        });
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("logs").join("tui-crash.log");
+        write_log(&path, &"panicked at src/tui/app.rs:1:1: boom").unwrap();
+
+        let log = fs::read_to_string(&path).unwrap();
+        let mut lines = log.lines();
+        let header = lines.next().unwrap();
+        assert!(header.starts_with(&format!(
+            "linear {} TUI crashed at ",
+            env!("CARGO_PKG_VERSION")
+        )));
+        assert_eq!(lines.next(), Some("panicked at src/tui/app.rs:1:1: boom"));
+        assert!(log.contains("\n\nBacktrace:\n"));
+        let events = log
+            .split_once(&format!("Last {TRAIL_LEN} events:\n"))
+            .unwrap()
+            .1;
+        assert_eq!(events.lines().count(), 1);
+        assert!(
+            events.ends_with("INFO  linear::tui: opened key=\"ENG-1\"\n"),
+            "{events}"
+        );
+    }
+}
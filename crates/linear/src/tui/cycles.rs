@@ -0,0 +1,157 @@
+use std::collections::HashSet;
+
+use linear_core::graphql::{CycleSummary, IssueSummary};
+
+/// Pane that receives navigation keys.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum CyclesPane {
+    #[default]
+    Cycles,
+    Issues,
+}
+
+/// State of the cycles tab: the team's cycles, the opened cycle's issues, and the issues
+/// picked to move.
+#[derive(Default)]
+pub struct CyclesTab {
+    cycles: Vec<CycleSummary>,
+    cursor: usize,
+    opened: Option<String>,
+    issues: Vec<IssueSummary>,
+    issue_cursor: usize,
+    picked: HashSet<String>,
+    pane: CyclesPane,
+}
+
+impl CyclesTab {
+    pub fn cycles(&self) -> &[CycleSummary] {
+        &self.cycles
+    }
+
+    /// Replace the list, keeping the cursor on the same cycle when it is still there.
+    pub fn set_cycles(&mut self, cycles: Vec<CycleSummary>) {
+        let current = self.selected().map(|cycle| cycle.id.clone());
+        self.cursor = current
+            .and_then(|id| cycles.iter().position(|cycle| cycle.id == id))
+            .unwrap_or(0);
+        self.cycles = cycles;
+    }
+
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    pub fn selected(&self) -> Option<&CycleSummary> {
+        self.cycles.get(self.cursor)
+    }
+
+    /// The cycle whose issues are shown.
+    pub fn opened(&self) -> Option<&CycleSummary> {
+        let id = self.opened.as_deref()?;
+        self.cycles.iter().find(|cycle| cycle.id == id)
+    }
+
+    /// The active cycle of the opened cycle's team.
+    pub fn active_cycle(&self) -> Option<&CycleSummary> {
+        let team = self.opened()?.team.as_ref().map(|team| team.id.as_str());
+        self.cycles
+            .iter()
+            .find(|cycle| cycle.is_active && cycle.team.as_ref().map(|t| t.id.as_str()) == team)
+    }
+
+    pub fn set_issues(&mut self, cycle_id: String, issues: Vec<IssueSummary>) {
+        if self.opened.as_deref() != Some(cycle_id.as_str()) {
+            self.picked.clear();
+            self.issue_cursor = 0;
+        }
+        self.opened = Some(cycle_id);
+        self.issues = issues;
+        self.issue_cursor = self.issue_cursor.min(self.issues.len().saturating_sub(1));
+        self.picked
+            .retain(|id| self.issues.iter().any(|issue| &issue.id == id));
+        self.pane = CyclesPane::Issues;
+    }
+
+    pub fn issues(&self) -> &[IssueSummary] {
+        &self.issues
+    }
+
+    pub fn issue_cursor(&self) -> usize {
+        self.issue_cursor
+    }
+
+    pub fn is_picked(&self, issue: &IssueSummary) -> bool {
+        self.picked.contains(&issue.id)
+    }
+
+    pub fn picked_count(&self) -> usize {
+        self.picked.len()
+    }
+
+    /// Pick or drop the highlighted issue.
+    pub fn toggle_pick(&mut self) {
+        let Some(issue) = self.issues.get(self.issue_cursor) else {
+            return;
+        };
+        if !self.picked.remove(&issue.id) {
+            self.picked.insert(issue.id.clone());
+        }
+    }
+
+    /// Picked issues, or the highlighted one when nothing is picked.
+    pub fn move_targets(&self) -> Vec<IssueSummary> {
+        if self.picked.is_empty() {
+            return self
+                .issues
+                .get(self.issue_cursor)
+                .cloned()
+                .into_iter()
+                .collect();
+        }
+        self.issues
+            .iter()
+            .filter(|issue| self.picked.contains(&issue.id))
+            .cloned()
+            .collect()
+    }
+
+    /// Drop issues that left the opened cycle.
+    pub fn remove_issues(&mut self, ids: &[String]) {
+        self.issues.retain(|issue| !ids.contains(&issue.id));
+        for id in ids {
+            self.picked.remove(id);
+        }
+        self.issue_cursor = self.issue_cursor.min(self.issues.len().saturating_sub(1));
+    }
+
+    pub fn pane(&self) -> CyclesPane {
+        self.pane
+    }
+
+    pub fn toggle_pane(&mut self) {
+        self.pane = match self.pane {
+            CyclesPane::Cycles if self.opened.is_some() => CyclesPane::Issues,
+            _ => CyclesPane::Cycles,
+        };
+    }
+
+    pub fn move_cursor(&mut self, delta: isize) {
+        let (cursor, len) = match self.pane {
+            CyclesPane::Cycles => (&mut self.cursor, self.cycles.len()),
+            CyclesPane::Issues => (&mut self.issue_cursor, self.issues.len()),
+        };
+        if len == 0 {
+            return;
+        }
+        *cursor = (*cursor as isize + delta).clamp(0, len as isize - 1) as usize;
+    }
+}
+
+/// The cycle's name, or `Cycle N` for unnamed cycles.
+pub fn cycle_label(cycle: &CycleSummary) -> String {
+    cycle
+        .name
+        .clone()
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| format!("Cycle {}", cycle.number))
+}
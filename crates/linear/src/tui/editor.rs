@@ -0,0 +1,121 @@
+//! Editing a draft in the user's own editor while the TUI steps aside.
+//!
+//! The event loop reads input only when it polls, so nothing competes with the editor for
+//! stdin; the terminal just has to be handed over in cooked mode and taken back afterwards,
+//! which [`Suspended`] does even when the editor fails to start.
+
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use crossterm::event::{DisableMouseCapture, EnableMouseCapture};
+use crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use linear_core::shell::shell_command;
+use ratatui::backend::Backend;
+use ratatui::Terminal;
+use tokio::process::Command;
+
+/// Editor command from `$VISUAL`, then `$EDITOR`, else the platform's basic editor.
+pub fn editor_command() -> String {
+    ["VISUAL", "EDITOR"]
+        .into_iter()
+        .filter_map(|name| env::var(name).ok())
+        .find(|value| !value.trim().is_empty())
+        .unwrap_or_else(|| if cfg!(windows) { "notepad" } else { "vi" }.to_owned())
+}
+
+/// Open `text` in the editor as `<name>.md` and return what was saved, or `None` when the
+/// editor exits with an error (e.g. `:cq` in vim), which cancels the edit.
+pub async fn edit<B: Backend>(
+    terminal: &mut Terminal<B>,
+    name: &str,
+    text: &str,
+) -> Result<Option<String>> {
+    // Created exclusively with owner-only permissions under an unpredictable name, so no other
+    // user can plant a symlink there or read the draft; removed when `draft` drops.
+    let mut draft = tempfile::Builder::new()
+        .prefix(&format!("linear-{}-", draft_name(name)))
+        .suffix(".md")
+        .tempfile()
+        .context("failed to create the draft file")?;
+    draft
+        .write_all(text.as_bytes())
+        .and_then(|()| draft.flush())
+        .context("failed to write the draft file")?;
+    if !run_editor(terminal, draft.path()).await? {
+        return Ok(None);
+    }
+    // Read by path: editors that save by renaming replace the file behind the handle.
+    fs::read_to_string(draft.path())
+        .map(Some)
+        .context("failed to read the draft back")
+}
+
+async fn run_editor<B: Backend>(terminal: &mut Terminal<B>, path: &Path) -> Result<bool> {
+    let editor = editor_command();
+    let command = if cfg!(windows) {
+        shell_command(&format!("{editor} \"{}\"", path.display()))
+    } else {
+        // Passing the path as `$1` keeps editor arguments (`code --wait`) and odd paths intact.
+        let mut command = shell_command(&format!("{editor} \"$1\""));
+        command.arg("sh").arg(path);
+        command
+    };
+    let mut command = Command::from(command);
+
+    let suspended = Suspended::enter(terminal)?;
+    let status = command
+        .status()
+        .await
+        .with_context(|| format!("failed to start editor '{editor}'"));
+    suspended.resume(terminal)?;
+    Ok(status?.success())
+}
+
+fn draft_name(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect()
+}
+
+/// The terminal handed back to the shell: raw mode, the alternate screen, and mouse capture
+/// are off until [`Suspended::resume`], or until the guard drops if the editor never ran.
+struct Suspended {
+    resumed: bool,
+}
+
+impl Suspended {
+    fn enter<B: Backend>(terminal: &mut Terminal<B>) -> Result<Self> {
+        let guard = Self { resumed: false };
+        terminal.show_cursor()?;
+        crossterm::execute!(io::stdout(), DisableMouseCapture, LeaveAlternateScreen)?;
+        disable_raw_mode()?;
+        Ok(guard)
+    }
+
+    fn resume<B: Backend>(mut self, terminal: &mut Terminal<B>) -> Result<()> {
+        self.resumed = true;
+        restore()?;
+        // The editor drew over everything, so repaint from scratch.
+        terminal.clear()?;
+        terminal.hide_cursor()?;
+        Ok(())
+    }
+}
+
+impl Drop for Suspended {
+    fn drop(&mut self) {
+        if !self.resumed {
+            let _ = restore();
+        }
+    }
+}
+
+fn restore() -> io::Result<()> {
+    enable_raw_mode()?;
+    crossterm::execute!(io::stdout(), EnterAlternateScreen, EnableMouseCapture)
+}
@@ -0,0 +1,96 @@
+use std::collections::VecDeque;
+
+use chrono::{DateTime, Local};
+use linear_core::graphql::IssueUpdateInput;
+
+use super::app::EditField;
+
+/// Errors kept for the `!` console; older ones are dropped.
+const MAX_ERRORS: usize = 50;
+
+/// How to repeat the operation behind an error.
+#[derive(Clone, Debug)]
+pub enum Retry {
+    Issues,
+    Detail(String),
+    Projects,
+    Cycles,
+    FieldSave {
+        issue_id: String,
+        identifier: String,
+        field: EditField,
+        input: IssueUpdateInput,
+    },
+    Update {
+        issue_id: String,
+        identifier: String,
+        change: String,
+        input: IssueUpdateInput,
+    },
+}
+
+#[derive(Clone, Debug)]
+pub struct ErrorEntry {
+    pub at: DateTime<Local>,
+    /// What was being done, phrased to follow "Failed to", e.g. `load projects`.
+    pub operation: String,
+    pub message: String,
+    pub retry: Option<Retry>,
+}
+
+/// Recent errors, newest first, with the console's cursor and an unseen count.
+#[derive(Default)]
+pub struct ErrorLog {
+    entries: VecDeque<ErrorEntry>,
+    unseen: usize,
+    index: usize,
+}
+
+impl ErrorLog {
+    pub fn push(&mut self, operation: String, message: String, retry: Option<Retry>) {
+        self.entries.push_front(ErrorEntry {
+            at: Local::now(),
+            operation,
+            message,
+            retry,
+        });
+        self.entries.truncate(MAX_ERRORS);
+        self.unseen = (self.unseen + 1).min(self.entries.len());
+        // Keep the cursor on the same entry as new ones arrive on top.
+        if self.index > 0 {
+            self.index = (self.index + 1).min(self.entries.len() - 1);
+        }
+    }
+
+    pub fn entries(&self) -> &VecDeque<ErrorEntry> {
+        &self.entries
+    }
+
+    pub fn unseen(&self) -> usize {
+        self.unseen
+    }
+
+    pub fn mark_seen(&mut self) {
+        self.unseen = 0;
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn selected(&self) -> Option<&ErrorEntry> {
+        self.entries.get(self.index)
+    }
+
+    pub fn move_cursor(&mut self, delta: isize) {
+        if self.entries.is_empty() {
+            return;
+        }
+        let last = self.entries.len() as isize - 1;
+        self.index = (self.index as isize + delta).clamp(0, last) as usize;
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
@@ -0,0 +1,273 @@
+use linear_core::graphql::schema::{Schema, SchemaField, SchemaType, Selection};
+
+/// Name of the operation the explorer builds.
+const OPERATION_NAME: &str = "Explorer";
+
+/// Pane that receives navigation keys.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ExplorerPane {
+    #[default]
+    Fields,
+    Result,
+}
+
+/// Text being typed into the explorer.
+#[derive(Clone, Debug)]
+pub enum ExplorerInput {
+    /// Narrowing the field list by name.
+    Filter,
+    /// Argument list for the field at `path`.
+    Arguments { path: Vec<String>, text: String },
+}
+
+/// State of the `:api` schema explorer: where in the query type the user is, which fields are
+/// picked, and the last response.
+#[derive(Default)]
+pub struct Explorer {
+    schema: Option<Schema>,
+    path: Vec<String>,
+    cursor: usize,
+    filter: String,
+    input: Option<ExplorerInput>,
+    selection: Selection,
+    pane: ExplorerPane,
+    result: Vec<String>,
+    scroll: usize,
+}
+
+impl Explorer {
+    pub fn schema(&self) -> Option<&Schema> {
+        self.schema.as_ref()
+    }
+
+    pub fn set_schema(&mut self, schema: Schema) {
+        // A refreshed schema may no longer have the fields along the current path.
+        if schema.type_at(&schema.query_type, &self.path).is_none() {
+            self.path.clear();
+            self.cursor = 0;
+        }
+        self.schema = Some(schema);
+    }
+
+    pub fn pane(&self) -> ExplorerPane {
+        self.pane
+    }
+
+    pub fn toggle_pane(&mut self) {
+        self.pane = match self.pane {
+            ExplorerPane::Fields => ExplorerPane::Result,
+            ExplorerPane::Result => ExplorerPane::Fields,
+        };
+    }
+
+    pub fn input(&self) -> Option<&ExplorerInput> {
+        self.input.as_ref()
+    }
+
+    pub fn filter(&self) -> &str {
+        &self.filter
+    }
+
+    pub fn result(&self) -> &[String] {
+        &self.result
+    }
+
+    pub fn scroll(&self) -> usize {
+        self.scroll
+    }
+
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// `Query › issues › nodes`, naming the type being listed.
+    pub fn breadcrumb(&self) -> String {
+        let root = self
+            .schema
+            .as_ref()
+            .map_or("Query", |schema| schema.query_type.as_str());
+        let mut parts = vec![root.to_owned()];
+        parts.extend(self.path.iter().cloned());
+        match self.current_type() {
+            Some(ty) if !self.path.is_empty() => format!("{} : {}", parts.join(" › "), ty.name),
+            _ => parts.join(" › "),
+        }
+    }
+
+    pub fn current_type(&self) -> Option<&SchemaType> {
+        let schema = self.schema.as_ref()?;
+        schema.type_at(&schema.query_type, &self.path)
+    }
+
+    /// Fields of the current type whose names contain the filter.
+    pub fn visible_fields(&self) -> Vec<&SchemaField> {
+        let filter = self.filter.to_ascii_lowercase();
+        self.current_type()
+            .map(|ty| {
+                ty.fields
+                    .iter()
+                    .filter(|field| field.name.to_ascii_lowercase().contains(&filter))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn highlighted(&self) -> Option<&SchemaField> {
+        self.visible_fields().get(self.cursor).copied()
+    }
+
+    /// Path from the query root to `field`, a field of the current type.
+    pub fn path_to(&self, field: &SchemaField) -> Vec<String> {
+        let mut path = self.path.clone();
+        path.push(field.name.clone());
+        path
+    }
+
+    pub fn is_selected(&self, field: &SchemaField) -> bool {
+        self.selection.contains(&self.path_to(field))
+    }
+
+    pub fn is_composite(&self, field: &SchemaField) -> bool {
+        self.schema
+            .as_ref()
+            .and_then(|schema| schema.get(field.ty.named()))
+            .is_some_and(SchemaType::is_composite)
+    }
+
+    pub fn move_cursor(&mut self, delta: isize) {
+        match self.pane {
+            ExplorerPane::Fields => {
+                let total = self.visible_fields().len();
+                if total > 0 {
+                    self.cursor =
+                        (self.cursor as isize + delta).clamp(0, total as isize - 1) as usize;
+                }
+            }
+            ExplorerPane::Result => {
+                let last = self.result.len().saturating_sub(1);
+                self.scroll = (self.scroll as isize + delta).clamp(0, last as isize) as usize;
+            }
+        }
+    }
+
+    /// List the highlighted field's type; fails for scalars and enums.
+    pub fn descend(&mut self) -> Result<(), String> {
+        let Some(field) = self.highlighted() else {
+            return Err("No field highlighted".into());
+        };
+        if !self.is_composite(field) {
+            return Err(format!("{}: {} has no fields", field.name, field.ty));
+        }
+        self.path = self.path_to(field);
+        self.cursor = 0;
+        self.filter.clear();
+        Ok(())
+    }
+
+    /// Back to the parent type, highlighting the field just left.
+    pub fn ascend(&mut self) -> bool {
+        let Some(left) = self.path.pop() else {
+            return false;
+        };
+        self.filter.clear();
+        self.cursor = self
+            .visible_fields()
+            .iter()
+            .position(|field| field.name == left)
+            .unwrap_or(0);
+        true
+    }
+
+    /// Pick or drop the highlighted field; returns its name and whether it is now picked.
+    pub fn toggle(&mut self) -> Option<(String, bool)> {
+        let field = self.highlighted()?;
+        let name = field.name.clone();
+        let path = self.path_to(field);
+        Some((name, self.selection.toggle(&path)))
+    }
+
+    pub fn clear_selection(&mut self) {
+        self.selection = Selection::default();
+    }
+
+    pub fn begin_filter(&mut self) {
+        self.pane = ExplorerPane::Fields;
+        self.input = Some(ExplorerInput::Filter);
+    }
+
+    /// Edit the highlighted field's arguments, starting from what was entered before.
+    pub fn begin_arguments(&mut self) -> Result<(), String> {
+        let Some(field) = self.highlighted() else {
+            return Err("No field highlighted".into());
+        };
+        if field.args.is_empty() {
+            return Err(format!("{} takes no arguments", field.name));
+        }
+        let path = self.path_to(field);
+        let text = self
+            .selection
+            .get(&path)
+            .map(|picked| picked.arguments.clone())
+            .unwrap_or_default();
+        self.input = Some(ExplorerInput::Arguments { path, text });
+        Ok(())
+    }
+
+    pub fn push_char(&mut self, c: char) {
+        match self.input.as_mut() {
+            Some(ExplorerInput::Filter) => {
+                self.filter.push(c);
+                self.cursor = 0;
+            }
+            Some(ExplorerInput::Arguments { text, .. }) => text.push(c),
+            None => {}
+        }
+    }
+
+    pub fn pop_char(&mut self) {
+        match self.input.as_mut() {
+            Some(ExplorerInput::Filter) => {
+                self.filter.pop();
+                self.cursor = 0;
+            }
+            Some(ExplorerInput::Arguments { text, .. }) => {
+                text.pop();
+            }
+            None => {}
+        }
+    }
+
+    /// Finish typing: keep the filter, or store the arguments (selecting the field).
+    pub fn commit_input(&mut self) {
+        if let Some(ExplorerInput::Arguments { path, text }) = self.input.take() {
+            if let Some(field) = self.selection.ensure(&path) {
+                field.arguments = text.trim().to_owned();
+            }
+        }
+    }
+
+    /// Stop typing, dropping a filter or the argument edit.
+    pub fn cancel_input(&mut self) {
+        if let Some(ExplorerInput::Filter) = self.input.take() {
+            self.filter.clear();
+            self.cursor = 0;
+        }
+    }
+
+    /// The query for the picked fields, or `None` when nothing is picked.
+    pub fn document(&self) -> Option<String> {
+        let schema = self.schema.as_ref()?;
+        if self.selection.is_empty() {
+            return None;
+        }
+        Some(
+            self.selection
+                .document(schema, "query", OPERATION_NAME, &schema.query_type),
+        )
+    }
+
+    pub fn set_result(&mut self, lines: Vec<String>) {
+        self.result = lines;
+        self.scroll = 0;
+    }
+}
@@ -0,0 +1,130 @@
+//! OSC 8 hyperlinks for the TUI.
+//!
+//! Escape sequences cannot live inside ratatui cells (the buffer diff measures symbol width), so
+//! views register link targets while rendering and, after each draw, the matching cells are
+//! printed again wrapped in OSC 8.
+
+use std::cell::RefCell;
+use std::io::{self, Write};
+
+use crossterm::cursor::MoveTo;
+use crossterm::queue;
+use crossterm::style::{Attribute, Colors, Print, ResetColor, SetAttribute, SetColors};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Modifier;
+
+use crate::hyperlink;
+
+struct Target {
+    area: Rect,
+    text: String,
+    url: String,
+}
+
+thread_local! {
+    static TARGETS: RefCell<Vec<Target>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Link the first occurrence of `text` on each row of `area` to `url` for the current frame.
+/// Only whole tokens match, so `ENG-1` is not linked inside `ENG-12`.
+pub fn register(area: Rect, text: &str, url: &str) {
+    if text.is_empty() || !hyperlink::enabled() {
+        return;
+    }
+    TARGETS.with(|targets| {
+        targets.borrow_mut().push(Target {
+            area,
+            text: text.to_owned(),
+            url: url.to_owned(),
+        })
+    });
+}
+
+/// Re-print registered targets found in the completed frame as hyperlinks.
+pub fn emit(buffer: &Buffer) -> io::Result<()> {
+    let targets = TARGETS.with(|targets| std::mem::take(&mut *targets.borrow_mut()));
+    if targets.is_empty() {
+        return Ok(());
+    }
+    let mut out = io::stdout().lock();
+    for target in &targets {
+        let area = target.area.intersection(buffer.area);
+        let needle: Vec<String> = target.text.chars().map(String::from).collect();
+        for y in area.top()..area.bottom() {
+            let row: Vec<&str> = (area.left()..area.right())
+                .map(|x| buffer.get(x, y).symbol())
+                .collect();
+            let Some(offset) = find_token(&row, &needle) else {
+                continue;
+            };
+            let x = area.left() + offset as u16;
+            queue!(out, MoveTo(x, y), Print(hyperlink::open(&target.url)))?;
+            for dx in 0..needle.len() as u16 {
+                let cell = buffer.get(x + dx, y);
+                queue!(out, SetColors(Colors::new(cell.fg.into(), cell.bg.into())))?;
+                for attribute in attributes(cell.modifier) {
+                    queue!(out, SetAttribute(attribute))?;
+                }
+                queue!(out, Print(cell.symbol()), SetAttribute(Attribute::Reset))?;
+            }
+            queue!(out, Print(hyperlink::close()), ResetColor)?;
+        }
+    }
+    out.flush()
+}
+
+/// Start of the first run of cells spelling `needle` that is not part of a longer word.
+fn find_token(row: &[&str], needle: &[String]) -> Option<usize> {
+    if needle.is_empty() || needle.len() > row.len() {
+        return None;
+    }
+    let word = |cell: Option<&&str>| {
+        cell.and_then(|symbol| symbol.chars().next())
+            .is_some_and(|ch| ch.is_alphanumeric() || ch == '-' || ch == '_')
+    };
+    (0..=row.len() - needle.len()).find(|&start| {
+        row[start..start + needle.len()]
+            .iter()
+            .zip(needle)
+            .all(|(cell, ch)| cell == ch)
+            && !(start > 0 && word(row.get(start - 1)))
+            && !word(row.get(start + needle.len()))
+    })
+}
+
+fn attributes(modifier: Modifier) -> Vec<Attribute> {
+    [
+        (Modifier::BOLD, Attribute::Bold),
+        (Modifier::DIM, Attribute::Dim),
+        (Modifier::ITALIC, Attribute::Italic),
+        (Modifier::UNDERLINED, Attribute::Underlined),
+        (Modifier::REVERSED, Attribute::Reverse),
+        (Modifier::CROSSED_OUT, Attribute::CrossedOut),
+    ]
+    .into_iter()
+    .filter(|(flag, _)| modifier.contains(*flag))
+    .map(|(_, attribute)| attribute)
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find(row: &str, needle: &str) -> Option<usize> {
+        let row: Vec<String> = row.chars().map(String::from).collect();
+        let row: Vec<&str> = row.iter().map(String::as_str).collect();
+        let needle: Vec<String> = needle.chars().map(String::from).collect();
+        find_token(&row, &needle)
+    }
+
+    #[test]
+    fn matches_whole_tokens_only() {
+        assert_eq!(find("ENG-12 Fix ENG-1", "ENG-1"), Some(11));
+        assert_eq!(find("ENG-1: Fix", "ENG-1"), Some(0));
+        assert_eq!(find("XENG-1 ENG-10", "ENG-1"), None);
+        assert_eq!(find("(ENG-1)", "ENG-1"), Some(1));
+        assert_eq!(find("ENG", "ENG-1"), None);
+    }
+}
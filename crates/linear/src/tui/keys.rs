@@ -0,0 +1,150 @@
+use std::time::{Duration, Instant};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// How long a lone `1`-`4` waits for a motion before it switches the status tab instead.
+pub const COUNT_TIMEOUT: Duration = Duration::from_millis(600);
+
+/// A vim-style movement through the issue list.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Motion {
+    Down(usize),
+    Up(usize),
+    First,
+    Last,
+    /// `5G` / `5gg`: the fifth issue on the page.
+    Line(usize),
+    SetMark(char),
+    /// `'a`; `''` jumps back to where the last jump started.
+    JumpMark(char),
+}
+
+/// What a key press did to the sequence being typed.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Step {
+    /// The key extended the sequence; wait for more.
+    Pending,
+    Motion(Motion),
+    /// The sequence was abandoned and the key consumed.
+    Cancelled,
+    /// Not part of a sequence; handle the key normally. `count` is any count typed before it.
+    Pass {
+        count: Option<usize>,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Prefix {
+    G,
+    Mark,
+    Jump,
+}
+
+/// Parses count prefixes (`5j`), `gg`/`G`, and marks (`m a`, `' a`) one key at a time.
+#[derive(Default, Debug)]
+pub struct KeySequence {
+    count: Option<usize>,
+    prefix: Option<Prefix>,
+    started: Option<Instant>,
+}
+
+impl KeySequence {
+    pub fn feed(&mut self, key: KeyEvent, now: Instant) -> Step {
+        let plain = (key.modifiers - KeyModifiers::SHIFT).is_empty();
+        let code = if plain { key.code } else { KeyCode::Null };
+        if let Some(prefix) = self.prefix {
+            let count = self.count;
+            self.clear();
+            return match (prefix, code) {
+                (Prefix::G, KeyCode::Char('g')) => {
+                    Step::Motion(count.map_or(Motion::First, Motion::Line))
+                }
+                (Prefix::Mark, KeyCode::Char(c)) if c.is_ascii_alphabetic() => {
+                    Step::Motion(Motion::SetMark(c))
+                }
+                (Prefix::Jump, KeyCode::Char(c))
+                    if c.is_ascii_alphabetic() || c == '\'' || c == '`' =>
+                {
+                    Step::Motion(Motion::JumpMark(c))
+                }
+                _ => Step::Cancelled,
+            };
+        }
+        match code {
+            KeyCode::Char(c @ '0'..='9') if c != '0' || self.count.is_some() => {
+                let digit = c.to_digit(10).unwrap_or_default() as usize;
+                let count = self.count.unwrap_or(0).saturating_mul(10);
+                self.count = Some(count.saturating_add(digit));
+                self.started.get_or_insert(now);
+                Step::Pending
+            }
+            KeyCode::Char('g') => self.start(Prefix::G, now),
+            KeyCode::Char('m') => self.start(Prefix::Mark, now),
+            KeyCode::Char('\'') | KeyCode::Char('`') => self.start(Prefix::Jump, now),
+            KeyCode::Char('G') => {
+                let count = self.count;
+                self.clear();
+                Step::Motion(count.map_or(Motion::Last, Motion::Line))
+            }
+            KeyCode::Char('j') | KeyCode::Down if self.count.is_some() => {
+                let count = self.count.unwrap_or(1);
+                self.clear();
+                Step::Motion(Motion::Down(count))
+            }
+            KeyCode::Char('k') | KeyCode::Up if self.count.is_some() => {
+                let count = self.count.unwrap_or(1);
+                self.clear();
+                Step::Motion(Motion::Up(count))
+            }
+            KeyCode::Esc if self.is_pending() => {
+                self.clear();
+                Step::Cancelled
+            }
+            _ => {
+                let count = self.count;
+                self.clear();
+                Step::Pass { count }
+            }
+        }
+    }
+
+    /// The count typed so far once it has waited [`COUNT_TIMEOUT`] without a motion.
+    pub fn expire(&mut self, now: Instant) -> Option<usize> {
+        let started = self.started?;
+        if self.prefix.is_some() || now.duration_since(started) < COUNT_TIMEOUT {
+            return None;
+        }
+        let count = self.count;
+        self.clear();
+        count
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.count.is_some() || self.prefix.is_some()
+    }
+
+    /// The keys typed so far, shown in the status line like vim's `showcmd`.
+    pub fn text(&self) -> String {
+        let mut text = self
+            .count
+            .map(|count| count.to_string())
+            .unwrap_or_default();
+        match self.prefix {
+            Some(Prefix::G) => text.push('g'),
+            Some(Prefix::Mark) => text.push('m'),
+            Some(Prefix::Jump) => text.push('\''),
+            None => {}
+        }
+        text
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    fn start(&mut self, prefix: Prefix, now: Instant) -> Step {
+        self.prefix = Some(prefix);
+        self.started.get_or_insert(now);
+        Step::Pending
+    }
+}
@@ -1,4 +1,14 @@
+mod actions;
 pub mod app;
+mod board;
+mod crash;
+mod cycles;
+mod editor;
+mod errors;
+mod explorer;
+mod hyperlinks;
+mod keys;
+mod projects;
 mod runner;
 mod view;
 
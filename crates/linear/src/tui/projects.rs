@@ -0,0 +1,206 @@
+use chrono::NaiveDate;
+use linear_core::graphql::{IssueSummary, ProjectDetail, ProjectSummary, TeamMember};
+
+use super::app::parse_due_date;
+
+/// Project states offered by the state picker, in lifecycle order.
+pub const PROJECT_STATES: [&str; 6] = [
+    "backlog",
+    "planned",
+    "started",
+    "paused",
+    "completed",
+    "canceled",
+];
+
+/// Quick edit open over the projects tab.
+#[derive(Clone, Debug)]
+pub enum ProjectEditor {
+    State {
+        index: usize,
+    },
+    TargetDate {
+        input: String,
+    },
+    Lead {
+        members: Vec<TeamMember>,
+        index: usize,
+    },
+}
+
+/// A change picked in a [`ProjectEditor`], ready to be sent.
+#[derive(Clone, Debug)]
+pub enum ProjectEdit {
+    State(String),
+    TargetDate(NaiveDate),
+    Lead { id: String, name: String },
+}
+
+/// State of the projects tab: the project list, the opened project with its issues, and any
+/// quick edit in progress.
+#[derive(Default)]
+pub struct ProjectsTab {
+    projects: Vec<ProjectSummary>,
+    cursor: usize,
+    detail: Option<ProjectDetail>,
+    issues: Vec<IssueSummary>,
+    editor: Option<ProjectEditor>,
+}
+
+impl ProjectsTab {
+    pub fn projects(&self) -> &[ProjectSummary] {
+        &self.projects
+    }
+
+    /// Replace the list, keeping the cursor on the same project when it is still there.
+    pub fn set_projects(&mut self, projects: Vec<ProjectSummary>) {
+        let current = self.selected().map(|project| project.id.clone());
+        self.cursor = current
+            .and_then(|id| projects.iter().position(|project| project.id == id))
+            .unwrap_or(0);
+        self.projects = projects;
+    }
+
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    pub fn selected(&self) -> Option<&ProjectSummary> {
+        self.projects.get(self.cursor)
+    }
+
+    pub fn move_cursor(&mut self, delta: isize) {
+        if self.projects.is_empty() {
+            return;
+        }
+        let last = self.projects.len() as isize - 1;
+        self.cursor = (self.cursor as isize + delta).clamp(0, last) as usize;
+    }
+
+    /// The opened project, if it is the one under the cursor.
+    pub fn detail(&self) -> Option<&ProjectDetail> {
+        self.detail.as_ref().filter(|detail| {
+            self.selected()
+                .is_some_and(|project| project.id == detail.id)
+        })
+    }
+
+    pub fn issues(&self) -> &[IssueSummary] {
+        if self.detail().is_some() {
+            &self.issues
+        } else {
+            &[]
+        }
+    }
+
+    pub fn set_detail(&mut self, detail: ProjectDetail, issues: Vec<IssueSummary>) {
+        self.detail = Some(detail);
+        self.issues = issues;
+    }
+
+    /// Fold a saved project back into the list and the opened detail.
+    pub fn apply_update(&mut self, mut updated: ProjectDetail) {
+        if let Some(project) = self.projects.iter_mut().find(|p| p.id == updated.id) {
+            // Mutations do not select progress; keep the last known value.
+            if updated.progress.is_none() {
+                updated.progress = project.progress;
+            }
+            project.name = updated.name.clone();
+            project.state = updated.state.clone();
+            project.target_date = updated.target_date.clone();
+            project.status = updated.status.clone();
+            project.lead = updated.lead.clone();
+            project.updated_at = updated.updated_at;
+        }
+        if self.detail.as_ref().is_some_and(|d| d.id == updated.id) {
+            self.detail = Some(updated);
+        }
+    }
+
+    pub fn editor(&self) -> Option<&ProjectEditor> {
+        self.editor.as_ref()
+    }
+
+    pub fn begin_state_edit(&mut self) {
+        let current = self.selected().and_then(|project| project.state.as_deref());
+        let index = current
+            .and_then(|state| PROJECT_STATES.iter().position(|s| *s == state))
+            .unwrap_or(0);
+        self.editor = Some(ProjectEditor::State { index });
+    }
+
+    pub fn begin_target_date_edit(&mut self) {
+        let input = self
+            .selected()
+            .and_then(|project| project.target_date.clone())
+            .unwrap_or_default();
+        self.editor = Some(ProjectEditor::TargetDate { input });
+    }
+
+    pub fn begin_lead_edit(&mut self, members: Vec<TeamMember>) {
+        let lead = self
+            .selected()
+            .and_then(|project| project.lead.as_ref())
+            .map(|lead| lead.id.as_str());
+        let index = lead
+            .and_then(|id| members.iter().position(|member| member.id == id))
+            .unwrap_or(0);
+        self.editor = Some(ProjectEditor::Lead { members, index });
+    }
+
+    pub fn cancel_edit(&mut self) {
+        self.editor = None;
+    }
+
+    pub fn move_editor(&mut self, delta: isize) {
+        let (index, len) = match self.editor.as_mut() {
+            Some(ProjectEditor::State { index }) => (index, PROJECT_STATES.len()),
+            Some(ProjectEditor::Lead { members, index }) => {
+                let len = members.len();
+                (index, len)
+            }
+            _ => return,
+        };
+        if len > 0 {
+            *index = (*index as isize + delta).rem_euclid(len as isize) as usize;
+        }
+    }
+
+    pub fn push_char(&mut self, c: char) {
+        if let Some(ProjectEditor::TargetDate { input }) = self.editor.as_mut() {
+            input.push(c);
+        }
+    }
+
+    pub fn pop_char(&mut self) {
+        if let Some(ProjectEditor::TargetDate { input }) = self.editor.as_mut() {
+            input.pop();
+        }
+    }
+
+    /// Close the editor and return the picked change. A date that does not parse keeps the
+    /// editor open so it can be corrected.
+    pub fn take_edit(&mut self, today: NaiveDate) -> Result<Option<ProjectEdit>, String> {
+        if let Some(ProjectEditor::TargetDate { input }) = self.editor.as_ref() {
+            // Linear accepts a null target date, but the update input cannot send one.
+            parse_due_date(input, today)?.ok_or("Enter a target date (YYYY-MM-DD)")?;
+        }
+        let edit = match self.editor.take() {
+            Some(ProjectEditor::State { index }) => {
+                Some(ProjectEdit::State(PROJECT_STATES[index].into()))
+            }
+            Some(ProjectEditor::TargetDate { input }) => parse_due_date(&input, today)
+                .ok()
+                .flatten()
+                .map(ProjectEdit::TargetDate),
+            Some(ProjectEditor::Lead { members, index }) => {
+                members.get(index).map(|member| ProjectEdit::Lead {
+                    id: member.id.clone(),
+                    name: member.display_name.clone(),
+                })
+            }
+            None => None,
+        };
+        Ok(edit)
+    }
+}
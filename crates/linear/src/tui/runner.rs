@@ -1,32 +1,47 @@
+use std::collections::VecDeque;
+use std::future::Future;
 use std::io;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
-use anyhow::{Context, Result};
-use crossterm::event::{self, Event, KeyCode, KeyModifiers};
-use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
-use linear_core::graphql::LinearGraphqlClient;
+use anyhow::Result;
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
+use linear_core::config::{ConfigLocator, LayoutPreset};
+use linear_core::error::LinearError;
 use linear_core::services::cycles::CycleService;
 use linear_core::services::issues::IssueService;
 use linear_core::services::projects::ProjectService;
 use ratatui::backend::CrosstermBackend;
 use ratatui::Terminal;
 
-use crate::tui::app::{App, Focus, StatusTab};
+use crate::tui::app::{App, ComposeTarget, FieldEditor, Focus, StatusTab};
+use crate::tui::crash::TerminalGuard;
+use crate::tui::cycles::CyclesPane;
+use crate::tui::editor;
+use crate::tui::explorer::ExplorerPane;
+use crate::tui::hyperlinks;
+use crate::tui::keys::Step;
+use crate::tui::projects::ProjectEditor;
 use crate::tui::view::render_app;
 
+/// Percent the split moves per `+`/`-` press.
+const SPLIT_STEP: i16 = 5;
+
 pub async fn run(profile: &str) -> Result<()> {
     let session = crate::load_session(profile).await?;
-    let client =
-        LinearGraphqlClient::from_session(&session).context("failed to build GraphQL client")?;
+    let client = crate::build_client(&session)?;
     let issue_service = IssueService::new(client.clone());
     let project_service = ProjectService::new(client.clone());
     let cycle_service = CycleService::new(client);
+    let stale_after = crate::stale_threshold()?;
+    let layout = crate::tui_layout()?;
+    let board_limits = crate::board_limits()?;
 
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    crossterm::execute!(&mut stdout, crossterm::terminal::EnterAlternateScreen)?;
-    crossterm::execute!(&mut stdout, crossterm::event::EnableMouseCapture)?;
-    let backend = CrosstermBackend::new(stdout);
+    let crash_log = ConfigLocator::new()
+        .ok()
+        .map(|locator| locator.tui_crash_log());
+    let guard = TerminalGuard::enter(crash_log)?;
+    tracing::info!(profile, "tui started");
+    let backend = CrosstermBackend::new(io::stdout());
     let mut terminal = Terminal::new(backend)?;
 
     let mut app = App::new(
@@ -35,193 +50,582 @@ pub async fn run(profile: &str) -> Result<()> {
         cycle_service,
         profile.to_string(),
     );
-    app.load_issues().await;
-
-    let result = run_app(&mut terminal, &mut app).await;
-
-    disable_raw_mode()?;
-    crossterm::execute!(
-        terminal.backend_mut(),
-        crossterm::event::DisableMouseCapture,
-        crossterm::terminal::LeaveAlternateScreen
-    )?;
-    terminal.show_cursor()?;
+    app.set_stale_after(stale_after);
+    app.set_layout(layout);
+    app.set_board_limits(board_limits);
+    app.set_live(crate::daemon::subscribe(profile).await);
+    if app.restore_snapshot() {
+        let frame = terminal.draw(|frame| render_app(frame, &app))?;
+        hyperlinks::emit(frame.buffer)?;
+    }
+    let mut queued = VecDeque::new();
+    let result = match interruptible(app.load_issues(), &mut queued).await {
+        Ok(()) => {
+            app.load_workspace();
+            run_app(&mut terminal, &mut app, &mut queued).await
+        }
+        Err(err) => Err(err),
+    };
+    app.abort_tasks();
 
+    guard.restore()?;
     result
 }
 
+/// What the event loop should do after handling an event.
+enum Flow {
+    Continue,
+    Quit,
+}
+
 async fn run_app<B: ratatui::backend::Backend>(
     terminal: &mut Terminal<B>,
     app: &mut App,
+    queued: &mut VecDeque<Event>,
 ) -> Result<()> {
     loop {
-        terminal.draw(|frame| render_app(frame, app))?;
-
-        if event::poll(Duration::from_millis(200))? {
-            let evt = event::read()?;
-            if let Event::Key(key_event) = evt {
-                if key_event.modifiers.contains(KeyModifiers::CONTROL)
-                    && matches!(key_event.code, KeyCode::Enter)
-                {
-                    app.trigger_cli_action();
-                    continue;
-                }
+        app.set_terminal_width(terminal.size()?.width);
+        let frame = terminal.draw(|frame| render_app(frame, app))?;
+        hyperlinks::emit(frame.buffer)?;
+
+        let evt = match queued.pop_front() {
+            Some(evt) => Some(evt),
+            None if event::poll(Duration::from_millis(200))? => Some(event::read()?),
+            None => None,
+        };
+        if let Some(evt) = evt {
+            trace_input(app, &evt);
+            if is_interrupt(&evt) {
+                return Err(LinearError::interrupted().into());
             }
-            if app.show_help_overlay() {
-                if let Event::Key(key) = evt {
-                    match key.code {
-                        KeyCode::Char('?') | KeyCode::Esc => {
-                            app.toggle_help_overlay();
-                        }
-                        _ => {}
+            if let Event::Resize(width, _) = evt {
+                // Repaint from scratch so wrapped text reflows without stale cells or links.
+                terminal.autoresize()?;
+                terminal.clear()?;
+                app.set_terminal_width(width);
+            }
+            if let Flow::Quit = interruptible(handle_event(app, evt), queued).await? {
+                break;
+            }
+            if let Some((name, text)) = app.editor_draft() {
+                let edited = editor::edit(terminal, &name, &text).await;
+                app.finish_external_edit(edited);
+            }
+        }
+
+        app.process_pending_detail().await;
+        app.process_automation().await;
+        app.process_field_save().await;
+        app.process_compose().await;
+        app.process_explorer().await;
+        app.process_projects().await;
+        app.process_cycles().await;
+        app.process_workspace().await;
+        app.process_actions().await;
+        app.process_key_sequence().await;
+        app.process_live().await;
+
+        if app.status_spinner_active() {
+            app.tick_status_spinner();
+        }
+    }
+    Ok(())
+}
+
+/// Drive `action` to completion unless Ctrl+C is pressed meanwhile, which drops it and so
+/// cancels any request it is awaiting. Other input arriving in the meantime is queued.
+async fn interruptible<T>(
+    action: impl Future<Output = T>,
+    queued: &mut VecDeque<Event>,
+) -> Result<T> {
+    tokio::pin!(action);
+    loop {
+        tokio::select! {
+            output = &mut action => return Ok(output),
+            _ = tokio::time::sleep(Duration::from_millis(50)) => {
+                while event::poll(Duration::ZERO)? {
+                    let evt = event::read()?;
+                    if is_interrupt(&evt) {
+                        return Err(LinearError::interrupted().into());
                     }
+                    queued.push_back(evt);
                 }
-                continue;
             }
+        }
+    }
+}
 
-            if app.show_projects_overlay() {
-                if let Event::Key(key) = evt {
-                    match key.code {
-                        KeyCode::Char('o') | KeyCode::Char('O') | KeyCode::Esc => {
-                            app.close_projects_overlay();
-                        }
-                        _ => {}
-                    }
+/// Record input for the crash log, leaving out the keys of anything being typed.
+fn trace_input(app: &App, evt: &Event) {
+    match evt {
+        Event::Key(key) if typing(app) => tracing::debug!(modifiers = ?key.modifiers, "text key"),
+        Event::Key(key) => tracing::debug!(code = ?key.code, modifiers = ?key.modifiers, "key"),
+        Event::Mouse(mouse) => tracing::debug!(kind = ?mouse.kind, "mouse"),
+        Event::Resize(width, height) => tracing::debug!(width, height, "resize"),
+        _ => {}
+    }
+}
+
+/// Whether keys go into typed text (a comment, a command, a search, a field value, an explorer
+/// filter or arguments, a target date), which the crash trail must not record.
+fn typing(app: &App) -> bool {
+    app.composer().is_some()
+        || app.palette_active()
+        || app.field_editor().is_some()
+        || app
+            .explorer()
+            .is_some_and(|explorer| explorer.input().is_some())
+        || app
+            .projects_tab()
+            .is_some_and(|tab| matches!(tab.editor(), Some(ProjectEditor::TargetDate { .. })))
+}
+
+/// Raw mode swallows SIGINT, so Ctrl+C arrives as a key press.
+fn is_interrupt(evt: &Event) -> bool {
+    matches!(
+        evt,
+        Event::Key(key)
+            if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL)
+    )
+}
+
+async fn handle_event(app: &mut App, evt: Event) -> Flow {
+    if app.composer().is_some() {
+        if let Event::Key(key) = evt {
+            let control = key.modifiers.contains(KeyModifiers::CONTROL);
+            match key.code {
+                KeyCode::Esc => app.cancel_compose(),
+                KeyCode::Char('s') if control => app.submit_compose(),
+                KeyCode::Char('e') if control => app.open_draft_in_editor(),
+                KeyCode::Enter => app.composer_enter(),
+                KeyCode::Tab => {
+                    app.accept_suggestion();
                 }
-                continue;
+                KeyCode::Down => app.move_suggestion(1),
+                KeyCode::Up => app.move_suggestion(-1),
+                KeyCode::Backspace => app.pop_composer_char(),
+                KeyCode::Char(c) if !control => app.push_composer_char(c),
+                _ => {}
+            }
+        }
+        return Flow::Continue;
+    }
+    if app.explorer().is_some() {
+        if let Event::Key(key) = evt {
+            handle_explorer_key(app, key);
+        }
+        return Flow::Continue;
+    }
+    if let Event::Key(key_event) = evt {
+        if key_event.modifiers.contains(KeyModifiers::CONTROL)
+            && matches!(key_event.code, KeyCode::Enter)
+        {
+            app.trigger_cli_action();
+            return Flow::Continue;
+        }
+    }
+    if app.show_help_overlay() {
+        if let Event::Key(key) = evt {
+            match key.code {
+                KeyCode::Char('?') | KeyCode::Esc => {
+                    app.toggle_help_overlay();
+                }
+                _ => {}
             }
+        }
+        return Flow::Continue;
+    }
 
-            if app.show_cycles_overlay() {
-                if let Event::Key(key) = evt {
-                    match key.code {
-                        KeyCode::Char('y') | KeyCode::Esc => {
-                            app.close_cycles_overlay();
-                        }
-                        _ => {}
-                    }
+    if app.show_error_console() {
+        if let Event::Key(key) = evt {
+            match key.code {
+                KeyCode::Char('!') | KeyCode::Esc => app.toggle_error_console(),
+                KeyCode::Down | KeyCode::Char('j') => app.move_error_selection(1),
+                KeyCode::Up | KeyCode::Char('k') => app.move_error_selection(-1),
+                KeyCode::Enter | KeyCode::Char('r') => app.retry_selected_error().await,
+                KeyCode::Char('c') => app.clear_errors(),
+                _ => {}
+            }
+        }
+        return Flow::Continue;
+    }
+
+    if app.show_projects_overlay() {
+        if let Event::Key(key) = evt {
+            match key.code {
+                KeyCode::Char('o') | KeyCode::Char('O') | KeyCode::Esc => {
+                    app.close_projects_overlay();
                 }
-                continue;
-            }
-
-            if app.palette_active() {
-                if let Event::Key(key) = evt {
-                    match key.code {
-                        KeyCode::Esc => {
-                            app.exit_palette();
-                        }
-                        KeyCode::Enter => {
-                            let cmd = app.take_palette_input();
-                            app.execute_command(cmd).await;
-                        }
-                        KeyCode::Backspace => {
-                            app.pop_palette_char();
-                        }
-                        KeyCode::Up => {
-                            app.recall_palette_history(-1);
-                        }
-                        KeyCode::Down => {
-                            app.recall_palette_history(1);
-                        }
-                        KeyCode::Char(c) => {
-                            app.push_palette_char(c);
-                        }
-                        _ => {}
-                    }
+                _ => {}
+            }
+        }
+        return Flow::Continue;
+    }
+
+    if app.show_profiles_overlay() {
+        if let Event::Key(key) = evt {
+            match key.code {
+                KeyCode::Char('w') | KeyCode::Esc => app.close_profiles_overlay(),
+                KeyCode::Down | KeyCode::Char('j') => app.move_profile_selection(1),
+                KeyCode::Up | KeyCode::Char('k') => app.move_profile_selection(-1),
+                KeyCode::Enter => app.confirm_profile_selection().await,
+                _ => {}
+            }
+        }
+        return Flow::Continue;
+    }
+
+    if let Some(editor) = app.field_editor() {
+        let choosing = matches!(editor, FieldEditor::Priority { .. });
+        if let Event::Key(key) = evt {
+            match key.code {
+                KeyCode::Esc => app.cancel_field_edit(),
+                KeyCode::Enter => app.commit_field_edit(),
+                KeyCode::Backspace => app.pop_field_char(),
+                KeyCode::Down | KeyCode::Right => app.move_field_editor(1),
+                KeyCode::Up | KeyCode::Left => app.move_field_editor(-1),
+                KeyCode::Char('j') | KeyCode::Char('l') if choosing => app.move_field_editor(1),
+                KeyCode::Char('k') | KeyCode::Char('h') if choosing => app.move_field_editor(-1),
+                KeyCode::Char(c) => app.push_field_char(c),
+                _ => {}
+            }
+        }
+        return Flow::Continue;
+    }
+
+    if app.palette_active() {
+        if let Event::Key(key) = evt {
+            match key.code {
+                KeyCode::Esc => {
+                    app.exit_palette();
                 }
-                continue;
-            }
-
-            match evt {
-                Event::Key(key) => {
-                    let modifiers = key.modifiers;
-                    match key.code {
-                        KeyCode::Char('q') | KeyCode::Esc => break,
-                        KeyCode::Char('r') | KeyCode::Char('R') if modifiers.is_empty() => {
-                            app.load_issues().await
-                        }
-                        KeyCode::Char('p') | KeyCode::Char('P') => {
-                            if modifiers.contains(KeyModifiers::CONTROL) {
-                                app.clear_project_filter().await;
-                            } else if modifiers.contains(KeyModifiers::SHIFT)
-                                || matches!(key.code, KeyCode::Char('P'))
-                            {
-                                app.cycle_project_filter(-1).await;
-                            } else {
-                                app.cycle_project_filter(1).await;
-                            }
-                        }
-                        KeyCode::Char('o') | KeyCode::Char('O') => {
-                            app.open_projects_overlay().await
-                        }
-                        KeyCode::Char('y') | KeyCode::Char('Y') => app.open_cycles_overlay().await,
-                        KeyCode::Char('1') => app.set_status_tab(StatusTab::Todo).await,
-                        KeyCode::Char('2') => app.set_status_tab(StatusTab::Doing).await,
-                        KeyCode::Char('3') => app.set_status_tab(StatusTab::Done).await,
-                        KeyCode::Char('4') => app.set_status_tab(StatusTab::All).await,
-                        KeyCode::Char(']') => {
-                            if modifiers.contains(KeyModifiers::CONTROL) {
-                                app.cycle_status_tab(1).await;
-                            } else if app.has_next_page() {
-                                app.next_page().await;
-                            } else {
-                                app.set_status("No more issues", false);
-                            }
-                        }
-                        KeyCode::Char('[') => {
-                            if modifiers.contains(KeyModifiers::CONTROL) {
-                                app.cycle_status_tab(-1).await;
-                            } else {
-                                app.previous_page().await;
-                            }
-                        }
-                        KeyCode::Char('.') if !modifiers.contains(KeyModifiers::CONTROL) => {
-                            app.cycle_detail_tab(1);
-                        }
-                        KeyCode::Char(',') if !modifiers.contains(KeyModifiers::CONTROL) => {
-                            app.cycle_detail_tab(-1);
-                        }
-                        KeyCode::Down | KeyCode::Char('j') => match app.focus() {
-                            Focus::Issues => app.move_issue_selection(1).await,
-                            Focus::Teams => app.move_team_selection(1).await,
-                            Focus::States => app.move_state_selection(1).await,
-                        },
-                        KeyCode::Up | KeyCode::Char('k') => match app.focus() {
-                            Focus::Issues => app.move_issue_selection(-1).await,
-                            Focus::Teams => app.move_team_selection(-1).await,
-                            Focus::States => app.move_state_selection(-1).await,
-                        },
-                        KeyCode::Tab => app.toggle_focus(),
-                        KeyCode::Char('t') | KeyCode::Char('T')
-                            if !modifiers.contains(KeyModifiers::CONTROL) =>
-                        {
-                            app.move_team_selection(1).await
-                        }
-                        KeyCode::Char('s') | KeyCode::Char('S')
-                            if !modifiers.contains(KeyModifiers::CONTROL) =>
-                        {
-                            app.move_state_selection(1).await
-                        }
-                        KeyCode::Char('/') => app.enter_contains_palette(),
-                        KeyCode::Char('c') | KeyCode::Char('C')
-                            if !modifiers.contains(KeyModifiers::CONTROL) =>
-                        {
-                            app.clear_all_filters().await
-                        }
-                        KeyCode::Char('?') => app.toggle_help_overlay(),
-                        KeyCode::Char(':') => app.enter_palette(),
-                        _ => {}
-                    }
+                KeyCode::Enter => {
+                    let cmd = app.take_palette_input();
+                    app.execute_command(cmd).await;
+                }
+                KeyCode::Backspace => {
+                    app.pop_palette_char();
+                }
+                KeyCode::Up => {
+                    app.recall_palette_history(-1);
+                }
+                KeyCode::Down => {
+                    app.recall_palette_history(1);
+                }
+                KeyCode::Char(c) => {
+                    app.push_palette_char(c);
                 }
                 _ => {}
             }
         }
+        return Flow::Continue;
+    }
 
-        app.process_pending_detail().await;
-        app.process_automation().await;
+    if app.quick_menu().is_some() {
+        if let Event::Key(key) = evt {
+            handle_quick_menu_key(app, key);
+        }
+        return Flow::Continue;
+    }
 
-        if app.status_spinner_active() {
-            app.tick_status_spinner();
+    // The error console opens from the issue view and from either tab.
+    if let Event::Key(key) = evt {
+        if key.code == KeyCode::Char('!') && !app.yank_pending() {
+            app.toggle_error_console();
+            return Flow::Continue;
         }
     }
-    Ok(())
+
+    if app.projects_tab().is_some() {
+        if let Event::Key(key) = evt {
+            return handle_projects_key(app, key);
+        }
+        return Flow::Continue;
+    }
+
+    if app.cycles_tab().is_some() {
+        if let Event::Key(key) = evt {
+            return handle_cycles_key(app, key);
+        }
+        return Flow::Continue;
+    }
+
+    if app.board_tab().is_some() {
+        if let Event::Key(key) = evt {
+            return handle_board_key(app, key).await;
+        }
+        return Flow::Continue;
+    }
+
+    if app.yank_pending() {
+        if let Event::Key(key) = evt {
+            let target = match key.code {
+                KeyCode::Char(c) => Some(c),
+                _ => None,
+            };
+            app.finish_yank(target);
+        }
+        return Flow::Continue;
+    }
+
+    if let Event::Key(key) = evt {
+        if app.focus() == Focus::Issues {
+            match app.key_sequence_mut().feed(key, Instant::now()) {
+                Step::Pending | Step::Cancelled => return Flow::Continue,
+                Step::Motion(motion) => {
+                    app.apply_motion(motion).await;
+                    return Flow::Continue;
+                }
+                // A lone `1`-`4` followed by another key keeps its status-tab meaning.
+                Step::Pass { count } => {
+                    if let Some(tab) = count.and_then(StatusTab::from_digit) {
+                        app.set_status_tab(tab).await;
+                    }
+                }
+            }
+        }
+        let modifiers = key.modifiers;
+        match key.code {
+            KeyCode::Esc if app.sidebar_drawer_open() => app.toggle_sidebar_drawer(),
+            KeyCode::Esc if app.detail_overlay_open() => app.close_detail_overlay(),
+            KeyCode::Char('q') | KeyCode::Esc => return Flow::Quit,
+            KeyCode::Char('b') if modifiers.is_empty() => app.toggle_sidebar_drawer(),
+            KeyCode::Char('r') if modifiers.is_empty() => app.refresh_issues(false).await,
+            KeyCode::Char('R') => app.refresh_issues(true).await,
+            KeyCode::Char('p') => {
+                if modifiers.contains(KeyModifiers::CONTROL) {
+                    app.clear_project_filter().await;
+                } else {
+                    app.cycle_project_filter(1).await;
+                }
+            }
+            KeyCode::Char('P') => app.toggle_projects_tab(),
+            KeyCode::Char('o') | KeyCode::Char('O') => app.open_projects_overlay().await,
+            KeyCode::Char('Y') => app.toggle_cycles_tab(),
+            KeyCode::Char('B') => app.toggle_board_tab(),
+            KeyCode::Char('y') if modifiers.is_empty() => app.begin_yank(),
+            KeyCode::Char('z') => app.cycle_layout(),
+            KeyCode::Char('|') => app.set_layout_preset(LayoutPreset::SideBySide),
+            KeyCode::Char('_') => app.set_layout_preset(LayoutPreset::Stacked),
+            KeyCode::Char('+') => app.resize_split(SPLIT_STEP),
+            KeyCode::Char('-') => app.resize_split(-SPLIT_STEP),
+            KeyCode::Char('=') => app.reset_split(),
+            KeyCode::Char('w') if modifiers.is_empty() => app.open_profiles_overlay(),
+            KeyCode::Char('1') => app.set_status_tab(StatusTab::Todo).await,
+            KeyCode::Char('2') => app.set_status_tab(StatusTab::Doing).await,
+            KeyCode::Char('3') => app.set_status_tab(StatusTab::Done).await,
+            KeyCode::Char('4') => app.set_status_tab(StatusTab::All).await,
+            KeyCode::Char(']') => {
+                if modifiers.contains(KeyModifiers::CONTROL) {
+                    app.cycle_status_tab(1).await;
+                } else if app.has_next_page() {
+                    app.next_page().await;
+                } else {
+                    app.set_status("No more issues", false);
+                }
+            }
+            KeyCode::Char('[') => {
+                if modifiers.contains(KeyModifiers::CONTROL) {
+                    app.cycle_status_tab(-1).await;
+                } else {
+                    app.previous_page().await;
+                }
+            }
+            KeyCode::Char('.') if !modifiers.contains(KeyModifiers::CONTROL) => {
+                app.open_quick_menu();
+            }
+            KeyCode::Char('>') => app.cycle_detail_tab(1),
+            KeyCode::Char('<') => app.cycle_detail_tab(-1),
+            KeyCode::Down | KeyCode::Char('j') => match app.focus() {
+                Focus::Issues => app.move_issue_selection(1).await,
+                Focus::Teams => app.move_team_selection(1).await,
+                Focus::States => app.move_state_selection(1).await,
+                Focus::Detail => app.move_field_selection(1),
+            },
+            KeyCode::Up | KeyCode::Char('k') => match app.focus() {
+                Focus::Issues => app.move_issue_selection(-1).await,
+                Focus::Teams => app.move_team_selection(-1).await,
+                Focus::States => app.move_state_selection(-1).await,
+                Focus::Detail => app.move_field_selection(-1),
+            },
+            KeyCode::Enter if app.focus() == Focus::Detail => app.begin_field_edit(),
+            KeyCode::Enter if app.narrow() && app.focus() == Focus::Issues => {
+                app.open_detail_overlay();
+            }
+            KeyCode::Tab => app.toggle_focus(),
+            KeyCode::Char('t') | KeyCode::Char('T')
+                if !modifiers.contains(KeyModifiers::CONTROL) =>
+            {
+                app.move_team_selection(1).await
+            }
+            KeyCode::Char('s') | KeyCode::Char('S')
+                if !modifiers.contains(KeyModifiers::CONTROL) =>
+            {
+                app.move_state_selection(1).await
+            }
+            KeyCode::Char('/') => app.enter_contains_palette(),
+            KeyCode::Char('M') => app.toggle_mine().await,
+            KeyCode::Char('c') | KeyCode::Char('C')
+                if !modifiers.contains(KeyModifiers::CONTROL) =>
+            {
+                app.clear_all_filters().await
+            }
+            KeyCode::Char('a') if modifiers.is_empty() => {
+                app.begin_compose(ComposeTarget::Comment).await
+            }
+            KeyCode::Char('e') if modifiers.is_empty() => {
+                app.begin_compose(ComposeTarget::Description).await
+            }
+            KeyCode::Char('E') => app.edit_description_externally().await,
+            KeyCode::Char('?') => app.toggle_help_overlay(),
+            KeyCode::Char(':') => app.enter_palette(),
+            _ => {}
+        }
+    }
+    Flow::Continue
+}
+
+fn handle_projects_key(app: &mut App, key: KeyEvent) -> Flow {
+    let Some(tab) = app.projects_tab_mut() else {
+        return Flow::Continue;
+    };
+    if let Some(editor) = tab.editor() {
+        let choosing = !matches!(editor, ProjectEditor::TargetDate { .. });
+        match key.code {
+            KeyCode::Esc => app.cancel_project_edit(),
+            KeyCode::Enter => app.commit_project_edit(),
+            KeyCode::Backspace => tab.pop_char(),
+            KeyCode::Down => tab.move_editor(1),
+            KeyCode::Up => tab.move_editor(-1),
+            KeyCode::Char('j') if choosing => tab.move_editor(1),
+            KeyCode::Char('k') if choosing => tab.move_editor(-1),
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => tab.push_char(c),
+            _ => {}
+        }
+        return Flow::Continue;
+    }
+    match key.code {
+        KeyCode::Char('q') => return Flow::Quit,
+        KeyCode::Esc | KeyCode::Char('P') => app.toggle_projects_tab(),
+        KeyCode::Down | KeyCode::Char('j') => tab.move_cursor(1),
+        KeyCode::Up | KeyCode::Char('k') => tab.move_cursor(-1),
+        KeyCode::PageDown => tab.move_cursor(10),
+        KeyCode::PageUp => tab.move_cursor(-10),
+        KeyCode::Enter => app.open_selected_project(),
+        KeyCode::Char('s') => app.begin_project_state_edit(),
+        KeyCode::Char('d') => app.begin_project_target_date_edit(),
+        KeyCode::Char('l') => app.begin_project_lead_edit(),
+        KeyCode::Char('r') => app.load_project_list(),
+        KeyCode::Char('?') => app.toggle_help_overlay(),
+        KeyCode::Char(':') => app.enter_palette(),
+        _ => {}
+    }
+    Flow::Continue
+}
+
+fn handle_quick_menu_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('.') => app.close_quick_menu(),
+        KeyCode::Down | KeyCode::Char('j') => app.move_quick_menu(1),
+        KeyCode::Up | KeyCode::Char('k') => app.move_quick_menu(-1),
+        KeyCode::Enter => app.select_quick_menu(),
+        KeyCode::Char(c) => app.quick_menu_shortcut(c),
+        _ => {}
+    }
+}
+
+fn handle_cycles_key(app: &mut App, key: KeyEvent) -> Flow {
+    let Some(tab) = app.cycles_tab_mut() else {
+        return Flow::Continue;
+    };
+    let issues = tab.pane() == CyclesPane::Issues;
+    match key.code {
+        KeyCode::Char('q') => return Flow::Quit,
+        KeyCode::Esc | KeyCode::Char('Y') => app.toggle_cycles_tab(),
+        KeyCode::Down | KeyCode::Char('j') => tab.move_cursor(1),
+        KeyCode::Up | KeyCode::Char('k') => tab.move_cursor(-1),
+        KeyCode::PageDown => tab.move_cursor(10),
+        KeyCode::PageUp => tab.move_cursor(-10),
+        KeyCode::Tab => tab.toggle_pane(),
+        KeyCode::Enter if !issues => app.open_selected_cycle(),
+        KeyCode::Char(' ') if issues => tab.toggle_pick(),
+        KeyCode::Char('m') if issues => app.move_to_active_cycle(),
+        KeyCode::Char('r') => app.load_cycle_list(None),
+        KeyCode::Char('?') => app.toggle_help_overlay(),
+        KeyCode::Char(':') => app.enter_palette(),
+        _ => {}
+    }
+    Flow::Continue
+}
+
+async fn handle_board_key(app: &mut App, key: KeyEvent) -> Flow {
+    match key.code {
+        KeyCode::Char('q') => return Flow::Quit,
+        KeyCode::Esc | KeyCode::Char('B') => app.toggle_board_tab(),
+        KeyCode::Left | KeyCode::Char('h') => app.move_board_cursor(-1, 0),
+        KeyCode::Right | KeyCode::Char('l') => app.move_board_cursor(1, 0),
+        KeyCode::Down | KeyCode::Char('j') => app.move_board_cursor(0, 1),
+        KeyCode::Up | KeyCode::Char('k') => app.move_board_cursor(0, -1),
+        KeyCode::PageDown => app.move_board_cursor(0, 10),
+        KeyCode::PageUp => app.move_board_cursor(0, -10),
+        KeyCode::Enter => app.open_board_card(),
+        KeyCode::Char('r') => app.refresh_issues(false).await,
+        KeyCode::Char('?') => app.toggle_help_overlay(),
+        KeyCode::Char(':') => app.enter_palette(),
+        _ => {}
+    }
+    Flow::Continue
+}
+
+fn handle_explorer_key(app: &mut App, key: KeyEvent) {
+    let Some(explorer) = app.explorer_mut() else {
+        return;
+    };
+    if explorer.input().is_some() {
+        match key.code {
+            KeyCode::Esc => explorer.cancel_input(),
+            KeyCode::Enter => explorer.commit_input(),
+            KeyCode::Backspace => explorer.pop_char(),
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                explorer.push_char(c)
+            }
+            _ => {}
+        }
+        return;
+    }
+    let fields = explorer.pane() == ExplorerPane::Fields;
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => app.close_explorer(),
+        KeyCode::Down | KeyCode::Char('j') => explorer.move_cursor(1),
+        KeyCode::Up | KeyCode::Char('k') => explorer.move_cursor(-1),
+        KeyCode::PageDown => explorer.move_cursor(10),
+        KeyCode::PageUp => explorer.move_cursor(-10),
+        KeyCode::Tab => explorer.toggle_pane(),
+        KeyCode::Enter | KeyCode::Right | KeyCode::Char('l') if fields => {
+            if let Err(message) = explorer.descend() {
+                app.set_status(message, false);
+            }
+        }
+        KeyCode::Backspace | KeyCode::Left | KeyCode::Char('h') if fields => {
+            explorer.ascend();
+        }
+        KeyCode::Char(' ') if fields => {
+            if let Some((name, picked)) = explorer.toggle() {
+                let verb = if picked { "Picked" } else { "Dropped" };
+                app.set_status(format!("{verb} {name}"), false);
+            }
+        }
+        KeyCode::Char('a') if fields => {
+            if let Err(message) = explorer.begin_arguments() {
+                app.set_status(message, false);
+            }
+        }
+        KeyCode::Char('/') => explorer.begin_filter(),
+        KeyCode::Char('c') => {
+            explorer.clear_selection();
+            app.set_status("Cleared the query", false);
+        }
+        KeyCode::Char('x') => app.run_explorer_query(),
+        _ => {}
+    }
 }
@@ -0,0 +1,83 @@
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+use crate::color;
+use crate::tui::actions::QuickMenu;
+use crate::tui::app::App;
+use crate::tui::view::util::centered_rect;
+
+pub fn render(frame: &mut Frame, area: Rect, app: &App) {
+    let Some(menu) = app.quick_menu() else {
+        return;
+    };
+    let key = app
+        .selected_issue()
+        .map(|issue| issue.identifier.clone())
+        .unwrap_or_default();
+    let visible = area.height.saturating_sub(4) as usize;
+    let (title, lines): (String, Vec<Line>) = match menu {
+        QuickMenu::Actions { index } => (
+            format!("Actions · {key}"),
+            app.action_registry()
+                .actions()
+                .iter()
+                .enumerate()
+                .map(|(idx, spec)| {
+                    choice_line(
+                        vec![
+                            Span::styled(
+                                format!("{} ", spec.key),
+                                Style::default().fg(Color::Yellow),
+                            ),
+                            Span::raw(spec.label),
+                        ],
+                        idx == *index,
+                    )
+                })
+                .collect(),
+        ),
+        QuickMenu::States { states, index } => (
+            format!("State · {key}"),
+            window(states.len(), *index, visible)
+                .map(|idx| choice_line(vec![Span::raw(states[idx].name.clone())], idx == *index))
+                .collect(),
+        ),
+        QuickMenu::Labels { labels, index } => (
+            format!("Add label · {key}"),
+            window(labels.len(), *index, visible)
+                .map(|idx| choice_line(vec![color::label_span(&labels[idx])], idx == *index))
+                .collect(),
+        ),
+    };
+    let height = lines.len() as u16 + 2;
+    let popup = centered_rect(area.width.saturating_sub(4).min(40), height, area);
+    frame.render_widget(Clear, popup);
+    frame.render_widget(
+        Paragraph::new(lines).block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Yellow)),
+        ),
+        popup,
+    );
+}
+
+/// Indices of the rows to draw so that `index` stays on screen.
+fn window(len: usize, index: usize, visible: usize) -> std::ops::Range<usize> {
+    let start = index.saturating_sub(visible.saturating_sub(1));
+    start..len.min(start + visible.max(1))
+}
+
+fn choice_line(mut spans: Vec<Span<'static>>, selected: bool) -> Line<'static> {
+    spans.insert(0, Span::raw(if selected { "> " } else { "  " }));
+    let line = Line::from(spans);
+    if selected {
+        line.style(Style::default().fg(Color::Black).bg(Color::Cyan))
+    } else {
+        line
+    }
+}
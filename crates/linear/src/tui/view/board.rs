@@ -0,0 +1,145 @@
+use chrono::Utc;
+use linear_core::board::short_age;
+use linear_core::graphql::IssueSummary;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::Frame;
+
+use crate::table::truncate;
+use crate::tui::app::App;
+use crate::tui::view::util::selection_marker;
+
+pub fn render(frame: &mut Frame, area: Rect, app: &App) {
+    let Some(tab) = app.board_tab() else {
+        return;
+    };
+    let board = app.board();
+    let mut title = format!("Board · {} issues", app.issues().len());
+    if !tab.lanes().is_empty() {
+        title.push_str(&format!(" · lanes: {}", tab.lanes().join(", ")));
+    }
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    if board.columns.is_empty() {
+        frame.render_widget(
+            Paragraph::new("No issues loaded").style(Style::default().fg(Color::DarkGray)),
+            inner,
+        );
+        return;
+    }
+
+    let (cursor_column, cursor_row) = tab.cursor(&board);
+    let limits = app.board_limits();
+    let now = Utc::now();
+    let count = board.columns.len() as u32;
+    let areas = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(
+            board
+                .columns
+                .iter()
+                .map(|_| Constraint::Ratio(1, count))
+                .collect::<Vec<_>>(),
+        )
+        .split(inner);
+    // Lane headers only make sense once labels split the board.
+    let show_lanes = !tab.lanes().is_empty();
+
+    for (column, name) in board.columns.iter().enumerate() {
+        let focused = column == cursor_column;
+        let count = board.counts[column];
+        let title = match limits.over_wip(name, count) {
+            Some(limit) => Span::styled(
+                format!("{name} ({count}/{limit}) over WIP"),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ),
+            None => match limits.wip_limit(name) {
+                Some(limit) => Span::raw(format!("{name} ({count}/{limit})")),
+                None => Span::raw(format!("{name} ({count})")),
+            },
+        };
+        let block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(if focused {
+                Style::default().fg(Color::Yellow)
+            } else if limits.over_wip(name, count).is_some() {
+                Style::default().fg(Color::Red)
+            } else {
+                Style::default().fg(Color::DarkGray)
+            });
+        let body = block.inner(areas[column]);
+        let width = body.width as usize;
+
+        let mut lines = Vec::new();
+        let mut card = 0;
+        let mut cursor_line = 0;
+        for lane in &board.lanes {
+            let cell = &lane.cells[column];
+            if show_lanes {
+                let label = lane.label.as_deref().unwrap_or("other");
+                lines.push(Line::from(Span::styled(
+                    truncate(&format!("── {label} ({})", cell.len()), width),
+                    Style::default()
+                        .fg(Color::Magenta)
+                        .add_modifier(Modifier::BOLD),
+                )));
+            }
+            for issue in cell {
+                let highlighted = focused && card == cursor_row;
+                if highlighted {
+                    cursor_line = lines.len();
+                }
+                let age = limits.overdue(issue, now).map(short_age);
+                lines.push(card_line(issue, width, highlighted, age));
+                card += 1;
+            }
+            // Pad so the next lane starts on the same row in every column.
+            for _ in cell.len()..lane.height() {
+                lines.push(Line::from(""));
+            }
+        }
+
+        let visible = body.height as usize;
+        let offset = cursor_line.saturating_sub(visible.saturating_sub(1)) as u16;
+        frame.render_widget(
+            Paragraph::new(lines).block(block).scroll((offset, 0)),
+            areas[column],
+        );
+    }
+}
+
+/// A card, with its age in brackets when it is past its state's threshold.
+fn card_line(
+    issue: &IssueSummary,
+    width: usize,
+    highlighted: bool,
+    overdue: Option<String>,
+) -> Line<'static> {
+    let age = overdue
+        .as_deref()
+        .map(|age| format!("[{age}] "))
+        .unwrap_or_default();
+    let text = format!(
+        "{}{} {}{}",
+        selection_marker(highlighted),
+        issue.identifier,
+        age,
+        issue.title
+    );
+    let line = Line::from(truncate(&text, width));
+    if highlighted {
+        line.style(Style::default().fg(Color::Black).bg(Color::Cyan))
+    } else if overdue.is_some() {
+        line.style(Style::default().fg(Color::Red))
+    } else {
+        line
+    }
+}
@@ -1,5 +1,8 @@
-use ratatui::layout::Rect;
+use chrono::Local;
+use linear_core::graphql::Connectivity;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 use ratatui::Frame;
 
@@ -11,6 +14,112 @@ pub fn render_filters(frame: &mut Frame, area: Rect, app: &App) {
 }
 
 pub fn render_status(frame: &mut Frame, area: Rect, app: &App) {
+    let segment = status_segment(app);
+    // The message keeps at least half the row.
+    let width = (segment.width() as u16).min(area.width / 2);
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Min(1), Constraint::Length(width)])
+        .split(area);
     let widget = Paragraph::new(app.status_text()).style(Style::default().fg(Color::Cyan));
-    frame.render_widget(widget, area);
+    frame.render_widget(widget, chunks[0]);
+    frame.render_widget(Paragraph::new(segment), chunks[1]);
+}
+
+/// Pending keys, profile, workspace, unseen errors, rate-limit headroom, queued requests, last sync,
+/// and connectivity, right of the status.
+fn status_segment(app: &App) -> Line<'static> {
+    let metrics = app.client_metrics();
+    let dim = Style::default().fg(Color::DarkGray);
+    let mut spans = Vec::new();
+    let pending = app.pending_keys();
+    if !pending.is_empty() {
+        spans.push(Span::styled(
+            format!("{pending} "),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+    spans.push(Span::styled(
+        app.profile().to_owned(),
+        Style::default().fg(Color::Gray),
+    ));
+    if let Some(workspace) = app.workspace() {
+        spans.push(Span::styled(format!("@{workspace}"), dim));
+    }
+    let unseen = app.error_log().unseen();
+    if unseen > 0 {
+        spans.push(Span::styled(" · ", dim));
+        spans.push(Span::styled(
+            format!("! {unseen} error{}", if unseen == 1 { "" } else { "s" }),
+            Style::default().fg(Color::Red),
+        ));
+    }
+
+    let limits = metrics.rate_limit.as_ref();
+    if let Some(remaining) = limits.and_then(|limits| limits.requests_remaining) {
+        let limit = limits.and_then(|limits| limits.requests_limit);
+        let style = match limit {
+            _ if remaining == 0 => Style::default().fg(Color::Red),
+            Some(limit) if remaining * 10 < limit => Style::default().fg(Color::Yellow),
+            _ => Style::default().fg(Color::Gray),
+        };
+        let mut text = match limit {
+            Some(limit) => format!("API {remaining}/{limit}"),
+            None => format!("API {remaining} left"),
+        };
+        // The colors above also get words for screen readers.
+        if crate::accessible::enabled() {
+            match limit {
+                _ if remaining == 0 => text.push_str(" (exhausted)"),
+                Some(limit) if remaining * 10 < limit => text.push_str(" (low)"),
+                _ => {}
+            }
+        }
+        spans.push(Span::styled(" · ", dim));
+        spans.push(Span::styled(text, style));
+    }
+
+    let queue = metrics.queue;
+    if queue.waiting() > 0 {
+        let style = match queue.held_until {
+            Some(_) => Style::default().fg(Color::Yellow),
+            None => Style::default().fg(Color::Gray),
+        };
+        let mut text = format!("queue {}", queue.waiting());
+        if let Some(until) = queue.held_until {
+            text.push_str(&format!(
+                " (held to {})",
+                until.with_timezone(&Local).format("%H:%M")
+            ));
+        }
+        spans.push(Span::styled(" · ", dim));
+        spans.push(Span::styled(text, style));
+    }
+
+    spans.push(Span::styled(" · ", dim));
+    spans.push(match metrics.last_success {
+        Some(at) => Span::styled(
+            format!("synced {}", at.with_timezone(&Local).format("%H:%M")),
+            Style::default().fg(Color::Gray),
+        ),
+        None => Span::styled("not synced", dim),
+    });
+    if app.live() {
+        spans.push(Span::styled(" · ", dim));
+        spans.push(Span::styled("live", Style::default().fg(Color::Green)));
+    }
+
+    let (label, color) = match metrics.connectivity {
+        Connectivity::Offline => ("offline", Color::Red),
+        // Snapshot data stays on screen until the first live page replaces it.
+        _ if app.refreshing() => ("cached", Color::Yellow),
+        Connectivity::Online => ("online", Color::Green),
+        Connectivity::Unknown => ("connecting", Color::DarkGray),
+    };
+    spans.push(Span::styled(" · ", dim));
+    spans.push(Span::styled(
+        format!("● {label} "),
+        Style::default().fg(color),
+    ));
+    Line::from(spans)
 }
@@ -0,0 +1,101 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+use textwrap::wrap;
+
+use crate::tui::app::{App, ComposeTarget};
+use crate::tui::view::util::centered_rect;
+
+pub fn render(frame: &mut Frame, area: Rect, app: &App) {
+    let Some(composer) = app.composer() else {
+        return;
+    };
+    let overlay_area = centered_rect(area.width.clamp(40, 100), area.height.clamp(8, 20), area);
+    let title = match composer.target {
+        ComposeTarget::Comment => format!("Comment on {}", composer.identifier),
+        ComposeTarget::Description => format!("Description of {}", composer.identifier),
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow));
+    let inner = block.inner(overlay_area);
+    frame.render_widget(Clear, overlay_area);
+    frame.render_widget(block, overlay_area);
+    if inner.width < 4 || inner.height < 3 {
+        return;
+    }
+
+    let suggestions = app.mention_suggestions();
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(1),
+            Constraint::Length(suggestions.len() as u16),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+
+    // Keep the end of the draft, where typing happens, in view.
+    let width = chunks[0].width.saturating_sub(1).max(1) as usize;
+    let draft = format!("{}▏", composer.text);
+    let mut lines: Vec<Line> = draft
+        .split('\n')
+        .flat_map(|paragraph| {
+            if paragraph.is_empty() {
+                vec![Line::from("")]
+            } else {
+                wrap(paragraph, width)
+                    .into_iter()
+                    .map(|line| Line::from(line.into_owned()))
+                    .collect()
+            }
+        })
+        .collect();
+    let visible = chunks[0].height as usize;
+    if lines.len() > visible {
+        lines.drain(..lines.len() - visible);
+    }
+    frame.render_widget(Paragraph::new(lines), chunks[0]);
+
+    let items: Vec<Line> = suggestions
+        .iter()
+        .enumerate()
+        .map(|(idx, member)| {
+            let style = if idx == composer.suggestion {
+                Style::default().fg(Color::Black).bg(Color::Cyan)
+            } else {
+                Style::default().fg(Color::Cyan)
+            };
+            let mut spans = vec![
+                Span::styled(format!("@{}", member.display_name), style),
+                Span::raw(format!("  {}", member.name)),
+            ];
+            if !member.active {
+                spans.push(Span::styled(
+                    "  (inactive)",
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+            Line::from(spans)
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(items), chunks[1]);
+
+    let hint = if suggestions.is_empty() {
+        "@ mention  Enter newline  Ctrl+E $EDITOR  Ctrl+S send  Esc discard"
+    } else {
+        "↑/↓ choose  Tab/Enter insert  Ctrl+S send  Esc discard"
+    };
+    frame.render_widget(
+        Paragraph::new(Span::styled(
+            hint,
+            Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::ITALIC),
+        )),
+        chunks[2],
+    );
+}
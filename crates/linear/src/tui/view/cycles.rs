@@ -0,0 +1,176 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::Frame;
+
+use linear_core::graphql::CycleSummary;
+
+use crate::tui::app::App;
+use crate::tui::cycles::{cycle_label, CyclesPane, CyclesTab};
+use crate::tui::view::projects::split_direction;
+use crate::tui::view::util::{issue_list_line, progress_bar, selection_marker};
+
+const BAR_WIDTH: usize = 10;
+
+pub fn render(frame: &mut Frame, area: Rect, app: &App) {
+    let Some(tab) = app.cycles_tab() else {
+        return;
+    };
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(1)])
+        .split(area);
+    let columns = Layout::default()
+        .direction(split_direction(app))
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(rows[0]);
+
+    render_cycles(frame, columns[0], tab, app.narrow());
+    render_issues(frame, columns[1], app, tab);
+    let hint = match tab.pane() {
+        CyclesPane::Cycles => "j/k move  Enter issues  Tab issues pane  r refresh  Y/Esc issues",
+        CyclesPane::Issues => {
+            "j/k move  Space pick  m move to active cycle  Tab cycles pane  Y/Esc issues"
+        }
+    };
+    frame.render_widget(
+        Paragraph::new(Span::styled(hint, dim().add_modifier(Modifier::ITALIC))),
+        rows[1],
+    );
+}
+
+fn render_cycles(frame: &mut Frame, area: Rect, tab: &CyclesTab, compact: bool) {
+    let block = pane_block(
+        format!("Cycles ({})", tab.cycles().len()),
+        tab.pane() == CyclesPane::Cycles,
+    );
+    let visible = block.inner(area).height as usize;
+    // Keep the cursor on screen.
+    let offset = tab.cursor().saturating_sub(visible.saturating_sub(1));
+    let lines: Vec<Line> = tab
+        .cycles()
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(visible)
+        .map(|(idx, cycle)| cycle_line(cycle, idx == tab.cursor(), compact))
+        .collect();
+    let body = if lines.is_empty() {
+        vec![Line::from(Span::styled("No cycles loaded", dim()))]
+    } else {
+        lines
+    };
+    frame.render_widget(Paragraph::new(body).block(block), area);
+}
+
+/// A cycle row; `compact` leaves out the dates.
+fn cycle_line(cycle: &CycleSummary, highlighted: bool, compact: bool) -> Line<'static> {
+    let team = cycle
+        .team
+        .as_ref()
+        .map(|team| team.key.clone())
+        .unwrap_or_else(|| "-".into());
+    let name_style = if highlighted {
+        Style::default().fg(Color::Black).bg(Color::Cyan)
+    } else {
+        Style::default()
+    };
+    let mut spans = vec![
+        Span::raw(selection_marker(highlighted)),
+        Span::styled(format!("{team} {}", cycle_label(cycle)), name_style),
+    ];
+    if !compact {
+        spans.push(Span::styled(
+            format!(
+                "  {} → {}",
+                day(cycle.starts_at.as_deref()),
+                day(cycle.ends_at.as_deref())
+            ),
+            dim(),
+        ));
+    }
+    if cycle.is_active {
+        spans.push(Span::styled("  active", Style::default().fg(Color::Yellow)));
+    }
+    match cycle.issue_counts() {
+        Some((completed, scope)) => {
+            let progress = (scope > 0).then(|| f64::from(completed) / f64::from(scope));
+            spans.push(Span::styled(
+                format!("  {}", progress_bar(progress, BAR_WIDTH)),
+                Style::default().fg(Color::Green),
+            ));
+            spans.push(Span::raw(format!(" {completed}/{scope} done")));
+        }
+        None => spans.push(Span::styled("  no scope data", dim())),
+    }
+    Line::from(spans)
+}
+
+fn render_issues(frame: &mut Frame, area: Rect, app: &App, tab: &CyclesTab) {
+    let title = match (tab.opened(), tab.picked_count()) {
+        (None, _) => "Issues".to_owned(),
+        (Some(cycle), 0) => format!("{} ({})", cycle_label(cycle), tab.issues().len()),
+        (Some(cycle), picked) => format!(
+            "{} ({}, {picked} picked)",
+            cycle_label(cycle),
+            tab.issues().len()
+        ),
+    };
+    let focused = tab.pane() == CyclesPane::Issues;
+    let block = pane_block(title, focused);
+    let visible = block.inner(area).height as usize;
+    let offset = tab.issue_cursor().saturating_sub(visible.saturating_sub(1));
+    let lines: Vec<Line> = match tab.opened() {
+        None => vec![Line::from(Span::styled(
+            "Press Enter to load the highlighted cycle's issues",
+            dim(),
+        ))],
+        Some(_) if tab.issues().is_empty() => {
+            vec![Line::from(Span::styled("No issues in this cycle", dim()))]
+        }
+        Some(_) => tab
+            .issues()
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(visible)
+            .map(|(idx, issue)| {
+                let marker = if tab.is_picked(issue) { "[x] " } else { "[ ] " };
+                let highlighted = focused && idx == tab.issue_cursor();
+                let mut line = issue_list_line(issue, None, app.stale_days(issue));
+                line.spans.insert(0, Span::raw(marker));
+                line.spans
+                    .insert(0, Span::raw(selection_marker(highlighted)));
+                if focused && idx == tab.issue_cursor() {
+                    line = line.style(Style::default().fg(Color::Black).bg(Color::Cyan));
+                }
+                line
+            })
+            .collect(),
+    };
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// The date part of an ISO timestamp.
+fn day(timestamp: Option<&str>) -> String {
+    timestamp
+        .map(|value| value.chars().take(10).collect())
+        .unwrap_or_else(|| "-".into())
+}
+
+fn pane_block(title: String, focused: bool) -> Block<'static> {
+    let style = if focused {
+        Style::default().fg(Color::Cyan)
+    } else {
+        dim()
+    };
+    Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(style)
+}
+
+fn dim() -> Style {
+    Style::default().fg(Color::DarkGray)
+}
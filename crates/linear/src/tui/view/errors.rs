@@ -0,0 +1,114 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+use ratatui::Frame;
+
+use crate::i18n::{tr, tr_args};
+use crate::tui::app::App;
+use crate::tui::view::util::{centered_rect, selection_marker};
+
+pub fn render(frame: &mut Frame, area: Rect, app: &App) {
+    if !app.show_error_console() {
+        return;
+    }
+    let log = app.error_log();
+    let popup = centered_rect(
+        area.width.saturating_sub(4).min(100),
+        area.height.saturating_sub(2),
+        area,
+    );
+    frame.render_widget(Clear, popup);
+    let block = Block::default()
+        .title(tr_args(
+            "tui-errors-title",
+            &[("count", &log.entries().len().to_string())],
+        ))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Red));
+    let inner = block.inner(popup);
+    frame.render_widget(block, popup);
+
+    if log.entries().is_empty() {
+        frame.render_widget(
+            Paragraph::new(tr("tui-errors-empty")).style(Style::default().fg(Color::Gray)),
+            inner,
+        );
+        return;
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(50), Constraint::Min(3)])
+        .split(inner);
+
+    let visible = chunks[0].height as usize;
+    let start = log.index().saturating_sub(visible.saturating_sub(1));
+    let lines: Vec<Line> = log
+        .entries()
+        .iter()
+        .enumerate()
+        .skip(start)
+        .take(visible)
+        .map(|(idx, entry)| {
+            let mut spans = vec![
+                Span::raw(selection_marker(idx == log.index())),
+                Span::styled(
+                    format!("{} ", entry.at.format("%H:%M:%S")),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                Span::styled(entry.operation.clone(), Style::default().fg(Color::Red)),
+                Span::raw(format!(
+                    "  {}",
+                    entry.message.lines().next().unwrap_or_default()
+                )),
+            ];
+            if entry.retry.is_some() {
+                let retry = if crate::accessible::enabled() {
+                    "  (retryable)"
+                } else {
+                    "  ↻"
+                };
+                spans.push(Span::styled(retry, Style::default().fg(Color::Yellow)));
+            }
+            let line = Line::from(spans);
+            if idx == log.index() {
+                line.style(Style::default().fg(Color::Black).bg(Color::Cyan))
+            } else {
+                line
+            }
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines), chunks[0]);
+
+    if let Some(entry) = log.selected() {
+        let retry = if entry.retry.is_some() {
+            "Enter or r retries"
+        } else {
+            "Not retryable"
+        };
+        let mut lines = vec![Line::from(vec![
+            Span::styled(
+                format!("{} ", entry.at.format("%Y-%m-%d %H:%M:%S")),
+                Style::default().fg(Color::DarkGray),
+            ),
+            Span::styled(
+                format!("Failed to {}", entry.operation),
+                Style::default().fg(Color::Red),
+            ),
+            Span::styled(format!(" · {retry}"), Style::default().fg(Color::Yellow)),
+        ])];
+        lines.extend(
+            entry
+                .message
+                .lines()
+                .map(|line| Line::from(line.to_owned())),
+        );
+        frame.render_widget(
+            Paragraph::new(lines)
+                .wrap(Wrap { trim: false })
+                .block(Block::default().borders(Borders::TOP)),
+            chunks[1],
+        );
+    }
+}
@@ -0,0 +1,201 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+use ratatui::Frame;
+
+use crate::tui::app::App;
+use crate::tui::explorer::{Explorer, ExplorerInput, ExplorerPane};
+
+pub fn render(frame: &mut Frame, area: Rect, app: &App) {
+    let Some(explorer) = app.explorer() else {
+        return;
+    };
+    let block = Block::default()
+        .title(format!("API explorer — {}", explorer.breadcrumb()))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow));
+    let inner = block.inner(area);
+    frame.render_widget(Clear, area);
+    frame.render_widget(block, area);
+    if inner.width < 20 || inner.height < 6 {
+        return;
+    }
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(inner);
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(45), Constraint::Percentage(55)])
+        .split(rows[0]);
+
+    if explorer.schema().is_none() {
+        frame.render_widget(Paragraph::new("Loading schema…"), columns[0]);
+    } else {
+        render_fields(frame, columns[0], explorer);
+    }
+    render_query(frame, columns[1], explorer);
+    frame.render_widget(
+        Paragraph::new(Span::styled(
+            hint(explorer),
+            dim().add_modifier(Modifier::ITALIC),
+        )),
+        rows[1],
+    );
+}
+
+fn render_fields(frame: &mut Frame, area: Rect, explorer: &Explorer) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(area.height / 3)])
+        .split(area);
+
+    let fields = explorer.visible_fields();
+    let title = match explorer.input() {
+        Some(ExplorerInput::Filter) => format!("Fields /{}▏", explorer.filter()),
+        _ if !explorer.filter().is_empty() => format!("Fields /{}", explorer.filter()),
+        _ => "Fields".to_owned(),
+    };
+    let block = pane_block(title, explorer.pane() == ExplorerPane::Fields);
+    let visible = block.inner(chunks[0]).height as usize;
+    // Keep the cursor on screen.
+    let offset = explorer.cursor().saturating_sub(visible.saturating_sub(1));
+    let lines: Vec<Line> = fields
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(visible)
+        .map(|(idx, field)| {
+            let marker = if explorer.is_selected(field) {
+                "[x]"
+            } else {
+                "[ ]"
+            };
+            let arrow = if explorer.is_composite(field) {
+                " ›"
+            } else {
+                ""
+            };
+            let name_style = if idx == explorer.cursor() {
+                Style::default().fg(Color::Black).bg(Color::Cyan)
+            } else {
+                Style::default()
+            };
+            Line::from(vec![
+                Span::raw(format!("{marker} ")),
+                Span::styled(format!("{}{arrow}", field.name), name_style),
+                Span::styled(format!("  {}", field.ty), dim()),
+            ])
+        })
+        .collect();
+    let body = if lines.is_empty() {
+        vec![Line::from(Span::styled("No matching fields", dim()))]
+    } else {
+        lines
+    };
+    frame.render_widget(Paragraph::new(body).block(block), chunks[0]);
+
+    let mut detail: Vec<Line> = Vec::new();
+    if let Some(field) = explorer.highlighted() {
+        detail.push(Line::from(Span::styled(
+            format!("{}: {}", field.name, field.ty),
+            Style::default().add_modifier(Modifier::BOLD),
+        )));
+        if let Some(description) = field.description.as_deref() {
+            detail.push(Line::from(description.to_owned()));
+        }
+        for arg in &field.args {
+            let default = arg
+                .default_value
+                .as_deref()
+                .map(|value| format!(" = {value}"))
+                .unwrap_or_default();
+            detail.push(Line::from(vec![
+                Span::styled(format!("  {}", arg.name), Style::default().fg(Color::Cyan)),
+                Span::raw(format!(": {}{default}", arg.ty)),
+            ]));
+        }
+    }
+    frame.render_widget(
+        Paragraph::new(detail)
+            .wrap(Wrap { trim: false })
+            .block(Block::default().borders(Borders::TOP)),
+        chunks[1],
+    );
+}
+
+fn render_query(frame: &mut Frame, area: Rect, explorer: &Explorer) {
+    let document = explorer.document();
+    let query_height = document
+        .as_deref()
+        .map_or(3, |document| document.lines().count() as u16 + 2)
+        .min(area.height / 2);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(query_height), Constraint::Min(3)])
+        .split(area);
+
+    let query: Vec<Line> = match (explorer.input(), document) {
+        (Some(ExplorerInput::Arguments { path, text }), _) => vec![
+            Line::from(format!("Arguments for {}:", path.join("."))),
+            Line::from(format!("{text}▏")),
+        ],
+        (_, Some(document)) => document
+            .lines()
+            .map(|line| Line::from(line.to_owned()))
+            .collect(),
+        (_, None) => vec![Line::from(Span::styled(
+            "Pick fields with Space to build a query",
+            dim(),
+        ))],
+    };
+    frame.render_widget(
+        Paragraph::new(query).block(pane_block("Query".into(), false)),
+        chunks[0],
+    );
+
+    let result: Vec<Line> = explorer
+        .result()
+        .iter()
+        .skip(explorer.scroll())
+        .map(|line| Line::from(line.clone()))
+        .collect();
+    let title = match explorer.result().len() {
+        0 => "Result".to_owned(),
+        total => format!("Result {}/{}", explorer.scroll() + 1, total),
+    };
+    frame.render_widget(
+        Paragraph::new(result).block(pane_block(title, explorer.pane() == ExplorerPane::Result)),
+        chunks[1],
+    );
+}
+
+fn pane_block(title: String, focused: bool) -> Block<'static> {
+    let style = if focused {
+        Style::default().fg(Color::Cyan)
+    } else {
+        dim()
+    };
+    Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(style)
+}
+
+fn hint(explorer: &Explorer) -> &'static str {
+    match (explorer.input(), explorer.pane()) {
+        (Some(ExplorerInput::Filter), _) => "Type to filter  Enter keep  Esc clear",
+        (Some(ExplorerInput::Arguments { .. }), _) => {
+            "e.g. first: 10, filter: { title: { contains: \"bug\" } }  Enter save  Esc cancel"
+        }
+        (None, ExplorerPane::Fields) => {
+            "Space pick  Enter/l open  h back  a args  / filter  x run  c clear  Tab result  Esc close"
+        }
+        (None, ExplorerPane::Result) => "j/k PgUp/PgDn scroll  x run  Tab fields  Esc close",
+    }
+}
+
+fn dim() -> Style {
+    Style::default().fg(Color::DarkGray)
+}
@@ -7,26 +7,52 @@ use ratatui::Frame;
 use crate::tui::app::{App, StatusTab};
 
 pub fn render(frame: &mut Frame, area: Rect, app: &App) {
-    let chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
+    // Narrow terminals drop the selection box to keep the filters readable.
+    let constraints = if app.narrow() {
+        [
+            Constraint::Percentage(36),
+            Constraint::Percentage(34),
+            Constraint::Percentage(30),
+            Constraint::Length(0),
+        ]
+    } else {
+        [
             Constraint::Percentage(30),
             Constraint::Percentage(28),
             Constraint::Percentage(27),
             Constraint::Percentage(15),
-        ])
+        ]
+    };
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(constraints)
         .split(area);
 
     let team_line = Line::from(vec![
         Span::styled("Team ", Style::default().fg(Color::Gray)),
         Span::raw(app.current_team_label()),
     ]);
-    let state_line = Line::from(vec![
+    let mut state_line = Line::from(vec![
         Span::styled("State ", Style::default().fg(Color::Gray)),
         Span::raw(app.current_state_label()),
     ]);
-    let filters = Paragraph::new(vec![team_line, state_line])
-        .block(Block::default().title("Context").borders(Borders::ALL));
+    if let Some(filter) = app.assignee_filter() {
+        state_line.push_span(Span::styled(
+            "  Assignee ",
+            Style::default().fg(Color::Gray),
+        ));
+        state_line.push_span(Span::styled(
+            filter.label().to_owned(),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    let filters = Paragraph::new(vec![team_line, state_line]).block(
+        Block::default()
+            .title(format!("Context · {}", app.profile()))
+            .borders(Borders::ALL),
+    );
     frame.render_widget(filters, chunks[0]);
 
     let project_style = if app.project_filter_index().is_some() {
@@ -43,10 +69,10 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
     let project_hint = Line::from(vec![
         Span::styled("p", Style::default().fg(Color::Gray)),
         Span::raw(" next  "),
-        Span::styled("Shift+p", Style::default().fg(Color::Gray)),
-        Span::raw(" prev  "),
         Span::styled("Ctrl+p", Style::default().fg(Color::Gray)),
-        Span::raw(" clear"),
+        Span::raw(" clear  "),
+        Span::styled("P", Style::default().fg(Color::Gray)),
+        Span::raw(" tab"),
     ]);
     let project_widget = Paragraph::new(vec![project_line, project_hint]).block(
         Block::default()
@@ -81,6 +107,9 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
     let status_widget = Paragraph::new(vec![status_line, status_hint])
         .block(Block::default().title("Status Tabs").borders(Borders::ALL));
     frame.render_widget(status_widget, chunks[2]);
+    if app.narrow() {
+        return;
+    }
 
     let contains_line = Line::from(vec![
         Span::styled("Contains ", Style::default().fg(Color::Gray)),
@@ -97,7 +126,7 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
     let actions_line = Line::from(vec![
         Span::styled("o", Style::default().fg(Color::Gray)),
         Span::raw(" overlay  "),
-        Span::styled("y", Style::default().fg(Color::Gray)),
+        Span::styled("Y", Style::default().fg(Color::Gray)),
         Span::raw(" cycles  "),
         Span::styled("Ctrl+Enter", Style::default().fg(Color::Gray)),
         Span::raw(" automation"),
@@ -1,16 +1,27 @@
+use linear_core::config::LayoutPreset;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::widgets::Clear;
 use ratatui::Frame;
 
 use crate::tui::app::App;
 
+mod actions;
+mod board;
 mod bottom;
+mod composer;
+mod cycles;
+mod errors;
+mod explorer;
 mod filter_bar;
 mod overlays;
 mod palette;
+mod projects;
 mod sidebar;
 pub mod util;
 mod workspace;
 
+const SIDEBAR_WIDTH: u16 = 24;
+
 pub fn render_app(frame: &mut Frame, app: &App) {
     let frame_size = frame.size();
     let layout = Layout::default()
@@ -27,19 +38,49 @@ pub fn render_app(frame: &mut Frame, app: &App) {
     filter_bar::render(frame, layout[0], app);
 
     let content_area = layout[1];
-    let content_chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Length(24), Constraint::Min(1)])
-        .split(content_area);
+    let overlay_area = if app.projects_tab().is_some() {
+        projects::render(frame, content_area, app);
+        content_area
+    } else if app.cycles_tab().is_some() {
+        cycles::render(frame, content_area, app);
+        content_area
+    } else if app.board_tab().is_some() {
+        board::render(frame, content_area, app);
+        content_area
+    } else if app.narrow() {
+        workspace::render(frame, content_area, app);
+        if app.sidebar_drawer_open() {
+            let drawer = Rect {
+                width: content_area.width.min(SIDEBAR_WIDTH + 4),
+                ..content_area
+            };
+            frame.render_widget(Clear, drawer);
+            sidebar::render(frame, drawer, app);
+        }
+        content_area
+    } else if app.layout().layout == LayoutPreset::Zen {
+        workspace::render(frame, content_area, app);
+        content_area
+    } else {
+        let content_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(SIDEBAR_WIDTH), Constraint::Min(1)])
+            .split(content_area);
 
-    sidebar::render(frame, content_chunks[0], app);
-    workspace::render(frame, content_chunks[1], app);
+        sidebar::render(frame, content_chunks[0], app);
+        workspace::render(frame, content_chunks[1], app);
+        content_chunks[1]
+    };
 
     bottom::render_filters(frame, layout[2], app);
     bottom::render_status(frame, layout[3], app);
     palette::render(frame, layout[4], app);
 
-    overlays::render(frame, content_chunks[1], app);
+    overlays::render(frame, overlay_area, app);
+    composer::render(frame, overlay_area, app);
+    actions::render(frame, overlay_area, app);
+    explorer::render(frame, content_area, app);
+    errors::render(frame, content_area, app);
 }
 
 // helper for future responsive sizing
@@ -1,6 +1,6 @@
 use ratatui::layout::Rect;
-use ratatui::style::{Color, Style};
-use ratatui::text::Line;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
 use ratatui::Frame;
 
@@ -14,30 +14,52 @@ pub fn render(frame: &mut Frame, base_area: Rect, app: &App) {
     if app.show_projects_overlay() {
         render_projects(frame, base_area, app);
     }
-    if app.show_cycles_overlay() {
-        render_cycles(frame, base_area, app);
+    if app.show_profiles_overlay() {
+        render_profiles(frame, base_area, app);
     }
 }
 
 fn render_help(frame: &mut Frame, area: Rect) {
-    let overlay_width = area.width.min(80).max(40);
-    let overlay_height = area.height.min(12).max(7);
+    let overlay_width = area.width.clamp(40, 80);
+    let overlay_height = area.height.clamp(7, 12);
     let overlay_area = centered_rect(overlay_width, overlay_height, area);
     let lines = vec![
         Line::from("Navigation:"),
         Line::from("  j/k or arrow keys  move selection"),
-        Line::from("  tab cycles focus between issues/teams/states"),
+        Line::from("  5j/5k counts  gg/G first/last  5G fifth  m a mark  ' a jump  '' back"),
+        Line::from("  tab cycles focus between issues/teams/states/details"),
+        Line::from("  > next detail tab  < previous detail tab"),
         Line::from("Actions:"),
-        Line::from("  r refresh issues   c clear filters   q exit"),
+        Line::from("  r refresh changed issues   R full reload   c clear filters   q exit"),
         Line::from("  ] next page  [ previous page"),
-        Line::from("  p next project  Shift+p prev  Ctrl+p clear  o overlay"),
+        Line::from("  p next project  :project prev  Ctrl+p clear  o overlay"),
+        Line::from("  P projects tab: Enter issues  s state  d target date  l lead"),
+        Line::from("  Y cycles tab: Enter issues  Tab pane  Space pick  m move to active"),
+        Line::from("  B board: h/l columns  j/k cards  Enter opens  :lanes label <name>"),
+        Line::from("  y y copy key  y u copy URL  y b copy branch name"),
+        Line::from("  z next layout  | side by side  _ stacked  +/- resize split  = reset"),
+        Line::from("  under 100 columns: b sidebar drawer  Enter full-screen detail  Esc back"),
         Line::from("  1/2/3/4 set status tab  Ctrl+[ prev  Ctrl+] next"),
-        Line::from("  t / s cycle team or state filters"),
+        Line::from("  t / s cycle team or state filters  M only my issues"),
+        Line::from("  w switch profile  :profile <name> switches directly"),
+        Line::from("  ! error console: j/k move  Enter/r retry  c clear  ! or Esc close"),
         Line::from("  view next/prev/first/last/<key> jumps to an issue"),
+        Line::from("  export md|html [path] writes the current list as markdown or HTML"),
+        Line::from("  . actions: o open  c/u/b copy key/URL/branch  s state  m assign me  l label"),
+        Line::from("Editing:"),
+        Line::from("  Tab to details, j/k pick priority/estimate/due, Enter edit"),
+        Line::from("  Enter saves, Esc cancels, empty estimate or due clears"),
+        Line::from("  a comment  e edit description  E description in $EDITOR  @ mentions"),
+        Line::from("  in a draft: Ctrl+E moves it to $EDITOR and back"),
+        Line::from("API explorer (:api, :api refresh reloads the schema):"),
+        Line::from("  j/k move  Enter/l open type  h back  Space pick  a arguments"),
+        Line::from("  / filter  x run  Tab result pane  c clear  Esc close"),
         Line::from("Automation:"),
         Line::from("  Ctrl+Enter trigger CLI agent for active issue"),
         Line::from("Filters:"),
-        Line::from("  / opens contains filter  :team/:state/:project/:status"),
+        Line::from("  / opens contains filter  :team/:state/:project/:status/:group"),
+        Line::from("  :assignee <name|me|none|clear> filters by a team member"),
+        Line::from("  :sort <updated|created|priority>[:asc|:desc] orders the list"),
         Line::from("  clear resets filters  contains clear drops title filter"),
         Line::from("  help or :help opens this overlay"),
         Line::from("Close help with ? or Esc"),
@@ -50,8 +72,8 @@ fn render_help(frame: &mut Frame, area: Rect) {
 }
 
 fn render_projects(frame: &mut Frame, area: Rect, app: &App) {
-    let overlay_width = area.width.min(90).max(50);
-    let overlay_height = area.height.min(14).max(8);
+    let overlay_width = area.width.clamp(50, 90);
+    let overlay_height = area.height.clamp(8, 14);
     let overlay_area = centered_rect(overlay_width, overlay_height, area);
     let mut lines = Vec::new();
     if app.projects().is_empty() {
@@ -82,34 +104,26 @@ fn render_projects(frame: &mut Frame, area: Rect, app: &App) {
     frame.render_widget(widget, overlay_area);
 }
 
-fn render_cycles(frame: &mut Frame, area: Rect, app: &App) {
-    let overlay_width = area.width.min(80).max(40);
-    let overlay_height = area.height.min(12).max(7);
+fn render_profiles(frame: &mut Frame, area: Rect, app: &App) {
+    let overlay_width = area.width.clamp(30, 60);
+    let overlay_height = (app.profile_options().len() as u16 + 3).clamp(5, area.height.max(5));
     let overlay_area = centered_rect(overlay_width, overlay_height, area);
-    let mut lines = Vec::new();
-    if app.cycles().is_empty() {
-        lines.push(Line::from("No cycles available (press y or Esc to close)"));
-    } else {
-        lines.push(Line::from("Recent cycles (press y or Esc to close):"));
-        for cycle in app.cycles() {
-            lines.push(Line::from(format!(
-                "#{} {} {} → {} [{}]",
-                cycle.number,
-                cycle
-                    .team
-                    .as_ref()
-                    .map(|t| t.key.clone())
-                    .unwrap_or_else(|| "-".into()),
-                cycle.starts_at.as_deref().unwrap_or("-"),
-                cycle.ends_at.as_deref().unwrap_or("-"),
-                cycle.state.as_deref().unwrap_or("-"),
-            )));
-        }
+    let mut lines = vec![Line::from("Enter switches, w or Esc closes:")];
+    for (index, name) in app.profile_options().iter().enumerate() {
+        let marker = if name == app.profile() { "*" } else { " " };
+        let style = if index == app.profile_option_index() {
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Magenta)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        lines.push(Line::from(Span::styled(format!("{marker} {name}"), style)));
     }
     let widget = Paragraph::new(lines)
-        .block(Block::default().title("Cycles").borders(Borders::ALL))
-        .style(Style::default().fg(Color::LightBlue))
-        .wrap(Wrap { trim: true });
+        .block(Block::default().title("Profiles").borders(Borders::ALL))
+        .style(Style::default().fg(Color::Magenta));
     frame.render_widget(Clear, overlay_area);
     frame.render_widget(widget, overlay_area);
 }
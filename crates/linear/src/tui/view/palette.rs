@@ -8,18 +8,29 @@ use crate::tui::app::App;
 
 const KEYMAP_TEXT: &str = "\
 Navigation  j/k or arrow keys move selection\n\
-Focus       Tab cycles issues -> teams -> states\n\
-Refresh     r reload issues  c clear filters\n\
-Project     p next  Shift+p prev  Ctrl+p clear  o overlay\n\
+Vim         5j 5k counts  gg first  G last  5G fifth  m a mark  ' a jump  '' back\n\
+Focus       Tab cycles issues -> teams -> states -> details\n\
+Edit        details focus: j/k pick field  Enter edit  Esc cancel\n\
+Write       a comment  e description  E in $EDITOR  @ mention  Ctrl+S send\n\
+Refresh     r changed issues  R full reload  c clear filters\n\
+Project     p next  :project prev  Ctrl+p clear  o overlay\n\
+Projects    P projects tab: Enter issues  s state  d target  l lead\n\
 Status      1 Todo 2 Doing 3 Done 4 All  Ctrl+[ prev  Ctrl+] next\n\
-Filters     / contains filter  :team|:state|:project|:status\n\
+Filters     / contains filter  :team|:state|:project|:status|:group\n\
 Paging      ] next page  [ previous page  :page <n|next|prev>\n\
 Jump        view next|prev|first|last|<key>\n\
+Export      :export md|html [path] writes the current list to a file\n\
 Command     : enter palette  Esc exits palette\n\
-Cycles      y show team cycles\n\
+Cycles      Y cycles tab: Enter issues  Space pick  m move to active cycle\n\
+Board       B board by state: h/l j/k move  Enter open  :lanes label <name>\n\
+Actions     . menu: open  copy  state  assign me  label  :action <name>\n\
+Copy        y y key  y u URL  y b branch name\n\
+Layout      z next preset  | side  _ stacked  +/- resize  = reset\n\
+Profile     w pick profile  :profile <name> switch workspace\n\
 Automation  Ctrl+Enter run CLI agent\n\
+Errors      ! error console: j/k move  Enter/r retry  c clear  :errors\n\
 Help        ? toggle overlay  :help command\n\
-Quit        q or Esc";
+Quit        q or Esc  Ctrl+C cancels and exits";
 
 pub fn render(frame: &mut Frame, area: Rect, app: &App) {
     let chunks = Layout::default()
@@ -54,10 +65,11 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
     let mut overlay_y = chunks[1].y.saturating_sub(1);
 
     if !history_lines.is_empty() {
-        let history_height = history_lines.len() as u16;
+        // Both lists grow upwards from the prompt and are cut off at the top of the screen.
+        let history_height = (history_lines.len() as u16).min(overlay_y);
         let history_area = Rect {
             x: chunks[1].x,
-            y: chunks[1].y.saturating_sub(history_height + 1),
+            y: overlay_y - history_height,
             width: chunks[1].width,
             height: history_height,
         };
@@ -69,10 +81,10 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
     }
 
     if !suggestions_lines.is_empty() {
-        let suggestions_height = suggestions_lines.len() as u16;
+        let suggestions_height = (suggestions_lines.len() as u16).min(overlay_y);
         let suggestions_area = Rect {
             x: chunks[1].x,
-            y: overlay_y.saturating_sub(suggestions_height),
+            y: overlay_y - suggestions_height,
             width: chunks[1].width,
             height: suggestions_height,
         };
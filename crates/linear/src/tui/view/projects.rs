@@ -0,0 +1,248 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+use ratatui::Frame;
+
+use crate::tui::app::App;
+use crate::tui::projects::{ProjectEditor, ProjectsTab, PROJECT_STATES};
+use crate::tui::view::util::{centered_rect, issue_list_line, progress_bar, selection_marker};
+
+const BAR_WIDTH: usize = 10;
+
+pub fn render(frame: &mut Frame, area: Rect, app: &App) {
+    let Some(tab) = app.projects_tab() else {
+        return;
+    };
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(1)])
+        .split(area);
+    let columns = Layout::default()
+        .direction(split_direction(app))
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(rows[0]);
+
+    render_list(frame, columns[0], tab, app.narrow());
+    render_detail(frame, columns[1], app, tab);
+    frame.render_widget(
+        Paragraph::new(Span::styled(
+            hint(tab),
+            dim().add_modifier(Modifier::ITALIC),
+        )),
+        rows[1],
+    );
+    render_editor(frame, columns[1], tab);
+}
+
+/// Narrow terminals stack the two panes instead of placing them side by side.
+pub(super) fn split_direction(app: &App) -> Direction {
+    if app.narrow() {
+        Direction::Vertical
+    } else {
+        Direction::Horizontal
+    }
+}
+
+fn render_list(frame: &mut Frame, area: Rect, tab: &ProjectsTab, compact: bool) {
+    let block = Block::default()
+        .title(format!("Projects ({})", tab.projects().len()))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    let inner = block.inner(area);
+    let visible = inner.height as usize;
+    // Keep the cursor on screen.
+    let offset = tab.cursor().saturating_sub(visible.saturating_sub(1));
+    // The state column goes first when space is short.
+    let state_width = if compact { 0 } else { 11 };
+    let name_width = (inner.width as usize)
+        .saturating_sub(BAR_WIDTH + state_width + 6)
+        .max(8);
+    let lines: Vec<Line> = tab
+        .projects()
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(visible)
+        .map(|(idx, project)| {
+            let name_style = if idx == tab.cursor() {
+                Style::default().fg(Color::Black).bg(Color::Cyan)
+            } else {
+                Style::default()
+            };
+            let mut spans = vec![
+                Span::raw(selection_marker(idx == tab.cursor())),
+                Span::styled(
+                    format!("{:<name_width$}", truncate(&project.name, name_width)),
+                    name_style,
+                ),
+            ];
+            let state = if compact {
+                String::from(" ")
+            } else {
+                format!(" {:<10}", project.state.as_deref().unwrap_or("-"))
+            };
+            spans.push(Span::styled(state, dim()));
+            spans.push(Span::styled(
+                progress_bar(project.progress, BAR_WIDTH),
+                Style::default().fg(Color::Green),
+            ));
+            Line::from(spans)
+        })
+        .collect();
+    let body = if lines.is_empty() {
+        vec![Line::from(Span::styled("No projects loaded", dim()))]
+    } else {
+        lines
+    };
+    frame.render_widget(Paragraph::new(body).block(block), area);
+}
+
+fn render_detail(frame: &mut Frame, area: Rect, app: &App, tab: &ProjectsTab) {
+    let block = Block::default().title("Project").borders(Borders::ALL);
+    let Some(project) = tab.selected() else {
+        frame.render_widget(block, area);
+        return;
+    };
+    let detail = tab.detail();
+    let lead = project
+        .lead
+        .as_ref()
+        .and_then(|lead| lead.display_name.clone().or_else(|| lead.name.clone()))
+        .unwrap_or_else(|| "-".into());
+    let mut lines = vec![
+        Line::from(Span::styled(
+            project.name.clone(),
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        field_line("State", project.state.as_deref().unwrap_or("-")),
+        field_line("Lead", &lead),
+        field_line("Target", project.target_date.as_deref().unwrap_or("-")),
+        field_line("Progress", &progress_bar(project.progress, BAR_WIDTH * 2)),
+    ];
+    if let Some(detail) = detail {
+        let teams: Vec<&str> = detail.teams.iter().map(|team| team.key.as_str()).collect();
+        lines.push(field_line("Teams", &teams.join(", ")));
+    }
+    if let Some(url) = project.url.as_deref() {
+        lines.push(field_line("URL", url));
+    }
+    lines.push(Line::from(""));
+    match detail {
+        Some(_) if tab.issues().is_empty() => {
+            lines.push(Line::from(Span::styled("No issues in this project", dim())));
+        }
+        Some(_) => {
+            lines.push(Line::from(Span::styled(
+                format!("Issues ({})", tab.issues().len()),
+                Style::default().add_modifier(Modifier::BOLD),
+            )));
+            lines.extend(
+                tab.issues()
+                    .iter()
+                    .map(|issue| issue_list_line(issue, None, app.stale_days(issue))),
+            );
+        }
+        None => lines.push(Line::from(Span::styled(
+            "Press Enter to load this project's issues",
+            dim(),
+        ))),
+    }
+    frame.render_widget(
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(block),
+        area,
+    );
+}
+
+fn render_editor(frame: &mut Frame, area: Rect, tab: &ProjectsTab) {
+    let Some(editor) = tab.editor() else {
+        return;
+    };
+    let (title, lines): (&str, Vec<Line>) = match editor {
+        ProjectEditor::State { index } => (
+            "State",
+            PROJECT_STATES
+                .iter()
+                .enumerate()
+                .map(|(idx, state)| choice_line(state, idx == *index))
+                .collect(),
+        ),
+        ProjectEditor::TargetDate { input } => (
+            "Target date",
+            vec![
+                Line::from(format!("{input}▏")),
+                Line::from(Span::styled("YYYY-MM-DD, today, tomorrow or +N", dim())),
+            ],
+        ),
+        ProjectEditor::Lead { members, index } => {
+            let visible = area.height.saturating_sub(6) as usize;
+            let offset = index.saturating_sub(visible.saturating_sub(1));
+            (
+                "Lead",
+                members
+                    .iter()
+                    .enumerate()
+                    .skip(offset)
+                    .take(visible)
+                    .map(|(idx, member)| {
+                        let label = format!("{} ({})", member.display_name, member.name);
+                        choice_line(&label, idx == *index)
+                    })
+                    .collect(),
+            )
+        }
+    };
+    let height = lines.len() as u16 + 2;
+    let popup = centered_rect(area.width.saturating_sub(4).min(48), height, area);
+    frame.render_widget(Clear, popup);
+    frame.render_widget(
+        Paragraph::new(lines).block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Yellow)),
+        ),
+        popup,
+    );
+}
+
+fn choice_line(label: &str, selected: bool) -> Line<'static> {
+    if selected {
+        Line::from(Span::styled(
+            format!("> {label}"),
+            Style::default().fg(Color::Black).bg(Color::Cyan),
+        ))
+    } else {
+        Line::from(format!("  {label}"))
+    }
+}
+
+fn field_line(label: &str, value: &str) -> Line<'static> {
+    Line::from(vec![
+        Span::styled(format!("{label:<9}"), dim()),
+        Span::raw(value.to_owned()),
+    ])
+}
+
+fn hint(tab: &ProjectsTab) -> &'static str {
+    match tab.editor() {
+        Some(ProjectEditor::TargetDate { .. }) => "Type a date  Enter save  Esc cancel",
+        Some(_) => "j/k move  Enter save  Esc cancel",
+        None => "j/k move  Enter issues  s state  d target date  l lead  r refresh  P/Esc issues",
+    }
+}
+
+fn truncate(text: &str, width: usize) -> String {
+    if text.chars().count() <= width {
+        return text.to_owned();
+    }
+    let mut out: String = text.chars().take(width.saturating_sub(1)).collect();
+    out.push('…');
+    out
+}
+
+fn dim() -> Style {
+    Style::default().fg(Color::DarkGray)
+}
@@ -4,6 +4,7 @@ use ratatui::widgets::{Block, Borders, List, ListItem, ListState};
 use ratatui::Frame;
 
 use crate::tui::app::{App, Focus};
+use crate::tui::view::util::selection_marker;
 
 pub fn render(frame: &mut Frame, area: Rect, app: &App) {
     let panels = Layout::default()
@@ -31,7 +32,8 @@ fn render_team_list(frame: &mut Frame, area: Rect, app: &App) {
     };
     let list = List::new(items)
         .block(Block::default().title("Teams").borders(Borders::ALL))
-        .highlight_style(highlight);
+        .highlight_style(highlight)
+        .highlight_symbol(selection_marker(true));
     frame.render_stateful_widget(list, area, &mut state);
 }
 
@@ -51,6 +53,7 @@ fn render_state_list(frame: &mut Frame, area: Rect, app: &App) {
     };
     let list = List::new(items)
         .block(Block::default().title("States").borders(Borders::ALL))
-        .highlight_style(highlight);
+        .highlight_style(highlight)
+        .highlight_symbol(selection_marker(true));
     frame.render_stateful_widget(list, area, &mut list_state);
 }
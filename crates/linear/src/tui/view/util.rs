@@ -4,7 +4,11 @@ use ratatui::text::{Line, Span};
 
 use linear_core::graphql::IssueSummary;
 
-pub fn issue_list_line(issue: &IssueSummary, filter: Option<&str>) -> Line<'static> {
+pub fn issue_list_line(
+    issue: &IssueSummary,
+    filter: Option<&str>,
+    stale_days: Option<i64>,
+) -> Line<'static> {
     let mut spans = Vec::new();
     spans.push(Span::raw(format!("{}  ", issue.identifier)));
     if let Some(query) = filter.filter(|q| !q.is_empty()) {
@@ -12,6 +16,12 @@ pub fn issue_list_line(issue: &IssueSummary, filter: Option<&str>) -> Line<'stat
     } else {
         spans.push(Span::raw(issue.title.clone()));
     }
+    if let Some(days) = stale_days {
+        spans.push(Span::styled(
+            format!("  {}d idle", days),
+            Style::default().fg(Color::Magenta),
+        ));
+    }
     Line::from(spans)
 }
 
@@ -46,6 +56,15 @@ fn highlight_spans(text: &str, query: &str) -> Vec<Span<'static>> {
     spans
 }
 
+/// Text marker for the highlighted row in accessible mode, where color alone does not show it.
+pub fn selection_marker(highlighted: bool) -> &'static str {
+    match (crate::accessible::enabled(), highlighted) {
+        (false, _) => "",
+        (true, true) => "> ",
+        (true, false) => "  ",
+    }
+}
+
 pub fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
     let width = width.min(area.width).max(1);
     let height = height.min(area.height).max(1);
@@ -58,3 +77,18 @@ pub fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
         height,
     }
 }
+
+/// Text bar for a 0–1 progress value, e.g. `█████░░░░░  52%`.
+pub fn progress_bar(progress: Option<f64>, width: usize) -> String {
+    let Some(progress) = progress else {
+        return format!("{}    -", "░".repeat(width));
+    };
+    let progress = progress.clamp(0.0, 1.0);
+    let filled = (progress * width as f64).round() as usize;
+    format!(
+        "{}{} {:>3.0}%",
+        "█".repeat(filled),
+        "░".repeat(width - filled),
+        progress * 100.0
+    )
+}
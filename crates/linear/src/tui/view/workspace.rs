@@ -6,14 +6,37 @@ use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wra
 use ratatui::Frame;
 use textwrap::wrap;
 
-use crate::tui::app::{App, DetailTab, Focus};
-use crate::tui::view::util::issue_list_line;
+use crate::color;
+use crate::tui::app::{App, DetailTab, EditField, FieldEditor, FieldStatus, Focus};
+use crate::tui::hyperlinks;
+use crate::tui::view::util::{issue_list_line, selection_marker};
+use linear_core::config::LayoutPreset;
 use linear_core::graphql::{IssueAssignee, IssueDetail, IssueHistory, IssueSubIssue, UserSummary};
+use linear_core::priority::{self, Priority};
 
 pub fn render(frame: &mut Frame, area: Rect, app: &App) {
+    let layout = app.layout();
+    if app.narrow() {
+        // No room for a split: the list fills the view until the detail is opened over it.
+        return if app.detail_overlay_open() || layout.layout == LayoutPreset::Zen {
+            render_detail(frame, area, app)
+        } else {
+            render_issue_list(frame, area, app)
+        };
+    }
+    let direction = match layout.layout {
+        LayoutPreset::List => return render_issue_list(frame, area, app),
+        LayoutPreset::Zen => return render_detail(frame, area, app),
+        LayoutPreset::Stacked => Direction::Vertical,
+        LayoutPreset::SideBySide => Direction::Horizontal,
+    };
+    let split = layout.split().unwrap_or(50);
     let chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .direction(direction)
+        .constraints([
+            Constraint::Percentage(split),
+            Constraint::Percentage(100 - split),
+        ])
         .split(area);
 
     render_issue_list(frame, chunks[0], app);
@@ -21,13 +44,43 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
 }
 
 fn render_issue_list(frame: &mut Frame, area: Rect, app: &App) {
+    let mut selected_row = app.selected_index();
+    // The issue on each row, `None` for group headers, so its key can be linked on that row.
+    let mut row_issues = Vec::new();
     let items: Vec<ListItem> = if app.issues().is_empty() {
         vec![ListItem::new("No issues loaded")]
+    } else if let Some(groups) = app.issue_groups() {
+        // Issues are already ordered group by group; headers shift the highlighted row.
+        let mut items = Vec::new();
+        let mut seen = 0;
+        for group in groups {
+            if seen <= app.selected_index() {
+                selected_row += 1;
+            }
+            seen += group.issues.len();
+            items.push(ListItem::new(Line::from(Span::styled(
+                format!("{} ({})", group.label, group.issues.len()),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ))));
+            row_issues.push(None);
+            items.extend(group.issues.into_iter().map(|issue| {
+                row_issues.push(Some(issue));
+                ListItem::new(issue_list_line(
+                    issue,
+                    app.title_contains(),
+                    app.stale_days(issue),
+                ))
+            }));
+        }
+        items
     } else {
         app.issues()
             .iter()
             .map(|issue| {
-                let line = issue_list_line(issue, app.title_contains());
+                row_issues.push(Some(issue));
+                let line = issue_list_line(issue, app.title_contains(), app.stale_days(issue));
                 ListItem::new(line)
             })
             .collect()
@@ -35,7 +88,7 @@ fn render_issue_list(frame: &mut Frame, area: Rect, app: &App) {
 
     let mut state = ListState::default();
     if !app.issues().is_empty() {
-        state.select(Some(app.selected_index()));
+        state.select(Some(selected_row));
     }
 
     let highlight = if matches!(app.focus(), Focus::Issues) {
@@ -44,10 +97,33 @@ fn render_issue_list(frame: &mut Frame, area: Rect, app: &App) {
         Style::default().fg(Color::DarkGray)
     };
 
+    let title = if app.refreshing() {
+        "Issues · refreshing…"
+    } else {
+        "Issues"
+    };
+    let block = Block::default().title(title).borders(Borders::ALL);
+    let inner = block.inner(area);
+
     let list = List::new(items)
-        .block(Block::default().title("Issues").borders(Borders::ALL))
-        .highlight_style(highlight);
+        .block(block)
+        .highlight_style(highlight)
+        .highlight_symbol(selection_marker(true));
     frame.render_stateful_widget(list, area, &mut state);
+
+    // Rendering scrolled the list; every row is one line tall.
+    let visible = row_issues
+        .iter()
+        .skip(state.offset())
+        .take(inner.height as usize);
+    for (y, issue) in (inner.y..).zip(visible) {
+        if let Some(issue) = issue {
+            if let Some(url) = issue.url.as_deref() {
+                let row = Rect::new(inner.x, y, inner.width, 1);
+                hyperlinks::register(row, &issue.identifier, url);
+            }
+        }
+    }
 }
 
 fn render_detail(frame: &mut Frame, area: Rect, app: &App) {
@@ -94,14 +170,14 @@ fn render_detail_content(frame: &mut Frame, area: Rect, app: &App) {
     };
 
     match app.detail_tab() {
-        DetailTab::Summary => render_summary(frame, area, issue),
+        DetailTab::Summary => render_summary(frame, area, issue, app),
         DetailTab::Description => render_description(frame, area, issue),
         DetailTab::Activity => render_activity(frame, area, issue),
         DetailTab::SubIssues => render_sub_issues(frame, area, issue),
     }
 }
 
-fn render_summary(frame: &mut Frame, area: Rect, issue: &IssueDetail) {
+fn render_summary(frame: &mut Frame, area: Rect, issue: &IssueDetail, app: &App) {
     let mut lines = Vec::new();
     lines.push(Line::from(vec![
         Span::styled(
@@ -131,23 +207,22 @@ fn render_summary(frame: &mut Frame, area: Rect, issue: &IssueDetail) {
         lines.push(Line::from("Assignee: -"));
     }
 
-    let priority = issue
-        .priority
-        .map(|p| p.to_string())
-        .unwrap_or_else(|| "-".into());
-    lines.push(Line::from(format!("Priority: {}", priority)));
+    for field in EditField::all() {
+        lines.push(editable_field_line(issue, field, app));
+    }
 
     if let Some(labels) = issue.labels.as_ref() {
         if labels.nodes.is_empty() {
             lines.push(Line::from("Labels: -"));
         } else {
-            let list = labels
-                .nodes
-                .iter()
-                .map(|l| l.name.as_str())
-                .collect::<Vec<_>>()
-                .join(", ");
-            lines.push(Line::from(format!("Labels: {}", list)));
+            let mut spans = vec![Span::raw("Labels: ")];
+            for (idx, label) in labels.nodes.iter().enumerate() {
+                if idx > 0 {
+                    spans.push(Span::raw(" "));
+                }
+                spans.push(color::label_span(label));
+            }
+            lines.push(Line::from(spans));
         }
     } else {
         lines.push(Line::from("Labels: -"));
@@ -168,12 +243,92 @@ fn render_summary(frame: &mut Frame, area: Rect, issue: &IssueDetail) {
 
     if let Some(url) = issue.url.as_deref() {
         lines.push(Line::from(format!("URL: {}", url)));
+        hyperlinks::register(area, &issue.identifier.to_uppercase(), url);
+        hyperlinks::register(area, url, url);
     }
 
     let paragraph = Paragraph::new(lines).wrap(Wrap { trim: true });
     frame.render_widget(paragraph, area);
 }
 
+/// One editable summary field with its highlight, open editor and save state.
+fn editable_field_line(issue: &IssueDetail, field: EditField, app: &App) -> Line<'static> {
+    let selected = app.focus() == Focus::Detail && app.selected_field() == field;
+    let mut spans = Vec::new();
+    if selected {
+        spans.push(Span::styled(
+            format!("▸ {}: ", field.label()),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ));
+    } else {
+        spans.push(Span::raw(format!("{}: ", field.label())));
+    }
+
+    match app.field_editor().filter(|editor| editor.field() == field) {
+        Some(FieldEditor::Priority { index }) => {
+            for (i, option) in Priority::ALL.iter().enumerate() {
+                let style = if i == *index {
+                    priority_style(Some(*option)).add_modifier(Modifier::REVERSED)
+                } else {
+                    Style::default().fg(Color::DarkGray)
+                };
+                spans.push(Span::styled(format!(" {} ", option.label()), style));
+            }
+        }
+        Some(FieldEditor::Text { input, .. }) => {
+            spans.push(Span::styled(
+                format!("{input}▏"),
+                Style::default().add_modifier(Modifier::UNDERLINED),
+            ));
+        }
+        None => spans.push(match field {
+            EditField::Priority => Span::styled(
+                priority::label_for(issue.priority),
+                priority_style(issue.priority.and_then(Priority::from_value)),
+            ),
+            EditField::Estimate => Span::raw(
+                issue
+                    .estimate
+                    .map(|estimate| estimate.to_string())
+                    .unwrap_or_else(|| "-".into()),
+            ),
+            EditField::DueDate => Span::raw(
+                issue
+                    .due_date
+                    .map(|date| date.format("%Y-%m-%d").to_string())
+                    .unwrap_or_else(|| "-".into()),
+            ),
+        }),
+    }
+
+    match app.field_status(&issue.identifier, field) {
+        Some(FieldStatus::Pending) => spans.push(Span::styled(
+            "  saving…",
+            Style::default().fg(Color::DarkGray),
+        )),
+        Some(FieldStatus::Saved) => {
+            spans.push(Span::styled("  ✓ saved", Style::default().fg(Color::Green)))
+        }
+        Some(FieldStatus::Failed(err)) => spans.push(Span::styled(
+            format!("  ✗ {err}"),
+            Style::default().fg(Color::Red),
+        )),
+        None => {}
+    }
+    Line::from(spans)
+}
+
+fn priority_style(priority: Option<Priority>) -> Style {
+    match priority {
+        Some(Priority::Urgent) => Style::default().fg(Color::Red),
+        Some(Priority::High) => Style::default().fg(Color::LightYellow),
+        Some(Priority::Medium) => Style::default().fg(Color::Blue),
+        _ => Style::default().fg(Color::DarkGray),
+    }
+}
+
 fn render_description(frame: &mut Frame, area: Rect, issue: &IssueDetail) {
     let width = area.width.saturating_sub(1).max(10) as usize;
     let description = issue.description.as_deref().unwrap_or("(no description)");
@@ -198,7 +353,7 @@ fn render_activity(frame: &mut Frame, area: Rect, issue: &IssueDetail) {
         return;
     }
 
-    entries.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+    entries.sort_by_key(|a| a.timestamp);
 
     let mut grouped: Vec<(NaiveDate, Vec<ActivityEntry>)> = Vec::new();
     for entry in entries {
@@ -387,8 +542,8 @@ fn summarize_history(entry: &IssueHistory) -> Vec<String> {
     push_change(
         &mut lines,
         "Priority",
-        entry.from_priority.map(|p| p.to_string()),
-        entry.to_priority.map(|p| p.to_string()),
+        entry.from_priority.map(|p| priority::label_for(Some(p))),
+        entry.to_priority.map(|p| priority::label_for(Some(p))),
     );
     push_change(
         &mut lines,
@@ -503,10 +658,7 @@ fn append_sub_issue_lines(
         .as_ref()
         .map(|a| display_assignee_short(a.clone()))
         .unwrap_or_else(|| "-".into());
-    let priority = node
-        .priority
-        .map(|p| p.to_string())
-        .unwrap_or_else(|| "-".into());
+    let priority = priority::label_for(node.priority);
     let mut meta = format!("state: {state}, assignee: {assignee}, priority: {priority}");
     if let Some(team) = node.team.as_ref() {
         meta.push_str(&format!(", team: {}", team.key));
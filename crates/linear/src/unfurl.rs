@@ -0,0 +1,197 @@
+use std::collections::{HashMap, HashSet};
+use std::io::{self, IsTerminal, Write};
+
+use anyhow::{Context, Result};
+use linear_core::error::{ErrorKind, LinearError};
+use linear_core::graphql::{IssueDetail, LinearGraphqlClient};
+use linear_core::unfurl;
+use serde::Serialize;
+use tokio::io::{AsyncBufReadExt, BufReader};
+
+use crate::fields::print_json;
+use crate::{build_client, hyperlink, load_session, UnfurlArgs};
+
+/// What one reference unfurls to.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Unfurled {
+    identifier: String,
+    title: String,
+    state: Option<String>,
+    assignee: Option<String>,
+    url: Option<String>,
+}
+
+impl Unfurled {
+    fn new(issue: IssueDetail) -> Self {
+        Self {
+            assignee: issue
+                .assignee
+                .and_then(|assignee| assignee.display_name.or(assignee.name)),
+            state: issue.state.map(|state| state.name),
+            identifier: issue.identifier,
+            title: issue.title,
+            url: issue.url,
+        }
+    }
+
+    /// `Fix login redirect · In Progress · ada`, without the key.
+    fn details(&self) -> String {
+        format!(
+            "{} · {} · {}",
+            self.title,
+            self.state.as_deref().unwrap_or("-"),
+            self.assignee.as_deref().unwrap_or("unassigned")
+        )
+    }
+}
+
+/// Print a one-line summary per URL or key, or annotate references in text piped on stdin.
+pub(crate) async fn run(args: UnfurlArgs) -> Result<()> {
+    let keys = if args.stdin {
+        Vec::new()
+    } else {
+        if args.references.is_empty() {
+            return Err(LinearError::validation(
+                "pass Linear issue URLs or keys, or --stdin to annotate text",
+            )
+            .into());
+        }
+        args.references.clone()
+    };
+
+    let session = load_session(&args.profile).await?;
+    let client = build_client(&session)?;
+    let mut cache = Cache {
+        client,
+        issues: HashMap::new(),
+    };
+    if args.stdin {
+        return annotate(&mut cache, args.json).await;
+    }
+
+    let mut unfurled = Vec::new();
+    let mut failed = Vec::new();
+    for key in keys {
+        match cache.issue(&key).await {
+            Ok(Some(issue)) => {
+                if !args.json {
+                    let identifier = match &issue.url {
+                        Some(url) => hyperlink::link(url, &issue.identifier),
+                        None => issue.identifier.clone(),
+                    };
+                    println!("{identifier}  {}", issue.details());
+                }
+                unfurled.push(issue);
+            }
+            Ok(None) => {
+                eprintln!("{key}: not found");
+                failed.push(ErrorKind::NotFound);
+            }
+            Err(err) => {
+                eprintln!("{key}: {err:#}");
+                failed.push(ErrorKind::classify(&err));
+            }
+        }
+    }
+    if args.json {
+        print_json(&unfurled)?;
+    }
+    match failed.first() {
+        None => Ok(()),
+        Some(&kind) => Err(LinearError::new(
+            kind,
+            format!(
+                "{} of {} reference(s) could not be unfurled",
+                failed.len(),
+                failed.len() + unfurled.len()
+            ),
+        )
+        .into()),
+    }
+}
+
+/// Copy stdin to stdout line by line, following each issue reference with its summary in
+/// brackets. Bare keys count only when their prefix is a team key of the workspace, so `UTF-8`
+/// stays as it is. With `--json`, prints the issues found instead.
+async fn annotate(cache: &mut Cache, json: bool) -> Result<()> {
+    if io::stdin().is_terminal() {
+        return Err(LinearError::validation("--stdin expects text piped on stdin").into());
+    }
+    let teams: HashSet<String> = cache
+        .client
+        .teams()
+        .await
+        .context("GraphQL request failed")?
+        .into_iter()
+        .map(|team| team.key.to_ascii_uppercase())
+        .collect();
+
+    let mut seen = Vec::new();
+    let mut lines = BufReader::new(tokio::io::stdin()).lines();
+    let mut stdout = io::stdout();
+    while let Some(line) = lines.next_line().await? {
+        let mut annotated = String::with_capacity(line.len());
+        let mut copied = 0;
+        for found in unfurl::references(&line) {
+            let team = found.key.split('-').next().unwrap_or_default();
+            if !found.url && !teams.contains(team) {
+                continue;
+            }
+            // A lookup that fails leaves the text as it was; annotating is best effort.
+            let Ok(Some(issue)) = cache.issue(&found.key).await else {
+                continue;
+            };
+            annotated.push_str(&line[copied..found.end]);
+            if found.url {
+                annotated.push_str(&format!(" [{} {}]", issue.identifier, issue.details()));
+            } else {
+                annotated.push_str(&format!(" [{}]", issue.details()));
+            }
+            copied = found.end;
+            if !seen.contains(&found.key) {
+                seen.push(found.key);
+            }
+        }
+        if !json {
+            annotated.push_str(&line[copied..]);
+            writeln!(stdout, "{annotated}")?;
+            // Each line goes out as soon as it is read, so the output keeps up with the pipe.
+            stdout.flush()?;
+        }
+    }
+    if json {
+        let issues: Vec<&Unfurled> = seen
+            .iter()
+            .filter_map(|key| cache.issues.get(key).and_then(Option::as_ref))
+            .collect();
+        print_json(&issues)?;
+    }
+    Ok(())
+}
+
+/// Issues looked up so far, so a key mentioned many times is fetched once.
+struct Cache {
+    client: LinearGraphqlClient,
+    issues: HashMap<String, Option<Unfurled>>,
+}
+
+impl Cache {
+    async fn issue(&mut self, key: &str) -> Result<Option<Unfurled>> {
+        if let Some(issue) = self.issues.get(key) {
+            return Ok(issue.clone());
+        }
+        let issue = match self.client.issue_by_key(key).await {
+            Ok(issue) => Some(Unfurled::new(issue)),
+            Err(err) => {
+                let err = anyhow::Error::from(err);
+                if ErrorKind::classify(&err) != ErrorKind::NotFound {
+                    return Err(err.context("GraphQL request failed"));
+                }
+                None
+            }
+        };
+        self.issues.insert(key.to_owned(), issue.clone());
+        Ok(issue)
+    }
+}
@@ -0,0 +1,132 @@
+//! Runs the `linear` binary against recorded sessions in `tests/tapes`, so commands are
+//! exercised end to end (argument parsing, GraphQL, rendering) without the network.
+//!
+//! Replay answers each request by its query and variables, so a command that sends a
+//! different or extra request fails with a tape error. Re-record a tape with `--record` after
+//! changing the queries a command sends.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+
+use tempfile::TempDir;
+
+fn tape(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/tapes")
+        .join(format!("{name}.json"))
+}
+
+/// Run `linear --replay <tape> <args>` with a throwaway home, feeding it `stdin`.
+fn replay(name: &str, args: &[&str], stdin: &str) -> Output {
+    let home = TempDir::new().unwrap();
+    let mut child = Command::new(env!("CARGO_BIN_EXE_linear"))
+        .arg("--replay")
+        .arg(tape(name))
+        .args(args)
+        .env_clear()
+        .env("PATH", std::env::var_os("PATH").unwrap_or_default())
+        .env("HOME", home.path())
+        .env("XDG_CONFIG_HOME", home.path().join("config"))
+        .env("XDG_CACHE_HOME", home.path().join("cache"))
+        .env("XDG_DATA_HOME", home.path().join("data"))
+        .env("LINEAR_API_KEY", "test-key")
+        .env("LINEAR_NO_DAEMON", "1")
+        .env("LANG", "C")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    assert!(
+        output.status.success(),
+        "linear exited with {}: {}",
+        output.status,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn issue_list_prints_a_table() {
+    let out = stdout(&replay(
+        "issue_list",
+        &["issue", "list", "--limit", "2"],
+        "",
+    ));
+    let rows: Vec<&str> = out.lines().collect();
+    assert!(rows[0].starts_with("IDENTIFIER"), "{out}");
+    assert!(rows[2].starts_with("ENG-1") && rows[2].contains("Fix login loop"));
+    assert!(rows[3].starts_with("ENG-2") && rows[3].contains("In Progress"));
+}
+
+#[test]
+fn issue_view_prints_the_issue() {
+    let out = stdout(&replay("issue_view", &["issue", "view", "ENG-1"], ""));
+    assert!(out.starts_with("ENG-1 — Fix login loop"), "{out}");
+    assert!(out.contains("State     : Todo"));
+    assert!(out.contains("Labels    : bug"));
+    assert!(out.contains("Redirects forever after SSO."));
+}
+
+#[test]
+fn issue_update_prints_the_diff_and_the_updated_issue() {
+    let out = stdout(&replay(
+        "issue_update",
+        &["issue", "update", "ENG-1", "--title", "New title"],
+        "",
+    ));
+    assert!(out.contains("-Fix login loop\n+New title"), "{out}");
+    assert!(out.contains("Updated ENG-1"));
+    assert!(out.contains("ENG-1 — New title"));
+}
+
+#[test]
+fn issue_delete_sends_the_mutation() {
+    let out = stdout(&replay(
+        "issue_delete",
+        &["issue", "delete", "ENG-1", "--yes"],
+        "",
+    ));
+    assert_eq!(out.trim(), "Deleted ENG-1");
+
+    // The view tape ends before any mutation, so the delete has nothing to replay.
+    let output = replay("issue_view", &["issue", "delete", "ENG-1", "--yes"], "");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("no recorded response for IssueDelete"),
+        "{stderr}"
+    );
+}
+
+#[test]
+fn batch_dry_run_reports_what_each_key_would_send() {
+    let out = stdout(&replay(
+        "issue_update_dry_run",
+        &[
+            "--dry-run",
+            "issue",
+            "update",
+            "--stdin-keys",
+            "--title",
+            "New title",
+        ],
+        "ENG-1\n",
+    ));
+    assert!(
+        out.contains(r#"would send IssueUpdate {"id":"issue-1","input":{"title":"New title"}}"#),
+        "{out}"
+    );
+    assert!(out.contains("1 succeeded, 0 failed"));
+}
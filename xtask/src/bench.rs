@@ -0,0 +1,259 @@
+//! `cargo xtask bench-pages`: time and heap use of decoding large issue pages.
+//!
+//! A local server answers every request with the same synthetic page, sent with chunked
+//! encoding like the API does. Each page is fetched the old way (whole body into a string, then
+//! `serde_json::from_str`) and through `LinearGraphqlClient`, which decodes large bodies as they
+//! stream in. The server paces its chunks to `--mbps` so parsing can overlap the transfer as it
+//! does against the real API. Peak heap is measured by a counting allocator, relative to the heap in use before
+//! each request.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Context, Result};
+use linear_core::auth::AuthSession;
+use linear_core::graphql::LinearGraphqlClient;
+use linear_core::query::IssueQuery;
+use serde::Deserialize;
+use serde_json::json;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+use crate::BenchPagesArgs;
+
+/// Size of each chunk the server writes.
+const CHUNK: usize = 16 * 1024;
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+static IN_USE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            let in_use = IN_USE.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+            PEAK.fetch_max(in_use, Ordering::Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        IN_USE.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+}
+
+/// Fields `issue export` reads from each node, descriptions included.
+#[derive(Deserialize)]
+#[allow(dead_code)]
+struct Node {
+    id: String,
+    identifier: String,
+    title: String,
+    description: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Envelope {
+    data: Data,
+}
+
+#[derive(Deserialize)]
+struct Data {
+    issues: Nodes,
+}
+
+#[derive(Deserialize)]
+struct Nodes {
+    nodes: Vec<Node>,
+}
+
+#[derive(Default)]
+struct Samples {
+    latencies: Vec<Duration>,
+    peaks: Vec<usize>,
+}
+
+impl Samples {
+    fn report(&mut self, label: &str) {
+        self.latencies.sort();
+        self.peaks.sort();
+        let median = self.latencies[self.latencies.len() / 2];
+        let peak = self.peaks[self.peaks.len() / 2];
+        println!(
+            "{label:<10} {:>9.1} ms {:>9.1} ms {:>10.1} MiB",
+            median.as_secs_f64() * 1000.0,
+            self.latencies[0].as_secs_f64() * 1000.0,
+            peak as f64 / (1024.0 * 1024.0)
+        );
+    }
+}
+
+pub(crate) async fn run(args: BenchPagesArgs) -> Result<()> {
+    if args.pages == 0 {
+        bail!("--pages must be at least 1");
+    }
+    let body = page(args.nodes, args.description_bytes);
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let endpoint = format!("http://{}/graphql", listener.local_addr()?);
+    // Time one chunk takes on the wire at --mbps.
+    let per_chunk = (args.mbps > 0.0)
+        .then(|| Duration::from_secs_f64((CHUNK * 8) as f64 / (args.mbps * 1_000_000.0)));
+    let served = body.clone();
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            let body = served.clone();
+            tokio::spawn(async move {
+                let _ = serve(stream, &body, per_chunk).await;
+            });
+        }
+    });
+
+    let session = AuthSession::new_api_key("bench".into());
+    let client = LinearGraphqlClient::with_endpoint(&session, &endpoint)?;
+    let http = reqwest::Client::new();
+    let query = IssueQuery::new().first(args.nodes);
+
+    println!(
+        "{} pages of {} issues, {:.1} MiB each, {}",
+        args.pages,
+        args.nodes,
+        body.len() as f64 / (1024.0 * 1024.0),
+        if args.mbps > 0.0 {
+            format!("{} Mbit/s", args.mbps)
+        } else {
+            "unthrottled".to_owned()
+        }
+    );
+    println!(
+        "{:<10} {:>12} {:>12} {:>14}",
+        "", "median", "best", "peak heap"
+    );
+
+    let mut buffered = Samples::default();
+    let mut streamed = Samples::default();
+    for _ in 0..args.pages {
+        let (elapsed, peak) = measure(async {
+            let text = http
+                .post(&endpoint)
+                .json(&json!({ "query": "query { issues { nodes { id } } }" }))
+                .send()
+                .await?
+                .text()
+                .await?;
+            let page: Envelope = serde_json::from_str(&text)?;
+            Ok(page.data.issues.nodes.len())
+        })
+        .await?;
+        buffered.latencies.push(elapsed);
+        buffered.peaks.push(peak);
+
+        let (elapsed, peak) = measure(async {
+            let page = client.query_issues::<Node>(&query).await?;
+            Ok(page.nodes.len())
+        })
+        .await?;
+        streamed.latencies.push(elapsed);
+        streamed.peaks.push(peak);
+    }
+    buffered.report("buffered");
+    streamed.report("streamed");
+    Ok(())
+}
+
+/// Run one fetch, returning its wall time and the heap it used at most beyond what was in use
+/// before it started.
+async fn measure(
+    fetch: impl std::future::Future<Output = Result<usize>>,
+) -> Result<(Duration, usize)> {
+    let base = IN_USE.load(Ordering::Relaxed);
+    PEAK.store(base, Ordering::Relaxed);
+    let started = Instant::now();
+    let nodes = fetch.await?;
+    let elapsed = started.elapsed();
+    if nodes == 0 {
+        bail!("the benchmark page decoded to no issues");
+    }
+    Ok((elapsed, PEAK.load(Ordering::Relaxed).saturating_sub(base)))
+}
+
+fn page(nodes: usize, description_bytes: usize) -> Vec<u8> {
+    let description = "lorem ipsum dolor sit amet ".repeat(description_bytes / 27 + 1);
+    let nodes: Vec<_> = (1..=nodes)
+        .map(|n| {
+            json!({
+                "id": format!("issue-{n}"),
+                "identifier": format!("ENG-{n}"),
+                "title": format!("Synthetic issue {n}"),
+                "description": &description[..description_bytes],
+            })
+        })
+        .collect();
+    serde_json::to_vec(&json!({
+        "data": { "issues": {
+            "nodes": nodes,
+            "pageInfo": { "hasNextPage": false, "endCursor": null }
+        } }
+    }))
+    .expect("page serializes")
+}
+
+/// Answer every request on a keep-alive connection with `body`, pausing `per_chunk` before
+/// each chunk to model the network.
+async fn serve(mut stream: TcpStream, body: &[u8], per_chunk: Option<Duration>) -> Result<()> {
+    let mut buffer = Vec::new();
+    loop {
+        let Some(length) = read_request(&mut stream, &mut buffer).await? else {
+            return Ok(());
+        };
+        buffer.drain(..length);
+        stream
+            .write_all(
+                b"HTTP/1.1 200 OK\r\ncontent-type: application/json\r\n\
+                  transfer-encoding: chunked\r\n\r\n",
+            )
+            .await?;
+        for chunk in body.chunks(CHUNK) {
+            if let Some(delay) = per_chunk {
+                tokio::time::sleep(delay).await;
+            }
+            stream
+                .write_all(format!("{:x}\r\n", chunk.len()).as_bytes())
+                .await?;
+            stream.write_all(chunk).await?;
+            stream.write_all(b"\r\n").await?;
+        }
+        stream.write_all(b"0\r\n\r\n").await?;
+    }
+}
+
+/// Read until `buffer` holds a whole request, returning its length, or `None` at end of stream.
+async fn read_request(stream: &mut TcpStream, buffer: &mut Vec<u8>) -> Result<Option<usize>> {
+    loop {
+        if let Some(head) = buffer.windows(4).position(|window| window == b"\r\n\r\n") {
+            let headers = String::from_utf8_lossy(&buffer[..head]).to_ascii_lowercase();
+            let body = headers
+                .lines()
+                .find_map(|line| line.strip_prefix("content-length:"))
+                .map(|value| value.trim().parse::<usize>())
+                .transpose()
+                .context("bad content-length")?
+                .unwrap_or(0);
+            if buffer.len() >= head + 4 + body {
+                return Ok(Some(head + 4 + body));
+            }
+        }
+        let mut chunk = [0; 8192];
+        let read = stream.read(&mut chunk).await?;
+        if read == 0 {
+            return Ok(None);
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+    }
+}
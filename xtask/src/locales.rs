@@ -0,0 +1,102 @@
+//! Checks message catalogs against the English one and the ids used in the code.
+
+use std::collections::BTreeSet;
+
+use linear_core::i18n::Catalog;
+
+/// Problems with one translated catalog; missing ids only warn since English fills them in.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Report {
+    pub missing: Vec<String>,
+    pub problems: Vec<String>,
+}
+
+/// Compare `catalog` with `english`: unknown ids and placeables English does not pass are errors.
+pub fn compare(english: &Catalog, catalog: &Catalog) -> Report {
+    let mut report = Report::default();
+    for id in english.ids() {
+        if catalog.get(id).is_none() {
+            report.missing.push(id.to_owned());
+        }
+    }
+    for id in catalog.ids() {
+        let Some(source) = english.get(id) else {
+            report
+                .problems
+                .push(format!("`{id}` is not in the English catalog"));
+            continue;
+        };
+        let known = placeables(source);
+        for name in placeables(catalog.get(id).unwrap_or_default()) {
+            if !known.contains(&name) {
+                report
+                    .problems
+                    .push(format!("`{id}` uses {{ ${name} }}, which is never passed"));
+            }
+        }
+    }
+    report
+}
+
+/// Message ids passed to `tr("…")` or `tr_args("…", …)` in `source`.
+pub fn used_ids(source: &str) -> BTreeSet<String> {
+    let mut ids = BTreeSet::new();
+    for call in ["tr(", "tr_args("] {
+        let mut rest = source;
+        while let Some(start) = rest.find(call) {
+            let before = rest[..start].chars().next_back();
+            rest = &rest[start + call.len()..];
+            if before.is_some_and(|c| c.is_alphanumeric() || c == '_') {
+                continue;
+            }
+            let Some(literal) = rest.trim_start().strip_prefix('"') else {
+                continue;
+            };
+            if let Some(end) = literal.find('"') {
+                ids.insert(literal[..end].to_owned());
+            }
+        }
+    }
+    ids
+}
+
+/// `{ $name }` placeables in a message.
+fn placeables(message: &str) -> BTreeSet<String> {
+    message
+        .split('{')
+        .skip(1)
+        .filter_map(|part| part.split_once('}'))
+        .filter_map(|(inside, _)| inside.trim().strip_prefix('$'))
+        .map(str::to_owned)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_missing_unknown_and_bad_placeables() {
+        let english = Catalog::parse("a = A { $x }\nb = B").unwrap();
+        let german = Catalog::parse("a = A { $y }\nc = C").unwrap();
+        let report = compare(&english, &german);
+        assert_eq!(report.missing, vec!["b"]);
+        assert_eq!(
+            report.problems,
+            vec![
+                "`a` uses { $y }, which is never passed".to_owned(),
+                "`c` is not in the English catalog".to_owned(),
+            ]
+        );
+    }
+
+    #[test]
+    fn finds_ids_passed_to_tr() {
+        let source = r#"set(tr("one")); tr_args(
+            "two", &[]); attr("not-an-id"); tr(name)"#;
+        assert_eq!(
+            used_ids(source).into_iter().collect::<Vec<_>>(),
+            vec!["one", "two"]
+        );
+    }
+}
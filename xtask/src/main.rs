@@ -0,0 +1,224 @@
+//! Development tasks for linear-rs. Run with `cargo xtask <command>`.
+
+mod bench;
+mod locales;
+mod query;
+mod schema;
+mod verify;
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use clap::{Args, Parser, Subcommand};
+use linear_core::i18n::{Catalog, FALLBACK_LOCALE};
+
+use schema::Schema;
+
+#[derive(Parser)]
+#[command(name = "xtask", about = "Development tasks for linear-rs")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Check every GraphQL operation in the client against the Linear schema.
+    VerifySchema(VerifySchemaArgs),
+    /// Check the message catalogs against English and the message ids used in the code.
+    VerifyLocales(VerifyLocalesArgs),
+    /// Compare buffered and streamed decoding of large issue pages.
+    BenchPages(BenchPagesArgs),
+}
+
+#[derive(Args)]
+struct BenchPagesArgs {
+    /// Pages fetched with each decoder.
+    #[arg(long, default_value_t = 20)]
+    pages: usize,
+    /// Issues per page.
+    #[arg(long, default_value_t = 250)]
+    nodes: usize,
+    /// Length of each issue's description.
+    #[arg(long, default_value_t = 4000)]
+    description_bytes: usize,
+    /// Bandwidth the server sends at, in Mbit/s; 0 sends as fast as loopback allows.
+    #[arg(long, default_value_t = 100.0)]
+    mbps: f64,
+}
+
+#[derive(Args)]
+struct VerifyLocalesArgs {
+    /// Directory holding `<tag>.ftl` catalogs, including `en.ftl`.
+    #[arg(long, default_value = "crates/linear/locales")]
+    dir: PathBuf,
+    /// Source tree scanned for `tr("…")` calls.
+    #[arg(long, default_value = "crates/linear/src")]
+    sources: PathBuf,
+}
+
+#[derive(Args)]
+struct VerifySchemaArgs {
+    /// Introspection result to validate against (vendored schema).
+    #[arg(long, default_value = "schema/linear.json")]
+    schema: PathBuf,
+    /// Introspect the live API instead, authenticating with LINEAR_API_KEY.
+    #[arg(long)]
+    live: bool,
+    /// With --live, write the introspection result to --schema.
+    #[arg(long, requires = "live")]
+    save: bool,
+    /// GraphQL endpoint used by --live.
+    #[arg(long, default_value = schema::DEFAULT_ENDPOINT)]
+    endpoint: String,
+    /// Rust source files to scan for query strings.
+    #[arg(
+        long = "source",
+        default_value = "crates/linear-core/src/graphql/client.rs"
+    )]
+    sources: Vec<PathBuf>,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+    // Paths are relative to the workspace root regardless of where cargo was invoked.
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .context("xtask must live inside the workspace")?;
+    std::env::set_current_dir(root)?;
+    match cli.command {
+        Command::VerifySchema(args) => verify_schema(args).await,
+        Command::VerifyLocales(args) => verify_locales(args),
+        Command::BenchPages(args) => bench::run(args).await,
+    }
+}
+
+fn verify_locales(args: VerifyLocalesArgs) -> Result<()> {
+    let load = |path: &Path| -> Result<Catalog> {
+        let source = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        Catalog::parse(&source).with_context(|| format!("{} is malformed", path.display()))
+    };
+    let english = load(&args.dir.join(format!("{FALLBACK_LOCALE}.ftl")))?;
+
+    let mut problems = 0;
+    let mut used = BTreeSet::new();
+    for path in rust_files(&args.sources)? {
+        used.extend(locales::used_ids(&std::fs::read_to_string(&path)?));
+    }
+    for id in &used {
+        if english.get(id).is_none() {
+            problems += 1;
+            eprintln!("{FALLBACK_LOCALE}.ftl: `{id}` is used in the code but not defined");
+        }
+    }
+
+    let mut entries: Vec<PathBuf> = std::fs::read_dir(&args.dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<std::io::Result<_>>()?;
+    entries.sort();
+    let mut locales = 0;
+    for path in entries {
+        let tag = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or_default();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("ftl") || tag == FALLBACK_LOCALE {
+            continue;
+        }
+        locales += 1;
+        let report = locales::compare(&english, &load(&path)?);
+        for problem in &report.problems {
+            problems += 1;
+            eprintln!("{tag}.ftl: {problem}");
+        }
+        if !report.missing.is_empty() {
+            eprintln!(
+                "{tag}.ftl: {} message(s) fall back to English: {}",
+                report.missing.len(),
+                report.missing.join(", ")
+            );
+        }
+    }
+
+    if problems > 0 {
+        bail!("{} problem(s) in the message catalogs", problems);
+    }
+    println!(
+        "{} message(s) in use; {} translation(s) match the English catalog",
+        used.len(),
+        locales
+    );
+    Ok(())
+}
+
+/// Every `.rs` file under `dir`.
+fn rust_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in
+        std::fs::read_dir(dir).with_context(|| format!("failed to read {}", dir.display()))?
+    {
+        let path = entry?.path();
+        if path.is_dir() {
+            files.extend(rust_files(&path)?);
+        } else if path.extension().and_then(|ext| ext.to_str()) == Some("rs") {
+            files.push(path);
+        }
+    }
+    Ok(files)
+}
+
+async fn verify_schema(args: VerifySchemaArgs) -> Result<()> {
+    let schema = if args.live {
+        let key = std::env::var("LINEAR_API_KEY")
+            .context("--live needs a personal API key in LINEAR_API_KEY")?;
+        let response = schema::introspect(&args.endpoint, &key).await?;
+        if args.save {
+            if let Some(parent) = args.schema.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(
+                &args.schema,
+                serde_json::to_string_pretty(&response)? + "\n",
+            )
+            .with_context(|| format!("failed to write {}", args.schema.display()))?;
+            eprintln!("Saved schema to {}", args.schema.display());
+        }
+        Schema::from_introspection(response)?
+    } else {
+        if !args.schema.exists() {
+            bail!(
+                "no vendored schema at {}; run `cargo xtask verify-schema --live --save` \
+                 with LINEAR_API_KEY set, or pass --schema <introspection.json>",
+                args.schema.display()
+            );
+        }
+        Schema::load(&args.schema)?
+    };
+
+    let mut operations = 0;
+    let mut problems = 0;
+    for path in &args.sources {
+        let source = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        for embedded in verify::extract(&source) {
+            operations += 1;
+            for diagnostic in verify::check(&schema, &embedded) {
+                problems += 1;
+                eprintln!("{}:{}", path.display(), diagnostic);
+            }
+        }
+    }
+
+    if problems > 0 {
+        bail!(
+            "{} problem(s) in {} GraphQL operation(s)",
+            problems,
+            operations
+        );
+    }
+    println!("{} GraphQL operation(s) match the schema", operations);
+    Ok(())
+}
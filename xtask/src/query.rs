@@ -0,0 +1,451 @@
+//! Minimal parser for the executable GraphQL documents embedded in the client.
+//!
+//! Covers what hand-written operations use: operations with variables, fields with aliases and
+//! arguments, inline fragments, and directives (parsed and ignored). Named fragments are rejected.
+
+use std::fmt;
+
+use anyhow::{anyhow, bail, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperationKind {
+    Query,
+    Mutation,
+}
+
+impl fmt::Display for OperationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            OperationKind::Query => "query",
+            OperationKind::Mutation => "mutation",
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Operation {
+    pub kind: OperationKind,
+    pub name: Option<String>,
+    pub variables: Vec<VariableDefinition>,
+    pub selection: Vec<Selection>,
+}
+
+#[derive(Debug)]
+pub struct VariableDefinition {
+    pub name: String,
+    pub ty: TypeRef,
+    pub has_default: bool,
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypeRef {
+    Named(String),
+    List(Box<TypeRef>),
+    NonNull(Box<TypeRef>),
+}
+
+impl TypeRef {
+    /// Innermost named type.
+    pub fn base(&self) -> &str {
+        match self {
+            TypeRef::Named(name) => name,
+            TypeRef::List(inner) | TypeRef::NonNull(inner) => inner.base(),
+        }
+    }
+}
+
+impl fmt::Display for TypeRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypeRef::Named(name) => f.write_str(name),
+            TypeRef::List(inner) => write!(f, "[{}]", inner),
+            TypeRef::NonNull(inner) => write!(f, "{}!", inner),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Selection {
+    Field(Field),
+    InlineFragment {
+        type_condition: Option<String>,
+        selection: Vec<Selection>,
+        line: usize,
+    },
+}
+
+#[derive(Debug)]
+pub struct Field {
+    pub name: String,
+    pub arguments: Vec<Argument>,
+    pub selection: Vec<Selection>,
+    pub line: usize,
+}
+
+#[derive(Debug)]
+pub struct Argument {
+    pub name: String,
+    pub value: Value,
+    pub line: usize,
+}
+
+#[derive(Debug)]
+pub enum Value {
+    Variable(String),
+    Int,
+    Float,
+    String,
+    Boolean,
+    Null,
+    Enum(String),
+    List(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Punct(char),
+    Spread,
+    Name(String),
+    Int,
+    Float,
+    String,
+}
+
+/// Parse a single operation; lines in the result are 1-based within `source`.
+pub fn parse(source: &str) -> Result<Operation> {
+    let tokens = tokenize(source)?;
+    let mut parser = Parser { tokens, pos: 0 };
+    let operation = parser.operation()?;
+    if let Some((token, line)) = parser.tokens.get(parser.pos) {
+        bail!(
+            "line {}: unexpected {:?} after the operation (one operation per string)",
+            line,
+            token
+        );
+    }
+    Ok(operation)
+}
+
+fn tokenize(source: &str) -> Result<Vec<(Token, usize)>> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut line = 1;
+    let mut i = 0;
+    while i < chars.len() {
+        let ch = chars[i];
+        match ch {
+            '\n' => {
+                line += 1;
+                i += 1;
+            }
+            ',' | ' ' | '\t' | '\r' | '\u{feff}' => i += 1,
+            '#' => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            '{' | '}' | '(' | ')' | '[' | ']' | ':' | '!' | '$' | '=' | '@' => {
+                tokens.push((Token::Punct(ch), line));
+                i += 1;
+            }
+            '.' if chars[i..].starts_with(&['.', '.', '.']) => {
+                tokens.push((Token::Spread, line));
+                i += 3;
+            }
+            '"' => {
+                let start_line = line;
+                if chars[i..].starts_with(&['"', '"', '"']) {
+                    i += 3;
+                    loop {
+                        if i >= chars.len() {
+                            bail!("line {}: unterminated block string", start_line);
+                        }
+                        if chars[i..].starts_with(&['"', '"', '"']) {
+                            i += 3;
+                            break;
+                        }
+                        if chars[i] == '\n' {
+                            line += 1;
+                        }
+                        i += 1;
+                    }
+                } else {
+                    i += 1;
+                    loop {
+                        match chars.get(i) {
+                            None | Some('\n') => bail!("line {}: unterminated string", start_line),
+                            Some('\\') => i += 2,
+                            Some('"') => {
+                                i += 1;
+                                break;
+                            }
+                            Some(_) => i += 1,
+                        }
+                    }
+                }
+                tokens.push((Token::String, start_line));
+            }
+            '-' | '0'..='9' => {
+                let mut float = false;
+                i += 1;
+                while let Some(&next) = chars.get(i) {
+                    match next {
+                        '0'..='9' => {}
+                        '.' | 'e' | 'E' | '+' | '-' => float = true,
+                        _ => break,
+                    }
+                    i += 1;
+                }
+                tokens.push((if float { Token::Float } else { Token::Int }, line));
+            }
+            _ if ch == '_' || ch.is_ascii_alphabetic() => {
+                let start = i;
+                while i < chars.len() && (chars[i] == '_' || chars[i].is_ascii_alphanumeric()) {
+                    i += 1;
+                }
+                tokens.push((Token::Name(chars[start..i].iter().collect()), line));
+            }
+            _ => bail!("line {}: unexpected character '{}'", line, ch),
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(token, _)| token)
+    }
+
+    fn line(&self) -> usize {
+        self.tokens
+            .get(self.pos)
+            .or(self.tokens.last())
+            .map_or(1, |(_, line)| *line)
+    }
+
+    fn next(&mut self) -> Result<Token> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .map(|(token, _)| token.clone())
+            .ok_or_else(|| anyhow!("line {}: unexpected end of document", self.line()))?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn is_punct(&self, ch: char) -> bool {
+        self.peek() == Some(&Token::Punct(ch))
+    }
+
+    fn eat_punct(&mut self, ch: char) -> bool {
+        let matched = self.is_punct(ch);
+        if matched {
+            self.pos += 1;
+        }
+        matched
+    }
+
+    fn expect_punct(&mut self, ch: char) -> Result<()> {
+        let line = self.line();
+        match self.next()? {
+            Token::Punct(found) if found == ch => Ok(()),
+            other => bail!("line {}: expected '{}', found {:?}", line, ch, other),
+        }
+    }
+
+    fn name(&mut self) -> Result<String> {
+        let line = self.line();
+        match self.next()? {
+            Token::Name(name) => Ok(name),
+            other => bail!("line {}: expected a name, found {:?}", line, other),
+        }
+    }
+
+    fn operation(&mut self) -> Result<Operation> {
+        if self.is_punct('{') {
+            return Ok(Operation {
+                kind: OperationKind::Query,
+                name: None,
+                variables: Vec::new(),
+                selection: self.selection_set()?,
+            });
+        }
+        let line = self.line();
+        let kind = match self.name()?.as_str() {
+            "query" => OperationKind::Query,
+            "mutation" => OperationKind::Mutation,
+            "fragment" => bail!("line {}: named fragments are not supported", line),
+            other => bail!("line {}: unsupported operation type '{}'", line, other),
+        };
+        let name = match self.peek() {
+            Some(Token::Name(_)) => Some(self.name()?),
+            _ => None,
+        };
+        let variables = if self.is_punct('(') {
+            self.variable_definitions()?
+        } else {
+            Vec::new()
+        };
+        self.directives()?;
+        Ok(Operation {
+            kind,
+            name,
+            variables,
+            selection: self.selection_set()?,
+        })
+    }
+
+    fn variable_definitions(&mut self) -> Result<Vec<VariableDefinition>> {
+        self.expect_punct('(')?;
+        let mut variables = Vec::new();
+        while !self.eat_punct(')') {
+            let line = self.line();
+            self.expect_punct('$')?;
+            let name = self.name()?;
+            self.expect_punct(':')?;
+            let ty = self.type_ref()?;
+            let has_default = self.eat_punct('=');
+            if has_default {
+                self.value()?;
+            }
+            self.directives()?;
+            variables.push(VariableDefinition {
+                name,
+                ty,
+                has_default,
+                line,
+            });
+        }
+        Ok(variables)
+    }
+
+    fn type_ref(&mut self) -> Result<TypeRef> {
+        let ty = if self.eat_punct('[') {
+            let inner = self.type_ref()?;
+            self.expect_punct(']')?;
+            TypeRef::List(Box::new(inner))
+        } else {
+            TypeRef::Named(self.name()?)
+        };
+        Ok(if self.eat_punct('!') {
+            TypeRef::NonNull(Box::new(ty))
+        } else {
+            ty
+        })
+    }
+
+    fn directives(&mut self) -> Result<()> {
+        while self.eat_punct('@') {
+            self.name()?;
+            if self.is_punct('(') {
+                self.arguments()?;
+            }
+        }
+        Ok(())
+    }
+
+    fn selection_set(&mut self) -> Result<Vec<Selection>> {
+        self.expect_punct('{')?;
+        let mut selection = Vec::new();
+        while !self.eat_punct('}') {
+            let line = self.line();
+            if self.peek() == Some(&Token::Spread) {
+                self.pos += 1;
+                let type_condition = match self.peek() {
+                    Some(Token::Name(name)) if name == "on" => {
+                        self.pos += 1;
+                        Some(self.name()?)
+                    }
+                    Some(Token::Name(name)) => {
+                        bail!("line {}: fragment spread '{}' is not supported", line, name)
+                    }
+                    _ => None,
+                };
+                self.directives()?;
+                selection.push(Selection::InlineFragment {
+                    type_condition,
+                    selection: self.selection_set()?,
+                    line,
+                });
+                continue;
+            }
+            let mut name = self.name()?;
+            if self.eat_punct(':') {
+                name = self.name()?;
+            }
+            let arguments = if self.is_punct('(') {
+                self.arguments()?
+            } else {
+                Vec::new()
+            };
+            self.directives()?;
+            let children = if self.is_punct('{') {
+                self.selection_set()?
+            } else {
+                Vec::new()
+            };
+            selection.push(Selection::Field(Field {
+                name,
+                arguments,
+                selection: children,
+                line,
+            }));
+        }
+        Ok(selection)
+    }
+
+    fn arguments(&mut self) -> Result<Vec<Argument>> {
+        self.expect_punct('(')?;
+        let mut arguments = Vec::new();
+        while !self.eat_punct(')') {
+            let line = self.line();
+            let name = self.name()?;
+            self.expect_punct(':')?;
+            arguments.push(Argument {
+                name,
+                value: self.value()?,
+                line,
+            });
+        }
+        Ok(arguments)
+    }
+
+    fn value(&mut self) -> Result<Value> {
+        let line = self.line();
+        Ok(match self.next()? {
+            Token::Punct('$') => Value::Variable(self.name()?),
+            Token::Int => Value::Int,
+            Token::Float => Value::Float,
+            Token::String => Value::String,
+            Token::Name(name) => match name.as_str() {
+                "true" | "false" => Value::Boolean,
+                "null" => Value::Null,
+                _ => Value::Enum(name),
+            },
+            Token::Punct('[') => {
+                let mut items = Vec::new();
+                while !self.eat_punct(']') {
+                    items.push(self.value()?);
+                }
+                Value::List(items)
+            }
+            Token::Punct('{') => {
+                let mut fields = Vec::new();
+                while !self.eat_punct('}') {
+                    let name = self.name()?;
+                    self.expect_punct(':')?;
+                    fields.push((name, self.value()?));
+                }
+                Value::Object(fields)
+            }
+            other => bail!("line {}: expected a value, found {:?}", line, other),
+        })
+    }
+}
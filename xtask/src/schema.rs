@@ -0,0 +1,208 @@
+//! GraphQL schema loaded from an introspection result, vendored or fetched live.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::query::TypeRef;
+
+pub const DEFAULT_ENDPOINT: &str = "https://api.linear.app/graphql";
+
+const INTROSPECTION_QUERY: &str = r#"
+    query IntrospectionQuery {
+        __schema {
+            queryType { name }
+            mutationType { name }
+            types {
+                kind
+                name
+                fields(includeDeprecated: true) {
+                    name
+                    args { name defaultValue type { ...TypeRef } }
+                    type { ...TypeRef }
+                }
+                inputFields { name defaultValue type { ...TypeRef } }
+                enumValues(includeDeprecated: true) { name }
+            }
+        }
+    }
+
+    fragment TypeRef on __Type {
+        kind name
+        ofType { kind name ofType { kind name ofType { kind name ofType { kind name
+            ofType { kind name ofType { kind name ofType { kind name } } } } } } }
+    }
+"#;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TypeKind {
+    Scalar,
+    Object,
+    Interface,
+    Union,
+    Enum,
+    InputObject,
+    List,
+    NonNull,
+}
+
+impl TypeKind {
+    pub fn is_leaf(self) -> bool {
+        matches!(self, TypeKind::Scalar | TypeKind::Enum)
+    }
+
+    pub fn is_input(self) -> bool {
+        matches!(
+            self,
+            TypeKind::Scalar | TypeKind::Enum | TypeKind::InputObject
+        )
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FullType {
+    pub kind: TypeKind,
+    pub name: String,
+    #[serde(default)]
+    pub fields: Option<Vec<FieldDef>>,
+    #[serde(default)]
+    pub input_fields: Option<Vec<InputValue>>,
+    #[serde(default)]
+    pub enum_values: Option<Vec<EnumValue>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FieldDef {
+    pub name: String,
+    #[serde(default)]
+    pub args: Vec<InputValue>,
+    #[serde(rename = "type")]
+    pub ty: IntrospectedType,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InputValue {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: IntrospectedType,
+    #[serde(default)]
+    pub default_value: Option<String>,
+}
+
+impl InputValue {
+    pub fn is_required(&self) -> bool {
+        self.ty.kind == TypeKind::NonNull && self.default_value.is_none()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EnumValue {
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntrospectedType {
+    pub kind: TypeKind,
+    pub name: Option<String>,
+    pub of_type: Option<Box<IntrospectedType>>,
+}
+
+impl IntrospectedType {
+    pub fn to_type_ref(&self) -> TypeRef {
+        let inner = || {
+            self.of_type
+                .as_ref()
+                .map_or(TypeRef::Named(String::new()), |inner| inner.to_type_ref())
+        };
+        match self.kind {
+            TypeKind::NonNull => TypeRef::NonNull(Box::new(inner())),
+            TypeKind::List => TypeRef::List(Box::new(inner())),
+            _ => TypeRef::Named(self.name.clone().unwrap_or_default()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Schema {
+    pub query_type: String,
+    pub mutation_type: Option<String>,
+    types: HashMap<String, FullType>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawSchema {
+    query_type: NamedRef,
+    mutation_type: Option<NamedRef>,
+    types: Vec<FullType>,
+}
+
+#[derive(Deserialize)]
+struct NamedRef {
+    name: String,
+}
+
+impl Schema {
+    /// Parse an introspection result, either the full response or its `data` payload.
+    pub fn from_introspection(value: Value) -> Result<Self> {
+        let mut value = value;
+        if let Some(data) = value.get_mut("data") {
+            value = data.take();
+        }
+        let raw = value
+            .get_mut("__schema")
+            .map(Value::take)
+            .context("introspection result has no __schema")?;
+        let raw: RawSchema =
+            serde_json::from_value(raw).context("failed to parse introspection result")?;
+        Ok(Self {
+            query_type: raw.query_type.name,
+            mutation_type: raw.mutation_type.map(|named| named.name),
+            types: raw
+                .types
+                .into_iter()
+                .map(|ty| (ty.name.clone(), ty))
+                .collect(),
+        })
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read schema {}", path.display()))?;
+        let value = serde_json::from_str(&content)
+            .with_context(|| format!("{} is not valid JSON", path.display()))?;
+        Self::from_introspection(value)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&FullType> {
+        self.types.get(name)
+    }
+}
+
+/// Run the introspection query against `endpoint`, returning the raw response.
+pub async fn introspect(endpoint: &str, authorization: &str) -> Result<Value> {
+    let response = reqwest::Client::new()
+        .post(endpoint)
+        .header("Authorization", authorization)
+        .header("User-Agent", "linear-rs-xtask")
+        .json(&serde_json::json!({ "query": INTROSPECTION_QUERY }))
+        .send()
+        .await
+        .context("introspection request failed")?;
+    let status = response.status();
+    let body: Value = response
+        .json()
+        .await
+        .context("introspection response is not JSON")?;
+    if !status.is_success() || body.get("errors").is_some_and(|errors| !errors.is_null()) {
+        bail!("introspection failed with HTTP {}: {}", status, body);
+    }
+    Ok(body)
+}
@@ -0,0 +1,611 @@
+//! Checks hand-written GraphQL operations against the schema.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use crate::query::{self, Field, Operation, Selection, TypeRef, Value};
+use crate::schema::{FullType, InputValue, Schema, TypeKind};
+
+/// A GraphQL string constant found in a Rust source file.
+#[derive(Debug)]
+pub struct Embedded {
+    /// Enclosing function, for context in diagnostics.
+    pub function: String,
+    /// 1-based line of the `r#"` opening the string.
+    pub line: usize,
+    pub text: String,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub line: usize,
+    pub context: String,
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}: {}", self.line, self.context, self.message)
+    }
+}
+
+/// Collect `const X: &str = r#"..."#;` strings that hold a query or mutation.
+pub fn extract(source: &str) -> Vec<Embedded> {
+    let mut found = Vec::new();
+    let mut function = String::from("<module>");
+    let mut lines = source.lines().enumerate();
+    while let Some((index, line)) = lines.next() {
+        let trimmed = line.trim_start();
+        if let Some(rest) = trimmed
+            .strip_prefix("pub async fn ")
+            .or_else(|| trimmed.strip_prefix("async fn "))
+            .or_else(|| trimmed.strip_prefix("pub fn "))
+            .or_else(|| trimmed.strip_prefix("fn "))
+        {
+            function = rest.split(['(', '<']).next().unwrap_or(rest).to_owned();
+        }
+        if !(trimmed.starts_with("const ") && trimmed.contains(": &str = r#\"")) {
+            continue;
+        }
+        let Some((_, mut text)) = line.split_once("r#\"").map(|(a, b)| (a, b.to_owned())) else {
+            continue;
+        };
+        while !text.contains("\"#") {
+            let Some((_, next)) = lines.next() else { break };
+            text.push('\n');
+            text.push_str(next);
+        }
+        if let Some((body, _)) = text.split_once("\"#") {
+            text = body.to_owned();
+        }
+        let head = text.trim_start();
+        if head.starts_with("query") || head.starts_with("mutation") || head.starts_with('{') {
+            found.push(Embedded {
+                function: function.clone(),
+                line: index + 1,
+                text,
+            });
+        }
+    }
+    found
+}
+
+/// Validate one embedded operation; diagnostics carry absolute source lines.
+pub fn check(schema: &Schema, embedded: &Embedded) -> Vec<Diagnostic> {
+    let at = |line: usize| embedded.line + line - 1;
+    let operation = match query::parse(&embedded.text) {
+        Ok(operation) => operation,
+        Err(err) => {
+            return vec![Diagnostic {
+                line: embedded.line,
+                context: embedded.function.clone(),
+                message: format!("parse error: {}", err),
+            }]
+        }
+    };
+    let context = match &operation.name {
+        Some(name) => format!("{} ({} {})", embedded.function, operation.kind, name),
+        None => format!("{} (anonymous {})", embedded.function, operation.kind),
+    };
+    let mut validator = Validator {
+        schema,
+        variables: operation
+            .variables
+            .iter()
+            .map(|variable| (variable.name.as_str(), variable))
+            .collect(),
+        used: HashSet::new(),
+        problems: Vec::new(),
+    };
+    validator.operation(&operation);
+    validator
+        .problems
+        .into_iter()
+        .map(|(line, message)| Diagnostic {
+            line: at(line),
+            context: context.clone(),
+            message,
+        })
+        .collect()
+}
+
+struct Validator<'a> {
+    schema: &'a Schema,
+    variables: HashMap<&'a str, &'a query::VariableDefinition>,
+    used: HashSet<String>,
+    problems: Vec<(usize, String)>,
+}
+
+impl<'a> Validator<'a> {
+    fn report(&mut self, line: usize, message: String) {
+        self.problems.push((line, message));
+    }
+
+    fn operation(&mut self, operation: &'a Operation) {
+        for variable in &operation.variables {
+            match self.schema.get(variable.ty.base()) {
+                None => self.report(
+                    variable.line,
+                    format!(
+                        "variable '${}' has unknown type '{}'",
+                        variable.name,
+                        variable.ty.base()
+                    ),
+                ),
+                Some(ty) if !ty.kind.is_input() => self.report(
+                    variable.line,
+                    format!(
+                        "variable '${}' has output type '{}'; variables must be input types",
+                        variable.name, ty.name
+                    ),
+                ),
+                Some(_) => {}
+            }
+        }
+
+        let root = match operation.kind {
+            query::OperationKind::Query => Some(self.schema.query_type.clone()),
+            query::OperationKind::Mutation => self.schema.mutation_type.clone(),
+        };
+        let Some(root) = root else {
+            self.report(1, format!("schema has no {} root type", operation.kind));
+            return;
+        };
+        self.selection(&root, &operation.selection);
+
+        for variable in &operation.variables {
+            if !self.used.contains(&variable.name) {
+                self.report(
+                    variable.line,
+                    format!("variable '${}' is declared but never used", variable.name),
+                );
+            }
+        }
+    }
+
+    fn selection(&mut self, parent: &str, selection: &'a [Selection]) {
+        for item in selection {
+            match item {
+                Selection::Field(field) => self.field(parent, field),
+                Selection::InlineFragment {
+                    type_condition,
+                    selection,
+                    line,
+                } => {
+                    let target = type_condition.as_deref().unwrap_or(parent);
+                    match self.schema.get(target) {
+                        Some(ty) if !ty.kind.is_leaf() && ty.kind != TypeKind::InputObject => {
+                            self.selection(target, selection)
+                        }
+                        Some(_) => self.report(
+                            *line,
+                            format!("fragment condition '{}' is not a composite type", target),
+                        ),
+                        None => {
+                            self.report(*line, format!("fragment on unknown type '{}'", target))
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn field(&mut self, parent: &str, field: &'a Field) {
+        if field.name == "__typename" {
+            if !field.selection.is_empty() {
+                self.report(
+                    field.line,
+                    "'__typename' cannot have a selection set".into(),
+                );
+            }
+            return;
+        }
+        let schema = self.schema;
+        let Some(parent_type) = schema.get(parent) else {
+            return;
+        };
+        let Some(fields) = parent_type.fields.as_ref() else {
+            self.report(
+                field.line,
+                format!(
+                    "cannot select '{}' on '{}' directly; use an inline fragment",
+                    field.name, parent
+                ),
+            );
+            return;
+        };
+        let Some(definition) = fields.iter().find(|def| def.name == field.name) else {
+            let message = format!(
+                "field '{}' does not exist on type '{}'{}",
+                field.name,
+                parent,
+                suggest(&field.name, fields.iter().map(|def| def.name.as_str()))
+            );
+            self.report(field.line, message);
+            return;
+        };
+
+        let location = format!("{}.{}", parent, field.name);
+        self.arguments(field, &definition.args, &location);
+
+        let ty = definition.ty.to_type_ref();
+        let Some(field_type) = schema.get(ty.base()) else {
+            return;
+        };
+        if field_type.kind.is_leaf() {
+            if !field.selection.is_empty() {
+                self.report(
+                    field.line,
+                    format!(
+                        "'{}' returns leaf type '{}' and cannot have a selection set",
+                        location, ty
+                    ),
+                );
+            }
+        } else if field.selection.is_empty() {
+            self.report(
+                field.line,
+                format!(
+                    "'{}' returns object type '{}' and needs a selection set",
+                    location, ty
+                ),
+            );
+        } else {
+            self.selection(&field_type.name, &field.selection);
+        }
+    }
+
+    fn arguments(&mut self, field: &'a Field, definitions: &[InputValue], location: &str) {
+        for argument in &field.arguments {
+            match definitions.iter().find(|def| def.name == argument.name) {
+                Some(definition) => self.value(
+                    &argument.value,
+                    &definition.ty.to_type_ref(),
+                    &format!("argument '{}' of '{}'", argument.name, location),
+                    argument.line,
+                ),
+                None => {
+                    let message = format!(
+                        "unknown argument '{}' on '{}'{}",
+                        argument.name,
+                        location,
+                        suggest(
+                            &argument.name,
+                            definitions.iter().map(|def| def.name.as_str())
+                        )
+                    );
+                    self.report(argument.line, message);
+                }
+            }
+        }
+        for definition in definitions.iter().filter(|def| def.is_required()) {
+            if !field
+                .arguments
+                .iter()
+                .any(|arg| arg.name == definition.name)
+            {
+                self.report(
+                    field.line,
+                    format!(
+                        "missing required argument '{}: {}' on '{}'",
+                        definition.name,
+                        definition.ty.to_type_ref(),
+                        location
+                    ),
+                );
+            }
+        }
+    }
+
+    fn value(&mut self, value: &'a Value, expected: &TypeRef, context: &str, line: usize) {
+        if let Value::Variable(name) = value {
+            self.used.insert(name.clone());
+            match self.variables.get(name.as_str()) {
+                None => self.report(line, format!("variable '${}' is not declared", name)),
+                Some(variable) if !variable_fits(variable, expected) => self.report(
+                    line,
+                    format!(
+                        "variable '${}' of type '{}' cannot be used for {} of type '{}'",
+                        name, variable.ty, context, expected
+                    ),
+                ),
+                Some(_) => {}
+            }
+            return;
+        }
+        if let Value::Null = value {
+            if matches!(expected, TypeRef::NonNull(_)) {
+                self.report(
+                    line,
+                    format!("null given for {} of type '{}'", context, expected),
+                );
+            }
+            return;
+        }
+        let expected = match expected {
+            TypeRef::NonNull(inner) => inner.as_ref(),
+            other => other,
+        };
+        match (expected, value) {
+            (TypeRef::List(inner), Value::List(items)) => {
+                for item in items {
+                    self.value(item, inner, context, line);
+                }
+            }
+            (TypeRef::List(inner), _) => self.value(value, inner, context, line),
+            (TypeRef::Named(name), _) => {
+                let schema = self.schema;
+                match schema.get(name) {
+                    Some(ty) => self.literal(value, ty, context, line),
+                    None => self.report(line, format!("{} has unknown type '{}'", context, name)),
+                }
+            }
+            (TypeRef::NonNull(_), _) => unreachable!("non-null is stripped above"),
+        }
+    }
+
+    fn literal(&mut self, value: &'a Value, ty: &FullType, context: &str, line: usize) {
+        let mismatch =
+            |kind: &str| format!("{} value given for {} of type '{}'", kind, context, ty.name);
+        match value {
+            Value::Object(fields) => {
+                let Some(inputs) = ty.input_fields.as_ref() else {
+                    self.report(line, mismatch("object"));
+                    return;
+                };
+                for (name, field_value) in fields {
+                    match inputs.iter().find(|input| &input.name == name) {
+                        Some(input) => self.value(
+                            field_value,
+                            &input.ty.to_type_ref(),
+                            &format!("field '{}' of input '{}'", name, ty.name),
+                            line,
+                        ),
+                        None => {
+                            let message = format!(
+                                "input type '{}' has no field '{}'{}",
+                                ty.name,
+                                name,
+                                suggest(name, inputs.iter().map(|input| input.name.as_str()))
+                            );
+                            self.report(line, message);
+                        }
+                    }
+                }
+                for input in inputs.iter().filter(|input| input.is_required()) {
+                    if !fields.iter().any(|(name, _)| name == &input.name) {
+                        self.report(
+                            line,
+                            format!(
+                                "input '{}' is missing required field '{}'",
+                                ty.name, input.name
+                            ),
+                        );
+                    }
+                }
+            }
+            Value::Enum(name) => {
+                let known = ty
+                    .enum_values
+                    .as_ref()
+                    .map(|values| values.iter().any(|value| &value.name == name));
+                match known {
+                    Some(true) => {}
+                    Some(false) => self.report(
+                        line,
+                        format!(
+                            "'{}' is not a value of enum '{}' ({})",
+                            name, ty.name, context
+                        ),
+                    ),
+                    None => self.report(line, mismatch("enum")),
+                }
+            }
+            Value::List(_) => self.report(line, mismatch("list")),
+            Value::Int | Value::Float | Value::String | Value::Boolean => {
+                let kind = match value {
+                    Value::Int => "Int",
+                    Value::Float => "Float",
+                    Value::String => "String",
+                    _ => "Boolean",
+                };
+                if !scalar_accepts(ty, kind) {
+                    self.report(line, mismatch(kind));
+                }
+            }
+            Value::Variable(_) | Value::Null => {}
+        }
+    }
+}
+
+/// Whether a literal of scalar `kind` is valid input for `ty`.
+fn scalar_accepts(ty: &FullType, kind: &str) -> bool {
+    if ty.kind != TypeKind::Scalar {
+        return false;
+    }
+    match ty.name.as_str() {
+        "Int" => kind == "Int",
+        "Float" => kind == "Int" || kind == "Float",
+        "String" => kind == "String",
+        "Boolean" => kind == "Boolean",
+        "ID" => kind == "String" || kind == "Int",
+        // Custom scalars (DateTime, JSON, ...) define their own coercion.
+        _ => true,
+    }
+}
+
+/// GraphQL's "all variable usages are allowed" rule.
+fn variable_fits(variable: &query::VariableDefinition, expected: &TypeRef) -> bool {
+    match (&variable.ty, expected) {
+        (ty, TypeRef::NonNull(inner))
+            if variable.has_default && !matches!(ty, TypeRef::NonNull(_)) =>
+        {
+            type_fits(ty, inner)
+        }
+        (ty, expected) => type_fits(ty, expected),
+    }
+}
+
+fn type_fits(given: &TypeRef, expected: &TypeRef) -> bool {
+    match (given, expected) {
+        (TypeRef::NonNull(given), TypeRef::NonNull(expected)) => type_fits(given, expected),
+        (_, TypeRef::NonNull(_)) => false,
+        (TypeRef::NonNull(given), expected) => type_fits(given, expected),
+        (TypeRef::List(given), TypeRef::List(expected)) => type_fits(given, expected),
+        (TypeRef::Named(given), TypeRef::Named(expected)) => given == expected,
+        _ => false,
+    }
+}
+
+/// ` (did you mean 'x'?)` for the closest candidate within a small edit distance.
+fn suggest<'c>(name: &str, candidates: impl Iterator<Item = &'c str>) -> String {
+    let lowered = name.to_ascii_lowercase();
+    candidates
+        .map(|candidate| {
+            (
+                edit_distance(&lowered, &candidate.to_ascii_lowercase()),
+                candidate,
+            )
+        })
+        .filter(|(distance, _)| *distance <= 2.max(name.len() / 4))
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| format!(" (did you mean '{}'?)", candidate))
+        .unwrap_or_default()
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let current = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                previous
+            } else {
+                1 + previous.min(row[j]).min(current)
+            };
+            previous = current;
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn named(kind: &str, name: &str) -> serde_json::Value {
+        serde_json::json!({ "kind": kind, "name": name, "ofType": null })
+    }
+
+    fn non_null(inner: serde_json::Value) -> serde_json::Value {
+        serde_json::json!({ "kind": "NON_NULL", "name": null, "ofType": inner })
+    }
+
+    fn list(inner: serde_json::Value) -> serde_json::Value {
+        serde_json::json!({ "kind": "LIST", "name": null, "ofType": inner })
+    }
+
+    fn schema() -> Schema {
+        let scalar = |name: &str| serde_json::json!({ "kind": "SCALAR", "name": name });
+        Schema::from_introspection(serde_json::json!({ "data": { "__schema": {
+            "queryType": { "name": "Query" },
+            "mutationType": null,
+            "types": [
+                scalar("String"),
+                scalar("Int"),
+                scalar("DateTime"),
+                { "kind": "OBJECT", "name": "Query", "fields": [
+                    { "name": "issue", "type": named("OBJECT", "Issue"), "args": [
+                        { "name": "id", "type": non_null(named("SCALAR", "String")), "defaultValue": null }
+                    ] },
+                    { "name": "issues", "type": non_null(named("OBJECT", "IssueConnection")), "args": [
+                        { "name": "first", "type": named("SCALAR", "Int"), "defaultValue": null },
+                        { "name": "filter", "type": named("INPUT_OBJECT", "IssueFilter"), "defaultValue": null },
+                        { "name": "orderBy", "type": named("ENUM", "OrderBy"), "defaultValue": null }
+                    ] }
+                ] },
+                { "kind": "OBJECT", "name": "IssueConnection", "fields": [
+                    { "name": "nodes", "args": [], "type": non_null(list(non_null(named("OBJECT", "Issue")))) }
+                ] },
+                { "kind": "OBJECT", "name": "Issue", "fields": [
+                    { "name": "id", "args": [], "type": non_null(named("SCALAR", "String")) },
+                    { "name": "title", "args": [], "type": non_null(named("SCALAR", "String")) },
+                    { "name": "updatedAt", "args": [], "type": non_null(named("SCALAR", "DateTime")) }
+                ] },
+                { "kind": "INPUT_OBJECT", "name": "IssueFilter", "inputFields": [
+                    { "name": "title", "type": named("INPUT_OBJECT", "StringComparator"), "defaultValue": null }
+                ] },
+                { "kind": "INPUT_OBJECT", "name": "StringComparator", "inputFields": [
+                    { "name": "eq", "type": named("SCALAR", "String"), "defaultValue": null }
+                ] },
+                { "kind": "ENUM", "name": "OrderBy", "enumValues": [
+                    { "name": "createdAt" }, { "name": "updatedAt" }
+                ] }
+            ]
+        } } }))
+        .unwrap()
+    }
+
+    fn messages(source: &str) -> Vec<String> {
+        let embedded = extract(source);
+        assert_eq!(embedded.len(), 1);
+        check(&schema(), &embedded[0])
+            .into_iter()
+            .map(|diagnostic| diagnostic.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn accepts_valid_operations() {
+        let source = r##"
+            pub async fn issues(&self) {
+                const QUERY: &str = r#"
+                    query Issues($first: Int!, $title: String) {
+                        issues(first: $first, orderBy: updatedAt, filter: { title: { eq: $title } }) {
+                            nodes { id title updatedAt __typename }
+                        }
+                    }
+                "#;
+            }
+        "##;
+        assert!(messages(source).is_empty(), "{:?}", messages(source));
+    }
+
+    #[test]
+    fn reports_schema_mismatches_with_source_lines() {
+        let source = r##"
+            pub async fn issue(&self) {
+                const QUERY: &str = r#"
+                    query Issue($id: Int!, $unused: String) {
+                        issue(id: $id) {
+                            titel
+                        }
+                        issues(filter: { titl: { eq: "x" } }, orderBy: priority) {
+                            nodes
+                        }
+                    }
+                "#;
+            }
+        "##;
+        let messages = messages(source);
+        assert_eq!(
+            messages,
+            vec![
+                "5: issue (query Issue): variable '$id' of type 'Int!' cannot be used for \
+                 argument 'id' of 'Query.issue' of type 'String!'",
+                "6: issue (query Issue): field 'titel' does not exist on type 'Issue' \
+                 (did you mean 'title'?)",
+                "8: issue (query Issue): input type 'IssueFilter' has no field 'titl' \
+                 (did you mean 'title'?)",
+                "8: issue (query Issue): 'priority' is not a value of enum 'OrderBy' \
+                 (argument 'orderBy' of 'Query.issues')",
+                "9: issue (query Issue): 'IssueConnection.nodes' returns object type \
+                 '[Issue!]!' and needs a selection set",
+                "4: issue (query Issue): variable '$unused' is declared but never used",
+            ]
+        );
+    }
+}